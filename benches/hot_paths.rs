@@ -0,0 +1,50 @@
+use criterion::{
+    Criterion,
+    criterion_main,
+    criterion_group,
+};
+use pbtc_core::prelude::BenchFixtures;
+
+fn bench_block_deserialization(c: &mut Criterion) {
+    let fixtures = BenchFixtures::load().unwrap();
+    c.bench_function("parse_btc_submission", |b|
+        b.iter(|| fixtures.parse_btc_submission().unwrap())
+    );
+    c.bench_function("parse_eth_submission", |b|
+        b.iter(|| fixtures.parse_eth_submission().unwrap())
+    );
+    c.bench_function("parse_borrowed_btc_submission", |b|
+        b.iter(|| fixtures.parse_borrowed_btc_submission().unwrap())
+    );
+    c.bench_function("parse_borrowed_eth_submission", |b|
+        b.iter(|| fixtures.parse_borrowed_eth_submission().unwrap())
+    );
+}
+
+fn bench_filtering(c: &mut Criterion) {
+    let fixtures = BenchFixtures::load().unwrap();
+    c.bench_function("filter_p2sh_deposit_txs", |b|
+        b.iter(|| fixtures.filter_p2sh_deposit_txs().unwrap())
+    );
+    c.bench_function("filter_receipts", |b|
+        b.iter(|| fixtures.filter_receipts().unwrap())
+    );
+}
+
+fn bench_signing(c: &mut Criterion) {
+    let fixtures = BenchFixtures::load().unwrap();
+    c.bench_function("sign_btc_tx", |b|
+        b.iter(|| fixtures.sign_btc_tx().unwrap())
+    );
+    c.bench_function("sign_eth_mint_tx", |b|
+        b.iter(|| fixtures.sign_eth_mint_tx().unwrap())
+    );
+}
+
+criterion_group!(
+    benches,
+    bench_block_deserialization,
+    bench_filtering,
+    bench_signing,
+);
+criterion_main!(benches);
@@ -0,0 +1,204 @@
+use std::{
+    env,
+    fs,
+    process,
+    str::FromStr,
+    collections::HashMap,
+};
+use simplelog::{
+    Config,
+    LevelFilter,
+    SimpleLogger,
+};
+use pbtc_core::{
+    PbtcResult,
+    PbtcCoreError,
+    RocksDbDatabase,
+    SubmissionMaterial,
+    submit_block,
+    get_enclave_state,
+    debug_get_all_utxos,
+    get_eth_pending_txs,
+    check_core_integrity,
+    debug_get_core_state,
+    get_core_version_info,
+    debug_get_key_from_db,
+    get_latest_block_numbers,
+    debug_get_safe_addresses,
+    get_ptoken_contract_address,
+    submit_btc_block_to_enclave,
+    submit_eth_block_to_enclave,
+    maybe_initialize_btc_enclave,
+    maybe_initialize_eth_enclave,
+    debug_set_key_in_db_to_value,
+    debug_get_unexpected_db_keys,
+};
+
+static USAGE: &str = "❍ pToken Core CLI ❍
+
+    An example CLI driving the pBTC core via a RocksDB database.
+
+❍ Usage ❍
+
+    ptokens-cli [--db=<path>] [--verbose] <command> [<args>...]
+
+❍ Commands ❍
+
+    init-btc <block-file> [--fee=<u64>] [--difficulty=<u64>]
+        [--network=<network>] [--confs=<u64>]
+    init-eth <block-file> [--chain-id=<u8>] [--gas-price=<u64>]
+        [--confs=<u64>]
+    submit-btc-block <block-file> [--dry-run]
+    submit-eth-block <block-file> [--dry-run]
+    submit-block <submission-material-file>
+    get-state
+    get-latest-block-numbers
+    get-core-version
+    get-eth-pending-txs
+    get-ptoken-contract-address
+    check-core-integrity
+    debug-get-core-state
+    debug-get-all-utxos
+    debug-get-safe-addresses
+    debug-get-unexpected-db-keys
+    debug-get-key <key>
+    debug-set-key <key> <value> <signature>
+
+❍ Options ❍
+
+    --db=<path>     Path to the RocksDB database [default: ./database].
+    --verbose       Log the core's progress to the terminal.
+";
+
+struct CliArgs {
+    command: String,
+    positionals: Vec<String>,
+    options: HashMap<String, String>,
+}
+
+impl CliArgs {
+    fn parse(args: Vec<String>) -> PbtcResult<Self> {
+        let mut positionals = vec![];
+        let mut options = HashMap::new();
+        args.into_iter().for_each(|arg| match arg.starts_with("--") {
+            false => positionals.push(arg),
+            true => {
+                let mut split = arg[2..].splitn(2, '=');
+                let name = split.next().unwrap_or_default().to_string();
+                let value = split.next().unwrap_or("true").to_string();
+                options.insert(name, value);
+            }
+        });
+        match positionals.is_empty() {
+            true => Err(PbtcCoreError::Custom(USAGE.to_string())),
+            false => Ok(
+                CliArgs {
+                    command: positionals.remove(0),
+                    positionals,
+                    options,
+                }
+            ),
+        }
+    }
+
+    fn get_positional(&self, index: usize, name: &str) -> PbtcResult<String> {
+        match self.positionals.get(index) {
+            Some(positional) => Ok(positional.clone()),
+            None => Err(PbtcCoreError::Custom(
+                format!("✘ Missing argument: <{}>!\n\n{}", name, USAGE)
+            )),
+        }
+    }
+
+    fn get_file_contents(&self, name: &str) -> PbtcResult<String> {
+        Ok(fs::read_to_string(self.get_positional(0, name)?)?)
+    }
+
+    fn get_option<T: FromStr>(&self, name: &str, default: T) -> PbtcResult<T> {
+        match self.options.get(name) {
+            None => Ok(default),
+            Some(value) => value.parse().map_err(|_|
+                PbtcCoreError::Custom(
+                    format!("✘ Invalid value for --{}: {}!", name, value)
+                )
+            ),
+        }
+    }
+
+    fn is_flag_set(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+}
+
+fn run_command(args: CliArgs) -> PbtcResult<String> {
+    if args.is_flag_set("verbose") {
+        SimpleLogger::init(LevelFilter::Info, Config::default())?;
+    };
+    let db = RocksDbDatabase::open(
+        &args.get_option("db", "./database".to_string())?
+    )?;
+    match args.command.as_str() {
+        "init-btc" => maybe_initialize_btc_enclave(
+            db,
+            args.get_file_contents("block-file")?,
+            args.get_option("fee", 23)?,
+            args.get_option("difficulty", 1337)?,
+            args.get_option("network", "Testnet".to_string())?,
+            args.get_option("confs", 0)?,
+        ),
+        "init-eth" => maybe_initialize_eth_enclave(
+            db,
+            args.get_file_contents("block-file")?,
+            args.get_option("chain-id", 3)?,
+            args.get_option("gas-price", 20_000_000_000)?,
+            args.get_option("confs", 0)?,
+        ),
+        "submit-btc-block" => submit_btc_block_to_enclave(
+            db,
+            args.get_file_contents("block-file")?,
+            args.is_flag_set("dry-run"),
+        ),
+        "submit-eth-block" => submit_eth_block_to_enclave(
+            db,
+            args.get_file_contents("block-file")?,
+            args.is_flag_set("dry-run"),
+        ),
+        "submit-block" => SubmissionMaterial::from_str(
+            &args.get_file_contents("submission-material-file")?
+        )
+            .and_then(|material| submit_block(db, material)),
+        "get-state" => get_enclave_state(db),
+        "get-latest-block-numbers" => get_latest_block_numbers(db),
+        "get-core-version" => get_core_version_info(db),
+        "get-eth-pending-txs" => get_eth_pending_txs(db),
+        "get-ptoken-contract-address" => get_ptoken_contract_address(db),
+        "check-core-integrity" => check_core_integrity(db),
+        "debug-get-core-state" => debug_get_core_state(db),
+        "debug-get-all-utxos" => debug_get_all_utxos(db),
+        "debug-get-safe-addresses" => debug_get_safe_addresses(db),
+        "debug-get-unexpected-db-keys" => debug_get_unexpected_db_keys(db),
+        "debug-get-key" => debug_get_key_from_db(
+            db,
+            args.get_positional(0, "key")?,
+        ),
+        "debug-set-key" => debug_set_key_in_db_to_value(
+            db,
+            args.get_positional(0, "key")?,
+            args.get_positional(1, "value")?,
+            args.get_positional(2, "signature")?,
+        ),
+        command => Err(PbtcCoreError::Custom(
+            format!("✘ Unrecognized command: {}!\n\n{}", command, USAGE)
+        )),
+    }
+}
+
+fn main() {
+    match CliArgs::parse(env::args().skip(1).collect()).and_then(run_command) {
+        Ok(output) => println!("{}", output),
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,70 @@
+// An IndexedDB-backed database for the core's WASM bindings. IndexedDB is
+// asynchronous whilst the core's database calls are synchronous, so the store
+// is loaded into memory when opened & each transaction's writes are persisted
+// when it ends. Keys & values are hex strings.
+export class IndexedDbDatabase {
+  static async open(name) {
+    const idb = await new Promise((resolve, reject) => {
+      const request = indexedDB.open(name, 1)
+      request.onupgradeneeded = () => request.result.createObjectStore('core')
+      request.onsuccess = () => resolve(request.result)
+      request.onerror = () => reject(request.error)
+    })
+    const store = idb.transaction('core').objectStore('core')
+    const [keys, values] = await Promise.all(
+      [store.getAllKeys(), store.getAll()].map(request =>
+        new Promise((resolve, reject) => {
+          request.onsuccess = () => resolve(request.result)
+          request.onerror = () => reject(request.error)
+        })
+      )
+    )
+    const cache = new Map(keys.map((key, i) => [key, values[i]]))
+    return new IndexedDbDatabase(idb, cache)
+  }
+
+  constructor(idb, cache) {
+    this.idb = idb
+    this.cache = cache
+    this.writes = new Map()
+  }
+
+  get(key) {
+    return this.cache.get(key)
+  }
+
+  put(key, value) {
+    this.cache.set(key, value)
+    this.writes.set(key, value)
+  }
+
+  delete(key) {
+    this.cache.delete(key)
+    this.writes.set(key, undefined)
+  }
+
+  keysWithPrefix(prefix) {
+    return [...this.cache.keys()].filter(key => key.startsWith(prefix))
+  }
+
+  startTransaction() {
+    this.writes.clear()
+  }
+
+  endTransaction() {
+    const store = this.idb.transaction('core', 'readwrite').objectStore('core')
+    this.writes.forEach((value, key) =>
+      value === undefined ? store.delete(key) : store.put(value, key)
+    )
+    this.writes.clear()
+  }
+}
+
+// Usage, with the bindings built via `wasm-pack build --target web`:
+//
+//   import init, { getEnclaveState } from './pkg/pbtc_core.js'
+//   import { IndexedDbDatabase } from './indexed-db-database.js'
+//
+//   await init()
+//   const db = await IndexedDbDatabase.open('ptokens-core')
+//   const enclaveState = JSON.parse(getEnclaveState(db))
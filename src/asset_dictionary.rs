@@ -0,0 +1,223 @@
+use ethereum_types::Address as EthAddress;
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    constants::ASSET_DICTIONARY_KEY,
+    token_decimals::check_host_token_decimals,
+    btc::btc_constants::MAX_PEG_IN_FEE_BASIS_POINTS,
+    utils::{
+        convert_hex_to_address,
+        check_hex_is_valid_ethereum_address,
+    },
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetDictionaryEntry {
+    pub token_symbol: String,
+    pub eth_contract_address: EthAddress,
+    pub token_decimals: u32,
+    pub peg_in_fee_basis_points: u64,
+    pub minimum_peg_in_satoshis: u64,
+    pub minimum_peg_out_satoshis: u64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AssetDictionaryEntryJson {
+    pub token_symbol: String,
+    pub eth_contract_address: String,
+    pub token_decimals: u32,
+    pub peg_in_fee_basis_points: u64,
+    pub minimum_peg_in_satoshis: u64,
+    pub minimum_peg_out_satoshis: u64,
+}
+
+impl AssetDictionaryEntry {
+    pub fn from_json(json: &AssetDictionaryEntryJson) -> Result<Self> {
+        if json.token_symbol.is_empty() {
+            return Err(AppError::InvalidInput(
+                "✘ Asset dictionary token symbol cannot be empty!".to_string()
+            ))
+        };
+        if json.peg_in_fee_basis_points > MAX_PEG_IN_FEE_BASIS_POINTS {
+            return Err(AppError::InvalidAmount(
+                format!(
+                    "✘ Asset peg-in fee cannot exceed {} basis points!",
+                    MAX_PEG_IN_FEE_BASIS_POINTS,
+                )
+            ))
+        };
+        let eth_contract_address =
+            match check_hex_is_valid_ethereum_address(
+                &json.eth_contract_address
+            ) {
+                true => convert_hex_to_address(
+                    json.eth_contract_address.clone()
+                ),
+                false => Err(AppError::InvalidAddress(
+                    "✘ Asset contract address is not a valid ETH address!"
+                        .to_string()
+                )),
+            }?;
+        if eth_contract_address.is_zero() {
+            return Err(AppError::InvalidAddress(
+                "✘ Asset contract address cannot be the zero address!"
+                    .to_string()
+            ))
+        };
+        Ok(
+            AssetDictionaryEntry {
+                eth_contract_address,
+                token_symbol: json.token_symbol.to_uppercase(),
+                token_decimals: check_host_token_decimals(json.token_decimals)?,
+                peg_in_fee_basis_points: json.peg_in_fee_basis_points,
+                minimum_peg_in_satoshis: json.minimum_peg_in_satoshis,
+                minimum_peg_out_satoshis: json.minimum_peg_out_satoshis,
+            }
+        )
+    }
+
+    pub fn from_json_string(json_string: &str) -> Result<Self> {
+        match serde_json::from_str(json_string) {
+            Ok(json) => AssetDictionaryEntry::from_json(&json),
+            Err(err) => Err(AppError::InvalidInput(
+                format!("✘ Error parsing asset dictionary entry: {}", err)
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetDictionary(pub Vec<AssetDictionaryEntry>);
+
+impl AssetDictionary {
+    pub fn get_from_db<D>(db: &D) -> Result<Self>
+        where D: DatabaseInterface
+    {
+        trace!("✔ Getting asset dictionary from db...");
+        match db.get(ASSET_DICTIONARY_KEY.to_vec(), DataSensitivity::Public) {
+            Err(_) => Ok(AssetDictionary::default()),
+            Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+        }
+    }
+
+    pub fn put_in_db<D>(&self, db: &D) -> Result<()>
+        where D: DatabaseInterface
+    {
+        trace!("✔ Putting asset dictionary in db...");
+        db.put(
+            ASSET_DICTIONARY_KEY.to_vec(),
+            serde_json::to_vec(self)?,
+            DataSensitivity::Public,
+        )
+    }
+
+    pub fn get_entry(
+        &self,
+        token_symbol: &str,
+    ) -> Option<&AssetDictionaryEntry> {
+        self.0
+            .iter()
+            .find(|entry| entry.token_symbol == token_symbol.to_uppercase())
+    }
+
+    pub fn add_entry(mut self, entry: AssetDictionaryEntry) -> Result<Self> {
+        match self.get_entry(&entry.token_symbol).is_some() {
+            true => Err(AppError::InvalidInput(
+                format!(
+                    "✘ Asset '{}' already exists in asset dictionary!",
+                    entry.token_symbol,
+                )
+            )),
+            false => {
+                info!("✔ Adding asset '{}' to dictionary...", entry.token_symbol);
+                self.0.push(entry);
+                Ok(self)
+            }
+        }
+    }
+
+    pub fn remove_entry(mut self, token_symbol: &str) -> Result<Self> {
+        match self.get_entry(token_symbol).is_some() {
+            false => Err(AppError::NotFound(
+                format!(
+                    "✘ Asset '{}' does not exist in asset dictionary!",
+                    token_symbol,
+                )
+            )),
+            true => {
+                info!("✔ Removing asset '{}' from dictionary...", token_symbol);
+                self.0.retain(|entry|
+                    entry.token_symbol != token_symbol.to_uppercase()
+                );
+                Ok(self)
+            }
+        }
+    }
+
+    pub fn to_json_string(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_test_database;
+
+    fn get_sample_asset_dictionary_entry_json_string() -> String {
+        format!(
+            "{{{},{}{},{},{},{},{}}}",
+            "\"token_symbol\":\"pbtc\"",
+            "\"eth_contract_address\":",
+            "\"0x1739624f5cd969885a224da84418d12b8570d61a\"",
+            "\"token_decimals\":18",
+            "\"peg_in_fee_basis_points\":25",
+            "\"minimum_peg_in_satoshis\":5000",
+            "\"minimum_peg_out_satoshis\":5000",
+        )
+    }
+
+    fn get_sample_asset_dictionary_entry() -> AssetDictionaryEntry {
+        AssetDictionaryEntry::from_json_string(
+            &get_sample_asset_dictionary_entry_json_string()
+        ).unwrap()
+    }
+
+    #[test]
+    fn should_parse_asset_dictionary_entry_from_str() {
+        let result = get_sample_asset_dictionary_entry();
+        assert_eq!(result.token_symbol, "PBTC");
+        assert_eq!(result.token_decimals, 18);
+        assert_eq!(result.peg_in_fee_basis_points, 25);
+    }
+
+    #[test]
+    fn should_fail_to_parse_entry_with_fee_above_max_basis_points() {
+        let json_string = get_sample_asset_dictionary_entry_json_string()
+            .replace(
+                "\"peg_in_fee_basis_points\":25",
+                "\"peg_in_fee_basis_points\":10001",
+            );
+        assert!(AssetDictionaryEntry::from_json_string(&json_string).is_err());
+    }
+
+    #[test]
+    fn should_add_and_remove_asset_dictionary_entry() {
+        let db = get_test_database();
+        let entry = get_sample_asset_dictionary_entry();
+        AssetDictionary::get_from_db(&db)
+            .and_then(|dictionary| dictionary.add_entry(entry.clone()))
+            .and_then(|dictionary| dictionary.put_in_db(&db))
+            .unwrap();
+        let dictionary = AssetDictionary::get_from_db(&db).unwrap();
+        assert_eq!(dictionary.get_entry("pbtc"), Some(&entry));
+        assert!(dictionary.clone().add_entry(entry).is_err());
+        let result = dictionary.remove_entry("PBTC").unwrap();
+        assert!(result.0.is_empty());
+        assert!(result.remove_entry("PBTC").is_err());
+    }
+}
@@ -0,0 +1,480 @@
+#![cfg(feature="async")]
+use std::{
+    thread,
+    pin::Pin,
+    future::Future,
+    collections::HashMap,
+    panic::{
+        self,
+        AssertUnwindSafe,
+    },
+    task::{
+        Poll,
+        Waker,
+        Context,
+    },
+    sync::{
+        Arc,
+        Mutex,
+        Condvar,
+        MutexGuard,
+    },
+};
+use crate::{
+    errors::AppError,
+    utils::get_not_in_db_err,
+    types::{
+        Bytes,
+        Result,
+        DataSensitivity,
+    },
+    traits::{
+        DatabaseWrite,
+        DatabaseInterface,
+    },
+};
+
+pub type AsyncDbFuture<'a, T> =
+    Pin<Box<dyn Future<Output = Result<T>> + Send + 'a>>;
+
+type FetchedValues = HashMap<Bytes, Option<Bytes>>;
+
+type HeldWrites = HashMap<Bytes, DatabaseWrite>;
+
+pub static FETCH_CHANNEL_LOCK_ERROR: &'static str =
+    "✘ Cannot get lock on async database fetch channel!";
+
+// NOTE: Unlike `DatabaseInterface::get`, a missing key resolves to `None`
+// rather than an error, so the core can tell absent keys from failed reads.
+pub trait AsyncDatabaseInterface: Sync {
+    fn end_transaction(&self) -> AsyncDbFuture<()>;
+
+    fn start_transaction(&self) -> AsyncDbFuture<()>;
+
+    fn delete(&self, key: Bytes) -> AsyncDbFuture<()>;
+
+    fn get(
+        &self,
+        key: Bytes,
+        data_sensitivity: DataSensitivity
+    ) -> AsyncDbFuture<Option<Bytes>>;
+
+    fn put(
+        &self,
+        key: Bytes,
+        value: Bytes,
+        data_sensitivity: DataSensitivity
+    ) -> AsyncDbFuture<()>;
+
+    // NOTE: Hosts able to write atomically should override this.
+    fn write_batch(&self, batch: Vec<DatabaseWrite>) -> AsyncDbFuture<()> {
+        Box::pin(async move {
+            for write in batch {
+                match write {
+                    DatabaseWrite::Delete(key) => self.delete(key).await?,
+                    DatabaseWrite::Put(key, value, data_sensitivity) =>
+                        self.put(key, value, data_sensitivity).await?,
+                }
+            }
+            Ok(())
+        })
+    }
+}
+
+enum WorkerRequest {
+    Fetch(Bytes, DataSensitivity),
+    Commit(Result<String>, HeldWrites),
+}
+
+#[derive(Default)]
+struct Exchange {
+    is_abandoned: bool,
+    waker: Option<Waker>,
+    request: Option<WorkerRequest>,
+    response: Option<Result<Option<Bytes>>>,
+}
+
+// NOTE: Carries requests from the worker thread running the pipeline to the
+// async side, & the fetched values back, one request at a time.
+#[derive(Default)]
+struct FetchChannel {
+    exchange: Mutex<Exchange>,
+    responded: Condvar,
+}
+
+impl FetchChannel {
+    fn lock(&self) -> MutexGuard<Exchange> {
+        self.exchange.lock().expect(FETCH_CHANNEL_LOCK_ERROR)
+    }
+
+    fn send_request(&self, request: WorkerRequest) {
+        let mut exchange = self.lock();
+        exchange.request = Some(request);
+        if let Some(waker) = exchange.waker.take() {
+            waker.wake();
+        }
+    }
+
+    fn fetch(
+        &self,
+        key: Bytes,
+        sensitivity: DataSensitivity,
+    ) -> Result<Option<Bytes>> {
+        self.send_request(WorkerRequest::Fetch(key, sensitivity));
+        let mut exchange = self.lock();
+        loop {
+            if exchange.is_abandoned {
+                return Err(AppError::DatabaseError(
+                    "✘ Async database submission was abandoned!".to_string()
+                ));
+            };
+            if let Some(response) = exchange.response.take() {
+                return response;
+            };
+            exchange = self
+                .responded
+                .wait(exchange)
+                .expect(FETCH_CHANNEL_LOCK_ERROR);
+        }
+    }
+
+    fn respond(&self, response: Result<Option<Bytes>>) {
+        self.lock().response = Some(response);
+        self.responded.notify_one();
+    }
+
+    fn abandon(&self) {
+        self.lock().is_abandoned = true;
+        self.responded.notify_one();
+    }
+}
+
+struct NextRequest<'a>(&'a FetchChannel);
+
+impl<'a> Future for NextRequest<'a> {
+    type Output = WorkerRequest;
+
+    fn poll(
+        self: Pin<&mut Self>,
+        context: &mut Context,
+    ) -> Poll<WorkerRequest> {
+        let mut exchange = self.0.lock();
+        match exchange.request.take() {
+            Some(request) => Poll::Ready(request),
+            None => {
+                exchange.waker = Some(context.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+// NOTE: Stops a worker waiting on a fetch should the future driving it be
+// dropped before it finishes.
+struct AbandonOnDrop(Arc<FetchChannel>);
+
+impl Drop for AbandonOnDrop {
+    fn drop(&mut self) {
+        self.0.abandon();
+    }
+}
+
+// NOTE: Serves the pipelines' reads from values already fetched from the
+// async database, pausing the pipeline whilst any others are fetched, so it
+// only ever runs once. Writes are held back until the pipeline finishes.
+pub struct FetchingDatabase {
+    channel: Arc<FetchChannel>,
+    fetched: Mutex<FetchedValues>,
+    writes: Arc<Mutex<HeldWrites>>,
+}
+
+impl FetchingDatabase {
+    fn lock_writes(&self) -> MutexGuard<HeldWrites> {
+        self.writes.lock().expect(FETCH_CHANNEL_LOCK_ERROR)
+    }
+
+    fn record_write(&self, key: Bytes, write: DatabaseWrite) -> Result<()> {
+        self.lock_writes().insert(key, write);
+        Ok(())
+    }
+
+    fn get_fetched(
+        &self,
+        key: Bytes,
+        sensitivity: DataSensitivity,
+    ) -> Result<Option<Bytes>> {
+        let maybe_fetched = self
+            .fetched
+            .lock()
+            .expect(FETCH_CHANNEL_LOCK_ERROR)
+            .get(&key)
+            .cloned();
+        match maybe_fetched {
+            Some(value) => Ok(value),
+            None => {
+                let value = self.channel.fetch(key.clone(), sensitivity)?;
+                self
+                    .fetched
+                    .lock()
+                    .expect(FETCH_CHANNEL_LOCK_ERROR)
+                    .insert(key, value.clone());
+                Ok(value)
+            }
+        }
+    }
+}
+
+impl DatabaseInterface for FetchingDatabase {
+    fn end_transaction(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn start_transaction(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn delete(&self, key: Bytes) -> Result<()> {
+        self.record_write(key.clone(), DatabaseWrite::Delete(key))
+    }
+
+    fn get(&self, key: Bytes, sensitivity: DataSensitivity) -> Result<Bytes> {
+        let maybe_written = self.lock_writes().get(&key).cloned();
+        match maybe_written {
+            Some(DatabaseWrite::Put(_, value, _)) => Ok(value),
+            Some(DatabaseWrite::Delete(_)) => Err(get_not_in_db_err()),
+            None => self
+                .get_fetched(key, sensitivity)?
+                .ok_or_else(get_not_in_db_err),
+        }
+    }
+
+    fn put(
+        &self,
+        key: Bytes,
+        value: Bytes,
+        sensitivity: DataSensitivity,
+    ) -> Result<()> {
+        self.record_write(
+            key.clone(),
+            DatabaseWrite::Put(key, value, sensitivity),
+        )
+    }
+}
+
+fn spawn_worker<F>(channel: Arc<FetchChannel>, run: F) -> Result<()>
+    where F: FnOnce(FetchingDatabase) -> Result<String> + Send + 'static
+{
+    let writes = Arc::new(Mutex::new(HeldWrites::new()));
+    let fetching_db = FetchingDatabase {
+        channel: channel.clone(),
+        writes: writes.clone(),
+        fetched: Mutex::new(FetchedValues::new()),
+    };
+    thread::Builder::new()
+        .name("async-database-worker".to_string())
+        .spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(||
+                run(fetching_db)
+            ))
+                .unwrap_or_else(|_| Err(AppError::DatabaseError(
+                    "✘ Async database worker panicked!".to_string()
+                )));
+            let writes = std::mem::take(
+                &mut *writes.lock().expect(FETCH_CHANNEL_LOCK_ERROR)
+            );
+            channel.send_request(WorkerRequest::Commit(result, writes));
+        })?;
+    Ok(())
+}
+
+async fn commit_writes<D>(db: &D, writes: HeldWrites) -> Result<()>
+    where D: AsyncDatabaseInterface
+{
+    info!("✔ Committing {} writes to async database...", writes.len());
+    db.start_transaction().await?;
+    db.write_batch(writes.into_iter().map(|(_, write)| write).collect())
+        .await?;
+    db.end_transaction().await
+}
+
+// NOTE: The pipeline runs once on its own thread, blocking that thread (but
+// no async executor's) at each read not yet fetched, rather than being
+// replayed from the start after every miss.
+pub async fn run_with_async_database<D, F>(db: &D, run: F) -> Result<String>
+    where
+        D: AsyncDatabaseInterface,
+        F: FnOnce(FetchingDatabase) -> Result<String> + Send + 'static,
+{
+    let channel = Arc::new(FetchChannel::default());
+    let _abandon_on_drop = AbandonOnDrop(channel.clone());
+    spawn_worker(channel.clone(), run)?;
+    loop {
+        match NextRequest(&channel).await {
+            WorkerRequest::Fetch(key, sensitivity) => {
+                trace!("✔ Fetching key 0x{}...", hex::encode(&key));
+                channel.respond(db.get(key, sensitivity).await)
+            }
+            WorkerRequest::Commit(result, writes) => return match writes
+                .is_empty()
+            {
+                true => result,
+                false => commit_writes(db, writes).await.and(result),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::task::{
+        Poll,
+        Context,
+        RawWaker,
+        RawWakerVTable,
+        Waker,
+    };
+    use crate::{
+        test_utils::{
+            TestDB,
+            get_test_database,
+        },
+    };
+
+    struct AsyncTestDatabase {
+        db: TestDB,
+        gets: Mutex<usize>,
+    }
+
+    impl AsyncDatabaseInterface for AsyncTestDatabase {
+        fn end_transaction(&self) -> AsyncDbFuture<()> {
+            Box::pin(async move { self.db.end_transaction() })
+        }
+
+        fn start_transaction(&self) -> AsyncDbFuture<()> {
+            Box::pin(async move { self.db.start_transaction() })
+        }
+
+        fn delete(&self, key: Bytes) -> AsyncDbFuture<()> {
+            Box::pin(async move { self.db.delete(key) })
+        }
+
+        fn get(
+            &self,
+            key: Bytes,
+            sensitivity: DataSensitivity,
+        ) -> AsyncDbFuture<Option<Bytes>> {
+            Box::pin(async move {
+                *self.gets.lock().unwrap() += 1;
+                Ok(self.db.get(key, sensitivity).ok())
+            })
+        }
+
+        fn put(
+            &self,
+            key: Bytes,
+            value: Bytes,
+            sensitivity: DataSensitivity,
+        ) -> AsyncDbFuture<()> {
+            Box::pin(async move { self.db.put(key, value, sensitivity) })
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        fn clone(_: *const ()) -> RawWaker {
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable =
+            RawWakerVTable::new(clone, noop, noop, noop);
+        let waker = unsafe { Waker::from_raw(clone(std::ptr::null())) };
+        let mut context = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn should_fetch_all_reads_then_commit_writes() {
+        let sensitivity = DataSensitivity::Public;
+        let (key_1, key_2, key_3) = (vec![0x01], vec![0x02], vec![0x03]);
+        let db = AsyncTestDatabase {
+            db: get_test_database(),
+            gets: Mutex::new(0),
+        };
+        db.db.put(key_1.clone(), vec![0xc0], sensitivity).unwrap();
+        let written_key = key_3.clone();
+        let result = block_on(run_with_async_database(&db, move |fetching_db| {
+            let value = fetching_db.get(key_1, sensitivity)?;
+            let default = fetching_db
+                .get(key_2, sensitivity)
+                .unwrap_or_else(|_| vec![0xff]);
+            let concatenated = [value, default].concat();
+            fetching_db.put(written_key, concatenated, sensitivity)?;
+            Ok("done".to_string())
+        }))
+            .unwrap();
+        assert_eq!(result, "done");
+        assert_eq!(*db.gets.lock().unwrap(), 2);
+        assert_eq!(db.db.get(key_3, sensitivity).unwrap(), vec![0xc0, 0xff]);
+    }
+
+    #[test]
+    fn should_err_as_sync_database_would_for_keys_missing_from_db() {
+        let db = AsyncTestDatabase {
+            db: get_test_database(),
+            gets: Mutex::new(0),
+        };
+        let result = block_on(run_with_async_database(&db, |fetching_db| {
+            fetching_db
+                .get(vec![0x01], DataSensitivity::Public)
+                .map(hex::encode)
+        }));
+        match result {
+            Err(AppError::NotInDb) => (),
+            _ => panic!("Missing key should not be found!"),
+        }
+    }
+
+    #[test]
+    fn should_run_pipeline_once_however_many_reads_it_makes() {
+        let num_reads = 100u8;
+        let num_runs = Arc::new(Mutex::new(0));
+        let worker_num_runs = num_runs.clone();
+        let db = AsyncTestDatabase {
+            db: get_test_database(),
+            gets: Mutex::new(0),
+        };
+        let result = block_on(run_with_async_database(&db, move |fetching_db| {
+            *worker_num_runs.lock().unwrap() += 1;
+            for i in 0..num_reads {
+                fetching_db.get(vec![i], DataSensitivity::Public).ok();
+            }
+            fetching_db.get(vec![0x00], DataSensitivity::Public).ok();
+            Ok("done".to_string())
+        }))
+            .unwrap();
+        assert_eq!(result, "done");
+        assert_eq!(*num_runs.lock().unwrap(), 1);
+        assert_eq!(*db.gets.lock().unwrap(), num_reads as usize);
+    }
+
+    #[test]
+    fn should_err_if_worker_panics() {
+        let db = AsyncTestDatabase {
+            db: get_test_database(),
+            gets: Mutex::new(0),
+        };
+        let result = block_on(run_with_async_database(&db, |_| {
+            panic!("Worker should panic!")
+        }));
+        match result {
+            Err(AppError::DatabaseError(e)) =>
+                assert_eq!(e, "✘ Async database worker panicked!"),
+            _ => panic!("Panicking worker should err!"),
+        }
+    }
+}
@@ -0,0 +1,272 @@
+use std::convert::TryFrom;
+use crate::{
+    errors::AppError,
+    types::Result,
+    traits::DatabaseInterface,
+    eth::{
+        eth_state::EthState,
+        eth_database_utils::get_eth_host_token_decimals_from_db,
+    },
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    utxo_manager::utxo_database_utils::get_total_utxo_balance_from_db,
+    token_decimals::{
+        MINTING_ROUNDING_MODE,
+        convert_host_token_amount_to_satoshis,
+    },
+    database_utils::{
+        put_u64_in_db,
+        get_u64_from_db,
+    },
+    constants::{
+        TOTAL_SATOSHIS_MINTED_KEY,
+        TOTAL_SATOSHIS_REDEEMED_KEY,
+    },
+    btc::{
+        btc_state::BtcState,
+        btc_types::MintingParams,
+        sign_transactions::get_minting_params_of_eth_signed_txs,
+        btc_database_utils::{
+            get_btc_canon_block_from_db,
+            get_btc_accrued_peg_in_fees_from_db,
+        },
+    },
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceReconciliationReport {
+    pub is_solvent: bool,
+    pub total_satoshis_minted: u64,
+    pub total_satoshis_redeemed: u64,
+    pub utxo_balance_in_satoshis: u64,
+    pub discrepancy_in_satoshis: i64,
+    pub expected_ptoken_supply_in_satoshis: i64,
+    pub accrued_peg_in_fees_in_satoshis: u64,
+}
+
+fn convert_u64_to_i64(u_64: u64) -> Result<i64> {
+    i64::try_from(u_64).map_err(|_| AppError::ConversionError(format!(
+        "✘ Cannot convert {} to i64 without overflowing!",
+        u_64,
+    )))
+}
+
+fn get_total_from_db<D>(db: &D, key: &[u8]) -> u64
+    where D: DatabaseInterface
+{
+    get_u64_from_db(db, &key.to_vec()).unwrap_or(0)
+}
+
+fn increment_total_in_db<D>(db: &D, key: &[u8], amount: u64) -> Result<()>
+    where D: DatabaseInterface
+{
+    put_u64_in_db(
+        db,
+        &key.to_vec(),
+        &get_total_from_db(db, key).saturating_add(amount),
+    )
+}
+
+// NOTE: Only the mints actually signed are counted, not every minting param in
+// the canon block, since some may be yet to be signed or never be signed.
+fn get_signed_minting_params<D>(
+    state: &BtcState<D>,
+    minting_params: &MintingParams,
+) -> Result<MintingParams>
+    where D: DatabaseInterface
+{
+    let mut signed_minting_params = match state.eth_signed_txs.as_option() {
+        None => vec![],
+        Some(eth_txs) =>
+            get_minting_params_of_eth_signed_txs(
+                &state.db,
+                eth_txs,
+                minting_params,
+            )?.concat(),
+    };
+    if let Some(relay_requests) = state.eth_relay_requests.as_option() {
+        signed_minting_params.extend_from_slice(
+            minting_params
+                .get(..relay_requests.len())
+                .ok_or_else(|| AppError::NotFound(
+                    "✘ Fewer minting params than ETH relay requests!"
+                        .to_string()
+                ))?
+        );
+    };
+    Ok(signed_minting_params)
+}
+
+pub fn maybe_increment_total_satoshis_minted_in_db<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    let has_signed_mints = state.eth_signed_txs.is_filled()
+        || state.eth_relay_requests.is_filled();
+    if !has_signed_mints {
+        info!("✔ No mints signed ∴ not incrementing total satoshis minted!");
+        return Ok(state)
+    };
+    let token_decimals = get_eth_host_token_decimals_from_db(&state.db)?;
+    get_signed_minting_params(
+        &state,
+        &get_btc_canon_block_from_db(&state.db)?.minting_params,
+    )?
+        .iter()
+        .map(|params|
+            convert_host_token_amount_to_satoshis(
+                params.amount,
+                token_decimals,
+                &MINTING_ROUNDING_MODE,
+            )
+        )
+        .collect::<Result<Vec<u64>>>()
+        .and_then(|amounts| {
+            let total = amounts
+                .iter()
+                .fold(0u64, |total, amount| total.saturating_add(*amount));
+            info!("✔ Incrementing total satoshis minted by {}...", total);
+            increment_total_in_db(&state.db, &TOTAL_SATOSHIS_MINTED_KEY, total)
+        })
+        .map(|_| state)
+}
+
+pub fn maybe_increment_total_satoshis_redeemed_in_db<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    if !state.btc_transactions.is_filled() {
+        info!("✔ No payouts signed ∴ not incrementing total satoshis redeemed!");
+        return Ok(state)
+    };
+    let total = state.redeem_params
+        .iter()
+        .map(|params| params.get_gross_amount())
+        .sum::<u64>();
+    info!("✔ Incrementing total satoshis redeemed by {}...", total);
+    increment_total_in_db(&state.db, &TOTAL_SATOSHIS_REDEEMED_KEY, total)
+        .map(|_| state)
+}
+
+pub fn get_balance_reconciliation_report_from_db<D>(
+    db: &D
+) -> Result<BalanceReconciliationReport>
+    where D: DatabaseInterface
+{
+    let total_satoshis_minted =
+        get_total_from_db(db, &TOTAL_SATOSHIS_MINTED_KEY);
+    let total_satoshis_redeemed =
+        get_total_from_db(db, &TOTAL_SATOSHIS_REDEEMED_KEY);
+    let utxo_balance_in_satoshis = get_total_utxo_balance_from_db(db)?;
+    let expected_ptoken_supply_in_satoshis =
+        convert_u64_to_i64(total_satoshis_minted)?
+            - convert_u64_to_i64(total_satoshis_redeemed)?;
+    let discrepancy_in_satoshis = convert_u64_to_i64(utxo_balance_in_satoshis)?
+        .saturating_sub(expected_ptoken_supply_in_satoshis);
+    Ok(
+        BalanceReconciliationReport {
+            total_satoshis_minted,
+            total_satoshis_redeemed,
+            discrepancy_in_satoshis,
+            utxo_balance_in_satoshis,
+            expected_ptoken_supply_in_satoshis,
+            is_solvent: discrepancy_in_satoshis >= 0,
+            accrued_peg_in_fees_in_satoshis:
+                get_btc_accrued_peg_in_fees_from_db(db)?,
+        }
+    )
+}
+
+pub fn get_balance_reconciliation_report<D>(db: D) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting balance reconciliation report...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| get_balance_reconciliation_report_from_db(&db))
+        .and_then(|report| Ok(serde_json::to_string(&report)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        btc::{
+            btc_types::BtcBlockInDbFormat,
+            btc_database_utils::put_btc_canon_block_in_db,
+            btc_test_utils::{
+                get_sample_minting_params,
+                get_sample_btc_block_in_db_format,
+            },
+        },
+        eth::{
+            eth_types::PTokenContractVariant,
+            eth_constants::GAS_LIMIT_FOR_MINTING_TX,
+            eth_crypto::eth_transaction::get_signed_minting_tx,
+            eth_test_utils::{
+                get_sample_eth_address,
+                get_sample_eth_private_key,
+            },
+        },
+    };
+
+    #[test]
+    fn should_only_increment_total_satoshis_minted_by_signed_mints() {
+        let minting_params = get_sample_minting_params();
+        assert!(minting_params.len() > 1);
+        let sample_block = get_sample_btc_block_in_db_format().unwrap();
+        let state = BtcState::init(get_test_database());
+        put_btc_canon_block_in_db(
+            &state.db,
+            &BtcBlockInDbFormat::new(
+                sample_block.height,
+                sample_block.id,
+                minting_params.clone(),
+                sample_block.block,
+                sample_block.extra_data,
+            ).unwrap(),
+        ).unwrap();
+        let signed_tx = get_signed_minting_tx(
+            minting_params[0].amount,
+            0,
+            4,
+            get_sample_eth_address(),
+            20_000_000_000,
+            GAS_LIMIT_FOR_MINTING_TX,
+            minting_params[0].eth_address,
+            get_sample_eth_private_key(),
+            &PTokenContractVariant::Erc777,
+        ).unwrap();
+        let state = state.add_eth_signed_txs(vec![signed_tx])
+            .and_then(maybe_increment_total_satoshis_minted_in_db)
+            .unwrap();
+        let expected_total = convert_host_token_amount_to_satoshis(
+            minting_params[0].amount,
+            get_eth_host_token_decimals_from_db(&state.db).unwrap(),
+            &MINTING_ROUNDING_MODE,
+        ).unwrap();
+        assert_eq!(
+            get_total_from_db(&state.db, &TOTAL_SATOSHIS_MINTED_KEY),
+            expected_total,
+        );
+    }
+
+    #[test]
+    fn should_not_convert_u64_to_i64_if_it_would_overflow() {
+        assert_eq!(convert_u64_to_i64(1337).unwrap(), 1337);
+        assert!(convert_u64_to_i64(u64::MAX).is_err());
+    }
+
+    #[test]
+    fn should_report_discrepancy_if_supply_exceeds_utxo_balance() {
+        let db = get_test_database();
+        increment_total_in_db(&db, &TOTAL_SATOSHIS_MINTED_KEY, 1337).unwrap();
+        increment_total_in_db(&db, &TOTAL_SATOSHIS_REDEEMED_KEY, 337).unwrap();
+        let result = get_balance_reconciliation_report_from_db(&db).unwrap();
+        assert_eq!(result.expected_ptoken_supply_in_satoshis, 1000);
+        assert_eq!(result.utxo_balance_in_satoshis, 0);
+        assert_eq!(result.discrepancy_in_satoshis, -1000);
+        assert!(!result.is_solvent);
+    }
+}
@@ -10,6 +10,8 @@
 // You should have received a copy of the CC0 Public Domain Dedication
 // along with this software.
 // If not, see <http://creativecommons.org/publicdomain/zero/1.0/>.
+// NOTE: Vendored whole from `rust-bitcoin`, so not all of it is used.
+#![allow(dead_code)]
 use std::{error, fmt, str, slice, iter};
 use byteorder::{ByteOrder, LittleEndian};
 use bitcoin_hashes::{sha256d, Hash};
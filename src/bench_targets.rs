@@ -0,0 +1,256 @@
+#![cfg(any(test, feature="benchmarks"))]
+// NOTE: Fixtures & entrypoints for the criterion benchmarks in `benches/`, so
+// the hot paths may be measured without widening the crate's public surface.
+use std::fs::read_to_string;
+use bitcoin_hashes::{
+    Hash,
+    sha256d,
+};
+use bitcoin::{
+    util::address::Address as BtcAddress,
+    network::constants::Network as BtcNetwork,
+};
+use ethereum_types::U256;
+use crate::{
+    types::Result,
+    btc::{
+        btc_crypto::btc_private_key::BtcPrivateKey,
+        btc_transaction::create_signed_raw_btc_tx_for_n_input_n_outputs,
+        filter_p2sh_deposit_txs::filter_p2sh_deposit_txs,
+        get_deposit_info_hash_map::create_hash_map_from_deposit_info_list,
+        btc_utils::create_unsigned_utxo_from_tx,
+        parse_btc_block::{
+            parse_btc_block_string_to_json,
+            parse_btc_block_string_to_json_ref,
+            parse_btc_block_and_tx_json_to_struct,
+            parse_btc_block_and_tx_json_ref_to_struct,
+        },
+        btc_types::{
+            BtcBlockAndId,
+            BtcUtxoAndValue,
+            BtcUtxosAndValues,
+            DepositAddressInfo,
+            DepositInfoHashMap,
+            BtcRecipientAndAmount,
+        },
+    },
+    eth::{
+        eth_constants::PTOKEN_CONTRACT_TOPICS,
+        filter_receipts::filter_eth_block_and_receipts,
+        eth_crypto::{
+            eth_private_key::EthPrivateKey,
+            eth_transaction::get_signed_minting_tx,
+        },
+        eth_types::{
+            EthAddress,
+            EthBlockAndReceipts,
+            PTokenContractVariant,
+        },
+        parse_eth_block_and_receipts::{
+            parse_eth_block_and_receipts_json,
+            parse_eth_block_and_receipts_json_ref,
+            parse_eth_block_and_receipts_json_string,
+            parse_eth_block_and_receipts_json_str_to_ref,
+        },
+    },
+};
+
+pub const BENCH_BTC_BLOCK_JSON_PATH: &str =
+    "src/btc/btc_test_utils/604700-btc-block-and-txs.json";
+
+pub const BENCH_ETH_BLOCK_AND_RECEIPTS_JSON_PATH: &str =
+    "src/eth/eth_test_utils/eth-7004586-ropsten-eth-block-and-receipts.json";
+
+const BENCH_NUM_UTXOS: usize = 50;
+const BENCH_MIN_UTXO_VALUE: u64 = 10_000;
+const BENCH_PRIVATE_KEY_SLICE: [u8; 32] = [0x2a; 32];
+const BENCH_ROPSTEN_CONTRACT_ADDRESS_HEX: &str =
+    "1ee4d5f444d0ab291d748049231dc9331b2f04c8";
+
+// NOTE: Lists every `p2sh` output of the block as a deposit address, none of
+// them locked to the core's key, so each is checked against its redeem script:
+// the worst case for the deposit filter.
+fn get_worst_case_deposit_info(
+    btc_block_and_id: &BtcBlockAndId,
+) -> Result<DepositInfoHashMap> {
+    create_hash_map_from_deposit_info_list(
+        &btc_block_and_id
+            .block
+            .txdata
+            .iter()
+            .flat_map(|tx| tx.output.iter())
+            .filter(|tx_out| tx_out.script_pubkey.is_p2sh())
+            .filter_map(|tx_out|
+                BtcAddress::from_script(
+                    &tx_out.script_pubkey,
+                    BtcNetwork::Bitcoin,
+                )
+            )
+            .enumerate()
+            .map(|(nonce, btc_deposit_address)| DepositAddressInfo {
+                nonce: nonce as u64,
+                btc_deposit_address,
+                eth_address: EthAddress::zero(),
+                eth_address_and_nonce_hash: sha256d::Hash::hash(
+                    &nonce.to_le_bytes()
+                ),
+            })
+            .collect()
+    )
+}
+
+fn get_utxos_from_block(btc_block_and_id: &BtcBlockAndId) -> BtcUtxosAndValues {
+    btc_block_and_id
+        .block
+        .txdata
+        .iter()
+        .skip(1)
+        .filter(|tx| tx.output[0].value >= BENCH_MIN_UTXO_VALUE)
+        .take(BENCH_NUM_UTXOS)
+        .map(|tx|
+            BtcUtxoAndValue::new(
+                tx.output[0].value,
+                &create_unsigned_utxo_from_tx(tx, 0),
+                None,
+                None,
+            )
+        )
+        .collect()
+}
+
+pub struct BenchFixtures {
+    btc_submission: String,
+    eth_submission: String,
+    btc_private_key: BtcPrivateKey,
+    eth_private_key: EthPrivateKey,
+    btc_block_and_id: BtcBlockAndId,
+    btc_deposit_info: DepositInfoHashMap,
+    btc_utxos_and_values: BtcUtxosAndValues,
+    eth_block_and_receipts: EthBlockAndReceipts,
+}
+
+impl BenchFixtures {
+    pub fn load() -> Result<Self> {
+        let btc_submission = read_to_string(BENCH_BTC_BLOCK_JSON_PATH)?;
+        let eth_submission = read_to_string(
+            BENCH_ETH_BLOCK_AND_RECEIPTS_JSON_PATH
+        )?;
+        let btc_block_and_id = parse_btc_block_string_to_json(&btc_submission)
+            .and_then(parse_btc_block_and_tx_json_to_struct)?;
+        let eth_block_and_receipts = parse_eth_block_and_receipts_json_string(
+            &eth_submission
+        )
+            .and_then(parse_eth_block_and_receipts_json)?;
+        Ok(
+            BenchFixtures {
+                btc_private_key: BtcPrivateKey::from_slice(
+                    &BENCH_PRIVATE_KEY_SLICE,
+                    BtcNetwork::Bitcoin,
+                )?,
+                eth_private_key: EthPrivateKey::from_slice(
+                    BENCH_PRIVATE_KEY_SLICE
+                )?,
+                btc_deposit_info: get_worst_case_deposit_info(
+                    &btc_block_and_id
+                )?,
+                btc_utxos_and_values: get_utxos_from_block(&btc_block_and_id),
+                btc_submission,
+                eth_submission,
+                btc_block_and_id,
+                eth_block_and_receipts,
+            }
+        )
+    }
+
+    pub fn parse_btc_submission(&self) -> Result<()> {
+        parse_btc_block_string_to_json(&self.btc_submission)
+            .and_then(parse_btc_block_and_tx_json_to_struct)
+            .map(|_| ())
+    }
+
+    pub fn parse_eth_submission(&self) -> Result<()> {
+        parse_eth_block_and_receipts_json_string(&self.eth_submission)
+            .and_then(parse_eth_block_and_receipts_json)
+            .map(|_| ())
+    }
+
+    pub fn parse_borrowed_btc_submission(&self) -> Result<()> {
+        parse_btc_block_string_to_json_ref(&self.btc_submission)
+            .and_then(|json| parse_btc_block_and_tx_json_ref_to_struct(&json))
+            .map(|_| ())
+    }
+
+    pub fn parse_borrowed_eth_submission(&self) -> Result<()> {
+        parse_eth_block_and_receipts_json_str_to_ref(&self.eth_submission)
+            .and_then(|json| parse_eth_block_and_receipts_json_ref(&json))
+            .map(|_| ())
+    }
+
+    pub fn filter_p2sh_deposit_txs(&self) -> Result<usize> {
+        filter_p2sh_deposit_txs(
+            &self.btc_deposit_info,
+            &self.btc_private_key.to_public_key_slice(),
+            &self.btc_block_and_id.block.txdata,
+            &BtcNetwork::Bitcoin,
+        )
+            .map(|txs| txs.len())
+    }
+
+    pub fn filter_receipts(&self) -> Result<usize> {
+        filter_eth_block_and_receipts(
+            &self.eth_block_and_receipts,
+            &EthAddress::from_slice(
+                &hex::decode(BENCH_ROPSTEN_CONTRACT_ADDRESS_HEX)?
+            ),
+            &PTOKEN_CONTRACT_TOPICS.to_vec(),
+        )
+            .map(|block_and_receipts| block_and_receipts.receipts.len())
+    }
+
+    pub fn sign_btc_tx(&self) -> Result<()> {
+        let address = self.btc_private_key.to_p2pkh_btc_address();
+        create_signed_raw_btc_tx_for_n_input_n_outputs(
+            1,
+            vec![BtcRecipientAndAmount::new(&address, BENCH_MIN_UTXO_VALUE)?],
+            &address,
+            self.btc_private_key.clone(),
+            self.btc_utxos_and_values.clone(),
+        )
+            .map(|_| ())
+    }
+
+    pub fn sign_eth_mint_tx(&self) -> Result<()> {
+        get_signed_minting_tx(
+            U256::from(BENCH_MIN_UTXO_VALUE),
+            0,
+            1,
+            EthAddress::zero(),
+            20_000_000_000,
+            200_000,
+            EthAddress::zero(),
+            self.eth_private_key.clone(),
+            &PTokenContractVariant::Erc777,
+        )
+            .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_run_every_bench_target_over_fixtures() {
+        let fixtures = BenchFixtures::load().unwrap();
+        assert_eq!(fixtures.btc_utxos_and_values.len(), BENCH_NUM_UTXOS);
+        assert!(!fixtures.btc_deposit_info.is_empty());
+        assert!(fixtures.parse_btc_submission().is_ok());
+        assert!(fixtures.parse_eth_submission().is_ok());
+        assert!(fixtures.parse_borrowed_btc_submission().is_ok());
+        assert!(fixtures.parse_borrowed_eth_submission().is_ok());
+        assert_eq!(fixtures.filter_p2sh_deposit_txs().unwrap(), 0);
+        assert!(fixtures.filter_receipts().is_ok());
+        assert!(fixtures.sign_btc_tx().is_ok());
+        assert!(fixtures.sign_eth_mint_tx().is_ok());
+    }
+}
@@ -17,10 +17,12 @@ pub fn maybe_add_btc_block_to_db<D>(
     where D: DatabaseInterface
 {
     info!("✔ Checking if BTC block is already in the db...");
-    match btc_block_exists_in_db(&state.db, &state.get_btc_block_and_id()?.id) {
-        true => Err(AppError::Custom(
-            format!("✘ BTC Block Rejected - it's already in the db!")
-        )),
+    let block_hash = state.get_btc_block_and_id()?.id;
+    match btc_block_exists_in_db(&state.db, &block_hash) {
+        true => Err(AppError::BlockAlreadyInDb {
+            chain: "BTC".to_string(),
+            block_hash: block_hash.to_string(),
+        }),
         false => {
             let block = state.get_btc_block_in_db_format()?;
             info!("✔ BTC block not in db!");
@@ -5,6 +5,11 @@ pub const PTOKEN_P2SH_SCRIPT_BYTES: usize = 101;
 pub const PTOKEN_P2SH_SCRIPT_BYTES: usize = 0;
 
 pub const BTC_TAIL_LENGTH: u64 = 10;
+pub const MAX_PEG_IN_FEE_BASIS_POINTS: u64 = 10_000;
+pub const MAX_PEG_OUT_FEE_BASIS_POINTS: u64 = 10_000;
+pub const BTC_DUST_LIMIT_IN_SATOSHIS: u64 = 546;
+// NOTE: Block id (32 bytes) + height (8 bytes) + consensus header (80 bytes)!
+pub const BTC_BLOCK_HEADER_IN_DB_FORMAT_LENGTH: usize = 120;
 // NOTE: Following is used as placeholder for bad address parsing in ETH params!
 pub const DEFAULT_BTC_ADDRESS: &'static str =
     "msTgHeQgPZ11LRcUdtfzagEfiZyKF57DhR";
@@ -140,3 +145,64 @@ pub static BTC_TAIL_BLOCK_HASH_KEY: [u8; 32] = [
   113, 70, 137, 49, 39, 61, 15, 108,
   22, 176, 156, 155, 190, 49, 111, 113
 ];
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('btc-peg-in-fee-basis-points-key').slice(2), 'hex')
+// )
+// d9afd11f5e36fbfb5aa029373ad25600ba9a953d9409e27c8c6f00fdb71726e6
+pub static BTC_PEG_IN_FEE_BASIS_POINTS_KEY: [u8; 32] = [
+  217, 175, 209, 31, 94, 54, 251, 251,
+  90, 160, 41, 55, 58, 210, 86, 0,
+  186, 154, 149, 61, 148, 9, 226, 124,
+  140, 111, 0, 253, 183, 23, 38, 230
+];
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('btc-accrued-peg-in-fees-key').slice(2), 'hex')
+// )
+// ba8731afe1293386b0f139da80e82a35988515977b42f8d50045e2fcaab6b085
+pub static BTC_ACCRUED_PEG_IN_FEES_KEY: [u8; 32] = [
+  186, 135, 49, 175, 225, 41, 51, 134,
+  176, 241, 57, 218, 128, 232, 42, 53,
+  152, 133, 21, 151, 123, 66, 248, 213,
+  0, 69, 226, 252, 170, 182, 176, 133
+];
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('btc-peg-out-fee-basis-points-key').slice(2), 'hex')
+// )
+// 9ec9e2d56191434b1eafa089e33ce8d5ab3ac3e98458702d640791ab51c1e1f3
+pub static BTC_PEG_OUT_FEE_BASIS_POINTS_KEY: [u8; 32] = [
+  158, 201, 226, 213, 97, 145, 67, 75,
+  30, 175, 160, 137, 227, 60, 232, 213,
+  171, 58, 195, 233, 132, 88, 112, 45,
+  100, 7, 145, 171, 81, 193, 225, 243
+];
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('btc-accrued-peg-out-fees-key').slice(2), 'hex')
+// )
+// 5acb4c10b270f4d2d09a75344cbe5e3966b23b7ea199d8f97229a01515a15efe
+pub static BTC_ACCRUED_PEG_OUT_FEES_KEY: [u8; 32] = [
+  90, 203, 76, 16, 178, 112, 244, 210,
+  208, 154, 117, 52, 76, 190, 94, 57,
+  102, 178, 59, 126, 161, 153, 216, 249,
+  114, 41, 160, 21, 21, 161, 94, 254
+];
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('btc-mint-gas-recapture-rate-key').slice(2), 'hex')
+// )
+// 57eeac00a71655e8662a991206a6d77127acce10a630271453aefc72cfd26b7c
+pub static BTC_MINT_GAS_RECAPTURE_RATE_KEY: [u8; 32] = [
+  87, 238, 172, 0, 167, 22, 85, 232,
+  102, 42, 153, 18, 6, 166, 215, 113,
+  39, 172, 206, 16, 166, 48, 39, 20,
+  83, 174, 252, 114, 207, 210, 107, 124
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('btc-queued-minting-params-key').slice(2), 'hex')
+// )
+// ca6138bfdfafe68c8250f3c957bdfb779879c131bb170a6fa28e485c11d37bd0
+pub static BTC_QUEUED_MINTING_PARAMS_KEY: [u8; 32] = [
+  202, 97, 56, 191, 223, 175, 230, 140,
+  130, 80, 243, 201, 87, 189, 251, 119,
+  152, 121, 193, 49, 187, 23, 10, 111,
+  162, 142, 72, 92, 17, 211, 123, 208
+];
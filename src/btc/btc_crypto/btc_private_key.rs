@@ -18,10 +18,10 @@ use secp256k1::{
 use crate::{
     traits::DatabaseInterface,
     crypto_utils::generate_random_private_key,
-    constants::PRIVATE_KEY_DATA_SENSITIVITY_LEVEL,
     types::{
         Bytes,
         Result,
+        DataSensitivity,
     },
 };
 
@@ -113,7 +113,7 @@ impl BtcPrivateKey {
         db.put(
             key.to_vec(),
             self.0[..].to_vec(),
-            PRIVATE_KEY_DATA_SENSITIVITY_LEVEL,
+            DataSensitivity::Private,
         )
     }
 }
@@ -124,13 +124,16 @@ impl fmt::Display for BtcPrivateKey {
     }
 }
 
+// NOTE: A throwaway key, written over the real one when it's dropped.
+const DUMMY_BTC_PRIVATE_KEY_WIF: &str =
+    "cP2Dv4mx1DwJzN8iF6CCyPZmuS27bT9MV4Qmgb9h6cNQNq2Jgpmy";
+
 impl Drop for BtcPrivateKey {
     fn drop(&mut self) {
-        use crate::btc::btc_test_utils::SAMPLE_BTC_PRIVATE_KEY_WIF;
         unsafe {
             ::std::ptr::write_volatile(
                 &mut self.0,
-                PrivateKey::from_wif(SAMPLE_BTC_PRIVATE_KEY_WIF)
+                PrivateKey::from_wif(DUMMY_BTC_PRIVATE_KEY_WIF)
                     .expect("Failed to get BTC private key!"),
             )
         };
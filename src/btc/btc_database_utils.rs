@@ -6,22 +6,32 @@ use bitcoin_hashes::{
 use crate::{
     errors::AppError,
     traits::DatabaseInterface,
+    db_codec::get_db_serialization_format_from_db,
     types::{
         Bytes,
         Result,
         DataSensitivity,
     },
+    db_keys::{
+        DbKeyPrefix,
+        get_btc_block_header_db_key,
+    },
     database_utils::{
         put_u64_in_db,
         get_u64_from_db,
+        put_namespaced_key_in_db,
+        delete_namespaced_key_from_db,
     },
     utils::{
         convert_bytes_to_u64,
         convert_u64_to_bytes,
     },
     btc::{
-        btc_state::BtcState,
-        btc_types::BtcBlockInDbFormat,
+        btc_types::{
+            MintingParams,
+            BtcBlockInDbFormat,
+            BtcBlockHeaderInDbFormat,
+        },
         btc_crypto::btc_private_key::BtcPrivateKey,
         btc_constants::{
             BTC_FEE_KEY,
@@ -36,46 +46,30 @@ use crate::{
             BTC_ANCHOR_BLOCK_HASH_KEY,
             BTC_LATEST_BLOCK_HASH_KEY,
             BTC_CANON_TO_TIP_LENGTH_KEY,
+            BTC_ACCRUED_PEG_IN_FEES_KEY,
+            MAX_PEG_IN_FEE_BASIS_POINTS,
+            BTC_PEG_IN_FEE_BASIS_POINTS_KEY,
+            BTC_QUEUED_MINTING_PARAMS_KEY,
+            BTC_ACCRUED_PEG_OUT_FEES_KEY,
+            MAX_PEG_OUT_FEE_BASIS_POINTS,
+            BTC_PEG_OUT_FEE_BASIS_POINTS_KEY,
+            BTC_MINT_GAS_RECAPTURE_RATE_KEY,
         },
         btc_utils::{
             convert_btc_network_to_bytes,
             convert_bytes_to_btc_network,
             convert_bytes_to_btc_address,
             convert_btc_address_to_bytes,
+            serialize_minting_params,
+            deserialize_minting_params,
             serialize_btc_block_in_db_format,
             deserialize_btc_block_in_db_format,
+            serialize_btc_block_header_in_db_format,
+            deserialize_btc_block_header_in_db_format,
         },
     },
 };
 
-pub fn start_btc_db_transaction<D>(
-    state: BtcState<D>,
-) -> Result<BtcState<D>>
-    where D: DatabaseInterface
-{
-    state
-        .db
-        .start_transaction()
-        .map(|_| {
-            info!("✔ Database transaction begun for BTC block submission!");
-            state
-        })
-}
-
-pub fn end_btc_db_transaction<D>(
-    state: BtcState<D>,
-) -> Result<BtcState<D>>
-    where D: DatabaseInterface
-{
-    state
-        .db
-        .end_transaction()
-        .map(|_| {
-            info!("✔ Database transaction ended for BTC block submission!");
-            state
-        })
-}
-
 pub fn get_btc_account_nonce_from_db<D>(
     db: &D
 ) -> Result<u64>
@@ -112,7 +106,7 @@ pub fn get_btc_fee_from_db<D>(db: &D) -> Result<u64>
     where D: DatabaseInterface
 {
     trace!("✔ Getting BTC fee from db...");
-    db.get(BTC_FEE_KEY.to_vec(), None)
+    db.get(BTC_FEE_KEY.to_vec(), DataSensitivity::Public)
         .and_then(|bytes| convert_bytes_to_u64(&bytes))
 }
 
@@ -120,13 +114,209 @@ pub fn put_btc_fee_in_db<D>(db: &D, fee: &u64) -> Result<()>
     where D: DatabaseInterface
 {
     trace!("✔ Adding BTC fee of '{}' satoshis-per-byte to db...", fee);
-    db.put(BTC_FEE_KEY.to_vec(), convert_u64_to_bytes(fee), None)
+    db.put(
+        BTC_FEE_KEY.to_vec(),
+        convert_u64_to_bytes(fee),
+        DataSensitivity::Public,
+    )
+}
+
+pub fn put_btc_peg_in_fee_basis_points_in_db<D>(
+    db: &D,
+    basis_points: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting BTC peg-in fee of {} basis points in db...", basis_points);
+    match *basis_points <= MAX_PEG_IN_FEE_BASIS_POINTS {
+        true => put_u64_in_db(
+            db,
+            &BTC_PEG_IN_FEE_BASIS_POINTS_KEY.to_vec(),
+            basis_points,
+        ),
+        false => Err(AppError::InvalidAmount(
+            format!(
+                "✘ BTC peg-in fee cannot exceed {} basis points!",
+                MAX_PEG_IN_FEE_BASIS_POINTS,
+            )
+        )),
+    }
+}
+
+pub fn get_btc_peg_in_fee_basis_points_from_db<D>(db: &D) -> Result<u64>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting BTC peg-in fee basis points from db...");
+    match get_u64_from_db(db, &BTC_PEG_IN_FEE_BASIS_POINTS_KEY.to_vec()) {
+        Ok(basis_points) => Ok(basis_points),
+        Err(_) => {
+            trace!("✔ No BTC peg-in fee in db ∴ defaulting to zero!");
+            Ok(0)
+        }
+    }
+}
+
+pub fn get_btc_accrued_peg_in_fees_from_db<D>(db: &D) -> Result<u64>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting BTC accrued peg-in fees from db...");
+    match get_u64_from_db(db, &BTC_ACCRUED_PEG_IN_FEES_KEY.to_vec()) {
+        Ok(accrued_fees) => Ok(accrued_fees),
+        Err(_) => {
+            trace!("✔ No BTC accrued peg-in fees in db ∴ defaulting to zero!");
+            Ok(0)
+        }
+    }
+}
+
+pub fn increment_btc_accrued_peg_in_fees_in_db<D>(
+    db: &D,
+    amount_to_increment_by: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Incrementing BTC accrued peg-in fees in db...");
+    get_btc_accrued_peg_in_fees_from_db(db)
+        .and_then(|accrued_fees|
+            put_u64_in_db(
+                db,
+                &BTC_ACCRUED_PEG_IN_FEES_KEY.to_vec(),
+                &(accrued_fees + amount_to_increment_by),
+            )
+        )
+}
+
+pub fn put_btc_peg_out_fee_basis_points_in_db<D>(
+    db: &D,
+    basis_points: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting BTC peg-out fee of {} basis points in db...", basis_points);
+    match *basis_points <= MAX_PEG_OUT_FEE_BASIS_POINTS {
+        true => put_u64_in_db(
+            db,
+            &BTC_PEG_OUT_FEE_BASIS_POINTS_KEY.to_vec(),
+            basis_points,
+        ),
+        false => Err(AppError::InvalidAmount(
+            format!(
+                "✘ BTC peg-out fee cannot exceed {} basis points!",
+                MAX_PEG_OUT_FEE_BASIS_POINTS,
+            )
+        )),
+    }
+}
+
+pub fn get_btc_peg_out_fee_basis_points_from_db<D>(db: &D) -> Result<u64>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting BTC peg-out fee basis points from db...");
+    match get_u64_from_db(db, &BTC_PEG_OUT_FEE_BASIS_POINTS_KEY.to_vec()) {
+        Ok(basis_points) => Ok(basis_points),
+        Err(_) => {
+            trace!("✔ No BTC peg-out fee in db ∴ defaulting to zero!");
+            Ok(0)
+        }
+    }
+}
+
+pub fn get_btc_accrued_peg_out_fees_from_db<D>(db: &D) -> Result<u64>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting BTC accrued peg-out fees from db...");
+    match get_u64_from_db(db, &BTC_ACCRUED_PEG_OUT_FEES_KEY.to_vec()) {
+        Ok(accrued_fees) => Ok(accrued_fees),
+        Err(_) => {
+            trace!("✔ No BTC accrued peg-out fees in db ∴ defaulting to zero!");
+            Ok(0)
+        }
+    }
+}
+
+pub fn increment_btc_accrued_peg_out_fees_in_db<D>(
+    db: &D,
+    amount_to_increment_by: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Incrementing BTC accrued peg-out fees in db...");
+    get_btc_accrued_peg_out_fees_from_db(db)
+        .and_then(|accrued_fees|
+            put_u64_in_db(
+                db,
+                &BTC_ACCRUED_PEG_OUT_FEES_KEY.to_vec(),
+                &(accrued_fees + amount_to_increment_by),
+            )
+        )
+}
+
+pub fn put_btc_mint_gas_recapture_rate_in_db<D>(
+    db: &D,
+    satoshis_per_eth: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!(
+        "✔ Putting BTC mint gas recapture rate of {} sats per ETH in db...",
+        satoshis_per_eth,
+    );
+    put_u64_in_db(
+        db,
+        &BTC_MINT_GAS_RECAPTURE_RATE_KEY.to_vec(),
+        satoshis_per_eth,
+    )
+}
+
+pub fn get_btc_mint_gas_recapture_rate_from_db<D>(db: &D) -> Result<u64>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting BTC mint gas recapture rate from db...");
+    match get_u64_from_db(db, &BTC_MINT_GAS_RECAPTURE_RATE_KEY.to_vec()) {
+        Ok(satoshis_per_eth) => Ok(satoshis_per_eth),
+        Err(_) => {
+            trace!("✔ No mint gas recapture rate in db ∴ defaulting to zero!");
+            Ok(0)
+        }
+    }
+}
+
+pub fn get_btc_queued_minting_params_from_db<D>(
+    db: &D,
+) -> Result<MintingParams>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting BTC queued minting params from db...");
+    match db.get(
+        BTC_QUEUED_MINTING_PARAMS_KEY.to_vec(),
+        DataSensitivity::Public,
+    ) {
+        Err(_) => Ok(vec![]),
+        Ok(bytes) => deserialize_minting_params(bytes),
+    }
+}
+
+pub fn put_btc_queued_minting_params_in_db<D>(
+    db: &D,
+    minting_params: &MintingParams,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting BTC queued minting params in db...");
+    db.put(
+        BTC_QUEUED_MINTING_PARAMS_KEY.to_vec(),
+        serialize_minting_params(
+            minting_params,
+            get_db_serialization_format_from_db(db)?,
+        )?,
+        DataSensitivity::Public,
+    )
 }
 
 pub fn get_btc_network_from_db<D>(db: &D) -> Result<BtcNetwork>
     where D: DatabaseInterface
 {
-    db.get(BTC_NETWORK_KEY.to_vec(), None)
+    db.get(BTC_NETWORK_KEY.to_vec(), DataSensitivity::Public)
         .and_then(|bytes| convert_bytes_to_btc_network(&bytes))
 }
 
@@ -137,7 +327,7 @@ pub fn put_btc_network_in_db<D>(db: &D, network: &BtcNetwork) -> Result<()>
     db.put(
         BTC_NETWORK_KEY.to_vec(),
         convert_btc_network_to_bytes(network)?,
-        None,
+        DataSensitivity::Public,
     )
 }
 
@@ -148,7 +338,7 @@ pub fn put_btc_difficulty_in_db<D>(db: &D, difficulty: &u64) -> Result<()>
     db.put(
         BTC_DIFFICULTY_THRESHOLD.to_vec(),
         convert_u64_to_bytes(difficulty),
-        None,
+        DataSensitivity::Public,
     )
 }
 
@@ -156,7 +346,7 @@ pub fn get_btc_difficulty_from_db<D>(db: &D) -> Result<u64>
     where D: DatabaseInterface
 {
     trace!("✔ Getting BTC difficulty threshold from db...");
-    db.get(BTC_DIFFICULTY_THRESHOLD.to_vec(), None)
+    db.get(BTC_DIFFICULTY_THRESHOLD.to_vec(), DataSensitivity::Public)
         .and_then(|bytes| convert_bytes_to_u64(&bytes))
 }
 
@@ -164,8 +354,8 @@ pub fn get_btc_latest_block_number<D>(db: &D) -> Result<u64>
     where D: DatabaseInterface
 {
     trace!("✔ Getting BTC latest block number from db...");
-    get_btc_latest_block_from_db(db)
-        .map(|block_and_id| block_and_id.height)
+    get_special_btc_block_header_from_db(db, "latest")
+        .map(|header_in_db_format| header_in_db_format.height)
 }
 
 pub fn get_special_btc_block_from_db<D>(
@@ -178,6 +368,16 @@ pub fn get_special_btc_block_from_db<D>(
         .and_then(|block_hash| get_btc_block_from_db(db, &block_hash))
 }
 
+pub fn get_special_btc_block_header_from_db<D>(
+    db: &D,
+    block_type: &str
+) -> Result<BtcBlockHeaderInDbFormat>
+    where D: DatabaseInterface
+{
+    get_special_hash_from_db(db, block_type)
+        .and_then(|block_hash| get_btc_block_header_from_db(db, &block_hash))
+}
+
 pub fn get_special_hash_from_db<D>(
     db: &D,
     hash_type: &str,
@@ -189,7 +389,7 @@ pub fn get_special_hash_from_db<D>(
         "canon" => Ok(BTC_CANON_BLOCK_HASH_KEY),
         "anchor" => Ok(BTC_ANCHOR_BLOCK_HASH_KEY),
         "latest" => Ok(BTC_LATEST_BLOCK_HASH_KEY),
-        _ => Err(AppError::Custom(
+        _ => Err(AppError::InvalidInput(
             format!("✘ Cannot get special BTC hash of type: {}!", hash_type)
         ))
     }?;
@@ -223,7 +423,7 @@ pub fn put_special_btc_hash_in_db<D>(
         "canon" => Ok(BTC_CANON_BLOCK_HASH_KEY.to_vec()),
         "anchor" => Ok(BTC_ANCHOR_BLOCK_HASH_KEY.to_vec()),
         "latest" => Ok(BTC_LATEST_BLOCK_HASH_KEY.to_vec()),
-        _ => Err(AppError::Custom(
+        _ => Err(AppError::InvalidInput(
             format!("✘ Cannot store special BTC hash of type: {}!", hash_type)
         ))
     }?;
@@ -237,7 +437,7 @@ pub fn btc_block_exists_in_db<D>(db: &D, btc_block_id: &sha256d::Hash) -> bool
         "✔ Checking for existence of BTC block: {}",
        hex::encode(btc_block_id.to_vec())
    );
-    key_exists_in_db(db, &btc_block_id.to_vec(), None)
+    key_exists_in_db(db, &btc_block_id.to_vec(), DataSensitivity::Public)
 }
 
 pub fn key_exists_in_db<D>(
@@ -263,14 +463,14 @@ pub fn put_btc_canon_to_tip_length_in_db<D>(
     db.put(
         BTC_CANON_TO_TIP_LENGTH_KEY.to_vec(),
         convert_u64_to_bytes(btc_canon_to_tip_length),
-        None,
+        DataSensitivity::Public,
     )
 }
 
 pub fn get_btc_canon_to_tip_length_from_db<D>(db: &D) -> Result<u64>
     where D: DatabaseInterface
 {
-    db.get(BTC_CANON_TO_TIP_LENGTH_KEY.to_vec(), None)
+    db.get(BTC_CANON_TO_TIP_LENGTH_KEY.to_vec(), DataSensitivity::Public)
         .and_then(|bytes| convert_bytes_to_u64(&bytes))
 }
 
@@ -284,12 +484,13 @@ pub fn put_btc_private_key_in_db<D>(db: &D, pk: &BtcPrivateKey) -> Result<()>
 pub fn get_btc_private_key_from_db<D>(db: &D) -> Result<BtcPrivateKey>
     where D: DatabaseInterface
 {
-    db.get(BTC_PRIVATE_KEY_DB_KEY.to_vec(), Some(255))
+    db.get(BTC_PRIVATE_KEY_DB_KEY.to_vec(), DataSensitivity::Private)
         .and_then(|bytes|
             BtcPrivateKey::from_slice(&bytes[..], get_btc_network_from_db(db)?)
         )
 }
 
+#[cfg(test)]
 pub fn put_btc_anchor_block_in_db<D>(
     db: &D,
     block: &BtcBlockInDbFormat,
@@ -300,6 +501,7 @@ pub fn put_btc_anchor_block_in_db<D>(
     put_special_btc_block_in_db(db, block, "anchor")
 }
 
+#[cfg(test)]
 pub fn put_btc_tail_block_in_db<D>(
     db: &D,
     block: &BtcBlockInDbFormat
@@ -320,6 +522,7 @@ pub fn put_btc_canon_block_in_db<D>(
     put_special_btc_block_in_db(db, block, "canon")
 }
 
+#[cfg(test)]
 pub fn put_btc_latest_block_in_db<D>(
     db: &D,
     block: &BtcBlockInDbFormat,
@@ -330,6 +533,7 @@ pub fn put_btc_latest_block_in_db<D>(
     put_special_btc_block_in_db(db, block, "latest")
 }
 
+#[allow(dead_code)]
 pub fn get_btc_anchor_block_from_db<D>(db: &D) -> Result<BtcBlockInDbFormat>
     where D: DatabaseInterface
 {
@@ -337,6 +541,7 @@ pub fn get_btc_anchor_block_from_db<D>(db: &D) -> Result<BtcBlockInDbFormat>
     get_special_btc_block_from_db(db, "anchor")
 }
 
+#[allow(dead_code)]
 pub fn get_btc_latest_block_from_db<D>(db: &D) -> Result<BtcBlockInDbFormat>
     where D: DatabaseInterface
 {
@@ -399,6 +604,13 @@ pub fn get_btc_canon_block_hash_from_db<D>(db: &D) -> Result<sha256d::Hash>
     get_btc_hash_from_db(db, &BTC_CANON_BLOCK_HASH_KEY.to_vec())
 }
 
+pub fn get_btc_tail_block_hash_from_db<D>(db: &D) -> Result<sha256d::Hash>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting BTC tail block hash from db...");
+    get_btc_hash_from_db(db, &BTC_TAIL_BLOCK_HASH_KEY.to_vec())
+}
+
 pub fn put_btc_tail_block_hash_in_db<D>(
     db: &D,
     hash: &sha256d::Hash
@@ -440,16 +652,17 @@ pub fn put_btc_hash_in_db<D>(
 ) -> Result<()>
     where D: DatabaseInterface
 {
-    db.put(key.to_vec(), hash.to_vec(), None)
+    db.put(key.to_vec(), hash.to_vec(), DataSensitivity::Public)
 }
 
 pub fn get_btc_hash_from_db<D>(db: &D, key: &Bytes) -> Result<sha256d::Hash>
     where D: DatabaseInterface
 {
-    db.get(key.to_vec(), None)
+    db.get(key.to_vec(), DataSensitivity::Public)
         .and_then(|bytes| Ok(sha256d::Hash::from_slice(&bytes)?))
 }
 
+#[cfg(test)]
 pub fn maybe_get_parent_btc_block_and_id<D>(
     db: &D,
     id: &sha256d::Hash,
@@ -488,6 +701,44 @@ pub fn maybe_get_nth_ancestor_btc_block_and_id<D>(
     }
 }
 
+pub fn maybe_get_parent_btc_block_header<D>(
+    db: &D,
+    id: &sha256d::Hash,
+) -> Option<BtcBlockHeaderInDbFormat>
+    where D: DatabaseInterface
+{
+    trace!("✔ Maybe getting BTC parent block header for id: {}", id);
+    maybe_get_nth_ancestor_btc_block_header(db, id, &1)
+}
+
+pub fn maybe_get_nth_ancestor_btc_block_header<D>(
+    db: &D,
+    id: &sha256d::Hash,
+    n: &u64,
+) -> Option<BtcBlockHeaderInDbFormat>
+    where D: DatabaseInterface
+{
+    trace!(
+        "✔ Maybe getting ancestor #{} header of BTC block id: {}",
+        n,
+        hex::encode(id.to_vec()),
+    );
+    match get_btc_block_header_from_db(db, id) {
+        Err(_) => {
+            trace!("✘ No ancestor #{} BTC block header found!", n);
+            None
+        }
+        Ok(header_in_db_format) => match n {
+            0 => Some(header_in_db_format),
+            _ => maybe_get_nth_ancestor_btc_block_header(
+                db,
+                &header_in_db_format.header.prev_blockhash,
+                &(n - 1),
+            )
+        }
+    }
+}
+
 pub fn put_btc_address_in_db<D>(db: &D, btc_address: &String) -> Result<()>
     where D: DatabaseInterface
 {
@@ -495,7 +746,7 @@ pub fn put_btc_address_in_db<D>(db: &D, btc_address: &String) -> Result<()>
     db.put(
         BTC_ADDRESS_KEY.to_vec(),
         convert_btc_address_to_bytes(btc_address)?,
-        None,
+        DataSensitivity::Public,
     )
 }
 
@@ -503,7 +754,7 @@ pub fn get_btc_address_from_db<D>(db: &D) -> Result<String>
     where D: DatabaseInterface
 {
     trace!("✔  Getting BTC address from db...");
-    db.get(BTC_ADDRESS_KEY.to_vec(), None)
+    db.get(BTC_ADDRESS_KEY.to_vec(), DataSensitivity::Public)
         .map(convert_bytes_to_btc_address)
 }
 
@@ -513,12 +764,74 @@ pub fn put_btc_block_in_db<D>(
 ) -> Result<()>
     where D: DatabaseInterface
 {
-    debug!(
+    sensitive_debug!(
         "✔ Putting BTC block in db: {:?}",
         btc_block_in_db_format,
     );
-    serialize_btc_block_in_db_format(btc_block_in_db_format)
-        .and_then(|(id, block)| db.put(id, block, None))
+    get_db_serialization_format_from_db(db)
+        .and_then(|format|
+            serialize_btc_block_in_db_format(btc_block_in_db_format, format)
+        )
+        .and_then(|(id, block)| {
+            db.put(id.clone(), block, DataSensitivity::Public)?;
+            put_btc_block_header_in_db(
+                db,
+                &BtcBlockHeaderInDbFormat::from_btc_block_in_db_format(
+                    btc_block_in_db_format
+                ),
+            )?;
+            put_namespaced_key_in_db(db, &DbKeyPrefix::BtcBlock, &id)
+        })
+}
+
+fn put_btc_block_header_in_db<D>(
+    db: &D,
+    btc_block_header_in_db_format: &BtcBlockHeaderInDbFormat,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting BTC block header in db...");
+    db.put(
+        get_btc_block_header_db_key(&btc_block_header_in_db_format.id),
+        serialize_btc_block_header_in_db_format(btc_block_header_in_db_format),
+        DataSensitivity::Public,
+    )
+}
+
+pub fn get_btc_block_header_from_db<D>(
+    db: &D,
+    id: &sha256d::Hash
+) -> Result<BtcBlockHeaderInDbFormat>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting BTC block header from db via id: {}", id);
+    match db.get(get_btc_block_header_db_key(id), DataSensitivity::Public) {
+        Ok(bytes) => deserialize_btc_block_header_in_db_format(&bytes),
+        Err(_) => {
+            trace!("✔ No BTC block header stored ∴ getting full block...");
+            get_btc_block_from_db(db, id)
+                .map(|block_in_db_format|
+                    BtcBlockHeaderInDbFormat::from_btc_block_in_db_format(
+                        &block_in_db_format
+                    )
+                )
+        }
+    }
+}
+
+pub fn delete_btc_block_from_db<D>(db: &D, id: &sha256d::Hash) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Deleting BTC block from db via id: {}", id);
+    db.delete(id.to_vec())
+        .and_then(|_| db.delete(get_btc_block_header_db_key(id)))
+        .and_then(|_|
+            delete_namespaced_key_from_db(
+                db,
+                &DbKeyPrefix::BtcBlock,
+                &id.to_vec(),
+            )
+        )
 }
 
 pub fn maybe_get_btc_block_from_db<D>(
@@ -547,7 +860,7 @@ pub fn get_btc_block_from_db<D>(
     where D: DatabaseInterface
 {
     trace!("✔ Getting BTC block from db via id: {}", hex::encode(id.to_vec()));
-    db.get(id.to_vec(), None)
+    db.get(id.to_vec(), DataSensitivity::Public)
         .and_then(|bytes| deserialize_btc_block_in_db_format(&bytes))
 }
 
@@ -564,13 +877,41 @@ mod tests {
         },
     };
 
+    #[test]
+    fn should_put_and_get_btc_peg_in_fee_basis_points_in_db() {
+        let db = get_test_database();
+        let basis_points = 25;
+        assert_eq!(get_btc_peg_in_fee_basis_points_from_db(&db).unwrap(), 0);
+        put_btc_peg_in_fee_basis_points_in_db(&db, &basis_points).unwrap();
+        let result = get_btc_peg_in_fee_basis_points_from_db(&db).unwrap();
+        assert_eq!(result, basis_points);
+    }
+
+    #[test]
+    fn should_not_put_btc_peg_in_fee_above_max_basis_points_in_db() {
+        let db = get_test_database();
+        let basis_points = MAX_PEG_IN_FEE_BASIS_POINTS + 1;
+        assert!(
+            put_btc_peg_in_fee_basis_points_in_db(&db, &basis_points).is_err()
+        );
+    }
+
+    #[test]
+    fn should_increment_btc_accrued_peg_in_fees_in_db() {
+        let db = get_test_database();
+        assert_eq!(get_btc_accrued_peg_in_fees_from_db(&db).unwrap(), 0);
+        increment_btc_accrued_peg_in_fees_in_db(&db, &1337).unwrap();
+        increment_btc_accrued_peg_in_fees_in_db(&db, &1).unwrap();
+        assert_eq!(get_btc_accrued_peg_in_fees_from_db(&db).unwrap(), 1338);
+    }
+
     #[test]
     fn non_existing_key_should_not_exist_in_db() {
         let db = get_test_database();
         let result = key_exists_in_db(
             &db,
             &BTC_CANON_TO_TIP_LENGTH_KEY.to_vec(),
-            None,
+            DataSensitivity::Public,
         );
         assert!(!result);
     }
@@ -585,7 +926,7 @@ mod tests {
         let result = key_exists_in_db(
             &db,
             &BTC_CANON_TO_TIP_LENGTH_KEY.to_vec(),
-            None,
+            DataSensitivity::Public,
         );
         assert!(result);
     }
@@ -644,7 +985,7 @@ mod tests {
             Ok(_) => {
                 panic!("Should not have succeeded!");
             }
-            Err(AppError::Custom(e)) => {
+            Err(AppError::InvalidInput(e)) => {
                 assert!(e == expected_error);
             }
             Err(_) => {
@@ -684,7 +1025,7 @@ mod tests {
             Ok(_) => {
                 panic!("Should not have got special block!");
             }
-            Err(AppError::Custom(e)) => {
+            Err(AppError::InvalidInput(e)) => {
                 assert!(e == expected_error);
             }
             Err(_) => {
@@ -879,6 +1220,55 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_get_btc_block_header_from_db() {
+        let db = get_test_database();
+        let block = get_sample_btc_block_in_db_format()
+            .unwrap();
+        let expected_result =
+            BtcBlockHeaderInDbFormat::from_btc_block_in_db_format(&block);
+        put_btc_block_in_db(&db, &block).unwrap();
+        let result = get_btc_block_header_from_db(&db, &block.id).unwrap();
+        assert_eq!(result, expected_result);
+        db.delete(get_btc_block_header_db_key(&block.id)).unwrap();
+        let result = get_btc_block_header_from_db(&db, &block.id).unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_get_nth_ancestor_btc_block_header() {
+        let db = get_test_database();
+        let blocks = get_sample_sequential_btc_blocks_in_db_format();
+        let test_block = blocks[blocks.len() - 1]
+            .clone();
+        let expected_result =
+            BtcBlockHeaderInDbFormat::from_btc_block_in_db_format(
+                &blocks[blocks.len() - 3]
+            );
+        blocks
+            .iter()
+            .map(|block| put_btc_block_in_db(&db, &block))
+            .collect::<Result<()>>()
+            .unwrap();
+        let result = maybe_get_nth_ancestor_btc_block_header(
+            &db,
+            &test_block.id,
+            &2,
+        ).unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_delete_btc_block_from_db() {
+        let db = get_test_database();
+        let block = get_sample_btc_block_in_db_format()
+            .unwrap();
+        put_btc_block_in_db(&db, &block).unwrap();
+        delete_btc_block_from_db(&db, &block.id).unwrap();
+        assert!(!btc_block_exists_in_db(&db, &block.id));
+        assert!(get_btc_block_header_from_db(&db, &block.id).is_err());
+    }
+
     #[test]
     fn should_get_and_put_btc_address_in_database() {
         let db = get_test_database();
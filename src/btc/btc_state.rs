@@ -1,8 +1,13 @@
 use crate::{
     types::Result,
-    errors::AppError,
+    state_slot::StateSlot,
+    skipped_items::SkippedItems,
     traits::DatabaseInterface,
-    eth::eth_types::EthTransactions,
+    stage_timing::StageTimings,
+    eth::{
+        eth_types::EthTransactions,
+        eth_crypto::eth_relay_request::EthRelayRequests,
+    },
     btc::btc_types::{
         BtcBlockAndId,
         MintingParams,
@@ -11,39 +16,47 @@ use crate::{
         BtcBlockInDbFormat,
         DepositInfoHashMap,
     },
-    utils::{
-        get_not_in_state_err,
-        get_no_overwrite_state_err,
-    },
 };
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct BtcState<D: DatabaseInterface> {
     pub db: D,
+    pub dry_run: bool,
+    pub stage_timings: StageTimings,
+    pub skipped_items: SkippedItems,
     pub minting_params: MintingParams,
-    pub output_json_string: Option<String>,
+    pub host_timestamp: StateSlot<u64>,
+    pub host_eth_gas_price: StateSlot<u64>,
     pub utxos_and_values: BtcUtxosAndValues,
-    pub btc_block_and_id: Option<BtcBlockAndId>,
-    pub eth_signed_txs: Option<EthTransactions>,
-    pub p2sh_deposit_txs: Option<BtcTransactions>,
-    pub op_return_deposit_txs: Option<BtcTransactions>,
-    pub deposit_info_hash_map: Option<DepositInfoHashMap>,
-    pub btc_block_in_db_format: Option<BtcBlockInDbFormat>,
+    pub output_json_string: StateSlot<String>,
+    pub btc_block_and_id: StateSlot<BtcBlockAndId>,
+    pub eth_signed_txs: StateSlot<EthTransactions>,
+    pub p2sh_deposit_txs: StateSlot<BtcTransactions>,
+    pub eth_relay_requests: StateSlot<EthRelayRequests>,
+    pub op_return_deposit_txs: StateSlot<BtcTransactions>,
+    pub deposit_info_hash_map: StateSlot<DepositInfoHashMap>,
+    pub btc_block_in_db_format: StateSlot<BtcBlockInDbFormat>,
 }
 
 impl<D> BtcState<D> where D: DatabaseInterface {
     pub fn init(db: D) -> BtcState<D> {
         BtcState {
             db,
-            eth_signed_txs: None,
-            btc_block_and_id: None,
-            p2sh_deposit_txs: None,
-            output_json_string: None,
+            dry_run: false,
+            stage_timings: Vec::new(),
+            skipped_items: Vec::new(),
             minting_params: Vec::new(),
-            op_return_deposit_txs: None,
-            deposit_info_hash_map: None,
-            btc_block_in_db_format: None,
             utxos_and_values: Vec::new(),
+            host_timestamp: StateSlot::empty("host_timestamp"),
+            host_eth_gas_price: StateSlot::empty("host_eth_gas_price"),
+            eth_signed_txs: StateSlot::empty("eth_signed_txs"),
+            btc_block_and_id: StateSlot::empty("btc_block_and_id"),
+            p2sh_deposit_txs: StateSlot::empty("p2sh_deposit_txs"),
+            eth_relay_requests: StateSlot::empty("eth_relay_requests"),
+            output_json_string: StateSlot::empty("output_json_string"),
+            op_return_deposit_txs: StateSlot::empty("op_return_deposit_txs"),
+            deposit_info_hash_map: StateSlot::empty("deposit_info_hash_map"),
+            btc_block_in_db_format: StateSlot::empty("btc_block_in_db_format"),
         }
     }
 
@@ -51,96 +64,90 @@ impl<D> BtcState<D> where D: DatabaseInterface {
         mut self,
         btc_block_and_id: BtcBlockAndId,
     ) -> Result<BtcState<D>> {
-        match self.btc_block_and_id {
-            Some(_) => Err(AppError::Custom(
-                get_no_overwrite_state_err("btc_block_and_id"))
-            ),
-            None => {
-                info!("✔ Adding BTC block and ID to BTC state...");
-                self.btc_block_and_id = Some(btc_block_and_id);
-                Ok(self)
-            }
-        }
+        info!("✔ Adding BTC block and ID to BTC state...");
+        self.btc_block_and_id = self.btc_block_and_id.fill(btc_block_and_id)?;
+        Ok(self)
+    }
+
+    pub fn add_skipped_items(
+        mut self,
+        mut skipped_items: SkippedItems,
+    ) -> Result<BtcState<D>> {
+        info!("✔ Adding {} skipped items to BTC state...", skipped_items.len());
+        self.skipped_items.append(&mut skipped_items);
+        Ok(self)
+    }
+
+    pub fn add_host_timestamp(
+        mut self,
+        host_timestamp: u64,
+    ) -> Result<BtcState<D>> {
+        info!("✔ Adding host timestamp to BTC state...");
+        self.host_timestamp = self.host_timestamp.fill(host_timestamp)?;
+        Ok(self)
+    }
+
+    pub fn add_host_eth_gas_price(
+        mut self,
+        host_eth_gas_price: u64,
+    ) -> Result<BtcState<D>> {
+        info!("✔ Adding host ETH gas price to BTC state...");
+        self.host_eth_gas_price =
+            self.host_eth_gas_price.fill(host_eth_gas_price)?;
+        Ok(self)
     }
 
     pub fn add_p2sh_deposit_txs(
         mut self,
         p2sh_deposit_txs: BtcTransactions,
     ) -> Result<BtcState<D>> {
-        match self.p2sh_deposit_txs {
-            Some(_) => Err(AppError::Custom(
-                get_no_overwrite_state_err("p2sh_deposit_txs"))
-            ),
-            None => {
-                info!("✔ Adding `p2sh` deposit txs to BTC state...");
-                self.p2sh_deposit_txs = Some(p2sh_deposit_txs);
-                Ok(self)
-            }
-        }
+        info!("✔ Adding `p2sh` deposit txs to BTC state...");
+        self.p2sh_deposit_txs = self.p2sh_deposit_txs.fill(p2sh_deposit_txs)?;
+        Ok(self)
     }
 
     pub fn add_output_json_string(
         mut self,
         output_json_string: String,
     ) -> Result<BtcState<D>> {
-        match self.output_json_string {
-            Some(_) => Err(AppError::Custom(
-                get_no_overwrite_state_err("output_json_string"))
-            ),
-            None => {
-                info!("✔ Adding BTC output JSON to BTC state...");
-                self.output_json_string = Some(output_json_string);
-                Ok(self)
-            }
-        }
+        info!("✔ Adding BTC output JSON to BTC state...");
+        self.output_json_string = self
+            .output_json_string
+            .fill(output_json_string)?;
+        Ok(self)
     }
 
     pub fn add_btc_block_in_db_format(
         mut self,
         btc_block_in_db_format: BtcBlockInDbFormat,
     ) -> Result<BtcState<D>> {
-        match self.btc_block_in_db_format {
-            Some(_) => Err(AppError::Custom(
-                get_no_overwrite_state_err("btc_block_in_db_format"))
-            ),
-            None => {
-                info!("✔ Adding BTC block in DB format to BTC state...");
-                self.btc_block_in_db_format = Some(btc_block_in_db_format);
-                Ok(self)
-            }
-        }
+        info!("✔ Adding BTC block in DB format to BTC state...");
+        self.btc_block_in_db_format = self
+            .btc_block_in_db_format
+            .fill(btc_block_in_db_format)?;
+        Ok(self)
     }
 
     pub fn add_op_return_deposit_txs(
         mut self,
         op_return_deposit_txs: BtcTransactions,
     ) -> Result<BtcState<D>> {
-        match self.op_return_deposit_txs {
-            Some(_) => Err(AppError::Custom(
-                get_no_overwrite_state_err("op_return_deposit_txs"))
-            ),
-            None => {
-                info!("✔ Adding `op_return` deposit txs to BTC state...");
-                self.op_return_deposit_txs = Some(op_return_deposit_txs);
-                Ok(self)
-            }
-        }
+        info!("✔ Adding `op_return` deposit txs to BTC state...");
+        self.op_return_deposit_txs = self
+            .op_return_deposit_txs
+            .fill(op_return_deposit_txs)?;
+        Ok(self)
     }
 
     pub fn add_deposit_info_hash_map(
         mut self,
         deposit_info_hash_map: DepositInfoHashMap,
     ) -> Result<BtcState<D>> {
-        match self.deposit_info_hash_map {
-            Some(_) => Err(AppError::Custom(
-                get_no_overwrite_state_err("deposit_info_hash_map"))
-            ),
-            None => {
-                info!("✔ Adding deposit info hash map to BTC state...");
-                self.deposit_info_hash_map = Some(deposit_info_hash_map);
-                Ok(self)
-            }
-        }
+        info!("✔ Adding deposit info hash map to BTC state...");
+        self.deposit_info_hash_map = self
+            .deposit_info_hash_map
+            .fill(deposit_info_hash_map)?;
+        Ok(self)
     }
 
     pub fn add_minting_params(
@@ -175,63 +182,51 @@ impl<D> BtcState<D> where D: DatabaseInterface {
         mut self,
         eth_signed_txs: EthTransactions,
     ) -> Result<BtcState<D>> {
-        match self.eth_signed_txs {
-            Some(_) => Err(AppError::Custom(
-                get_no_overwrite_state_err("eth_signed_txs"))
-            ),
-            None => {
-                info!("✔ Adding ETH signed txs to BTC state...");
-                self.eth_signed_txs = Some(eth_signed_txs);
-                Ok(self)
-            }
-        }
+        info!("✔ Adding ETH signed txs to BTC state...");
+        self.eth_signed_txs = self.eth_signed_txs.fill(eth_signed_txs)?;
+        Ok(self)
     }
 
-    pub fn add_utxos_and_values(
+    pub fn add_eth_relay_requests(
         mut self,
-        mut utxos_and_values: BtcUtxosAndValues,
+        eth_relay_requests: EthRelayRequests,
     ) -> Result<BtcState<D>> {
-        info!("✔ Adding UTXOs & values to BTC state...");
-        self.utxos_and_values
-            .append(&mut utxos_and_values);
+        info!("✔ Adding ETH relay requests to BTC state...");
+        self.eth_relay_requests = self
+            .eth_relay_requests
+            .fill(eth_relay_requests)?;
         Ok(self)
     }
 
-    pub fn update_btc_block_and_id(
+    pub fn add_utxos_and_values(
         mut self,
-        new_btc_block_and_id: BtcBlockAndId
+        mut utxos_and_values: BtcUtxosAndValues,
     ) -> Result<BtcState<D>> {
-        info!("✔ Updating BTC block & ID in BTC state...");
-        self.btc_block_and_id = Some(new_btc_block_and_id);
+        info!("✔ Adding UTXOs & values to BTC state...");
+        self.utxos_and_values
+            .append(&mut utxos_and_values);
         Ok(self)
     }
 
     pub fn get_btc_block_and_id(
         &self
     ) -> Result<&BtcBlockAndId> {
-        match &self.btc_block_and_id {
-            Some(btc_block_and_id) => {
-                info!("✔ Getting BTC block & ID from BTC state...");
-                Ok(&btc_block_and_id)
-            }
-            None => Err(AppError::Custom(
-                get_not_in_state_err("btc_block_and_id"))
-            )
-        }
+        info!("✔ Getting BTC block & ID from BTC state...");
+        self.btc_block_and_id.get()
+    }
+
+    pub fn get_eth_relay_requests(
+        &self
+    ) -> Result<&EthRelayRequests> {
+        info!("✔ Getting ETH relay requests from BTC state...");
+        self.eth_relay_requests.get()
     }
 
     pub fn get_eth_signed_txs(
         &self
     ) -> Result<&EthTransactions> {
-        match &self.eth_signed_txs {
-            Some(eth_signed_txs) => {
-                info!("✔ Getting ETH signed txs from BTC state...");
-                Ok(&eth_signed_txs)
-            }
-            None => Err(AppError::Custom(
-                get_not_in_state_err("eth_signed_txs"))
-            )
-        }
+        info!("✔ Getting ETH signed txs from BTC state...");
+        self.eth_signed_txs.get()
     }
 
     pub fn get_minting_params(
@@ -243,87 +238,53 @@ impl<D> BtcState<D> where D: DatabaseInterface {
     pub fn get_deposit_info_hash_map(
         &self
     ) -> Result<&DepositInfoHashMap> {
-        match &self.deposit_info_hash_map {
-            Some(deposit_info_hash_map) => {
-                info!("✔ Getting deposit info hash map from BTC state...");
-                Ok(&deposit_info_hash_map)
-            }
-            None => Err(AppError::Custom(
-                get_not_in_state_err("deposit_info_hash_map"))
-            )
-        }
+        info!("✔ Getting deposit info hash map from BTC state...");
+        self.deposit_info_hash_map.get()
     }
 
     pub fn get_op_return_deposit_txs(
         &self
     ) -> Result<&BtcTransactions> {
-        match &self.op_return_deposit_txs {
-            Some(op_return_deposit_txs) => {
-                info!("✔ Getting `op_return` deposit txs from BTC state...");
-                Ok(&op_return_deposit_txs)
-            }
-            None => Err(AppError::Custom(
-                get_not_in_state_err("op_return_deposit_txs"))
-            )
-        }
+        info!("✔ Getting `op_return` deposit txs from BTC state...");
+        self.op_return_deposit_txs.get()
     }
 
     pub fn get_p2sh_deposit_txs(
         &self
     ) -> Result<&BtcTransactions> {
-        match &self.p2sh_deposit_txs {
-            Some(p2sh_deposit_txs) => {
-                info!("✔ Getting `p2sh` deposit txs from BTC state...");
-                Ok(&p2sh_deposit_txs)
-            }
-            None => Err(AppError::Custom(
-                get_not_in_state_err("p2sh_deposit_txs"))
-            )
-        }
+        info!("✔ Getting `p2sh` deposit txs from BTC state...");
+        self.p2sh_deposit_txs.get()
     }
 
     pub fn get_btc_block_in_db_format(
         &self
     ) -> Result<&BtcBlockInDbFormat> {
-        match &self.btc_block_in_db_format {
-            Some(btc_block_in_db_format) => {
-                info!("✔ Getting BTC block in DB format from BTC state...");
-                Ok(&btc_block_in_db_format)
-            }
-            None => Err(AppError::Custom(
-                get_not_in_state_err("btc_block_in_db_format"))
-            )
-        }
+        info!("✔ Getting BTC block in DB format from BTC state...");
+        self.btc_block_in_db_format.get()
     }
 
     pub fn get_output_json_string(
         &self
     ) -> Result<&String> {
-        match &self.output_json_string {
-            Some(output_json_string) => {
-                info!("✔ Getting BTC output json string from state...");
-                Ok(&output_json_string)
-            }
-            None => Err(AppError::Custom(
-                get_not_in_state_err("output_json_string"))
-            )
-        }
+        info!("✔ Getting BTC output json string from state...");
+        self.output_json_string.get()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::{
-        get_test_database,
+    use crate::{
+        errors::AppError,
+        test_utils::get_test_database,
     };
 
     #[test]
     fn should_fail_to_get_btc_block_and_receipts_in_state() {
-        let expected_error = get_not_in_state_err("btc_block_and_id");
         let initial_state = BtcState::init(get_test_database());
         match initial_state.get_btc_block_and_id() {
-            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            Err(AppError::NotInState(name)) =>
+                assert_eq!(name, "btc_block_and_id"),
             Ok(_) => panic!("Block should not be in state yet!"),
             Err(_) => panic!("Wrong error received!")
         };
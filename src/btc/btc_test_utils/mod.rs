@@ -130,18 +130,24 @@ pub fn get_sample_minting_params() -> MintingParams {
         eth_address: eth_address_1,
         originating_tx_hash: originating_tx_hash_1,
         originating_tx_address: originating_tx_address_1,
+        user_data: vec![],
+        correlation_id: String::new(),
     };
     let minting_params_2 = MintingParamStruct {
         amount: amount_2,
         eth_address: eth_address_2,
         originating_tx_hash: originating_tx_hash_2,
         originating_tx_address: originating_tx_address_2,
+        user_data: vec![],
+        correlation_id: String::new(),
     };
     let minting_params_3 = MintingParamStruct {
         amount: amount_3,
         eth_address: eth_address_3,
         originating_tx_hash: originating_tx_hash_3,
         originating_tx_address: originating_tx_address_3,
+        user_data: vec![],
+        correlation_id: String::new(),
     };
     vec![minting_params_1, minting_params_2, minting_params_3]
 }
@@ -35,6 +35,9 @@ use crate::{
     },
 };
 
+#[cfg(feature="script-verification")]
+use crate::btc::verify_btc_tx_scripts::verify_btc_tx_scripts;
+
 // NOTE: Current tx constants. Could make generic in future if needed.
 pub const VERSION: u32 = 1;
 pub const LOCK_TIME: u32 = 0;
@@ -64,9 +67,10 @@ pub fn create_signed_raw_btc_tx_for_n_input_n_outputs(
     info!("✔ Tx fee:         {}", fee);
     match total_to_spend + fee > utxo_total {
         true => return Err(
-            AppError::Custom(
-                "✘ Not enough UTXO value to make transaction!".to_string()
-            )
+            AppError::InsufficientUtxoValue {
+                utxo_total,
+                total_required: total_to_spend + fee,
+            }
         ),
         _ => {
             let mut outputs = recipient_addresses_and_amounts
@@ -183,14 +187,19 @@ pub fn create_signed_raw_btc_tx_for_n_input_n_outputs(
                     )
                 })
                 .collect::<Result<Vec<BtcUtxo>>>()?;
-            Ok(
-                BtcTransaction {
-                    output: tx.output,
-                    version: tx.version,
-                    lock_time: tx.lock_time,
-                    input: utxos_with_signatures,
-                }
-            )
+            let signed_tx = BtcTransaction {
+                output: tx.output,
+                version: tx.version,
+                lock_time: tx.lock_time,
+                input: utxos_with_signatures,
+            };
+            #[cfg(feature="script-verification")]
+            verify_btc_tx_scripts(
+                &signed_tx,
+                &utxos_and_values,
+                &btc_private_key.to_public_key_slice(),
+            )?;
+            Ok(signed_tx)
         }
     }
 }
@@ -3,11 +3,14 @@ use ethereum_types::{
     Address as EthAddress
 };
 use std::{
+    borrow::Cow,
     str::FromStr,
     collections::HashMap,
 };
 use crate::{
-    constants::SAFE_BTC_ADDRESS,
+    errors::AppError,
+    cow_json::deserialize_cow_strs,
+    correlation_id::get_correlation_id,
     utils::{
         strip_hex_prefix,
         convert_hex_to_address,
@@ -25,12 +28,16 @@ use crate::{
 };
 use bitcoin::{
     util::address::Address as BtcAddress,
+    consensus::encode::serialize as btc_serialize,
     hashes::{
         Hash,
         sha256d,
     },
     blockdata::{
-        block::Block as BtcBlock,
+        block::{
+            Block as BtcBlock,
+            BlockHeader as BtcBlockHeader,
+        },
         transaction::{
             TxIn as BtcUtxo,
             Transaction as BtcTransaction,
@@ -38,8 +45,6 @@ use bitcoin::{
     },
 };
 
-pub type BtcUtxos = Vec<BtcUtxo>;
-pub type BtcSignature = [u8; 65];
 pub type BtcTransactions = Vec<BtcTransaction>;
 pub type MintingParams = Vec<MintingParamStruct>;
 pub type BtcUtxosAndValues = Vec<BtcUtxoAndValue>;
@@ -59,23 +64,23 @@ impl BtcRecipientAndAmount {
         Ok(
             BtcRecipientAndAmount {
                 amount,
-                recipient: match BtcAddress::from_str(recipient) {
-                    Ok(address) => address,
-                    Err(error) => {
-                        info!(
-                            "✔ Error parsing BTC address for recipient: {}",
-                            error
-                        );
-                        info!(
-                            "✔ Defaulting to SAFE BTC address: {}",
-                            SAFE_BTC_ADDRESS,
-                        );
-                        BtcAddress::from_str(SAFE_BTC_ADDRESS)?
-                    }
-                }
+                recipient: BtcAddress::from_str(recipient)?,
             }
         )
     }
+
+    pub fn new_or_safe(
+        recipient: &str,
+        amount: u64,
+        safe_btc_address: &str,
+    ) -> Result<Self> {
+        BtcRecipientAndAmount::new(recipient, amount)
+            .or_else(|error| {
+                info!("✔ Error parsing BTC address for recipient: {}", error);
+                info!("✔ Defaulting to SAFE BTC address: {}", safe_btc_address);
+                BtcRecipientAndAmount::new(safe_btc_address, amount)
+            })
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -99,12 +104,35 @@ impl BtcBlockInDbFormat {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BtcBlockHeaderInDbFormat {
+    pub height: u64,
+    pub id: sha256d::Hash,
+    pub header: BtcBlockHeader,
+}
+
+impl BtcBlockHeaderInDbFormat {
+    pub fn from_btc_block_in_db_format(
+        btc_block_in_db_format: &BtcBlockInDbFormat,
+    ) -> Self {
+        BtcBlockHeaderInDbFormat {
+            id: btc_block_in_db_format.id,
+            height: btc_block_in_db_format.height,
+            header: btc_block_in_db_format.block.header,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct MintingParamStruct {
     pub amount: U256,
     pub eth_address: EthAddress,
     pub originating_tx_hash: sha256d::Hash,
     pub originating_tx_address: String,
+    #[serde(default)]
+    pub user_data: Bytes,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub correlation_id: String,
 }
 
 impl MintingParamStruct {
@@ -119,18 +147,91 @@ impl MintingParamStruct {
             eth_address,
             originating_tx_hash,
             originating_tx_address: originating_tx_address.to_string(),
+            user_data: vec![],
+            correlation_id: String::new(),
         }
     }
+
+    pub fn with_user_data(self, user_data: Bytes) -> Self {
+        MintingParamStruct { user_data, ..self }
+    }
+
+    pub fn with_correlation_id(self, vout: usize) -> Self {
+        MintingParamStruct {
+            correlation_id: get_correlation_id(
+                "BTC",
+                &self.originating_tx_hash.to_string(),
+                vout,
+            ),
+            ..self
+        }
+    }
+
+    pub fn get_mint_user_data(&self) -> Bytes {
+        let mut originating_tx_hash_bytes = self.originating_tx_hash
+            .into_inner()
+            .to_vec();
+        originating_tx_hash_bytes.reverse();
+        [originating_tx_hash_bytes, self.user_data.clone()].concat()
+    }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BtcBlockAndTxsJson {
     pub block: BtcBlockJson,
+    pub host_timestamp: Option<u64>,
+    #[serde(default)]
+    pub host_eth_gas_price: Option<u64>,
     pub transactions: Vec<String>,
     pub deposit_address_list: DepositAddressJsonList,
 }
 
+// NOTE: Borrows its strings from the submission rather than copying them,
+// save for any containing JSON escapes, which are unescaped into owned copies.
+#[derive(Clone, Debug, Deserialize)]
+pub struct BtcBlockAndTxsJsonRef<'a> {
+    #[serde(borrow)]
+    pub block: BtcBlockJsonRef<'a>,
+    pub host_timestamp: Option<u64>,
+    #[serde(default)]
+    pub host_eth_gas_price: Option<u64>,
+    #[serde(borrow, deserialize_with = "deserialize_cow_strs")]
+    pub transactions: Vec<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub deposit_address_list: Vec<DepositAddressInfoJsonRef<'a>>,
+}
+
 #[derive(Clone, Debug, Deserialize)]
+pub struct BitcoindSubmissionJson {
+    pub block: BitcoindBlockJson,
+    #[serde(default)]
+    pub host_timestamp: Option<u64>,
+    #[serde(default)]
+    pub host_eth_gas_price: Option<u64>,
+    #[serde(default)]
+    pub deposit_address_list: DepositAddressJsonList,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct BitcoindBlockJson {
+    pub hash: String,
+    pub height: u64,
+    pub version: i32,
+    pub merkleroot: String,
+    pub time: u32,
+    pub nonce: u32,
+    pub bits: String,
+    #[serde(default)]
+    pub previousblockhash: Option<String>,
+    pub tx: Vec<BitcoindTxJson>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct BitcoindTxJson {
+    pub hex: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BtcBlockJson {
     pub bits: u32,
     pub id: String,
@@ -142,6 +243,21 @@ pub struct BtcBlockJson {
     pub previousblockhash: String,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct BtcBlockJsonRef<'a> {
+    pub bits: u32,
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    pub nonce: u32,
+    pub version: u32,
+    pub height: u64,
+    pub timestamp: u32,
+    #[serde(borrow)]
+    pub merkle_root: Cow<'a, str>,
+    #[serde(borrow)]
+    pub previousblockhash: Cow<'a, str>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DepositAddressInfo {
     pub nonce: u64,
@@ -150,12 +266,19 @@ pub struct DepositAddressInfo {
     pub eth_address_and_nonce_hash: sha256d::Hash,
 }
 
+fn parse_deposit_info_hash(hash_hex: &str) -> Result<sha256d::Hash> {
+    strip_hex_prefix(hash_hex)
+        .and_then(|stripped_hex| Ok(hex::decode(stripped_hex)?))
+        .and_then(|bytes| Ok(sha256d::Hash::from_slice(&bytes)?))
+        .map_err(|_| AppError::InvalidDepositInfoHash(hash_hex.to_string()))
+}
+
 impl DepositAddressInfo {
     pub fn new(
         nonce: &u64,
-        eth_address: &String,
-        btc_deposit_address: &String,
-        eth_address_and_nonce_hash: &String,
+        eth_address: &str,
+        btc_deposit_address: &str,
+        eth_address_and_nonce_hash: &str,
     ) -> Result<Self> {
         Ok(
             DepositAddressInfo {
@@ -163,9 +286,9 @@ impl DepositAddressInfo {
                 eth_address: convert_hex_to_address(
                     strip_hex_prefix(eth_address)?
                 )?,
-                btc_deposit_address: BtcAddress::from_str(&btc_deposit_address)?,
-                eth_address_and_nonce_hash: sha256d::Hash::from_slice(
-                    &hex::decode(strip_hex_prefix(eth_address_and_nonce_hash)?)?
+                btc_deposit_address: BtcAddress::from_str(btc_deposit_address)?,
+                eth_address_and_nonce_hash: parse_deposit_info_hash(
+                    eth_address_and_nonce_hash
                 )?,
             }
         )
@@ -180,6 +303,17 @@ pub struct DepositAddressInfoJson {
     pub eth_address_and_nonce_hash: String,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct DepositAddressInfoJsonRef<'a> {
+    pub nonce: u64,
+    #[serde(borrow)]
+    pub eth_address: Cow<'a, str>,
+    #[serde(borrow)]
+    pub btc_deposit_address: Cow<'a, str>,
+    #[serde(borrow)]
+    pub eth_address_and_nonce_hash: Cow<'a, str>,
+}
+
 impl DepositAddressInfoJson {
     pub fn new(
         nonce: u64,
@@ -213,6 +347,10 @@ impl BtcBlockAndId {
     ) -> Self {
         BtcBlockAndId { id, block, height, deposit_address_list }
     }
+
+    pub fn get_size_in_bytes(&self) -> usize {
+        btc_serialize(&self.block).len()
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
@@ -1,7 +1,11 @@
-use serde_json;
+#[cfg(test)]
 use ethereum_types::Address as EthAddress;
+#[cfg(test)]
+use crate::constants::SAFE_ETH_ADDRESS;
+#[cfg(any(test, feature="test-generators"))]
+use crate::btc::btc_types::BtcUtxoAndValue;
 use crate::{
-    constants::SAFE_ETH_ADDRESS,
+    errors::AppError,
     types::{
         Bytes,
         Result,
@@ -9,15 +13,16 @@ use crate::{
     btc::{
         btc_constants::{
             DEFAULT_BTC_SEQUENCE,
+            BTC_BLOCK_HEADER_IN_DB_FORMAT_LENGTH,
             PTOKEN_P2SH_SCRIPT_BYTES,
         },
         btc_types::{
             BtcBlockAndId,
             MintingParams,
-            BtcUtxoAndValue,
             BtcUtxosAndValues,
             BtcBlockInDbFormat,
             DepositAddressInfo,
+            BtcBlockHeaderInDbFormat,
             DepositAddressInfoJson,
         },
     },
@@ -25,6 +30,11 @@ use crate::{
         convert_bytes_to_u64,
         convert_u64_to_bytes,
     },
+    db_codec::{
+        serialize_for_db,
+        deserialize_from_db,
+        DbSerializationFormat,
+    },
     base58::{
         from as from_base58,
         encode_slice as base58_encode_slice,
@@ -106,11 +116,11 @@ pub fn get_p2sh_redeem_script_sig(
     eth_address_and_nonce_hash: &sha256d::Hash,
 ) -> BtcScript {
     info!("✔ Generating `p2sh`'s redeem `script_sig`");
-    debug!(
+    sensitive_debug!(
         "✔ Using `eth_address_and_nonce_hash`: {}",
         hex::encode(eth_address_and_nonce_hash)
     );
-    debug!(
+    sensitive_debug!(
         "✔ Using `pub key slice`: {}",
         hex::encode(utxo_spender_pub_key_slice)
     );
@@ -147,17 +157,19 @@ pub fn get_btc_block_in_db_format(
 }
 
 pub fn serialize_minting_params(
-    minting_params: &MintingParams
+    minting_params: &MintingParams,
+    format: DbSerializationFormat,
 ) -> Result<Bytes> {
-    Ok(serde_json::to_vec(minting_params)?)
+    serialize_for_db(minting_params, format)
 }
 
 pub fn deserialize_minting_params(
     serialized_minting_params: Bytes
 ) -> Result<MintingParams> {
-    Ok(serde_json::from_slice(&serialized_minting_params[..])?)
+    deserialize_from_db(&serialized_minting_params[..])
 }
 
+#[cfg(any(test, feature="test-generators"))]
 pub fn create_op_return_btc_utxo_and_value_from_tx_output(
     tx: &BtcTransaction,
     output_index: u32,
@@ -222,21 +234,24 @@ pub fn convert_bytes_to_btc_network(bytes: &Bytes) -> Result<BtcNetwork> {
 
 pub fn serialize_btc_block_in_db_format(
     btc_block_in_db_format: &BtcBlockInDbFormat,
+    format: DbSerializationFormat,
 ) -> Result<(Bytes, Bytes)> {
     let serialized_id = btc_block_in_db_format.id.to_vec();
     Ok(
         (
             serialized_id.clone(),
-            serde_json::to_vec(
+            serialize_for_db(
                 &SerializedBlockInDbFormat::new(
                     serialized_id,
                     btc_serialize(&btc_block_in_db_format.block),
                     convert_u64_to_bytes(&btc_block_in_db_format.height),
                     btc_block_in_db_format.extra_data.clone(),
                     serialize_minting_params(
-                        &btc_block_in_db_format.minting_params
+                        &btc_block_in_db_format.minting_params,
+                        format,
                     )?,
-                )
+                ),
+                format,
             )?
         )
     )
@@ -245,7 +260,7 @@ pub fn serialize_btc_block_in_db_format(
 pub fn deserialize_btc_block_in_db_format(
     serialized_block_in_db_format: &Bytes
 ) -> Result<BtcBlockInDbFormat> {
-    let serialized_struct: SerializedBlockInDbFormat = serde_json::from_slice(
+    let serialized_struct: SerializedBlockInDbFormat = deserialize_from_db(
         &serialized_block_in_db_format
     )?;
     BtcBlockInDbFormat::new(
@@ -259,6 +274,38 @@ pub fn deserialize_btc_block_in_db_format(
     )
 }
 
+pub fn serialize_btc_block_header_in_db_format(
+    btc_block_header_in_db_format: &BtcBlockHeaderInDbFormat,
+) -> Bytes {
+    [
+        btc_block_header_in_db_format.id.to_vec(),
+        convert_u64_to_bytes(&btc_block_header_in_db_format.height),
+        btc_serialize(&btc_block_header_in_db_format.header),
+    ].concat()
+}
+
+pub fn deserialize_btc_block_header_in_db_format(
+    serialized_block_header_in_db_format: &Bytes
+) -> Result<BtcBlockHeaderInDbFormat> {
+    let bytes = serialized_block_header_in_db_format;
+    match bytes.len() == BTC_BLOCK_HEADER_IN_DB_FORMAT_LENGTH {
+        false => Err(AppError::ConversionError(
+            format!(
+                "✘ BTC block header in db format must be {} bytes long!",
+                BTC_BLOCK_HEADER_IN_DB_FORMAT_LENGTH,
+            )
+        )),
+        true => Ok(
+            BtcBlockHeaderInDbFormat {
+                id: sha256d::Hash::from_slice(&bytes[..32])?,
+                height: convert_bytes_to_u64(&bytes[32..40].to_vec())?,
+                header: btc_deserialize(&bytes[40..])?,
+            }
+        ),
+    }
+}
+
+#[cfg(test)]
 pub fn get_safe_eth_address() -> EthAddress {
     EthAddress::from_slice(&SAFE_ETH_ADDRESS)
 }
@@ -272,6 +319,7 @@ pub fn get_total_value_of_utxos_and_values(
         .sum()
 }
 
+#[cfg(test)]
 pub fn get_tx_id_from_signed_btc_tx(
     signed_btc_tx: &BtcTransaction
 ) -> String {
@@ -341,10 +389,17 @@ pub fn convert_bytes_to_btc_address(encoded_bytes: Bytes) -> String {
     base58_encode_slice(&encoded_bytes[..])
 }
 
+// NOTE: A version byte, then the 20 byte pub key hash, then the checksum.
 pub fn convert_btc_address_to_pub_key_hash_bytes(
     btc_address: &str
 ) -> Result<Bytes> {
-    Ok(from_base58(btc_address)?[1..21].to_vec())
+    let bytes = from_base58(btc_address)?;
+    match bytes.len() >= 21 {
+        true => Ok(bytes[1..21].to_vec()),
+        false => Err(AppError::InvalidAddress(
+            format!("✘ BTC address '{}' is too short!", btc_address)
+        )),
+    }
 }
 
 pub fn get_pay_to_pub_key_hash_script(btc_address: &str) -> Result<BtcScript> {
@@ -552,7 +607,8 @@ mod tests {
         );
         let minting_params = vec![minting_param_struct];
         let serialized_minting_params = serialize_minting_params(
-            &minting_params
+            &minting_params,
+            DbSerializationFormat::Json,
         ).unwrap();
         assert!(serialized_minting_params == expected_serialization);
         let deserialized = deserialize_minting_params(serialized_minting_params)
@@ -572,7 +628,8 @@ mod tests {
         let block = get_sample_btc_block_in_db_format()
             .unwrap();
         let (_db_key, serialized_block)= serialize_btc_block_in_db_format(
-            &block
+            &block,
+            DbSerializationFormat::Json,
         ).unwrap();
         let deserialized = deserialize_btc_block_in_db_format(&serialized_block)
             .unwrap();
@@ -650,7 +707,8 @@ mod tests {
         let block_in_db_format = get_sample_btc_block_in_db_format()
             .unwrap();
         let (id, serialized_block) = serialize_btc_block_in_db_format(
-            &block_in_db_format
+            &block_in_db_format,
+            DbSerializationFormat::Json,
         ).unwrap();
         assert!(id == &block_in_db_format.id[..]);
         let result = deserialize_btc_block_in_db_format(&serialized_block)
@@ -658,6 +716,25 @@ mod tests {
         assert!(result == block_in_db_format);
     }
 
+    #[test]
+    fn should_serde_btc_block_header_in_db_format_correctly() {
+        let header_in_db_format =
+            BtcBlockHeaderInDbFormat::from_btc_block_in_db_format(
+                &get_sample_btc_block_in_db_format().unwrap()
+            );
+        let serialized_header = serialize_btc_block_header_in_db_format(
+            &header_in_db_format
+        );
+        assert_eq!(
+            serialized_header.len(),
+            BTC_BLOCK_HEADER_IN_DB_FORMAT_LENGTH,
+        );
+        let result = deserialize_btc_block_header_in_db_format(
+            &serialized_header
+        ).unwrap();
+        assert_eq!(result, header_in_db_format);
+    }
+
     #[test]
     fn should_get_safe_eth_address() {
         let expected_result = "71a440ee9fa7f99fb9a697e96ec7839b8a1643b8";
@@ -4,7 +4,7 @@ use crate::{
     traits::DatabaseInterface,
     btc::{
         btc_state::BtcState,
-        btc_database_utils::get_btc_block_from_db,
+        btc_database_utils::get_btc_block_header_from_db,
     },
 };
 
@@ -14,16 +14,15 @@ pub fn check_for_parent_of_btc_block_in_state<D>(
     where D: DatabaseInterface
 {
     info!("✔ Checking BTC block's parent exists in database...");
-    match get_btc_block_from_db(
-        &state.db,
-        &state.get_btc_block_and_id()?.block.header.prev_blockhash,
-    ) {
+    let parent_hash = state.get_btc_block_and_id()?.block.header.prev_blockhash;
+    match get_btc_block_header_from_db(&state.db, &parent_hash) {
         Ok(_)=> {
             info!("✔ BTC block's parent exists in database!");
             Ok(state)
         },
-        _ => Err(AppError::Custom(
-            format!("✘ BTC block Rejected - no parent exists in database!")
-        )),
+        _ => Err(AppError::NoParentBlock {
+            chain: "BTC".to_string(),
+            parent_hash: parent_hash.to_string(),
+        }),
     }
 }
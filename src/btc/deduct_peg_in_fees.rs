@@ -0,0 +1,473 @@
+use std::cmp::min;
+use ethereum_types::U256;
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    utils::convert_u256_to_u64,
+    eth::{
+        eth_constants::WEI_PER_ETH,
+        eth_database_utils::{
+            get_eth_gas_price_from_db,
+            get_eth_mint_gas_limit_from_db,
+            get_eth_host_token_decimals_from_db,
+        },
+    },
+    fee_ledger::{
+        FeeType,
+        record_btc_fee_in_ledger,
+    },
+    token_decimals::{
+        MINTING_ROUNDING_MODE,
+        REDEEMING_ROUNDING_MODE,
+        convert_host_token_amount_to_satoshis,
+        convert_satoshis_to_host_token_amount,
+    },
+    btc::{
+        btc_state::BtcState,
+        btc_constants::MAX_PEG_IN_FEE_BASIS_POINTS,
+        btc_types::{
+            MintingParams,
+            BtcBlockInDbFormat,
+            MintingParamStruct,
+        },
+        btc_database_utils::{
+            put_btc_canon_block_in_db,
+            get_btc_canon_block_from_db,
+            get_btc_peg_in_fee_basis_points_from_db,
+            increment_btc_accrued_peg_in_fees_in_db,
+            get_btc_mint_gas_recapture_rate_from_db,
+        },
+    },
+};
+
+fn calculate_peg_in_fee_in_satoshis(
+    amount_in_satoshis: u64,
+    basis_points: u64,
+) -> Result<u64> {
+    convert_u256_to_u64(
+        &(
+            U256::from(amount_in_satoshis) *
+            U256::from(basis_points) /
+            U256::from(MAX_PEG_IN_FEE_BASIS_POINTS)
+        )
+    )
+}
+
+// NOTE: Rounded up so that the operator never mints at a loss.
+fn calculate_mint_gas_cost_in_satoshis(
+    gas_price: u64,
+    gas_limit: u64,
+    satoshis_per_eth: u64,
+) -> Result<u64> {
+    let wei_per_eth = U256::from(WEI_PER_ETH);
+    convert_u256_to_u64(
+        &(
+            (
+                U256::from(gas_price) *
+                U256::from(gas_limit) *
+                U256::from(satoshis_per_eth) +
+                wei_per_eth -
+                U256::one()
+            ) / wei_per_eth
+        )
+    )
+}
+
+pub fn get_mint_gas_cost_in_satoshis_from_db<D>(
+    db: &D,
+    host_eth_gas_price: Option<u64>,
+) -> Result<u64>
+    where D: DatabaseInterface
+{
+    let satoshis_per_eth = get_btc_mint_gas_recapture_rate_from_db(db)?;
+    if satoshis_per_eth == 0 {
+        return Ok(0)
+    };
+    // NOTE: The host's gas price is submitted w/ the block ∴ it's clamped to
+    // the one the core signs mints with so it can't inflate the subsidy.
+    let db_gas_price = get_eth_gas_price_from_db(db)?;
+    let gas_price = match host_eth_gas_price {
+        Some(gas_price) => min(gas_price, db_gas_price),
+        None => db_gas_price,
+    };
+    calculate_mint_gas_cost_in_satoshis(
+        gas_price,
+        get_eth_mint_gas_limit_from_db(db)?,
+        satoshis_per_eth,
+    )
+}
+
+// NOTE: Peg-ins whose fee doesn't cover the estimated mint gas cost also have
+// the shortfall deducted, up to their whole amount, as a gas subsidy. Those
+// left w/ nothing to mint are dropped rather than signed. The fees are worked
+// out in satoshis & only the net amount is scaled to the host token, so any
+// satoshis that token's decimals can't represent are deducted as fee too.
+fn deduct_peg_in_fee_from_minting_param_struct(
+    minting_param_struct: &MintingParamStruct,
+    basis_points: u64,
+    token_decimals: u32,
+    mint_gas_cost: u64,
+) -> Result<(MintingParamStruct, u64, u64)> {
+    let amount_in_satoshis = convert_host_token_amount_to_satoshis(
+        minting_param_struct.amount,
+        token_decimals,
+        &REDEEMING_ROUNDING_MODE,
+    )?;
+    let fee = calculate_peg_in_fee_in_satoshis(
+        amount_in_satoshis,
+        basis_points,
+    )?;
+    let gas_subsidy = min(
+        mint_gas_cost.saturating_sub(fee),
+        amount_in_satoshis.saturating_sub(fee),
+    );
+    let amount = convert_satoshis_to_host_token_amount(
+        amount_in_satoshis - fee - gas_subsidy,
+        token_decimals,
+        &MINTING_ROUNDING_MODE,
+    )?;
+    let net_amount_in_satoshis = convert_host_token_amount_to_satoshis(
+        amount,
+        token_decimals,
+        &REDEEMING_ROUNDING_MODE,
+    )?;
+    let fee = amount_in_satoshis - gas_subsidy - net_amount_in_satoshis;
+    info!(
+        "✔ Deducting peg-in fee of {} & gas subsidy of {} satoshis from {:?}",
+        fee,
+        gas_subsidy,
+        minting_param_struct,
+    );
+    Ok((
+        MintingParamStruct { amount, ..minting_param_struct.clone() },
+        fee,
+        gas_subsidy,
+    ))
+}
+
+pub fn deduct_peg_in_fees_from_minting_params(
+    minting_params: &MintingParams,
+    basis_points: u64,
+    token_decimals: u32,
+    mint_gas_cost: u64,
+) -> Result<(MintingParams, u64, u64)> {
+    minting_params
+        .iter()
+        .map(|minting_param_struct|
+            deduct_peg_in_fee_from_minting_param_struct(
+                minting_param_struct,
+                basis_points,
+                token_decimals,
+                mint_gas_cost,
+            )
+        )
+        .collect::<Result<Vec<(MintingParamStruct, u64, u64)>>>()
+        .map(|params_and_fees| {
+            let total_fee = params_and_fees
+                .iter()
+                .map(|(_, fee, _)| fee)
+                .sum();
+            let total_gas_subsidy = params_and_fees
+                .iter()
+                .map(|(_, _, gas_subsidy)| gas_subsidy)
+                .sum();
+            let params = params_and_fees
+                .into_iter()
+                .map(|(params, _, _)| params)
+                .filter(|params| match params.amount.is_zero() {
+                    false => true,
+                    true => {
+                        info!("✘ Dropping fully deducted peg-in: {:?}", params);
+                        false
+                    },
+                })
+                .collect::<MintingParams>();
+            (params, total_fee, total_gas_subsidy)
+        })
+}
+
+pub fn maybe_deduct_peg_in_fees_from_canon_block<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe deducting peg-in fees from canon block minting params...");
+    let basis_points = get_btc_peg_in_fee_basis_points_from_db(&state.db)?;
+    let mint_gas_cost = get_mint_gas_cost_in_satoshis_from_db(
+        &state.db,
+        state.host_eth_gas_price.as_option().cloned(),
+    )?;
+    let canon_block = get_btc_canon_block_from_db(&state.db)?;
+    if basis_points == 0 && mint_gas_cost == 0 {
+        info!("✔ No peg-in fees to deduct from canon block!");
+        return Ok(state)
+    };
+    if canon_block.minting_params.is_empty() {
+        info!("✔ No minting params in canon block ∴ no peg-in fees to deduct!");
+        return Ok(state)
+    };
+    let (minting_params, total_fee, total_gas_subsidy) =
+        deduct_peg_in_fees_from_minting_params(
+            &canon_block.minting_params,
+            basis_points,
+            get_eth_host_token_decimals_from_db(&state.db)?,
+            mint_gas_cost,
+        )?;
+    info!("✔ Total peg-in fees deducted: {} satoshis", total_fee);
+    info!("✔ Total gas subsidies deducted: {} satoshis", total_gas_subsidy);
+    let canon_block_height = canon_block.height;
+    BtcBlockInDbFormat::new(
+        canon_block.height,
+        canon_block.id,
+        minting_params,
+        canon_block.block,
+        canon_block.extra_data,
+    )
+        .and_then(|block| put_btc_canon_block_in_db(&state.db, &block))
+        .and_then(|_|
+            increment_btc_accrued_peg_in_fees_in_db(
+                &state.db,
+                &(total_fee + total_gas_subsidy),
+            )
+        )
+        .and_then(|_|
+            record_btc_fee_in_ledger(
+                &state.db,
+                canon_block_height,
+                FeeType::PegIn,
+                total_fee,
+            )
+        )
+        .and_then(|_|
+            record_btc_fee_in_ledger(
+                &state.db,
+                canon_block_height,
+                FeeType::GasSubsidy,
+                total_gas_subsidy,
+            )
+        )
+        .map(|_| state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        constants::PTOKEN_ERC777_NUM_DECIMALS,
+        eth::eth_database_utils::put_eth_gas_price_in_db,
+        utils::{
+            convert_satoshis_to_ptoken,
+            convert_ptoken_to_satoshis,
+        },
+        btc::{
+            btc_database_utils::{
+                put_btc_peg_in_fee_basis_points_in_db,
+                get_btc_accrued_peg_in_fees_from_db,
+                put_btc_mint_gas_recapture_rate_in_db,
+            },
+            btc_test_utils::{
+                get_sample_minting_params,
+                get_sample_btc_block_in_db_format,
+            },
+        },
+    };
+
+    #[test]
+    fn should_calculate_peg_in_fee_in_satoshis() {
+        let amount = 1_000_000;
+        let result = calculate_peg_in_fee_in_satoshis(amount, 25).unwrap();
+        assert_eq!(result, 2_500);
+        let result = calculate_peg_in_fee_in_satoshis(amount, 0).unwrap();
+        assert_eq!(result, 0);
+    }
+
+    #[test]
+    fn should_deduct_peg_in_fee_in_satoshis_for_fewer_decimals_than_btc() {
+        let decimals = 6;
+        let gross_params = MintingParamStruct {
+            amount: convert_satoshis_to_host_token_amount(
+                1_234_567,
+                decimals,
+                &MINTING_ROUNDING_MODE,
+            ).unwrap(),
+            ..get_sample_minting_params()[0].clone()
+        };
+        assert_eq!(gross_params.amount, U256::from(12_345));
+        let (net_params, fee, gas_subsidy) =
+            deduct_peg_in_fee_from_minting_param_struct(
+                &gross_params,
+                25,
+                decimals,
+                0,
+            ).unwrap();
+        assert_eq!(net_params.amount, U256::from(12_314));
+        assert_eq!(gas_subsidy, 0);
+        assert_eq!(fee, 3_100);
+        assert_eq!(
+            (gross_params.amount - net_params.amount).as_u64() * 100,
+            fee,
+        );
+    }
+
+    #[test]
+    fn should_deduct_peg_in_fees_from_minting_params() {
+        let basis_points = 25;
+        let decimals = PTOKEN_ERC777_NUM_DECIMALS;
+        let minting_params = get_sample_minting_params();
+        let (result, total_fee, total_gas_subsidy) =
+            deduct_peg_in_fees_from_minting_params(
+                &minting_params,
+                basis_points,
+                decimals,
+                0,
+            ).unwrap();
+        assert_eq!(total_gas_subsidy, 0);
+        let expected_total_fee = minting_params
+            .iter()
+            .map(|params|
+                calculate_peg_in_fee_in_satoshis(
+                    convert_ptoken_to_satoshis(params.amount),
+                    basis_points,
+                ).unwrap()
+            )
+            .sum::<u64>();
+        assert_eq!(total_fee, expected_total_fee);
+        result
+            .iter()
+            .zip(minting_params.iter())
+            .for_each(|(net_params, gross_params)| {
+                let fee = convert_ptoken_to_satoshis(
+                    gross_params.amount - net_params.amount
+                );
+                assert_eq!(
+                    fee,
+                    calculate_peg_in_fee_in_satoshis(
+                        convert_ptoken_to_satoshis(gross_params.amount),
+                        basis_points,
+                    ).unwrap(),
+                );
+                assert_eq!(net_params.eth_address, gross_params.eth_address);
+            });
+    }
+
+    #[test]
+    fn should_deduct_peg_in_fees_from_canon_block_and_accrue_them() {
+        let db = get_test_database();
+        let basis_points = 100;
+        let minting_params = get_sample_minting_params();
+        let block = get_sample_btc_block_in_db_format().unwrap();
+        let canon_block = BtcBlockInDbFormat::new(
+            block.height,
+            block.id,
+            minting_params.clone(),
+            block.block,
+            block.extra_data,
+        ).unwrap();
+        put_btc_canon_block_in_db(&db, &canon_block).unwrap();
+        put_btc_peg_in_fee_basis_points_in_db(&db, &basis_points).unwrap();
+        let state = maybe_deduct_peg_in_fees_from_canon_block(
+            BtcState::init(db)
+        ).unwrap();
+        let (expected_params, expected_fee, _) =
+            deduct_peg_in_fees_from_minting_params(
+                &minting_params,
+                basis_points,
+                PTOKEN_ERC777_NUM_DECIMALS,
+                0,
+            ).unwrap();
+        let result = get_btc_canon_block_from_db(&state.db).unwrap();
+        assert_eq!(result.minting_params, expected_params);
+        assert_eq!(
+            get_btc_accrued_peg_in_fees_from_db(&state.db).unwrap(),
+            expected_fee,
+        );
+    }
+
+    #[test]
+    fn should_calculate_mint_gas_cost_in_satoshis_rounding_up() {
+        let gas_price = 20_000_000_000;
+        let gas_limit = 120_000;
+        let satoshis_per_eth = 2_500_000;
+        let result = calculate_mint_gas_cost_in_satoshis(
+            gas_price,
+            gas_limit,
+            satoshis_per_eth,
+        ).unwrap();
+        assert_eq!(result, 6_000);
+        assert_eq!(calculate_mint_gas_cost_in_satoshis(1, 1, 1).unwrap(), 1);
+        assert_eq!(calculate_mint_gas_cost_in_satoshis(1, 1, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn should_fail_to_calculate_mint_gas_cost_overflowing_u64() {
+        let result = calculate_mint_gas_cost_in_satoshis(
+            u64::MAX,
+            u64::MAX,
+            u64::MAX,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_prefer_lower_host_eth_gas_price_for_mint_gas_cost() {
+        let db = get_test_database();
+        assert_eq!(
+            get_mint_gas_cost_in_satoshis_from_db(&db, Some(1)).unwrap(),
+            0,
+        );
+        put_btc_mint_gas_recapture_rate_in_db(&db, &2_500_000).unwrap();
+        put_eth_gas_price_in_db(&db, &40_000_000_000).unwrap();
+        let result = get_mint_gas_cost_in_satoshis_from_db(
+            &db,
+            Some(20_000_000_000),
+        ).unwrap();
+        assert_eq!(result, 6_000);
+    }
+
+    #[test]
+    fn should_clamp_host_eth_gas_price_to_db_gas_price() {
+        let db = get_test_database();
+        put_btc_mint_gas_recapture_rate_in_db(&db, &2_500_000).unwrap();
+        put_eth_gas_price_in_db(&db, &20_000_000_000).unwrap();
+        let result = get_mint_gas_cost_in_satoshis_from_db(
+            &db,
+            Some(u64::MAX),
+        ).unwrap();
+        assert_eq!(result, 6_000);
+    }
+
+    #[test]
+    fn should_deduct_mint_gas_cost_shortfall_from_tiny_peg_ins() {
+        let decimals = PTOKEN_ERC777_NUM_DECIMALS;
+        let minting_params = vec![
+            MintingParamStruct {
+                amount: convert_satoshis_to_ptoken(1_000_000),
+                ..get_sample_minting_params()[0].clone()
+            },
+            MintingParamStruct {
+                amount: convert_satoshis_to_ptoken(10_000),
+                ..get_sample_minting_params()[1].clone()
+            },
+            MintingParamStruct {
+                amount: convert_satoshis_to_ptoken(100),
+                ..get_sample_minting_params()[2].clone()
+            },
+        ];
+        let (result, total_fee, total_gas_subsidy) =
+            deduct_peg_in_fees_from_minting_params(
+                &minting_params,
+                25,
+                decimals,
+                1_000,
+            ).unwrap();
+        assert_eq!(total_fee, 2_500 + 25);
+        assert_eq!(total_gas_subsidy, 975 + 100);
+        assert_eq!(result.len(), 2);
+        assert_eq!(
+            convert_ptoken_to_satoshis(result[0].amount),
+            1_000_000 - 2_500,
+        );
+        assert_eq!(convert_ptoken_to_satoshis(result[1].amount), 9_000);
+    }
+}
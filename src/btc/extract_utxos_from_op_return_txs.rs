@@ -61,7 +61,7 @@ pub fn maybe_extract_utxos_from_op_return_txs_and_put_in_state<D>(
             )
         )
         .and_then(|utxos| {
-            debug!("✔ Extracted UTXOs: {:?}", utxos);
+            sensitive_debug!("✔ Extracted UTXOs: {:?}", utxos);
             info!("✔ Extracted {} `op_return` UTXOs", utxos.len());
             state.add_utxos_and_values(utxos)
         })
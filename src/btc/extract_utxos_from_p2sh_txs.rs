@@ -134,7 +134,7 @@ pub fn maybe_extract_utxos_from_p2sh_txs_and_put_in_state<D>(
         &get_btc_network_from_db(&state.db)?,
     )
         .and_then(|utxos| {
-            debug!("✔ Extracted `p2sh` UTXOs: {:?}", utxos);
+            sensitive_debug!("✔ Extracted `p2sh` UTXOs: {:?}", utxos);
             info!("✔ Extracted {} `p2sh` UTXOs", utxos.len());
             state.add_utxos_and_values(utxos)
         })
@@ -1,8 +1,25 @@
+use ethereum_types::{
+    U256,
+    Address as EthAddress,
+};
 use crate::{
     types::Result,
     traits::DatabaseInterface,
-    utils::convert_satoshis_to_ptoken,
     constants::MINIMUM_REQUIRED_SATOSHIS,
+    eth::eth_database_utils::get_eth_host_token_decimals_from_db,
+    safe_addresses::{
+        SafeAddressTier,
+        get_safe_eth_address_for_tier_from_db,
+    },
+    skipped_items::{
+        SkipReason,
+        SkippedItem,
+        SkippedItems,
+    },
+    token_decimals::{
+        MINTING_ROUNDING_MODE,
+        convert_satoshis_to_host_token_amount,
+    },
     btc::{
         btc_state::BtcState,
         btc_types::{
@@ -12,10 +29,52 @@ use crate::{
     },
 };
 
+fn get_minting_threshold(token_decimals: u32) -> Result<U256> {
+    convert_satoshis_to_host_token_amount(
+        MINIMUM_REQUIRED_SATOSHIS,
+        token_decimals,
+        &MINTING_ROUNDING_MODE,
+    )
+}
+
+fn get_skipped_items_from_minting_params(
+    minting_params: &MintingParams,
+    token_decimals: u32,
+    safe_eth_address: &EthAddress,
+) -> Result<SkippedItems> {
+    let threshold = get_minting_threshold(token_decimals)?;
+    Ok(
+        minting_params
+            .iter()
+            .filter_map(|params| {
+                let skipped_item = SkippedItem::new(
+                    params.originating_tx_hash.to_string(),
+                    params.amount.to_string(),
+                    SkipReason::DustAmount,
+                ).with_correlation_id(&params.correlation_id);
+                match params.amount >= threshold {
+                    false => Some(skipped_item),
+                    true if &params.eth_address == safe_eth_address => Some(
+                        SkippedItem {
+                            reason: SkipReason::InvalidRecipient,
+                            ..skipped_item
+                        }.with_detail(format!(
+                            "Diverted to safe ETH address: 0x{}",
+                            hex::encode(safe_eth_address),
+                        ))
+                    ),
+                    true => None,
+                }
+            })
+            .collect()
+    )
+}
+
 fn filter_minting_params(
     minting_params: &MintingParams,
+    token_decimals: u32,
 ) -> Result<MintingParams> {
-    let threshold = convert_satoshis_to_ptoken(MINIMUM_REQUIRED_SATOSHIS);
+    let threshold = get_minting_threshold(token_decimals)?;
     Ok(
         minting_params
             .into_iter()
@@ -42,14 +101,28 @@ pub fn maybe_filter_minting_params_in_state<D>(
     where D: DatabaseInterface
 {
     info!("✔ Filtering out any minting params below minimum # of Satoshis...");
-    filter_minting_params(&state.minting_params)
+    let token_decimals = get_eth_host_token_decimals_from_db(&state.db)?;
+    let skipped_items = get_skipped_items_from_minting_params(
+        &state.minting_params,
+        token_decimals,
+        &get_safe_eth_address_for_tier_from_db(
+            &state.db,
+            SafeAddressTier::MalformedMetadata,
+        )?,
+    )?;
+    filter_minting_params(&state.minting_params, token_decimals)
         .and_then(|new_params| state.replace_minting_params(new_params))
+        .and_then(|state| state.add_skipped_items(skipped_items))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::btc::btc_test_utils::get_sample_minting_params;
+    use crate::{
+        utils::convert_satoshis_to_ptoken,
+        constants::PTOKEN_ERC777_NUM_DECIMALS,
+        btc::btc_test_utils::get_sample_minting_params,
+    };
 
     #[test]
     fn should_filter_minting_params() {
@@ -59,8 +132,10 @@ mod tests {
         let threshold = convert_satoshis_to_ptoken(MINIMUM_REQUIRED_SATOSHIS);
         let length_before = minting_params.len();
         assert_eq!(length_before, expected_length_before);
-        let result = filter_minting_params(&minting_params)
-            .unwrap();
+        let result = filter_minting_params(
+            &minting_params,
+            PTOKEN_ERC777_NUM_DECIMALS,
+        ).unwrap();
         let length_after = result.len();
         assert_eq!(length_after, expected_length_after);
         result
@@ -68,4 +143,23 @@ mod tests {
             .map(|params| assert!(params.amount >= threshold))
             .for_each(drop);
     }
+
+    #[test]
+    fn should_get_skipped_items_from_minting_params() {
+        let minting_params = get_sample_minting_params();
+        let safe_eth_address = minting_params[1].eth_address;
+        let result = get_skipped_items_from_minting_params(
+            &minting_params,
+            PTOKEN_ERC777_NUM_DECIMALS,
+            &safe_eth_address,
+        ).unwrap();
+        let reasons = result
+            .iter()
+            .map(|item| item.reason)
+            .collect::<Vec<SkipReason>>();
+        assert_eq!(
+            reasons,
+            vec![SkipReason::InvalidRecipient, SkipReason::DustAmount],
+        );
+    }
 }
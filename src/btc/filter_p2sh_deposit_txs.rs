@@ -1,3 +1,6 @@
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use std::collections::HashSet;
 use bitcoin::{
     util::address::Address as BtcAddress,
     blockdata::transaction::TxOut as BtcTxOut,
@@ -41,9 +44,9 @@ fn is_address_locked_to_pub_key(
                 ),
                 *btc_network
             );
-            debug!("Deposit info: {:?}", deposit_info);
-            debug!("Address from UTXO  : {}", address_from_utxo);
-            debug!("Address from script: {}", address_from_script);
+            sensitive_debug!("Deposit info: {:?}", deposit_info);
+            sensitive_debug!("Address from UTXO  : {}", address_from_utxo);
+            sensitive_debug!("Address from script: {}", address_from_script);
             match &address_from_script == address_from_utxo {
                 true => {
                     info!("✔ UTXO IS locked to the enclave!");
@@ -58,43 +61,45 @@ fn is_address_locked_to_pub_key(
     }
 }
 
-fn is_output_address_locked_to_pub_key(
-    tx_output: &BtcTxOut,
-    btc_network: &BtcNetwork,
-    enclave_public_key_slice: &[u8],
+// NOTE: Derives the `p2sh` address each deposit info's redeem script would
+// have once per submission, rather than once per output paying to it.
+pub fn get_deposit_addresses_locked_to_pub_key(
     deposit_info: &DepositInfoHashMap,
-) -> bool {
-    match BtcAddress::from_script(&tx_output.script_pubkey, *btc_network) {
-        None => false,
-        Some(address_from_utxo) => is_address_locked_to_pub_key(
-            btc_network,
-            enclave_public_key_slice,
-            &address_from_utxo,
-            deposit_info,
+    enclave_public_key_slice: &[u8],
+    btc_network: &BtcNetwork,
+) -> HashSet<BtcAddress> {
+    deposit_info
+        .keys()
+        .filter(|address|
+            is_address_locked_to_pub_key(
+                btc_network,
+                enclave_public_key_slice,
+                address,
+                deposit_info,
+            )
         )
-    }
+        .cloned()
+        .collect()
 }
 
-fn is_output_address_in_hash_map(
+fn is_output_address_in_locked_addresses(
     tx_output: &BtcTxOut,
-    deposit_info: &DepositInfoHashMap,
     btc_network: &BtcNetwork,
+    locked_addresses: &HashSet<BtcAddress>,
 ) -> bool {
-    info!("✔ Checking if output address is in hash map...");
     match BtcAddress::from_script(&tx_output.script_pubkey, *btc_network) {
         None => false,
         Some(address) => {
-            match deposit_info
-                .contains_key(&address) {
-                    true => {
-                        info!("✔ Output address {} IS in hash map!", address);
-                        true
-                    }
-                    false => {
-                        info!("✘ Output address {} is NOT in hash map!", address);
-                        false
-                    }
+            match locked_addresses.contains(&address) {
+                true => {
+                    info!("✔ Output address {} IS locked!", address);
+                    true
                 }
+                false => {
+                    trace!("✘ Output address {} is NOT locked!", address);
+                    false
+                }
+            }
         }
     }
 }
@@ -105,31 +110,29 @@ pub fn filter_p2sh_deposit_txs(
     transactions: &BtcTransactions,
     btc_network: &BtcNetwork,
 ) -> Result<BtcTransactions> {
+    #[cfg(feature = "parallel")]
+    let transactions_iter = transactions.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let transactions_iter = transactions.iter();
+    let locked_addresses = get_deposit_addresses_locked_to_pub_key(
+        deposit_info,
+        enclave_public_key_slice,
+        btc_network,
+    );
     Ok(
-        transactions
-            .iter()
+        transactions_iter
             .filter(|txdata|
                 txdata
                     .output
                     .iter()
                     .filter(|tx_out| tx_out.script_pubkey.is_p2sh())
-                    .filter(|tx_out|
-                        is_output_address_in_hash_map(
+                    .any(|tx_out|
+                        is_output_address_in_locked_addresses(
                             tx_out,
-                            deposit_info,
                             btc_network,
+                            &locked_addresses,
                         )
                     )
-                    .filter(|tx_out|
-                        is_output_address_locked_to_pub_key(
-                            tx_out,
-                            &btc_network,
-                            enclave_public_key_slice,
-                            deposit_info,
-                        )
-                    )
-                    .collect::<Vec<&BtcTxOut>>()
-                    .len() > 0
             )
             .cloned()
             .collect::<BtcTransactions>()
@@ -251,80 +254,67 @@ mod tests {
         assert!(!result);
     }
 
-    #[test]
-    fn address_from_output_should_be_locked_to_pub_key() {
-        let enclave_public_key_slice = &get_sample_btc_pub_key_bytes()[..];
-        let btc_network = BtcNetwork::Testnet;
+    fn get_sample_locked_addresses(
+        enclave_public_key_slice: &[u8],
+    ) -> HashSet<BtcAddress> {
         let deposit_address_list = get_sample_btc_block_with_p2sh_deposit()
             .deposit_address_list
             .clone();
         let deposit_info = create_hash_map_from_deposit_info_list(
             &deposit_address_list
         ).unwrap();
+        get_deposit_addresses_locked_to_pub_key(
+            &deposit_info,
+            enclave_public_key_slice,
+            &BtcNetwork::Testnet,
+        )
+    }
+
+    #[test]
+    fn address_from_output_should_be_locked_to_pub_key() {
+        let locked_addresses = get_sample_locked_addresses(
+            &get_sample_btc_pub_key_bytes()[..]
+        );
         let tx_output = get_sample_tx_output_with_p2sh_deposit();
-        let result = is_output_address_locked_to_pub_key(
+        let result = is_output_address_in_locked_addresses(
             &tx_output,
-            &btc_network,
-            &enclave_public_key_slice,
-            &deposit_info,
+            &BtcNetwork::Testnet,
+            &locked_addresses,
         );
         assert!(result);
     }
 
     #[test]
     fn address_from_wrong_output_should_not_be_locked_to_pub_key() {
-        let enclave_public_key_slice = &get_sample_btc_pub_key_bytes()[..];
-        let btc_network = BtcNetwork::Testnet;
-        let deposit_address_list = get_sample_btc_block_with_p2sh_deposit()
-            .deposit_address_list
-            .clone();
-        let deposit_info = create_hash_map_from_deposit_info_list(
-            &deposit_address_list
-        ).unwrap();
+        let locked_addresses = get_sample_locked_addresses(
+            &get_sample_btc_pub_key_bytes()[..]
+        );
         let tx_output = get_wrong_sample_tx_output();
-        let result = is_output_address_locked_to_pub_key(
+        let result = is_output_address_in_locked_addresses(
             &tx_output,
-            &btc_network,
-            &enclave_public_key_slice,
-            &deposit_info,
+            &BtcNetwork::Testnet,
+            &locked_addresses,
         );
         assert!(!result);
     }
 
     #[test]
-    fn outputs_address_should_be_in_hash_map() {
-        let btc_network = BtcNetwork::Testnet;
-        let deposit_address_list = get_sample_btc_block_with_p2sh_deposit()
-            .deposit_address_list
-            .clone();
-        let deposit_info = create_hash_map_from_deposit_info_list(
-            &deposit_address_list
-        ).unwrap();
-        let tx_output = get_sample_tx_output_with_p2sh_deposit();
-        let result = is_output_address_in_hash_map(
-            &tx_output,
-            &deposit_info,
-            &btc_network,
+    fn should_get_deposit_addresses_locked_to_pub_key() {
+        let locked_addresses = get_sample_locked_addresses(
+            &get_sample_btc_pub_key_bytes()[..]
+        );
+        assert!(locked_addresses.contains(&get_sample_btc_deposit_address()));
+        assert!(
+            !locked_addresses.contains(&get_wrong_sample_btc_deposit_address())
         );
-        assert!(result);
     }
 
     #[test]
-    fn wrong_outputs_address_should_not_be_in_hash_map() {
-        let btc_network = BtcNetwork::Testnet;
-        let deposit_address_list = get_sample_btc_block_with_p2sh_deposit()
-            .deposit_address_list
-            .clone();
-        let deposit_info = create_hash_map_from_deposit_info_list(
-            &deposit_address_list
-        ).unwrap();
-        let tx_output = get_wrong_sample_tx_output();
-        let result = is_output_address_in_hash_map(
-            &tx_output,
-            &deposit_info,
-            &btc_network,
-        );
-        assert!(!result);
+    fn should_not_get_deposit_addresses_locked_to_other_pub_key() {
+        let mut other_pub_key = get_sample_btc_pub_key_bytes();
+        other_pub_key[1] ^= 0xff;
+        let locked_addresses = get_sample_locked_addresses(&other_pub_key[..]);
+        assert!(locked_addresses.is_empty());
     }
 
     #[test]
@@ -0,0 +1,123 @@
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    processed_tx_ledger::btc_deposit_has_been_processed,
+    skipped_items::{
+        SkipReason,
+        SkippedItem,
+    },
+    btc::{
+        btc_state::BtcState,
+        btc_types::{
+            MintingParams,
+            BtcBlockInDbFormat,
+        },
+        btc_database_utils::{
+            put_btc_canon_block_in_db,
+            get_btc_canon_block_from_db,
+        },
+    },
+};
+
+fn filter_processed_deposits_from_minting_params<D>(
+    db: &D,
+    minting_params: &MintingParams,
+) -> MintingParams
+    where D: DatabaseInterface
+{
+    minting_params
+        .iter()
+        .filter(|params| {
+            let txid = &params.originating_tx_hash;
+            match btc_deposit_has_been_processed(db, txid) {
+                false => true,
+                true => {
+                    info!("✘ Filtering already processed deposit: {}", txid);
+                    false
+                }
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+pub fn maybe_filter_processed_deposits_from_canon_block<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe filtering already processed deposits from canon block...");
+    let canon_block = get_btc_canon_block_from_db(&state.db)?;
+    let minting_params = filter_processed_deposits_from_minting_params(
+        &state.db,
+        &canon_block.minting_params,
+    );
+    if minting_params.len() == canon_block.minting_params.len() {
+        info!("✔ No already processed deposits in canon block!");
+        return Ok(state)
+    };
+    let skipped_items = canon_block.minting_params
+        .iter()
+        .filter(|params| !minting_params.contains(params))
+        .map(|params|
+            SkippedItem::new(
+                params.originating_tx_hash.to_string(),
+                params.amount.to_string(),
+                SkipReason::AlreadyProcessed,
+            ).with_correlation_id(&params.correlation_id)
+        )
+        .collect();
+    BtcBlockInDbFormat::new(
+        canon_block.height,
+        canon_block.id,
+        minting_params,
+        canon_block.block,
+        canon_block.extra_data,
+    )
+        .and_then(|block| put_btc_canon_block_in_db(&state.db, &block))
+        .and_then(|_| state.add_skipped_items(skipped_items))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        processed_tx_ledger::put_processed_btc_deposit_in_db,
+        btc::btc_test_utils::{
+            get_sample_minting_params,
+            get_sample_btc_block_in_db_format,
+        },
+    };
+
+    #[test]
+    fn should_filter_processed_deposits_from_canon_block() {
+        let db = get_test_database();
+        let minting_params = get_sample_minting_params();
+        let block = get_sample_btc_block_in_db_format().unwrap();
+        let canon_block = BtcBlockInDbFormat::new(
+            block.height,
+            block.id,
+            minting_params.clone(),
+            block.block,
+            block.extra_data,
+        ).unwrap();
+        put_btc_canon_block_in_db(&db, &canon_block).unwrap();
+        put_processed_btc_deposit_in_db(
+            &db,
+            &minting_params[1].originating_tx_hash,
+        ).unwrap();
+        let state = maybe_filter_processed_deposits_from_canon_block(
+            BtcState::init(db)
+        ).unwrap();
+        let result = get_btc_canon_block_from_db(&state.db)
+            .unwrap()
+            .minting_params;
+        assert_eq!(
+            result,
+            vec![minting_params[0].clone(), minting_params[2].clone()],
+        );
+        assert_eq!(state.skipped_items.len(), 1);
+        assert_eq!(state.skipped_items[0].reason, SkipReason::AlreadyProcessed);
+    }
+}
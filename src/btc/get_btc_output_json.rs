@@ -1,39 +1,67 @@
-use std::time::{
-    SystemTime,
-    UNIX_EPOCH
-};
 use crate::{
     types::Result,
+    host_clock::get_unix_timestamp,
     traits::DatabaseInterface,
+    stage_timing::StageTimings,
+    skipped_items::SkippedItems,
     eth::{
         eth_types::EthTransactions,
-        eth_crypto::eth_transaction::EthTransaction,
+        eth_crypto::{
+            eth_transaction::EthTransaction,
+            eth_relay_request::{
+                EthRelayRequest,
+                EthRelayRequests,
+            },
+        },
         eth_database_utils::get_eth_account_nonce_from_db,
     },
     btc::{
         btc_state::BtcState,
         btc_constants::DEFAULT_BTC_ADDRESS,
+        sign_transactions::should_batch_mint,
         btc_types::{
             MintingParams,
             MintingParamStruct,
         },
         btc_database_utils::{
             get_btc_canon_block_from_db,
-            get_btc_latest_block_from_db,
+            get_btc_latest_block_number,
         },
     },
 };
 
+struct OriginatingInfo {
+    block_height: u64,
+    host_timestamp: Option<u64>,
+}
+
+fn get_originating_address_string(
+    minting_param_struct: &MintingParamStruct,
+) -> String {
+    let default_address = DEFAULT_BTC_ADDRESS.to_string();
+    let retrieved_address = minting_param_struct
+        .originating_tx_address
+        .to_string();
+    match default_address == retrieved_address {
+        false => retrieved_address,
+        true => "could not retrieve sender address".to_string(),
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
-struct EthTxInfo {
-    eth_tx_hex: String,
-    eth_tx_hash: String,
-    eth_tx_amount: String,
-    eth_account_nonce: u64,
-    eth_tx_recipient: String,
-    signature_timestamp: u64,
-    originating_tx_hash: String,
-    originating_address: String,
+pub struct EthTxInfo {
+    pub eth_tx_hex: String,
+    pub eth_tx_hash: String,
+    pub eth_tx_amount: String,
+    pub eth_tx_gas_limit: u64,
+    pub eth_account_nonce: u64,
+    pub eth_tx_recipient: String,
+    pub signature_timestamp: u64,
+    pub host_timestamp: Option<u64>,
+    pub correlation_id: String,
+    pub originating_tx_hash: String,
+    pub originating_address: String,
+    pub originating_block_height: u64,
 }
 
 impl EthTxInfo {
@@ -41,46 +69,206 @@ impl EthTxInfo {
         eth_tx: &EthTransaction,
         minting_param_struct: &MintingParamStruct,
         eth_account_nonce: u64,
+        originating_info: &OriginatingInfo,
     ) -> Result<EthTxInfo> {
-        let default_address = DEFAULT_BTC_ADDRESS.to_string();
-        let retrieved_address = minting_param_struct
-            .originating_tx_address
-            .to_string();
-        let address_string = match default_address == retrieved_address {
-            false => retrieved_address,
-            true => "could not retrieve sender address".to_string(),
-        };
         Ok(
             EthTxInfo {
                 eth_account_nonce,
+                host_timestamp: originating_info.host_timestamp,
+                originating_block_height: originating_info.block_height,
+                eth_tx_hash: format!("0x{}", eth_tx.get_tx_hash()),
+                eth_tx_hex: eth_tx.serialize_hex(),
+                eth_tx_gas_limit: eth_tx.gas_limit.as_u64(),
+                originating_address:
+                    get_originating_address_string(minting_param_struct),
+                eth_tx_amount: minting_param_struct.amount.to_string(),
+                originating_tx_hash:
+                    minting_param_struct.originating_tx_hash.to_string(),
+                correlation_id: minting_param_struct.correlation_id.clone(),
+                eth_tx_recipient: format!(
+                    "0x{}",
+                    hex::encode(minting_param_struct.eth_address.as_bytes())
+                ),
+                signature_timestamp: get_unix_timestamp()?,
+            }
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EthBatchMintInfo {
+    pub eth_tx_amount: String,
+    pub eth_tx_recipient: String,
+    pub correlation_id: String,
+    pub originating_tx_hash: String,
+    pub originating_address: String,
+}
+
+impl EthBatchMintInfo {
+    pub fn new(minting_param_struct: &MintingParamStruct) -> EthBatchMintInfo {
+        EthBatchMintInfo {
+            originating_address:
+                get_originating_address_string(minting_param_struct),
+            eth_tx_amount: minting_param_struct.amount.to_string(),
+            originating_tx_hash:
+                minting_param_struct.originating_tx_hash.to_string(),
+            correlation_id: minting_param_struct.correlation_id.clone(),
+            eth_tx_recipient: format!(
+                "0x{}",
+                hex::encode(minting_param_struct.eth_address.as_bytes())
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EthBatchTxInfo {
+    pub eth_tx_hex: String,
+    pub eth_tx_hash: String,
+    pub eth_tx_gas_limit: u64,
+    pub eth_account_nonce: u64,
+    pub signature_timestamp: u64,
+    pub host_timestamp: Option<u64>,
+    pub originating_block_height: u64,
+    pub eth_tx_mints: Vec<EthBatchMintInfo>,
+}
+
+impl EthBatchTxInfo {
+    pub fn new(
+        eth_tx: &EthTransaction,
+        minting_params: &MintingParams,
+        eth_account_nonce: u64,
+        originating_info: &OriginatingInfo,
+    ) -> Result<EthBatchTxInfo> {
+        Ok(
+            EthBatchTxInfo {
+                eth_account_nonce,
+                host_timestamp: originating_info.host_timestamp,
+                originating_block_height: originating_info.block_height,
                 eth_tx_hash: format!("0x{}", eth_tx.get_tx_hash()),
                 eth_tx_hex: eth_tx.serialize_hex(),
-                originating_address: address_string,
+                eth_tx_gas_limit: eth_tx.gas_limit.as_u64(),
+                eth_tx_mints: minting_params
+                    .iter()
+                    .map(EthBatchMintInfo::new)
+                    .collect(),
+                signature_timestamp: get_unix_timestamp()?,
+            }
+        )
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EthRelayRequestInfo {
+    pub eth_relay_request_to: String,
+    pub eth_relay_request_gas: u64,
+    pub eth_relay_request_from: String,
+    pub eth_relay_request_data: String,
+    pub eth_relay_request_nonce: u64,
+    pub eth_relay_request_value: String,
+    pub eth_relay_request_forwarder: String,
+    pub eth_relay_request_signature: String,
+    pub eth_tx_amount: String,
+    pub eth_tx_recipient: String,
+    pub signature_timestamp: u64,
+    pub host_timestamp: Option<u64>,
+    pub correlation_id: String,
+    pub originating_tx_hash: String,
+    pub originating_address: String,
+    pub originating_block_height: u64,
+}
+
+impl EthRelayRequestInfo {
+    pub fn new(
+        relay_request: &EthRelayRequest,
+        minting_param_struct: &MintingParamStruct,
+        originating_info: &OriginatingInfo,
+    ) -> Result<EthRelayRequestInfo> {
+        Ok(
+            EthRelayRequestInfo {
+                host_timestamp: originating_info.host_timestamp,
+                originating_block_height: originating_info.block_height,
+                eth_relay_request_gas: relay_request.gas.as_u64(),
+                eth_relay_request_nonce: relay_request.nonce.as_u64(),
+                eth_relay_request_value: relay_request.value.to_string(),
+                eth_relay_request_signature: format!(
+                    "0x{}",
+                    relay_request.get_signature_hex(),
+                ),
+                eth_relay_request_data: format!(
+                    "0x{}",
+                    hex::encode(&relay_request.data),
+                ),
+                eth_relay_request_to: format!(
+                    "0x{}",
+                    hex::encode(relay_request.to.as_bytes()),
+                ),
+                eth_relay_request_from: format!(
+                    "0x{}",
+                    hex::encode(relay_request.from.as_bytes()),
+                ),
+                eth_relay_request_forwarder: format!(
+                    "0x{}",
+                    hex::encode(relay_request.forwarder.as_bytes()),
+                ),
+                originating_address:
+                    get_originating_address_string(minting_param_struct),
                 eth_tx_amount: minting_param_struct.amount.to_string(),
                 originating_tx_hash:
                     minting_param_struct.originating_tx_hash.to_string(),
+                correlation_id: minting_param_struct.correlation_id.clone(),
                 eth_tx_recipient: format!(
                     "0x{}",
                     hex::encode(minting_param_struct.eth_address.as_bytes())
                 ),
-                signature_timestamp: SystemTime::now()
-                    .duration_since(UNIX_EPOCH)?
-                    .as_secs(),
+                signature_timestamp: get_unix_timestamp()?,
             }
         )
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct BtcOutput {
-    btc_latest_block_number: u64,
-    eth_signed_transactions: Vec<EthTxInfo>,
+pub struct BtcSubmissionReport {
+    pub btc_latest_block_number: u64,
+    pub eth_signed_transactions: Vec<EthTxInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eth_batch_minting_transaction: Option<EthBatchTxInfo>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub eth_relay_requests: Vec<EthRelayRequestInfo>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dry_run: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub eth_unsigned_mints: Vec<EthBatchMintInfo>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stage_timings: StageTimings,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped_items: SkippedItems,
+}
+
+fn get_eth_relay_request_info_from_relay_requests(
+    relay_requests: &EthRelayRequests,
+    minting_params: &MintingParams,
+    originating_info: &OriginatingInfo,
+) -> Result<Vec<EthRelayRequestInfo>> {
+    info!("✔ Getting ETH relay request info from relay requests...");
+    relay_requests
+        .iter()
+        .enumerate()
+        .map(|(i, relay_request)|
+            EthRelayRequestInfo::new(
+                relay_request,
+                &minting_params[i],
+                originating_info,
+            )
+        )
+        .collect::<Result<Vec<EthRelayRequestInfo>>>()
 }
 
 fn get_eth_signed_tx_info_from_eth_txs(
     eth_txs: &EthTransactions,
     minting_params: &MintingParams,
     eth_account_nonce: u64,
+    originating_info: &OriginatingInfo,
 ) -> Result<Vec<EthTxInfo>> {
     info!("✔ Getting ETH tx info from ETH txs...");
     let start_nonce = eth_account_nonce - eth_txs.len() as u64;
@@ -88,7 +276,12 @@ fn get_eth_signed_tx_info_from_eth_txs(
         .iter()
         .enumerate()
         .map(|(i, tx)|
-            EthTxInfo::new(tx, &minting_params[i], start_nonce + i as u64)
+            EthTxInfo::new(
+                tx,
+                &minting_params[i],
+                start_nonce + i as u64,
+                originating_info,
+            )
         )
         .collect::<Result<Vec<EthTxInfo>>>()
 }
@@ -99,19 +292,56 @@ pub fn create_btc_output_json_and_put_in_state<D>(
     where D: DatabaseInterface
 {
     info!("✔ Getting BTC output json and putting in state...");
+    let canon_block = get_btc_canon_block_from_db(&state.db)?;
+    let minting_params = canon_block.minting_params;
+    let is_batch_mint = should_batch_mint(&state.db, minting_params.len());
+    let originating_info = OriginatingInfo {
+        block_height: canon_block.height,
+        host_timestamp: state.host_timestamp.as_option().cloned(),
+    };
+    let eth_signed_txs = state.eth_signed_txs.as_option();
     Ok(serde_json::to_string(
-        &BtcOutput {
-            btc_latest_block_number: get_btc_latest_block_from_db(&state.db)?
-                .height,
-            eth_signed_transactions: match &state.eth_signed_txs {
-                None => vec![],
-                Some(txs) =>
+        &BtcSubmissionReport {
+            btc_latest_block_number: get_btc_latest_block_number(&state.db)?,
+            eth_signed_transactions: match eth_signed_txs {
+                Some(txs) if !is_batch_mint =>
                     get_eth_signed_tx_info_from_eth_txs(
                         txs,
-                        &get_btc_canon_block_from_db(&state.db)?.minting_params,
+                        &minting_params,
                         get_eth_account_nonce_from_db(&state.db)?,
+                        &originating_info,
                     )?,
-            }
+                _ => vec![],
+            },
+            eth_batch_minting_transaction: match eth_signed_txs {
+                Some(txs) if is_batch_mint && txs.len() == 1 =>
+                    Some(EthBatchTxInfo::new(
+                        &txs[0],
+                        &minting_params,
+                        get_eth_account_nonce_from_db(&state.db)? - 1,
+                        &originating_info,
+                    )?),
+                _ => None,
+            },
+            eth_relay_requests: match state.eth_relay_requests.as_option() {
+                None => vec![],
+                Some(relay_requests) =>
+                    get_eth_relay_request_info_from_relay_requests(
+                        relay_requests,
+                        &minting_params,
+                        &originating_info,
+                    )?,
+            },
+            dry_run: state.dry_run,
+            eth_unsigned_mints: match state.dry_run {
+                false => vec![],
+                true => minting_params
+                    .iter()
+                    .map(EthBatchMintInfo::new)
+                    .collect(),
+            },
+            stage_timings: state.stage_timings.clone(),
+            skipped_items: state.skipped_items.clone(),
         }
     )?)
         .and_then(|output| state.add_output_json_string(output))
@@ -0,0 +1,27 @@
+use crate::{
+    types::Result,
+    btc::btc_state::BtcState,
+    traits::DatabaseInterface,
+    eth::eth_database_utils::increment_eth_relay_nonce_in_db,
+};
+
+pub fn maybe_increment_eth_relay_nonce_in_db<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    match state.get_eth_relay_requests() {
+        Err(_) => {
+            info!("✔ Not incrementing ETH relay nonce - no requests signed!");
+            Ok(state)
+        }
+        Ok(relay_requests) => {
+            info!("✔ Incrementing ETH relay nonce by {}", relay_requests.len());
+            increment_eth_relay_nonce_in_db(
+                &state.db,
+                &(relay_requests.len() as u64),
+            )
+                .and_then(|_| Ok(state))
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
     types::Result,
-    errors::AppError,
+    init_report::InitReport,
     traits::DatabaseInterface,
     btc::{
         btc_state::BtcState,
@@ -31,15 +31,6 @@ impl BtcInitializationOutput {
     }
 }
 
-fn json_stringify(
-    output: BtcInitializationOutput
-) -> Result<String> {
-    match serde_json::to_string(&output) {
-        Ok(res) => Ok(res),
-        Err(err) => Err(AppError::Custom(err.to_string()))
-    }
-}
-
 pub fn get_btc_init_output_json<D>(
     state: BtcState<D>
 ) -> Result<String>
@@ -49,5 +40,5 @@ pub fn get_btc_init_output_json<D>(
         get_btc_address_from_db(&state.db)?,
         get_btc_latest_block_number(&state.db)?,
     )
-        .and_then(json_stringify)
+        .and_then(|output| InitReport::Btc(output).to_json_string())
 }
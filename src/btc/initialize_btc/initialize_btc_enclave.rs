@@ -1,6 +1,13 @@
 use crate::{
     types::Result,
+    init_report::InitReport,
     traits::DatabaseInterface,
+    core_version::maybe_put_core_version_info_in_db,
+    chain_state::{
+        db_step,
+        end_db_transaction,
+        start_db_transaction,
+    },
     btc::{
         btc_state::BtcState,
         add_btc_block_to_db::maybe_add_btc_block_to_db,
@@ -12,8 +19,11 @@ use crate::{
         validate_btc_block_header::validate_btc_block_header_in_state,
         validate_btc_difficulty::validate_difficulty_of_btc_block_in_state,
         btc_database_utils::{
-            end_btc_db_transaction,
-            start_btc_db_transaction,
+            put_btc_fee_in_db,
+            put_btc_network_in_db,
+            put_btc_difficulty_in_db,
+            put_btc_account_nonce_in_db,
+            put_btc_canon_to_tip_length_in_db,
         },
         validate_btc_proof_of_work::{
             validate_proof_of_work_of_btc_block_in_state,
@@ -27,12 +37,8 @@ use crate::{
             generate_btc_address::generate_and_store_btc_address,
             generate_btc_private_key::generate_and_store_btc_private_key,
             btc_init_utils::{
-                put_difficulty_threshold_in_db,
-                put_btc_fee_in_db_and_return_state,
-                put_btc_network_in_db_and_return_state,
-                put_btc_account_nonce_in_db_and_return_state,
+                get_btc_network_from_arg,
                 put_btc_tail_block_hash_in_db_and_return_state,
-                put_canon_to_tip_length_in_db_and_return_state,
             },
         }
     },
@@ -54,29 +60,21 @@ pub fn maybe_initialize_btc_enclave<D>(
             match is_btc_enclave_initialized(&state.db) {
                 true => {
                     info!("✔ BTC enclave already initialized!");
-                    Ok("{btc_enclave_initialized:true}".to_string())
+                    InitReport::btc_already_initialized().to_json_string()
                 }
                 false => {
                     info!("✔ Initializing enclave for BTC...");
-                    start_btc_db_transaction(state)
-                        .and_then(|state|
-                            put_difficulty_threshold_in_db(
-                                difficulty,
-                                state,
-                            )
-                        )
-                        .and_then(|state|
-                            put_btc_network_in_db_and_return_state(
-                                &network,
-                                state,
+                    start_db_transaction(state)
+                        .and_then(db_step(|db|
+                            put_btc_difficulty_in_db(db, &difficulty)
+                        ))
+                        .and_then(db_step(|db|
+                            put_btc_network_in_db(
+                                db,
+                                &get_btc_network_from_arg(&network),
                             )
-                        )
-                        .and_then(|state|
-                            put_btc_fee_in_db_and_return_state(
-                                fee,
-                                state,
-                            )
-                        )
+                        ))
+                        .and_then(db_step(|db| put_btc_fee_in_db(db, &fee)))
                         .and_then(|state|
                             parse_btc_block_and_id_and_put_in_state(
                                 block_json_string,
@@ -87,19 +85,21 @@ pub fn maybe_initialize_btc_enclave<D>(
                         .and_then(validate_difficulty_of_btc_block_in_state)
                         .and_then(validate_proof_of_work_of_btc_block_in_state)
                         .and_then(validate_btc_merkle_root)
-                        .and_then(|state|
-                            put_canon_to_tip_length_in_db_and_return_state(
-                                canon_to_tip_length,
-                                state,
+                        .and_then(db_step(|db|
+                            put_btc_canon_to_tip_length_in_db(
+                                db,
+                                &canon_to_tip_length,
                             )
-                        )
+                        ))
                         .and_then(maybe_set_btc_anchor_block_hash)
                         .and_then(maybe_set_btc_latest_block_hash)
                         .and_then(maybe_set_btc_canon_block_hash)
                         .and_then(put_btc_tail_block_hash_in_db_and_return_state)
                         .and_then(create_btc_block_in_db_format_and_put_in_state)
                         .and_then(maybe_add_btc_block_to_db)
-                        .and_then(put_btc_account_nonce_in_db_and_return_state)
+                        .and_then(db_step(|db|
+                            put_btc_account_nonce_in_db(db, &0)
+                        ))
                         .and_then(|state|
                             generate_and_store_btc_private_key(
                                 &network,
@@ -107,7 +107,8 @@ pub fn maybe_initialize_btc_enclave<D>(
                             )
                         )
                         .and_then(generate_and_store_btc_address)
-                        .and_then(end_btc_db_transaction)
+                        .and_then(db_step(maybe_put_core_version_info_in_db))
+                        .and_then(end_db_transaction)
                         .and_then(get_btc_init_output_json)
                 }
             }
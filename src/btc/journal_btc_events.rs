@@ -0,0 +1,92 @@
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    btc::{
+        btc_state::BtcState,
+        btc_database_utils::get_btc_canon_block_from_db,
+        sign_transactions::get_correlation_ids_of_eth_signed_txs,
+    },
+    event_journal::{
+        BridgeEvent,
+        BridgeEventType,
+        append_events_to_journal,
+    },
+};
+
+fn get_peg_in_events_from_state<D>(
+    state: &BtcState<D>
+) -> Result<Vec<BridgeEvent>>
+    where D: DatabaseInterface
+{
+    let block_hash = state.get_btc_block_and_id()?.id.to_string();
+    Ok(
+        state.minting_params
+            .iter()
+            .map(|params|
+                BridgeEvent::new(
+                    BridgeEventType::PegInDetected,
+                    block_hash.clone(),
+                    Some(params.originating_tx_hash.to_string()),
+                ).with_correlation_id(&params.correlation_id)
+            )
+            .collect()
+    )
+}
+
+// NOTE: A batch minting tx carries many mints, so one event's journaled per
+// mint a signed tx carries.
+fn get_mint_events_from_state<D>(
+    state: &BtcState<D>
+) -> Result<Vec<BridgeEvent>>
+    where D: DatabaseInterface
+{
+    match state.eth_signed_txs.as_option() {
+        None => Ok(vec![]),
+        Some(eth_txs) => {
+            let canon_block = get_btc_canon_block_from_db(&state.db)?;
+            let block_hash = canon_block.id.to_string();
+            Ok(
+                get_correlation_ids_of_eth_signed_txs(
+                    &state.db,
+                    eth_txs,
+                    &canon_block.minting_params,
+                )?
+                    .iter()
+                    .zip(eth_txs.iter())
+                    .flat_map(|(correlation_ids, eth_tx)| {
+                        let tx_hash = format!("0x{}", eth_tx.get_tx_hash());
+                        let block_hash = block_hash.clone();
+                        correlation_ids
+                            .iter()
+                            .map(move |correlation_id|
+                                BridgeEvent::new(
+                                    BridgeEventType::MintSigned,
+                                    block_hash.clone(),
+                                    Some(tx_hash.clone()),
+                                ).with_correlation_id(correlation_id)
+                            )
+                    })
+                    .collect()
+            )
+        }
+    }
+}
+
+pub fn maybe_journal_btc_bridge_events<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe journaling BTC bridge events...");
+    let events = [
+        get_peg_in_events_from_state(&state)?,
+        get_mint_events_from_state(&state)?,
+    ].concat();
+    match events.is_empty() {
+        true => {
+            info!("✔ No BTC bridge events to journal!");
+            Ok(state)
+        }
+        false => append_events_to_journal(&state.db, events).map(|_| state),
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    types::Result,
+    btc::btc_state::BtcState,
+    traits::DatabaseInterface,
+    eth::{
+        eth_types::EthPendingTxStatus,
+        eth_database_utils::{
+            put_eth_pending_tx_in_db,
+            get_eth_pending_txs_from_db,
+        },
+    },
+};
+
+pub fn maybe_mark_eth_pending_txs_as_reported<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Marking signed ETH pending txs as reported...");
+    get_eth_pending_txs_from_db(&state.db)?
+        .into_iter()
+        .filter(|pending_tx| pending_tx.status == EthPendingTxStatus::Signed)
+        .map(|mut pending_tx| {
+            pending_tx.status = EthPendingTxStatus::Reported;
+            put_eth_pending_tx_in_db(&state.db, &pending_tx)
+        })
+        .collect::<Result<()>>()
+        .and_then(|_| Ok(state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        eth::{
+            eth_types::EthPendingTx,
+            eth_database_utils::{
+                get_eth_pending_tx_from_db,
+                add_eth_pending_tx_to_queue_in_db,
+            },
+        },
+    };
+
+    #[test]
+    fn should_mark_previously_signed_eth_pending_txs_as_reported() {
+        let pending_tx = EthPendingTx {
+            nonce: 1,
+            receipt_block: None,
+            correlation_ids: vec![],
+            replaced_tx_hashes: vec![],
+            tx_hex: "c0ffee".to_string(),
+            tx_hash: "0xdecaf".to_string(),
+            status: EthPendingTxStatus::Signed,
+        };
+        let state = BtcState::init(get_test_database());
+        add_eth_pending_tx_to_queue_in_db(&state.db, &pending_tx).unwrap();
+        let result_state = maybe_mark_eth_pending_txs_as_reported(state)
+            .unwrap();
+        assert_eq!(
+            get_eth_pending_tx_from_db(&result_state.db, 1).unwrap().status,
+            EthPendingTxStatus::Reported,
+        );
+    }
+}
@@ -4,18 +4,30 @@ pub mod btc_utils;
 pub mod btc_crypto;
 pub mod filter_utxos;
 pub mod btc_constants;
+#[cfg(test)]
 pub mod btc_test_utils;
 pub mod initialize_btc;
 pub mod parse_btc_block;
+pub mod parse_bitcoind_block;
 pub mod btc_transaction;
+pub mod verify_btc_tx_scripts;
 pub mod save_utxos_to_db;
 pub mod submit_btc_block;
 pub mod sign_transactions;
+pub mod sign_relay_requests;
 pub mod btc_database_utils;
 pub mod add_btc_block_to_db;
 pub mod get_btc_output_json;
+pub mod validate_btc_block;
+pub mod reprocess_btc_block;
 pub mod increment_eth_nonce;
+pub mod journal_btc_events;
+pub mod preview_minting_txs;
+pub mod queue_minting_params;
 pub mod filter_minting_params;
+pub mod validation_checkpoint;
+pub mod increment_eth_relay_nonce;
+pub mod deduct_peg_in_fees;
 pub mod update_btc_linker_hash;
 pub mod filter_p2sh_deposit_txs;
 pub mod check_btc_parent_exists;
@@ -23,17 +35,22 @@ pub mod validate_btc_difficulty;
 pub mod validate_btc_merkle_root;
 pub mod set_btc_canon_block_hash;
 pub mod get_deposit_info_hash_map;
+pub mod filter_processed_deposits;
 pub mod set_btc_latest_block_hash;
 pub mod set_btc_anchor_block_hash;
 pub mod validate_btc_block_header;
 pub mod remove_old_btc_tail_block;
 pub mod get_btc_block_in_db_format;
 pub mod update_btc_tail_block_hash;
+pub mod mark_eth_pending_txs_as_reported;
 pub mod validate_btc_proof_of_work;
 pub mod update_btc_canon_block_hash;
 pub mod extract_utxos_from_p2sh_txs;
 pub mod filter_op_return_deposit_txs;
 pub mod update_btc_latest_block_hash;
+pub mod save_eth_pending_txs_to_db;
+pub mod save_processed_deposits_to_db;
+pub mod save_eth_mint_tx_records_to_db;
 pub mod extract_utxos_from_op_return_txs;
 pub mod remove_minting_params_from_canon_block;
 pub mod parse_minting_params_from_p2sh_deposits;
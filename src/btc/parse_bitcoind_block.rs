@@ -0,0 +1,131 @@
+use crate::{
+    types::Result,
+    errors::AppError,
+    traits::DatabaseInterface,
+    btc::{
+        btc_state::BtcState,
+        parse_btc_block::put_btc_block_and_txs_json_in_state,
+        btc_types::{
+            BtcBlockJson,
+            BitcoindBlockJson,
+            BtcBlockAndTxsJson,
+            BitcoindSubmissionJson,
+        },
+    },
+};
+
+// NOTE: Only the genesis block lacks a `previousblockhash`.
+const NULL_BTC_BLOCK_HASH: &str =
+    "0000000000000000000000000000000000000000000000000000000000000000";
+
+pub fn parse_bitcoind_submission_string_to_json(
+    bitcoind_submission_json_string: &str
+) -> Result<BitcoindSubmissionJson> {
+    trace!("✔ Parsing JSON string to `BitcoindSubmissionJson`...");
+    match serde_json::from_str(bitcoind_submission_json_string) {
+        Ok(json) => Ok(json),
+        Err(e) => Err(AppError::InvalidSubmission(e.to_string()))
+    }
+}
+
+fn convert_bitcoind_block_json_to_btc_block_json(
+    bitcoind_block_json: BitcoindBlockJson
+) -> Result<BtcBlockJson> {
+    Ok(
+        BtcBlockJson {
+            bits: u32::from_str_radix(&bitcoind_block_json.bits, 16)?,
+            id: bitcoind_block_json.hash,
+            nonce: bitcoind_block_json.nonce,
+            version: bitcoind_block_json.version as u32,
+            height: bitcoind_block_json.height,
+            timestamp: bitcoind_block_json.time,
+            merkle_root: bitcoind_block_json.merkleroot,
+            previousblockhash: bitcoind_block_json
+                .previousblockhash
+                .unwrap_or_else(|| NULL_BTC_BLOCK_HASH.to_string()),
+        }
+    )
+}
+
+pub fn convert_bitcoind_submission_json_to_btc_block_and_txs_json(
+    bitcoind_submission_json: BitcoindSubmissionJson
+) -> Result<BtcBlockAndTxsJson> {
+    trace!("✔ Converting `bitcoind` JSON to `BtcBlockAndTxsJson`...");
+    Ok(
+        BtcBlockAndTxsJson {
+            transactions: bitcoind_submission_json
+                .block
+                .tx
+                .iter()
+                .map(|tx| tx.hex.clone())
+                .collect(),
+            block: convert_bitcoind_block_json_to_btc_block_json(
+                bitcoind_submission_json.block
+            )?,
+            host_timestamp: bitcoind_submission_json.host_timestamp,
+            host_eth_gas_price: bitcoind_submission_json.host_eth_gas_price,
+            deposit_address_list: bitcoind_submission_json
+                .deposit_address_list,
+        }
+    )
+}
+
+pub fn parse_bitcoind_block_and_put_in_state<D>(
+    block_json: String,
+    state: BtcState<D>,
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Parsing `bitcoind` BTC block...");
+    parse_bitcoind_submission_string_to_json(&block_json)
+        .and_then(convert_bitcoind_submission_json_to_btc_block_and_txs_json)
+        .and_then(|json| put_btc_block_and_txs_json_in_state(json, state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::btc::{
+        btc_types::BitcoindTxJson,
+        parse_btc_block::parse_btc_block_and_tx_json_to_struct,
+        btc_test_utils::{
+            get_sample_btc_block_json,
+            get_sample_btc_block_and_id,
+        },
+    };
+
+    fn get_sample_bitcoind_submission_json() -> BitcoindSubmissionJson {
+        let json = get_sample_btc_block_json().unwrap();
+        BitcoindSubmissionJson {
+            host_timestamp: None,
+            host_eth_gas_price: None,
+            deposit_address_list: json.deposit_address_list,
+            block: BitcoindBlockJson {
+                hash: json.block.id,
+                height: json.block.height,
+                version: json.block.version as i32,
+                merkleroot: json.block.merkle_root,
+                time: json.block.timestamp,
+                nonce: json.block.nonce,
+                bits: format!("{:08x}", json.block.bits),
+                previousblockhash: Some(json.block.previousblockhash),
+                tx: json
+                    .transactions
+                    .into_iter()
+                    .map(|hex| BitcoindTxJson { hex })
+                    .collect(),
+            },
+        }
+    }
+
+    #[test]
+    fn should_convert_bitcoind_submission_json_to_btc_block() {
+        let expected_result = get_sample_btc_block_and_id().unwrap();
+        let result = convert_bitcoind_submission_json_to_btc_block_and_txs_json(
+            get_sample_bitcoind_submission_json()
+        )
+            .and_then(parse_btc_block_and_tx_json_to_struct)
+            .unwrap();
+        assert_eq!(result, expected_result);
+    }
+}
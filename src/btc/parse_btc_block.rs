@@ -18,10 +18,13 @@ use crate::{
             BtcBlockJson,
             BtcBlockAndId,
             DepositInfoList,
+            BtcBlockJsonRef,
             BtcBlockAndTxsJson,
             DepositAddressInfo,
+            BtcBlockAndTxsJsonRef,
             DepositAddressInfoJson,
             DepositAddressJsonList,
+            DepositAddressInfoJsonRef,
         },
     },
 };
@@ -58,13 +61,19 @@ pub fn parse_btc_block_json_to_btc_block(
     )
 }
 
+#[cfg(any(
+    test,
+    feature="fuzzing",
+    feature="benchmarks",
+    feature="fixture-converter",
+))]
 pub fn parse_btc_block_string_to_json(
     btc_block_json_string: &str
 ) -> Result<BtcBlockAndTxsJson> {
     trace!("✔ Parsing JSON string to `BtcBlockAndTxsJson`...");
     match serde_json::from_str(btc_block_json_string) {
         Ok(json) => Ok(json),
-        Err(e) => Err(AppError::Custom(e.to_string()))
+        Err(e) => Err(AppError::InvalidSubmission(e.to_string()))
     }
 }
 
@@ -92,7 +101,7 @@ fn parse_deposit_list_json_to_deposit_info(
     )
 }
 
-fn parse_deposit_info_jsons_to_deposit_info_list(
+pub fn parse_deposit_info_jsons_to_deposit_info_list(
     deposit_address_json_list: &DepositAddressJsonList
 ) -> Result<DepositInfoList> {
     deposit_address_json_list
@@ -119,6 +128,112 @@ pub fn parse_btc_block_and_tx_json_to_struct(
     )
 }
 
+fn parse_btc_block_json_ref_to_block_header(
+    btc_block_json: &BtcBlockJsonRef
+) -> Result<BtcBlockHeader> {
+    trace!("✔ Parsing `BtcBlockJsonRef` to `BtcBlockHeader`...");
+    Ok(
+        BtcBlockHeader::new(
+            btc_block_json.timestamp,
+            btc_block_json.bits,
+            btc_block_json.nonce,
+            btc_block_json.version,
+            sha256d::Hash::from_str(&btc_block_json.merkle_root)?,
+            sha256d::Hash::from_str(&btc_block_json.previousblockhash)?,
+        )
+    )
+}
+
+fn convert_hex_str_to_btc_transaction(hex_str: &str) -> Result<BtcTransaction> {
+    Ok(deserialize::<BtcTransaction>(&hex::decode(hex_str)?)?)
+}
+
+fn parse_deposit_list_json_ref_to_deposit_info(
+    deposit_address_info_json: &DepositAddressInfoJsonRef
+) -> Result<DepositAddressInfo> {
+    DepositAddressInfo::new(
+        &deposit_address_info_json.nonce,
+        &deposit_address_info_json.eth_address,
+        &deposit_address_info_json.btc_deposit_address,
+        &deposit_address_info_json.eth_address_and_nonce_hash,
+    )
+}
+
+pub fn parse_btc_block_string_to_json_ref(
+    btc_block_json_string: &str
+) -> Result<BtcBlockAndTxsJsonRef> {
+    trace!("✔ Parsing JSON string to `BtcBlockAndTxsJsonRef`...");
+    match serde_json::from_str(btc_block_json_string) {
+        Ok(json) => Ok(json),
+        Err(e) => Err(AppError::InvalidSubmission(e.to_string()))
+    }
+}
+
+pub fn parse_btc_block_and_tx_json_ref_to_struct(
+    btc_block_json: &BtcBlockAndTxsJsonRef
+) -> Result<BtcBlockAndId> {
+    trace!("✔ Parsing `BtcBlockAndTxsJsonRef` to `BtcBlockAndId`...");
+    Ok(
+        BtcBlockAndId {
+            height: btc_block_json.block.height,
+            id: sha256d::Hash::from_str(&btc_block_json.block.id)?,
+            deposit_address_list: btc_block_json
+                .deposit_address_list
+                .iter()
+                .map(parse_deposit_list_json_ref_to_deposit_info)
+                .collect::<Result<DepositInfoList>>()?,
+            block: BtcBlock::new(
+                parse_btc_block_json_ref_to_block_header(
+                    &btc_block_json.block
+                )?,
+                btc_block_json
+                    .transactions
+                    .iter()
+                    .map(|hex_str| convert_hex_str_to_btc_transaction(hex_str))
+                    .collect::<Result<Vec<BtcTransaction>>>()?,
+            ),
+        }
+    )
+}
+
+fn maybe_add_host_params_to_state<D>(
+    host_timestamp: Option<u64>,
+    host_eth_gas_price: Option<u64>,
+    state: BtcState<D>,
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    match host_timestamp {
+        Some(timestamp) => state.add_host_timestamp(timestamp),
+        None => Ok(state),
+    }
+        .and_then(|state| match host_eth_gas_price {
+            Some(gas_price) => state.add_host_eth_gas_price(gas_price),
+            None => Ok(state),
+        })
+}
+
+pub fn put_btc_block_and_txs_json_in_state<D>(
+    json: BtcBlockAndTxsJson,
+    state: BtcState<D>,
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    let host_timestamp = json.host_timestamp;
+    let host_eth_gas_price = json.host_eth_gas_price;
+    parse_btc_block_and_tx_json_to_struct(json)
+        .and_then(|result| state.add_btc_block_and_id(result))
+        .and_then(|state|
+            maybe_add_host_params_to_state(
+                host_timestamp,
+                host_eth_gas_price,
+                state,
+            )
+        )
+}
+
+// NOTE: Parses via the borrowed JSON types, so the transactions' hex is decoded
+// in place rather than first being copied into a `String` per transaction.
 pub fn parse_btc_block_and_id_and_put_in_state<D>(
     block_json: String,
     state: BtcState<D>,
@@ -126,17 +241,26 @@ pub fn parse_btc_block_and_id_and_put_in_state<D>(
     where D: DatabaseInterface
 {
     info!("✔ Parsing BTC block...");
-    parse_btc_block_string_to_json(&block_json)
-        .and_then(parse_btc_block_and_tx_json_to_struct)
+    let json = parse_btc_block_string_to_json_ref(&block_json)?;
+    parse_btc_block_and_tx_json_ref_to_struct(&json)
         .and_then(|result| state.add_btc_block_and_id(result))
+        .and_then(|state|
+            maybe_add_host_params_to_state(
+                json.host_timestamp,
+                json.host_eth_gas_price,
+                state,
+            )
+        )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs::read_to_string;
     use crate::btc::btc_test_utils::{
         get_sample_btc_block_json,
         get_sample_btc_block_json_string,
+        SAMPLE_TESTNET_BTC_BLOCK_JSON_PATH_6,
     };
 
     #[test]
@@ -156,6 +280,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_parse_borrowed_json_as_owned_json_is_parsed() {
+        let string = get_sample_btc_block_json_string();
+        let expected_result = parse_btc_block_string_to_json(&string)
+            .and_then(parse_btc_block_and_tx_json_to_struct)
+            .unwrap();
+        let result = parse_btc_block_string_to_json_ref(&string)
+            .and_then(|json| parse_btc_block_and_tx_json_ref_to_struct(&json))
+            .unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_parse_borrowed_deposit_address_list() {
+        let string = read_to_string(SAMPLE_TESTNET_BTC_BLOCK_JSON_PATH_6)
+            .unwrap();
+        let expected_result = parse_btc_block_string_to_json(&string)
+            .and_then(parse_btc_block_and_tx_json_to_struct)
+            .unwrap();
+        let result = parse_btc_block_string_to_json_ref(&string)
+            .and_then(|json| parse_btc_block_and_tx_json_ref_to_struct(&json))
+            .unwrap();
+        assert!(!result.deposit_address_list.is_empty());
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_parse_borrowed_json_with_escaped_strings() {
+        let string = read_to_string(SAMPLE_TESTNET_BTC_BLOCK_JSON_PATH_6)
+            .unwrap();
+        let escaped_string = string
+            .replace(r#""id":"0"#, r#""id":"\u0030"#)
+            .replace(r#""transactions":["0"#, r#""transactions":["\u0030"#)
+            .replace(r#""eth_address":"0x"#, r#""eth_address":"\u0030x"#);
+        assert_ne!(escaped_string, string);
+        let expected_result = parse_btc_block_string_to_json_ref(&string)
+            .and_then(|json| parse_btc_block_and_tx_json_ref_to_struct(&json))
+            .unwrap();
+        let result = parse_btc_block_string_to_json_ref(&escaped_string)
+            .and_then(|json| parse_btc_block_and_tx_json_ref_to_struct(&json))
+            .unwrap();
+        assert_eq!(result, expected_result);
+    }
+
     #[test]
     fn should_not_panic_deserializing_tx() {
         let tx_bytes = hex::decode("0200000000010117c33a062c8d0c2ce104c9988599f6ba382ff9f786ad48519425e39af23da9880000000000feffffff022c920b00000000001976a914be8a09363cd4719b1c05b2703797ca890b718b5088acf980d30d000000001600147448bbdfe47ec14f27c68393e766567ac7c9c77102473044022073fc2b43d5c5f56d7bc92b47a28db989e04988411721db96fb0eea6689fb83ab022034b7ce2729e867962891fec894210d0faf538b971d3ae9059ebb34358209ec9e012102a51b8eb0eb8ef6b2a421fb1aae3d7308e6cdae165b90f78074c2493af98e3612c43b0900")
@@ -185,4 +353,36 @@ mod tests {
             panic!("Error parsing deposit info json: {}", e);
         }
     }
+
+    #[test]
+    fn should_parse_host_timestamp_from_btc_block_json() {
+        let host_timestamp = 1584696813;
+        let mut json: serde_json::Value = serde_json::from_str(
+            &get_sample_btc_block_json_string()
+        ).unwrap();
+        json["host_timestamp"] = serde_json::Value::from(host_timestamp);
+        let result = parse_btc_block_string_to_json(&json.to_string())
+            .unwrap();
+        assert_eq!(result.host_timestamp, Some(host_timestamp));
+    }
+
+    #[test]
+    fn should_default_host_timestamp_to_none() {
+        let result = parse_btc_block_string_to_json(
+            &get_sample_btc_block_json_string()
+        ).unwrap();
+        assert_eq!(result.host_timestamp, None);
+    }
+
+    #[test]
+    fn should_parse_host_eth_gas_price_from_btc_block_json() {
+        let gas_price = 20_000_000_000;
+        let mut json: serde_json::Value = serde_json::from_str(
+            &get_sample_btc_block_json_string()
+        ).unwrap();
+        json["host_eth_gas_price"] = serde_json::Value::from(gas_price);
+        let result = parse_btc_block_string_to_json(&json.to_string())
+            .unwrap();
+        assert_eq!(result.host_eth_gas_price, Some(gas_price));
+    }
 }
@@ -13,16 +13,22 @@ use bitcoin::{
         },
         transaction::{
             TxIn as BtcTxIn,
-            TxOut as BtcTxOut,
             Transaction as BtcTransaction,
         },
     },
-    consensus::encode::serialize as btc_serialize,
 };
 use crate::{
+    errors::AppError,
     traits::DatabaseInterface,
-    constants::SAFE_ETH_ADDRESS,
-    utils::convert_satoshis_to_ptoken,
+    eth::eth_database_utils::get_eth_host_token_decimals_from_db,
+    safe_addresses::{
+        SafeAddressTier,
+        get_safe_eth_address_for_tier_from_db,
+    },
+    token_decimals::{
+        MINTING_ROUNDING_MODE,
+        convert_satoshis_to_host_token_amount,
+    },
     types::{
         Bytes,
         Result,
@@ -34,10 +40,7 @@ use crate::{
             get_btc_address_from_db,
             get_btc_network_from_db,
         },
-        btc_utils::{
-            get_safe_eth_address,
-            get_pay_to_pub_key_hash_script,
-        },
+        btc_utils::get_pay_to_pub_key_hash_script,
         btc_types::{
             MintingParams,
             BtcTransactions,
@@ -46,11 +49,9 @@ use crate::{
     },
 };
 
-pub const NUM_BYTES_IN_SCRIPT: u8 = 22;
 pub const OP_RETURN_AS_DECIMAL: u8 = 106;
-pub const NUM_BYTES_IN_ETH_ADDRESS: u8 = 20;
-pub const NUM_BYTES_IN_SCRIPT_WITH_LEN_PREFIX: usize = 23;
-pub const NUM_PREFIX_BYTES_IN_SERIALIZED_OP_RETURN: usize = 3;
+pub const NUM_BYTES_IN_ETH_ADDRESS: usize = 20;
+pub const MAX_NUM_BYTES_IN_OP_RETURN_DATA: usize = 80;
 
 fn extract_spender_address_from_p2pkh_input(
     input: &BtcTxIn,
@@ -83,32 +84,39 @@ fn extract_spender_address_from_p2pkh_input(
     )
 }
 
-fn parse_eth_address_from_op_return_script(
-    op_return_script: &BtcScript
-) -> EthAddress {
-    trace!("✔ Parsing ETH address from script: {}", op_return_script);
-    EthAddress::from_slice(
-        &btc_serialize(op_return_script)[
-            NUM_PREFIX_BYTES_IN_SERIALIZED_OP_RETURN..
-        ]
-    )
+// NOTE: The desired `OP_RETURN` pushes a single chunk of data, the first 20
+// bytes of which are the ETH address to mint to, w/ any remainder being the
+// user data to pass on to the pToken contract.
+fn get_op_return_data(op_return_script: &BtcScript) -> Option<Bytes> {
+    match op_return_script.as_bytes().first() {
+        Some(&OP_RETURN_AS_DECIMAL) => match op_return_script
+            .iter(false)
+            .skip(1)
+            .collect::<Vec<Instruction>>()
+            .as_slice()
+        {
+            [Instruction::PushBytes(data)] => Some(data.to_vec()),
+            _ => None,
+        },
+        _ => None,
+    }
 }
 
-fn serialized_script_pubkey_is_desired_op_return(
-    serialized_script: &Bytes
-) -> bool {
-    serialized_script.len() == NUM_BYTES_IN_SCRIPT_WITH_LEN_PREFIX &&
-    serialized_script[0] == NUM_BYTES_IN_SCRIPT &&
-    serialized_script[1] == OP_RETURN_AS_DECIMAL &&
-    serialized_script[2] == NUM_BYTES_IN_ETH_ADDRESS
+fn op_return_data_is_desired_length(op_return_data: &[u8]) -> bool {
+    op_return_data.len() >= NUM_BYTES_IN_ETH_ADDRESS &&
+    op_return_data.len() <= MAX_NUM_BYTES_IN_OP_RETURN_DATA
 }
 
-fn output_is_desired_op_return(
-    output: &BtcTxOut
-) -> bool {
-    serialized_script_pubkey_is_desired_op_return(
-        &btc_serialize(&output.script_pubkey)
-    )
+fn parse_eth_address_and_user_data_from_op_return_script(
+    op_return_script: &BtcScript
+) -> Option<(EthAddress, Bytes)> {
+    trace!("✔ Parsing ETH address from script: {}", op_return_script);
+    get_op_return_data(op_return_script)
+        .filter(|data| op_return_data_is_desired_length(data))
+        .map(|data| (
+            EthAddress::from_slice(&data[..NUM_BYTES_IN_ETH_ADDRESS]),
+            data[NUM_BYTES_IN_ETH_ADDRESS..].to_vec(),
+        ))
 }
 
 fn sum_deposit_values_from_tx_outputs(
@@ -124,33 +132,49 @@ fn sum_deposit_values_from_tx_outputs(
         .sum::<u64>()
 }
 
-fn get_eth_address_from_op_return_in_tx_else_safe_address(
+fn get_first_deposit_output_index(
     transaction: &BtcTransaction,
-) -> EthAddress {
-    let maybe_op_return = transaction
+    target_deposit_script: &BtcScript,
+) -> Result<usize> {
+    transaction
         .output
         .iter()
-        .cloned()
-        .filter(output_is_desired_op_return)
-        .collect::<Vec<BtcTxOut>>();
+        .position(|output| &output.script_pubkey == target_deposit_script)
+        .ok_or_else(|| AppError::NotFound(
+            format!("✘ No deposit output in tx: {}!", transaction.txid())
+        ))
+}
 
-    match maybe_op_return.len() {
-        0 => {
+fn get_eth_address_and_user_data_from_tx_else_safe_address(
+    transaction: &BtcTransaction,
+    safe_eth_address: &EthAddress,
+) -> (EthAddress, Bytes) {
+    let maybe_address_and_user_data = transaction
+        .output
+        .iter()
+        .find_map(|output|
+            parse_eth_address_and_user_data_from_op_return_script(
+                &output.script_pubkey
+            )
+        );
+    match maybe_address_and_user_data {
+        None => {
             info!(
                 "✔ No address found, default to safe address: 0x{}",
-                hex::encode(SAFE_ETH_ADDRESS)
+                hex::encode(safe_eth_address)
             );
-            get_safe_eth_address()
+            (*safe_eth_address, vec![])
         }
-        _ => {
-            let address = parse_eth_address_from_op_return_script(
-                &maybe_op_return[0].script_pubkey
-            );
+        Some((address, user_data)) => {
             info!(
                 "✔ Address parsed from `op_return` script: 0x{}",
                 hex::encode(address)
             );
-            address
+            info!(
+                "✔ User data parsed from `op_return` script: 0x{}",
+                hex::encode(&user_data)
+            );
+            (address, user_data)
         }
     }
 }
@@ -159,13 +183,22 @@ fn parse_minting_param_struct_from_tx(
     target_deposit_script: &BtcScript,
     tx: &BtcTransaction,
     btc_network: BtcNetwork,
+    token_decimals: u32,
+    safe_eth_address: &EthAddress,
 ) -> Result<MintingParamStruct> {
+    let (eth_address, user_data) =
+        get_eth_address_and_user_data_from_tx_else_safe_address(
+            &tx,
+            safe_eth_address,
+        );
     Ok(
         MintingParamStruct::new(
-            convert_satoshis_to_ptoken(
+            convert_satoshis_to_host_token_amount(
                 sum_deposit_values_from_tx_outputs(&tx, &target_deposit_script),
-            ),
-            get_eth_address_from_op_return_in_tx_else_safe_address(&tx),
+                token_decimals,
+                &MINTING_ROUNDING_MODE,
+            )?,
+            eth_address,
             tx.txid(),
             // NOTE: Currently not supporting the getting of the origin from
             // witness data.
@@ -180,6 +213,10 @@ fn parse_minting_param_struct_from_tx(
                 }
             }
         )
+            .with_user_data(user_data)
+            .with_correlation_id(
+                get_first_deposit_output_index(&tx, &target_deposit_script)?
+            )
     )
 }
 
@@ -187,6 +224,8 @@ fn parse_minting_params_from_txs(
     target_deposit_script: &BtcScript,
     op_return_deposit_containing_transactions: &BtcTransactions,
     btc_network: BtcNetwork,
+    token_decimals: u32,
+    safe_eth_address: &EthAddress,
 ) -> Result<MintingParams> {
     trace!(
         "✔ Parsing minting params from target script: {}",
@@ -198,7 +237,9 @@ fn parse_minting_params_from_txs(
              parse_minting_param_struct_from_tx(
                  target_deposit_script,
                  tx,
-                 btc_network
+                 btc_network,
+                 token_decimals,
+                 safe_eth_address,
              )
         )
         .collect::<Result<Vec<MintingParamStruct>>>()
@@ -217,6 +258,11 @@ pub fn parse_minting_params_from_op_return_deposits_and_add_to_state<D>(
                 &target_deposit_script,
                 state.get_op_return_deposit_txs()?,
                 get_btc_network_from_db(&state.db)?,
+                get_eth_host_token_decimals_from_db(&state.db)?,
+                &get_safe_eth_address_for_tier_from_db(
+                    &state.db,
+                    SafeAddressTier::MalformedMetadata,
+                )?,
             )
         )
         .and_then(|minting_params| state.add_minting_params(minting_params))
@@ -225,19 +271,29 @@ pub fn parse_minting_params_from_op_return_deposits_and_add_to_state<D>(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::btc::{
-        filter_op_return_deposit_txs::{
-            filter_txs_for_op_return_deposits
-        },
-        btc_test_utils::{
-            get_sample_btc_tx,
-            get_sample_btc_block_n,
-            get_sample_btc_private_key,
-            get_sample_btc_op_return_tx,
-            get_sample_op_return_output,
-            get_sample_pay_to_pub_key_hash_script,
-            get_sample_op_return_btc_block_and_txs,
-            SAMPLE_OP_RETURN_TRANSACTION_OUTPUT_INDEX
+    use bitcoin::blockdata::{
+        opcodes,
+        transaction::TxOut as BtcTxOut,
+        script::Builder as BtcScriptBuilder,
+    };
+    use crate::{
+        utils::convert_satoshis_to_ptoken,
+        constants::PTOKEN_ERC777_NUM_DECIMALS,
+        btc::{
+            btc_utils::get_safe_eth_address,
+            filter_op_return_deposit_txs::{
+                filter_txs_for_op_return_deposits
+            },
+            btc_test_utils::{
+                get_sample_btc_tx,
+                get_sample_btc_block_n,
+                get_sample_btc_private_key,
+                get_sample_btc_op_return_tx,
+                get_sample_op_return_output,
+                get_sample_pay_to_pub_key_hash_script,
+                get_sample_op_return_btc_block_and_txs,
+                SAMPLE_OP_RETURN_TRANSACTION_OUTPUT_INDEX
+            },
         },
     };
 
@@ -247,16 +303,29 @@ mod tests {
         )
     }
 
+    fn output_is_desired_op_return(output: &BtcTxOut) -> bool {
+        parse_eth_address_and_user_data_from_op_return_script(
+            &output.script_pubkey
+        ).is_some()
+    }
+
+    fn get_sample_op_return_script_with_user_data(
+        user_data: &[u8]
+    ) -> BtcScript {
+        BtcScriptBuilder::new()
+            .push_opcode(opcodes::all::OP_RETURN)
+            .push_slice(
+                &[get_expected_eth_address().as_bytes(), user_data].concat()
+            )
+            .into_script()
+    }
+
     #[test]
-    fn serialized_script_pubkey_should_be_desired_op_return() {
+    fn should_get_op_return_data() {
         let op_return_output = get_sample_op_return_output();
-        let serialized_output_script = btc_serialize(
-            &op_return_output.script_pubkey
-        );
-        let result = serialized_script_pubkey_is_desired_op_return(
-            &serialized_output_script
-        );
-        assert!(result);
+        let result = get_op_return_data(&op_return_output.script_pubkey)
+            .unwrap();
+        assert_eq!(result, get_expected_eth_address().as_bytes().to_vec());
     }
 
     #[test]
@@ -287,8 +356,62 @@ mod tests {
         let expected_result = get_expected_eth_address();
         let script = get_sample_op_return_output()
             .script_pubkey;
-        let result = parse_eth_address_from_op_return_script(&script);
-        assert!(result == expected_result);
+        let (address, user_data) =
+            parse_eth_address_and_user_data_from_op_return_script(&script)
+                .unwrap();
+        assert!(address == expected_result);
+        assert!(user_data.is_empty());
+    }
+
+    #[test]
+    fn should_parse_eth_address_and_user_data_from_op_return_script() {
+        let expected_user_data = hex::decode("c0ffee").unwrap();
+        let script = get_sample_op_return_script_with_user_data(
+            &expected_user_data
+        );
+        let (address, user_data) =
+            parse_eth_address_and_user_data_from_op_return_script(&script)
+                .unwrap();
+        assert_eq!(address, get_expected_eth_address());
+        assert_eq!(user_data, expected_user_data);
+    }
+
+    #[test]
+    fn should_parse_op_return_script_with_max_length_user_data() {
+        let user_data = vec![
+            0xc0;
+            MAX_NUM_BYTES_IN_OP_RETURN_DATA - NUM_BYTES_IN_ETH_ADDRESS
+        ];
+        let script = get_sample_op_return_script_with_user_data(&user_data);
+        let result =
+            parse_eth_address_and_user_data_from_op_return_script(&script)
+                .unwrap();
+        assert_eq!(result.1, user_data);
+    }
+
+    #[test]
+    fn should_not_parse_op_return_script_with_too_much_data() {
+        let user_data = vec![
+            0xc0;
+            MAX_NUM_BYTES_IN_OP_RETURN_DATA - NUM_BYTES_IN_ETH_ADDRESS + 1
+        ];
+        let script = get_sample_op_return_script_with_user_data(&user_data);
+        assert!(
+            parse_eth_address_and_user_data_from_op_return_script(&script)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn should_not_parse_op_return_script_with_too_little_data() {
+        let script = BtcScriptBuilder::new()
+            .push_opcode(opcodes::all::OP_RETURN)
+            .push_slice(&[0xc0; NUM_BYTES_IN_ETH_ADDRESS - 1])
+            .into_script();
+        assert!(
+            parse_eth_address_and_user_data_from_op_return_script(&script)
+                .is_none()
+        );
     }
 
     #[test]
@@ -300,13 +423,25 @@ mod tests {
         assert!(result == expected_result);
     }
 
+    #[test]
+    fn should_fail_to_get_first_deposit_output_index_if_none() {
+        let tx = get_sample_btc_op_return_tx();
+        let target_script = get_sample_pay_to_pub_key_hash_script();
+        assert!(get_first_deposit_output_index(&tx, &target_script).is_ok());
+        assert!(
+            get_first_deposit_output_index(&tx, &BtcScript::new()).is_err()
+        );
+    }
+
     #[test]
     fn should_get_eth_address_from_op_return_in_tx_else_safe_address() {
         let expected_result = get_expected_eth_address();
         let tx = get_sample_btc_op_return_tx();
-        let result = get_eth_address_from_op_return_in_tx_else_safe_address(
-            &tx
-        );
+        let (result, _) =
+            get_eth_address_and_user_data_from_tx_else_safe_address(
+                &tx,
+                &get_safe_eth_address(),
+            );
         assert!(result == expected_result);
     }
 
@@ -314,10 +449,13 @@ mod tests {
     fn should_default_to_safe_address_if_no_op_return() {
         let tx_no_op_return = get_sample_btc_tx();
         let expected_result = get_safe_eth_address();
-        let result = get_eth_address_from_op_return_in_tx_else_safe_address(
-            &tx_no_op_return
-        );
+        let (result, user_data) =
+            get_eth_address_and_user_data_from_tx_else_safe_address(
+                &tx_no_op_return,
+                &get_safe_eth_address(),
+            );
         assert!(result == expected_result);
+        assert!(user_data.is_empty());
     }
 
     #[test]
@@ -340,6 +478,8 @@ mod tests {
             &target_deposit_script,
             &filtered_txs,
             network,
+            PTOKEN_ERC777_NUM_DECIMALS,
+            &get_safe_eth_address(),
         ).unwrap();
         assert!(result.len() == 1);
         assert!(result[0].amount == expected_value);
@@ -385,6 +525,8 @@ mod tests {
             &target_deposit_script,
             &tx,
             network,
+            PTOKEN_ERC777_NUM_DECIMALS,
+            &get_safe_eth_address(),
         ).unwrap();
         assert!(result.amount == expected_value);
         assert!(result.eth_address == expected_address);
@@ -414,6 +556,8 @@ mod tests {
             &target_deposit_script,
             &tx,
             network,
+            PTOKEN_ERC777_NUM_DECIMALS,
+            &get_safe_eth_address(),
         ).unwrap();
         assert!(result.amount == expected_value);
         assert!(result.eth_address == expected_eth_address);
@@ -424,6 +568,24 @@ mod tests {
         assert!(address.to_string() == expected_origin_address);
     }
 
+    #[test]
+    fn should_parse_minting_params_w_user_data_from_tx() {
+        let expected_user_data = hex::decode("decaf0").unwrap();
+        let mut tx = get_sample_btc_op_return_tx();
+        tx.output[SAMPLE_OP_RETURN_TRANSACTION_OUTPUT_INDEX].script_pubkey =
+            get_sample_op_return_script_with_user_data(&expected_user_data);
+        let result = parse_minting_param_struct_from_tx(
+            &get_sample_pay_to_pub_key_hash_script(),
+            &tx,
+            BtcNetwork::Testnet,
+            PTOKEN_ERC777_NUM_DECIMALS,
+            &get_safe_eth_address(),
+        ).unwrap();
+        assert_eq!(result.eth_address, get_expected_eth_address());
+        assert_eq!(result.user_data, expected_user_data);
+        assert!(result.get_mint_user_data().ends_with(&expected_user_data));
+    }
+
     // TODO Fashion a transaction w/ > 1 deposit output in OP_RETURN
     // plus another output that's NOT a deposit & use that as test vector.
 }
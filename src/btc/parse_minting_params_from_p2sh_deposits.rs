@@ -6,7 +6,11 @@ use bitcoin::{
 use crate::{
     types::Result,
     traits::DatabaseInterface,
-    utils::convert_satoshis_to_ptoken,
+    eth::eth_database_utils::get_eth_host_token_decimals_from_db,
+    token_decimals::{
+        MINTING_ROUNDING_MODE,
+        convert_satoshis_to_host_token_amount,
+    },
     btc::{
         btc_state::BtcState,
         btc_database_utils::get_btc_network_from_db,
@@ -23,13 +27,15 @@ fn parse_minting_params_from_p2sh_deposit_tx(
     p2sh_deposit_containing_tx: &BtcTransaction,
     deposit_info_hash_map: &DepositInfoHashMap,
     btc_network: BtcNetwork,
+    token_decimals: u32,
 ) -> Result<MintingParams> {
     info!("✔ Parsing minting params from single `p2sh` transaction...");
     p2sh_deposit_containing_tx
         .output
         .iter()
-        .filter(|tx_out| tx_out.script_pubkey.is_p2sh())
-        .map(|p2sh_tx_out| {
+        .enumerate()
+        .filter(|(_, tx_out)| tx_out.script_pubkey.is_p2sh())
+        .map(|(vout, p2sh_tx_out)| {
             match BtcAddress::from_script(
                 &p2sh_tx_out.script_pubkey,
                 btc_network,
@@ -60,22 +66,26 @@ fn parse_minting_params_from_p2sh_deposit_tx(
                                 deposit_info,
                             );
                             Some(
-                                MintingParamStruct::new(
-                                    convert_satoshis_to_ptoken(
-                                        p2sh_tx_out.value,
-                                    ),
-                                    deposit_info.eth_address,
-                                    p2sh_deposit_containing_tx.txid(),
-                                    btc_address,
+                                convert_satoshis_to_host_token_amount(
+                                    p2sh_tx_out.value,
+                                    token_decimals,
+                                    &MINTING_ROUNDING_MODE,
                                 )
+                                    .map(|amount|
+                                        MintingParamStruct::new(
+                                            amount,
+                                            deposit_info.eth_address,
+                                            p2sh_deposit_containing_tx.txid(),
+                                            btc_address,
+                                        ).with_correlation_id(vout)
+                                    )
                             )
                         }
                     }
                 }
             }
         })
-        .filter(|maybe_minting_params| maybe_minting_params.is_some())
-        .map(|maybe_minting_params| Ok(maybe_minting_params?))
+        .filter_map(|maybe_minting_params| maybe_minting_params)
         .collect::<Result<MintingParams>>()
 }
 
@@ -83,6 +93,7 @@ fn parse_minting_params_from_p2sh_deposit_txs(
     p2sh_deposit_containing_txs: &BtcTransactions,
     deposit_info_hash_map: &DepositInfoHashMap,
     btc_network: BtcNetwork,
+    token_decimals: u32,
 ) -> Result<MintingParams> {
     info!("✔ Parsing minting params from `p2sh` transactions...");
     Ok(
@@ -92,7 +103,8 @@ fn parse_minting_params_from_p2sh_deposit_txs(
                  parse_minting_params_from_p2sh_deposit_tx(
                      tx,
                      deposit_info_hash_map,
-                     btc_network
+                     btc_network,
+                     token_decimals,
                  )
             )
             .flatten()
@@ -110,6 +122,7 @@ pub fn parse_minting_params_from_p2sh_deposits_and_add_to_state<D>(
         state.get_p2sh_deposit_txs()?,
         state.get_deposit_info_hash_map()?,
         get_btc_network_from_db(&state.db)?,
+        get_eth_host_token_decimals_from_db(&state.db)?,
     )
         .and_then(|minting_params| state.add_minting_params(minting_params))
 }
@@ -124,6 +137,8 @@ mod tests {
         util::address::Address as BtcAddress,
     };
     use crate::{
+        utils::convert_satoshis_to_ptoken,
+        constants::PTOKEN_ERC777_NUM_DECIMALS,
         btc::{
             filter_p2sh_deposit_txs::filter_p2sh_deposit_txs,
             btc_test_utils::{
@@ -173,6 +188,7 @@ mod tests {
             &tx,
             &hash_map,
             btc_network,
+            PTOKEN_ERC777_NUM_DECIMALS,
         ).unwrap();
         assert!(result.len() == expected_num_results);
         assert!(result[0].amount == expected_amount);
@@ -212,6 +228,7 @@ mod tests {
             &txs,
             &hash_map,
             btc_network,
+            PTOKEN_ERC777_NUM_DECIMALS,
         ).unwrap();
         assert!(result.len() == expected_num_results);
         assert!(result[0].amount == expected_amount);
@@ -287,6 +304,7 @@ mod tests {
             &filtered_txs,
             &hash_map,
             btc_network,
+            PTOKEN_ERC777_NUM_DECIMALS,
         ).unwrap();
         let result_1 = result[0].clone();
         let result_2 = result[1].clone();
@@ -0,0 +1,209 @@
+use std::str::FromStr;
+use bitcoin_hashes::sha256d;
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    staged_database::StagedDatabase,
+    volume_limits::maybe_apply_mint_volume_limit,
+    pending_approvals::maybe_hold_minting_params_for_approval,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    eth::eth_database_utils::eth_relay_mode_is_enabled,
+    pipeline::{
+        Pipeline,
+        run_pipeline,
+        PipelineStage,
+    },
+    btc::{
+        btc_state::BtcState,
+        get_btc_output_json::EthBatchMintInfo,
+        deduct_peg_in_fees::maybe_deduct_peg_in_fees_from_canon_block,
+        queue_minting_params::maybe_queue_or_release_minting_params,
+        filter_processed_deposits::{
+            maybe_filter_processed_deposits_from_canon_block,
+        },
+        sign_transactions::{
+            EthMintingTxPreview,
+            get_eth_minting_tx_previews,
+        },
+        btc_database_utils::{
+            get_btc_block_from_db,
+            put_btc_canon_block_in_db,
+            get_btc_canon_block_from_db,
+        },
+    },
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct BtcBlockMintingPreview {
+    btc_block_hash: String,
+    btc_block_number: u64,
+    eth_txs: Vec<EthMintingTxPreview>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    eth_relay_mints: Vec<EthBatchMintInfo>,
+}
+
+// NOTE: The same canon block stages a submission runs before signing, so the
+// preview reflects the relay mode, pause queue, volume limits & approvals.
+fn get_btc_minting_preview_pipeline<'a, D>()
+    -> Pipeline<'a, BtcState<StagedDatabase<D>>>
+    where D: DatabaseInterface + 'a
+{
+    vec![
+        PipelineStage::required(
+            maybe_filter_processed_deposits_from_canon_block
+        ),
+        PipelineStage::required(maybe_deduct_peg_in_fees_from_canon_block),
+        PipelineStage::required(maybe_hold_minting_params_for_approval),
+        PipelineStage::required(maybe_apply_mint_volume_limit),
+        PipelineStage::required(maybe_queue_or_release_minting_params),
+        PipelineStage::required(
+            maybe_filter_processed_deposits_from_canon_block
+        ),
+    ]
+}
+
+fn get_btc_block_minting_preview<D>(
+    state: &BtcState<D>,
+    btc_block_hash: String,
+    btc_block_number: u64,
+) -> Result<BtcBlockMintingPreview>
+    where D: DatabaseInterface
+{
+    let minting_params = get_btc_canon_block_from_db(&state.db)?
+        .minting_params;
+    Ok(match eth_relay_mode_is_enabled(&state.db) {
+        true => BtcBlockMintingPreview {
+            btc_block_hash,
+            btc_block_number,
+            eth_txs: vec![],
+            eth_relay_mints: minting_params
+                .iter()
+                .map(EthBatchMintInfo::new)
+                .collect(),
+        },
+        false => BtcBlockMintingPreview {
+            btc_block_hash,
+            btc_block_number,
+            eth_txs: get_eth_minting_tx_previews(&state.db, &minting_params)?,
+            eth_relay_mints: vec![],
+        },
+    })
+}
+
+// NOTE: The block is staged as the canon block & the staged writes are never
+// committed, so nothing the filter stages write reaches the database.
+pub fn preview_eth_minting_txs_for_btc_block<D>(
+    db: D,
+    btc_block_hash: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Previewing ETH minting txs for BTC block: {}", btc_block_hash);
+    let state = BtcState {
+        dry_run: true,
+        ..BtcState::init(StagedDatabase::new(&db))
+    };
+    check_enclave_is_initialized(&db)
+        .and_then(|_| Ok(sha256d::Hash::from_str(&btc_block_hash)?))
+        .and_then(|block_hash| get_btc_block_from_db(&db, &block_hash))
+        .and_then(|block| {
+            put_btc_canon_block_in_db(&state.db, &block)
+                .and_then(|_|
+                    run_pipeline(state, get_btc_minting_preview_pipeline())
+                )
+                .and_then(|state|
+                    get_btc_block_minting_preview(
+                        &state,
+                        block.id.to_string(),
+                        block.height,
+                    )
+                )
+        })
+        .and_then(|preview| Ok(serde_json::to_string(&preview)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::read_to_string;
+    use crate::{
+        test_utils::get_test_database,
+        database_utils::put_core_is_paused_in_db,
+        eth::initialize_eth::initialize_eth_enclave::{
+            maybe_initialize_eth_enclave,
+        },
+        btc::{
+            btc_types::BtcBlockInDbFormat,
+            initialize_btc::initialize_btc_enclave::{
+                maybe_initialize_btc_enclave,
+            },
+            btc_database_utils::{
+                put_btc_block_in_db,
+                get_btc_canon_block_hash_from_db,
+            },
+            btc_test_utils::{
+                get_sample_minting_params,
+                get_sample_btc_block_in_db_format,
+            },
+        },
+    };
+
+    const BTC_BLOCK_JSON_PATH: &str =
+        "src/btc/btc_test_utils/sequential_block_and_ids/\
+        1611090-btc-block-and-txs.json";
+    const ETH_BLOCK_JSON_PATH: &str =
+        "src/eth/eth_test_utils/sequential_block_and_receipts_jsons/\
+        eth_block_and_receipts_num_8065750.json";
+
+    fn get_preview<D>(db: D, btc_block_hash: &str) -> BtcBlockMintingPreview
+        where D: DatabaseInterface
+    {
+        serde_json::from_str(
+            &preview_eth_minting_txs_for_btc_block(
+                db,
+                btc_block_hash.to_string(),
+            ).unwrap()
+        ).unwrap()
+    }
+
+    #[test]
+    fn should_preview_minting_txs_through_filter_stages() {
+        let db = get_test_database();
+        maybe_initialize_btc_enclave(
+            &db,
+            read_to_string(BTC_BLOCK_JSON_PATH).unwrap(),
+            23,
+            1,
+            "Testnet".to_string(),
+            2,
+        ).unwrap();
+        maybe_initialize_eth_enclave(
+            &db,
+            read_to_string(ETH_BLOCK_JSON_PATH).unwrap(),
+            1,
+            20_000_000_000,
+            2,
+        ).unwrap();
+        let minting_params = get_sample_minting_params();
+        let sample_block = get_sample_btc_block_in_db_format().unwrap();
+        let block = BtcBlockInDbFormat::new(
+            sample_block.height,
+            sample_block.id,
+            minting_params.clone(),
+            sample_block.block,
+            sample_block.extra_data,
+        ).unwrap();
+        put_btc_block_in_db(&db, &block).unwrap();
+        let canon_block_hash = get_btc_canon_block_hash_from_db(&db).unwrap();
+        let block_hash = block.id.to_string();
+        let preview = get_preview(&db, &block_hash);
+        assert_eq!(preview.eth_txs.len(), minting_params.len());
+        put_core_is_paused_in_db(&db, true).unwrap();
+        let preview = get_preview(&db, &block_hash);
+        assert!(preview.eth_txs.is_empty());
+        assert_eq!(
+            get_btc_canon_block_hash_from_db(&db).unwrap(),
+            canon_block_hash,
+        );
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    database_utils::core_is_paused,
+    btc::{
+        btc_state::BtcState,
+        btc_types::{
+            MintingParams,
+            BtcBlockInDbFormat,
+        },
+        btc_database_utils::{
+            put_btc_canon_block_in_db,
+            get_btc_canon_block_from_db,
+            put_btc_queued_minting_params_in_db,
+            get_btc_queued_minting_params_from_db,
+        },
+    },
+};
+
+fn replace_minting_params_in_canon_block<D>(
+    db: &D,
+    canon_block: BtcBlockInDbFormat,
+    minting_params: MintingParams,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    BtcBlockInDbFormat::new(
+        canon_block.height,
+        canon_block.id,
+        minting_params,
+        canon_block.block,
+        canon_block.extra_data,
+    )
+        .and_then(|block| put_btc_canon_block_in_db(db, &block))
+}
+
+fn queue_canon_block_minting_params<D>(
+    db: &D,
+    canon_block: BtcBlockInDbFormat,
+    queued_params: MintingParams,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!(
+        "✔ Core is paused ∴ queueing {} canon block minting params...",
+        canon_block.minting_params.len(),
+    );
+    put_btc_queued_minting_params_in_db(
+        db,
+        &[queued_params, canon_block.minting_params.clone()].concat(),
+    )
+        .and_then(|_|
+            replace_minting_params_in_canon_block(db, canon_block, vec![])
+        )
+}
+
+fn release_queued_minting_params<D>(
+    db: &D,
+    canon_block: BtcBlockInDbFormat,
+    queued_params: MintingParams,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!(
+        "✔ Core is not paused ∴ releasing {} queued minting params...",
+        queued_params.len(),
+    );
+    let minting_params =
+        [queued_params, canon_block.minting_params.clone()].concat();
+    replace_minting_params_in_canon_block(db, canon_block, minting_params)
+        .and_then(|_| put_btc_queued_minting_params_in_db(db, &vec![]))
+}
+
+pub fn maybe_queue_or_release_minting_params<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe queueing or releasing canon block minting params...");
+    let canon_block = get_btc_canon_block_from_db(&state.db)?;
+    let queued_params = get_btc_queued_minting_params_from_db(&state.db)?;
+    match core_is_paused(&state.db) {
+        true => match canon_block.minting_params.is_empty() {
+            true => {
+                info!("✔ Core is paused but no minting params to queue!");
+                Ok(())
+            }
+            false => queue_canon_block_minting_params(
+                &state.db,
+                canon_block,
+                queued_params,
+            ),
+        },
+        false => match queued_params.is_empty() {
+            true => {
+                info!("✔ No queued minting params to release!");
+                Ok(())
+            }
+            false => release_queued_minting_params(
+                &state.db,
+                canon_block,
+                queued_params,
+            ),
+        },
+    }
+        .map(|_| state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        database_utils::put_core_is_paused_in_db,
+        btc::btc_test_utils::{
+            get_sample_minting_params,
+            get_sample_btc_block_in_db_format,
+        },
+    };
+
+    fn put_sample_canon_block_in_db<D>(db: &D, minting_params: MintingParams)
+        where D: DatabaseInterface
+    {
+        let block = get_sample_btc_block_in_db_format().unwrap();
+        replace_minting_params_in_canon_block(db, block, minting_params)
+            .unwrap();
+    }
+
+    #[test]
+    fn should_queue_minting_params_if_core_is_paused_and_release_after() {
+        let db = get_test_database();
+        let minting_params = get_sample_minting_params();
+        put_sample_canon_block_in_db(&db, minting_params.clone());
+        put_core_is_paused_in_db(&db, true).unwrap();
+        let state = maybe_queue_or_release_minting_params(BtcState::init(db))
+            .unwrap();
+        assert!(
+            get_btc_canon_block_from_db(&state.db)
+                .unwrap()
+                .minting_params
+                .is_empty()
+        );
+        assert_eq!(
+            get_btc_queued_minting_params_from_db(&state.db).unwrap(),
+            minting_params,
+        );
+        put_core_is_paused_in_db(&state.db, false).unwrap();
+        let state = maybe_queue_or_release_minting_params(state).unwrap();
+        assert_eq!(
+            get_btc_canon_block_from_db(&state.db).unwrap().minting_params,
+            minting_params,
+        );
+        assert!(
+            get_btc_queued_minting_params_from_db(&state.db)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn should_not_alter_minting_params_if_core_is_not_paused() {
+        let db = get_test_database();
+        let minting_params = get_sample_minting_params();
+        put_sample_canon_block_in_db(&db, minting_params.clone());
+        let state = maybe_queue_or_release_minting_params(BtcState::init(db))
+            .unwrap();
+        assert_eq!(
+            get_btc_canon_block_from_db(&state.db).unwrap().minting_params,
+            minting_params,
+        );
+    }
+}
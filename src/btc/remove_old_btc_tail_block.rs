@@ -5,10 +5,11 @@ use crate::{
     traits::DatabaseInterface,
     btc::{
         btc_state::BtcState,
-        btc_types::BtcBlockInDbFormat,
+        btc_types::BtcBlockHeaderInDbFormat,
         btc_database_utils::{
-            get_btc_block_from_db,
+            delete_btc_block_from_db,
             get_btc_tail_block_from_db,
+            get_btc_block_header_from_db,
             get_btc_anchor_block_hash_from_db,
         },
     },
@@ -22,7 +23,7 @@ fn is_anchor_block<D>(
 {
     match get_btc_anchor_block_hash_from_db(db) {
         Ok(hash) => Ok(&hash == btc_block_hash),
-        _ => Err(AppError::Custom(
+        _ => Err(AppError::NotFound(
             format!("✘ No anchor hash found in db!")
         ))
     }
@@ -30,13 +31,13 @@ fn is_anchor_block<D>(
 
 fn remove_parents_if_not_anchor<D>(
     db: &D,
-    block_whose_parents_to_be_removed: &BtcBlockInDbFormat,
+    block_whose_parents_to_be_removed: &BtcBlockHeaderInDbFormat,
 ) -> Result<()>
     where D: DatabaseInterface
 {
-    match get_btc_block_from_db(
+    match get_btc_block_header_from_db(
         db,
-        &block_whose_parents_to_be_removed.block.header.prev_blockhash,
+        &block_whose_parents_to_be_removed.header.prev_blockhash,
     ) {
         Err(_) => {
             info!("✔ No block found ∵ doing nothing!");
@@ -51,8 +52,7 @@ fn remove_parents_if_not_anchor<D>(
                 }
                 false => {
                     info!("✔ Block is NOT the anchor ∴ removing it...");
-                    db
-                        .delete(parent_block.id.to_vec())
+                    delete_btc_block_from_db(db, &parent_block.id)
                         .and_then(|_|
                             remove_parents_if_not_anchor(db, &parent_block)
                         )
@@ -70,7 +70,12 @@ pub fn maybe_remove_old_btc_tail_block<D>(
     info!("✔ Maybe removing old BTC tail block...");
     get_btc_tail_block_from_db(&state.db)
         .and_then(|tail_block|
-            remove_parents_if_not_anchor(&state.db, &tail_block)
+            remove_parents_if_not_anchor(
+                &state.db,
+                &BtcBlockHeaderInDbFormat::from_btc_block_in_db_format(
+                    &tail_block
+                ),
+            )
         )
         .and_then(|_| Ok(state))
 }
@@ -143,7 +148,10 @@ mod tests {
             panic!("Error putting btc block in db: {}", e);
         };
         assert!(btc_block_exists_in_db(&db, &parent_block.id));
-        if let Err(e) = remove_parents_if_not_anchor(&db, &block) {
+        if let Err(e) = remove_parents_if_not_anchor(
+            &db,
+            &BtcBlockHeaderInDbFormat::from_btc_block_in_db_format(&block),
+        ) {
             panic!("Error removing parent block if not anchor: {}", e);
         };
         assert!(!btc_block_exists_in_db(&db, &parent_block.id));
@@ -165,7 +173,10 @@ mod tests {
             panic!("Error putting btc block in db: {}", e);
         };
         assert!(btc_block_exists_in_db(&db, &anchor_block.id));
-        if let Err(e) = remove_parents_if_not_anchor(&db, &block) {
+        if let Err(e) = remove_parents_if_not_anchor(
+            &db,
+            &BtcBlockHeaderInDbFormat::from_btc_block_in_db_format(&block),
+        ) {
             panic!("Error removing parent block if not anchor: {}", e);
         };
         assert!(btc_block_exists_in_db(&db, &block.id));
@@ -200,7 +211,7 @@ mod tests {
             .for_each(drop);
         if let Err(e) = remove_parents_if_not_anchor(
             &db,
-            &tail_block,
+            &BtcBlockHeaderInDbFormat::from_btc_block_in_db_format(&tail_block),
         ) {
             panic!("Error removing parent block if not anchor: {}", e);
         };
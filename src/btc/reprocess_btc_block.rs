@@ -0,0 +1,76 @@
+use bitcoin_hashes::sha256d;
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    staged_database::StagedDatabase,
+    recipient_policy::maybe_apply_recipient_policy_to_minting_params,
+    btc::{
+        btc_state::BtcState,
+        btc_database_utils::get_btc_block_from_db,
+        filter_minting_params::maybe_filter_minting_params_in_state,
+        parse_btc_block::parse_deposit_info_jsons_to_deposit_info_list,
+        filter_p2sh_deposit_txs::filter_p2sh_deposit_txs_and_add_to_state,
+        btc_types::{
+            BtcBlockAndId,
+            MintingParams,
+            DepositAddressJsonList,
+        },
+        get_deposit_info_hash_map::{
+            get_deposit_info_hash_map_and_put_in_state,
+        },
+        filter_op_return_deposit_txs::{
+            filter_op_return_deposit_txs_and_add_to_state,
+        },
+        parse_minting_params_from_p2sh_deposits::{
+            parse_minting_params_from_p2sh_deposits_and_add_to_state,
+        },
+        parse_minting_params_from_op_return_deposits::{
+            parse_minting_params_from_op_return_deposits_and_add_to_state,
+        },
+    },
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct BtcBlockReprocessingReport {
+    pub btc_block_hash: String,
+    pub btc_block_number: u64,
+    pub stored_minting_params: MintingParams,
+    pub reprocessed_minting_params: MintingParams,
+}
+
+pub fn reprocess_btc_block_in_db<D>(
+    db: D,
+    btc_block_hash: &sha256d::Hash,
+    deposit_address_list: &DepositAddressJsonList,
+) -> Result<BtcBlockReprocessingReport>
+    where D: DatabaseInterface
+{
+    info!("✔ Re-processing BTC block {} from db...", btc_block_hash);
+    let block = get_btc_block_from_db(&db, btc_block_hash)?;
+    let block_and_id = BtcBlockAndId {
+        id: block.id,
+        height: block.height,
+        block: block.block.clone(),
+        deposit_address_list: parse_deposit_info_jsons_to_deposit_info_list(
+            deposit_address_list,
+        )?,
+    };
+    // NOTE: Staged & never committed so nothing re-processed is persisted.
+    BtcState::init(StagedDatabase::new(db))
+        .add_btc_block_and_id(block_and_id)
+        .and_then(get_deposit_info_hash_map_and_put_in_state)
+        .and_then(filter_op_return_deposit_txs_and_add_to_state)
+        .and_then(filter_p2sh_deposit_txs_and_add_to_state)
+        .and_then(parse_minting_params_from_op_return_deposits_and_add_to_state)
+        .and_then(parse_minting_params_from_p2sh_deposits_and_add_to_state)
+        .and_then(maybe_filter_minting_params_in_state)
+        .and_then(maybe_apply_recipient_policy_to_minting_params)
+        .map(|state|
+            BtcBlockReprocessingReport {
+                btc_block_hash: block.id.to_string(),
+                btc_block_number: block.height,
+                stored_minting_params: block.minting_params,
+                reprocessed_minting_params: state.minting_params,
+            }
+        )
+}
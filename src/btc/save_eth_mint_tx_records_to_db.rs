@@ -0,0 +1,131 @@
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    btc::{
+        btc_state::BtcState,
+        sign_transactions::get_correlation_ids_of_eth_signed_txs,
+        btc_database_utils::get_btc_canon_block_from_db,
+    },
+    eth::{
+        eth_types::EthMintTxRecord,
+        eth_database_utils::put_eth_mint_tx_record_in_db,
+    },
+};
+
+pub fn maybe_save_eth_mint_tx_records_to_db<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    match state.get_eth_signed_txs() {
+        Err(_) => {
+            info!("✔ No ETH signed txs ∴ no mint tx records to save!");
+            Ok(state)
+        }
+        Ok(signed_txs) => {
+            info!("✔ Saving {} ETH mint tx records to db...", signed_txs.len());
+            get_btc_canon_block_from_db(&state.db)
+                .and_then(|canon_block|
+                    get_correlation_ids_of_eth_signed_txs(
+                        &state.db,
+                        signed_txs,
+                        &canon_block.minting_params,
+                    )
+                )?
+                .into_iter()
+                .zip(signed_txs.iter())
+                .map(|(correlation_ids, tx)|
+                    put_eth_mint_tx_record_in_db(
+                        &state.db,
+                        &EthMintTxRecord::from_eth_tx(tx)
+                            .with_correlation_ids(correlation_ids),
+                    )
+                )
+                .collect::<Result<()>>()
+                .and_then(|_| Ok(state))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        utils::convert_satoshis_to_ptoken,
+        test_utils::{
+            TestDB,
+            get_test_database,
+        },
+        btc::{
+            btc_types::BtcBlockInDbFormat,
+            btc_database_utils::put_btc_canon_block_in_db,
+            btc_test_utils::{
+                get_sample_minting_params,
+                get_sample_btc_block_in_db_format,
+            },
+        },
+        eth::{
+            eth_types::PTokenContractVariant,
+            eth_constants::GAS_LIMIT_FOR_MINTING_TX,
+            eth_database_utils::get_eth_mint_tx_record_from_db,
+            eth_crypto::eth_transaction::{
+                EthTransaction,
+                get_signed_minting_tx,
+            },
+            eth_test_utils::{
+                get_sample_eth_address,
+                get_sample_eth_private_key,
+            },
+        },
+    };
+
+    fn get_state_with_canon_block_and_signed_tx(
+        tx: &EthTransaction,
+        correlation_id: &str,
+    ) -> BtcState<TestDB> {
+        let sample_block = get_sample_btc_block_in_db_format().unwrap();
+        let mut minting_params = get_sample_minting_params();
+        minting_params.truncate(1);
+        minting_params[0].correlation_id = correlation_id.to_string();
+        let state = BtcState::init(get_test_database());
+        put_btc_canon_block_in_db(
+            &state.db,
+            &BtcBlockInDbFormat::new(
+                sample_block.height,
+                sample_block.id,
+                minting_params,
+                sample_block.block,
+                sample_block.extra_data,
+            ).unwrap(),
+        ).unwrap();
+        state.add_eth_signed_txs(vec![tx.clone()]).unwrap()
+    }
+
+    #[test]
+    fn should_save_eth_mint_tx_records_to_db() {
+        let nonce = 6;
+        let gas_price = 20_000_000_000;
+        let tx = get_signed_minting_tx(
+            convert_satoshis_to_ptoken(1337),
+            nonce,
+            4,
+            get_sample_eth_address(),
+            gas_price,
+            GAS_LIMIT_FOR_MINTING_TX,
+            get_sample_eth_address(),
+            get_sample_eth_private_key(),
+            &PTokenContractVariant::Erc777,
+        ).unwrap();
+        let state = get_state_with_canon_block_and_signed_tx(&tx, "0xc0ffee");
+        let result_state = maybe_save_eth_mint_tx_records_to_db(state)
+            .unwrap();
+        let record = get_eth_mint_tx_record_from_db(&result_state.db, nonce)
+            .unwrap();
+        assert_eq!(record.data, tx.data);
+        assert_eq!(record.to, tx.to);
+        assert_eq!(record.gas_price, gas_price);
+        assert_eq!(record.correlation_ids, vec!["0xc0ffee".to_string()]);
+        let state = get_state_with_canon_block_and_signed_tx(&tx, "");
+        assert!(maybe_save_eth_mint_tx_records_to_db(state).is_err());
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    processed_tx_ledger::put_processed_btc_deposit_in_db,
+    btc::{
+        btc_state::BtcState,
+        btc_database_utils::get_btc_canon_block_from_db,
+    },
+};
+
+pub fn maybe_save_processed_deposits_to_db<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe saving processed deposits to db...");
+    let minting_params = get_btc_canon_block_from_db(&state.db)?.minting_params;
+    match minting_params.is_empty() {
+        true => {
+            info!("✔ No minting params in canon block ∴ no deposits to save!");
+            Ok(state)
+        }
+        false => {
+            info!("✔ Saving {} processed deposits to db...", minting_params.len());
+            minting_params
+                .iter()
+                .map(|params|
+                    put_processed_btc_deposit_in_db(
+                        &state.db,
+                        &params.originating_tx_hash,
+                    )
+                )
+                .collect::<Result<()>>()
+                .map(|_| state)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        processed_tx_ledger::btc_deposit_has_been_processed,
+        btc::{
+            btc_types::BtcBlockInDbFormat,
+            btc_database_utils::put_btc_canon_block_in_db,
+            btc_test_utils::{
+                get_sample_minting_params,
+                get_sample_btc_block_in_db_format,
+            },
+        },
+    };
+
+    #[test]
+    fn should_save_canon_block_deposits_as_processed() {
+        let db = get_test_database();
+        let minting_params = get_sample_minting_params();
+        let block = get_sample_btc_block_in_db_format().unwrap();
+        let canon_block = BtcBlockInDbFormat::new(
+            block.height,
+            block.id,
+            minting_params.clone(),
+            block.block,
+            block.extra_data,
+        ).unwrap();
+        put_btc_canon_block_in_db(&db, &canon_block).unwrap();
+        let state = maybe_save_processed_deposits_to_db(BtcState::init(db))
+            .unwrap();
+        minting_params
+            .iter()
+            .for_each(|params|
+                assert!(
+                    btc_deposit_has_been_processed(
+                        &state.db,
+                        &params.originating_tx_hash,
+                    )
+                )
+            );
+    }
+}
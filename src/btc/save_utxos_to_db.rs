@@ -1,10 +1,73 @@
 use crate::{
-    types::Result,
-    btc::btc_state::BtcState,
     traits::DatabaseInterface,
-    utxo_manager::utxo_database_utils::save_utxos_to_db,
+    processed_tx_ledger::btc_deposit_has_been_processed,
+    types::{
+        Bytes,
+        Result,
+    },
+    btc::{
+        btc_state::BtcState,
+        btc_utils::deserialize_btc_utxo,
+        btc_types::{
+            BtcUtxoAndValue,
+            BtcUtxosAndValues,
+        },
+    },
+    utxo_manager::utxo_database_utils::{
+        save_utxos_to_db,
+        get_all_utxos_from_db_via_key_prefix,
+    },
 };
 
+// NOTE: A deposit re-mined on the winning side of a BTC reorg is extracted a
+// second time, & must not re-enter the set whether since spent or not.
+fn utxo_is_already_known<D>(
+    db: &D,
+    utxos_in_db: &[Bytes],
+    utxo: &BtcUtxoAndValue,
+) -> Result<bool>
+    where D: DatabaseInterface
+{
+    Ok(
+        utxos_in_db.contains(&utxo.serialized_utxo) ||
+        btc_deposit_has_been_processed(
+            db,
+            &deserialize_btc_utxo(&utxo.serialized_utxo)?.previous_output.txid,
+        )
+    )
+}
+
+fn filter_out_known_utxos<D>(
+    db: &D,
+    utxos: &BtcUtxosAndValues,
+) -> Result<BtcUtxosAndValues>
+    where D: DatabaseInterface
+{
+    let utxos_in_db = get_all_utxos_from_db_via_key_prefix(db)?
+        .into_iter()
+        .map(|utxo| utxo.serialized_utxo)
+        .collect::<Vec<Bytes>>();
+    utxos
+        .iter()
+        .map(|utxo| {
+            utxo_is_already_known(db, &utxos_in_db, utxo)
+                .map(|is_known| (utxo, is_known))
+        })
+        .collect::<Result<Vec<(&BtcUtxoAndValue, bool)>>>()
+        .map(|utxos_and_flags|
+            utxos_and_flags
+                .into_iter()
+                .filter(|(utxo, is_known)| {
+                    if *is_known {
+                        info!("✘ Not saving already known UTXO: {:?}", utxo);
+                    };
+                    !is_known
+                })
+                .map(|(utxo, _)| utxo.clone())
+                .collect()
+        )
+}
+
 pub fn maybe_save_utxos_to_db<D>(state: BtcState<D>) -> Result<BtcState<D>>
     where D: DatabaseInterface
 {
@@ -15,8 +78,57 @@ pub fn maybe_save_utxos_to_db<D>(state: BtcState<D>) -> Result<BtcState<D>>
             Ok(state)
         }
         _ => {
-            save_utxos_to_db(&state.db, &state.utxos_and_values)
+            filter_out_known_utxos(&state.db, &state.utxos_and_values)
+                .and_then(|utxos| save_utxos_to_db(&state.db, &utxos))
                 .and_then(|_| Ok(state))
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        processed_tx_ledger::put_processed_btc_deposit_in_db,
+        btc::btc_test_utils::get_sample_utxo_and_values,
+        utxo_manager::utxo_database_utils::get_total_number_of_utxos_from_db,
+    };
+
+    #[test]
+    fn should_not_save_utxos_already_in_db() {
+        let db = get_test_database();
+        let utxos = get_sample_utxo_and_values();
+        let state = BtcState::init(db)
+            .add_utxos_and_values(utxos.clone())
+            .and_then(maybe_save_utxos_to_db)
+            .unwrap();
+        let num_utxos_before = get_total_number_of_utxos_from_db(&state.db)
+            .unwrap();
+        let state = BtcState::init(state.db)
+            .add_utxos_and_values(utxos)
+            .and_then(maybe_save_utxos_to_db)
+            .unwrap();
+        assert!(num_utxos_before > 0);
+        assert_eq!(
+            get_total_number_of_utxos_from_db(&state.db).unwrap(),
+            num_utxos_before,
+        );
+    }
+
+    #[test]
+    fn should_not_save_utxos_of_processed_deposits() {
+        let db = get_test_database();
+        let utxo = get_sample_utxo_and_values()[0].clone();
+        let txid = deserialize_btc_utxo(&utxo.serialized_utxo)
+            .unwrap()
+            .previous_output
+            .txid;
+        put_processed_btc_deposit_in_db(&db, &txid).unwrap();
+        let state = BtcState::init(db)
+            .add_utxos_and_values(vec![utxo])
+            .and_then(maybe_save_utxos_to_db)
+            .unwrap();
+        assert_eq!(get_total_number_of_utxos_from_db(&state.db).unwrap(), 0);
+    }
+}
@@ -1,5 +1,8 @@
 use crate::{
-    types::Result,
+    types::{
+        Result,
+        DataSensitivity,
+    },
     traits::DatabaseInterface,
     btc::{
         btc_state::BtcState,
@@ -16,7 +19,11 @@ pub fn is_btc_anchor_block_hash_set<D>(
 ) -> bool
     where D: DatabaseInterface
 {
-    key_exists_in_db(db, &BTC_ANCHOR_BLOCK_HASH_KEY.to_vec(), None)
+    key_exists_in_db(
+        db,
+        &BTC_ANCHOR_BLOCK_HASH_KEY.to_vec(),
+        DataSensitivity::Public,
+    )
 }
 
 pub fn maybe_set_btc_anchor_block_hash<D>(
@@ -1,5 +1,8 @@
 use crate::{
-    types::Result,
+    types::{
+        Result,
+        DataSensitivity,
+    },
     traits::DatabaseInterface,
     btc::{
         btc_state::BtcState,
@@ -20,7 +23,7 @@ pub fn maybe_set_btc_canon_block_hash<D>(
     match key_exists_in_db(
         &state.db,
         &BTC_CANON_BLOCK_HASH_KEY.to_vec(),
-        None,
+        DataSensitivity::Public,
     ) {
         true => {
             info!("✔ BTC canon block hash set in database!");
@@ -1,5 +1,8 @@
 use crate::{
-    types::Result,
+    types::{
+        Result,
+        DataSensitivity,
+    },
     traits::DatabaseInterface,
     btc::{
         btc_state::BtcState,
@@ -19,7 +22,7 @@ pub fn maybe_set_btc_latest_block_hash<D>(
     match key_exists_in_db(
         &state.db,
         &BTC_LATEST_BLOCK_HASH_KEY.to_vec(),
-        None
+        DataSensitivity::Public
     ) {
         true => {
             info!("✔ BTC latest block hash set in database");
@@ -0,0 +1,210 @@
+use ethereum_types::{
+    U256,
+    Address as EthAddress,
+};
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    btc::{
+        btc_state::BtcState,
+        btc_types::MintingParams,
+        btc_database_utils::get_btc_canon_block_from_db,
+    },
+    eth::{
+        eth_types::PTokenContractVariant,
+        eth_crypto::{
+            eth_private_key::EthPrivateKey,
+            eth_relay_request::{
+                EthRelayRequests,
+                get_signed_minting_relay_request,
+            },
+        },
+        eth_database_utils::{
+            eth_relay_mode_is_enabled,
+            get_eth_chain_id_from_db,
+            get_eth_relay_nonce_from_db,
+            get_eth_private_key_from_db,
+            get_eth_mint_gas_limit_from_db,
+            get_ptoken_contract_variant_from_db,
+            get_eth_smart_contract_address_from_db,
+            get_eth_relay_forwarder_address_from_db,
+        },
+    },
+};
+
+#[derive(Debug)]
+pub struct EthRelaySigningParams {
+    chain_id: u8,
+    relay_nonce: u64,
+    mint_gas_limit: usize,
+    eth_private_key: EthPrivateKey,
+    forwarder_address: EthAddress,
+    ptoken_contract_address: EthAddress,
+    contract_variant: PTokenContractVariant,
+}
+
+fn get_relay_signing_params_from_db<D>(
+    db: &D,
+) -> Result<EthRelaySigningParams>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting relay signing params from db...");
+    Ok(
+        EthRelaySigningParams {
+            chain_id:
+                get_eth_chain_id_from_db(db)?,
+            relay_nonce:
+                get_eth_relay_nonce_from_db(db)?,
+            mint_gas_limit:
+                get_eth_mint_gas_limit_from_db(db)? as usize,
+            eth_private_key:
+                get_eth_private_key_from_db(db)?,
+            forwarder_address:
+                get_eth_relay_forwarder_address_from_db(db)?,
+            ptoken_contract_address:
+                get_eth_smart_contract_address_from_db(db)?,
+            contract_variant:
+                get_ptoken_contract_variant_from_db(db)?,
+        }
+    )
+}
+
+fn get_eth_signed_relay_requests(
+    signing_params: &EthRelaySigningParams,
+    minting_params: &MintingParams,
+) -> Result<EthRelayRequests> {
+    trace!("✔ Getting ETH signed relay requests...");
+    minting_params
+        .iter()
+        .enumerate()
+        .map(|(i, minting_param_struct)| {
+            info!(
+                "✔ Signing ETH relay request for amount: {}, to address: {}",
+                minting_param_struct.amount,
+                minting_param_struct.eth_address,
+            );
+            get_signed_minting_relay_request(
+                U256::from(minting_param_struct.amount.clone()),
+                signing_params.relay_nonce + i as u64,
+                signing_params.chain_id,
+                signing_params.ptoken_contract_address,
+                signing_params.mint_gas_limit,
+                minting_param_struct.eth_address.clone(),
+                signing_params.forwarder_address,
+                signing_params.eth_private_key.clone(),
+                &signing_params.contract_variant,
+            )
+        })
+        .collect::<Result<EthRelayRequests>>()
+}
+
+pub fn maybe_sign_canon_block_relay_requests_and_add_to_state<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe signing relay requests...");
+    if state.dry_run {
+        info!("✔ Dry run ∴ not signing relay requests!");
+        return Ok(state)
+    };
+    if !eth_relay_mode_is_enabled(&state.db) {
+        info!("✔ ETH relay mode not enabled ∴ not signing relay requests!");
+        return Ok(state)
+    };
+    get_eth_signed_relay_requests(
+        &get_relay_signing_params_from_db(&state.db)?,
+        &get_btc_canon_block_from_db(&state.db)?.minting_params,
+    )
+        .and_then(|relay_requests| {
+            #[cfg(feature="debug")] {
+                sensitive_debug!("✔ Signed relay requests: {:?}", relay_requests);
+            }
+            state.add_eth_relay_requests(relay_requests)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use bitcoin::util::address::Address as BtcAddress;
+    use bitcoin_hashes::{
+        Hash,
+        sha256d,
+    };
+    use crate::{
+        test_utils::get_test_database,
+        utils::convert_satoshis_to_ptoken,
+        btc::{
+            btc_types::MintingParamStruct,
+            btc_test_utils::SAMPLE_TARGET_BTC_ADDRESS,
+        },
+        eth::{
+            eth_test_utils::{
+                get_sample_eth_address,
+                get_sample_eth_private_key,
+            },
+            eth_database_utils::{
+                put_eth_chain_id_in_db,
+                put_eth_relay_nonce_in_db,
+                put_eth_private_key_in_db,
+                put_eth_smart_contract_address_in_db,
+                put_eth_relay_forwarder_address_in_db,
+            },
+        },
+    };
+
+    #[test]
+    fn should_get_eth_relay_signing_params() {
+        let db = get_test_database();
+        let chain_id = 4;
+        let relay_nonce = 3;
+        let address = get_sample_eth_address();
+        put_eth_chain_id_in_db(&db, &chain_id).unwrap();
+        put_eth_relay_nonce_in_db(&db, &relay_nonce).unwrap();
+        put_eth_private_key_in_db(&db, &get_sample_eth_private_key()).unwrap();
+        put_eth_smart_contract_address_in_db(&db, &address).unwrap();
+        put_eth_relay_forwarder_address_in_db(&db, &address).unwrap();
+        let result = get_relay_signing_params_from_db(&db).unwrap();
+        assert_eq!(result.chain_id, chain_id);
+        assert_eq!(result.relay_nonce, relay_nonce);
+        assert_eq!(result.forwarder_address, address);
+    }
+
+    #[test]
+    fn should_get_eth_signed_relay_requests() {
+        let signing_params = EthRelaySigningParams {
+            chain_id: 4,
+            relay_nonce: 7,
+            mint_gas_limit: 120_000,
+            eth_private_key: get_sample_eth_private_key(),
+            forwarder_address: get_sample_eth_address(),
+            ptoken_contract_address: get_sample_eth_address(),
+            contract_variant: PTokenContractVariant::Erc777,
+        };
+        let originating_address = BtcAddress::from_str(
+            SAMPLE_TARGET_BTC_ADDRESS
+        ).unwrap();
+        let minting_params = vec![
+            MintingParamStruct::new(
+                convert_satoshis_to_ptoken(1337),
+                get_sample_eth_address(),
+                sha256d::Hash::hash(&vec![0xc0]),
+                originating_address.clone(),
+            ),
+            MintingParamStruct::new(
+                convert_satoshis_to_ptoken(666),
+                get_sample_eth_address(),
+                sha256d::Hash::hash(&vec![0xc0]),
+                originating_address,
+            ),
+        ];
+        let result = get_eth_signed_relay_requests(
+            &signing_params,
+            &minting_params,
+        ).unwrap();
+        assert_eq!(result.len(), minting_params.len());
+        assert_eq!(result[1].nonce, U256::from(8u64));
+    }
+}
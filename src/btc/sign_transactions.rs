@@ -3,27 +3,54 @@ use ethereum_types::{
     Address as EthAddress,
 };
 use crate::{
+    errors::AppError,
     traits::DatabaseInterface,
+    correlation_id::get_checked_correlation_id,
     types::{
+        Bytes,
         Result,
     },
     btc::{
         btc_state::BtcState,
-        btc_types::MintingParams,
+        btc_types::{
+            MintingParams,
+            MintingParamStruct,
+        },
         btc_database_utils::get_btc_canon_block_from_db,
     },
     eth::{
-        eth_types::EthTransactions,
+        eth_types::{
+            EthTransactions,
+            EthTransactionType,
+            PTokenContractVariant,
+        },
+        eth_constants::VALUE_FOR_MINTING_TX,
         eth_crypto::{
             eth_private_key::EthPrivateKey,
-            eth_transaction::get_signed_minting_tx,
+            eth_transaction::{
+                EthTransaction,
+                get_unsigned_minting_tx,
+                encode_batch_minting_tx_params,
+                encode_minting_tx_params_for_contract_variant,
+                encode_minting_with_data_tx_params,
+                get_signed_dynamic_fee_minting_tx,
+            },
         },
         eth_database_utils::{
+            get_eth_tx_type_from_db,
+            eth_relay_mode_is_enabled,
+            eth_batch_mint_mode_is_enabled,
+            eth_pre_eip155_signing_is_enabled,
+            eth_mint_with_data_mode_is_enabled,
             get_eth_chain_id_from_db,
             get_eth_gas_price_from_db,
             get_eth_private_key_from_db,
             get_eth_account_nonce_from_db,
+            get_eth_max_fee_per_gas_from_db,
+            get_eth_mint_gas_limit_from_db,
             get_eth_smart_contract_address_from_db,
+            get_ptoken_contract_variant_from_db,
+            get_eth_max_priority_fee_per_gas_from_db,
         },
     },
 };
@@ -42,26 +69,319 @@ fn get_eth_signed_txs(
                 minting_param_struct.amount,
                 minting_param_struct.eth_address,
             );
-            get_signed_minting_tx(
-                U256::from(minting_param_struct.amount.clone()),
-                signing_params.eth_account_nonce + i as u64,
-                signing_params.chain_id,
-                signing_params.ptoken_contract_address,
-                signing_params.gas_price,
-                minting_param_struct.eth_address.clone(),
-                signing_params.eth_private_key.clone(),
-            )
+            if signing_params.mint_with_data {
+                return get_eth_signed_minting_with_data_tx(
+                    signing_params,
+                    minting_param_struct,
+                    signing_params.eth_account_nonce + i as u64,
+                )
+            };
+            match signing_params.tx_type {
+                EthTransactionType::Legacy => get_unsigned_minting_tx(
+                    signing_params.eth_account_nonce + i as u64,
+                    U256::from(minting_param_struct.amount.clone()),
+                    signing_params.chain_id,
+                    signing_params.ptoken_contract_address,
+                    signing_params.gas_price,
+                    signing_params.mint_gas_limit,
+                    minting_param_struct.eth_address.clone(),
+                    &signing_params.contract_variant,
+                )?
+                    .with_eip155(signing_params.eip155)
+                    .sign(signing_params.eth_private_key.clone()),
+                EthTransactionType::DynamicFee => get_signed_dynamic_fee_minting_tx(
+                    U256::from(minting_param_struct.amount.clone()),
+                    signing_params.eth_account_nonce + i as u64,
+                    signing_params.chain_id,
+                    signing_params.ptoken_contract_address,
+                    signing_params.max_fee_per_gas,
+                    signing_params.max_priority_fee_per_gas,
+                    signing_params.mint_gas_limit,
+                    minting_param_struct.eth_address.clone(),
+                    signing_params.eth_private_key.clone(),
+                    &signing_params.contract_variant,
+                ),
+            }
         })
         .collect::<Result<EthTransactions>>()
 }
 
+fn get_signed_eth_tx_with_data(
+    signing_params: &EthSigningParams,
+    data: Bytes,
+    nonce: u64,
+    gas_limit: usize,
+) -> Result<EthTransaction> {
+    match signing_params.tx_type {
+        EthTransactionType::Legacy => EthTransaction::new(
+            data,
+            nonce,
+            VALUE_FOR_MINTING_TX,
+            signing_params.ptoken_contract_address,
+            signing_params.chain_id,
+            gas_limit,
+            signing_params.gas_price,
+        ),
+        EthTransactionType::DynamicFee => EthTransaction::new_dynamic_fee(
+            data,
+            nonce,
+            VALUE_FOR_MINTING_TX,
+            signing_params.ptoken_contract_address,
+            signing_params.chain_id,
+            gas_limit,
+            signing_params.max_fee_per_gas,
+            signing_params.max_priority_fee_per_gas,
+        ),
+    }
+        .with_eip155(signing_params.eip155)
+        .sign(signing_params.eth_private_key.clone())
+}
+
+fn get_eth_signed_minting_with_data_tx(
+    signing_params: &EthSigningParams,
+    minting_param_struct: &MintingParamStruct,
+    nonce: u64,
+) -> Result<EthTransaction> {
+    trace!("✔ Signing ETH minting tx with user data...");
+    encode_minting_with_data_tx_params(
+        &signing_params.contract_variant,
+        minting_param_struct.eth_address,
+        minting_param_struct.amount,
+        minting_param_struct.get_mint_user_data(),
+    )
+        .and_then(|data|
+            get_signed_eth_tx_with_data(
+                signing_params,
+                data,
+                nonce,
+                signing_params.mint_gas_limit,
+            )
+        )
+}
+
+pub fn should_batch_mint<D>(
+    db: &D,
+    num_minting_params: usize,
+) -> bool
+    where D: DatabaseInterface
+{
+    num_minting_params > 1 &&
+        eth_batch_mint_mode_is_enabled(db) &&
+        match get_ptoken_contract_variant_from_db(db) {
+            Ok(contract_variant) => contract_variant.supports_batch_minting(),
+            Err(_) => false,
+        }
+}
+
+// NOTE: A batch minting tx carries every mint, otherwise each tx carries the
+// mint at its own index.
+pub fn get_minting_params_of_eth_signed_txs<'a, D>(
+    db: &D,
+    eth_txs: &EthTransactions,
+    minting_params: &'a MintingParams,
+) -> Result<Vec<&'a [MintingParamStruct]>>
+    where D: DatabaseInterface
+{
+    let is_batch_mint = should_batch_mint(db, minting_params.len());
+    eth_txs
+        .iter()
+        .enumerate()
+        .map(|(i, eth_tx)|
+            match (is_batch_mint, i) {
+                (true, 0) => Some(&minting_params[..]),
+                (true, _) => None,
+                (false, _) => minting_params.get(i..i + 1),
+            }
+                .ok_or_else(|| AppError::NotFound(format!(
+                    "✘ No minting params for ETH tx: 0x{}!",
+                    eth_tx.get_tx_hash(),
+                )))
+        )
+        .collect()
+}
+
+pub fn get_correlation_ids_of_eth_signed_txs<D>(
+    db: &D,
+    eth_txs: &EthTransactions,
+    minting_params: &MintingParams,
+) -> Result<Vec<Vec<String>>>
+    where D: DatabaseInterface
+{
+    get_minting_params_of_eth_signed_txs(db, eth_txs, minting_params)?
+        .iter()
+        .zip(eth_txs.iter())
+        .map(|(tx_minting_params, eth_tx)| {
+            let tx_hash = format!("0x{}", eth_tx.get_tx_hash());
+            tx_minting_params
+                .iter()
+                .map(|params|
+                    get_checked_correlation_id(&params.correlation_id, &tx_hash)
+                )
+                .collect()
+        })
+        .collect()
+}
+
+fn get_eth_signed_batch_minting_tx(
+    signing_params: &EthSigningParams,
+    minting_params: &MintingParams,
+) -> Result<EthTransactions> {
+    info!("✔ Signing ETH batch minting tx for {} mints...", minting_params.len());
+    let data = encode_batch_minting_tx_params(
+        &minting_params
+            .iter()
+            .map(|minting_param_struct| minting_param_struct.eth_address)
+            .collect::<Vec<EthAddress>>(),
+        &minting_params
+            .iter()
+            .map(|minting_param_struct| minting_param_struct.amount)
+            .collect::<Vec<U256>>(),
+    )?;
+    get_signed_eth_tx_with_data(
+        signing_params,
+        data,
+        signing_params.eth_account_nonce,
+        signing_params.mint_gas_limit * minting_params.len(),
+    )
+        .map(|signed_tx| vec![signed_tx])
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EthMintingTxPreview {
+    pub to: String,
+    pub nonce: u64,
+    pub calldata: String,
+    pub gas_limit: usize,
+    pub amounts: Vec<String>,
+    pub recipients: Vec<String>,
+}
+
+impl EthMintingTxPreview {
+    fn new(
+        signing_params: &EthSigningParams,
+        minting_params: &[MintingParamStruct],
+        calldata: Bytes,
+        nonce: u64,
+        gas_limit: usize,
+    ) -> Self {
+        EthMintingTxPreview {
+            nonce,
+            gas_limit,
+            calldata: format!("0x{}", hex::encode(calldata)),
+            to: format!(
+                "0x{}",
+                hex::encode(signing_params.ptoken_contract_address.as_bytes()),
+            ),
+            amounts: minting_params
+                .iter()
+                .map(|params| params.amount.to_string())
+                .collect(),
+            recipients: minting_params
+                .iter()
+                .map(|params|
+                    format!("0x{}", hex::encode(params.eth_address.as_bytes()))
+                )
+                .collect(),
+        }
+    }
+}
+
+fn get_eth_minting_tx_data(
+    signing_params: &EthSigningParams,
+    minting_param_struct: &MintingParamStruct,
+) -> Result<Bytes> {
+    match signing_params.mint_with_data {
+        true => encode_minting_with_data_tx_params(
+            &signing_params.contract_variant,
+            minting_param_struct.eth_address,
+            minting_param_struct.amount,
+            minting_param_struct.get_mint_user_data(),
+        ),
+        false => encode_minting_tx_params_for_contract_variant(
+            &signing_params.contract_variant,
+            minting_param_struct.eth_address,
+            minting_param_struct.amount,
+        ),
+    }
+}
+
+fn get_eth_minting_tx_previews_from_signing_params(
+    signing_params: &EthSigningParams,
+    minting_params: &MintingParams,
+    batch_mint: bool,
+) -> Result<Vec<EthMintingTxPreview>> {
+    match batch_mint {
+        true => encode_batch_minting_tx_params(
+            &minting_params
+                .iter()
+                .map(|minting_param_struct| minting_param_struct.eth_address)
+                .collect::<Vec<EthAddress>>(),
+            &minting_params
+                .iter()
+                .map(|minting_param_struct| minting_param_struct.amount)
+                .collect::<Vec<U256>>(),
+        )
+            .map(|calldata|
+                vec![
+                    EthMintingTxPreview::new(
+                        signing_params,
+                        minting_params,
+                        calldata,
+                        signing_params.eth_account_nonce,
+                        signing_params.mint_gas_limit * minting_params.len(),
+                    )
+                ]
+            ),
+        false => minting_params
+            .iter()
+            .enumerate()
+            .map(|(i, minting_param_struct)|
+                get_eth_minting_tx_data(signing_params, minting_param_struct)
+                    .map(|calldata|
+                        EthMintingTxPreview::new(
+                            signing_params,
+                            &[minting_param_struct.clone()],
+                            calldata,
+                            signing_params.eth_account_nonce + i as u64,
+                            signing_params.mint_gas_limit,
+                        )
+                    )
+            )
+            .collect::<Result<Vec<EthMintingTxPreview>>>(),
+    }
+}
+
+pub fn get_eth_minting_tx_previews<D>(
+    db: &D,
+    minting_params: &MintingParams,
+) -> Result<Vec<EthMintingTxPreview>>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting previews of {} ETH minting txs...", minting_params.len());
+    get_signing_params_from_db(db)
+        .and_then(|signing_params|
+            get_eth_minting_tx_previews_from_signing_params(
+                &signing_params,
+                minting_params,
+                should_batch_mint(db, minting_params.len()),
+            )
+        )
+}
+
 #[derive(Debug)]
 pub struct EthSigningParams {
+    eip155: bool,
     chain_id: u8,
     gas_price: u64,
+    mint_with_data: bool,
+    max_fee_per_gas: u64,
+    mint_gas_limit: usize,
     eth_account_nonce: u64,
+    tx_type: EthTransactionType,
+    max_priority_fee_per_gas: u64,
     eth_private_key: EthPrivateKey,
     ptoken_contract_address: EthAddress,
+    contract_variant: PTokenContractVariant,
 }
 
 fn get_signing_params_from_db<D>(
@@ -70,10 +390,27 @@ fn get_signing_params_from_db<D>(
     where D: DatabaseInterface
 {
     trace!("✔ Getting signing params from db...");
+    let tx_type = get_eth_tx_type_from_db(db)?;
+    let (max_fee_per_gas, max_priority_fee_per_gas) = match tx_type {
+        EthTransactionType::Legacy => (0, 0),
+        EthTransactionType::DynamicFee => (
+            get_eth_max_fee_per_gas_from_db(db)?,
+            get_eth_max_priority_fee_per_gas_from_db(db)?,
+        ),
+    };
     Ok(
         EthSigningParams {
+            tx_type,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            eip155:
+                !eth_pre_eip155_signing_is_enabled(db),
+            mint_with_data:
+                eth_mint_with_data_mode_is_enabled(db),
             chain_id:
                 get_eth_chain_id_from_db(db)?,
+            mint_gas_limit:
+                get_eth_mint_gas_limit_from_db(db)? as usize,
             gas_price:
                 get_eth_gas_price_from_db(db)?,
             eth_private_key:
@@ -82,6 +419,8 @@ fn get_signing_params_from_db<D>(
                 get_eth_account_nonce_from_db(db)?,
             ptoken_contract_address:
                 get_eth_smart_contract_address_from_db(db)?,
+            contract_variant:
+                get_ptoken_contract_variant_from_db(db)?,
         }
     )
 }
@@ -92,13 +431,23 @@ pub fn maybe_sign_canon_block_transactions_and_add_to_state<D>(
     where D: DatabaseInterface
 {
     info!("✔ Maybe signing txs...");
-    get_eth_signed_txs(
-        &get_signing_params_from_db(&state.db)?,
-        &get_btc_canon_block_from_db(&state.db)?.minting_params,
-    )
+    if state.dry_run {
+        info!("✔ Dry run ∴ not signing txs!");
+        return Ok(state)
+    };
+    if eth_relay_mode_is_enabled(&state.db) {
+        info!("✔ ETH relay mode enabled ∴ not signing txs!");
+        return Ok(state)
+    };
+    let signing_params = get_signing_params_from_db(&state.db)?;
+    let minting_params = get_btc_canon_block_from_db(&state.db)?.minting_params;
+    match should_batch_mint(&state.db, minting_params.len()) {
+        true => get_eth_signed_batch_minting_tx(&signing_params, &minting_params),
+        false => get_eth_signed_txs(&signing_params, &minting_params),
+    }
         .and_then(|signed_txs| {
             #[cfg(feature="debug")] {
-                debug!("✔ Signed transactions: {:?}", signed_txs);
+                sensitive_debug!("✔ Signed transactions: {:?}", signed_txs);
             }
             state.add_eth_signed_txs(signed_txs)
         })
@@ -108,6 +457,7 @@ pub fn maybe_sign_canon_block_transactions_and_add_to_state<D>(
 mod tests {
     use super::*;
     use std::str::FromStr;
+    use proptest::prelude::*;
     use bitcoin::util::address::Address as BtcAddress;
     use bitcoin_hashes::{
         Hash,
@@ -116,9 +466,13 @@ mod tests {
     use crate::{
         test_utils::get_test_database,
         utils::convert_satoshis_to_ptoken,
+        test_generators::arb_minting_params,
         btc::{
             btc_types::MintingParamStruct,
-            btc_test_utils::SAMPLE_TARGET_BTC_ADDRESS,
+            btc_test_utils::{
+                SAMPLE_TARGET_BTC_ADDRESS,
+                get_sample_minting_params,
+            },
         },
         eth::{
             eth_test_utils::{
@@ -128,9 +482,14 @@ mod tests {
             eth_database_utils::{
                 put_eth_chain_id_in_db,
                 put_eth_gas_price_in_db,
+                put_eth_tx_type_in_db,
+                put_eth_batch_mint_mode_in_db,
                 put_eth_private_key_in_db,
+                put_ptoken_contract_variant_in_db,
                 put_eth_account_nonce_in_db,
+                put_eth_max_fee_per_gas_in_db,
                 put_eth_smart_contract_address_in_db,
+                put_eth_max_priority_fee_per_gas_in_db,
             }
         }
     };
@@ -180,11 +539,18 @@ mod tests {
     #[test]
     fn should_get_eth_signatures() {
         let signing_params = EthSigningParams {
+            eip155: true,
             chain_id: 1,
+            max_fee_per_gas: 0,
+            mint_gas_limit: 120_000,
             eth_account_nonce: 0,
             gas_price: 20_000_000_000,
+            max_priority_fee_per_gas: 0,
+            tx_type: EthTransactionType::Legacy,
             eth_private_key: get_sample_eth_private_key(),
             ptoken_contract_address: get_sample_eth_address(),
+            mint_with_data: false,
+            contract_variant: PTokenContractVariant::Erc777,
         };
         let originating_address = BtcAddress::from_str(
             SAMPLE_TARGET_BTC_ADDRESS
@@ -215,4 +581,254 @@ mod tests {
         ).unwrap();
         assert!(result.len() == minting_params.len());
     }
+
+    #[test]
+    fn should_get_eth_minting_tx_previews() {
+        let signing_params = EthSigningParams {
+            eip155: true,
+            chain_id: 1,
+            max_fee_per_gas: 0,
+            mint_gas_limit: 120_000,
+            eth_account_nonce: 5,
+            gas_price: 20_000_000_000,
+            max_priority_fee_per_gas: 0,
+            tx_type: EthTransactionType::Legacy,
+            eth_private_key: get_sample_eth_private_key(),
+            ptoken_contract_address: get_sample_eth_address(),
+            mint_with_data: false,
+            contract_variant: PTokenContractVariant::Erc777,
+        };
+        let minting_params = get_sample_minting_params();
+        let result = get_eth_minting_tx_previews_from_signing_params(
+            &signing_params,
+            &minting_params,
+            false,
+        ).unwrap();
+        assert_eq!(result.len(), minting_params.len());
+        result
+            .iter()
+            .zip(minting_params.iter())
+            .enumerate()
+            .for_each(|(i, (preview, minting_param_struct))| {
+                assert_eq!(preview.nonce, 5 + i as u64);
+                assert_eq!(
+                    preview.amounts,
+                    vec![minting_param_struct.amount.to_string()],
+                );
+                assert!(preview.calldata.starts_with("0x40c10f19"));
+            });
+        let result = get_eth_minting_tx_previews_from_signing_params(
+            &signing_params,
+            &minting_params,
+            true,
+        ).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].recipients.len(), minting_params.len());
+        assert_eq!(result[0].gas_limit, 120_000 * minting_params.len());
+    }
+
+    #[test]
+    fn should_get_dynamic_fee_eth_signing_params() {
+        let db = get_test_database();
+        let max_fee_per_gas = 20_000_000_000;
+        let max_priority_fee_per_gas = 2_000_000_000;
+        put_eth_chain_id_in_db(&db, &4).unwrap();
+        put_eth_gas_price_in_db(&db, &1).unwrap();
+        put_eth_account_nonce_in_db(&db, &0).unwrap();
+        put_eth_private_key_in_db(&db, &get_sample_eth_private_key()).unwrap();
+        put_eth_smart_contract_address_in_db(&db, &get_sample_eth_address())
+            .unwrap();
+        put_eth_tx_type_in_db(&db, &EthTransactionType::DynamicFee).unwrap();
+        put_eth_max_fee_per_gas_in_db(&db, &max_fee_per_gas).unwrap();
+        put_eth_max_priority_fee_per_gas_in_db(&db, &max_priority_fee_per_gas)
+            .unwrap();
+        let result = get_signing_params_from_db(&db).unwrap();
+        assert_eq!(result.tx_type, EthTransactionType::DynamicFee);
+        assert_eq!(result.max_fee_per_gas, max_fee_per_gas);
+        assert_eq!(result.max_priority_fee_per_gas, max_priority_fee_per_gas);
+    }
+
+    #[test]
+    fn should_get_dynamic_fee_eth_signatures() {
+        let signing_params = EthSigningParams {
+            eip155: true,
+            chain_id: 4,
+            gas_price: 0,
+            mint_gas_limit: 120_000,
+            eth_account_nonce: 0,
+            max_fee_per_gas: 20_000_000_000,
+            max_priority_fee_per_gas: 2_000_000_000,
+            tx_type: EthTransactionType::DynamicFee,
+            eth_private_key: get_sample_eth_private_key(),
+            ptoken_contract_address: get_sample_eth_address(),
+            mint_with_data: false,
+            contract_variant: PTokenContractVariant::Erc777,
+        };
+        let originating_address = BtcAddress::from_str(
+            SAMPLE_TARGET_BTC_ADDRESS
+        ).unwrap();
+        let minting_params = vec![
+            MintingParamStruct::new(
+                convert_satoshis_to_ptoken(1337),
+                get_sample_eth_address(),
+                sha256d::Hash::hash(&vec![0xc0]),
+                originating_address,
+            ),
+        ];
+        let result = get_eth_signed_txs(
+            &signing_params,
+            &minting_params,
+        ).unwrap();
+        assert_eq!(result.len(), minting_params.len());
+        assert_eq!(result[0].tx_type, EthTransactionType::DynamicFee);
+        assert!(result[0].serialize_hex().starts_with("02"));
+    }
+
+    #[test]
+    fn should_get_eth_signed_batch_minting_tx() {
+        let signing_params = EthSigningParams {
+            eip155: true,
+            chain_id: 4,
+            max_fee_per_gas: 0,
+            mint_gas_limit: 120_000,
+            eth_account_nonce: 3,
+            gas_price: 20_000_000_000,
+            max_priority_fee_per_gas: 0,
+            tx_type: EthTransactionType::Legacy,
+            eth_private_key: get_sample_eth_private_key(),
+            ptoken_contract_address: get_sample_eth_address(),
+            mint_with_data: false,
+            contract_variant: PTokenContractVariant::Erc777,
+        };
+        let originating_address = BtcAddress::from_str(
+            SAMPLE_TARGET_BTC_ADDRESS
+        ).unwrap();
+        let minting_params = vec![
+            MintingParamStruct::new(
+                convert_satoshis_to_ptoken(1337),
+                get_sample_eth_address(),
+                sha256d::Hash::hash(&vec![0xc0]),
+                originating_address.clone(),
+            ),
+            MintingParamStruct::new(
+                convert_satoshis_to_ptoken(666),
+                get_sample_eth_address(),
+                sha256d::Hash::hash(&vec![0xc0]),
+                originating_address,
+            ),
+        ];
+        let result = get_eth_signed_batch_minting_tx(
+            &signing_params,
+            &minting_params,
+        ).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].nonce, U256::from(3u64));
+        assert_eq!(result[0].gas_limit, U256::from(240_000u64));
+        assert_eq!(hex::encode(&result[0].data[..4]), "7c88e3d9");
+    }
+
+    #[test]
+    fn should_only_batch_mint_multiple_params_when_enabled() {
+        let db = get_test_database();
+        assert!(!should_batch_mint(&db, 2));
+        put_eth_batch_mint_mode_in_db(&db, true).unwrap();
+        assert!(!should_batch_mint(&db, 1));
+        assert!(should_batch_mint(&db, 2));
+    }
+
+    #[test]
+    fn should_not_batch_mint_if_contract_variant_does_not_support_it() {
+        let db = get_test_database();
+        put_eth_batch_mint_mode_in_db(&db, true).unwrap();
+        put_ptoken_contract_variant_in_db(
+            &db,
+            &PTokenContractVariant::Erc777WithOperators,
+        ).unwrap();
+        assert!(!should_batch_mint(&db, 2));
+    }
+
+    #[test]
+    fn should_get_eth_signed_minting_with_data_txs() {
+        let signing_params = EthSigningParams {
+            eip155: true,
+            chain_id: 4,
+            max_fee_per_gas: 0,
+            mint_gas_limit: 120_000,
+            eth_account_nonce: 5,
+            gas_price: 20_000_000_000,
+            max_priority_fee_per_gas: 0,
+            tx_type: EthTransactionType::Legacy,
+            eth_private_key: get_sample_eth_private_key(),
+            ptoken_contract_address: get_sample_eth_address(),
+            mint_with_data: true,
+            contract_variant: PTokenContractVariant::Erc777,
+        };
+        let originating_address = BtcAddress::from_str(
+            SAMPLE_TARGET_BTC_ADDRESS
+        ).unwrap();
+        let minting_param_struct = MintingParamStruct::new(
+            convert_satoshis_to_ptoken(1337),
+            get_sample_eth_address(),
+            sha256d::Hash::hash(&vec![0xc0]),
+            originating_address,
+        );
+        let result = get_eth_signed_txs(
+            &signing_params,
+            &vec![minting_param_struct.clone()],
+        ).unwrap();
+        let expected_user_data = hex::decode(
+            minting_param_struct.originating_tx_hash.to_string()
+        ).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].nonce, U256::from(5u64));
+        assert_eq!(hex::encode(&result[0].data[..4]), "94d008ef");
+        let user_data_start_index = result[0].data.len() - 32;
+        assert_eq!(
+            result[0].data[user_data_start_index..].to_vec(),
+            expected_user_data,
+        );
+    }
+
+    #[test]
+    fn should_not_sign_txs_in_dry_run() {
+        let db = get_test_database();
+        let state = BtcState { dry_run: true, ..BtcState::init(db) };
+        let result = maybe_sign_canon_block_transactions_and_add_to_state(state)
+            .unwrap();
+        assert!(!result.eth_signed_txs.is_filled());
+    }
+
+    proptest! {
+        #[test]
+        fn should_sign_one_tx_per_minting_params_with_sequential_nonces(
+            minting_params in arb_minting_params(10),
+            eth_account_nonce in 0..u64::max_value() / 2,
+        ) {
+            let signing_params = EthSigningParams {
+                eip155: true,
+                chain_id: 1,
+                eth_account_nonce,
+                max_fee_per_gas: 0,
+                mint_gas_limit: 120_000,
+                gas_price: 20_000_000_000,
+                max_priority_fee_per_gas: 0,
+                tx_type: EthTransactionType::Legacy,
+                eth_private_key: get_sample_eth_private_key(),
+                ptoken_contract_address: get_sample_eth_address(),
+                mint_with_data: false,
+                contract_variant: PTokenContractVariant::Erc777,
+            };
+            let result = get_eth_signed_txs(
+                &signing_params,
+                &minting_params,
+            ).unwrap();
+            prop_assert_eq!(result.len(), minting_params.len());
+            for (i, tx) in result.iter().enumerate() {
+                prop_assert_eq!(
+                    tx.nonce,
+                    U256::from(eth_account_nonce + i as u64),
+                );
+            }
+        }
+    }
 }
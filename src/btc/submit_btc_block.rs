@@ -1,42 +1,92 @@
 use crate::{
     types::Result,
     traits::DatabaseInterface,
+    pipeline::{
+        Pipeline,
+        run_pipeline,
+        PipelineStage,
+    },
+    state_size::{
+        check_state_size_is_within_limit,
+        check_submission_size_is_within_limit,
+    },
+    log_config::apply_log_config_from_db,
+    error_log::{
+        ErrorContext,
+        record_error_in_db,
+    },
+    heartbeat::update_btc_heartbeat_in_db,
+    volume_limits::maybe_apply_mint_volume_limit,
+    pending_approvals::maybe_hold_minting_params_for_approval,
+    recipient_policy::maybe_apply_recipient_policy_to_minting_params,
+    refund_ledger::{
+        maybe_record_refundable_utxos_in_db,
+        maybe_add_canon_block_refunds_to_refund_ledger,
+    },
+    stale_transactions::{
+        maybe_watch_signed_eth_txs,
+        mark_seen_btc_txs_in_watchlist,
+        maybe_remove_canon_btc_txs_from_watchlist,
+    },
+    balance_reconciliation::maybe_increment_total_satoshis_minted_in_db,
+    core_metrics::{
+        record_last_error_code,
+        increment_blocks_processed_metric,
+    },
+    staged_database::StagedDatabase,
+    chain_state::{
+        end_db_transaction,
+        start_db_transaction,
+    },
     check_enclave_is_initialized::{
         check_enclave_is_initialized_and_return_btc_state,
     },
     btc::{
         btc_state::BtcState,
+        validation_checkpoint::{
+            clear_btc_validation_checkpoint,
+            validate_btc_block_unless_checkpointed,
+        },
         save_utxos_to_db::maybe_save_utxos_to_db,
         filter_utxos::maybe_filter_utxos_in_state,
         add_btc_block_to_db::maybe_add_btc_block_to_db,
-        validate_btc_merkle_root::validate_btc_merkle_root,
+        deduct_peg_in_fees::maybe_deduct_peg_in_fees_from_canon_block,
+        save_eth_pending_txs_to_db::maybe_save_eth_pending_txs_to_db,
         update_btc_linker_hash::maybe_update_btc_linker_hash,
         increment_eth_nonce::maybe_increment_eth_nonce_in_db,
+        journal_btc_events::maybe_journal_btc_bridge_events,
+        increment_eth_relay_nonce::maybe_increment_eth_relay_nonce_in_db,
         parse_btc_block::parse_btc_block_and_id_and_put_in_state,
+        parse_bitcoind_block::parse_bitcoind_block_and_put_in_state,
         remove_old_btc_tail_block::maybe_remove_old_btc_tail_block,
         filter_minting_params::maybe_filter_minting_params_in_state,
+        queue_minting_params::maybe_queue_or_release_minting_params,
         update_btc_tail_block_hash::maybe_update_btc_tail_block_hash,
-        validate_btc_block_header::validate_btc_block_header_in_state,
         update_btc_canon_block_hash::maybe_update_btc_canon_block_hash,
         check_btc_parent_exists::check_for_parent_of_btc_block_in_state,
         update_btc_latest_block_hash::maybe_update_btc_latest_block_hash,
         filter_p2sh_deposit_txs::filter_p2sh_deposit_txs_and_add_to_state,
-        validate_btc_difficulty::validate_difficulty_of_btc_block_in_state,
+        save_processed_deposits_to_db::maybe_save_processed_deposits_to_db,
         sign_transactions::maybe_sign_canon_block_transactions_and_add_to_state,
-        btc_database_utils::{
-            end_btc_db_transaction,
-            start_btc_db_transaction,
-        },
         get_btc_output_json::{
             get_btc_output_as_string,
             create_btc_output_json_and_put_in_state,
         },
+        sign_relay_requests::{
+            maybe_sign_canon_block_relay_requests_and_add_to_state,
+        },
+        filter_processed_deposits::{
+            maybe_filter_processed_deposits_from_canon_block,
+        },
+        mark_eth_pending_txs_as_reported::{
+            maybe_mark_eth_pending_txs_as_reported,
+        },
+        save_eth_mint_tx_records_to_db::{
+            maybe_save_eth_mint_tx_records_to_db,
+        },
         get_deposit_info_hash_map::{
             get_deposit_info_hash_map_and_put_in_state,
         },
-        validate_btc_proof_of_work::{
-            validate_proof_of_work_of_btc_block_in_state,
-        },
         filter_op_return_deposit_txs::{
             filter_op_return_deposit_txs_and_add_to_state,
         },
@@ -61,45 +111,222 @@ use crate::{
     },
 };
 
+// NOTE: The deposit flags only switch off the parsing of minting params. The
+// UTXOs of such deposits are still extracted so the core's balance stays true.
+pub fn get_btc_deposit_pipeline<'a, D>() -> Pipeline<'a, BtcState<D>>
+    where D: DatabaseInterface + 'a
+{
+    vec![
+        PipelineStage::required(filter_op_return_deposit_txs_and_add_to_state),
+        PipelineStage::required(filter_p2sh_deposit_txs_and_add_to_state),
+        PipelineStage::optional(
+            "op_return_deposits",
+            parse_minting_params_from_op_return_deposits_and_add_to_state,
+        ),
+        PipelineStage::optional(
+            "p2sh_deposits",
+            parse_minting_params_from_p2sh_deposits_and_add_to_state,
+        ),
+        PipelineStage::required(
+            maybe_extract_utxos_from_op_return_txs_and_put_in_state
+        ),
+        PipelineStage::required(
+            maybe_extract_utxos_from_p2sh_txs_and_put_in_state
+        ),
+    ]
+}
+
+pub fn get_btc_submission_pipeline<'a, D>()
+    -> Pipeline<'a, BtcState<StagedDatabase<D>>>
+    where D: DatabaseInterface + 'a
+{
+    let mut pipeline = vec![
+        PipelineStage::required(
+            check_enclave_is_initialized_and_return_btc_state
+        ),
+        PipelineStage::required(check_state_size_is_within_limit),
+        PipelineStage::required(start_db_transaction),
+        PipelineStage::required(apply_log_config_from_db),
+        PipelineStage::required(check_for_parent_of_btc_block_in_state),
+        PipelineStage::required(validate_btc_block_unless_checkpointed),
+        // NOTE: Txs signed by a previous submission were output to the host,
+        // since it's submitting again.
+        PipelineStage::optional(
+            "eth_pending_tx_tracking",
+            maybe_mark_eth_pending_txs_as_reported,
+        ),
+        PipelineStage::optional(
+            "stale_tx_watchdog",
+            mark_seen_btc_txs_in_watchlist,
+        ),
+        PipelineStage::required(get_deposit_info_hash_map_and_put_in_state),
+    ];
+    pipeline.extend(get_btc_deposit_pipeline());
+    pipeline.extend(vec![
+        PipelineStage::required(maybe_record_refundable_utxos_in_db),
+        PipelineStage::required(maybe_filter_utxos_in_state),
+        PipelineStage::required(maybe_save_utxos_to_db),
+        PipelineStage::required(maybe_filter_minting_params_in_state),
+        PipelineStage::required(
+            maybe_apply_recipient_policy_to_minting_params
+        ),
+        PipelineStage::required(create_btc_block_in_db_format_and_put_in_state),
+        PipelineStage::required(maybe_add_btc_block_to_db),
+        PipelineStage::required(maybe_update_btc_latest_block_hash),
+        PipelineStage::required(maybe_update_btc_canon_block_hash),
+        PipelineStage::required(
+            maybe_add_canon_block_refunds_to_refund_ledger
+        ),
+        PipelineStage::optional(
+            "stale_tx_watchdog",
+            maybe_remove_canon_btc_txs_from_watchlist,
+        ),
+        PipelineStage::required(maybe_update_btc_tail_block_hash),
+        PipelineStage::required(maybe_update_btc_linker_hash),
+        PipelineStage::required(
+            maybe_filter_processed_deposits_from_canon_block
+        ),
+        PipelineStage::required(maybe_deduct_peg_in_fees_from_canon_block),
+        PipelineStage::required(maybe_hold_minting_params_for_approval),
+        PipelineStage::required(maybe_apply_mint_volume_limit),
+        PipelineStage::required(maybe_queue_or_release_minting_params),
+        // NOTE: Again, for any params just released from the queue.
+        PipelineStage::required(
+            maybe_filter_processed_deposits_from_canon_block
+        ),
+        PipelineStage::required(
+            maybe_sign_canon_block_transactions_and_add_to_state
+        ),
+        PipelineStage::required(maybe_save_eth_mint_tx_records_to_db),
+        PipelineStage::optional(
+            "eth_pending_tx_tracking",
+            maybe_save_eth_pending_txs_to_db,
+        ),
+        PipelineStage::optional("stale_tx_watchdog", maybe_watch_signed_eth_txs),
+        PipelineStage::required(maybe_increment_eth_nonce_in_db),
+        PipelineStage::required(
+            maybe_sign_canon_block_relay_requests_and_add_to_state
+        ),
+        PipelineStage::required(maybe_increment_eth_relay_nonce_in_db),
+        PipelineStage::required(maybe_increment_total_satoshis_minted_in_db),
+        PipelineStage::required(maybe_save_processed_deposits_to_db),
+        PipelineStage::required(maybe_remove_old_btc_tail_block),
+        PipelineStage::required(create_btc_output_json_and_put_in_state),
+        PipelineStage::required(
+            remove_minting_params_from_canon_block_and_return_state
+        ),
+        PipelineStage::required(maybe_journal_btc_bridge_events),
+        PipelineStage::required(increment_blocks_processed_metric),
+        PipelineStage::optional("heartbeat", update_btc_heartbeat_in_db),
+        PipelineStage::required(clear_btc_validation_checkpoint),
+        PipelineStage::required(end_db_transaction),
+    ]);
+    pipeline
+}
+
+pub fn submit_parsed_btc_block_to_enclave<D, P>(
+    db: D,
+    dry_run: bool,
+    parse_block_and_put_in_state: P,
+) -> Result<String>
+    where
+        D: DatabaseInterface,
+        P: FnOnce(BtcState<StagedDatabase<&D>>)
+            -> Result<BtcState<StagedDatabase<&D>>>,
+{
+    parse_block_and_put_in_state(
+        BtcState { dry_run, ..BtcState::init(StagedDatabase::new(&db)) }
+    )
+        .map_err(|err|
+            record_error_in_db(&db, dry_run, ErrorContext::new("BTC"), err)
+        )
+        .and_then(|state| {
+            let error_context = ErrorContext::from_state(&state);
+            run_pipeline(state, get_btc_submission_pipeline())
+                .and_then(get_btc_output_as_string)
+                .map_err(|err|
+                    record_error_in_db(&db, dry_run, error_context, err)
+                )
+        })
+        .map_err(record_last_error_code)
+}
+
 pub fn submit_btc_block_to_enclave<D>(
     db: D,
-    block_json_string: String
+    block_json_string: String,
+    dry_run: bool,
 ) -> Result<String>
     where D: DatabaseInterface
 {
     info!("✔ Submitting BTC block to enclave...");
-    parse_btc_block_and_id_and_put_in_state(
-        block_json_string,
-        BtcState::init(db),
+    submit_parsed_btc_block_to_enclave(db, dry_run, |state|
+        check_submission_size_is_within_limit(state, &block_json_string)
+            .and_then(|state| parse_btc_block_and_id_and_put_in_state(
+                block_json_string,
+                state,
+            ))
+    )
+}
+
+pub fn submit_bitcoind_block_to_enclave<D>(
+    db: D,
+    block_json_string: String,
+    dry_run: bool,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Submitting `bitcoind` BTC block to enclave...");
+    submit_parsed_btc_block_to_enclave(db, dry_run, |state|
+        check_submission_size_is_within_limit(state, &block_json_string)
+            .and_then(|state| parse_bitcoind_block_and_put_in_state(
+                block_json_string,
+                state,
+            ))
     )
-        .and_then(check_enclave_is_initialized_and_return_btc_state)
-        .and_then(start_btc_db_transaction)
-        .and_then(check_for_parent_of_btc_block_in_state)
-        .and_then(validate_btc_block_header_in_state)
-        .and_then(validate_difficulty_of_btc_block_in_state)
-        .and_then(validate_proof_of_work_of_btc_block_in_state)
-        .and_then(validate_btc_merkle_root)
-        .and_then(get_deposit_info_hash_map_and_put_in_state)
-        .and_then(filter_op_return_deposit_txs_and_add_to_state)
-        .and_then(filter_p2sh_deposit_txs_and_add_to_state)
-        .and_then(parse_minting_params_from_op_return_deposits_and_add_to_state)
-        .and_then(parse_minting_params_from_p2sh_deposits_and_add_to_state)
-        .and_then(maybe_extract_utxos_from_op_return_txs_and_put_in_state)
-        .and_then(maybe_extract_utxos_from_p2sh_txs_and_put_in_state)
-        .and_then(maybe_filter_utxos_in_state)
-        .and_then(maybe_save_utxos_to_db)
-        .and_then(maybe_filter_minting_params_in_state)
-        .and_then(create_btc_block_in_db_format_and_put_in_state)
-        .and_then(maybe_add_btc_block_to_db)
-        .and_then(maybe_update_btc_latest_block_hash)
-        .and_then(maybe_update_btc_canon_block_hash)
-        .and_then(maybe_update_btc_tail_block_hash)
-        .and_then(maybe_update_btc_linker_hash)
-        .and_then(maybe_sign_canon_block_transactions_and_add_to_state)
-        .and_then(maybe_increment_eth_nonce_in_db)
-        .and_then(maybe_remove_old_btc_tail_block)
-        .and_then(create_btc_output_json_and_put_in_state)
-        .and_then(remove_minting_params_from_canon_block_and_return_state)
-        .and_then(end_btc_db_transaction)
-        .and_then(get_btc_output_as_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::network::constants::Network as BtcNetwork;
+    use crate::{
+        test_utils::get_test_database,
+        pipeline::set_pipeline_stage_enabled_in_db,
+        btc::{
+            btc_database_utils::{
+                put_btc_address_in_db,
+                put_btc_network_in_db,
+                put_btc_private_key_in_db,
+            },
+            btc_test_utils::{
+                get_sample_btc_block_n,
+                SAMPLE_TARGET_BTC_ADDRESS,
+                get_sample_btc_private_key,
+                get_sample_p2sh_utxo_and_value,
+            },
+        },
+    };
+
+    #[test]
+    fn should_extract_utxos_of_disabled_deposit_types() {
+        let db = get_test_database();
+        put_btc_network_in_db(&db, &BtcNetwork::Testnet).unwrap();
+        put_btc_private_key_in_db(&db, &get_sample_btc_private_key())
+            .unwrap();
+        put_btc_address_in_db(&db, &SAMPLE_TARGET_BTC_ADDRESS.to_string())
+            .unwrap();
+        set_pipeline_stage_enabled_in_db(&db, "p2sh_deposits", false).unwrap();
+        set_pipeline_stage_enabled_in_db(&db, "op_return_deposits", false)
+            .unwrap();
+        let state = BtcState::init(db)
+            .add_btc_block_and_id(get_sample_btc_block_n(5).unwrap())
+            .and_then(get_deposit_info_hash_map_and_put_in_state)
+            .unwrap();
+        let result = run_pipeline(state, get_btc_deposit_pipeline()).unwrap();
+        assert!(result.minting_params.is_empty());
+        assert!(
+            result.utxos_and_values
+                .contains(&get_sample_p2sh_utxo_and_value().unwrap())
+        );
+    }
 }
@@ -3,25 +3,27 @@ use crate::{
     traits::DatabaseInterface,
     btc::{
         btc_state::BtcState,
-        btc_types::BtcBlockInDbFormat,
+        btc_types::BtcBlockHeaderInDbFormat,
         btc_database_utils::{
-            get_btc_canon_block_from_db,
-            get_btc_latest_block_from_db,
+            get_btc_block_header_from_db,
             put_btc_canon_block_hash_in_db,
+            get_btc_canon_block_hash_from_db,
+            get_btc_latest_block_hash_from_db,
             get_btc_canon_to_tip_length_from_db,
-            maybe_get_nth_ancestor_btc_block_and_id,
+            maybe_get_nth_ancestor_btc_block_header,
         },
     },
 };
 
 fn does_canon_block_require_updating<D>(
     db: &D,
-    calculated_canon_block: &BtcBlockInDbFormat,
+    calculated_canon_block: &BtcBlockHeaderInDbFormat,
 ) -> Result<bool>
     where D: DatabaseInterface
 {
     info!("✔ Checking if BTC canon block needs updating...");
-    get_btc_canon_block_from_db(db)
+    get_btc_canon_block_hash_from_db(db)
+        .and_then(|hash| get_btc_block_header_from_db(db, &hash))
         .map(|db_canon_block_and_receipts|
             db_canon_block_and_receipts.height <=
             calculated_canon_block.height - 1
@@ -35,11 +37,11 @@ pub fn maybe_update_btc_canon_block_hash<D>(
 {
     info!("✔ Maybe updating BTC canon block hash...");
     let canon_to_tip_length = get_btc_canon_to_tip_length_from_db(&state.db)?;
-    get_btc_latest_block_from_db(&state.db)
-        .map(|latest_btc_block| {
-            maybe_get_nth_ancestor_btc_block_and_id(
+    get_btc_latest_block_hash_from_db(&state.db)
+        .map(|latest_btc_block_hash| {
+            maybe_get_nth_ancestor_btc_block_header(
                 &state.db,
-                &latest_btc_block.id,
+                &latest_btc_block_hash,
                 &canon_to_tip_length,
             )
         })
@@ -4,8 +4,8 @@ use crate::{
     btc::{
         btc_state::BtcState,
         btc_database_utils::{
-            get_btc_latest_block_from_db,
             put_btc_latest_block_hash_in_db,
+            get_special_btc_block_header_from_db,
         },
     },
 };
@@ -22,7 +22,7 @@ pub fn maybe_update_btc_latest_block_hash<D>(
 ) -> Result<BtcState<D>>
     where D: DatabaseInterface
 {
-    get_btc_latest_block_from_db(&state.db)
+    get_special_btc_block_header_from_db(&state.db, "latest")
         .and_then(|latest_block_and_id|
             match is_block_subsequent(
                 &latest_block_and_id.height,
@@ -10,10 +10,10 @@ use crate::{
         btc_constants::PTOKEN_GENESIS_HASH,
         btc_database_utils::{
             put_btc_linker_hash_in_db,
-            get_btc_tail_block_from_db,
             get_btc_linker_hash_from_db,
-            get_btc_anchor_block_from_db,
-            maybe_get_parent_btc_block_and_id,
+            get_btc_tail_block_hash_from_db,
+            get_btc_anchor_block_hash_from_db,
+            maybe_get_parent_btc_block_header,
         },
     },
 };
@@ -71,12 +71,12 @@ fn get_new_linker_hash<D>(
     where D: DatabaseInterface
 {
     info!("✔ Calculating new linker hash...");
-    get_btc_anchor_block_from_db(db)
-        .and_then(|anchor_block|
+    get_btc_anchor_block_hash_from_db(db)
+        .and_then(|anchor_block_hash|
             Ok(
                 calculate_linker_hash(
                     &block_hash_to_link_to,
-                    &anchor_block.id,
+                    &anchor_block_hash,
                     &get_linker_hash_or_genesis_hash(db)?,
                 )
             )
@@ -89,11 +89,11 @@ pub fn maybe_update_btc_linker_hash<D>(
     where D: DatabaseInterface
 {
     info!("✔ Maybe updating BTC linker hash...");
-    get_btc_tail_block_from_db(&state.db)
-        .and_then(|btc_tail_block|
-            match maybe_get_parent_btc_block_and_id(
+    get_btc_tail_block_hash_from_db(&state.db)
+        .and_then(|btc_tail_block_hash|
+            match maybe_get_parent_btc_block_header(
                 &state.db,
-                &btc_tail_block.id
+                &btc_tail_block_hash,
             ) {
                 Some(parent_btc_block) => {
                     info!(
@@ -3,26 +3,28 @@ use crate::{
     traits::DatabaseInterface,
     btc::{
         btc_state::BtcState,
-        btc_types::BtcBlockInDbFormat,
+        btc_types::BtcBlockHeaderInDbFormat,
         btc_constants::BTC_TAIL_LENGTH,
         btc_database_utils::{
-            get_btc_tail_block_from_db,
-            get_btc_latest_block_from_db,
+            get_btc_block_header_from_db,
             put_btc_tail_block_hash_in_db,
+            get_btc_tail_block_hash_from_db,
+            get_btc_latest_block_hash_from_db,
             get_btc_canon_to_tip_length_from_db,
-            maybe_get_nth_ancestor_btc_block_and_id,
+            maybe_get_nth_ancestor_btc_block_header,
         },
     },
 };
 
 fn does_tail_block_require_updating<D>(
     db: &D,
-    calculated_tail_block: &BtcBlockInDbFormat,
+    calculated_tail_block: &BtcBlockHeaderInDbFormat,
 ) -> Result<bool>
     where D: DatabaseInterface
 {
     trace!("✔ Checking if BTC tail block needs updating...");
-    get_btc_tail_block_from_db(db)
+    get_btc_tail_block_hash_from_db(db)
+        .and_then(|hash| get_btc_block_header_from_db(db, &hash))
         .map(|db_tail_block|
             db_tail_block.height <= calculated_tail_block.height - 1
         )
@@ -35,15 +37,15 @@ pub fn maybe_update_btc_tail_block_hash<D>(
 {
     info!("✔ Maybe updating BTC tail block hash...");
     let canon_to_tip_length = get_btc_canon_to_tip_length_from_db(&state.db)?;
-    get_btc_latest_block_from_db(&state.db)
-        .map(|latest_btc_block| {
+    get_btc_latest_block_hash_from_db(&state.db)
+        .map(|latest_btc_block_hash| {
             info!(
                 "✔ Searching for tail block {} blocks back from tip...",
                 canon_to_tip_length + BTC_TAIL_LENGTH,
             );
-            maybe_get_nth_ancestor_btc_block_and_id(
+            maybe_get_nth_ancestor_btc_block_header(
                 &state.db,
-                &latest_btc_block.id,
+                &latest_btc_block_hash,
                 &(canon_to_tip_length + BTC_TAIL_LENGTH),
             )
         })
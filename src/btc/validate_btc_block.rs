@@ -0,0 +1,69 @@
+use crate::{
+    types::Result,
+    stage_hooks::stage,
+    traits::DatabaseInterface,
+    validation_verdict::ValidationVerdict,
+    state_size::{
+        check_state_size_is_within_limit,
+        check_submission_size_is_within_limit,
+    },
+    btc::{
+        btc_state::BtcState,
+        validate_btc_merkle_root::validate_btc_merkle_root,
+        parse_btc_block::parse_btc_block_and_id_and_put_in_state,
+        validate_btc_block_header::validate_btc_block_header_in_state,
+        validate_btc_difficulty::validate_difficulty_of_btc_block_in_state,
+        validate_btc_proof_of_work::{
+            validate_proof_of_work_of_btc_block_in_state,
+        },
+    },
+};
+
+pub fn validate_btc_block_in_state<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    stage(validate_btc_block_header_in_state)(state)
+        .and_then(stage(validate_difficulty_of_btc_block_in_state))
+        .and_then(stage(validate_proof_of_work_of_btc_block_in_state))
+        .and_then(stage(validate_btc_merkle_root))
+}
+
+pub fn validate_btc_block<D>(
+    db: D,
+    block_json_string: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Validating BTC block without submitting it...");
+    let validation_result = check_submission_size_is_within_limit(
+        BtcState::init(db),
+        &block_json_string,
+    )
+        .and_then(|state| parse_btc_block_and_id_and_put_in_state(
+            block_json_string,
+            state,
+        ))
+        .and_then(check_state_size_is_within_limit)
+        .and_then(validate_btc_block_in_state)
+        .and_then(|state| Ok(state.get_btc_block_and_id()?.id.to_string()));
+    ValidationVerdict::from_validation_result("BTC", validation_result)
+        .to_json_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_test_database;
+
+    #[test]
+    fn should_return_invalid_verdict_for_unparseable_block() {
+        let verdict: ValidationVerdict = serde_json::from_str(
+            &validate_btc_block(get_test_database(), "{}".to_string())
+                .unwrap()
+        ).unwrap();
+        assert!(!verdict.is_valid);
+        assert!(verdict.error.is_some());
+    }
+}
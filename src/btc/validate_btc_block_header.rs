@@ -15,7 +15,7 @@ fn validate_btc_block_header(btc_block_and_id: &BtcBlockAndId) -> Result<()> {
             info!("✔ BTC block header valid!");
             Ok(())
         }
-        false => Err(AppError::Custom(
+        false => Err(AppError::InvalidBlock(
             "✘ Invalid BTC block! Block header hash does not match block id!"
                 .to_string()
         ))
@@ -67,7 +67,7 @@ mod tests {
             id: sha256d::Hash::from_str(&wrong_block_id).unwrap(),
         };
         match validate_btc_block_header(&invalid_block_and_id) {
-            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            Err(AppError::InvalidBlock(e)) => assert!(e == expected_error),
             Ok(_) => panic!("Should not be valid!"),
             Err(_) => panic!("Wrong error for invalid btc block!"),
         }
@@ -38,7 +38,7 @@ fn check_difficulty_is_above_threshold(
                             btc_block_header.difficulty(*network),
                             threshold,
                         );
-                        Err(AppError::Custom(
+                        Err(AppError::InvalidBlock(
                             "✘ Invalid block! Difficulty is below threshold!"
                                 .to_string()
                         ))
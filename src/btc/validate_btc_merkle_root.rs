@@ -12,7 +12,7 @@ fn validate_merkle_root(btc_block: &BtcBlock) -> Result<()> {
             info!("✔ Merkle-root valid!");
             Ok(())
         }
-        false => Err(AppError::Custom(
+        false => Err(AppError::InvalidBlock(
             "✘ Invalid block! Merkle root doesn't match calculated merkle root!"
                 .to_string()
         ))
@@ -14,7 +14,7 @@ fn validate_proof_of_work_in_block(
             info!("✔ BTC block's proof-of-work is valid!");
             Ok(())
         }
-        Err(_) => Err(AppError::Custom(
+        Err(_) => Err(AppError::InvalidBlock(
             "✘ Invalid block! PoW validation error: Block hash > target!"
                 .to_string()
         ))
@@ -0,0 +1,77 @@
+use bitcoin::{
+    consensus::encode::serialize as btc_serialize,
+    hashes::{
+        Hash,
+        sha256d,
+    },
+};
+use crate::{
+    traits::DatabaseInterface,
+    utils::convert_u64_to_bytes,
+    staged_database::StagedDatabase,
+    constants::BTC_VALIDATION_CHECKPOINT_KEY,
+    types::{
+        Bytes,
+        Result,
+    },
+    validation_checkpoint::{
+        clear_validation_checkpoint,
+        validate_unless_checkpointed,
+    },
+    btc::{
+        btc_state::BtcState,
+        btc_types::BtcBlockAndId,
+        validate_btc_block::validate_btc_block_in_state,
+    },
+};
+
+fn get_btc_block_digest(block_and_id: &BtcBlockAndId) -> Bytes {
+    sha256d::Hash::hash(
+        &[
+            btc_serialize(&block_and_id.block),
+            block_and_id.id.to_vec(),
+            convert_u64_to_bytes(&block_and_id.height),
+        ].concat()
+    ).to_vec()
+}
+
+pub fn validate_btc_block_unless_checkpointed<D>(
+    state: BtcState<StagedDatabase<D>>
+) -> Result<BtcState<StagedDatabase<D>>>
+    where D: DatabaseInterface
+{
+    info!("✔ Validating BTC block unless already checkpointed...");
+    let block_digest = get_btc_block_digest(state.get_btc_block_and_id()?);
+    validate_unless_checkpointed(
+        state,
+        &BTC_VALIDATION_CHECKPOINT_KEY,
+        block_digest,
+        validate_btc_block_in_state,
+    )
+}
+
+pub fn clear_btc_validation_checkpoint<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    clear_validation_checkpoint(&state.db, &BTC_VALIDATION_CHECKPOINT_KEY)
+        .map(|_| state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::btc::btc_test_utils::get_sample_btc_block_and_id;
+
+    #[test]
+    fn should_get_different_digests_for_different_heights() {
+        let block_and_id = get_sample_btc_block_and_id().unwrap();
+        let mut other_block_and_id = block_and_id.clone();
+        other_block_and_id.height += 1;
+        assert_ne!(
+            get_btc_block_digest(&block_and_id),
+            get_btc_block_digest(&other_block_and_id),
+        );
+    }
+}
@@ -0,0 +1,188 @@
+#![cfg(feature="script-verification")]
+// NOTE: Differential check of the core's BTC signing against the reference
+// script interpreter in `libbitcoinconsensus`, so that a sighash or script bug
+// fails the tests rather than a broadcast on a real network.
+use bitcoin::{
+    consensus::encode::serialize as btc_serialize,
+    network::constants::Network as BtcNetwork,
+    util::address::Address as BtcAddress,
+    hashes::{
+        Hash,
+        sha256d,
+    },
+    blockdata::{
+        script::Script as BtcScript,
+        transaction::Transaction as BtcTransaction,
+    },
+};
+use crate::{
+    types::Result,
+    errors::AppError,
+    btc::{
+        btc_types::BtcUtxoAndValue,
+        btc_utils::get_p2sh_redeem_script_sig,
+    },
+};
+
+// NOTE: A `p2pkh` UTXO stores the script it's locked to in its `script_sig`,
+// whereas a `p2sh` one's is rebuilt from its deposit info. The network only
+// affects the address' encoding, not its script.
+fn get_spent_output_script(
+    utxo_and_value: &BtcUtxoAndValue,
+    btc_pub_key_slice: &[u8],
+) -> Result<BtcScript> {
+    match &utxo_and_value.maybe_deposit_info_json {
+        None => Ok(utxo_and_value.get_utxo()?.script_sig),
+        Some(deposit_info_json) => {
+            let redeem_script = get_p2sh_redeem_script_sig(
+                btc_pub_key_slice,
+                &sha256d::Hash::from_slice(
+                    &hex::decode(
+                        &deposit_info_json.eth_address_and_nonce_hash
+                    )?
+                )?,
+            );
+            Ok(
+                BtcAddress::p2sh(&redeem_script, BtcNetwork::Bitcoin)
+                    .script_pubkey()
+            )
+        }
+    }
+}
+
+pub fn verify_btc_tx_scripts(
+    signed_tx: &BtcTransaction,
+    spent_utxos_and_values: &[BtcUtxoAndValue],
+    btc_pub_key_slice: &[u8],
+) -> Result<()> {
+    info!("✔ Verifying BTC tx scripts via `libbitcoinconsensus`...");
+    if signed_tx.input.len() != spent_utxos_and_values.len() {
+        return Err(AppError::InvalidTransaction(
+            format!(
+                "✘ BTC tx has {} inputs but {} spent UTXOs were supplied!",
+                signed_tx.input.len(),
+                spent_utxos_and_values.len(),
+            )
+        ))
+    };
+    let serialized_tx = btc_serialize(signed_tx);
+    spent_utxos_and_values
+        .iter()
+        .enumerate()
+        .map(|(i, utxo_and_value)|
+            get_spent_output_script(utxo_and_value, btc_pub_key_slice)
+                .and_then(|script|
+                    bitcoinconsensus::verify(
+                        script.as_bytes(),
+                        utxo_and_value.value,
+                        &serialized_tx,
+                        i,
+                    )
+                        .map_err(|err| AppError::InvalidTransaction(
+                            format!(
+                                "✘ Input #{} of BTC tx {} failed {}: {:?}",
+                                i,
+                                signed_tx.txid(),
+                                "`libbitcoinconsensus` verification",
+                                err,
+                            )
+                        ))
+                )
+        )
+        .collect::<Result<()>>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::btc::{
+        btc_transaction::create_signed_raw_btc_tx_for_n_input_n_outputs,
+        btc_types::{
+            BtcRecipientAndAmount,
+            DepositAddressInfoJson,
+        },
+        btc_test_utils::{
+            SAMPLE_TARGET_BTC_ADDRESS,
+            get_sample_btc_private_key,
+            get_sample_op_return_utxo_and_value_n,
+        },
+    };
+
+    // NOTE: Locked to the sample key by construction, since it's the key the
+    // core signs with that determines the `p2sh` redeem script.
+    fn get_sample_p2sh_utxo_and_value() -> BtcUtxoAndValue {
+        let mut utxo = get_sample_op_return_utxo_and_value_n(3)
+            .unwrap()
+            .get_utxo()
+            .unwrap();
+        utxo.previous_output.vout = 1337;
+        BtcUtxoAndValue::new(
+            1_000_000,
+            &utxo,
+            Some(
+                DepositAddressInfoJson::new(
+                    1337,
+                    "fedfe2616eb3661cb8fed2782f5f0cc91d59dcac".to_string(),
+                    SAMPLE_TARGET_BTC_ADDRESS.to_string(),
+                    hex::encode(&sha256d::Hash::hash(b"address-and-nonce")[..]),
+                )
+            ),
+            None,
+        )
+    }
+
+    fn get_sample_utxos_and_values() -> Vec<BtcUtxoAndValue> {
+        vec![
+            get_sample_op_return_utxo_and_value_n(3).unwrap(),
+            get_sample_op_return_utxo_and_value_n(4).unwrap(),
+            get_sample_p2sh_utxo_and_value(),
+        ]
+    }
+
+    fn get_sample_signed_tx() -> BtcTransaction {
+        create_signed_raw_btc_tx_for_n_input_n_outputs(
+            23,
+            vec![
+                BtcRecipientAndAmount::new(
+                    "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM",
+                    1337,
+                ).unwrap()
+            ],
+            SAMPLE_TARGET_BTC_ADDRESS,
+            get_sample_btc_private_key(),
+            get_sample_utxos_and_values(),
+        ).unwrap()
+    }
+
+    #[test]
+    fn should_verify_scripts_of_core_signed_p2pkh_and_p2sh_inputs() {
+        let result = verify_btc_tx_scripts(
+            &get_sample_signed_tx(),
+            &get_sample_utxos_and_values(),
+            &get_sample_btc_private_key().to_public_key_slice(),
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_reject_tx_altered_after_signing() {
+        let mut tx = get_sample_signed_tx();
+        tx.output[0].value += 1;
+        let result = verify_btc_tx_scripts(
+            &tx,
+            &get_sample_utxos_and_values(),
+            &get_sample_btc_private_key().to_public_key_slice(),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_error_if_spent_utxos_do_not_match_inputs() {
+        let result = verify_btc_tx_scripts(
+            &get_sample_signed_tx(),
+            &get_sample_utxos_and_values()[..1],
+            &get_sample_btc_private_key().to_public_key_slice(),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{
+    types::Result,
+    eth::eth_state::EthState,
+    btc::btc_state::BtcState,
+    traits::DatabaseInterface,
+    stage_hooks::StageView,
+    stage_timing::StageTiming,
+};
+
+pub trait ChainState: Sized {
+    type Db: DatabaseInterface;
+
+    fn chain_name() -> &'static str;
+
+    fn db(&self) -> &Self::Db;
+
+    fn is_dry_run(&self) -> bool;
+
+    fn add_stage_timing(self, stage_timing: StageTiming) -> Self;
+
+    fn get_stage_view(&self, stage: &str) -> StageView;
+
+    fn get_block_hash(&self) -> Option<String>;
+
+    fn get_block_height(&self) -> Option<u64>;
+
+    fn get_size_in_bytes(&self) -> usize;
+}
+
+impl<D> ChainState for BtcState<D> where D: DatabaseInterface {
+    type Db = D;
+
+    fn chain_name() -> &'static str {
+        "BTC"
+    }
+
+    fn db(&self) -> &D {
+        &self.db
+    }
+
+    fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    fn add_stage_timing(mut self, stage_timing: StageTiming) -> Self {
+        self.stage_timings.push(stage_timing);
+        self
+    }
+
+    fn get_stage_view(&self, stage: &str) -> StageView {
+        StageView {
+            stage: stage.to_string(),
+            dry_run: self.dry_run,
+            chain: Self::chain_name().to_string(),
+            block_hash: self.get_block_hash(),
+            senders: self
+                .minting_params
+                .iter()
+                .map(|params| params.originating_tx_address.clone())
+                .collect(),
+            recipients: self
+                .minting_params
+                .iter()
+                .map(|params| format!("0x{}", hex::encode(params.eth_address)))
+                .collect(),
+        }
+    }
+
+    fn get_block_hash(&self) -> Option<String> {
+        self
+            .btc_block_and_id
+            .as_option()
+            .map(|block_and_id| block_and_id.id.to_string())
+    }
+
+    fn get_block_height(&self) -> Option<u64> {
+        self
+            .btc_block_and_id
+            .as_option()
+            .map(|block_and_id| block_and_id.height)
+    }
+
+    fn get_size_in_bytes(&self) -> usize {
+        self
+            .btc_block_and_id
+            .as_option()
+            .map(|block_and_id| block_and_id.get_size_in_bytes())
+            .unwrap_or(0)
+    }
+}
+
+impl<D> ChainState for EthState<D> where D: DatabaseInterface {
+    type Db = D;
+
+    fn chain_name() -> &'static str {
+        "ETH"
+    }
+
+    fn db(&self) -> &D {
+        &self.db
+    }
+
+    fn is_dry_run(&self) -> bool {
+        self.dry_run
+    }
+
+    fn add_stage_timing(mut self, stage_timing: StageTiming) -> Self {
+        self.stage_timings.push(stage_timing);
+        self
+    }
+
+    fn get_stage_view(&self, stage: &str) -> StageView {
+        StageView {
+            stage: stage.to_string(),
+            dry_run: self.dry_run,
+            chain: Self::chain_name().to_string(),
+            block_hash: self.get_block_hash(),
+            senders: self
+                .redeem_params
+                .iter()
+                .map(|params| format!("0x{}", hex::encode(params.from)))
+                .collect(),
+            recipients: self
+                .redeem_params
+                .iter()
+                .map(|params| params.recipient.clone())
+                .collect(),
+        }
+    }
+
+    fn get_block_hash(&self) -> Option<String> {
+        self
+            .eth_block_and_receipts
+            .as_option()
+            .map(|block_and_receipts|
+                format!("0x{}", hex::encode(block_and_receipts.block.hash))
+            )
+    }
+
+    fn get_block_height(&self) -> Option<u64> {
+        self
+            .eth_block_and_receipts
+            .as_option()
+            .map(|block_and_receipts| block_and_receipts.block.number.as_u64())
+    }
+
+    fn get_size_in_bytes(&self) -> usize {
+        let proofs_size = match self.eth_receipt_proofs.as_option() {
+            Some(proofs) => proofs
+                .iter()
+                .flatten()
+                .map(|node| node.len())
+                .sum(),
+            None => 0,
+        };
+        proofs_size + self
+            .eth_block_and_receipts
+            .as_option()
+            .map(|block_and_receipts| block_and_receipts.get_size_in_bytes())
+            .unwrap_or(0)
+    }
+}
+
+pub fn db_step<S, F>(db_fn: F) -> impl FnOnce(S) -> Result<S>
+    where S: ChainState, F: FnOnce(&S::Db) -> Result<()>
+{
+    move |state| db_fn(state.db()).map(|_| state)
+}
+
+pub fn start_db_transaction<S>(state: S) -> Result<S>
+    where S: ChainState
+{
+    state
+        .db()
+        .start_transaction()
+        .map(|_| {
+            info!("✔ Database transaction begun for {}!", S::chain_name());
+            state
+        })
+}
+
+pub fn end_db_transaction<S>(state: S) -> Result<S>
+    where S: ChainState
+{
+    if state.is_dry_run() {
+        info!("✔ Dry run ∴ discarding {} db writes!", S::chain_name());
+        return Ok(state)
+    };
+    state
+        .db()
+        .end_transaction()
+        .map(|_| {
+            info!("✔ Database transaction ended for {}!", S::chain_name());
+            state
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        btc::btc_database_utils::{
+            put_btc_fee_in_db,
+            get_btc_fee_from_db,
+        },
+    };
+
+    #[test]
+    fn db_step_should_run_db_fn_and_return_state() {
+        let fee = 1337;
+        let state = Ok(BtcState::init(get_test_database()))
+            .and_then(db_step(|db| put_btc_fee_in_db(db, &fee)))
+            .unwrap();
+        assert_eq!(get_btc_fee_from_db(state.db()).unwrap(), fee);
+    }
+
+    #[test]
+    fn db_step_should_propagate_db_fn_error() {
+        let result = Ok(EthState::init(get_test_database()))
+            .and_then(db_step(|db| get_btc_fee_from_db(db).map(|_| ())));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,343 @@
+use std::fmt::Debug;
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    utxo_manager::utxo_database_utils::{
+        get_utxo_from_db,
+        get_all_utxo_db_keys,
+        get_total_utxo_balance_from_db,
+        get_total_number_of_utxos_from_db,
+    },
+    eth::eth_database_utils::{
+        get_eth_block_header_from_db,
+        get_eth_tail_block_hash_from_db,
+        get_eth_canon_block_hash_from_db,
+        get_eth_latest_block_hash_from_db,
+        get_eth_anchor_block_hash_from_db,
+    },
+    btc::btc_database_utils::{
+        get_btc_block_header_from_db,
+        get_btc_tail_block_hash_from_db,
+        get_btc_canon_block_hash_from_db,
+        get_btc_latest_block_hash_from_db,
+        get_btc_anchor_block_hash_from_db,
+    },
+};
+
+static BLOCK_POINTER_NAMES: [&str; 4] = ["latest", "canon", "tail", "anchor"];
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChainIntegrityReport {
+    pub is_healthy: bool,
+    pub num_blocks_walked: u64,
+    pub errors: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UtxoIntegrityReport {
+    pub is_healthy: bool,
+    pub num_utxos: u64,
+    pub stored_num_utxos: u64,
+    pub utxo_total_value: u64,
+    pub stored_utxo_balance: u64,
+    pub errors: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoreIntegrityReport {
+    pub core_is_healthy: bool,
+    pub btc_chain: ChainIntegrityReport,
+    pub eth_chain: ChainIntegrityReport,
+    pub btc_utxos: UtxoIntegrityReport,
+}
+
+fn walk_chain_from_latest_to_tail<H, F>(
+    chain: &str,
+    latest_hash: H,
+    canon_hash: &H,
+    tail_hash: &H,
+    get_parent_hash: &F,
+    errors: &mut Vec<String>,
+) -> u64
+    where H: PartialEq + Debug, F: Fn(&H) -> Result<H>
+{
+    let mut hash = latest_hash;
+    let mut num_blocks_walked = 0;
+    let mut canon_block_walked = false;
+    loop {
+        let parent_hash = match get_parent_hash(&hash) {
+            Ok(parent_hash) => parent_hash,
+            Err(err) => {
+                errors.push(format!(
+                    "✘ {} chain broken @ block {:?}: {}",
+                    chain,
+                    hash,
+                    err,
+                ));
+                break
+            }
+        };
+        num_blocks_walked += 1;
+        if hash == *canon_hash {
+            canon_block_walked = true;
+        };
+        if hash == *tail_hash {
+            break
+        };
+        hash = parent_hash;
+    }
+    if !canon_block_walked {
+        errors.push(format!(
+            "✘ {} canon block not found between latest & tail blocks!",
+            chain,
+        ));
+    };
+    num_blocks_walked
+}
+
+fn check_chain_integrity<H, F, G>(
+    chain: &str,
+    get_pointer_hash: F,
+    get_parent_hash: G,
+) -> ChainIntegrityReport
+    where
+        H: Clone + PartialEq + Debug,
+        F: Fn(&str) -> Result<H>,
+        G: Fn(&H) -> Result<H>,
+{
+    info!("✔ Checking {} chain integrity...", chain);
+    let mut errors = vec![];
+    let pointer_hashes = BLOCK_POINTER_NAMES
+        .iter()
+        .map(|&name|
+            match get_pointer_hash(name)
+                .and_then(|hash| get_parent_hash(&hash).map(|_| hash))
+            {
+                Ok(hash) => Some(hash),
+                Err(err) => {
+                    errors.push(format!(
+                        "✘ {} {} block pointer is invalid: {}",
+                        chain,
+                        name,
+                        err,
+                    ));
+                    None
+                }
+            }
+        )
+        .collect::<Vec<Option<H>>>();
+    let num_blocks_walked = match (
+        &pointer_hashes[0],
+        &pointer_hashes[1],
+        &pointer_hashes[2],
+    ) {
+        (Some(latest_hash), Some(canon_hash), Some(tail_hash)) =>
+            walk_chain_from_latest_to_tail(
+                chain,
+                latest_hash.clone(),
+                canon_hash,
+                tail_hash,
+                &get_parent_hash,
+                &mut errors,
+            ),
+        _ => 0,
+    };
+    ChainIntegrityReport {
+        num_blocks_walked,
+        is_healthy: errors.is_empty(),
+        errors,
+    }
+}
+
+fn check_btc_chain_integrity<D>(db: &D) -> ChainIntegrityReport
+    where D: DatabaseInterface
+{
+    check_chain_integrity(
+        "BTC",
+        |name| match name {
+            "latest" => get_btc_latest_block_hash_from_db(db),
+            "canon" => get_btc_canon_block_hash_from_db(db),
+            "tail" => get_btc_tail_block_hash_from_db(db),
+            _ => get_btc_anchor_block_hash_from_db(db),
+        },
+        |hash| get_btc_block_header_from_db(db, hash)
+            .map(|block_header| block_header.header.prev_blockhash),
+    )
+}
+
+fn check_eth_chain_integrity<D>(db: &D) -> ChainIntegrityReport
+    where D: DatabaseInterface
+{
+    check_chain_integrity(
+        "ETH",
+        |name| match name {
+            "latest" => get_eth_latest_block_hash_from_db(db),
+            "canon" => get_eth_canon_block_hash_from_db(db),
+            "tail" => get_eth_tail_block_hash_from_db(db),
+            _ => get_eth_anchor_block_hash_from_db(db),
+        },
+        |hash| get_eth_block_header_from_db(db, hash)
+            .map(|block_header| block_header.parent_hash),
+    )
+}
+
+fn check_utxo_integrity<D>(db: &D) -> Result<UtxoIntegrityReport>
+    where D: DatabaseInterface
+{
+    info!("✔ Checking UTXO integrity...");
+    let mut errors = vec![];
+    let utxo_db_keys = get_all_utxo_db_keys(db);
+    let utxo_total_value = utxo_db_keys
+        .iter()
+        .map(|key| match get_utxo_from_db(db, key) {
+            Ok(utxo) => utxo.value,
+            Err(err) => {
+                errors.push(format!(
+                    "✘ UTXO @ key {} is invalid: {}",
+                    hex::encode(key),
+                    err,
+                ));
+                0
+            }
+        })
+        .sum::<u64>();
+    let num_utxos = utxo_db_keys.len() as u64;
+    let stored_num_utxos = get_total_number_of_utxos_from_db(db)?;
+    let stored_utxo_balance = get_total_utxo_balance_from_db(db)?;
+    if num_utxos != stored_num_utxos {
+        errors.push(format!(
+            "✘ Found {} UTXOs but stored number of UTXOs is {}!",
+            num_utxos,
+            stored_num_utxos,
+        ));
+    };
+    if utxo_total_value != stored_utxo_balance {
+        errors.push(format!(
+            "✘ UTXOs total {} satoshis but stored UTXO balance is {}!",
+            utxo_total_value,
+            stored_utxo_balance,
+        ));
+    };
+    Ok(
+        UtxoIntegrityReport {
+            num_utxos,
+            stored_num_utxos,
+            utxo_total_value,
+            stored_utxo_balance,
+            is_healthy: errors.is_empty(),
+            errors,
+        }
+    )
+}
+
+pub fn get_core_integrity_report<D>(db: &D) -> Result<CoreIntegrityReport>
+    where D: DatabaseInterface
+{
+    let btc_chain = check_btc_chain_integrity(db);
+    let eth_chain = check_eth_chain_integrity(db);
+    let btc_utxos = check_utxo_integrity(db)?;
+    Ok(
+        CoreIntegrityReport {
+            core_is_healthy:
+                btc_chain.is_healthy &&
+                eth_chain.is_healthy &&
+                btc_utxos.is_healthy,
+            btc_chain,
+            eth_chain,
+            btc_utxos,
+        }
+    )
+}
+
+pub fn check_core_integrity<D>(
+    db: D
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Checking core integrity...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| get_core_integrity_report(&db))
+        .and_then(|report| {
+            match report.core_is_healthy {
+                true => info!("✔ Core integrity check passed!"),
+                false => info!("✘ Core integrity check failed!"),
+            };
+            Ok(serde_json::to_string(&report)?)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        btc::btc_test_utils::get_sample_utxo_and_values,
+        utxo_manager::utxo_database_utils::{
+            save_utxos_to_db,
+            put_total_utxo_balance_in_db,
+        },
+        eth::{
+            eth_types::EthBlockAndReceipts,
+            eth_test_utils::get_sequential_eth_blocks_and_receipts,
+            eth_database_utils::{
+                delete_eth_block_from_db,
+                put_eth_tail_block_hash_in_db,
+                put_eth_canon_block_hash_in_db,
+                put_eth_latest_block_hash_in_db,
+                put_eth_anchor_block_hash_in_db,
+                put_eth_block_and_receipts_in_db,
+            },
+        },
+    };
+
+    #[test]
+    fn should_walk_healthy_eth_chain_then_detect_missing_block() {
+        let db = get_test_database();
+        let blocks = get_sequential_eth_blocks_and_receipts()
+            .into_iter()
+            .take(5)
+            .collect::<Vec<EthBlockAndReceipts>>();
+        blocks
+            .iter()
+            .for_each(|block| put_eth_block_and_receipts_in_db(&db, block)
+                .unwrap()
+            );
+        put_eth_tail_block_hash_in_db(&db, &blocks[0].block.hash).unwrap();
+        put_eth_anchor_block_hash_in_db(&db, &blocks[0].block.hash).unwrap();
+        put_eth_canon_block_hash_in_db(&db, &blocks[2].block.hash).unwrap();
+        put_eth_latest_block_hash_in_db(&db, &blocks[4].block.hash).unwrap();
+        let result = check_eth_chain_integrity(&db);
+        assert!(result.is_healthy);
+        assert_eq!(result.num_blocks_walked, 5);
+        delete_eth_block_from_db(&db, &blocks[3].block.hash).unwrap();
+        let result = check_eth_chain_integrity(&db);
+        assert!(!result.is_healthy);
+        assert_eq!(result.num_blocks_walked, 1);
+    }
+
+    #[test]
+    fn should_report_all_btc_block_pointers_invalid_if_none_in_db() {
+        let db = get_test_database();
+        let result = check_btc_chain_integrity(&db);
+        assert!(!result.is_healthy);
+        assert_eq!(result.num_blocks_walked, 0);
+        assert_eq!(result.errors.len(), BLOCK_POINTER_NAMES.len());
+    }
+
+    #[test]
+    fn should_detect_utxo_balance_mismatch() {
+        let db = get_test_database();
+        let utxos = get_sample_utxo_and_values();
+        let total_value = utxos.iter().map(|utxo| utxo.value).sum::<u64>();
+        save_utxos_to_db(&db, &utxos).unwrap();
+        let result = check_utxo_integrity(&db).unwrap();
+        assert!(result.is_healthy);
+        assert_eq!(result.num_utxos, utxos.len() as u64);
+        assert_eq!(result.utxo_total_value, total_value);
+        put_total_utxo_balance_in_db(&db, &(total_value + 1)).unwrap();
+        let result = check_utxo_integrity(&db).unwrap();
+        assert!(!result.is_healthy);
+        assert_eq!(result.errors.len(), 1);
+    }
+}
@@ -11,9 +11,7 @@ pub fn check_debug_mode() -> Result<()> {
             Ok(())
         }
         false => {
-            Err(AppError::Custom(
-                "✘ Application NOT in debug mode - exiting!".to_string()
-            ))
+            Err(AppError::NotInDebugMode)
         }
     }
 }
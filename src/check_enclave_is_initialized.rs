@@ -19,14 +19,14 @@ pub fn check_enclave_is_initialized<D>(
 {
     info!("✔ Checking enclave is initialized...");
     match is_btc_enclave_initialized(db) {
-        false => Err(AppError::Custom(
-            "✘ BTC side of enclave not initialized!".to_string()
-        )),
+        false => Err(AppError::NotInitialized {
+            chain: "BTC".to_string(),
+        }),
         true => {
             match is_eth_enclave_initialized(db) {
-                false => Err(AppError::Custom(
-                    "✘ ETH side of enclave not initialized!".to_string()
-                )),
+                false => Err(AppError::NotInitialized {
+                    chain: "ETH".to_string(),
+                }),
                 true => Ok(())
             }
         }
@@ -100,8 +100,6 @@ mod tests {
     #[test]
     fn should_error_if_btc_enclave_not_initialized() {
         let db = get_test_database();
-        let expected_error = "✘ BTC side of enclave not initialized!"
-            .to_string();
         if let Err(e) = put_public_eth_address_in_db(
             &db,
             &get_sample_eth_address(),
@@ -113,8 +111,8 @@ mod tests {
             Ok(_) => {
                 panic!("Enc should not be initialized!");
             }
-            Err(AppError::Custom(e)) => {
-                assert!(e == expected_error);
+            Err(AppError::NotInitialized { chain }) => {
+                assert_eq!(chain, "BTC");
             }
             Err(e) => {
                 panic!("Wrong err recieved: {}", e);
@@ -125,8 +123,6 @@ mod tests {
     #[test]
     fn should_error_if_eth_enclave_not_initialized() {
         let db = get_test_database();
-        let expected_error = "✘ ETH side of enclave not initialized!"
-            .to_string();
         if let Err(e) = put_btc_address_in_db(
             &db,
             &SAMPLE_TARGET_BTC_ADDRESS.to_string(),
@@ -140,8 +136,8 @@ mod tests {
             Ok(_) => {
                 panic!("Enc should not be initialized!");
             }
-            Err(AppError::Custom(e)) => {
-                assert!(e == expected_error);
+            Err(AppError::NotInitialized { chain }) => {
+                assert_eq!(chain, "ETH");
             }
             Err(e) => {
                 panic!("Wrong err recieved: {}", e);
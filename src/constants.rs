@@ -10,14 +10,34 @@ pub static ETH_BYTECODE_PATH: &'static str = "./ptoken-erc777-bytecode";
 #[cfg(test)]
 pub static ETH_BYTECODE_PATH: &'static str = "./src/eth/eth_test_utils/ptoken-erc777-bytecode";
 
+#[cfg(not(test))]
+pub static ETH_OPERATOR_BYTECODE_PATH: &'static str = "./ptoken-erc777-operator-bytecode";
+
+// NOTE: A minimal stand-in deploying a distinct runtime, since the operator
+// contract's compiled bytecode isn't in this repo.
+#[cfg(test)]
+pub static ETH_OPERATOR_BYTECODE_PATH: &'static str = "./src/eth/eth_test_utils/ptoken-erc777-operator-bytecode";
+
+pub const DB_SCHEMA_VERSION: u64 = 1;
 pub const HASH_LENGTH: usize = 32;
 pub const U64_NUM_BYTES: usize = 8;
+pub const ETH_ADDRESS_LENGTH: usize = 20;
 pub const BTC_NUM_DECIMALS: u32 = 8;
+#[cfg(test)]
 pub const HASH_HEX_CHARS: usize  = 64;
+#[cfg(test)]
 pub const HEX_PREFIX_LENGTH: usize = 2;
+pub const MAX_HOST_TOKEN_DECIMALS: u32 = 36;
 pub const PTOKEN_ERC777_NUM_DECIMALS: u32 = 18;
 pub const MINIMUM_REQUIRED_SATOSHIS: u64 = 5_000;
-pub const PRIVATE_KEY_DATA_SENSITIVITY_LEVEL: Option<u8> = Some(255);
+pub const DEFAULT_MAX_STATE_SIZE_IN_BYTES: u64 = 32_000_000;
+pub const MAX_EVENT_JOURNAL_PAGE_SIZE: u64 = 1_000;
+pub const MAX_ERROR_LOG_LENGTH: usize = 100;
+pub const DEFAULT_STALE_TX_THRESHOLD_IN_BLOCKS: u64 = 10;
+pub static DB_ITEM_NOT_FOUND_ERROR: &'static str =
+    "✘ Cannot find item in database!";
+pub const MAX_NUM_WATCHED_TXS: usize = 1_000;
+pub const CORE_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub static SAFE_BTC_ADDRESS: &'static str = "136CTERaocm8dLbEtzCaFtJJX9jfFhnChK";
 
 // NOTE: "0x71A440EE9Fa7F99FB9a697e96eC7839B8A1643B8"
@@ -27,3 +47,443 @@ pub static SAFE_ETH_ADDRESS: [u8; 20] = [
     151, 233, 110, 199, 131,
     155, 138, 22, 67, 184
 ];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('core-is-paused-key').slice(2), 'hex')
+// )
+// 2dbf26836d3b232959ad98ef4bb3bc348cc538216a165065db9023bed82a7188
+pub static CORE_IS_PAUSED_KEY: [u8; 32] = [
+  45, 191, 38, 131, 109, 59, 35, 41,
+  89, 173, 152, 239, 75, 179, 188, 52,
+  140, 197, 56, 33, 106, 22, 80, 101,
+  219, 144, 35, 190, 216, 42, 113, 136
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('asset-dictionary-key').slice(2), 'hex')
+// )
+// fce02cbc9dc719053cc4c9eca331254c93ed26cc9581a098b9053ffb71f053e3
+pub static ASSET_DICTIONARY_KEY: [u8; 32] = [
+  252, 224, 44, 188, 157, 199, 25, 5,
+  60, 196, 201, 236, 163, 49, 37, 76,
+  147, 237, 38, 204, 149, 129, 160, 152,
+  185, 5, 63, 251, 113, 240, 83, 227
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('safe-eth-address-key').slice(2), 'hex')
+// )
+// 10db6fef55a8524960ace0530ba0f72ff7b1901dc61268fb6f628f2212fb9f01
+pub static SAFE_ETH_ADDRESS_KEY: [u8; 32] = [
+  16, 219, 111, 239, 85, 168, 82, 73,
+  96, 172, 224, 83, 11, 160, 247, 47,
+  247, 177, 144, 29, 198, 18, 104, 251,
+  111, 98, 143, 34, 18, 251, 159, 1
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('safe-btc-address-key').slice(2), 'hex')
+// )
+// 499419baf8d202dc6db3214a0176e4c746e8eef77ec192a7ee4788163830dd0d
+pub static SAFE_BTC_ADDRESS_KEY: [u8; 32] = [
+  73, 148, 25, 186, 248, 210, 2, 220,
+  109, 179, 33, 74, 1, 118, 228, 199,
+  70, 232, 238, 247, 126, 193, 146, 167,
+  238, 71, 136, 22, 56, 48, 221, 13
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('safe-address-history-key').slice(2), 'hex')
+// )
+// b5342394fe004765c23ab6197124d06b912c1a6759dde9298a4f9c56909de7bf
+pub static SAFE_ADDRESS_HISTORY_KEY: [u8; 32] = [
+  181, 52, 35, 148, 254, 0, 71, 101,
+  194, 58, 182, 25, 113, 36, 208, 107,
+  145, 44, 26, 103, 89, 221, 233, 41,
+  138, 79, 156, 86, 144, 157, 231, 191
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('core-version-info-key').slice(2), 'hex')
+// )
+// aa4dbe219be2656e02fb425f7c9c71151b942c4292c0f8c8a3f10be7f7448192
+pub static CORE_VERSION_INFO_KEY: [u8; 32] = [
+  170, 77, 190, 33, 155, 226, 101, 110,
+  2, 251, 66, 95, 124, 156, 113, 21,
+  27, 148, 44, 66, 146, 192, 248, 200,
+  163, 241, 11, 231, 247, 68, 129, 146
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('debug-admin-public-key-key').slice(2), 'hex')
+// )
+// 83e2bd09b825d740b0ef3bd9fa86d49ace6e037390fbe36b8d173bfe7f2002b8
+pub static DEBUG_ADMIN_PUBLIC_KEY_KEY: [u8; 32] = [
+  131, 226, 189, 9, 184, 37, 215, 64,
+  176, 239, 59, 217, 250, 134, 212, 154,
+  206, 110, 3, 115, 144, 251, 227, 107,
+  141, 23, 59, 254, 127, 32, 2, 184
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('debug-signature-challenge-key').slice(2), 'hex')
+// )
+// 568a36d5f4023fb1234c4f6c22b14b991c6aa353c6445aa8579f57d18d0d9c10
+pub static DEBUG_SIGNATURE_CHALLENGE_KEY: [u8; 32] = [
+  86, 138, 54, 213, 244, 2, 63, 177,
+  35, 76, 79, 108, 34, 177, 75, 153,
+  28, 106, 163, 83, 198, 68, 90, 168,
+  87, 159, 87, 209, 141, 13, 156, 16
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('btc-validation-checkpoint-key').slice(2), 'hex')
+// )
+// d70505a1af175054111ae764062693d1fb35194d8c3970cb5b7d7ee65b26279c
+pub static BTC_VALIDATION_CHECKPOINT_KEY: [u8; 32] = [
+  215, 5, 5, 161, 175, 23, 80, 84,
+  17, 26, 231, 100, 6, 38, 147, 209,
+  251, 53, 25, 77, 140, 57, 112, 203,
+  91, 125, 126, 230, 91, 38, 39, 156
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-validation-checkpoint-key').slice(2), 'hex')
+// )
+// 53760c7cba6a8e8714ce4a66490e777aca9ca1f6c6836c64c0a41b7e05b3e2d7
+pub static ETH_VALIDATION_CHECKPOINT_KEY: [u8; 32] = [
+  83, 118, 12, 124, 186, 106, 142, 135,
+  20, 206, 74, 102, 73, 14, 119, 122,
+  202, 156, 161, 246, 198, 131, 108, 100,
+  192, 164, 27, 126, 5, 179, 226, 215
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('max-state-size-key').slice(2), 'hex')
+// )
+// cfa88eb2ab0c8837160908d8625c83d7e820816b5562b131c847cb312767fed0
+pub static MAX_STATE_SIZE_KEY: [u8; 32] = [
+  207, 168, 142, 178, 171, 12, 136, 55,
+  22, 9, 8, 216, 98, 92, 131, 215,
+  232, 32, 129, 107, 85, 98, 177, 49,
+  200, 71, 203, 49, 39, 103, 254, 208
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('disabled-pipeline-stages-key').slice(2), 'hex')
+// )
+// 14e00605fb47cf494ff14f5cd5cc3a23385485994a288e239f45197d63ce35ed
+pub static DISABLED_PIPELINE_STAGES_KEY: [u8; 32] = [
+  20, 224, 6, 5, 251, 71, 207, 73,
+  79, 241, 79, 92, 213, 204, 58, 35,
+  56, 84, 133, 153, 74, 40, 142, 35,
+  159, 69, 25, 125, 99, 206, 53, 237
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('event-journal-sequence-key').slice(2), 'hex')
+// )
+// 36f8c20a7abbd85c1813a1c987f4ba638c8ddc9b8020e7058ff33b6f1903aa59
+pub static EVENT_JOURNAL_SEQUENCE_KEY: [u8; 32] = [
+  54, 248, 194, 10, 122, 187, 216, 92,
+  24, 19, 161, 201, 135, 244, 186, 99,
+  140, 141, 220, 155, 128, 32, 231, 5,
+  143, 243, 59, 111, 25, 3, 170, 89
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('core-metrics-key').slice(2), 'hex')
+// )
+// fb9a43a2e38ba2f20a4618ab8536d968d12d0162ce5abd0f977bd9daac407fa2
+pub static CORE_METRICS_KEY: [u8; 32] = [
+  251, 154, 67, 162, 227, 139, 162, 242,
+  10, 70, 24, 171, 133, 54, 217, 104,
+  209, 45, 1, 98, 206, 90, 189, 15,
+  151, 123, 217, 218, 172, 64, 127, 162
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('total-satoshis-minted-key').slice(2), 'hex')
+// )
+// e8a9c154e864ca92ba1fd43601aa9239e4981472a18808e3e4700fc283508eec
+pub static TOTAL_SATOSHIS_MINTED_KEY: [u8; 32] = [
+  232, 169, 193, 84, 232, 100, 202, 146,
+  186, 31, 212, 54, 1, 170, 146, 57,
+  228, 152, 20, 114, 161, 136, 8, 227,
+  228, 112, 15, 194, 131, 80, 142, 236
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('total-satoshis-redeemed-key').slice(2), 'hex')
+// )
+// 73991dbb232bbd10597cb6e1096eaf764395a8b8f22961ff1ce0b7e66a3f7715
+pub static TOTAL_SATOSHIS_REDEEMED_KEY: [u8; 32] = [
+  115, 153, 29, 187, 35, 43, 189, 16,
+  89, 124, 182, 225, 9, 110, 175, 118,
+  67, 149, 168, 184, 242, 41, 97, 255,
+  28, 224, 183, 230, 106, 63, 119, 21
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('btc-heartbeat-key').slice(2), 'hex')
+// )
+// ea4684c677b775fc27e8a80550eea334a21fa4715b4905bef4c1cb3bfee507af
+pub static BTC_HEARTBEAT_KEY: [u8; 32] = [
+  234, 70, 132, 198, 119, 183, 117, 252,
+  39, 232, 168, 5, 80, 238, 163, 52,
+  162, 31, 164, 113, 91, 73, 5, 190,
+  244, 193, 203, 59, 254, 229, 7, 175
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-heartbeat-key').slice(2), 'hex')
+// )
+// 3c1701b4af223ad0b70d4b5b10bb50ed840b7d15c29d26d4c9f6d9d84554d221
+pub static ETH_HEARTBEAT_KEY: [u8; 32] = [
+  60, 23, 1, 180, 175, 34, 58, 208,
+  183, 13, 75, 91, 16, 187, 80, 237,
+  132, 11, 125, 21, 194, 157, 38, 212,
+  201, 246, 217, 216, 69, 84, 210, 33
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('log-config-key').slice(2), 'hex')
+// )
+// b72be8989cc8b0bf9cd3f532d505036548494bddfc03c38062c6796f5150fdaa
+pub static LOG_CONFIG_KEY: [u8; 32] = [
+  183, 43, 232, 152, 156, 200, 176, 191,
+  156, 211, 245, 50, 213, 5, 3, 101,
+  72, 73, 75, 221, 252, 3, 195, 128,
+  98, 198, 121, 111, 81, 80, 253, 170
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('error-log-key').slice(2), 'hex')
+// )
+// 087e7d90e51c83fc571f74cf173533df381ae0a185090c21c46e39807ce52f65
+pub static ERROR_LOG_KEY: [u8; 32] = [
+  8, 126, 125, 144, 229, 28, 131, 252,
+  87, 31, 116, 207, 23, 53, 51, 223,
+  56, 26, 224, 161, 133, 9, 12, 33,
+  196, 110, 57, 128, 124, 229, 47, 101
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('watched-signed-txs-key').slice(2), 'hex')
+// )
+// a737255c6ee87bd86b587dd97c4ba828a627953a2db0422cd1a7cbf52f5c4e97
+pub static WATCHED_SIGNED_TXS_KEY: [u8; 32] = [
+  167, 55, 37, 92, 110, 232, 123, 216,
+  107, 88, 125, 217, 124, 75, 168, 40,
+  166, 39, 149, 58, 45, 176, 66, 44,
+  209, 167, 203, 245, 47, 92, 78, 151
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('stale-tx-threshold-key').slice(2), 'hex')
+// )
+// f3d3b002fef38672004e5d3dd7c89fd20886aa8286bd9595ec20939d843f92af
+pub static STALE_TX_THRESHOLD_KEY: [u8; 32] = [
+  243, 211, 176, 2, 254, 243, 134, 114,
+  0, 78, 93, 61, 215, 200, 159, 210,
+  8, 134, 170, 130, 134, 189, 149, 149,
+  236, 32, 147, 157, 132, 63, 146, 175
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('operator-fee-btc-address-key').slice(2), 'hex')
+// )
+// ac54ed810889f910671a45c43e96abc869ade41c6edc1caf248d3dd33c249e72
+pub static OPERATOR_FEE_BTC_ADDRESS_KEY: [u8; 32] = [
+  172, 84, 237, 129, 8, 137, 249, 16,
+  103, 26, 69, 196, 62, 150, 171, 200,
+  105, 173, 228, 28, 110, 220, 28, 175,
+  36, 141, 61, 211, 60, 36, 158, 114
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('mint-volume-limit-key').slice(2), 'hex')
+// )
+// 677188ffcd9723062cce4fa0c4910b5ee06ecd10506cfa8a5b3ef53f0669652d
+pub static MINT_VOLUME_LIMIT_KEY: [u8; 32] = [
+  103, 113, 136, 255, 205, 151, 35, 6,
+  44, 206, 79, 160, 196, 145, 11, 94,
+  224, 110, 205, 16, 80, 108, 250, 138,
+  91, 62, 245, 63, 6, 105, 101, 45
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('payout-volume-limit-key').slice(2), 'hex')
+// )
+// 004aab885f0ebbf5693c7abb98e9dbbde2be05c496057f00bc034b4ec59ca20d
+pub static PAYOUT_VOLUME_LIMIT_KEY: [u8; 32] = [
+  0, 74, 171, 136, 95, 14, 187, 245,
+  105, 60, 122, 187, 152, 233, 219, 189,
+  226, 190, 5, 196, 150, 5, 127, 0,
+  188, 3, 75, 78, 197, 156, 162, 13
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('mint-volume-usage-key').slice(2), 'hex')
+// )
+// 2e2551390b029fb0ab7698019da91e2830a2e5eff02ddb49d0bc0e615b56f70f
+pub static MINT_VOLUME_USAGE_KEY: [u8; 32] = [
+  46, 37, 81, 57, 11, 2, 159, 176,
+  171, 118, 152, 1, 157, 169, 30, 40,
+  48, 162, 229, 239, 240, 45, 219, 73,
+  208, 188, 14, 97, 91, 86, 247, 15
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('payout-volume-usage-key').slice(2), 'hex')
+// )
+// acf1bc7b111a430e195db199eb8cb91b08f40a30e263a38f9f4bc27b0d07f845
+pub static PAYOUT_VOLUME_USAGE_KEY: [u8; 32] = [
+  172, 241, 188, 123, 17, 26, 67, 14,
+  25, 93, 177, 153, 235, 140, 185, 27,
+  8, 244, 10, 48, 226, 99, 163, 143,
+  159, 75, 194, 123, 13, 7, 248, 69
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('volume-limited-minting-params-key').slice(2), 'hex')
+// )
+// 79dd9f0c26181eaf3c4438fa89865a0b546f23b1c31cab6871e106501844127d
+pub static VOLUME_LIMITED_MINTING_PARAMS_KEY: [u8; 32] = [
+  121, 221, 159, 12, 38, 24, 30, 175,
+  60, 68, 56, 250, 137, 134, 90, 11,
+  84, 111, 35, 177, 195, 28, 171, 104,
+  113, 225, 6, 80, 24, 68, 18, 125
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('volume-limited-redeem-params-key').slice(2), 'hex')
+// )
+// dcfb128c56e4774ce4650ff3f412424baf656a57195ec6c71c6ec4119bf513fc
+pub static VOLUME_LIMITED_REDEEM_PARAMS_KEY: [u8; 32] = [
+  220, 251, 18, 140, 86, 228, 119, 76,
+  228, 101, 15, 243, 244, 18, 66, 75,
+  175, 101, 106, 87, 25, 94, 198, 199,
+  28, 110, 196, 17, 155, 245, 19, 252
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('recipient-policy-key').slice(2), 'hex')
+// )
+// 337b8ef72ea08ba6985fb6399337fe704242a40f5b9337b42244577dcb5a5ade
+pub static RECIPIENT_POLICY_KEY: [u8; 32] = [
+  51, 123, 142, 247, 46, 160, 139, 166,
+  152, 95, 182, 57, 147, 55, 254, 112,
+  66, 66, 164, 15, 91, 147, 55, 180,
+  34, 68, 87, 125, 203, 90, 90, 222
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('btc-fee-ledger-size-key').slice(2), 'hex')
+// )
+// 3ec55cee2ac8884dd262eb45b86cc55ae93cc65b3880a79f4c679bc249639d15
+pub static BTC_FEE_LEDGER_SIZE_KEY: [u8; 32] = [
+  62, 197, 92, 238, 42, 200, 136, 77,
+  210, 98, 235, 69, 184, 108, 197, 90,
+  233, 60, 198, 91, 56, 128, 167, 159,
+  76, 103, 155, 194, 73, 99, 157, 21
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-fee-ledger-size-key').slice(2), 'hex')
+// )
+// d5080ed185a07d9e546c66e005b9bd8beaaffb86ffab61edc12c920cf5b8c151
+pub static ETH_FEE_LEDGER_SIZE_KEY: [u8; 32] = [
+  213, 8, 14, 209, 133, 160, 125, 158,
+  84, 108, 102, 224, 5, 185, 189, 139,
+  234, 175, 251, 134, 255, 171, 97, 237,
+  193, 44, 146, 12, 245, 184, 193, 81
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('refund-ledger-key').slice(2), 'hex')
+// )
+// 383b062c2b505d3fdd68f056654b6d86638bdffdd3a9d5feb52858ed49a66f73
+pub static REFUND_LEDGER_KEY: [u8; 32] = [
+  56, 59, 6, 44, 43, 80, 93, 63,
+  221, 104, 240, 86, 101, 75, 109, 134,
+  99, 139, 223, 253, 211, 169, 213, 254,
+  181, 40, 88, 237, 73, 166, 111, 115
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('provisional-refund-ledger-key').slice(2), 'hex')
+// )
+// 4ce7580c16b48f25e656844be66d34199049bb5ff826814bb4bfdc656d8c4a78
+pub static PROVISIONAL_REFUND_LEDGER_KEY: [u8; 32] = [
+  76, 231, 88, 12, 22, 180, 143, 37,
+  230, 86, 132, 75, 230, 109, 52, 25,
+  144, 73, 187, 95, 248, 38, 129, 75,
+  180, 191, 220, 101, 109, 140, 74, 120
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('safe-address-tiers-key').slice(2), 'hex')
+// )
+// 4fde4d79c7b6eddfe22fa658e8ea8bdb87241e26ee285c2637863ddf3bd4b22d
+pub static SAFE_ADDRESS_TIERS_KEY: [u8; 32] = [
+  79, 222, 77, 121, 199, 182, 237, 223,
+  226, 47, 166, 88, 232, 234, 139, 219,
+  135, 36, 30, 38, 238, 40, 92, 38,
+  55, 134, 61, 223, 59, 212, 178, 45
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('mint-approval-threshold-key').slice(2), 'hex')
+// )
+// ade0843de2188016a455761536448f6898b903ca50fd34be450d0b8482767d05
+pub static MINT_APPROVAL_THRESHOLD_KEY: [u8; 32] = [
+  173, 224, 132, 61, 226, 24, 128, 22,
+  164, 85, 118, 21, 54, 68, 143, 104,
+  152, 185, 3, 202, 80, 253, 52, 190,
+  69, 13, 11, 132, 130, 118, 125, 5
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('payout-approval-threshold-key').slice(2), 'hex')
+// )
+// a7b550aa554a56c417fced49304105afa3b00bc7ecd4849e19b7d4ca854f4011
+pub static PAYOUT_APPROVAL_THRESHOLD_KEY: [u8; 32] = [
+  167, 181, 80, 170, 85, 74, 86, 196,
+  23, 252, 237, 73, 48, 65, 5, 175,
+  163, 176, 11, 199, 236, 212, 132, 158,
+  25, 183, 212, 202, 133, 79, 64, 17
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('pending-approval-minting-params-key').slice(2), 'hex')
+// )
+// 5ac9a852328bde58e6d4d623c6340b22f5e587b9688390ee3a6f27873562bb7b
+pub static PENDING_APPROVAL_MINTING_PARAMS_KEY: [u8; 32] = [
+  90, 201, 168, 82, 50, 139, 222, 88,
+  230, 212, 214, 35, 198, 52, 11, 34,
+  245, 229, 135, 185, 104, 131, 144, 238,
+  58, 111, 39, 135, 53, 98, 187, 123
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('pending-approval-redeem-params-key').slice(2), 'hex')
+// )
+// 25f7a0e932b2121d5dc917db6015da004185e966ac9b7503bfa7969e002efd9d
+pub static PENDING_APPROVAL_REDEEM_PARAMS_KEY: [u8; 32] = [
+  37, 247, 160, 233, 50, 178, 18, 29,
+  93, 201, 23, 219, 96, 21, 218, 0,
+  65, 133, 233, 102, 172, 155, 117, 3,
+  191, 167, 150, 158, 0, 46, 253, 157
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('db-serialization-format-key').slice(2), 'hex')
+// )
+// 649dfbce0ff5e2eebc6d38252410596838e11a06c6e7fc397ecc0c64dcbbc616
+pub static DB_SERIALIZATION_FORMAT_KEY: [u8; 32] = [
+  100, 157, 251, 206, 15, 245, 226, 238,
+  188, 109, 56, 37, 36, 16, 89, 104,
+  56, 225, 26, 6, 198, 231, 252, 57,
+  126, 204, 12, 100, 220, 187, 198, 22
+];
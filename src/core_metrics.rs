@@ -0,0 +1,151 @@
+use std::{
+    sync::Mutex,
+    collections::BTreeMap,
+};
+use crate::{
+    errors::AppError,
+    chain_state::ChainState,
+    constants::CORE_METRICS_KEY,
+    traits::DatabaseInterface,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    event_journal::{
+        BridgeEvent,
+        BridgeEventType,
+    },
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    utxo_manager::utxo_database_utils::{
+        get_total_utxo_balance_from_db,
+        get_total_number_of_utxos_from_db,
+    },
+};
+
+pub type CoreMetrics = BTreeMap<String, u64>;
+
+lazy_static! {
+    static ref LAST_ERROR_CODE: Mutex<Option<u16>> = Mutex::new(None);
+}
+
+// NOTE: Kept in memory since a failed submission's db writes are discarded.
+pub fn record_last_error_code(err: AppError) -> AppError {
+    if let Ok(mut last_error_code) = LAST_ERROR_CODE.lock() {
+        *last_error_code = Some(err.code());
+    };
+    err
+}
+
+fn get_last_error_code() -> u64 {
+    LAST_ERROR_CODE
+        .lock()
+        .ok()
+        .and_then(|last_error_code| *last_error_code)
+        .map(u64::from)
+        .unwrap_or(0)
+}
+
+fn get_event_metric_name(event_type: &BridgeEventType) -> &'static str {
+    match event_type {
+        BridgeEventType::MintSigned => "mints_signed",
+        BridgeEventType::ReorgHandled => "reorgs_handled",
+        BridgeEventType::PayoutSigned => "payouts_signed",
+        BridgeEventType::PegInDetected => "peg_ins_detected",
+        BridgeEventType::RedeemDetected => "redeems_detected",
+    }
+}
+
+pub fn get_core_metrics_from_db<D>(db: &D) -> Result<CoreMetrics>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting core metrics from db...");
+    match db.get(CORE_METRICS_KEY.to_vec(), DataSensitivity::Public) {
+        Err(_) => Ok(CoreMetrics::new()),
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+    }
+}
+
+fn increment_core_metrics_in_db<D>(db: &D, names: Vec<&str>) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Incrementing {} core metrics in db...", names.len());
+    let mut metrics = get_core_metrics_from_db(db)?;
+    names
+        .iter()
+        .for_each(|name| *metrics.entry(name.to_string()).or_insert(0) += 1);
+    db.put(
+        CORE_METRICS_KEY.to_vec(),
+        serde_json::to_vec(&metrics)?,
+        DataSensitivity::Public,
+    )
+}
+
+pub fn increment_event_metrics_in_db<D>(
+    db: &D,
+    events: &[BridgeEvent],
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    increment_core_metrics_in_db(
+        db,
+        events
+            .iter()
+            .map(|event| get_event_metric_name(&event.event_type))
+            .collect(),
+    )
+}
+
+pub fn increment_blocks_processed_metric<S>(state: S) -> Result<S>
+    where S: ChainState
+{
+    info!("✔ Incrementing {} blocks processed metric...", S::chain_name());
+    let name = format!("{}_blocks_processed", S::chain_name().to_lowercase());
+    increment_core_metrics_in_db(state.db(), vec![&name]).map(|_| state)
+}
+
+pub fn get_core_metrics<D>(db: D) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting core metrics...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| get_core_metrics_from_db(&db))
+        .and_then(|mut metrics| {
+            metrics.insert(
+                "utxo_count".to_string(),
+                get_total_number_of_utxos_from_db(&db)?,
+            );
+            metrics.insert(
+                "utxo_value".to_string(),
+                get_total_utxo_balance_from_db(&db)?,
+            );
+            metrics.insert(
+                "last_error_code".to_string(),
+                get_last_error_code(),
+            );
+            Ok(serde_json::to_string(&metrics)?)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        btc::btc_state::BtcState,
+        test_utils::get_test_database,
+    };
+
+    #[test]
+    fn should_increment_core_metrics() {
+        let db = get_test_database();
+        let events = vec![
+            BridgeEvent::new(BridgeEventType::MintSigned, "0x".into(), None),
+            BridgeEvent::new(BridgeEventType::MintSigned, "0x".into(), None),
+        ];
+        increment_event_metrics_in_db(&db, &events).unwrap();
+        let state = increment_blocks_processed_metric(BtcState::init(db))
+            .unwrap();
+        let metrics = get_core_metrics_from_db(&state.db).unwrap();
+        assert_eq!(metrics["mints_signed"], 2);
+        assert_eq!(metrics["btc_blocks_processed"], 1);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{
+    traits::DatabaseInterface,
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    constants::{
+        CORE_VERSION,
+        DB_SCHEMA_VERSION,
+        CORE_VERSION_INFO_KEY,
+    },
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoreVersionInfo {
+    pub core_version: String,
+    pub db_schema_version: u64,
+    pub enabled_features: Vec<String>,
+}
+
+impl CoreVersionInfo {
+    pub fn from_this_build() -> Self {
+        CoreVersionInfo {
+            core_version: CORE_VERSION.to_string(),
+            db_schema_version: DB_SCHEMA_VERSION,
+            enabled_features: get_enabled_features(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoreVersionReport {
+    pub this_build: CoreVersionInfo,
+    pub database: Option<CoreVersionInfo>,
+}
+
+// NOTE: Must list every feature in the `Cargo.toml`, as its test checks.
+fn get_feature_flags() -> Vec<(&'static str, bool)> {
+    vec![
+        ("sgx", cfg!(feature = "sgx")),
+        ("ffi", cfg!(feature = "ffi")),
+        ("wasm", cfg!(feature = "wasm")),
+        ("python", cfg!(feature = "python")),
+        ("debug", cfg!(feature = "debug")),
+        ("async", cfg!(feature = "async")),
+        ("fuzzing", cfg!(feature = "fuzzing")),
+        ("parallel", cfg!(feature = "parallel")),
+        ("protobuf", cfg!(feature = "protobuf")),
+        ("rocksdb", cfg!(feature = "rocksdb")),
+        ("reorg-kit", cfg!(feature = "reorg-kit")),
+        ("benchmarks", cfg!(feature = "benchmarks")),
+        ("btc-on-eth", cfg!(feature = "btc-on-eth")),
+        ("simulation", cfg!(feature = "simulation")),
+        ("test-doubles", cfg!(feature = "test-doubles")),
+        ("stage-timing", cfg!(feature = "stage-timing")),
+        ("ethash-light", cfg!(feature = "ethash-light")),
+        ("test-database", cfg!(feature = "test-database")),
+        ("test-generators", cfg!(feature = "test-generators")),
+        ("python-extension", cfg!(feature = "python-extension")),
+        ("fixture-converter", cfg!(feature = "fixture-converter")),
+        ("script-verification", cfg!(feature = "script-verification")),
+    ]
+}
+
+fn get_enabled_features() -> Vec<String> {
+    get_feature_flags()
+        .into_iter()
+        .filter(|(_, is_enabled)| *is_enabled)
+        .map(|(feature, _)| feature.to_string())
+        .collect()
+}
+
+pub fn get_core_version_info_from_db<D>(
+    db: &D,
+) -> Result<Option<CoreVersionInfo>>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting core version info from db...");
+    match db.get(CORE_VERSION_INFO_KEY.to_vec(), DataSensitivity::Public) {
+        Err(_) => Ok(None),
+        Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+    }
+}
+
+pub fn maybe_put_core_version_info_in_db<D>(db: &D) -> Result<()>
+    where D: DatabaseInterface
+{
+    match get_core_version_info_from_db(db)? {
+        Some(_) => {
+            info!("✔ Core version info already in db ∴ not overwriting it!");
+            Ok(())
+        }
+        None => {
+            info!("✔ Putting core version info in db...");
+            db.put(
+                CORE_VERSION_INFO_KEY.to_vec(),
+                serde_json::to_vec(&CoreVersionInfo::from_this_build())?,
+                DataSensitivity::Public,
+            )
+        }
+    }
+}
+
+pub fn get_core_version_info<D>(db: D) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting core version info...");
+    get_core_version_info_from_db(&db)
+        .map(|database|
+            CoreVersionReport {
+                database,
+                this_build: CoreVersionInfo::from_this_build(),
+            }
+        )
+        .and_then(|report| Ok(serde_json::to_string(&report)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_test_database;
+
+    #[test]
+    fn should_put_core_version_info_in_db_only_once() {
+        let db = get_test_database();
+        assert!(get_core_version_info_from_db(&db).unwrap().is_none());
+        let stale_info = CoreVersionInfo {
+            core_version: "0.0.1".to_string(),
+            db_schema_version: 0,
+            enabled_features: vec![],
+        };
+        db.put(
+            CORE_VERSION_INFO_KEY.to_vec(),
+            serde_json::to_vec(&stale_info).unwrap(),
+            DataSensitivity::Public,
+        ).unwrap();
+        maybe_put_core_version_info_in_db(&db).unwrap();
+        assert_eq!(
+            get_core_version_info_from_db(&db).unwrap(),
+            Some(stale_info),
+        );
+    }
+
+    #[test]
+    fn should_report_this_build_version_info() {
+        let db = get_test_database();
+        maybe_put_core_version_info_in_db(&db).unwrap();
+        let report: CoreVersionReport = serde_json::from_str(
+            &get_core_version_info(db).unwrap()
+        ).unwrap();
+        assert_eq!(report.this_build.core_version, CORE_VERSION);
+        assert_eq!(report.database, Some(report.this_build.clone()));
+    }
+
+    #[test]
+    fn should_have_flag_for_every_cargo_feature() {
+        let cargo_toml = include_str!("../Cargo.toml");
+        let mut cargo_features = cargo_toml
+            .lines()
+            .skip_while(|line| line.trim() != "[features]")
+            .skip(1)
+            .take_while(|line| !line.trim().starts_with('['))
+            .filter_map(|line| line.split('=').next())
+            .map(|feature| feature.trim())
+            .filter(|feature| !feature.is_empty() && *feature != "default")
+            .collect::<Vec<&str>>();
+        let mut flagged_features = get_feature_flags()
+            .into_iter()
+            .map(|(feature, _)| feature)
+            .collect::<Vec<&str>>();
+        // NOTE: An optional dependency rather than a feature of its own.
+        cargo_features.push("rocksdb");
+        cargo_features.sort();
+        flagged_features.sort();
+        assert_eq!(flagged_features, cargo_features);
+    }
+}
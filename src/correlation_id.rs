@@ -0,0 +1,57 @@
+use crate::{
+    types::Result,
+    errors::AppError,
+    crypto_utils::keccak_hash_bytes,
+};
+
+// NOTE: Hosts may derive the same id via `keccak256("<chain>:<txid>:<index>")`
+// where the index is a deposit's `vout` or a redeem's log index in its receipt.
+pub fn get_correlation_id(chain: &str, tx_hash: &str, index: usize) -> String {
+    format!(
+        "0x{}",
+        hex::encode(
+            keccak_hash_bytes(
+                format!("{}:{}:{}", chain, tx_hash, index).into_bytes()
+            )
+        )
+    )
+}
+
+// NOTE: Every record of a signed tx must be traceable back to the deposit or
+// redeem it's for ∴ one without an id is an error, never a default.
+pub fn get_checked_correlation_id(
+    correlation_id: &str,
+    tx_hash: &str,
+) -> Result<String> {
+    match correlation_id.is_empty() {
+        true => Err(AppError::NotFound(
+            format!("✘ No correlation ID for tx: {}!", tx_hash)
+        )),
+        false => Ok(correlation_id.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_correlation_id() {
+        let tx_hash =
+            "9e8dd29f08398d7adf92528ac113bcc736f7adcd7c99eee0468a992c81f3ea98";
+        let expected_result =
+            "0x65ea9f9ba2bb1aef1c17b473b26005503853ca4cd9c0250a9bf256849691f9c7";
+        let result = get_correlation_id("BTC", tx_hash, 1);
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_to_check_empty_correlation_id() {
+        let tx_hash = "0xc0ffee";
+        assert!(get_checked_correlation_id("", tx_hash).is_err());
+        assert_eq!(
+            get_checked_correlation_id("0xdecaf0", tx_hash).unwrap(),
+            "0xdecaf0",
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use std::borrow::Cow;
+use serde::{
+    Deserialize,
+    Deserializer,
+};
+
+// NOTE: `serde` only borrows a `Cow<str>` in place when it's a field's own
+// type, so these wrap each string in one for the container fields too. Any
+// string w/ JSON escapes is unescaped into an owned copy instead.
+#[derive(Deserialize)]
+#[serde(transparent)]
+struct CowStr<'a>(#[serde(borrow)] Cow<'a, str>);
+
+pub fn deserialize_cow_strs<'de, D>(
+    deserializer: D
+) -> Result<Vec<Cow<'de, str>>, D::Error>
+    where D: Deserializer<'de>
+{
+    Vec::<CowStr<'de>>::deserialize(deserializer)
+        .map(|strs| strs.into_iter().map(|CowStr(s)| s).collect())
+}
+
+pub fn deserialize_maybe_cow_str<'de, D>(
+    deserializer: D
+) -> Result<Option<Cow<'de, str>>, D::Error>
+    where D: Deserializer<'de>
+{
+    Option::<CowStr<'de>>::deserialize(deserializer)
+        .map(|maybe_str| maybe_str.map(|CowStr(s)| s))
+}
+
+pub fn deserialize_maybe_cow_str_pair<'de, D>(
+    deserializer: D
+) -> Result<Option<(Cow<'de, str>, Cow<'de, str>)>, D::Error>
+    where D: Deserializer<'de>
+{
+    Option::<(CowStr<'de>, CowStr<'de>)>::deserialize(deserializer)
+        .map(|maybe_pair| maybe_pair.map(|(CowStr(a), CowStr(b))| (a, b)))
+}
+
+pub fn deserialize_maybe_nested_cow_strs<'de, D>(
+    deserializer: D
+) -> Result<Option<Vec<Vec<Cow<'de, str>>>>, D::Error>
+    where D: Deserializer<'de>
+{
+    Option::<Vec<Vec<CowStr<'de>>>>::deserialize(deserializer)
+        .map(|maybe_nested_strs|
+            maybe_nested_strs.map(|nested_strs|
+                nested_strs
+                    .into_iter()
+                    .map(|strs| strs.into_iter().map(|CowStr(s)| s).collect())
+                    .collect()
+            )
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct TestJson<'a> {
+        #[serde(borrow, deserialize_with = "deserialize_cow_strs")]
+        strs: Vec<Cow<'a, str>>,
+    }
+
+    #[test]
+    fn should_borrow_unescaped_strs_and_own_escaped_ones() {
+        let json = r#"{"strs":["c0ffee","c0ff\u0065e"]}"#;
+        let result: TestJson = serde_json::from_str(json).unwrap();
+        assert!(matches!(result.strs[0], Cow::Borrowed("c0ffee")));
+        assert!(matches!(&result.strs[1], Cow::Owned(s) if s == "c0ffee"));
+    }
+}
@@ -4,15 +4,16 @@ use secp256k1::{
     Message,
     key::SecretKey,
 };
-use rand::{
-    RngCore,
-    thread_rng,
-};
 use bitcoin_hashes::{
     sha256,
     Hash as HashTrait
 };
 use crate::{
+    entropy::{
+        EntropySource,
+        get_random_bytes,
+        get_random_bytes_from_source,
+    },
     types::{
         Bytes,
         Result,
@@ -23,32 +24,40 @@ pub fn keccak_hash_bytes(bytes: Bytes) -> H256 {
     H256::from(keccak256(&bytes[..]))
 }
 
+#[allow(dead_code)]
 pub fn sha256_hash_message_bytes(
     message_bytes: &Bytes
 ) -> Result<Sha256HashedMessage> {
     Ok(Message::from_slice(&sha256::Hash::hash(message_bytes))?)
 }
 
-fn get_x_random_bytes(num_bytes: usize) -> Vec<u8> {
-    let mut bytes = vec![0u8; num_bytes];
-    thread_rng().fill_bytes(&mut bytes);
-    bytes
+pub fn get_x_random_bytes(num_bytes: usize) -> Result<Vec<u8>> {
+    get_random_bytes(num_bytes)
 }
 
-fn get_32_random_bytes_arr() -> [u8; 32] {
+fn get_32_random_bytes_arr() -> Result<[u8; 32]> {
     let mut arr = [0; 32];
-    arr.copy_from_slice(&get_x_random_bytes(32));
-    arr
+    arr.copy_from_slice(&get_x_random_bytes(32)?);
+    Ok(arr)
 }
 
 pub fn generate_random_private_key() -> Result<SecretKey> {
-    Ok(SecretKey::from_slice(&get_32_random_bytes_arr())?)
+    Ok(SecretKey::from_slice(&get_32_random_bytes_arr()?)?)
+}
+
+pub fn generate_private_key_from_source(
+    source: &dyn EntropySource,
+) -> Result<SecretKey> {
+    Ok(SecretKey::from_slice(&get_random_bytes_from_source(source, 32)?)?)
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::utils::convert_hex_to_h256;
+    use crate::{
+        utils::convert_hex_to_h256,
+        entropy::DeterministicEntropySource,
+    };
 
     #[test]
     fn should_keccak_hash_bytes() {
@@ -64,14 +73,14 @@ mod test {
 
     #[test]
     fn should_generate_32_random_bytes() {
-        let result = get_32_random_bytes_arr();
+        let result = get_32_random_bytes_arr().unwrap();
         assert!(result.len() == 32);
     }
 
     #[test]
     fn should_generate_x_random_bytes() {
         let x: usize = 100;
-        let result = get_x_random_bytes(x);
+        let result = get_x_random_bytes(x).unwrap();
         assert!(result.len() == x);
     }
 
@@ -80,4 +89,15 @@ mod test {
         generate_random_private_key()
             .unwrap();
     }
+
+    #[test]
+    fn should_generate_same_private_key_from_same_seeded_source() {
+        let result_1 = generate_private_key_from_source(
+            &DeterministicEntropySource::new(b"seed")
+        ).unwrap();
+        let result_2 = generate_private_key_from_source(
+            &DeterministicEntropySource::new(b"seed")
+        ).unwrap();
+        assert_eq!(result_1, result_2);
+    }
 }
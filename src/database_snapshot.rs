@@ -0,0 +1,127 @@
+use crate::{
+    types::Result,
+    utils::is_not_in_db_err,
+    db_keys::get_data_sensitivity_of_db_key,
+    traits::{
+        DatabaseWrite,
+        DatabaseInterface,
+    },
+};
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DatabaseSnapshot(Vec<DatabaseWrite>);
+
+impl DatabaseSnapshot {
+    // NOTE: Only a missing key is snapshotted as absent, since restoring any
+    // other failed read as such would delete a key that exists.
+    pub fn take<D>(db: &D, writes: &[DatabaseWrite]) -> Result<Self>
+        where D: DatabaseInterface
+    {
+        trace!("✔ Snapshotting {} db keys about to be written...", writes.len());
+        writes
+            .iter()
+            .map(|write| {
+                let (key, sensitivity) = match write {
+                    DatabaseWrite::Put(key, _, sensitivity) =>
+                        (key.clone(), *sensitivity),
+                    DatabaseWrite::Delete(key) => (
+                        key.clone(),
+                        get_data_sensitivity_of_db_key(db, key),
+                    ),
+                };
+                match db.get(key.clone(), sensitivity) {
+                    Ok(value) =>
+                        Ok(DatabaseWrite::Put(key, value, sensitivity)),
+                    Err(err) => match is_not_in_db_err(&err) {
+                        true => Ok(DatabaseWrite::Delete(key)),
+                        false => Err(err),
+                    },
+                }
+            })
+            .collect::<Result<Vec<DatabaseWrite>>>()
+            .map(DatabaseSnapshot)
+    }
+
+    pub fn restore<D>(self, db: &D) -> Result<()>
+        where D: DatabaseInterface
+    {
+        info!("✔ Rolling back {} db keys to snapshot...", self.0.len());
+        db.write_batch(self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::AppError,
+        test_utils::get_test_database,
+        types::{
+            Bytes,
+            DataSensitivity,
+        },
+    };
+
+    #[test]
+    fn should_restore_db_keys_to_snapshot() {
+        let db = get_test_database();
+        let sensitivity = DataSensitivity::Public;
+        let existing_key = vec![0xc0, 0xff, 0xee];
+        let new_key = vec![0xde, 0xca, 0xf0];
+        let value = vec![0x01];
+        db.put(existing_key.clone(), value.clone(), sensitivity).unwrap();
+        let writes = vec![
+            DatabaseWrite::Delete(existing_key.clone()),
+            DatabaseWrite::Put(new_key.clone(), vec![0x02], sensitivity),
+        ];
+        let snapshot = DatabaseSnapshot::take(&db, &writes).unwrap();
+        db.write_batch(writes).unwrap();
+        assert!(db.get(existing_key.clone(), sensitivity).is_err());
+        snapshot.restore(&db).unwrap();
+        assert_eq!(db.get(existing_key, sensitivity).unwrap(), value);
+        assert!(db.get(new_key, sensitivity).is_err());
+    }
+
+    struct FailingDatabase;
+
+    impl DatabaseInterface for FailingDatabase {
+        fn end_transaction(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn start_transaction(&self) -> Result<()> {
+            Ok(())
+        }
+
+        fn delete(&self, _key: Bytes) -> Result<()> {
+            Ok(())
+        }
+
+        fn get(
+            &self,
+            _key: Bytes,
+            _sensitivity: DataSensitivity,
+        ) -> Result<Bytes> {
+            Err(AppError::Custom("✘ Database unavailable!".to_string()))
+        }
+
+        fn put(
+            &self,
+            _key: Bytes,
+            _value: Bytes,
+            _sensitivity: DataSensitivity,
+        ) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn should_fail_to_snapshot_keys_that_fail_to_read() {
+        let writes = vec![DatabaseWrite::Delete(vec![0xc0, 0xff, 0xee])];
+        match DatabaseSnapshot::take(&FailingDatabase, &writes) {
+            Err(AppError::Custom(e)) =>
+                assert_eq!(e, "✘ Database unavailable!"),
+            _ => panic!("Failed read should not be snapshotted as absent!"),
+        }
+    }
+}
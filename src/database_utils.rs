@@ -1,9 +1,12 @@
 use crate::{
     errors::AppError,
+    db_keys::DbKeyPrefix,
+    constants::CORE_IS_PAUSED_KEY,
     traits::DatabaseInterface,
     types::{
         Bytes,
         Result,
+        DataSensitivity,
     },
 };
 
@@ -15,7 +18,7 @@ pub fn put_u64_in_db<D>(
     where D: DatabaseInterface
 {
     trace!("✔ Putting `u64` of {} in db...", u_64);
-    db.put(key.to_vec(), u_64.to_le_bytes().to_vec(), None)
+    db.put(key.to_vec(), u_64.to_le_bytes().to_vec(), DataSensitivity::Public)
 }
 
 pub fn get_u64_from_db<D>(
@@ -25,7 +28,7 @@ pub fn get_u64_from_db<D>(
     where D: DatabaseInterface
 {
     trace!("✔ Getting `u64` from db...");
-    db.get(key.to_vec(), None)
+    db.get(key.to_vec(), DataSensitivity::Public)
         .and_then(|bytes|
             match bytes.len() <= 8 {
                 true => {
@@ -34,13 +37,73 @@ pub fn get_u64_from_db<D>(
                     array.copy_from_slice(bytes);
                     Ok(u64::from_le_bytes(array))
                 },
-                false => Err(AppError::Custom(
+                false => Err(AppError::ConversionError(
                     "✘ Too many bytes to convert to u64!".to_string()
                 ))
             }
         )
 }
 
+pub fn put_namespaced_key_in_db<D>(
+    db: &D,
+    prefix: &DbKeyPrefix,
+    key: &[u8],
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting {:?} namespaced key in db...", prefix);
+    db.put(prefix.namespace_key(key), vec![], DataSensitivity::Public)
+}
+
+pub fn delete_namespaced_key_from_db<D>(
+    db: &D,
+    prefix: &DbKeyPrefix,
+    key: &[u8],
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Deleting {:?} namespaced key from db...", prefix);
+    db.delete(prefix.namespace_key(key))
+}
+
+pub fn get_namespaced_keys_from_db<D>(
+    db: &D,
+    prefix: &DbKeyPrefix,
+) -> Result<Vec<Bytes>>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting all {:?} namespaced keys from db...", prefix);
+    let prefix_bytes = prefix.to_bytes();
+    db.get_keys_with_prefix(prefix_bytes.clone())
+        .map(|keys|
+            keys
+                .into_iter()
+                .map(|key| key[prefix_bytes.len()..].to_vec())
+                .collect()
+        )
+}
+
+pub fn put_core_is_paused_in_db<D>(db: &D, paused: bool) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting core is paused of {} in db...", paused);
+    db.put(
+        CORE_IS_PAUSED_KEY.to_vec(),
+        vec![paused as u8],
+        DataSensitivity::Public,
+    )
+}
+
+pub fn core_is_paused<D>(db: &D) -> bool
+    where D: DatabaseInterface
+{
+    trace!("✔ Checking if core is paused...");
+    match db.get(CORE_IS_PAUSED_KEY.to_vec(), DataSensitivity::Public) {
+        Ok(bytes) => bytes == vec![1u8],
+        Err(_) => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -63,4 +126,27 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn should_put_and_get_core_is_paused_in_db() {
+        let db = get_test_database();
+        assert!(!core_is_paused(&db));
+        put_core_is_paused_in_db(&db, true).unwrap();
+        assert!(core_is_paused(&db));
+        put_core_is_paused_in_db(&db, false).unwrap();
+        assert!(!core_is_paused(&db));
+    }
+
+    #[test]
+    fn should_put_get_and_delete_namespaced_keys_in_db() {
+        let db = get_test_database();
+        let key = vec![0xc0, 0xff, 0xee];
+        let prefix = DbKeyPrefix::Utxo;
+        put_namespaced_key_in_db(&db, &prefix, &key).unwrap();
+        put_namespaced_key_in_db(&db, &DbKeyPrefix::BtcBlock, &key).unwrap();
+        let result = get_namespaced_keys_from_db(&db, &prefix).unwrap();
+        assert_eq!(result, vec![key.clone()]);
+        delete_namespaced_key_from_db(&db, &prefix, &key).unwrap();
+        assert!(get_namespaced_keys_from_db(&db, &prefix).unwrap().is_empty());
+    }
 }
@@ -0,0 +1,161 @@
+use serde::{
+    Serialize,
+    de::DeserializeOwned,
+};
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    constants::DB_SERIALIZATION_FORMAT_KEY,
+    utils::is_not_in_db_err,
+    types::{
+        Byte,
+        Bytes,
+        Result,
+        DataSensitivity,
+    },
+};
+
+// NOTE: CBOR's self-describe tag, which can never begin a JSON document.
+const CBOR_SELF_DESCRIBE_PREFIX: [u8; 3] = [0xd9, 0xd9, 0xf7];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DbSerializationFormat {
+    Json,
+    Cbor,
+}
+
+impl DbSerializationFormat {
+    pub fn to_byte(&self) -> Byte {
+        match self {
+            DbSerializationFormat::Json => 0,
+            DbSerializationFormat::Cbor => 1,
+        }
+    }
+
+    pub fn from_byte(byte: &Byte) -> Result<Self> {
+        match byte {
+            0 => Ok(DbSerializationFormat::Json),
+            1 => Ok(DbSerializationFormat::Cbor),
+            _ => Err(AppError::InvalidInput(
+                format!("✘ Unrecognised db serialization format: {}", byte)
+            )),
+        }
+    }
+}
+
+pub fn put_db_serialization_format_in_db<D>(
+    db: &D,
+    format: DbSerializationFormat,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting db serialization format of {:?} in db...", format);
+    db.put(
+        DB_SERIALIZATION_FORMAT_KEY.to_vec(),
+        vec![format.to_byte()],
+        DataSensitivity::Public,
+    )
+}
+
+pub fn get_db_serialization_format_from_db<D>(
+    db: &D,
+) -> Result<DbSerializationFormat>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting db serialization format from db...");
+    match db.get(
+        DB_SERIALIZATION_FORMAT_KEY.to_vec(),
+        DataSensitivity::Public,
+    ) {
+        Err(err) => match is_not_in_db_err(&err) {
+            true => Ok(DbSerializationFormat::Json),
+            false => Err(err),
+        },
+        Ok(bytes) => match bytes.len() == 1 {
+            true => DbSerializationFormat::from_byte(&bytes[0]),
+            false => Err(AppError::ConversionError(
+                "✘ Wrong number of bytes to convert to db serialization format!"
+                    .to_string()
+            )),
+        },
+    }
+}
+
+fn serialize_as_cbor<T: Serialize>(item: &T) -> Result<Bytes> {
+    let mut serializer = serde_cbor::Serializer::new(Vec::new());
+    serializer.self_describe()?;
+    item.serialize(&mut serializer)?;
+    Ok(serializer.into_inner())
+}
+
+pub fn serialize_for_db<T: Serialize>(
+    item: &T,
+    format: DbSerializationFormat,
+) -> Result<Bytes> {
+    match format {
+        DbSerializationFormat::Json => Ok(serde_json::to_vec(item)?),
+        DbSerializationFormat::Cbor => serialize_as_cbor(item),
+    }
+}
+
+// NOTE: Reads detect the format of each item ∴ items written as JSON before
+// switching to CBOR remain readable, & vice versa.
+pub fn deserialize_from_db<T: DeserializeOwned>(bytes: &[u8]) -> Result<T> {
+    match bytes.starts_with(&CBOR_SELF_DESCRIBE_PREFIX) {
+        true => Ok(serde_cbor::from_slice(bytes)?),
+        false => Ok(serde_json::from_slice(bytes)?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        btc::{
+            btc_types::BtcUtxoAndValue,
+            btc_test_utils::get_sample_p2sh_utxo_and_value,
+        },
+    };
+
+    #[test]
+    fn should_serialize_as_smaller_cbor_and_deserialize_both_formats() {
+        let utxo = get_sample_p2sh_utxo_and_value().unwrap();
+        let json_bytes = serde_json::to_vec(&utxo).unwrap();
+        let cbor_bytes = serialize_as_cbor(&utxo).unwrap();
+        assert!(cbor_bytes.len() < json_bytes.len());
+        let utxo_from_json: BtcUtxoAndValue = deserialize_from_db(&json_bytes)
+            .unwrap();
+        let utxo_from_cbor: BtcUtxoAndValue = deserialize_from_db(&cbor_bytes)
+            .unwrap();
+        assert_eq!(utxo_from_json, utxo);
+        assert_eq!(utxo_from_cbor, utxo);
+    }
+
+    #[test]
+    fn should_default_db_serialization_format_to_json() {
+        let db = get_test_database();
+        assert_eq!(
+            get_db_serialization_format_from_db(&db).unwrap(),
+            DbSerializationFormat::Json,
+        );
+    }
+
+    #[test]
+    fn should_keep_db_serialization_format_per_database() {
+        let cbor_db = get_test_database();
+        let json_db = get_test_database();
+        put_db_serialization_format_in_db(
+            &cbor_db,
+            DbSerializationFormat::Cbor,
+        ).unwrap();
+        assert_eq!(
+            get_db_serialization_format_from_db(&cbor_db).unwrap(),
+            DbSerializationFormat::Cbor,
+        );
+        assert_eq!(
+            get_db_serialization_format_from_db(&json_db).unwrap(),
+            DbSerializationFormat::Json,
+        );
+    }
+}
@@ -0,0 +1,555 @@
+use std::collections::HashSet;
+use bitcoin_hashes::{
+    Hash,
+    sha256d,
+};
+use ethereum_types::H256 as EthHash;
+use crate::{
+    traits::DatabaseInterface,
+    database_utils::{
+        get_u64_from_db,
+        get_namespaced_keys_from_db,
+    },
+    types::{
+        Bytes,
+        Result,
+        DataSensitivity,
+    },
+    constants::{
+        CORE_IS_PAUSED_KEY,
+        SAFE_ETH_ADDRESS_KEY,
+        SAFE_BTC_ADDRESS_KEY,
+        ASSET_DICTIONARY_KEY,
+        CORE_VERSION_INFO_KEY,
+        SAFE_ADDRESS_HISTORY_KEY,
+        DEBUG_ADMIN_PUBLIC_KEY_KEY,
+        DEBUG_SIGNATURE_CHALLENGE_KEY,
+        BTC_VALIDATION_CHECKPOINT_KEY,
+        ETH_VALIDATION_CHECKPOINT_KEY,
+        MAX_STATE_SIZE_KEY,
+        DISABLED_PIPELINE_STAGES_KEY,
+        CORE_METRICS_KEY,
+        TOTAL_SATOSHIS_MINTED_KEY,
+        TOTAL_SATOSHIS_REDEEMED_KEY,
+        BTC_HEARTBEAT_KEY,
+        ETH_HEARTBEAT_KEY,
+        LOG_CONFIG_KEY,
+        ERROR_LOG_KEY,
+        STALE_TX_THRESHOLD_KEY,
+        WATCHED_SIGNED_TXS_KEY,
+        OPERATOR_FEE_BTC_ADDRESS_KEY,
+        MINT_VOLUME_LIMIT_KEY,
+        MINT_VOLUME_USAGE_KEY,
+        PAYOUT_VOLUME_LIMIT_KEY,
+        PAYOUT_VOLUME_USAGE_KEY,
+        VOLUME_LIMITED_REDEEM_PARAMS_KEY,
+        VOLUME_LIMITED_MINTING_PARAMS_KEY,
+        RECIPIENT_POLICY_KEY,
+        BTC_FEE_LEDGER_SIZE_KEY,
+        ETH_FEE_LEDGER_SIZE_KEY,
+        REFUND_LEDGER_KEY,
+        PROVISIONAL_REFUND_LEDGER_KEY,
+        SAFE_ADDRESS_TIERS_KEY,
+        MINT_APPROVAL_THRESHOLD_KEY,
+        PAYOUT_APPROVAL_THRESHOLD_KEY,
+        PENDING_APPROVAL_MINTING_PARAMS_KEY,
+        PENDING_APPROVAL_REDEEM_PARAMS_KEY,
+        EVENT_JOURNAL_SEQUENCE_KEY,
+        DB_SERIALIZATION_FORMAT_KEY,
+    },
+    utxo_manager::utxo_constants::{
+        UTXO_LAST,
+        UTXO_FIRST,
+        UTXO_NONCE,
+        UTXO_BALANCE,
+        TOTAL_NUM_UTXOS,
+    },
+    btc::btc_constants::{
+        BTC_FEE_KEY,
+        BTC_ADDRESS_KEY,
+        BTC_NETWORK_KEY,
+        BTC_LINKER_HASH_KEY,
+        BTC_ACCOUNT_NONCE_KEY,
+        BTC_PRIVATE_KEY_DB_KEY,
+        BTC_TAIL_BLOCK_HASH_KEY,
+        BTC_DIFFICULTY_THRESHOLD,
+        BTC_CANON_BLOCK_HASH_KEY,
+        BTC_LATEST_BLOCK_HASH_KEY,
+        BTC_ANCHOR_BLOCK_HASH_KEY,
+        BTC_CANON_TO_TIP_LENGTH_KEY,
+        BTC_ACCRUED_PEG_IN_FEES_KEY,
+        BTC_QUEUED_MINTING_PARAMS_KEY,
+        BTC_PEG_IN_FEE_BASIS_POINTS_KEY,
+        BTC_ACCRUED_PEG_OUT_FEES_KEY,
+        BTC_MINT_GAS_RECAPTURE_RATE_KEY,
+        BTC_PEG_OUT_FEE_BASIS_POINTS_KEY,
+    },
+    eth::{
+        eth_database_utils::{
+            get_eth_account_nonce_from_db,
+            get_eth_tail_pruning_distance_from_db,
+        },
+        eth_constants::{
+            ETH_ADDRESS_KEY,
+            ETH_TX_TYPE_KEY,
+            ETH_CHAIN_ID_KEY,
+            ETH_POS_MODE_KEY,
+            ETH_GAS_PRICE_KEY,
+            ETH_LINKER_HASH_KEY,
+            ETH_RELAY_NONCE_KEY,
+            ETH_ACCOUNT_NONCE_KEY,
+            ETH_PRIVATE_KEY_DB_KEY,
+            ETH_MINT_GAS_LIMIT_KEY,
+            ETH_CANCEL_GAS_LIMIT_KEY,
+            ETH_TAIL_BLOCK_HASH_KEY,
+            ETH_MAX_FEE_PER_GAS_KEY,
+            ETH_BATCH_MINT_MODE_KEY,
+            ETH_CANON_BLOCK_HASH_KEY,
+            ETH_ANCHOR_BLOCK_HASH_KEY,
+            ETH_LATEST_BLOCK_HASH_KEY,
+            ETH_PENDING_TX_NONCES_KEY,
+            ETH_PRE_EIP155_SIGNING_KEY,
+            ETH_CANON_TO_TIP_LENGTH_KEY,
+            ETH_MINT_WITH_DATA_MODE_KEY,
+            ETH_HOST_TOKEN_DECIMALS_KEY,
+            ETH_QUEUED_REDEEM_PARAMS_KEY,
+            ETH_TAIL_PRUNED_HEIGHT_KEY,
+            ETH_TAIL_PRUNING_DISTANCE_KEY,
+            ETH_SMART_CONTRACT_ADDRESS_KEY,
+            ETH_PTOKEN_CONTRACT_TOPICS_KEY,
+            ETH_RELAY_FORWARDER_ADDRESS_KEY,
+            ETH_PTOKEN_CONTRACT_VARIANT_KEY,
+            ETH_MAX_PRIORITY_FEE_PER_GAS_KEY,
+            ETH_PTOKEN_CONTRACT_BYTECODE_HASH_KEY,
+            ETH_SMART_CONTRACT_ADDRESS_HISTORY_KEY,
+        },
+    },
+};
+
+pub const UTXO_KEY_PREFIX: &str = "utxo-number-";
+pub const UTXO_INDEX_PREFIX: &str = "utxo-index-";
+pub const BTC_BLOCK_INDEX_PREFIX: &str = "btc-block-index-";
+pub const ETH_BLOCK_INDEX_PREFIX: &str = "eth-block-index-";
+pub const ETH_REDEEM_RECORD_KEY_PREFIX: &str = "eth-redeem-record-";
+pub const ETH_MINT_TX_RECORD_KEY_PREFIX: &str = "eth-mint-tx-nonce-";
+pub const ETH_PENDING_TX_KEY_PREFIX: &str = "eth-pending-tx-nonce-";
+pub const ETH_PENDING_TX_INDEX_PREFIX: &str = "eth-pending-tx-index-";
+pub const BTC_BLOCK_HEADER_KEY_PREFIX: &str = "btc-block-header-";
+pub const ETH_BLOCK_HEADER_KEY_PREFIX: &str = "eth-block-header-";
+pub const BTC_PROCESSED_DEPOSIT_INDEX_PREFIX: &str = "btc-processed-deposit-";
+pub const ETH_PROCESSED_REDEEM_INDEX_PREFIX: &str = "eth-processed-redeem-";
+pub const EVENT_JOURNAL_ENTRY_KEY_PREFIX: &str = "event-journal-entry-";
+pub const BTC_FEE_LEDGER_ENTRY_KEY_PREFIX: &str = "btc-fee-ledger-entry-";
+pub const ETH_FEE_LEDGER_ENTRY_KEY_PREFIX: &str = "eth-fee-ledger-entry-";
+pub const ETH_PRUNED_BLOCK_INDEX_PREFIX: &str = "eth-pruned-block-index-";
+
+pub const DB_KEY_PREFIXES: [&str; 16] = [
+    UTXO_KEY_PREFIX,
+    UTXO_INDEX_PREFIX,
+    BTC_BLOCK_INDEX_PREFIX,
+    ETH_BLOCK_INDEX_PREFIX,
+    ETH_REDEEM_RECORD_KEY_PREFIX,
+    ETH_MINT_TX_RECORD_KEY_PREFIX,
+    ETH_PENDING_TX_KEY_PREFIX,
+    ETH_PENDING_TX_INDEX_PREFIX,
+    BTC_BLOCK_HEADER_KEY_PREFIX,
+    ETH_BLOCK_HEADER_KEY_PREFIX,
+    BTC_PROCESSED_DEPOSIT_INDEX_PREFIX,
+    ETH_PROCESSED_REDEEM_INDEX_PREFIX,
+    EVENT_JOURNAL_ENTRY_KEY_PREFIX,
+    BTC_FEE_LEDGER_ENTRY_KEY_PREFIX,
+    ETH_FEE_LEDGER_ENTRY_KEY_PREFIX,
+    ETH_PRUNED_BLOCK_INDEX_PREFIX,
+];
+
+const fn is_prefix_of(prefix: &[u8], bytes: &[u8]) -> bool {
+    if prefix.len() > bytes.len() {
+        return false
+    };
+    let mut i = 0;
+    while i < prefix.len() {
+        if prefix[i] != bytes[i] {
+            return false
+        };
+        i += 1;
+    }
+    true
+}
+
+const fn prefixes_are_distinct(prefixes: &[&str]) -> bool {
+    let mut i = 0;
+    while i < prefixes.len() {
+        let mut j = 0;
+        while j < prefixes.len() {
+            if i != j && is_prefix_of(
+                prefixes[i].as_bytes(),
+                prefixes[j].as_bytes(),
+            ) {
+                return false
+            };
+            j += 1;
+        }
+        i += 1;
+    }
+    true
+}
+
+// NOTE: Fails compilation if any db key prefix is a prefix of another!
+const _: () = assert!(prefixes_are_distinct(&DB_KEY_PREFIXES));
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DbKeyPrefix {
+    Utxo,
+    BtcBlock,
+    EthBlock,
+    EthPendingTx,
+    ProcessedBtcDeposit,
+    ProcessedEthRedeem,
+}
+
+impl DbKeyPrefix {
+    pub fn all() -> Vec<Self> {
+        vec![
+            DbKeyPrefix::Utxo,
+            DbKeyPrefix::BtcBlock,
+            DbKeyPrefix::EthBlock,
+            DbKeyPrefix::EthPendingTx,
+            DbKeyPrefix::ProcessedBtcDeposit,
+            DbKeyPrefix::ProcessedEthRedeem,
+        ]
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        match self {
+            DbKeyPrefix::Utxo => UTXO_INDEX_PREFIX,
+            DbKeyPrefix::BtcBlock => BTC_BLOCK_INDEX_PREFIX,
+            DbKeyPrefix::EthBlock => ETH_BLOCK_INDEX_PREFIX,
+            DbKeyPrefix::EthPendingTx => ETH_PENDING_TX_INDEX_PREFIX,
+            DbKeyPrefix::ProcessedBtcDeposit =>
+                BTC_PROCESSED_DEPOSIT_INDEX_PREFIX,
+            DbKeyPrefix::ProcessedEthRedeem =>
+                ETH_PROCESSED_REDEEM_INDEX_PREFIX,
+        }.as_bytes().to_vec()
+    }
+
+    pub fn namespace_key(&self, key: &[u8]) -> Bytes {
+        [self.to_bytes(), key.to_vec()].concat()
+    }
+}
+
+fn get_hashed_db_key(prefix: &str, suffix: &str) -> Bytes {
+    sha256d::Hash::hash(format!("{}{}", prefix, suffix).as_bytes()).to_vec()
+}
+
+pub fn get_utxo_and_value_db_key(utxo_number: u64) -> Bytes {
+    get_hashed_db_key(UTXO_KEY_PREFIX, &utxo_number.to_string())
+}
+
+pub fn get_eth_mint_tx_record_db_key(nonce: u64) -> Bytes {
+    get_hashed_db_key(ETH_MINT_TX_RECORD_KEY_PREFIX, &nonce.to_string())
+}
+
+pub fn get_eth_pending_tx_db_key(nonce: u64) -> Bytes {
+    get_hashed_db_key(ETH_PENDING_TX_KEY_PREFIX, &nonce.to_string())
+}
+
+pub fn get_eth_redeem_record_db_key(block_hash: &EthHash) -> Bytes {
+    get_hashed_db_key(ETH_REDEEM_RECORD_KEY_PREFIX, &hex::encode(block_hash))
+}
+
+pub fn get_event_journal_entry_db_key(sequence: u64) -> Bytes {
+    get_hashed_db_key(EVENT_JOURNAL_ENTRY_KEY_PREFIX, &sequence.to_string())
+}
+
+pub fn get_fee_ledger_entry_db_key(prefix: &str, index: u64) -> Bytes {
+    get_hashed_db_key(prefix, &index.to_string())
+}
+
+pub fn get_eth_pruned_block_index_db_key(block_number: u64) -> Bytes {
+    get_hashed_db_key(ETH_PRUNED_BLOCK_INDEX_PREFIX, &block_number.to_string())
+}
+
+pub fn get_btc_block_header_db_key(id: &sha256d::Hash) -> Bytes {
+    get_hashed_db_key(BTC_BLOCK_HEADER_KEY_PREFIX, &hex::encode(id.to_vec()))
+}
+
+pub fn get_eth_block_header_db_key(block_hash: &EthHash) -> Bytes {
+    get_hashed_db_key(ETH_BLOCK_HEADER_KEY_PREFIX, &hex::encode(block_hash))
+}
+
+pub fn get_data_sensitivity_of_db_key<D>(
+    db: &D,
+    key: &[u8],
+) -> DataSensitivity
+    where D: DatabaseInterface
+{
+    let is_private_key = key == &ETH_PRIVATE_KEY_DB_KEY[..]
+        || key == &BTC_PRIVATE_KEY_DB_KEY[..];
+    let is_utxo = db
+        .get(DbKeyPrefix::Utxo.namespace_key(key), DataSensitivity::Public)
+        .is_ok();
+    match is_private_key || is_utxo {
+        true => DataSensitivity::Private,
+        false => DataSensitivity::Public,
+    }
+}
+
+pub fn get_static_db_keys() -> Vec<(&'static str, Bytes)> {
+    vec![
+        ("CORE_IS_PAUSED_KEY", CORE_IS_PAUSED_KEY.to_vec()),
+        ("ASSET_DICTIONARY_KEY", ASSET_DICTIONARY_KEY.to_vec()),
+        ("SAFE_ETH_ADDRESS_KEY", SAFE_ETH_ADDRESS_KEY.to_vec()),
+        ("SAFE_BTC_ADDRESS_KEY", SAFE_BTC_ADDRESS_KEY.to_vec()),
+        ("SAFE_ADDRESS_HISTORY_KEY", SAFE_ADDRESS_HISTORY_KEY.to_vec()),
+        ("CORE_VERSION_INFO_KEY", CORE_VERSION_INFO_KEY.to_vec()),
+        ("DEBUG_ADMIN_PUBLIC_KEY_KEY", DEBUG_ADMIN_PUBLIC_KEY_KEY.to_vec()),
+        ("DEBUG_SIGNATURE_CHALLENGE_KEY", DEBUG_SIGNATURE_CHALLENGE_KEY.to_vec()),
+        ("BTC_VALIDATION_CHECKPOINT_KEY", BTC_VALIDATION_CHECKPOINT_KEY.to_vec()),
+        ("ETH_VALIDATION_CHECKPOINT_KEY", ETH_VALIDATION_CHECKPOINT_KEY.to_vec()),
+        ("MAX_STATE_SIZE_KEY", MAX_STATE_SIZE_KEY.to_vec()),
+        ("DISABLED_PIPELINE_STAGES_KEY", DISABLED_PIPELINE_STAGES_KEY.to_vec()),
+        ("EVENT_JOURNAL_SEQUENCE_KEY", EVENT_JOURNAL_SEQUENCE_KEY.to_vec()),
+        ("CORE_METRICS_KEY", CORE_METRICS_KEY.to_vec()),
+        ("TOTAL_SATOSHIS_MINTED_KEY", TOTAL_SATOSHIS_MINTED_KEY.to_vec()),
+        ("TOTAL_SATOSHIS_REDEEMED_KEY", TOTAL_SATOSHIS_REDEEMED_KEY.to_vec()),
+        ("BTC_HEARTBEAT_KEY", BTC_HEARTBEAT_KEY.to_vec()),
+        ("ETH_HEARTBEAT_KEY", ETH_HEARTBEAT_KEY.to_vec()),
+        ("LOG_CONFIG_KEY", LOG_CONFIG_KEY.to_vec()),
+        ("ERROR_LOG_KEY", ERROR_LOG_KEY.to_vec()),
+        ("STALE_TX_THRESHOLD_KEY", STALE_TX_THRESHOLD_KEY.to_vec()),
+        ("WATCHED_SIGNED_TXS_KEY", WATCHED_SIGNED_TXS_KEY.to_vec()),
+        ("OPERATOR_FEE_BTC_ADDRESS_KEY", OPERATOR_FEE_BTC_ADDRESS_KEY.to_vec()),
+        ("MINT_VOLUME_LIMIT_KEY", MINT_VOLUME_LIMIT_KEY.to_vec()),
+        ("MINT_VOLUME_USAGE_KEY", MINT_VOLUME_USAGE_KEY.to_vec()),
+        ("PAYOUT_VOLUME_LIMIT_KEY", PAYOUT_VOLUME_LIMIT_KEY.to_vec()),
+        ("PAYOUT_VOLUME_USAGE_KEY", PAYOUT_VOLUME_USAGE_KEY.to_vec()),
+        ("VOLUME_LIMITED_REDEEM_PARAMS_KEY", VOLUME_LIMITED_REDEEM_PARAMS_KEY.to_vec()),
+        ("VOLUME_LIMITED_MINTING_PARAMS_KEY", VOLUME_LIMITED_MINTING_PARAMS_KEY.to_vec()),
+        ("RECIPIENT_POLICY_KEY", RECIPIENT_POLICY_KEY.to_vec()),
+        ("BTC_FEE_LEDGER_SIZE_KEY", BTC_FEE_LEDGER_SIZE_KEY.to_vec()),
+        ("ETH_FEE_LEDGER_SIZE_KEY", ETH_FEE_LEDGER_SIZE_KEY.to_vec()),
+        ("REFUND_LEDGER_KEY", REFUND_LEDGER_KEY.to_vec()),
+        (
+            "PROVISIONAL_REFUND_LEDGER_KEY",
+            PROVISIONAL_REFUND_LEDGER_KEY.to_vec(),
+        ),
+        ("SAFE_ADDRESS_TIERS_KEY", SAFE_ADDRESS_TIERS_KEY.to_vec()),
+        ("MINT_APPROVAL_THRESHOLD_KEY", MINT_APPROVAL_THRESHOLD_KEY.to_vec()),
+        ("PAYOUT_APPROVAL_THRESHOLD_KEY", PAYOUT_APPROVAL_THRESHOLD_KEY.to_vec()),
+        ("PENDING_APPROVAL_MINTING_PARAMS_KEY", PENDING_APPROVAL_MINTING_PARAMS_KEY.to_vec()),
+        ("PENDING_APPROVAL_REDEEM_PARAMS_KEY", PENDING_APPROVAL_REDEEM_PARAMS_KEY.to_vec()),
+        ("DB_SERIALIZATION_FORMAT_KEY", DB_SERIALIZATION_FORMAT_KEY.to_vec()),
+        ("BTC_DIFFICULTY_THRESHOLD", BTC_DIFFICULTY_THRESHOLD.to_vec()),
+        ("BTC_ADDRESS_KEY", BTC_ADDRESS_KEY.to_vec()),
+        ("BTC_CANON_BLOCK_HASH_KEY", BTC_CANON_BLOCK_HASH_KEY.to_vec()),
+        ("BTC_LATEST_BLOCK_HASH_KEY", BTC_LATEST_BLOCK_HASH_KEY.to_vec()),
+        ("BTC_LINKER_HASH_KEY", BTC_LINKER_HASH_KEY.to_vec()),
+        ("BTC_ANCHOR_BLOCK_HASH_KEY", BTC_ANCHOR_BLOCK_HASH_KEY.to_vec()),
+        ("BTC_PRIVATE_KEY_DB_KEY", BTC_PRIVATE_KEY_DB_KEY.to_vec()),
+        ("BTC_CANON_TO_TIP_LENGTH_KEY", BTC_CANON_TO_TIP_LENGTH_KEY.to_vec()),
+        ("BTC_NETWORK_KEY", BTC_NETWORK_KEY.to_vec()),
+        ("BTC_FEE_KEY", BTC_FEE_KEY.to_vec()),
+        ("BTC_ACCOUNT_NONCE_KEY", BTC_ACCOUNT_NONCE_KEY.to_vec()),
+        ("BTC_TAIL_BLOCK_HASH_KEY", BTC_TAIL_BLOCK_HASH_KEY.to_vec()),
+        ("BTC_PEG_IN_FEE_BASIS_POINTS_KEY", BTC_PEG_IN_FEE_BASIS_POINTS_KEY.to_vec()),
+        ("BTC_ACCRUED_PEG_IN_FEES_KEY", BTC_ACCRUED_PEG_IN_FEES_KEY.to_vec()),
+        ("BTC_PEG_OUT_FEE_BASIS_POINTS_KEY", BTC_PEG_OUT_FEE_BASIS_POINTS_KEY.to_vec()),
+        ("BTC_ACCRUED_PEG_OUT_FEES_KEY", BTC_ACCRUED_PEG_OUT_FEES_KEY.to_vec()),
+        ("BTC_MINT_GAS_RECAPTURE_RATE_KEY", BTC_MINT_GAS_RECAPTURE_RATE_KEY.to_vec()),
+        ("BTC_QUEUED_MINTING_PARAMS_KEY", BTC_QUEUED_MINTING_PARAMS_KEY.to_vec()),
+        ("ETH_CANON_TO_TIP_LENGTH_KEY", ETH_CANON_TO_TIP_LENGTH_KEY.to_vec()),
+        ("ETH_ANCHOR_BLOCK_HASH_KEY", ETH_ANCHOR_BLOCK_HASH_KEY.to_vec()),
+        ("ETH_LATEST_BLOCK_HASH_KEY", ETH_LATEST_BLOCK_HASH_KEY.to_vec()),
+        ("ETH_CANON_BLOCK_HASH_KEY", ETH_CANON_BLOCK_HASH_KEY.to_vec()),
+        ("ETH_LINKER_HASH_KEY", ETH_LINKER_HASH_KEY.to_vec()),
+        ("ETH_ACCOUNT_NONCE_KEY", ETH_ACCOUNT_NONCE_KEY.to_vec()),
+        ("ETH_SMART_CONTRACT_ADDRESS_KEY", ETH_SMART_CONTRACT_ADDRESS_KEY.to_vec()),
+        ("ETH_ADDRESS_KEY", ETH_ADDRESS_KEY.to_vec()),
+        ("ETH_PRIVATE_KEY_DB_KEY", ETH_PRIVATE_KEY_DB_KEY.to_vec()),
+        ("ETH_CHAIN_ID_KEY", ETH_CHAIN_ID_KEY.to_vec()),
+        ("ETH_GAS_PRICE_KEY", ETH_GAS_PRICE_KEY.to_vec()),
+        ("ETH_TAIL_BLOCK_HASH_KEY", ETH_TAIL_BLOCK_HASH_KEY.to_vec()),
+        ("ETH_MAX_FEE_PER_GAS_KEY", ETH_MAX_FEE_PER_GAS_KEY.to_vec()),
+        ("ETH_MAX_PRIORITY_FEE_PER_GAS_KEY", ETH_MAX_PRIORITY_FEE_PER_GAS_KEY.to_vec()),
+        ("ETH_TX_TYPE_KEY", ETH_TX_TYPE_KEY.to_vec()),
+        ("ETH_MINT_GAS_LIMIT_KEY", ETH_MINT_GAS_LIMIT_KEY.to_vec()),
+        ("ETH_CANCEL_GAS_LIMIT_KEY", ETH_CANCEL_GAS_LIMIT_KEY.to_vec()),
+        ("ETH_RELAY_FORWARDER_ADDRESS_KEY", ETH_RELAY_FORWARDER_ADDRESS_KEY.to_vec()),
+        ("ETH_RELAY_NONCE_KEY", ETH_RELAY_NONCE_KEY.to_vec()),
+        ("ETH_BATCH_MINT_MODE_KEY", ETH_BATCH_MINT_MODE_KEY.to_vec()),
+        ("ETH_PENDING_TX_NONCES_KEY", ETH_PENDING_TX_NONCES_KEY.to_vec()),
+        ("ETH_PRE_EIP155_SIGNING_KEY", ETH_PRE_EIP155_SIGNING_KEY.to_vec()),
+        ("ETH_PTOKEN_CONTRACT_TOPICS_KEY", ETH_PTOKEN_CONTRACT_TOPICS_KEY.to_vec()),
+        ("ETH_POS_MODE_KEY", ETH_POS_MODE_KEY.to_vec()),
+        ("ETH_TAIL_PRUNING_DISTANCE_KEY", ETH_TAIL_PRUNING_DISTANCE_KEY.to_vec()),
+        ("ETH_TAIL_PRUNED_HEIGHT_KEY", ETH_TAIL_PRUNED_HEIGHT_KEY.to_vec()),
+        ("ETH_PTOKEN_CONTRACT_VARIANT_KEY", ETH_PTOKEN_CONTRACT_VARIANT_KEY.to_vec()),
+        ("ETH_MINT_WITH_DATA_MODE_KEY", ETH_MINT_WITH_DATA_MODE_KEY.to_vec()),
+        ("ETH_SMART_CONTRACT_ADDRESS_HISTORY_KEY", ETH_SMART_CONTRACT_ADDRESS_HISTORY_KEY.to_vec()),
+        ("ETH_HOST_TOKEN_DECIMALS_KEY", ETH_HOST_TOKEN_DECIMALS_KEY.to_vec()),
+        ("ETH_PTOKEN_CONTRACT_BYTECODE_HASH_KEY", ETH_PTOKEN_CONTRACT_BYTECODE_HASH_KEY.to_vec()),
+        ("ETH_QUEUED_REDEEM_PARAMS_KEY", ETH_QUEUED_REDEEM_PARAMS_KEY.to_vec()),
+        ("UTXO_FIRST", UTXO_FIRST.to_vec()),
+        ("UTXO_LAST", UTXO_LAST.to_vec()),
+        ("UTXO_BALANCE", UTXO_BALANCE.to_vec()),
+        ("UTXO_NONCE", UTXO_NONCE.to_vec()),
+        ("TOTAL_NUM_UTXOS", TOTAL_NUM_UTXOS.to_vec()),
+    ]
+}
+
+fn get_expected_db_keys<D>(db: &D) -> Result<HashSet<Bytes>>
+    where D: DatabaseInterface
+{
+    let mut expected_keys = get_static_db_keys()
+        .into_iter()
+        .map(|(_, key)| key)
+        .collect::<HashSet<Bytes>>();
+    for prefix in DbKeyPrefix::all() {
+        for key in get_namespaced_keys_from_db(db, &prefix)? {
+            match (prefix, key.len() == 32) {
+                (DbKeyPrefix::BtcBlock, true) => {
+                    expected_keys.insert(get_btc_block_header_db_key(
+                        &sha256d::Hash::from_slice(&key)?
+                    ));
+                }
+                (DbKeyPrefix::EthBlock, true) => {
+                    let block_hash = EthHash::from_slice(&key);
+                    expected_keys.insert(
+                        get_eth_block_header_db_key(&block_hash)
+                    );
+                    expected_keys.insert(
+                        get_eth_redeem_record_db_key(&block_hash)
+                    );
+                }
+                _ => (),
+            };
+            expected_keys.insert(prefix.namespace_key(&key));
+            expected_keys.insert(key);
+        }
+    }
+    (0..get_eth_account_nonce_from_db(db).unwrap_or(0))
+        .for_each(|nonce| {
+            expected_keys.insert(get_eth_mint_tx_record_db_key(nonce));
+        });
+    (0..get_u64_from_db(db, &EVENT_JOURNAL_SEQUENCE_KEY.to_vec()).unwrap_or(0))
+        .for_each(|sequence| {
+            expected_keys.insert(get_event_journal_entry_db_key(sequence));
+        });
+    [
+        (BTC_FEE_LEDGER_ENTRY_KEY_PREFIX, BTC_FEE_LEDGER_SIZE_KEY),
+        (ETH_FEE_LEDGER_ENTRY_KEY_PREFIX, ETH_FEE_LEDGER_SIZE_KEY),
+    ]
+        .iter()
+        .for_each(|(prefix, size_key)|
+            (0..get_u64_from_db(db, &size_key.to_vec()).unwrap_or(0))
+                .for_each(|index| {
+                    expected_keys.insert(
+                        get_fee_ledger_entry_db_key(prefix, index)
+                    );
+                })
+        );
+    let pruned_height =
+        get_u64_from_db(db, &ETH_TAIL_PRUNED_HEIGHT_KEY.to_vec()).unwrap_or(0);
+    let pruning_distance = get_eth_tail_pruning_distance_from_db(db)?;
+    (pruned_height.saturating_sub(pruning_distance)..pruned_height)
+        .for_each(|block_number| {
+            expected_keys.insert(
+                get_eth_pruned_block_index_db_key(block_number)
+            );
+        });
+    Ok(expected_keys)
+}
+
+pub fn get_unexpected_db_keys<D>(db: &D) -> Result<Vec<Bytes>>
+    where D: DatabaseInterface
+{
+    info!("✔ Scanning db for unexpected keys...");
+    let expected_keys = get_expected_db_keys(db)?;
+    db.get_keys_with_prefix(vec![])
+        .map(|keys|
+            keys
+                .into_iter()
+                .filter(|key| !expected_keys.contains(key))
+                .collect()
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        utils::convert_h256_to_bytes,
+        test_utils::get_test_database,
+        database_utils::put_namespaced_key_in_db,
+    };
+
+    #[test]
+    fn should_get_utxo_db_key() {
+        let expected_result =
+            "b783e877488797a385ffd73089fc7d051db72ea1cf4290ee0d3a65efa712e29c";
+        let num = 1;
+        let result = get_utxo_and_value_db_key(num);
+        assert!(hex::encode(result) == expected_result);
+    }
+
+    #[test]
+    fn static_db_keys_should_all_be_distinct() {
+        let keys = get_static_db_keys();
+        let distinct_keys = keys
+            .iter()
+            .map(|(_, key)| key.clone())
+            .collect::<HashSet<Bytes>>();
+        assert_eq!(distinct_keys.len(), keys.len());
+    }
+
+    #[test]
+    fn should_get_unexpected_db_keys() {
+        let db = get_test_database();
+        let block_hash = EthHash::random();
+        let block_key = convert_h256_to_bytes(block_hash);
+        let unexpected_key = vec![0xc0, 0xff, 0xee];
+        db.put(
+            BTC_FEE_KEY.to_vec(),
+            vec![0x01],
+            DataSensitivity::Public,
+        ).unwrap();
+        db.put(block_key.clone(), vec![0x01], DataSensitivity::Public).unwrap();
+        db.put(
+            get_eth_redeem_record_db_key(&block_hash),
+            vec![],
+            DataSensitivity::Public,
+        )
+            .unwrap();
+        put_namespaced_key_in_db(&db, &DbKeyPrefix::EthBlock, &block_key)
+            .unwrap();
+        db.put(
+            unexpected_key.clone(),
+            vec![0x01],
+            DataSensitivity::Public,
+        ).unwrap();
+        let result = get_unexpected_db_keys(&db).unwrap();
+        assert_eq!(result, vec![unexpected_key]);
+    }
+
+    #[test]
+    fn should_get_data_sensitivity_of_db_key() {
+        let db = get_test_database();
+        let utxo_key = get_utxo_and_value_db_key(1);
+        put_namespaced_key_in_db(&db, &DbKeyPrefix::Utxo, &utxo_key).unwrap();
+        let private_keys = vec![
+            utxo_key,
+            ETH_PRIVATE_KEY_DB_KEY.to_vec(),
+            BTC_PRIVATE_KEY_DB_KEY.to_vec(),
+        ];
+        private_keys
+            .iter()
+            .for_each(|key| assert_eq!(
+                get_data_sensitivity_of_db_key(&db, key),
+                DataSensitivity::Private,
+            ));
+        assert_eq!(
+            get_data_sensitivity_of_db_key(&db, &BTC_FEE_KEY),
+            DataSensitivity::Public,
+        );
+    }
+}
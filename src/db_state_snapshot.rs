@@ -0,0 +1,251 @@
+#![cfg(any(test, feature="test-database"))]
+// NOTE: A canonical dump of every key & value in the core's db, so tests may
+// assert a pipeline's complete post-conditions rather than spot-check keys.
+use std::{
+    fmt,
+    collections::BTreeMap,
+};
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    types::Result,
+    db_keys::{
+        DbKeyPrefix,
+        get_static_db_keys,
+        get_data_sensitivity_of_db_key,
+    },
+};
+
+fn get_label_for_db_key(key: &[u8]) -> String {
+    if let Some((name, _)) = get_static_db_keys()
+        .into_iter()
+        .find(|(_, static_key)| static_key[..] == key[..])
+    {
+        return name.to_string()
+    };
+    match DbKeyPrefix::all()
+        .into_iter()
+        .find(|prefix| key.starts_with(&prefix.to_bytes()))
+    {
+        Some(prefix) => format!(
+            "{:?}:0x{}",
+            prefix,
+            hex::encode(&key[prefix.to_bytes().len()..]),
+        ),
+        None => format!("0x{}", hex::encode(key)),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DbStateDiff {
+    Added { key: String, actual: String },
+    Removed { key: String, expected: String },
+    Changed { key: String, expected: String, actual: String },
+}
+
+impl fmt::Display for DbStateDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DbStateDiff::Added { key, actual } =>
+                write!(f, "+ {}: {}", key, actual),
+            DbStateDiff::Removed { key, expected } =>
+                write!(f, "- {}: {}", key, expected),
+            DbStateDiff::Changed { key, expected, actual } =>
+                write!(f, "~ {}: {} -> {}", key, expected, actual),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DbStateSnapshot(pub BTreeMap<String, String>);
+
+impl DbStateSnapshot {
+    pub fn take<D>(db: &D) -> Result<Self>
+        where D: DatabaseInterface
+    {
+        db.get_keys_with_prefix(vec![])?
+            .into_iter()
+            .map(|key| {
+                let sensitivity = get_data_sensitivity_of_db_key(db, &key);
+                db.get(key.clone(), sensitivity)
+                    .map(|value| (
+                        get_label_for_db_key(&key),
+                        format!("0x{}", hex::encode(value)),
+                    ))
+            })
+            .collect::<Result<BTreeMap<String, String>>>()
+            .map(Self)
+    }
+
+    pub fn from_json_str(json_str: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json_str)?)
+    }
+
+    pub fn to_json_string(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    // NOTE: For keys whose values aren't deterministic, such as those holding
+    // freshly generated private keys or timestamps.
+    pub fn excluding(mut self, labels: &[&str]) -> Self {
+        labels.iter().for_each(|label| { self.0.remove(*label); });
+        self
+    }
+
+    pub fn diff(&self, actual: &Self) -> Vec<DbStateDiff> {
+        let removed_or_changed = self.0
+            .iter()
+            .filter_map(|(key, expected)| match actual.0.get(key) {
+                None => Some(DbStateDiff::Removed {
+                    key: key.clone(),
+                    expected: expected.clone(),
+                }),
+                Some(value) if value != expected =>
+                    Some(DbStateDiff::Changed {
+                        key: key.clone(),
+                        expected: expected.clone(),
+                        actual: value.clone(),
+                    }),
+                Some(_) => None,
+            });
+        let added = actual.0
+            .iter()
+            .filter(|(key, _)| !self.0.contains_key(*key))
+            .map(|(key, value)| DbStateDiff::Added {
+                key: key.clone(),
+                actual: value.clone(),
+            });
+        removed_or_changed.chain(added).collect()
+    }
+}
+
+pub fn check_db_matches_snapshot<D>(
+    db: &D,
+    expected: &DbStateSnapshot,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    let diffs = expected.diff(&DbStateSnapshot::take(db)?);
+    match diffs.is_empty() {
+        true => Ok(()),
+        false => Err(AppError::DatabaseError(
+            format!(
+                "✘ DB state differs from snapshot in {} key(s):\n{}",
+                diffs.len(),
+                diffs
+                    .iter()
+                    .map(|diff| diff.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            )
+        )),
+    }
+}
+
+pub fn assert_db_matches_snapshot<D>(db: &D, expected: &DbStateSnapshot)
+    where D: DatabaseInterface
+{
+    if let Err(err) = check_db_matches_snapshot(db, expected) {
+        panic!("{}", err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::DataSensitivity,
+        test_utils::get_test_database,
+        constants::CORE_IS_PAUSED_KEY,
+    };
+
+    fn put(db: &impl DatabaseInterface, key: &[u8], value: &[u8]) {
+        db.put(key.to_vec(), value.to_vec(), DataSensitivity::Public)
+            .unwrap()
+    }
+
+    #[test]
+    fn should_label_static_namespaced_and_other_keys() {
+        let namespaced_key = DbKeyPrefix::EthBlock.namespace_key(&[0xc0]);
+        assert_eq!(
+            get_label_for_db_key(&CORE_IS_PAUSED_KEY[..]),
+            "CORE_IS_PAUSED_KEY",
+        );
+        assert_eq!(get_label_for_db_key(&namespaced_key), "EthBlock:0xc0");
+        assert_eq!(get_label_for_db_key(&[0xde, 0xca, 0xf0]), "0xdecaf0");
+    }
+
+    #[test]
+    fn should_take_snapshot_independent_of_insertion_order() {
+        let db_1 = get_test_database();
+        let db_2 = get_test_database();
+        put(&db_1, &[0x01], &[0xaa]);
+        put(&db_1, &[0x02], &[0xbb]);
+        put(&db_2, &[0x02], &[0xbb]);
+        put(&db_2, &[0x01], &[0xaa]);
+        let snapshot = DbStateSnapshot::take(&db_1).unwrap();
+        assert_eq!(snapshot, DbStateSnapshot::take(&db_2).unwrap());
+        assert_db_matches_snapshot(&db_2, &snapshot);
+    }
+
+    #[test]
+    fn should_round_trip_snapshot_via_sorted_json() {
+        let db = get_test_database();
+        put(&db, &[0x02], &[0xbb]);
+        put(&db, &[0x01], &[0xaa]);
+        let json = DbStateSnapshot::take(&db)
+            .unwrap()
+            .to_json_string()
+            .unwrap();
+        assert!(json.find("0x01").unwrap() < json.find("0x02").unwrap());
+        assert_eq!(
+            DbStateSnapshot::from_json_str(&json).unwrap(),
+            DbStateSnapshot::take(&db).unwrap(),
+        );
+    }
+
+    #[test]
+    fn should_diff_added_removed_and_changed_keys() {
+        let db = get_test_database();
+        put(&db, &[0x01], &[0xaa]);
+        put(&db, &[0x02], &[0xbb]);
+        let expected = DbStateSnapshot::take(&db).unwrap();
+        db.delete(vec![0x01]).unwrap();
+        put(&db, &[0x02], &[0xcc]);
+        put(&db, &[0x03], &[0xdd]);
+        let diffs = expected.diff(&DbStateSnapshot::take(&db).unwrap());
+        assert_eq!(diffs, vec![
+            DbStateDiff::Removed {
+                key: "0x01".to_string(),
+                expected: "0xaa".to_string(),
+            },
+            DbStateDiff::Changed {
+                key: "0x02".to_string(),
+                expected: "0xbb".to_string(),
+                actual: "0xcc".to_string(),
+            },
+            DbStateDiff::Added {
+                key: "0x03".to_string(),
+                actual: "0xdd".to_string(),
+            },
+        ]);
+        let err = check_db_matches_snapshot(&db, &expected)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("~ 0x02: 0xbb -> 0xcc"));
+    }
+
+    #[test]
+    fn should_ignore_excluded_keys() {
+        let db = get_test_database();
+        put(&db, &CORE_IS_PAUSED_KEY[..], &[0x00]);
+        let expected = DbStateSnapshot::take(&db)
+            .unwrap()
+            .excluding(&["CORE_IS_PAUSED_KEY"]);
+        assert!(expected.0.is_empty());
+        let actual = DbStateSnapshot::take(&db)
+            .unwrap()
+            .excluding(&["CORE_IS_PAUSED_KEY"]);
+        assert!(expected.diff(&actual).is_empty());
+    }
+}
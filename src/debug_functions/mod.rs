@@ -1,30 +1,177 @@
+use std::str::FromStr;
+use serde_json::json;
+use bitcoin_hashes::sha256d;
 use crate::{
-    types::Result,
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    errors::AppError,
     traits::DatabaseInterface,
     check_debug_mode::check_debug_mode,
+    debug_signature::{
+        check_debug_signature,
+        generate_debug_signature_challenge,
+    },
+    db_keys::{
+        get_unexpected_db_keys,
+        get_data_sensitivity_of_db_key,
+    },
+    asset_dictionary::{
+        AssetDictionary,
+        AssetDictionaryEntry,
+    },
+    database_utils::put_core_is_paused_in_db,
+    db_codec::{
+        DbSerializationFormat,
+        put_db_serialization_format_in_db,
+    },
+    state_size::put_max_state_size_in_db,
+    stale_transactions::{
+        put_stale_tx_threshold_in_db,
+        remove_tx_from_watchlist_in_db,
+    },
+    operator_fees::{
+        put_operator_fee_btc_address_in_db,
+        withdraw_accrued_operator_fees_in_db,
+    },
+    refund_ledger::{
+        approve_refund_in_db,
+        build_refund_txs_in_db,
+    },
+    recipient_policy::{
+        RecipientPolicy,
+        RecipientPolicyMode,
+        put_recipient_policy_in_db,
+        get_recipient_policy_from_db,
+    },
+    volume_limits::{
+        VolumeLimit,
+        put_volume_limit_in_db,
+        remove_volume_limit_from_db,
+        release_volume_limited_params_in_db,
+    },
+    pending_approvals::{
+        ApprovalDecision,
+        put_approval_threshold_in_db,
+        remove_approval_threshold_from_db,
+        decide_pending_approval_in_db,
+    },
+    constants::{
+        MINT_VOLUME_LIMIT_KEY,
+        PAYOUT_VOLUME_LIMIT_KEY,
+        MINT_APPROVAL_THRESHOLD_KEY,
+        PAYOUT_APPROVAL_THRESHOLD_KEY,
+    },
+    log_config::{
+        LogConfig,
+        apply_log_config,
+        put_log_config_in_db,
+    },
+    pipeline::{
+        get_pipeline_flags,
+        set_pipeline_stage_enabled_in_db,
+    },
+    safe_addresses::{
+        SafeAddressTier,
+        get_safe_eth_address_from_db,
+        get_safe_btc_address_from_db,
+        update_safe_eth_address_in_db,
+        update_safe_btc_address_in_db,
+        get_safe_address_tiers_from_db,
+        get_safe_address_history_from_db,
+        update_safe_address_for_tier_in_db,
+    },
+    get_core_state::{
+        get_core_state,
+        get_btc_core_state,
+        get_eth_core_state,
+    },
     check_enclave_is_initialized::check_enclave_is_initialized,
-    eth::eth_constants::ETH_PRIVATE_KEY_DB_KEY as ETH_KEY,
+    utils::{
+        convert_hex_to_h256,
+        convert_hex_to_address,
+        convert_hex_strings_to_h256s,
+        check_hex_is_valid_ethereum_address,
+    },
+    eth::{
+        eth_types::EthTransactionType,
+        replace_eth_txs::{
+            replace_eth_mint_tx_in_db,
+            sign_eth_nonce_gap_tx_in_db,
+        },
+        reprocess_eth_block::reprocess_eth_block_in_db,
+        submit_eth_block::get_eth_submission_pipeline,
+        eth_database_utils::{
+            put_eth_tx_type_in_db,
+            put_eth_pos_mode_in_db,
+            put_eth_batch_mint_mode_in_db,
+            put_eth_mint_with_data_mode_in_db,
+            put_eth_pre_eip155_signing_in_db,
+            get_eth_canon_block_from_db,
+            get_eth_latest_block_from_db,
+            put_eth_canon_to_tip_length_in_db,
+            put_eth_tail_pruning_distance_in_db,
+            put_eth_host_token_decimals_in_db,
+            put_eth_ptoken_contract_topics_in_db,
+            update_eth_smart_contract_address_in_db,
+            put_eth_account_nonce_in_db,
+            get_eth_account_nonce_from_db,
+            put_eth_relay_forwarder_address_in_db,
+            remove_eth_relay_forwarder_address_from_db,
+            put_eth_mint_gas_limit_in_db,
+            put_eth_cancel_gas_limit_in_db,
+            put_eth_max_fee_per_gas_in_db,
+            get_eth_max_fee_per_gas_from_db,
+            put_eth_max_priority_fee_per_gas_in_db,
+        },
+    },
     utxo_manager::utxo_database_utils::{
         get_utxo_from_db,
         get_all_utxo_db_keys,
     },
     btc::{
-        btc_types::BtcUtxoAndValue,
-        btc_constants::BTC_PRIVATE_KEY_DB_KEY as BTC_KEY,
+        reprocess_btc_block::reprocess_btc_block_in_db,
+        submit_btc_block::get_btc_submission_pipeline,
+        btc_types::{
+            BtcUtxoAndValue,
+            DepositAddressJsonList,
+        },
+        btc_database_utils::{
+            put_btc_peg_in_fee_basis_points_in_db,
+            put_btc_peg_out_fee_basis_points_in_db,
+            put_btc_mint_gas_recapture_rate_in_db,
+        },
     },
 };
 
 pub fn debug_set_key_in_db_to_value<D>(
     db: D,
     key: String,
-    value: String
+    value: String,
+    signature: String,
 ) -> Result<String>
     where D: DatabaseInterface
 {
     info!("✔ Setting key: {} in DB to value: {}", key, value);
+    let key_bytes = hex::decode(&key)?;
     check_debug_mode()
         .and_then(|_| check_enclave_is_initialized(&db))
-        .and_then(|_| db.put(hex::decode(key)?, hex::decode(value)?, None))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_key_in_db_to_value",
+                &[key.clone(), value.clone()],
+                &signature,
+            )
+        )
+        .and_then(|_|
+            db.put(
+                key_bytes.clone(),
+                hex::decode(value)?,
+                get_data_sensitivity_of_db_key(&db, &key_bytes),
+            )
+        )
         .map(|_| "{putting_value_in_database_suceeded:true}".to_string())
 }
 
@@ -39,10 +186,10 @@ pub fn debug_get_key_from_db<D>(
     check_debug_mode()
         .and_then(|_| check_enclave_is_initialized(&db))
         .and_then(|_|
-            match key_bytes == ETH_KEY || key_bytes == BTC_KEY {
-                false => db.get(hex::decode(key.clone())?, None),
-                true => db.get(hex::decode(key.clone())?, Some(255)),
-            }
+            db.get(
+                key_bytes.clone(),
+                get_data_sensitivity_of_db_key(&db, &key_bytes),
+            )
         )
         .map(|value|
             format!(
@@ -80,7 +227,10 @@ pub fn debug_get_all_utxos<D>(
                                         get_utxo_from_db(&db, &db_key.to_vec())?,
                                     db_value:
                                         hex::encode(
-                                            db.get(db_key.to_vec(), None)?
+                                            db.get(
+                                                db_key.to_vec(),
+                                                DataSensitivity::Private,
+                                            )?
                                         ),
                                 }
                             )
@@ -92,3 +242,1728 @@ pub fn debug_get_all_utxos<D>(
             )
         )
 }
+
+pub fn debug_set_eth_dynamic_fee_params<D>(
+    db: D,
+    max_fee_per_gas: u64,
+    max_priority_fee_per_gas: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!(
+        "✔ Setting ETH max fee per gas: {} & max priority fee per gas: {}",
+        max_fee_per_gas,
+        max_priority_fee_per_gas,
+    );
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_eth_dynamic_fee_params",
+                &[
+                    max_fee_per_gas.to_string(),
+                    max_priority_fee_per_gas.to_string(),
+                ],
+                &signature,
+            )
+        )
+        .and_then(|_|
+            match max_priority_fee_per_gas > max_fee_per_gas {
+                false => Ok(()),
+                true => Err(AppError::InvalidAmount(
+                    "✘ Max priority fee per gas cannot exceed max fee per gas!"
+                        .to_string()
+                )),
+            }
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_eth_max_fee_per_gas_in_db(&db, &max_fee_per_gas))
+        .and_then(|_|
+            put_eth_max_priority_fee_per_gas_in_db(
+                &db,
+                &max_priority_fee_per_gas,
+            )
+        )
+        .and_then(|_| db.end_transaction())
+        .map(|_|
+            format!(
+                "{{max_fee_per_gas:{},max_priority_fee_per_gas:{}}}",
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+            )
+        )
+}
+
+pub fn debug_set_eth_tx_type<D>(
+    db: D,
+    tx_type: String,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting ETH transaction type to: {}", tx_type);
+    let eth_tx_type = EthTransactionType::from_str(&tx_type)?;
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_eth_tx_type",
+                &[tx_type.clone()],
+                &signature,
+            )
+        )
+        .and_then(|_|
+            match eth_tx_type {
+                EthTransactionType::Legacy => Ok(()),
+                EthTransactionType::DynamicFee =>
+                    get_eth_max_fee_per_gas_from_db(&db)
+                        .map_err(|_| AppError::InvalidInput(
+                            "✘ Set dynamic fee params before switching tx type!"
+                                .to_string()
+                        ))
+                        .map(|_| ()),
+            }
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_eth_tx_type_in_db(&db, &eth_tx_type))
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{eth_tx_type:{}}}", eth_tx_type))
+}
+
+pub fn debug_resign_eth_mint_tx<D>(
+    db: D,
+    nonce: u64,
+    gas_price: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!(
+        "✔ Re-signing ETH mint tx w/ nonce: {} & gas price: {}",
+        nonce,
+        gas_price,
+    );
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_resign_eth_mint_tx",
+                &[nonce.to_string(), gas_price.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| replace_eth_mint_tx_in_db(&db, nonce, gas_price))
+        .and_then(|signed_tx| {
+            db.end_transaction()?;
+            Ok(signed_tx)
+        })
+        .map(|signed_tx|
+            json!({
+                "nonce": nonce,
+                "gas_price": gas_price,
+                "tx_type": signed_tx.tx_type.to_string(),
+                "eth_tx_hex": signed_tx.serialize_hex(),
+                "eth_tx_hash": format!("0x{}", signed_tx.get_tx_hash()),
+            }).to_string()
+        )
+}
+
+pub fn debug_set_eth_account_nonce<D>(
+    db: D,
+    nonce: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting ETH account nonce to: {}", nonce);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_eth_account_nonce",
+                &[nonce.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_eth_account_nonce_in_db(&db, &nonce))
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{eth_account_nonce:{}}}", nonce))
+}
+
+pub fn debug_reconcile_eth_account_nonce<D>(
+    db: D,
+    on_chain_nonce: u64,
+    gas_price: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    #[derive(Serialize, Deserialize)]
+    struct NonceGapTxInfo {
+        pub nonce: u64,
+        pub tx_kind: String,
+        pub eth_tx_hex: String,
+        pub eth_tx_hash: String,
+    }
+    info!(
+        "✔ Reconciling ETH account nonce w/ on-chain nonce: {}",
+        on_chain_nonce,
+    );
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_reconcile_eth_account_nonce",
+                &[on_chain_nonce.to_string(), gas_price.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| get_eth_account_nonce_from_db(&db))
+        .and_then(|core_nonce|
+            match on_chain_nonce > core_nonce {
+                false => Ok(core_nonce),
+                true => Err(AppError::InvalidInput(format!(
+                    "✘ On-chain nonce of {} is ahead of core's nonce of {}!",
+                    on_chain_nonce,
+                    core_nonce,
+                )))
+            }
+        )
+        .and_then(|core_nonce| {
+            info!(
+                "✔ Signing {} txs for nonce gap...",
+                core_nonce - on_chain_nonce,
+            );
+            db.start_transaction()?;
+            let gap_txs = (on_chain_nonce..core_nonce)
+                .map(|nonce|
+                    sign_eth_nonce_gap_tx_in_db(&db, nonce, gas_price)
+                        .map(|(tx_kind, signed_tx)|
+                            NonceGapTxInfo {
+                                nonce,
+                                tx_kind: tx_kind.to_string(),
+                                eth_tx_hex: signed_tx.serialize_hex(),
+                                eth_tx_hash: format!(
+                                    "0x{}",
+                                    signed_tx.get_tx_hash(),
+                                ),
+                            }
+                        )
+                )
+                .collect::<Result<Vec<NonceGapTxInfo>>>()?;
+            db.end_transaction()?;
+            Ok(gap_txs)
+        })
+        .and_then(|gap_txs| Ok(serde_json::to_string(&gap_txs)?))
+}
+
+pub fn debug_set_eth_mint_gas_limit<D>(
+    db: D,
+    gas_limit: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting ETH mint gas limit to: {}", gas_limit);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_eth_mint_gas_limit",
+                &[gas_limit.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_eth_mint_gas_limit_in_db(&db, &gas_limit))
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{eth_mint_gas_limit:{}}}", gas_limit))
+}
+
+pub fn debug_set_eth_cancel_gas_limit<D>(
+    db: D,
+    gas_limit: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting ETH cancel gas limit to: {}", gas_limit);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_eth_cancel_gas_limit",
+                &[gas_limit.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_eth_cancel_gas_limit_in_db(&db, &gas_limit))
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{eth_cancel_gas_limit:{}}}", gas_limit))
+}
+
+pub fn debug_set_db_serialization_format<D>(
+    db: D,
+    format: DbSerializationFormat,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting db serialization format to {:?}...", format);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_db_serialization_format",
+                &[format!("{:?}", format)],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_db_serialization_format_in_db(&db, format))
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{db_serialization_format:{:?}}}", format))
+}
+
+pub fn debug_enable_eth_relay_mode<D>(
+    db: D,
+    forwarder_address: String,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Enabling ETH relay mode w/ forwarder: {}", forwarder_address);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_enable_eth_relay_mode",
+                &[forwarder_address.clone()],
+                &signature,
+            )
+        )
+        .and_then(|_|
+            match check_hex_is_valid_ethereum_address(&forwarder_address) {
+                true => convert_hex_to_address(forwarder_address.clone()),
+                false => Err(AppError::InvalidAddress(
+                    "✘ Forwarder address is not a valid ETH address!"
+                        .to_string()
+                )),
+            }
+        )
+        .and_then(|address| {
+            db.start_transaction()?;
+            put_eth_relay_forwarder_address_in_db(&db, &address)?;
+            db.end_transaction()
+        })
+        .map(|_|
+            format!(
+                "{{eth_relay_mode_enabled:true,forwarder_address:{}}}",
+                forwarder_address,
+            )
+        )
+}
+
+pub fn debug_disable_eth_relay_mode<D>(
+    db: D,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Disabling ETH relay mode...");
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_disable_eth_relay_mode",
+                &[],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| remove_eth_relay_forwarder_address_from_db(&db))
+        .and_then(|_| db.end_transaction())
+        .map(|_| "{eth_relay_mode_enabled:false}".to_string())
+}
+
+pub fn debug_set_eth_batch_mint_mode<D>(
+    db: D,
+    enabled: bool,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting ETH batch mint mode to: {}", enabled);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_eth_batch_mint_mode",
+                &[enabled.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_eth_batch_mint_mode_in_db(&db, enabled))
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{eth_batch_mint_mode_enabled:{}}}", enabled))
+}
+
+pub fn debug_set_eth_mint_with_data_mode<D>(
+    db: D,
+    enabled: bool,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting ETH mint with data mode to: {}", enabled);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_eth_mint_with_data_mode",
+                &[enabled.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_eth_mint_with_data_mode_in_db(&db, enabled))
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{eth_mint_with_data_mode_enabled:{}}}", enabled))
+}
+
+pub fn debug_set_eth_pre_eip155_signing<D>(
+    db: D,
+    enabled: bool,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting ETH pre-EIP155 signing to: {}", enabled);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_eth_pre_eip155_signing",
+                &[enabled.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_eth_pre_eip155_signing_in_db(&db, enabled))
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{eth_pre_eip155_signing_enabled:{}}}", enabled))
+}
+
+pub fn debug_set_eth_pos_mode<D>(
+    db: D,
+    enabled: bool,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting ETH proof-of-stake mode to: {}", enabled);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_eth_pos_mode",
+                &[enabled.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_eth_pos_mode_in_db(&db, enabled))
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{eth_pos_mode_enabled:{}}}", enabled))
+}
+
+fn check_pipeline_stage_flag_exists<D>(stage_flag: &str) -> Result<()>
+    where D: DatabaseInterface
+{
+    let stage_flags = [
+        get_pipeline_flags(&get_btc_submission_pipeline::<D>()),
+        get_pipeline_flags(&get_eth_submission_pipeline::<D>()),
+    ].concat();
+    match stage_flags.contains(&stage_flag) {
+        true => Ok(()),
+        false => Err(AppError::NotFound(
+            format!("✘ No optional pipeline stages for flag: {}!", stage_flag)
+        )),
+    }
+}
+
+pub fn debug_set_pipeline_stage_enabled<D>(
+    db: D,
+    stage_flag: String,
+    enabled: bool,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting '{}' pipeline stages to: {}", stage_flag, enabled);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_pipeline_stage_enabled",
+                &[stage_flag.clone(), enabled.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| check_pipeline_stage_flag_exists::<D>(&stage_flag))
+        .and_then(|_| db.start_transaction())
+        .and_then(|_|
+            set_pipeline_stage_enabled_in_db(&db, &stage_flag, enabled)
+        )
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{{}_enabled:{}}}", stage_flag, enabled))
+}
+
+pub fn debug_set_max_state_size<D>(
+    db: D,
+    max_state_size: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting max state size to: {} bytes", max_state_size);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_max_state_size",
+                &[max_state_size.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_max_state_size_in_db(&db, &max_state_size))
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{max_state_size:{}}}", max_state_size))
+}
+
+pub fn debug_set_stale_tx_threshold<D>(
+    db: D,
+    threshold_in_blocks: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting stale tx threshold to: {} blocks", threshold_in_blocks);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_stale_tx_threshold",
+                &[threshold_in_blocks.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_stale_tx_threshold_in_db(&db, threshold_in_blocks))
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{stale_tx_threshold:{}}}", threshold_in_blocks))
+}
+
+pub fn debug_remove_watched_tx<D>(
+    db: D,
+    chain: String,
+    tx_hash: String,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Removing {} tx {} from watchlist...", chain, tx_hash);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_remove_watched_tx",
+                &[chain.clone(), tx_hash.clone()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| remove_tx_from_watchlist_in_db(&db, &chain, &tx_hash))
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{removed_watched_tx:{}}}", tx_hash))
+}
+
+pub fn debug_set_log_config<D>(
+    db: D,
+    max_level: String,
+    redact_sensitive_data: bool,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug setting log config...");
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_log_config",
+                &[max_level.clone(), redact_sensitive_data.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| LogConfig::new(&max_level, redact_sensitive_data))
+        .and_then(|log_config| {
+            db.start_transaction()?;
+            put_log_config_in_db(&db, &log_config)?;
+            db.end_transaction()?;
+            apply_log_config(&log_config)?;
+            Ok(serde_json::to_string(&log_config)?)
+        })
+}
+
+pub fn debug_set_eth_tail_pruning_distance<D>(
+    db: D,
+    pruning_distance: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting ETH tail pruning distance to: {}", pruning_distance);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_eth_tail_pruning_distance",
+                &[pruning_distance.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_|
+            put_eth_tail_pruning_distance_in_db(&db, &pruning_distance)
+        )
+        .and_then(|_| db.end_transaction())
+        .map(|_|
+            format!("{{eth_tail_pruning_distance:{}}}", pruning_distance)
+        )
+}
+
+fn check_eth_canon_to_tip_length_is_safe<D>(
+    db: &D,
+    canon_to_tip_length: u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    let canon_block_number = get_eth_canon_block_from_db(db)?
+        .block
+        .number
+        .as_u64();
+    let latest_block_number = get_eth_latest_block_from_db(db)?
+        .block
+        .number
+        .as_u64();
+    let unprocessed_blocks =
+        latest_block_number.saturating_sub(canon_block_number);
+    match canon_to_tip_length >= unprocessed_blocks {
+        true => Ok(()),
+        false => Err(AppError::InvalidInput(format!(
+            "✘ Cannot reduce ETH canon-to-tip length below {} blocks {}",
+            unprocessed_blocks,
+            "already between canon & tip!",
+        ))),
+    }
+}
+
+pub fn debug_set_eth_canon_to_tip_length<D>(
+    db: D,
+    canon_to_tip_length: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting ETH canon-to-tip length to: {}", canon_to_tip_length);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_eth_canon_to_tip_length",
+                &[canon_to_tip_length.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| check_eth_canon_to_tip_length_is_safe(
+            &db,
+            canon_to_tip_length,
+        ))
+        .and_then(|_| db.start_transaction())
+        .and_then(|_|
+            put_eth_canon_to_tip_length_in_db(&db, &canon_to_tip_length)
+        )
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{eth_canon_to_tip_length:{}}}", canon_to_tip_length))
+}
+
+pub fn debug_set_eth_ptoken_contract_topics<D>(
+    db: D,
+    topics_json: String,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting pToken contract topics to: {}", topics_json);
+    let topics = convert_hex_strings_to_h256s(
+        serde_json::from_str::<Vec<String>>(&topics_json)?
+    )?;
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_eth_ptoken_contract_topics",
+                &[topics_json.clone()],
+                &signature,
+            )
+        )
+        .and_then(|_| match topics.is_empty() {
+            false => Ok(()),
+            true => Err(AppError::InvalidInput(
+                "✘ At least one pToken contract topic is required!".to_string()
+            )),
+        })
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_eth_ptoken_contract_topics_in_db(&db, &topics))
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{eth_ptoken_contract_topics:{}}}", topics.len()))
+}
+
+pub fn debug_set_ptoken_contract_address<D>(
+    db: D,
+    contract_address: String,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting pToken contract address to: {}", contract_address);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_ptoken_contract_address",
+                &[contract_address.clone()],
+                &signature,
+            )
+        )
+        .and_then(|_|
+            match check_hex_is_valid_ethereum_address(&contract_address) {
+                true => convert_hex_to_address(contract_address.clone()),
+                false => Err(AppError::InvalidAddress(
+                    "✘ pToken contract address is not a valid ETH address!"
+                        .to_string()
+                )),
+            }
+        )
+        .and_then(|address| {
+            db.start_transaction()?;
+            update_eth_smart_contract_address_in_db(&db, &address)?;
+            db.end_transaction()
+        })
+        .map(|_| format!("{{ptoken_contract_address:{}}}", contract_address))
+}
+
+pub fn debug_set_btc_peg_in_fee<D>(
+    db: D,
+    basis_points: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting BTC peg-in fee to {} basis points...", basis_points);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_btc_peg_in_fee",
+                &[basis_points.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_btc_peg_in_fee_basis_points_in_db(&db, &basis_points))
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{btc_peg_in_fee_basis_points:{}}}", basis_points))
+}
+
+pub fn debug_set_btc_peg_out_fee<D>(
+    db: D,
+    basis_points: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting BTC peg-out fee to {} basis points...", basis_points);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_btc_peg_out_fee",
+                &[basis_points.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_|
+            put_btc_peg_out_fee_basis_points_in_db(&db, &basis_points)
+        )
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{btc_peg_out_fee_basis_points:{}}}", basis_points))
+}
+
+pub fn debug_set_btc_mint_gas_recapture_rate<D>(
+    db: D,
+    satoshis_per_eth: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!(
+        "✔ Setting BTC mint gas recapture rate to {} satoshis per ETH...",
+        satoshis_per_eth,
+    );
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_btc_mint_gas_recapture_rate",
+                &[satoshis_per_eth.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_|
+            put_btc_mint_gas_recapture_rate_in_db(&db, &satoshis_per_eth)
+        )
+        .and_then(|_| db.end_transaction())
+        .map(|_|
+            format!("{{btc_mint_gas_recapture_rate:{}}}", satoshis_per_eth)
+        )
+}
+
+pub fn debug_set_operator_fee_btc_address<D>(
+    db: D,
+    address: String,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting operator fee BTC address to: {}", address);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_operator_fee_btc_address",
+                &[address.clone()],
+                &signature,
+            )
+        )
+        .and_then(|_| {
+            db.start_transaction()?;
+            put_operator_fee_btc_address_in_db(&db, &address)?;
+            db.end_transaction()?;
+            Ok(json!({"operator_fee_btc_address": address}).to_string())
+        })
+}
+
+pub fn debug_withdraw_accrued_operator_fees<D>(
+    db: D,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug withdrawing accrued operator fees...");
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_withdraw_accrued_operator_fees",
+                &[],
+                &signature,
+            )
+        )
+        .and_then(|_| {
+            db.start_transaction()?;
+            let withdrawal = withdraw_accrued_operator_fees_in_db(&db)?;
+            db.end_transaction()?;
+            Ok(serde_json::to_string(&withdrawal)?)
+        })
+}
+
+pub fn debug_approve_refund<D>(
+    db: D,
+    utxo_reference: String,
+    refund_btc_address: String,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug approving refund of {}...", utxo_reference);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_approve_refund",
+                &[utxo_reference.clone(), refund_btc_address.clone()],
+                &signature,
+            )
+        )
+        .and_then(|_| {
+            db.start_transaction()?;
+            let entry = approve_refund_in_db(
+                &db,
+                &utxo_reference,
+                &refund_btc_address,
+            )?;
+            db.end_transaction()?;
+            Ok(serde_json::to_string(&entry)?)
+        })
+}
+
+pub fn debug_build_refund_txs<D>(
+    db: D,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug building BTC refund txs...");
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_build_refund_txs",
+                &[],
+                &signature,
+            )
+        )
+        .and_then(|_| {
+            db.start_transaction()?;
+            let refund_txs = build_refund_txs_in_db(&db)?;
+            db.end_transaction()?;
+            Ok(serde_json::to_string(&refund_txs)?)
+        })
+}
+
+fn set_volume_limit<D>(
+    db: D,
+    debug_function_name: &str,
+    limit_key: &[u8],
+    max_amount_in_satoshis: u64,
+    window_in_blocks: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    let limit = VolumeLimit { window_in_blocks, max_amount_in_satoshis };
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                debug_function_name,
+                &[
+                    max_amount_in_satoshis.to_string(),
+                    window_in_blocks.to_string(),
+                ],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_volume_limit_in_db(&db, limit_key, &limit))
+        .and_then(|_| db.end_transaction())
+        .and_then(|_| Ok(serde_json::to_string(&limit)?))
+}
+
+pub fn debug_set_mint_volume_limit<D>(
+    db: D,
+    max_amount_in_satoshis: u64,
+    window_in_blocks: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!(
+        "✔ Setting mint volume limit to {} satoshis per {} BTC blocks...",
+        max_amount_in_satoshis,
+        window_in_blocks,
+    );
+    set_volume_limit(
+        db,
+        "debug_set_mint_volume_limit",
+        &MINT_VOLUME_LIMIT_KEY,
+        max_amount_in_satoshis,
+        window_in_blocks,
+        signature,
+    )
+}
+
+pub fn debug_set_payout_volume_limit<D>(
+    db: D,
+    max_amount_in_satoshis: u64,
+    window_in_blocks: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!(
+        "✔ Setting payout volume limit to {} satoshis per {} ETH blocks...",
+        max_amount_in_satoshis,
+        window_in_blocks,
+    );
+    set_volume_limit(
+        db,
+        "debug_set_payout_volume_limit",
+        &PAYOUT_VOLUME_LIMIT_KEY,
+        max_amount_in_satoshis,
+        window_in_blocks,
+        signature,
+    )
+}
+
+fn remove_volume_limit<D>(
+    db: D,
+    debug_function_name: &str,
+    limit_key: &[u8],
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(&db, debug_function_name, &[], &signature)
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| remove_volume_limit_from_db(&db, limit_key))
+        .and_then(|_| db.end_transaction())
+        .map(|_| json!({"volume_limit": null}).to_string())
+}
+
+pub fn debug_remove_mint_volume_limit<D>(
+    db: D,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Removing mint volume limit...");
+    remove_volume_limit(
+        db,
+        "debug_remove_mint_volume_limit",
+        &MINT_VOLUME_LIMIT_KEY,
+        signature,
+    )
+}
+
+pub fn debug_remove_payout_volume_limit<D>(
+    db: D,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Removing payout volume limit...");
+    remove_volume_limit(
+        db,
+        "debug_remove_payout_volume_limit",
+        &PAYOUT_VOLUME_LIMIT_KEY,
+        signature,
+    )
+}
+
+pub fn debug_release_volume_limited_params<D>(
+    db: D,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug releasing params held by volume limits...");
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_release_volume_limited_params",
+                &[],
+                &signature,
+            )
+        )
+        .and_then(|_| {
+            db.start_transaction()?;
+            let (num_minting_params, num_redeem_params) =
+                release_volume_limited_params_in_db(&db)?;
+            db.end_transaction()?;
+            Ok(json!({
+                "released_minting_params": num_minting_params,
+                "released_redeem_params": num_redeem_params,
+            }).to_string())
+        })
+}
+
+fn set_approval_threshold<D>(
+    db: D,
+    debug_function_name: &str,
+    threshold_key: &[u8],
+    threshold_in_satoshis: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                debug_function_name,
+                &[threshold_in_satoshis.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_|
+            put_approval_threshold_in_db(
+                &db,
+                threshold_key,
+                threshold_in_satoshis,
+            )
+        )
+        .and_then(|_| db.end_transaction())
+        .map(|_|
+            format!("{{approval_threshold:{}}}", threshold_in_satoshis)
+        )
+}
+
+pub fn debug_set_mint_approval_threshold<D>(
+    db: D,
+    threshold_in_satoshis: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!(
+        "✔ Setting mint approval threshold to {} satoshis...",
+        threshold_in_satoshis,
+    );
+    set_approval_threshold(
+        db,
+        "debug_set_mint_approval_threshold",
+        &MINT_APPROVAL_THRESHOLD_KEY,
+        threshold_in_satoshis,
+        signature,
+    )
+}
+
+pub fn debug_set_payout_approval_threshold<D>(
+    db: D,
+    threshold_in_satoshis: u64,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!(
+        "✔ Setting payout approval threshold to {} satoshis...",
+        threshold_in_satoshis,
+    );
+    set_approval_threshold(
+        db,
+        "debug_set_payout_approval_threshold",
+        &PAYOUT_APPROVAL_THRESHOLD_KEY,
+        threshold_in_satoshis,
+        signature,
+    )
+}
+
+fn remove_approval_threshold<D>(
+    db: D,
+    debug_function_name: &str,
+    threshold_key: &[u8],
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(&db, debug_function_name, &[], &signature)
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| remove_approval_threshold_from_db(&db, threshold_key))
+        .and_then(|_| db.end_transaction())
+        .map(|_| json!({"approval_threshold": null}).to_string())
+}
+
+pub fn debug_remove_mint_approval_threshold<D>(
+    db: D,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Removing mint approval threshold...");
+    remove_approval_threshold(
+        db,
+        "debug_remove_mint_approval_threshold",
+        &MINT_APPROVAL_THRESHOLD_KEY,
+        signature,
+    )
+}
+
+pub fn debug_remove_payout_approval_threshold<D>(
+    db: D,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Removing payout approval threshold...");
+    remove_approval_threshold(
+        db,
+        "debug_remove_payout_approval_threshold",
+        &PAYOUT_APPROVAL_THRESHOLD_KEY,
+        signature,
+    )
+}
+
+fn decide_pending_approval<D>(
+    db: D,
+    debug_function_name: &str,
+    correlation_id: String,
+    decision: ApprovalDecision,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                debug_function_name,
+                &[correlation_id.clone()],
+                &signature,
+            )
+        )
+        .and_then(|_| {
+            db.start_transaction()?;
+            let outcome = decide_pending_approval_in_db(
+                &db,
+                &correlation_id,
+                decision,
+            )?;
+            db.end_transaction()?;
+            Ok(serde_json::to_string(&outcome)?)
+        })
+}
+
+pub fn debug_release_pending_approval<D>(
+    db: D,
+    correlation_id: String,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug releasing {} from pending approval...", correlation_id);
+    decide_pending_approval(
+        db,
+        "debug_release_pending_approval",
+        correlation_id,
+        ApprovalDecision::Released,
+        signature,
+    )
+}
+
+pub fn debug_reject_pending_approval<D>(
+    db: D,
+    correlation_id: String,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug rejecting {} from pending approval...", correlation_id);
+    decide_pending_approval(
+        db,
+        "debug_reject_pending_approval",
+        correlation_id,
+        ApprovalDecision::Rejected,
+        signature,
+    )
+}
+
+fn update_recipient_policy<D, F>(
+    db: D,
+    debug_function_name: &str,
+    args: &[String],
+    signature: String,
+    update_fxn: F,
+) -> Result<String>
+    where D: DatabaseInterface,
+          F: FnOnce(RecipientPolicy) -> Result<RecipientPolicy>
+{
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(&db, debug_function_name, args, &signature)
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| get_recipient_policy_from_db(&db))
+        .and_then(|maybe_policy| update_fxn(maybe_policy.unwrap_or_default()))
+        .and_then(|policy| {
+            put_recipient_policy_in_db(&db, &policy)?;
+            db.end_transaction()?;
+            Ok(serde_json::to_string(&policy)?)
+        })
+}
+
+pub fn debug_add_recipient_to_policy<D>(
+    db: D,
+    chain: &str,
+    address: &str,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Adding {} address '{}' to recipient policy...", chain, address);
+    update_recipient_policy(
+        db,
+        "debug_add_recipient_to_policy",
+        &[chain.to_string(), address.to_string()],
+        signature,
+        |policy| policy.add_address(chain, address),
+    )
+}
+
+pub fn debug_remove_recipient_from_policy<D>(
+    db: D,
+    chain: &str,
+    address: &str,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!(
+        "✔ Removing {} address '{}' from recipient policy...",
+        chain,
+        address,
+    );
+    update_recipient_policy(
+        db,
+        "debug_remove_recipient_from_policy",
+        &[chain.to_string(), address.to_string()],
+        signature,
+        |policy| policy.remove_address(chain, address),
+    )
+}
+
+pub fn debug_set_recipient_policy_mode<D>(
+    db: D,
+    mode: &str,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting recipient policy mode to: {}", mode);
+    update_recipient_policy(
+        db,
+        "debug_set_recipient_policy_mode",
+        &[mode.to_string()],
+        signature,
+        |policy| Ok(policy.with_mode(RecipientPolicyMode::from_str(mode)?)),
+    )
+}
+
+pub fn debug_set_eth_host_token_decimals<D>(
+    db: D,
+    decimals: u32,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Setting ETH host token decimals to: {}", decimals);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_eth_host_token_decimals",
+                &[decimals.to_string()],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_eth_host_token_decimals_in_db(&db, &decimals))
+        .and_then(|_| db.end_transaction())
+        .map(|_| format!("{{eth_host_token_decimals:{}}}", decimals))
+}
+
+pub fn debug_set_core_is_paused<D>(
+    db: D,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Pausing core...");
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_core_is_paused",
+                &[],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_core_is_paused_in_db(&db, true))
+        .and_then(|_| db.end_transaction())
+        .map(|_| "{core_is_paused:true}".to_string())
+}
+
+pub fn debug_unset_core_is_paused<D>(
+    db: D,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Unpausing core...");
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_unset_core_is_paused",
+                &[],
+                &signature,
+            )
+        )
+        .and_then(|_| db.start_transaction())
+        .and_then(|_| put_core_is_paused_in_db(&db, false))
+        .and_then(|_| db.end_transaction())
+        .map(|_| "{core_is_paused:false}".to_string())
+}
+
+pub fn debug_add_asset_dictionary_entry<D>(
+    db: D,
+    entry_json: String,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Adding asset dictionary entry...");
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_add_asset_dictionary_entry",
+                &[entry_json.clone()],
+                &signature,
+            )
+        )
+        .and_then(|_| AssetDictionaryEntry::from_json_string(&entry_json))
+        .and_then(|entry| {
+            db.start_transaction()?;
+            let dictionary = AssetDictionary::get_from_db(&db)?
+                .add_entry(entry)?;
+            dictionary.put_in_db(&db)?;
+            db.end_transaction()?;
+            dictionary.to_json_string()
+        })
+}
+
+pub fn debug_remove_asset_dictionary_entry<D>(
+    db: D,
+    token_symbol: String,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Removing asset dictionary entry for '{}'...", token_symbol);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_remove_asset_dictionary_entry",
+                &[token_symbol.clone()],
+                &signature,
+            )
+        )
+        .and_then(|_| {
+            db.start_transaction()?;
+            let dictionary = AssetDictionary::get_from_db(&db)?
+                .remove_entry(&token_symbol)?;
+            dictionary.put_in_db(&db)?;
+            db.end_transaction()?;
+            dictionary.to_json_string()
+        })
+}
+
+pub fn debug_get_unexpected_db_keys<D>(
+    db: D,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug getting unexpected db keys...");
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_| get_unexpected_db_keys(&db))
+        .map(|keys|
+            json!({
+                "num_unexpected_keys": keys.len(),
+                "unexpected_keys": keys
+                    .iter()
+                    .map(hex::encode)
+                    .collect::<Vec<String>>(),
+            }).to_string()
+        )
+}
+
+pub fn debug_get_core_state<D>(
+    db: D,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug getting core state...");
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_| get_core_state(&db))
+        .and_then(|state| Ok(serde_json::to_string(&state)?))
+}
+
+pub fn debug_get_btc_core_state<D>(
+    db: D,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug getting BTC core state...");
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_| get_btc_core_state(&db))
+        .and_then(|state| Ok(serde_json::to_string(&state)?))
+}
+
+pub fn debug_get_eth_core_state<D>(
+    db: D,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug getting ETH core state...");
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_| get_eth_core_state(&db))
+        .and_then(|state| Ok(serde_json::to_string(&state)?))
+}
+
+pub fn debug_reprocess_btc_block<D>(
+    db: D,
+    btc_block_hash: String,
+    deposit_address_list_json: String,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug re-processing BTC block: {}", btc_block_hash);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_reprocess_btc_block",
+                &[btc_block_hash.clone(), deposit_address_list_json.clone()],
+                &signature,
+            )
+        )
+        .and_then(|_| {
+            let deposit_address_list = serde_json::from_str::<
+                DepositAddressJsonList
+            >(&deposit_address_list_json)?;
+            reprocess_btc_block_in_db(
+                db,
+                &sha256d::Hash::from_str(&btc_block_hash)?,
+                &deposit_address_list,
+            )
+        })
+        .and_then(|report| Ok(serde_json::to_string(&report)?))
+}
+
+pub fn debug_reprocess_eth_block<D>(
+    db: D,
+    eth_block_hash: String,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug re-processing ETH block: {}", eth_block_hash);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_reprocess_eth_block",
+                &[eth_block_hash.clone()],
+                &signature,
+            )
+        )
+        .and_then(|_| convert_hex_to_h256(eth_block_hash))
+        .and_then(|block_hash| reprocess_eth_block_in_db(db, &block_hash))
+        .and_then(|report| Ok(serde_json::to_string(&report)?))
+}
+
+pub fn debug_set_safe_eth_address<D>(
+    db: D,
+    safe_eth_address: String,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug setting safe ETH address to: {}", safe_eth_address);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_safe_eth_address",
+                &[safe_eth_address.clone()],
+                &signature,
+            )
+        )
+        .and_then(|_|
+            match check_hex_is_valid_ethereum_address(&safe_eth_address) {
+                false => Err(AppError::InvalidAddress(
+                    "✘ Safe address is not a valid ETH address!".to_string()
+                )),
+                true => convert_hex_to_address(safe_eth_address.clone()),
+            }
+        )
+        .and_then(|address| {
+            db.start_transaction()?;
+            update_safe_eth_address_in_db(&db, &address)?;
+            db.end_transaction()?;
+            Ok(json!({"safe_eth_address": safe_eth_address}).to_string())
+        })
+}
+
+pub fn debug_set_safe_btc_address<D>(
+    db: D,
+    safe_btc_address: String,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug setting safe BTC address to: {}", safe_btc_address);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_safe_btc_address",
+                &[safe_btc_address.clone()],
+                &signature,
+            )
+        )
+        .and_then(|_| {
+            db.start_transaction()?;
+            update_safe_btc_address_in_db(&db, &safe_btc_address)?;
+            db.end_transaction()?;
+            Ok(json!({"safe_btc_address": safe_btc_address}).to_string())
+        })
+}
+
+pub fn debug_set_safe_address_for_tier<D>(
+    db: D,
+    chain: &str,
+    tier: &str,
+    safe_address: &str,
+    signature: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug setting {} tier safe {} address...", tier, chain);
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            check_debug_signature(
+                &db,
+                "debug_set_safe_address_for_tier",
+                &[
+                    chain.to_string(),
+                    tier.to_string(),
+                    safe_address.to_string(),
+                ],
+                &signature,
+            )
+        )
+        .and_then(|_| SafeAddressTier::from_str(tier))
+        .and_then(|tier| {
+            db.start_transaction()?;
+            update_safe_address_for_tier_in_db(&db, chain, tier, safe_address)?;
+            db.end_transaction()?;
+            Ok(serde_json::to_string(&get_safe_address_tiers_from_db(&db)?)?)
+        })
+}
+
+pub fn debug_get_safe_addresses<D>(
+    db: D,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug getting safe addresses...");
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_|
+            Ok(json!({
+                "safe_eth_address": format!(
+                    "0x{}",
+                    hex::encode(get_safe_eth_address_from_db(&db)?.as_bytes())
+                ),
+                "safe_btc_address": get_safe_btc_address_from_db(&db)?,
+                "safe_address_tiers": get_safe_address_tiers_from_db(&db)?,
+                "safe_address_history": get_safe_address_history_from_db(&db)?,
+            }).to_string())
+        )
+}
+
+pub fn debug_get_signature_challenge<D>(
+    db: D,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug getting signature challenge...");
+    check_debug_mode()
+        .and_then(|_| check_enclave_is_initialized(&db))
+        .and_then(|_| generate_debug_signature_challenge(&db))
+        .map(|challenge| json!({"challenge": challenge}).to_string())
+}
@@ -0,0 +1,346 @@
+use secp256k1::{
+    Message,
+    Secp256k1,
+    key::PublicKey,
+    recovery::{
+        RecoveryId,
+        RecoverableSignature,
+    },
+};
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    check_debug_mode::check_debug_mode,
+    crypto_utils::{
+        keccak_hash_bytes,
+        get_x_random_bytes,
+    },
+    types::{
+        Bytes,
+        Result,
+        DataSensitivity,
+    },
+    constants::{
+        DEBUG_ADMIN_PUBLIC_KEY_KEY,
+        DEBUG_SIGNATURE_CHALLENGE_KEY,
+    },
+    btc::initialize_btc::is_btc_initialized::is_btc_enclave_initialized,
+    eth::initialize_eth::is_eth_initialized::is_eth_enclave_initialized,
+};
+
+pub fn get_debug_admin_public_key_from_db<D>(db: &D) -> Result<PublicKey>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting debug admin public key from db...");
+    db.get(DEBUG_ADMIN_PUBLIC_KEY_KEY.to_vec(), DataSensitivity::Public)
+        .map_err(|_|
+            AppError::DebugSignatureError(
+                "✘ No debug admin public key in db ∴ cannot verify signature!"
+                    .to_string()
+            )
+        )
+        .and_then(|bytes| Ok(PublicKey::from_slice(&bytes)?))
+}
+
+fn put_debug_admin_public_key_in_db<D>(
+    db: &D,
+    public_key: &PublicKey,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    match get_debug_admin_public_key_from_db(db) {
+        Ok(_) => Err(AppError::DebugSignatureError(
+            "✘ Debug admin public key already set & cannot be changed!"
+                .to_string()
+        )),
+        Err(_) => {
+            info!("✔ Putting debug admin public key in db...");
+            db.put(
+                DEBUG_ADMIN_PUBLIC_KEY_KEY.to_vec(),
+                public_key.serialize_uncompressed().to_vec(),
+                DataSensitivity::Public,
+            )
+        }
+    }
+}
+
+pub fn generate_debug_signature_challenge<D>(db: &D) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Generating new debug signature challenge...");
+    let challenge = hex::encode(get_x_random_bytes(32)?);
+    db.put(
+        DEBUG_SIGNATURE_CHALLENGE_KEY.to_vec(),
+        challenge.as_bytes().to_vec(),
+        DataSensitivity::Public,
+    )
+        .map(|_| challenge)
+}
+
+fn take_debug_signature_challenge_from_db<D>(db: &D) -> Result<String>
+    where D: DatabaseInterface
+{
+    let bytes = db.get(
+        DEBUG_SIGNATURE_CHALLENGE_KEY.to_vec(),
+        DataSensitivity::Public,
+    ).map_err(|_|
+        AppError::DebugSignatureError(
+            "✘ No debug signature challenge in db!".to_string()
+        )
+    )?;
+    db.delete(DEBUG_SIGNATURE_CHALLENGE_KEY.to_vec())
+        .and_then(|_| Ok(String::from_utf8(bytes).map_err(|e|
+            AppError::DebugSignatureError(
+                format!("✘ Invalid debug challenge: {}!", e)
+            )
+        )?))
+}
+
+pub fn get_debug_signature_message(
+    challenge: &str,
+    debug_function_name: &str,
+    args: &[String],
+) -> Bytes {
+    vec![challenge.to_string(), debug_function_name.to_string()]
+        .into_iter()
+        .chain(args.iter().cloned())
+        .collect::<Vec<String>>()
+        .join(":")
+        .as_bytes()
+        .to_vec()
+}
+
+pub fn recover_public_key_from_signature(
+    message: Bytes,
+    signature: &[u8],
+) -> Result<PublicKey> {
+    if signature.len() != 65 {
+        return Err(AppError::DebugSignatureError(
+            format!("✘ Debug signature must be 65 bytes: {}!", signature.len())
+        ))
+    };
+    let recovery_id = match signature[64] {
+        v if v >= 27 => v - 27,
+        v => v,
+    };
+    let recoverable_signature = RecoverableSignature::from_compact(
+        &signature[..64],
+        RecoveryId::from_i32(recovery_id as i32)?,
+    )?;
+    Ok(Secp256k1::new().recover(
+        &Message::from_slice(keccak_hash_bytes(message).as_bytes())?,
+        &recoverable_signature,
+    )?)
+}
+
+pub fn check_debug_signature<D>(
+    db: &D,
+    debug_function_name: &str,
+    args: &[String],
+    signature_hex: &str,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!("✔ Checking debug signature for '{}'...", debug_function_name);
+    let admin_public_key = get_debug_admin_public_key_from_db(db)?;
+    let challenge = take_debug_signature_challenge_from_db(db)?;
+    recover_public_key_from_signature(
+        get_debug_signature_message(&challenge, debug_function_name, args),
+        &hex::decode(signature_hex.trim_start_matches("0x"))?,
+    )
+        .and_then(|public_key| match public_key == admin_public_key {
+            true => {
+                info!("✔ Debug signature is valid!");
+                Ok(())
+            }
+            false => Err(AppError::DebugSignatureError(
+                "✘ Debug signature not signed by debug admin key!".to_string()
+            )),
+        })
+}
+
+// NOTE: The key may only be set whilst the core is being initialized, lest
+// whoever first calls this on a running core take over its debug functions.
+fn check_core_is_being_initialized<D>(db: &D) -> Result<()>
+    where D: DatabaseInterface
+{
+    match is_btc_enclave_initialized(db) && is_eth_enclave_initialized(db) {
+        false => Ok(()),
+        true => Err(AppError::DebugSignatureError(
+            "✘ Debug admin public key can only be set during initialization!"
+                .to_string()
+        )),
+    }
+}
+
+pub fn maybe_set_debug_admin_public_key<D>(
+    db: D,
+    public_key_hex: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe setting debug admin public key...");
+    check_core_is_being_initialized(&db)
+        .and_then(|_| set_debug_admin_public_key(db, public_key_hex))
+}
+
+// NOTE: Cores initialized before the key existed have no key & so no way to
+// call their guarded debug functions. Debug builds already let the host call
+// those unsigned, so this gives it no new powers, & it's still one-shot since
+// the key cannot be changed once set.
+pub fn debug_set_debug_admin_public_key<D>(
+    db: D,
+    public_key_hex: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Debug setting debug admin public key...");
+    check_debug_mode()
+        .and_then(|_| set_debug_admin_public_key(db, public_key_hex))
+}
+
+fn set_debug_admin_public_key<D>(
+    db: D,
+    public_key_hex: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    Ok(hex::decode(public_key_hex.trim_start_matches("0x"))?)
+        .and_then(|bytes| Ok(PublicKey::from_slice(&bytes)?))
+        .and_then(|public_key| {
+            db.start_transaction()?;
+            put_debug_admin_public_key_in_db(&db, &public_key)?;
+            db.end_transaction()?;
+            Ok(public_key)
+        })
+        .map(|public_key|
+            format!(
+                "{{debug_admin_public_key:0x{}}}",
+                hex::encode(public_key.serialize_uncompressed().to_vec()),
+            )
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        btc::{
+            btc_database_utils::put_btc_address_in_db,
+            btc_test_utils::SAMPLE_TARGET_BTC_ADDRESS,
+        },
+        eth::{
+            eth_crypto::eth_private_key::EthPrivateKey,
+            eth_test_utils::get_sample_eth_address,
+            eth_database_utils::put_public_eth_address_in_db,
+        },
+    };
+
+    fn setup_debug_admin_key<D>(db: &D) -> EthPrivateKey
+        where D: DatabaseInterface
+    {
+        let private_key = EthPrivateKey::generate_random().unwrap();
+        put_debug_admin_public_key_in_db(
+            db,
+            &private_key.to_public_key().public_key,
+        ).unwrap();
+        private_key
+    }
+
+    fn sign_challenge(
+        private_key: &EthPrivateKey,
+        challenge: &str,
+        args: &[String],
+    ) -> String {
+        hex::encode(
+            private_key.sign_message_bytes(
+                get_debug_signature_message(challenge, "debug_fn", args)
+            ).unwrap().to_vec()
+        )
+    }
+
+    #[test]
+    fn should_accept_admin_signature_over_challenge_only_once() {
+        let db = get_test_database();
+        let private_key = setup_debug_admin_key(&db);
+        let args = vec!["1337".to_string()];
+        let challenge = generate_debug_signature_challenge(&db).unwrap();
+        let signature = sign_challenge(&private_key, &challenge, &args);
+        let result = check_debug_signature(&db, "debug_fn", &args, &signature);
+        assert!(result.is_ok());
+        let result = check_debug_signature(&db, "debug_fn", &args, &signature);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_reject_signature_over_different_args() {
+        let db = get_test_database();
+        let private_key = setup_debug_admin_key(&db);
+        let challenge = generate_debug_signature_challenge(&db).unwrap();
+        let signature =
+            sign_challenge(&private_key, &challenge, &["1".to_string()]);
+        let result = check_debug_signature(
+            &db,
+            "debug_fn",
+            &["2".to_string()],
+            &signature,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_not_allow_debug_admin_key_to_be_changed() {
+        let db = get_test_database();
+        setup_debug_admin_key(&db);
+        let new_key = EthPrivateKey::generate_random().unwrap();
+        let result = put_debug_admin_public_key_in_db(
+            &db,
+            &new_key.to_public_key().public_key,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_set_debug_admin_key_whilst_initializing() {
+        let db = get_test_database();
+        put_btc_address_in_db(&db, &SAMPLE_TARGET_BTC_ADDRESS.to_string())
+            .unwrap();
+        let private_key = EthPrivateKey::generate_random().unwrap();
+        let public_key_hex = hex::encode(
+            private_key.to_public_key().public_key.serialize().to_vec()
+        );
+        assert!(maybe_set_debug_admin_public_key(db, public_key_hex).is_ok());
+    }
+
+    #[test]
+    fn should_not_set_debug_admin_key_once_core_is_initialized() {
+        let db = get_test_database();
+        put_btc_address_in_db(&db, &SAMPLE_TARGET_BTC_ADDRESS.to_string())
+            .unwrap();
+        put_public_eth_address_in_db(&db, &get_sample_eth_address()).unwrap();
+        let private_key = EthPrivateKey::generate_random().unwrap();
+        let public_key_hex = hex::encode(
+            private_key.to_public_key().public_key.serialize().to_vec()
+        );
+        assert!(maybe_set_debug_admin_public_key(db, public_key_hex).is_err());
+    }
+
+    #[cfg(feature="debug")]
+    #[test]
+    fn should_debug_set_debug_admin_key_once_core_is_initialized() {
+        let db = get_test_database();
+        put_btc_address_in_db(&db, &SAMPLE_TARGET_BTC_ADDRESS.to_string())
+            .unwrap();
+        put_public_eth_address_in_db(&db, &get_sample_eth_address()).unwrap();
+        let private_key = EthPrivateKey::generate_random().unwrap();
+        let public_key_hex = hex::encode(
+            private_key.to_public_key().public_key.serialize().to_vec()
+        );
+        assert!(
+            debug_set_debug_admin_public_key(&db, public_key_hex.clone())
+                .is_ok()
+        );
+        assert!(debug_set_debug_admin_public_key(&db, public_key_hex).is_err());
+    }
+}
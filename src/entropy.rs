@@ -0,0 +1,178 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+#[cfg(any(test, feature = "test-doubles"))]
+use std::sync::atomic::{
+    Ordering,
+    AtomicU64,
+};
+use rand::{
+    RngCore,
+    thread_rng,
+};
+#[cfg(any(test, feature = "test-doubles"))]
+use bitcoin_hashes::{
+    sha256,
+    Hash as HashTrait,
+};
+use crate::{
+    errors::AppError,
+    types::{
+        Bytes,
+        Result,
+    },
+};
+
+pub trait EntropySource: Send {
+    fn fill_bytes(&self, bytes: &mut [u8]) -> Result<()>;
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ThreadRngEntropySource;
+
+impl EntropySource for ThreadRngEntropySource {
+    fn fill_bytes(&self, bytes: &mut [u8]) -> Result<()> {
+        thread_rng().fill_bytes(bytes);
+        Ok(())
+    }
+}
+
+// NOTE: Lets a host keep a handle on a source it has registered.
+impl<E: EntropySource + Sync> EntropySource for Arc<E> {
+    fn fill_bytes(&self, bytes: &mut [u8]) -> Result<()> {
+        self.as_ref().fill_bytes(bytes)
+    }
+}
+
+lazy_static! {
+    static ref ENTROPY_SOURCE: Mutex<Option<Box<dyn EntropySource>>> =
+        Mutex::new(None);
+}
+
+pub fn set_entropy_source(source: Box<dyn EntropySource>) -> Result<()> {
+    info!("✔ Setting host supplied entropy source...");
+    ENTROPY_SOURCE
+        .lock()
+        .map(|mut entropy_source| *entropy_source = Some(source))
+        .map_err(|_|
+            AppError::HostError("✘ Could not set entropy source!".to_string())
+        )
+}
+
+pub fn get_random_bytes_from_source(
+    source: &dyn EntropySource,
+    num_bytes: usize,
+) -> Result<Bytes> {
+    let mut bytes = vec![0u8; num_bytes];
+    source.fill_bytes(&mut bytes).map(|_| bytes)
+}
+
+#[cfg(not(feature = "sgx"))]
+fn get_random_bytes_from_default_source(num_bytes: usize) -> Result<Bytes> {
+    get_random_bytes_from_source(&ThreadRngEntropySource, num_bytes)
+}
+
+// NOTE: The thread RNG is no source of keys inside an enclave ∴ there's no
+// default & the host must register the enclave's RDRAND backed source.
+#[cfg(feature = "sgx")]
+fn get_random_bytes_from_default_source(_num_bytes: usize) -> Result<Bytes> {
+    Err(AppError::HostError(
+        "✘ No default entropy source in `sgx` builds ∴ set one!".to_string()
+    ))
+}
+
+// NOTE: Unlike the clock, a poisoned lock errors rather than falling back, so
+// a host which registered a source never silently uses another.
+pub fn get_random_bytes(num_bytes: usize) -> Result<Bytes> {
+    match ENTROPY_SOURCE.lock() {
+        Ok(entropy_source) => match entropy_source.as_ref() {
+            Some(source) => get_random_bytes_from_source(
+                source.as_ref(),
+                num_bytes,
+            ),
+            None => get_random_bytes_from_default_source(num_bytes),
+        },
+        Err(_) => Err(AppError::HostError(
+            "✘ Could not get entropy source!".to_string()
+        )),
+    }
+}
+
+// NOTE: A test double yielding the stream `sha256(seed ++ counter)`, so a
+// given seed always produces the same keys. Never for use in production!
+#[cfg(any(test, feature = "test-doubles"))]
+#[derive(Debug, Default)]
+pub struct DeterministicEntropySource {
+    seed: Bytes,
+    counter: AtomicU64,
+}
+
+#[cfg(any(test, feature = "test-doubles"))]
+impl DeterministicEntropySource {
+    pub fn new(seed: &[u8]) -> Self {
+        DeterministicEntropySource {
+            seed: seed.to_vec(),
+            counter: AtomicU64::new(0),
+        }
+    }
+}
+
+#[cfg(any(test, feature = "test-doubles"))]
+impl EntropySource for DeterministicEntropySource {
+    fn fill_bytes(&self, bytes: &mut [u8]) -> Result<()> {
+        bytes
+            .chunks_mut(32)
+            .for_each(|chunk| {
+                let counter = self.counter.fetch_add(1, Ordering::SeqCst);
+                let hash = sha256::Hash::hash(
+                    &[self.seed.clone(), counter.to_le_bytes().to_vec()]
+                        .concat()
+                );
+                chunk.copy_from_slice(&hash.into_inner()[..chunk.len()]);
+            });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_random_bytes() {
+        let result = get_random_bytes(100);
+        match cfg!(feature = "sgx") {
+            false => assert_eq!(result.unwrap().len(), 100),
+            true => assert!(result.is_err()),
+        }
+    }
+
+    #[test]
+    fn deterministic_source_should_repeat_for_same_seed() {
+        let source_1 = DeterministicEntropySource::new(b"seed");
+        let source_2 = DeterministicEntropySource::new(b"seed");
+        let result_1 = get_random_bytes_from_source(&source_1, 50).unwrap();
+        let result_2 = get_random_bytes_from_source(&source_2, 50).unwrap();
+        assert_eq!(result_1.len(), 50);
+        assert_eq!(result_1, result_2);
+    }
+
+    #[test]
+    fn deterministic_source_should_not_repeat_between_calls() {
+        let source = DeterministicEntropySource::new(b"seed");
+        let result_1 = get_random_bytes_from_source(&source, 32).unwrap();
+        let result_2 = get_random_bytes_from_source(&source, 32).unwrap();
+        assert_ne!(result_1, result_2);
+    }
+
+    #[test]
+    fn deterministic_sources_should_differ_by_seed() {
+        let source_1 = DeterministicEntropySource::new(b"seed-1");
+        let source_2 = DeterministicEntropySource::new(b"seed-2");
+        assert_ne!(
+            get_random_bytes_from_source(&source_1, 32).unwrap(),
+            get_random_bytes_from_source(&source_2, 32).unwrap(),
+        );
+    }
+}
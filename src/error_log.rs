@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+use crate::{
+    errors::AppError,
+    chain_state::ChainState,
+    traits::DatabaseInterface,
+    host_clock::get_unix_timestamp,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    constants::{
+        ERROR_LOG_KEY,
+        MAX_ERROR_LOG_LENGTH,
+    },
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorContext {
+    pub chain: String,
+    pub block_hash: Option<String>,
+    pub block_height: Option<u64>,
+}
+
+impl ErrorContext {
+    pub fn new(chain: &str) -> Self {
+        ErrorContext {
+            chain: chain.to_string(),
+            block_hash: None,
+            block_height: None,
+        }
+    }
+
+    pub fn from_state<S>(state: &S) -> Self
+        where S: ChainState
+    {
+        ErrorContext {
+            chain: S::chain_name().to_string(),
+            block_hash: state.get_block_hash(),
+            block_height: state.get_block_height(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorRecord {
+    pub code: u16,
+    pub message: String,
+    pub timestamp: Option<u64>,
+    pub context: ErrorContext,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorLog {
+    pub total_errors: u64,
+    pub error_counts: BTreeMap<String, u64>,
+    pub recent_errors: Vec<ErrorRecord>,
+}
+
+impl ErrorLog {
+    pub fn add_errors(mut self, errors: Vec<ErrorRecord>) -> Self {
+        errors.into_iter().for_each(|error| {
+            self.total_errors += 1;
+            *self.error_counts.entry(error.code.to_string()).or_insert(0) += 1;
+            self.recent_errors.push(error);
+        });
+        let excess = self
+            .recent_errors
+            .len()
+            .saturating_sub(MAX_ERROR_LOG_LENGTH);
+        self.recent_errors.drain(..excess);
+        self
+    }
+}
+
+pub fn get_error_log_from_db<D>(db: &D) -> Result<ErrorLog>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting error log from db...");
+    match db.get(ERROR_LOG_KEY.to_vec(), DataSensitivity::Public) {
+        Err(_) => Ok(ErrorLog::default()),
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+    }
+}
+
+fn put_error_log_in_db<D>(db: &D, error_log: &ErrorLog) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting error log in db...");
+    db.put(
+        ERROR_LOG_KEY.to_vec(),
+        serde_json::to_vec(error_log)?,
+        DataSensitivity::Public,
+    )
+}
+
+// NOTE: Written straight to the host's db, bypassing the submission's staged
+// writes, since those are discarded when it fails.
+pub fn record_error_in_db<D>(
+    db: &D,
+    dry_run: bool,
+    context: ErrorContext,
+    err: AppError,
+) -> AppError
+    where D: DatabaseInterface
+{
+    if dry_run {
+        info!("✔ Dry run ∴ not recording error in db!");
+        return err
+    };
+    let error_record = ErrorRecord {
+        context,
+        code: err.code(),
+        message: err.to_string(),
+        timestamp: get_unix_timestamp().ok(),
+    };
+    let result = db.start_transaction()
+        .and_then(|_| get_error_log_from_db(db))
+        .map(|error_log| error_log.add_errors(vec![error_record]))
+        .and_then(|error_log| put_error_log_in_db(db, &error_log))
+        .and_then(|_| db.end_transaction());
+    if let Err(db_err) = result {
+        info!("✘ Error recording error in db: {}", db_err);
+    };
+    err
+}
+
+pub fn get_error_log<D>(db: D) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting error log...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| get_error_log_from_db(&db))
+        .and_then(|error_log| Ok(serde_json::to_string(&error_log)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_test_database;
+
+    fn get_sample_error_record(code: u16) -> ErrorRecord {
+        ErrorRecord {
+            code,
+            timestamp: None,
+            message: "✘ Sample error!".to_string(),
+            context: ErrorContext::new("BTC"),
+        }
+    }
+
+    #[test]
+    fn should_add_errors_to_error_log_up_to_max_length() {
+        let errors = (0..MAX_ERROR_LOG_LENGTH + 1)
+            .map(|i| get_sample_error_record(1000 + (i % 2) as u16))
+            .collect();
+        let result = ErrorLog::default().add_errors(errors);
+        assert_eq!(result.total_errors, MAX_ERROR_LOG_LENGTH as u64 + 1);
+        assert_eq!(result.recent_errors.len(), MAX_ERROR_LOG_LENGTH);
+        assert_eq!(result.recent_errors[0].code, 1001);
+        assert_eq!(result.error_counts["1000"], 51);
+        assert_eq!(result.error_counts["1001"], 50);
+    }
+
+    #[test]
+    fn should_record_error_in_db() {
+        let db = get_test_database();
+        let err = AppError::Custom("✘ Sample error!".to_string());
+        let result = record_error_in_db(
+            &db,
+            false,
+            ErrorContext::new("ETH"),
+            err,
+        );
+        assert_eq!(result.to_string(), "✘ Sample error!");
+        let error_log = get_error_log_from_db(&db).unwrap();
+        assert_eq!(error_log.total_errors, 1);
+        assert_eq!(error_log.recent_errors[0].message, "✘ Sample error!");
+        assert_eq!(error_log.recent_errors[0].context.chain, "ETH");
+    }
+
+    #[test]
+    fn should_not_record_error_in_db_in_dry_run() {
+        let db = get_test_database();
+        let err = AppError::Custom("✘ Sample error!".to_string());
+        record_error_in_db(&db, true, ErrorContext::new("ETH"), err);
+        let result = get_error_log_from_db(&db).unwrap();
+        assert_eq!(result, ErrorLog::default());
+    }
+}
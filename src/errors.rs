@@ -3,15 +3,48 @@ use log;
 use std::fmt;
 use serde_json;
 use std::error::Error;
+use crate::{
+    constants::DB_ITEM_NOT_FOUND_ERROR,
+    utils::{
+        get_not_in_state_err,
+        get_no_overwrite_state_err,
+    },
+};
 
 #[derive(Debug)]
 pub enum AppError {
+    #[cfg(test)]
     Custom(String),
+    InvalidDepositInfoHash(String),
+    NoParentBlock { chain: String, parent_hash: String },
+    BlockAlreadyInDb { chain: String, block_hash: String },
+    InsufficientUtxoValue { utxo_total: u64, total_required: u64 },
+    StateSizeLimitExceeded { chain: String, size: u64, limit: u64 },
+    NotInitialized { chain: String },
+    NotInDb,
+    NotInState(String),
+    CannotOverwriteState(String),
+    NotInDebugMode,
+    InvalidBlock(String),
+    InvalidSubmission(String),
+    InvalidTransaction(String),
+    InvalidAddress(String),
+    InvalidAmount(String),
+    InvalidInput(String),
+    ConversionError(String),
+    NotFound(String),
+    DatabaseError(String),
+    TrieError(String),
+    DebugSignatureError(String),
+    HostError(String),
+    SimulationInvariant(String),
+    SimulationStepFailed { step: usize, error: String },
     IOError(std::io::Error),
     HexError(hex::FromHexError),
     CryptoError(secp256k1::Error),
     Base58Error(crate::base58::Error),
     SerdeJsonError(serde_json::Error),
+    SerdeCborError(serde_cbor::Error),
     NoneError(std::option::NoneError),
     FromUtf8Error(std::str::Utf8Error),
     SetLoggerError(log::SetLoggerError),
@@ -24,11 +57,118 @@ pub enum AppError {
     BitcoinAddressError(bitcoin::util::address::Error),
 }
 
+impl AppError {
+    pub fn code(&self) -> u16 {
+        match *self {
+            #[cfg(test)]
+            AppError::Custom(_) => 1000,
+            AppError::IOError(_) => 1001,
+            AppError::HexError(_) => 1002,
+            AppError::CryptoError(_) => 1003,
+            AppError::Base58Error(_) => 1004,
+            AppError::SerdeJsonError(_) => 1005,
+            AppError::NoneError(_) => 1006,
+            AppError::FromUtf8Error(_) => 1007,
+            AppError::SetLoggerError(_) => 1008,
+            AppError::ParseIntError(_) => 1009,
+            AppError::BitcoinHexError(_) => 1010,
+            AppError::SystemTimeError(_) => 1011,
+            AppError::FromSliceError(_) => 1012,
+            AppError::BitcoinHashError(_) => 1013,
+            AppError::BitcoinError(_) => 1014,
+            AppError::BitcoinAddressError(_) => 1015,
+            AppError::SerdeCborError(_) => 1016,
+            AppError::NoParentBlock { .. } => 2000,
+            AppError::BlockAlreadyInDb { .. } => 2001,
+            AppError::InsufficientUtxoValue { .. } => 2002,
+            AppError::InvalidDepositInfoHash(_) => 2003,
+            AppError::StateSizeLimitExceeded { .. } => 2004,
+            AppError::NotInitialized { .. } => 2005,
+            AppError::NotInDb => 2006,
+            AppError::NotInState(_) => 2007,
+            AppError::CannotOverwriteState(_) => 2008,
+            AppError::NotInDebugMode => 2009,
+            AppError::InvalidBlock(_) => 2010,
+            AppError::InvalidSubmission(_) => 2011,
+            AppError::InvalidTransaction(_) => 2012,
+            AppError::InvalidAddress(_) => 2013,
+            AppError::InvalidAmount(_) => 2014,
+            AppError::InvalidInput(_) => 2015,
+            AppError::ConversionError(_) => 2016,
+            AppError::NotFound(_) => 2017,
+            AppError::DatabaseError(_) => 2018,
+            AppError::TrieError(_) => 2019,
+            AppError::DebugSignatureError(_) => 2020,
+            AppError::HostError(_) => 2021,
+            AppError::SimulationInvariant(_) => 2022,
+            AppError::SimulationStepFailed { .. } => 2023,
+        }
+    }
+}
+
 impl fmt::Display for AppError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            #[cfg(test)]
             AppError::Custom(ref msg) =>
                 format!("{}", msg),
+            AppError::InvalidBlock(ref msg) |
+            AppError::InvalidSubmission(ref msg) |
+            AppError::InvalidTransaction(ref msg) |
+            AppError::InvalidAddress(ref msg) |
+            AppError::InvalidAmount(ref msg) |
+            AppError::InvalidInput(ref msg) |
+            AppError::ConversionError(ref msg) |
+            AppError::NotFound(ref msg) |
+            AppError::DatabaseError(ref msg) |
+            AppError::TrieError(ref msg) |
+            AppError::DebugSignatureError(ref msg) |
+            AppError::HostError(ref msg) |
+            AppError::SimulationInvariant(ref msg) =>
+                format!("{}", msg),
+            AppError::SimulationStepFailed { step, ref error } =>
+                format!("✘ Simulation step #{} failed: {}", step, error),
+            AppError::NotInitialized { ref chain } =>
+                format!("✘ {} side of enclave not initialized!", chain),
+            AppError::NotInDb =>
+                DB_ITEM_NOT_FOUND_ERROR.to_string(),
+            AppError::NotInState(ref name) =>
+                get_not_in_state_err(name),
+            AppError::CannotOverwriteState(ref name) =>
+                get_no_overwrite_state_err(name),
+            AppError::NotInDebugMode =>
+                "✘ Application NOT in debug mode - exiting!".to_string(),
+            AppError::NoParentBlock { ref chain, ref parent_hash } =>
+                format!(
+                    "✘ {} block rejected - parent {} not in database!",
+                    chain,
+                    parent_hash,
+                ),
+            AppError::BlockAlreadyInDb { ref chain, ref block_hash } =>
+                format!(
+                    "✘ {} block rejected - block {} already in database!",
+                    chain,
+                    block_hash,
+                ),
+            AppError::InsufficientUtxoValue {
+                utxo_total,
+                total_required,
+            } =>
+                format!(
+                    "✘ Not enough UTXO value: need {} satoshis, have {}!",
+                    total_required,
+                    utxo_total,
+                ),
+            AppError::StateSizeLimitExceeded { ref chain, size, limit } =>
+                format!(
+                    "✘ {} submission rejected - size of {} bytes exceeds \
+                    limit of {} bytes!",
+                    chain,
+                    size,
+                    limit,
+                ),
+            AppError::InvalidDepositInfoHash(ref hash) =>
+                format!("✘ Invalid deposit info hash: {}!", hash),
             AppError::HexError(ref e) =>
                 format!("✘ Hex Error!\n✘ {}", e),
             AppError::IOError(ref e) =>
@@ -41,6 +181,8 @@ impl fmt::Display for AppError {
                 format!("✘ Bitcoin Error!\n✘ {}", e),
             AppError::SerdeJsonError(ref e) =>
                 format!("✘ Serde-Json Error!\n✘ {}", e),
+            AppError::SerdeCborError(ref e) =>
+                format!("✘ Serde-Cbor Error!\n✘ {}", e),
             AppError::BitcoinHexError(ref e) =>
                 format!("✘ Bitcoin Hex Error!\n✘ {}", e),
             AppError::ParseIntError(ref e) =>
@@ -60,7 +202,7 @@ impl fmt::Display for AppError {
             AppError::SetLoggerError(ref e) =>
                 format!("✘ Error setting up logger!\n✘ {}", e),
         };
-        f.write_fmt(format_args!("{}", msg))
+        f.write_fmt(format_args!("[E{}] {}", self.code(), msg))
     }
 }
 
@@ -159,3 +301,51 @@ impl From<bitcoin::util::address::Error> for AppError {
         AppError::BitcoinAddressError(e)
     }
 }
+
+impl From<serde_cbor::Error> for AppError {
+    fn from(e: serde_cbor::Error) -> AppError {
+        AppError::SerdeCborError(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_include_error_code_in_display_output() {
+        let error = AppError::NoParentBlock {
+            chain: "BTC".to_string(),
+            parent_hash: "c0ffee".to_string(),
+        };
+        let expected_result =
+            "[E2000] ✘ BTC block rejected - parent c0ffee not in database!";
+        assert_eq!(error.code(), 2000);
+        assert_eq!(error.to_string(), expected_result);
+    }
+
+    #[test]
+    fn should_give_typed_errors_their_own_codes() {
+        let errors = vec![
+            AppError::NotInDb,
+            AppError::NotInitialized { chain: "ETH".to_string() },
+            AppError::InvalidBlock("✘ Invalid block!".to_string()),
+            AppError::SimulationStepFailed {
+                step: 1,
+                error: "✘ Invalid block!".to_string(),
+            },
+        ];
+        let expected_results = vec![
+            "[E2006] ✘ Cannot find item in database!",
+            "[E2005] ✘ ETH side of enclave not initialized!",
+            "[E2010] ✘ Invalid block!",
+            "[E2023] ✘ Simulation step #1 failed: ✘ Invalid block!",
+        ];
+        errors
+            .iter()
+            .zip(expected_results.iter())
+            .for_each(|(error, expected_result)|
+                assert_eq!(&error.to_string(), expected_result)
+            );
+    }
+}
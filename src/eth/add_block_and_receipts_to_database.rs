@@ -24,9 +24,13 @@ fn add_block_and_receipts_to_db_if_not_extant<D>(
             info!("✔ Block & receipts not in db, adding them now...");
             put_eth_block_and_receipts_in_db(db, block_and_receipts)
         }
-        true => Err(AppError::Custom(
-            format!("✘ Block Rejected - it's already in the db!")
-        ))
+        true => Err(AppError::BlockAlreadyInDb {
+            chain: "ETH".to_string(),
+            block_hash: format!(
+                "0x{}",
+                hex::encode(block_and_receipts.block.hash.as_bytes())
+            ),
+        })
     }
 }
 
@@ -1,6 +1,9 @@
 use ethereum_types::H256 as EthHash;
 use crate::{
-    types::Result,
+    types::{
+        Result,
+        DataSensitivity,
+    },
     errors::AppError,
     eth::eth_state::EthState,
     traits::DatabaseInterface,
@@ -14,7 +17,11 @@ fn check_db_for_parent_of_block_in_state<D>(
 ) -> bool
     where D: DatabaseInterface
 {
-    key_exists_in_db(db, &convert_h256_to_bytes(parent_hash.clone()), None)
+    key_exists_in_db(
+        db,
+        &convert_h256_to_bytes(parent_hash.clone()),
+        DataSensitivity::Public,
+    )
 }
 
 pub fn check_for_parent_of_block_in_state<D>(
@@ -23,17 +30,16 @@ pub fn check_for_parent_of_block_in_state<D>(
     where D: DatabaseInterface
 {
     info!("✔ Checking block's parent exists in database...");
-    match check_db_for_parent_of_block_in_state(
-        &state.db,
-        &state.get_parent_hash()?
-    ) {
+    let parent_hash = state.get_parent_hash()?;
+    match check_db_for_parent_of_block_in_state(&state.db, &parent_hash) {
         true => {
             info!("✔ Block's parent exists in database!");
             Ok(state)
         },
-        false => Err(AppError::Custom(
-            format!("✘ Block Rejected - no parent exists in database!")
-        )),
+        false => Err(AppError::NoParentBlock {
+            chain: "ETH".to_string(),
+            parent_hash: format!("0x{}", hex::encode(parent_hash.as_bytes())),
+        }),
     }
 }
 
@@ -66,7 +72,7 @@ mod tests {
             .unwrap();
         let key = convert_h256_to_bytes(parent_hash);
         let value = vec![0xc0, 0xff, 0xee];
-        db.put(key, value, None)
+        db.put(key, value, DataSensitivity::Public)
             .unwrap();
         let result = check_db_for_parent_of_block_in_state(&db, &parent_hash);
         assert!(result);
@@ -0,0 +1,200 @@
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    utils::convert_u256_to_u64,
+    eth::{
+        eth_state::EthState,
+        eth_types::{
+            EthReceiptBlock,
+            EthPendingTxStatus,
+        },
+        eth_database_utils::{
+            put_eth_pending_tx_in_db,
+            get_eth_pending_txs_from_db,
+            get_special_eth_block_header_from_db,
+            remove_eth_pending_tx_from_queue_in_db,
+            maybe_get_nth_ancestor_eth_block_header,
+        },
+    },
+};
+
+// NOTE: The submitted block may yet be orphaned ∴ a receipt in it is only noted
+// & the tx confirmed once that block is in the canon chain.
+pub fn maybe_record_eth_pending_tx_receipts<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Recording ETH pending txs w/ receipts in submitted block...");
+    let block_and_receipts = state.get_eth_block_and_receipts()?;
+    let receipt_block = EthReceiptBlock {
+        number: convert_u256_to_u64(&block_and_receipts.block.number)?,
+        hash: format!("0x{}", hex::encode(block_and_receipts.block.hash)),
+    };
+    get_eth_pending_txs_from_db(&state.db)?
+        .into_iter()
+        .filter(|pending_tx|
+            pending_tx.has_receipt_in(&block_and_receipts.receipts)
+        )
+        .map(|mut pending_tx| {
+            info!(
+                "✔ Receipt for ETH tx w/ nonce {} in block {}!",
+                pending_tx.nonce,
+                receipt_block.hash,
+            );
+            pending_tx.receipt_block = Some(receipt_block.clone());
+            put_eth_pending_tx_in_db(&state.db, &pending_tx)
+        })
+        .collect::<Result<()>>()
+        .and_then(|_| Ok(state))
+}
+
+pub fn maybe_clear_confirmed_eth_pending_txs<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Clearing ETH pending txs w/ receipts in canon chain...");
+    let canon_block_header = get_special_eth_block_header_from_db(
+        &state.db,
+        "canon",
+    )?;
+    let canon_block_number = convert_u256_to_u64(&canon_block_header.number)?;
+    get_eth_pending_txs_from_db(&state.db)?
+        .into_iter()
+        .filter_map(|pending_tx|
+            pending_tx
+                .receipt_block
+                .clone()
+                .filter(|receipt_block|
+                    receipt_block.number <= canon_block_number
+                )
+                .map(|receipt_block| (pending_tx, receipt_block))
+        )
+        .map(|(mut pending_tx, receipt_block)| {
+            let maybe_canon_block_hash =
+                maybe_get_nth_ancestor_eth_block_header(
+                    &state.db,
+                    &canon_block_header.hash,
+                    &(canon_block_number - receipt_block.number),
+                )
+                    .map(|header| format!("0x{}", hex::encode(header.hash)));
+            match maybe_canon_block_hash == Some(receipt_block.hash) {
+                true => {
+                    info!("✔ ETH tx w/ nonce {} confirmed!", pending_tx.nonce);
+                    pending_tx.status = EthPendingTxStatus::Confirmed;
+                    put_eth_pending_tx_in_db(&state.db, &pending_tx)
+                        .and_then(|_|
+                            remove_eth_pending_tx_from_queue_in_db(
+                                &state.db,
+                                pending_tx.nonce,
+                            )
+                        )
+                }
+                false => {
+                    info!(
+                        "✘ Receipt for ETH tx w/ nonce {} was orphaned!",
+                        pending_tx.nonce,
+                    );
+                    pending_tx.receipt_block = None;
+                    put_eth_pending_tx_in_db(&state.db, &pending_tx)
+                }
+            }
+        })
+        .collect::<Result<()>>()
+        .and_then(|_| Ok(state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        eth::{
+            eth_types::EthPendingTx,
+            eth_test_utils::get_sample_eth_block_and_receipts,
+            eth_database_utils::{
+                put_eth_canon_block_in_db,
+                get_eth_pending_tx_from_db,
+                get_eth_pending_tx_nonces_from_db,
+                add_eth_pending_tx_to_queue_in_db,
+            },
+        },
+    };
+
+    fn get_sample_pending_tx(nonce: u64, tx_hash: String) -> EthPendingTx {
+        EthPendingTx {
+            nonce,
+            tx_hash,
+            receipt_block: None,
+            correlation_ids: vec![],
+            replaced_tx_hashes: vec![],
+            tx_hex: "c0ffee".to_string(),
+            status: EthPendingTxStatus::Reported,
+        }
+    }
+
+    #[test]
+    fn should_record_and_clear_confirmed_eth_pending_txs() {
+        let block_and_receipts = get_sample_eth_block_and_receipts();
+        let mut replaced_tx = get_sample_pending_tx(
+            1,
+            format!("0x{}", hex::encode([1u8; 32])),
+        );
+        replaced_tx.replaced_tx_hashes = vec![format!(
+            "0x{}",
+            hex::encode(block_and_receipts.receipts[0].transaction_hash),
+        )];
+        let unconfirmed_tx = get_sample_pending_tx(
+            2,
+            format!("0x{}", hex::encode([0u8; 32])),
+        );
+        let state = EthState::init(get_test_database())
+            .add_eth_block_and_receipts(block_and_receipts.clone())
+            .unwrap();
+        add_eth_pending_tx_to_queue_in_db(&state.db, &replaced_tx).unwrap();
+        add_eth_pending_tx_to_queue_in_db(&state.db, &unconfirmed_tx).unwrap();
+        put_eth_canon_block_in_db(&state.db, &block_and_receipts).unwrap();
+        let state = maybe_record_eth_pending_tx_receipts(state).unwrap();
+        let recorded_tx = get_eth_pending_tx_from_db(&state.db, 1).unwrap();
+        assert_eq!(
+            recorded_tx.receipt_block.unwrap().hash,
+            format!("0x{}", hex::encode(block_and_receipts.block.hash)),
+        );
+        let result_state = maybe_clear_confirmed_eth_pending_txs(state)
+            .unwrap();
+        assert_eq!(
+            get_eth_pending_tx_nonces_from_db(&result_state.db).unwrap(),
+            vec![2],
+        );
+        assert_eq!(
+            get_eth_pending_tx_from_db(&result_state.db, 1).unwrap().status,
+            EthPendingTxStatus::Confirmed,
+        );
+    }
+
+    #[test]
+    fn should_forget_receipt_not_in_canon_chain() {
+        let block_and_receipts = get_sample_eth_block_and_receipts();
+        let mut orphaned_tx = get_sample_pending_tx(
+            1,
+            format!("0x{}", hex::encode([1u8; 32])),
+        );
+        orphaned_tx.receipt_block = Some(EthReceiptBlock {
+            number: block_and_receipts.block.number.as_u64(),
+            hash: format!("0x{}", hex::encode([2u8; 32])),
+        });
+        let state = EthState::init(get_test_database());
+        add_eth_pending_tx_to_queue_in_db(&state.db, &orphaned_tx).unwrap();
+        put_eth_canon_block_in_db(&state.db, &block_and_receipts).unwrap();
+        let result_state = maybe_clear_confirmed_eth_pending_txs(state)
+            .unwrap();
+        let result = get_eth_pending_tx_from_db(&result_state.db, 1).unwrap();
+        assert_eq!(
+            get_eth_pending_tx_nonces_from_db(&result_state.db).unwrap(),
+            vec![1],
+        );
+        assert_eq!(result.status, EthPendingTxStatus::Reported);
+        assert!(result.receipt_block.is_none());
+    }
+}
@@ -5,6 +5,7 @@ use bitcoin::{
 use crate::{
     types::Result,
     traits::DatabaseInterface,
+    safe_addresses::get_safe_btc_address_from_db,
     utxo_manager::utxo_database_utils::get_utxo_and_value,
     btc::{
         btc_transaction::create_signed_raw_btc_tx_for_n_input_n_outputs,
@@ -41,7 +42,7 @@ fn sum_redeem_params(
         .sum()
 }
 
-fn get_enough_utxos_to_cover_total<D>(
+pub fn get_enough_utxos_to_cover_total<D>(
     db: &D,
     required_btc_amount: u64,
     num_outputs: usize,
@@ -91,23 +92,24 @@ fn get_enough_utxos_to_cover_total<D>(
 
 fn get_address_and_amounts_from_redeem_params(
     redeem_params: &Vec<RedeemParams>,
-) -> BtcRecipientsAndAmounts {
+    safe_btc_address: &str,
+) -> Result<BtcRecipientsAndAmounts> {
     info!("✔ Getting BTC addresses & amounts from redeem params...");
     redeem_params
         .iter()
         .map(|params| {
-            let recipient_and_amount = BtcRecipientAndAmount::new(
+            let recipient_and_amount = BtcRecipientAndAmount::new_or_safe(
                 &params.recipient[..],
-                params.amount.as_u64()
-            );
+                params.amount.as_u64(),
+                safe_btc_address,
+            )?;
             info!(
                 "✔ Recipients & amount retrieved from redeem: {:?}",
                 recipient_and_amount
             );
-            recipient_and_amount
+            Ok(recipient_and_amount)
          })
-        .flatten()
-        .collect::<BtcRecipientsAndAmounts>()
+        .collect::<Result<BtcRecipientsAndAmounts>>()
 }
 
 fn create_btc_tx_from_redeem_params<D>(
@@ -115,7 +117,7 @@ fn create_btc_tx_from_redeem_params<D>(
     sats_per_byte: u64,
     btc_network: BtcNetwork,
     redeem_params: &Vec<RedeemParams>,
-) -> Result<BtcTransaction>
+) -> Result<(BtcTransaction, BtcUtxosAndValues)>
     where D: DatabaseInterface
 {
     info!("✔ Getting correct amount of UTXOs...");
@@ -132,11 +134,15 @@ fn create_btc_tx_from_redeem_params<D>(
     info!("✔ Creating BTC transaction...");
     create_signed_raw_btc_tx_for_n_input_n_outputs(
         sats_per_byte,
-        get_address_and_amounts_from_redeem_params(&redeem_params),
+        get_address_and_amounts_from_redeem_params(
+            &redeem_params,
+            &get_safe_btc_address_from_db(db)?,
+        )?,
         &get_btc_address_from_db(db)?[..],
         get_btc_private_key_from_db(db)?,
-        utxos_and_values,
+        utxos_and_values.clone(),
     )
+        .map(|signed_tx| (signed_tx, utxos_and_values))
 }
 
 pub fn maybe_create_btc_txs_and_add_to_state<D>(
@@ -145,6 +151,10 @@ pub fn maybe_create_btc_txs_and_add_to_state<D>(
     where D: DatabaseInterface
 {
     info!("✔ Maybe creating BTC transaction(s) from redeem params...");
+    if state.dry_run {
+        info!("✔ Dry run ∴ not creating BTC txs!");
+        return Ok(state)
+    };
     match &state.redeem_params.len() {
         0 => {
             info!("✔ No redeem params in state ∴ not creating BTC txs!");
@@ -158,12 +168,52 @@ pub fn maybe_create_btc_txs_and_add_to_state<D>(
                 get_btc_network_from_db(&state.db)?,
                 &state.redeem_params,
             )
-                .and_then(|signed_tx| {
+                .and_then(|(signed_tx, spent_utxos)| {
                     #[cfg(feature="debug")] {
-                        debug!("✔ Signed transaction: {:?}", signed_tx);
+                        sensitive_debug!("✔ Signed transaction: {:?}", signed_tx);
                     }
-                    state.add_btc_transactions(vec![signed_tx])
+                    state
+                        .add_btc_transactions(vec![signed_tx])
+                        .and_then(|state|
+                            state.add_spent_btc_utxos_and_values(spent_utxos)
+                        )
                 })
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+    use ethereum_types::U256;
+    use bitcoin::util::address::Address as BtcAddress;
+    use crate::eth::eth_types::{
+        EthHash,
+        EthAddress,
+    };
+
+    const SAMPLE_SAFE_BTC_ADDRESS: &str = "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM";
+
+    #[test]
+    fn should_default_invalid_recipients_to_given_safe_btc_address() {
+        let redeem_params = vec![
+            RedeemParams::new(
+                U256::from(1337),
+                EthAddress::zero(),
+                "not a BTC address".to_string(),
+                EthHash::zero(),
+            ),
+        ];
+        let result = get_address_and_amounts_from_redeem_params(
+            &redeem_params,
+            SAMPLE_SAFE_BTC_ADDRESS,
+        ).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].amount, 1337);
+        assert_eq!(
+            result[0].recipient,
+            BtcAddress::from_str(SAMPLE_SAFE_BTC_ADDRESS).unwrap(),
+        );
+    }
+}
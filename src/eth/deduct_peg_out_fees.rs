@@ -0,0 +1,220 @@
+use ethereum_types::U256;
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    utils::convert_u256_to_u64,
+    constants::MINIMUM_REQUIRED_SATOSHIS,
+    skipped_items::{
+        SkipReason,
+        SkippedItem,
+        SkippedItems,
+    },
+    fee_ledger::{
+        FeeType,
+        record_eth_fee_in_ledger,
+    },
+    btc::{
+        btc_constants::MAX_PEG_OUT_FEE_BASIS_POINTS,
+        btc_database_utils::{
+            get_btc_peg_out_fee_basis_points_from_db,
+            increment_btc_accrued_peg_out_fees_in_db,
+        },
+    },
+    eth::{
+        eth_state::EthState,
+        eth_types::RedeemParams,
+        eth_database_utils::get_eth_canon_block_from_db,
+    },
+};
+
+fn calculate_peg_out_fee_in_satoshis(amount: U256, basis_points: u64) -> u64 {
+    (
+        amount *
+        U256::from(basis_points) /
+        U256::from(MAX_PEG_OUT_FEE_BASIS_POINTS)
+    ).as_u64()
+}
+
+// NOTE: Only the redeemer's output shrinks ∴ the fee stays in the change UTXO.
+fn deduct_peg_out_fee_from_redeem_params(
+    redeem_params: &RedeemParams,
+    basis_points: u64,
+) -> RedeemParams {
+    let fee = calculate_peg_out_fee_in_satoshis(
+        redeem_params.amount,
+        basis_points,
+    );
+    info!(
+        "✔ Deducting peg-out fee of {} satoshis from {:?}",
+        fee,
+        redeem_params,
+    );
+    RedeemParams {
+        amount: redeem_params.amount - U256::from(fee),
+        peg_out_fee: redeem_params.peg_out_fee + fee,
+        ..redeem_params.clone()
+    }
+}
+
+pub fn deduct_peg_out_fees_from_redeem_params(
+    redeem_params: &[RedeemParams],
+    basis_points: u64,
+) -> Vec<RedeemParams> {
+    redeem_params
+        .iter()
+        .map(|params|
+            deduct_peg_out_fee_from_redeem_params(params, basis_points)
+        )
+        .collect()
+}
+
+// NOTE: A fee can take a redeem that passed the dust filter back below it.
+fn filter_dust_redeem_params_after_fees(
+    redeem_params: &[RedeemParams],
+) -> (Vec<RedeemParams>, SkippedItems) {
+    let mut skipped_items = vec![];
+    let redeem_params = redeem_params
+        .iter()
+        .filter(|params|
+            match params.amount >= U256::from(MINIMUM_REQUIRED_SATOSHIS) {
+                true => true,
+                false => {
+                    info!("✘ Filtering redeem params ∵ net amount too low!");
+                    skipped_items.push(
+                        SkippedItem::new(
+                            format!(
+                                "0x{}",
+                                hex::encode(params.originating_tx_hash),
+                            ),
+                            params.amount.to_string(),
+                            SkipReason::DustAmount,
+                        )
+                            .with_correlation_id(&params.correlation_id)
+                            .with_detail(format!(
+                                "Amount after peg-out fee of {} is below {}",
+                                params.peg_out_fee,
+                                MINIMUM_REQUIRED_SATOSHIS,
+                            ))
+                    );
+                    false
+                }
+            }
+        )
+        .cloned()
+        .collect();
+    (redeem_params, skipped_items)
+}
+
+pub fn maybe_deduct_peg_out_fees_from_redeem_params<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe deducting peg-out fees from redeem params...");
+    let basis_points = get_btc_peg_out_fee_basis_points_from_db(&state.db)?;
+    if basis_points == 0 || state.redeem_params.is_empty() {
+        info!("✔ No peg-out fees to deduct from redeem params!");
+        return Ok(state)
+    };
+    let (redeem_params, skipped_items) = filter_dust_redeem_params_after_fees(
+        &deduct_peg_out_fees_from_redeem_params(
+            &state.redeem_params,
+            basis_points,
+        )
+    );
+    state.replace_redeem_params(redeem_params)
+        .and_then(|state| state.add_skipped_items(skipped_items))
+}
+
+pub fn maybe_accrue_peg_out_fees_in_db<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    if !state.btc_transactions.is_filled() {
+        info!("✔ No payouts signed ∴ no peg-out fees to accrue!");
+        return Ok(state)
+    };
+    let total_fee = state.redeem_params
+        .iter()
+        .map(|params| params.peg_out_fee)
+        .sum::<u64>();
+    info!("✔ Total peg-out fees accrued: {} satoshis", total_fee);
+    increment_btc_accrued_peg_out_fees_in_db(&state.db, &total_fee)
+        .and_then(|_| get_eth_canon_block_from_db(&state.db))
+        .and_then(|canon_block|
+            record_eth_fee_in_ledger(
+                &state.db,
+                convert_u256_to_u64(&canon_block.block.number)?,
+                FeeType::PegOut,
+                total_fee,
+            )
+        )
+        .map(|_| state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        btc::btc_database_utils::put_btc_peg_out_fee_basis_points_in_db,
+        eth::{
+            eth_types::EthHash,
+            eth_test_utils::get_sample_eth_address,
+        },
+    };
+
+    fn get_sample_redeem_params() -> Vec<RedeemParams> {
+        vec![
+            RedeemParams::new(
+                U256::from(1_000_000),
+                get_sample_eth_address(),
+                "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM".to_string(),
+                EthHash::zero(),
+            )
+        ]
+    }
+
+    #[test]
+    fn should_calculate_peg_out_fee_in_satoshis() {
+        let amount = U256::from(1_000_000);
+        assert_eq!(calculate_peg_out_fee_in_satoshis(amount, 25), 2_500);
+        assert_eq!(calculate_peg_out_fee_in_satoshis(amount, 0), 0);
+    }
+
+    #[test]
+    fn should_deduct_peg_out_fees_from_redeem_params() {
+        let db = get_test_database();
+        put_btc_peg_out_fee_basis_points_in_db(&db, &25).unwrap();
+        let state = EthState::init(db)
+            .add_redeem_params(get_sample_redeem_params())
+            .and_then(maybe_deduct_peg_out_fees_from_redeem_params)
+            .unwrap();
+        let result = &state.redeem_params[0];
+        assert_eq!(result.amount, U256::from(997_500));
+        assert_eq!(result.peg_out_fee, 2_500);
+        assert_eq!(result.get_gross_amount(), 1_000_000);
+    }
+
+    #[test]
+    fn should_filter_redeem_params_below_minimum_after_fee() {
+        let db = get_test_database();
+        put_btc_peg_out_fee_basis_points_in_db(&db, &25).unwrap();
+        let redeem_params = vec![
+            RedeemParams {
+                amount: U256::from(MINIMUM_REQUIRED_SATOSHIS + 1),
+                ..get_sample_redeem_params()[0].clone()
+            },
+            get_sample_redeem_params()[0].clone(),
+        ];
+        let state = EthState::init(db)
+            .add_redeem_params(redeem_params)
+            .and_then(maybe_deduct_peg_out_fees_from_redeem_params)
+            .unwrap();
+        assert_eq!(state.redeem_params.len(), 1);
+        assert_eq!(state.redeem_params[0].amount, U256::from(997_500));
+        assert_eq!(state.skipped_items.len(), 1);
+        assert_eq!(state.skipped_items[0].reason, SkipReason::DustAmount);
+    }
+}
@@ -0,0 +1,194 @@
+use ethereum_types::{
+    H256,
+    U256,
+    Address as EthAddress,
+};
+use crate::{
+    errors::AppError,
+    types::{
+        Bytes,
+        Result,
+    },
+    eth::eth_constants::ETH_WORD_SIZE_IN_BYTES,
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EthAbiToken {
+    Uint(U256),
+    Bytes(Bytes),
+    FixedBytes(H256),
+    Address(EthAddress),
+    Array(Vec<EthAbiToken>),
+}
+
+impl EthAbiToken {
+    fn is_dynamic(&self) -> bool {
+        match self {
+            EthAbiToken::Bytes(_) | EthAbiToken::Array(_) => true,
+            _ => false,
+        }
+    }
+
+    fn encode_static(&self) -> Result<Bytes> {
+        match self {
+            EthAbiToken::Uint(u256) => Ok(encode_u256_as_word(u256)),
+            EthAbiToken::FixedBytes(hash) => Ok(hash.as_bytes().to_vec()),
+            EthAbiToken::Address(address) => {
+                let mut word = vec![0u8; ETH_WORD_SIZE_IN_BYTES - 20];
+                word.extend_from_slice(address.as_bytes());
+                Ok(word)
+            }
+            _ => Err(AppError::InvalidTransaction(
+                "✘ Cannot ABI encode dynamic token as static!".to_string()
+            ))
+        }
+    }
+
+    fn encode_dynamic(&self) -> Result<Bytes> {
+        match self {
+            EthAbiToken::Bytes(bytes) => {
+                let mut encoded = encode_u256_as_word(&bytes.len().into());
+                encoded.extend_from_slice(bytes);
+                encoded.extend_from_slice(
+                    &vec![0u8; get_padding_length(bytes.len())]
+                );
+                Ok(encoded)
+            }
+            EthAbiToken::Array(tokens) => {
+                if tokens.iter().any(|token| token.is_dynamic()) {
+                    return Err(AppError::InvalidTransaction(
+                        "✘ ABI encoding arrays of dynamic tokens unsupported!"
+                            .to_string()
+                    ))
+                };
+                tokens
+                    .iter()
+                    .map(|token| token.encode_static())
+                    .collect::<Result<Vec<Bytes>>>()
+                    .map(|words| {
+                        let mut encoded =
+                            encode_u256_as_word(&tokens.len().into());
+                        encoded.extend(words.concat());
+                        encoded
+                    })
+            }
+            _ => Err(AppError::InvalidTransaction(
+                "✘ Cannot ABI encode static token as dynamic!".to_string()
+            ))
+        }
+    }
+}
+
+fn encode_u256_as_word(u256: &U256) -> Bytes {
+    let mut word = [0u8; ETH_WORD_SIZE_IN_BYTES];
+    u256.to_big_endian(&mut word);
+    word.to_vec()
+}
+
+fn get_padding_length(num_bytes: usize) -> usize {
+    match num_bytes % ETH_WORD_SIZE_IN_BYTES {
+        0 => 0,
+        remainder => ETH_WORD_SIZE_IN_BYTES - remainder,
+    }
+}
+
+pub fn encode_abi_tokens(tokens: &[EthAbiToken]) -> Result<Bytes> {
+    let mut head: Bytes = Vec::new();
+    let mut tail: Bytes = Vec::new();
+    let head_size_in_bytes = tokens.len() * ETH_WORD_SIZE_IN_BYTES;
+    for token in tokens {
+        match token.is_dynamic() {
+            false => head.extend(token.encode_static()?),
+            true => {
+                let offset = head_size_in_bytes + tail.len();
+                head.extend(encode_u256_as_word(&offset.into()));
+                tail.extend(token.encode_dynamic()?);
+            }
+        }
+    };
+    head.extend(tail);
+    Ok(head)
+}
+
+pub fn encode_fxn_call(fxn_sig: &str, tokens: &[EthAbiToken]) -> Result<Bytes> {
+    let mut encoded = hex::decode(fxn_sig)?;
+    if encoded.len() != 4 {
+        return Err(AppError::InvalidTransaction(
+            format!("✘ Function signature '{}' must be 4 bytes!", fxn_sig)
+        ))
+    };
+    encoded.extend(encode_abi_tokens(tokens)?);
+    Ok(encoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::eth_test_utils::get_sample_eth_address;
+
+    #[test]
+    fn should_encode_static_abi_tokens() {
+        let tokens = vec![
+            EthAbiToken::Address(get_sample_eth_address()),
+            EthAbiToken::Uint(U256::from(1)),
+            EthAbiToken::FixedBytes(H256::zero()),
+        ];
+        let expected_result = format!(
+            "{}{}{}",
+            "0000000000000000000000001739624f5cd969885a224da84418d12b8570d61a",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+        );
+        let result = encode_abi_tokens(&tokens).unwrap();
+        assert_eq!(hex::encode(result), expected_result);
+    }
+
+    #[test]
+    fn should_encode_dynamic_bytes_abi_token() {
+        let tokens = vec![
+            EthAbiToken::Uint(U256::from(1)),
+            EthAbiToken::Bytes(vec![0xde, 0xca, 0xf0]),
+        ];
+        let expected_result = format!(
+            "{}{}{}{}",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000040",
+            "0000000000000000000000000000000000000000000000000000000000000003",
+            "decaf00000000000000000000000000000000000000000000000000000000000",
+        );
+        let result = encode_abi_tokens(&tokens).unwrap();
+        assert_eq!(hex::encode(result), expected_result);
+    }
+
+    #[test]
+    fn should_encode_array_abi_token() {
+        let tokens = vec![
+            EthAbiToken::Array(vec![
+                EthAbiToken::Uint(U256::from(1)),
+                EthAbiToken::Uint(U256::from(2)),
+            ]),
+        ];
+        let expected_result = format!(
+            "{}{}{}{}",
+            "0000000000000000000000000000000000000000000000000000000000000020",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+        );
+        let result = encode_abi_tokens(&tokens).unwrap();
+        assert_eq!(hex::encode(result), expected_result);
+    }
+
+    #[test]
+    fn should_fail_to_encode_array_of_dynamic_abi_tokens() {
+        let tokens = vec![
+            EthAbiToken::Array(vec![EthAbiToken::Bytes(vec![])]),
+        ];
+        assert!(encode_abi_tokens(&tokens).is_err());
+    }
+
+    #[test]
+    fn should_fail_to_encode_fxn_call_with_invalid_signature() {
+        assert!(encode_fxn_call("40c10f", &[]).is_err());
+    }
+}
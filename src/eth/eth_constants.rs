@@ -6,21 +6,39 @@ use crate::{
 
 pub const ZERO_BYTE: u8 = 0u8;
 pub const ETH_TAIL_LENGTH: u64 = 100;
+pub const ETH_TAIL_PRUNING_DISTANCE: u64 = 0;
 pub const HIGH_NIBBLE_MASK: Byte = 15u8; // NOTE: 15u8 == [0,0,0,0,1,1,1,1]
 pub const NUM_BITS_IN_NIBBLE: usize = 4;
 pub const NUM_NIBBLES_IN_BYTE: usize = 2;
+pub const VALUE_FOR_CANCEL_TX: usize = 0;
 pub const VALUE_FOR_MINTING_TX: usize = 0;
 pub const VALUE_FOR_PTOKEN_DEPLOY: usize = 0;
 pub const ETH_WORD_SIZE_IN_BYTES: usize = 32;
+pub const LOGS_BLOOM_LENGTH: usize = 256;
+// NOTE: Block hash + parent hash + block number, each one ETH word wide!
+pub const ETH_BLOCK_HEADER_IN_DB_FORMAT_LENGTH: usize = 96;
+pub const MIN_BTC_ADDRESS_LENGTH: usize = 26;
+pub const MAX_BTC_ADDRESS_LENGTH: usize = 90;
+pub const GAS_LIMIT_FOR_CANCEL_TX: usize = 21_000;
 pub static LEAF_NODE_STRING: &'static str = "leaf";
 pub const GAS_LIMIT_FOR_MINTING_TX: usize = 120_000;
+pub const WEI_PER_ETH: u64 = 1_000_000_000_000_000_000;
 pub static BRANCH_NODE_STRING: &'static str = "branch";
 pub const LOG_DATA_BTC_ADDRESS_START_INDEX: usize = 96;
 pub const GAS_LIMIT_FOR_PTOKEN_DEPLOY: usize = 2_800_000;
 pub static EXTENSION_NODE_STRING: &'static str = "extension";
 pub const HASHED_NULL_NODE: EthHash = EthHash(HASHED_NULL_NODE_BYTES);
 pub static ETH_SMART_CONTRACT_MINTING_FXN_SIG: &'static str = "40c10f19";
+pub static ETH_SMART_CONTRACT_OPERATOR_MINTING_FXN_SIG: &'static str = "dcdc7dd0";
+pub static ETH_SMART_CONTRACT_MINTING_WITH_DATA_FXN_SIG: &'static str = "94d008ef";
+pub static ETH_SMART_CONTRACT_BATCH_MINTING_FXN_SIG: &'static str = "7c88e3d9";
 pub const EMPTY_NIBBLES: Nibbles = Nibbles { data: Vec::new(), offset: 0 };
+pub static ETH_RELAY_FORWARDER_VERSION: &'static str = "0.0.1";
+pub static ETH_RELAY_FORWARDER_NAME: &'static str = "MinimalForwarder";
+pub static EIP712_DOMAIN_TYPE_STRING: &'static str =
+    "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)";
+pub static ETH_RELAY_REQUEST_TYPE_STRING: &'static str =
+    "ForwardRequest(address from,address to,uint256 value,uint256 gas,uint256 nonce,bytes data)";
 pub static REDEEM_EVENT_TOPIC_HEX: &'static str =
     "78e6c3f67f57c26578f2487b930b70d844bcc8dd8f4d629fb4af81252ab5aa65";
 
@@ -177,3 +195,215 @@ pub static ETH_TAIL_BLOCK_HASH_KEY: [u8; 32] = [
   246, 203, 101, 53, 160, 36, 26, 55,
   34, 165, 18, 105, 14, 235, 167, 88
 ];
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-max-fee-per-gas').slice(2), 'hex')
+// )
+// c0c8fcbe630aef70dad584ba5544904cf20441448cf6fcd97d4aa23edee34ad2
+pub static ETH_MAX_FEE_PER_GAS_KEY: [u8; 32] = [
+  192, 200, 252, 190, 99, 10, 239, 112,
+  218, 213, 132, 186, 85, 68, 144, 76,
+  242, 4, 65, 68, 140, 246, 252, 217,
+  125, 74, 162, 62, 222, 227, 74, 210
+];
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-max-priority-fee-per-gas').slice(2), 'hex')
+// )
+// faa6fcffc872cf321ed0bb137f27deb0aa641db53a7e174107dc6bfb0cbfe2ca
+pub static ETH_MAX_PRIORITY_FEE_PER_GAS_KEY: [u8; 32] = [
+  250, 166, 252, 255, 200, 114, 207, 50,
+  30, 208, 187, 19, 127, 39, 222, 176,
+  170, 100, 29, 181, 58, 126, 23, 65,
+  7, 220, 107, 251, 12, 191, 226, 202
+];
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-tx-type').slice(2), 'hex')
+// )
+// d6ebc1e9c0bfb2702d176d5440f590de752638d8ba20c8675d41c67a5a7c9aeb
+pub static ETH_TX_TYPE_KEY: [u8; 32] = [
+  214, 235, 193, 233, 192, 191, 178, 112,
+  45, 23, 109, 84, 64, 245, 144, 222,
+  117, 38, 56, 216, 186, 32, 200, 103,
+  93, 65, 198, 122, 90, 124, 154, 235
+];
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-mint-gas-limit').slice(2), 'hex')
+// )
+// 01b6aeda9cfabcc18c5dc929570204bc87f442a5a3bea8341a6e159b93a51c85
+pub static ETH_MINT_GAS_LIMIT_KEY: [u8; 32] = [
+  1, 182, 174, 218, 156, 250, 188, 193,
+  140, 93, 201, 41, 87, 2, 4, 188,
+  135, 244, 66, 165, 163, 190, 168, 52,
+  26, 110, 21, 155, 147, 165, 28, 133
+];
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-cancel-gas-limit').slice(2), 'hex')
+// )
+// 215d0836059a9c46683b1093c52c8bee4e0079e920feabbcdfe57d8f29d9f812
+pub static ETH_CANCEL_GAS_LIMIT_KEY: [u8; 32] = [
+  33, 93, 8, 54, 5, 154, 156, 70,
+  104, 59, 16, 147, 197, 44, 139, 238,
+  78, 0, 121, 233, 32, 254, 171, 188,
+  223, 229, 125, 143, 41, 217, 248, 18
+];
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-relay-forwarder-address').slice(2), 'hex')
+// )
+// f0e5257243a2702d3de48fb0a90c52661b5a77d8fc7f63e59938c05990cf16de
+pub static ETH_RELAY_FORWARDER_ADDRESS_KEY: [u8; 32] = [
+  240, 229, 37, 114, 67, 162, 112, 45,
+  61, 228, 143, 176, 169, 12, 82, 102,
+  27, 90, 119, 216, 252, 127, 99, 229,
+  153, 56, 192, 89, 144, 207, 22, 222
+];
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-relay-nonce').slice(2), 'hex')
+// )
+// d744e9070e2715be5be6f1546b7e144025d954d8aab7cc67f0775093a89d2359
+pub static ETH_RELAY_NONCE_KEY: [u8; 32] = [
+  215, 68, 233, 7, 14, 39, 21, 190,
+  91, 230, 241, 84, 107, 126, 20, 64,
+  37, 217, 84, 216, 170, 183, 204, 103,
+  240, 119, 80, 147, 168, 157, 35, 89
+];
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-batch-mint-mode').slice(2), 'hex')
+// )
+// 5cb914921884da202e9e5476bf3984afc54939568ec91acc19d7860391ba9a4a
+pub static ETH_BATCH_MINT_MODE_KEY: [u8; 32] = [
+  92, 185, 20, 146, 24, 132, 218, 32,
+  46, 158, 84, 118, 191, 57, 132, 175,
+  197, 73, 57, 86, 142, 201, 26, 204,
+  25, 215, 134, 3, 145, 186, 154, 74
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-pending-tx-nonces').slice(2), 'hex')
+// )
+// 252dbe92b454a2e7fd24bd99d12f0b0956524eda1b99d0327d552eeb8608fd5d
+pub static ETH_PENDING_TX_NONCES_KEY: [u8; 32] = [
+  37, 45, 190, 146, 180, 84, 162, 231,
+  253, 36, 189, 153, 209, 47, 11, 9,
+  86, 82, 78, 218, 27, 153, 208, 50,
+  125, 85, 46, 235, 134, 8, 253, 93
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-pre-eip155-signing').slice(2), 'hex')
+// )
+// 295a80d7fce3f43fcd3841f4ccc462d5d51fe58946b5fe3eeecdd3cabfa47c25
+pub static ETH_PRE_EIP155_SIGNING_KEY: [u8; 32] = [
+  41, 90, 128, 215, 252, 227, 244, 63,
+  205, 56, 65, 244, 204, 196, 98, 213,
+  213, 31, 229, 137, 70, 181, 254, 62,
+  238, 205, 211, 202, 191, 164, 124, 37
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-ptoken-contract-topics').slice(2), 'hex')
+// )
+// ad5f8d67c25314be4340ba1e7d2ee582beb9ace44e9781f03f918d5091e22e45
+pub static ETH_PTOKEN_CONTRACT_TOPICS_KEY: [u8; 32] = [
+  173, 95, 141, 103, 194, 83, 20, 190,
+  67, 64, 186, 30, 125, 46, 229, 130,
+  190, 185, 172, 228, 78, 151, 129, 240,
+  63, 145, 141, 80, 145, 226, 46, 69
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-pos-mode').slice(2), 'hex')
+// )
+// 71128de7eb8cb42ececf887b952ff7a972f8ee19de9e5bd36de593c4b2acf179
+pub static ETH_POS_MODE_KEY: [u8; 32] = [
+  113, 18, 141, 231, 235, 140, 180, 46,
+  206, 207, 136, 123, 149, 47, 247, 169,
+  114, 248, 238, 25, 222, 158, 91, 211,
+  109, 229, 147, 196, 178, 172, 241, 121
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-tail-pruning-distance').slice(2), 'hex')
+// )
+// 0e094ff4949d7200a20878fbc657b1cef87b440e0eb28cb6907ecd7341d5db06
+pub static ETH_TAIL_PRUNING_DISTANCE_KEY: [u8; 32] = [
+  14, 9, 79, 244, 148, 157, 114, 0,
+  162, 8, 120, 251, 198, 87, 177, 206,
+  248, 123, 68, 14, 14, 178, 140, 182,
+  144, 126, 205, 115, 65, 213, 219, 6
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-tail-pruned-height').slice(2), 'hex')
+// )
+// 34281a02f772b49b9d7822137c002bf89867541bb9782458948f29b6b6e87731
+pub static ETH_TAIL_PRUNED_HEIGHT_KEY: [u8; 32] = [
+  52, 40, 26, 2, 247, 114, 180, 155,
+  157, 120, 34, 19, 124, 0, 43, 248,
+  152, 103, 84, 27, 185, 120, 36, 88,
+  148, 143, 41, 182, 182, 232, 119, 49
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-ptoken-contract-variant').slice(2), 'hex')
+// )
+// b7ba69e1b3ac74faf8016fbdd4023cb1e10583d436c334ea6ee853727357fe81
+pub static ETH_PTOKEN_CONTRACT_VARIANT_KEY: [u8; 32] = [
+  183, 186, 105, 225, 179, 172, 116, 250,
+  248, 1, 111, 189, 212, 2, 60, 177,
+  225, 5, 131, 212, 54, 195, 52, 234,
+  110, 232, 83, 114, 115, 87, 254, 129
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-mint-with-data-mode-key').slice(2), 'hex')
+// )
+// eafd1cd65155175ff2e639e5c52efb5140936c996fbc329aae423cc38f927152
+pub static ETH_MINT_WITH_DATA_MODE_KEY: [u8; 32] = [
+  234, 253, 28, 214, 81, 85, 23, 95,
+  242, 230, 57, 229, 197, 46, 251, 81,
+  64, 147, 108, 153, 111, 188, 50, 154,
+  174, 66, 60, 195, 143, 146, 113, 82
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-smart-contract-address-history-key').slice(2), 'hex')
+// )
+// cb1794a074707ca54903a863764cec4be20a6f565fcbbfb851de9be81fe72703
+pub static ETH_SMART_CONTRACT_ADDRESS_HISTORY_KEY: [u8; 32] = [
+  203, 23, 148, 160, 116, 112, 124, 165,
+  73, 3, 168, 99, 118, 76, 236, 75,
+  226, 10, 111, 86, 95, 203, 191, 184,
+  81, 222, 155, 232, 31, 231, 39, 3
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-host-token-decimals-key').slice(2), 'hex')
+// )
+// 1d264c4bb47fd7431624963ff093e41fd7e8b4fc6c7f759ed8bda2835029c864
+pub static ETH_HOST_TOKEN_DECIMALS_KEY: [u8; 32] = [
+  29, 38, 76, 75, 180, 127, 215, 67,
+  22, 36, 150, 63, 240, 147, 228, 31,
+  215, 232, 180, 252, 108, 127, 117, 158,
+  216, 189, 162, 131, 80, 41, 200, 100
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-ptoken-contract-bytecode-hash-key').slice(2), 'hex')
+// )
+// bcfa71d9bb79921a2956241f7580c594de3f52d311e193fc0d47499b4bd7d80d
+pub static ETH_PTOKEN_CONTRACT_BYTECODE_HASH_KEY: [u8; 32] = [
+  188, 250, 113, 217, 187, 121, 146, 26,
+  41, 86, 36, 31, 117, 128, 197, 148,
+  222, 63, 82, 211, 17, 225, 147, 252,
+  13, 71, 73, 155, 75, 215, 216, 13
+];
+
+// NOTE (javascript): new Uint8Array(
+//   Buffer.from(web3.utils.keccak256('eth-queued-redeem-params-key').slice(2), 'hex')
+// )
+// 785a75643582841fe63e7cd2e0c0345f9ef3aacee75507389be108351791e5bb
+pub static ETH_QUEUED_REDEEM_PARAMS_KEY: [u8; 32] = [
+  120, 90, 117, 100, 53, 130, 132, 31,
+  230, 62, 124, 210, 224, 192, 52, 95,
+  158, 243, 170, 206, 231, 85, 7, 56,
+  155, 225, 8, 53, 23, 145, 229, 187
+];
@@ -9,8 +9,8 @@ use secp256k1::{
     },
 };
 use crate::{
+    errors::AppError,
     traits::DatabaseInterface,
-    constants::PRIVATE_KEY_DATA_SENSITIVITY_LEVEL,
     eth::{
        eth_types::EthSignature,
        eth_crypto::eth_public_key::EthPublicKey,
@@ -18,6 +18,7 @@ use crate::{
     types::{
         Bytes,
         Result,
+        DataSensitivity,
     },
     crypto_utils::{
         keccak_hash_bytes,
@@ -78,7 +79,7 @@ impl EthPrivateKey {
         db.put(
             key.to_vec(),
             self.0[..].to_vec(),
-            PRIVATE_KEY_DATA_SENSITIVITY_LEVEL,
+            DataSensitivity::Private,
         )
     }
 }
@@ -89,12 +90,20 @@ impl fmt::Display for EthPrivateKey {
     }
 }
 
+// NOTE: A throwaway key, written over the real one when it's dropped if the
+// entropy source fails, since that must not panic mid-drop.
+const DUMMY_ETH_PRIVATE_KEY_BYTES: [u8; 32] = [1; 32];
+
 impl Drop for EthPrivateKey {
     fn drop(&mut self) {
         unsafe {
             ::std::ptr::write_volatile(
                 &mut self.0,
                 generate_random_private_key()
+                    .or_else(|_|
+                        SecretKey::from_slice(&DUMMY_ETH_PRIVATE_KEY_BYTES)
+                            .map_err(AppError::from)
+                    )
                     .expect("Failed to get ETH private key!"),
             )
         };
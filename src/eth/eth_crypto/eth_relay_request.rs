@@ -0,0 +1,236 @@
+use ethereum_types::{
+    H256,
+    U256,
+    Address as EthAddress,
+};
+use crate::{
+    types::{
+        Byte,
+        Bytes,
+        Result,
+    },
+    crypto_utils::keccak_hash_bytes,
+    eth::{
+        eth_crypto::{
+            eth_private_key::EthPrivateKey,
+            eth_transaction::encode_minting_tx_params_for_contract_variant,
+        },
+        eth_types::PTokenContractVariant,
+        eth_constants::{
+            VALUE_FOR_MINTING_TX,
+            ETH_RELAY_FORWARDER_NAME,
+            ETH_RELAY_FORWARDER_VERSION,
+            EIP712_DOMAIN_TYPE_STRING,
+            ETH_RELAY_REQUEST_TYPE_STRING,
+        },
+    },
+};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EthRelayRequest {
+    pub v: Byte,
+    pub r: U256,
+    pub s: U256,
+    pub gas: U256,
+    pub data: Bytes,
+    pub nonce: U256,
+    pub value: U256,
+    pub to: EthAddress,
+    pub chain_id: Byte,
+    pub from: EthAddress,
+    pub forwarder: EthAddress,
+}
+
+pub type EthRelayRequests = Vec<EthRelayRequest>;
+
+fn encode_u256(u_256: &U256) -> Bytes {
+    let mut bytes = [0u8; 32];
+    u_256.to_big_endian(&mut bytes);
+    bytes.to_vec()
+}
+
+fn encode_address(address: &EthAddress) -> Bytes {
+    let mut bytes = vec![0u8; 12];
+    bytes.extend_from_slice(address.as_bytes());
+    bytes
+}
+
+fn hash_str(string: &str) -> Bytes {
+    keccak_hash_bytes(string.as_bytes().to_vec()).as_bytes().to_vec()
+}
+
+impl EthRelayRequest {
+    pub fn new(
+        data: Bytes,
+        nonce: u64,
+        value: usize,
+        to: EthAddress,
+        from: EthAddress,
+        chain_id: Byte,
+        gas: usize,
+        forwarder: EthAddress,
+    ) -> EthRelayRequest {
+        EthRelayRequest {
+            to,
+            from,
+            data,
+            chain_id,
+            forwarder,
+            v: 0,
+            r: U256::zero(),
+            s: U256::zero(),
+            gas: gas.into(),
+            nonce: nonce.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn get_domain_separator(&self) -> H256 {
+        keccak_hash_bytes(
+            [
+                hash_str(EIP712_DOMAIN_TYPE_STRING),
+                hash_str(ETH_RELAY_FORWARDER_NAME),
+                hash_str(ETH_RELAY_FORWARDER_VERSION),
+                encode_u256(&self.chain_id.into()),
+                encode_address(&self.forwarder),
+            ].concat()
+        )
+    }
+
+    pub fn get_struct_hash(&self) -> H256 {
+        keccak_hash_bytes(
+            [
+                hash_str(ETH_RELAY_REQUEST_TYPE_STRING),
+                encode_address(&self.from),
+                encode_address(&self.to),
+                encode_u256(&self.value),
+                encode_u256(&self.gas),
+                encode_u256(&self.nonce),
+                keccak_hash_bytes(self.data.clone()).as_bytes().to_vec(),
+            ].concat()
+        )
+    }
+
+    fn get_signing_payload(&self) -> Bytes {
+        [
+            vec![0x19, 0x01], // NOTE: Per EIP712
+            self.get_domain_separator().as_bytes().to_vec(),
+            self.get_struct_hash().as_bytes().to_vec(),
+        ].concat()
+    }
+
+    #[cfg(test)]
+    pub fn get_signing_hash(&self) -> H256 {
+        keccak_hash_bytes(self.get_signing_payload())
+    }
+
+    pub fn sign(mut self, eth_private_key: EthPrivateKey) -> Result<Self> {
+        eth_private_key
+            .sign_message_bytes(self.get_signing_payload())
+            .map(|sig| {
+                self.r = sig[0..32].into();
+                self.s = sig[32..64].into();
+                self.v = sig[64] + 27;
+                self
+            })
+    }
+
+    pub fn get_signature_hex(&self) -> String {
+        let mut bytes = encode_u256(&self.r);
+        bytes.append(&mut encode_u256(&self.s));
+        bytes.push(self.v);
+        hex::encode(bytes)
+    }
+}
+
+pub fn get_signed_minting_relay_request(
+    amount: U256,
+    nonce: u64,
+    chain_id: u8,
+    to: EthAddress,
+    gas_limit: usize,
+    recipient: EthAddress,
+    forwarder: EthAddress,
+    eth_private_key: EthPrivateKey,
+    contract_variant: &PTokenContractVariant,
+) -> Result<EthRelayRequest> {
+    EthRelayRequest::new(
+        encode_minting_tx_params_for_contract_variant(
+            contract_variant,
+            recipient,
+            amount,
+        )?,
+        nonce,
+        VALUE_FOR_MINTING_TX,
+        to,
+        eth_private_key.to_public_key().to_address(),
+        chain_id,
+        gas_limit,
+        forwarder,
+    )
+        .sign(eth_private_key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eth::{
+        eth_constants::GAS_LIMIT_FOR_MINTING_TX,
+        eth_test_utils::{
+            get_sample_eth_address,
+            get_sample_eth_private_key,
+        },
+    };
+
+    fn get_sample_signed_relay_request() -> EthRelayRequest {
+        let to = EthAddress::from_slice(
+            &hex::decode("c63b099efB18c8db573981fB64564f1564af4f30").unwrap()
+        );
+        let forwarder = EthAddress::from_slice(
+            &hex::decode("9360a5C047e8Eb44647f17672638c3bB8e2B8a53").unwrap()
+        );
+        get_signed_minting_relay_request(
+            U256::from_dec_str("1").unwrap(),
+            0,
+            4, // NOTE: Rinkeby
+            to,
+            GAS_LIMIT_FOR_MINTING_TX,
+            get_sample_eth_address(),
+            forwarder,
+            get_sample_eth_private_key(),
+            &PTokenContractVariant::Erc777,
+        ).unwrap()
+    }
+
+    #[test]
+    fn should_get_relay_request_domain_separator() {
+        let expected_result =
+            "76d8ee5ad459da456dc6bfaed1c3a133afd0dd687bc3b2a4b06cf12d006f904a";
+        let result = get_sample_signed_relay_request().get_domain_separator();
+        assert_eq!(hex::encode(result.as_bytes()), expected_result);
+    }
+
+    #[test]
+    fn should_get_relay_request_struct_hash() {
+        let expected_result =
+            "8a53b8621ff88cb8f88d4c81269269aaef2f7952d285339da73ef55fb1bfbf63";
+        let result = get_sample_signed_relay_request().get_struct_hash();
+        assert_eq!(hex::encode(result.as_bytes()), expected_result);
+    }
+
+    #[test]
+    fn should_get_relay_request_signing_hash() {
+        let expected_result =
+            "5a71c578727bca18d22a364bf151c4c26cb60eb84096191dcb03ab14897e1526";
+        let result = get_sample_signed_relay_request().get_signing_hash();
+        assert_eq!(hex::encode(result.as_bytes()), expected_result);
+    }
+
+    #[test]
+    fn should_sign_minting_relay_request() {
+        let expected_result = "e7f83e8f5e5bba5eb7e1db1e0f1eb6bd94cfac9cf87261a8ec4788a0c57fc13e2d390f1dad1fdb627424dba0f658736b78d7cc75169b4f2f6da41ec9f8ee3f301c";
+        let result = get_sample_signed_relay_request();
+        assert_eq!(result.from, get_sample_eth_address());
+        assert_eq!(result.get_signature_hex(), expected_result);
+    }
+}
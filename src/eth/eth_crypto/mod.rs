@@ -1,3 +1,4 @@
 pub mod eth_public_key;
 pub mod eth_private_key;
 pub mod eth_transaction;
+pub mod eth_relay_request;
@@ -5,38 +5,86 @@ use ethereum_types::{
 use crate::{
     errors::AppError,
     traits::DatabaseInterface,
+    db_codec::get_db_serialization_format_from_db,
     types::{
         Bytes,
         Result,
         DataSensitivity,
     },
+    constants::PTOKEN_ERC777_NUM_DECIMALS,
+    token_decimals::check_host_token_decimals,
+    db_keys::{
+        DbKeyPrefix,
+        get_eth_pending_tx_db_key,
+        get_eth_block_header_db_key,
+        get_eth_redeem_record_db_key,
+        get_eth_mint_tx_record_db_key,
+        get_eth_pruned_block_index_db_key,
+    },
     database_utils::{
         put_u64_in_db,
         get_u64_from_db,
+        put_namespaced_key_in_db,
+        delete_namespaced_key_from_db,
     },
     utils::{
         convert_bytes_to_u64,
         convert_u64_to_bytes,
         convert_h256_to_bytes,
         convert_bytes_to_h256,
+        convert_bytes_to_eth_address,
     },
     eth::{
-        eth_state::EthState,
-        eth_types::EthBlockAndReceipts,
         eth_crypto::eth_private_key::EthPrivateKey,
+        eth_types::{
+            EthTopics,
+            RedeemParams,
+            EthPendingTx,
+            EthMintTxRecord,
+            EthRedeemRecord,
+            EthBlockAndReceipts,
+            EthBlockHeaderInDbFormat,
+            EthContractAddressUpgrade,
+            EthTransactionType,
+            PTokenContractVariant,
+        },
         eth_constants::{
+            ETH_TX_TYPE_KEY,
+            ETH_PTOKEN_CONTRACT_VARIANT_KEY,
+            ETH_PTOKEN_CONTRACT_BYTECODE_HASH_KEY,
             ETH_ADDRESS_KEY,
             ETH_CHAIN_ID_KEY,
+            ETH_POS_MODE_KEY,
             ETH_GAS_PRICE_KEY,
             ETH_LINKER_HASH_KEY,
             ETH_ACCOUNT_NONCE_KEY,
             ETH_PRIVATE_KEY_DB_KEY,
             ETH_TAIL_BLOCK_HASH_KEY,
+            ETH_TAIL_PRUNING_DISTANCE,
             ETH_CANON_BLOCK_HASH_KEY,
             ETH_LATEST_BLOCK_HASH_KEY,
             ETH_ANCHOR_BLOCK_HASH_KEY,
+            ETH_RELAY_NONCE_KEY,
+            ETH_MINT_GAS_LIMIT_KEY,
+            ETH_CANCEL_GAS_LIMIT_KEY,
+            ETH_BATCH_MINT_MODE_KEY,
+            ETH_MINT_WITH_DATA_MODE_KEY,
+            ETH_MAX_FEE_PER_GAS_KEY,
+            ETH_PENDING_TX_NONCES_KEY,
+            ETH_PRE_EIP155_SIGNING_KEY,
+            GAS_LIMIT_FOR_CANCEL_TX,
+            GAS_LIMIT_FOR_MINTING_TX,
             ETH_CANON_TO_TIP_LENGTH_KEY,
+            PTOKEN_CONTRACT_TOPICS,
             ETH_SMART_CONTRACT_ADDRESS_KEY,
+            ETH_SMART_CONTRACT_ADDRESS_HISTORY_KEY,
+            ETH_QUEUED_REDEEM_PARAMS_KEY,
+            ETH_PTOKEN_CONTRACT_TOPICS_KEY,
+            ETH_TAIL_PRUNED_HEIGHT_KEY,
+            ETH_TAIL_PRUNING_DISTANCE_KEY,
+            ETH_HOST_TOKEN_DECIMALS_KEY,
+            ETH_RELAY_FORWARDER_ADDRESS_KEY,
+            ETH_MAX_PRIORITY_FEE_PER_GAS_KEY,
         },
         eth_json_codec::{
             encode_eth_block_and_receipts_as_json_bytes,
@@ -45,34 +93,6 @@ use crate::{
     },
 };
 
-pub fn start_eth_db_transaction<D>(
-    state: EthState<D>,
-) -> Result<EthState<D>>
-    where D: DatabaseInterface
-{
-    state
-        .db
-        .start_transaction()
-        .map(|_| {
-            info!("✔ Database transaction begun for ETH block submission!");
-            state
-        })
-}
-
-pub fn end_eth_db_transaction<D>(
-    state: EthState<D>,
-) -> Result<EthState<D>>
-    where D: DatabaseInterface
-{
-    state
-        .db
-        .end_transaction()
-        .map(|_| {
-            info!("✔ Database transaction ended for ETH block submission!");
-            state
-        })
-}
-
 pub fn put_eth_canon_to_tip_length_in_db<D>(
     db: &D,
     length: &u64,
@@ -83,7 +103,7 @@ pub fn put_eth_canon_to_tip_length_in_db<D>(
     db.put(
         ETH_CANON_TO_TIP_LENGTH_KEY.to_vec(),
         convert_u64_to_bytes(length),
-        None,
+        DataSensitivity::Public,
     )
 }
 
@@ -91,10 +111,11 @@ pub fn get_eth_canon_to_tip_length_from_db<D>(db: &D) -> Result<u64>
     where D: DatabaseInterface
 {
     info!("✔ Getting ETH canon-to-tip length from db...");
-    db.get(ETH_CANON_TO_TIP_LENGTH_KEY.to_vec(), None)
+    db.get(ETH_CANON_TO_TIP_LENGTH_KEY.to_vec(), DataSensitivity::Public)
         .and_then(|bytes| convert_bytes_to_u64(&bytes))
 }
 
+#[cfg(test)]
 pub fn put_eth_latest_block_in_db<D>(
     db: &D,
     eth_block_and_receipts: &EthBlockAndReceipts,
@@ -105,6 +126,7 @@ pub fn put_eth_latest_block_in_db<D>(
     put_special_eth_block_in_db(db, eth_block_and_receipts, "latest")
 }
 
+#[cfg(test)]
 pub fn put_eth_anchor_block_in_db<D>(
     db: &D,
     eth_block_and_receipts: &EthBlockAndReceipts,
@@ -125,6 +147,7 @@ pub fn put_eth_canon_block_in_db<D>(
     put_special_eth_block_in_db(db, eth_block_and_receipts, "canon")
 }
 
+#[cfg(test)]
 pub fn put_eth_tail_block_in_db<D>(
     db: &D,
     eth_block_and_receipts: &EthBlockAndReceipts,
@@ -216,7 +239,7 @@ pub fn put_special_eth_hash_in_db<D>(
         "tail" => Ok(ETH_TAIL_BLOCK_HASH_KEY.to_vec()),
         "anchor" => Ok(ETH_ANCHOR_BLOCK_HASH_KEY.to_vec()),
         "latest" => Ok(ETH_LATEST_BLOCK_HASH_KEY.to_vec()),
-        _ => Err(AppError::Custom(
+        _ => Err(AppError::InvalidInput(
             format!("✘ Cannot store special ETH hash of type: {}!", hash_type)
         ))
     }?;
@@ -227,8 +250,8 @@ pub fn get_latest_eth_block_number<D>(db: &D) -> Result<usize>
     where D: DatabaseInterface
 {
     info!("✔ Getting latest ETH block number from db...");
-    match get_special_eth_block_from_db(db, "latest") {
-        Ok(result) => Ok(result.block.number.as_usize()),
+    match get_special_eth_block_header_from_db(db, "latest") {
+        Ok(result) => Ok(result.number.as_usize()),
         Err(e) => Err(e)
     }
 }
@@ -247,6 +270,7 @@ pub fn get_eth_latest_block_from_db<D>(db: &D) -> Result<EthBlockAndReceipts>
     get_special_eth_block_from_db(db, "latest")
 }
 
+#[allow(dead_code)]
 pub fn get_eth_anchor_block_from_db<D>(db: &D) -> Result<EthBlockAndReceipts>
     where D: DatabaseInterface
 {
@@ -291,6 +315,7 @@ pub fn get_eth_canon_block_hash_from_db<D>(db: &D) -> Result<EthHash>
     get_special_eth_hash_from_db(db, "canon")
 }
 
+#[cfg(test)]
 pub fn get_eth_linker_hash_from_db<D>(db: &D) -> Result<EthHash>
     where D: DatabaseInterface
 {
@@ -310,7 +335,7 @@ pub fn get_special_eth_hash_from_db<D>(
         "tail" => Ok(ETH_TAIL_BLOCK_HASH_KEY),
         "anchor" => Ok(ETH_ANCHOR_BLOCK_HASH_KEY),
         "latest" => Ok(ETH_LATEST_BLOCK_HASH_KEY),
-        _ => Err(AppError::Custom(
+        _ => Err(AppError::InvalidInput(
             format!("✘ Cannot get ETH special hash of type: {}!", hash_type)
         ))
     }?;
@@ -325,8 +350,8 @@ pub fn get_eth_hash_from_db<D>(db: &D, key: &Bytes) -> Result<EthHash>
         "✔ Getting ETH hash from db under key: {}",
         hex::encode(&key)
     );
-    db.get(key.to_vec(), None)
-        .and_then(|bytes| Ok(EthHash::from_slice(&bytes)))
+    db.get(key.to_vec(), DataSensitivity::Public)
+        .and_then(|bytes| convert_bytes_to_h256(&bytes))
 }
 
 pub fn get_special_eth_block_from_db<D>(
@@ -339,6 +364,16 @@ pub fn get_special_eth_block_from_db<D>(
         .and_then(|block_hash| get_eth_block_from_db(db, &block_hash))
 }
 
+pub fn get_special_eth_block_header_from_db<D>(
+    db: &D,
+    block_type: &str,
+) -> Result<EthBlockHeaderInDbFormat>
+    where D: DatabaseInterface
+{
+    get_special_eth_hash_from_db(db, block_type)
+        .and_then(|block_hash| get_eth_block_header_from_db(db, &block_hash))
+}
+
 pub fn put_eth_hash_in_db<D>(
     db: &D,
     key: &Bytes,
@@ -346,7 +381,11 @@ pub fn put_eth_hash_in_db<D>(
 ) -> Result<()>
     where D: DatabaseInterface
 {
-    db.put(key.to_vec(), convert_h256_to_bytes(*eth_hash), None)
+    db.put(
+        key.to_vec(),
+        convert_h256_to_bytes(*eth_hash),
+        DataSensitivity::Public,
+    )
 }
 
 pub fn eth_block_exists_in_db<D>(db: &D, block_hash: &EthHash) -> bool
@@ -356,7 +395,11 @@ pub fn eth_block_exists_in_db<D>(db: &D, block_hash: &EthHash) -> bool
         "✔ Checking for existence of ETH block: {}",
        hex::encode(block_hash.as_bytes().to_vec())
    );
-    key_exists_in_db(db, &block_hash.as_bytes().to_vec(), None)
+    key_exists_in_db(
+        db,
+        &block_hash.as_bytes().to_vec(),
+        DataSensitivity::Public,
+    )
 }
 
 pub fn get_hash_from_db_via_hash_key<D>(
@@ -365,7 +408,7 @@ pub fn get_hash_from_db_via_hash_key<D>(
 ) -> Result<Option<EthHash>>
     where D: DatabaseInterface
 {
-    match db.get(convert_h256_to_bytes(hash_key), None) {
+    match db.get(convert_h256_to_bytes(hash_key), DataSensitivity::Public) {
         Ok(bytes) => Ok(Some(convert_bytes_to_h256(&bytes)?)),
         Err(_) => Ok(None),
     }
@@ -380,12 +423,111 @@ pub fn put_eth_block_and_receipts_in_db<D>(
     let key = convert_h256_to_bytes(eth_block_and_receipts.block.hash.clone());
     trace!("✔ Adding block to database under key: {:?}", hex::encode(&key));
     db.put(
-        key,
-        encode_eth_block_and_receipts_as_json_bytes(eth_block_and_receipts)?,
-        None,
+        key.clone(),
+        encode_eth_block_and_receipts_as_json_bytes(
+            eth_block_and_receipts,
+            get_db_serialization_format_from_db(db)?,
+        )?,
+        DataSensitivity::Public,
     )
+        .and_then(|_|
+            put_eth_block_header_in_db(
+                db,
+                &EthBlockHeaderInDbFormat::from_eth_block(
+                    &eth_block_and_receipts.block
+                ),
+            )
+        )
+        .and_then(|_|
+            put_namespaced_key_in_db(db, &DbKeyPrefix::EthBlock, &key)
+        )
 }
 
+fn put_eth_block_header_in_db<D>(
+    db: &D,
+    eth_block_header_in_db_format: &EthBlockHeaderInDbFormat,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH block header in db...");
+    db.put(
+        get_eth_block_header_db_key(&eth_block_header_in_db_format.hash),
+        eth_block_header_in_db_format.to_bytes(),
+        DataSensitivity::Public,
+    )
+}
+
+pub fn get_eth_block_header_from_db<D>(
+    db: &D,
+    block_hash: &EthHash,
+) -> Result<EthBlockHeaderInDbFormat>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH block header from db...");
+    match db.get(
+        get_eth_block_header_db_key(block_hash),
+        DataSensitivity::Public,
+    ) {
+        Ok(bytes) => EthBlockHeaderInDbFormat::from_bytes(&bytes),
+        Err(_) => {
+            trace!("✔ No ETH block header stored ∴ getting full block...");
+            get_eth_block_from_db(db, block_hash)
+                .map(|block_and_receipts|
+                    EthBlockHeaderInDbFormat::from_eth_block(
+                        &block_and_receipts.block
+                    )
+                )
+        }
+    }
+}
+
+pub fn delete_eth_block_from_db<D>(db: &D, block_hash: &EthHash) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Deleting ETH block from db under hash: {}", block_hash);
+    db.delete(convert_h256_to_bytes(*block_hash))
+        .and_then(|_| db.delete(get_eth_block_header_db_key(block_hash)))
+        .and_then(|_|
+            delete_namespaced_key_from_db(
+                db,
+                &DbKeyPrefix::EthBlock,
+                block_hash.as_bytes(),
+            )
+        )
+}
+
+pub fn maybe_get_parent_eth_block_header<D>(
+    db: &D,
+    block_hash: &EthHash,
+) -> Option<EthBlockHeaderInDbFormat>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe getting parent ETH block header from db...");
+    maybe_get_nth_ancestor_eth_block_header(db, block_hash, &1)
+}
+
+pub fn maybe_get_nth_ancestor_eth_block_header<D>(
+    db: &D,
+    block_hash: &EthHash,
+    n: &u64,
+) -> Option<EthBlockHeaderInDbFormat>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting {}th ancester ETH block header from db...", n);
+    match get_eth_block_header_from_db(db, block_hash) {
+        Err(_) => None,
+        Ok(block_header) => match n {
+            0 => Some(block_header),
+            _ => maybe_get_nth_ancestor_eth_block_header(
+                db,
+                &block_header.parent_hash,
+                &(n - 1),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
 pub fn maybe_get_parent_eth_block_and_receipts<D>(
     db: &D,
     block_hash: &EthHash,
@@ -427,7 +569,7 @@ pub fn maybe_get_eth_block_and_receipts_from_db<D>(
         "✔ Maybe getting ETH block and receipts from db under hash: {}",
         block_hash,
     );
-    match db.get(convert_h256_to_bytes(*block_hash), None) {
+    match db.get(convert_h256_to_bytes(*block_hash), DataSensitivity::Public) {
         Err(_) => None,
         Ok(bytes) => {
             match decode_eth_block_and_receipts_from_json_bytes(bytes) {
@@ -451,88 +593,768 @@ pub fn get_eth_block_from_db<D>(
     where D: DatabaseInterface
 {
     trace!("✔ Getting ETH block and receipts from db...");
-    db.get(convert_h256_to_bytes(*block_hash), None)
+    db.get(convert_h256_to_bytes(*block_hash), DataSensitivity::Public)
         .and_then(|bytes| decode_eth_block_and_receipts_from_json_bytes(bytes))
 }
 
-pub fn key_exists_in_db<D>(
-    db: &D,
-    key: &Bytes,
-    sensitivity: DataSensitivity
-) -> bool
+pub fn key_exists_in_db<D>(
+    db: &D,
+    key: &Bytes,
+    sensitivity: DataSensitivity
+) -> bool
+    where D: DatabaseInterface
+{
+    trace!("✔ Checking for existence of key: {}", hex::encode(key));
+    match db.get(key.to_vec(), sensitivity) {
+        Ok(_) => true,
+        Err(_) => false,
+    }
+}
+
+pub fn put_eth_gas_price_in_db<D>(
+    db: &D,
+    gas_price: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH gas price of {} in db...", gas_price);
+    db.put(
+        ETH_GAS_PRICE_KEY.to_vec(),
+        gas_price.to_le_bytes().to_vec(),
+        DataSensitivity::Public,
+    )
+}
+
+pub fn get_eth_gas_price_from_db<D>(db: &D) -> Result<u64>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH gas price from db...");
+    db.get(ETH_GAS_PRICE_KEY.to_vec(), DataSensitivity::Public)
+        .and_then(|bytes|
+            match bytes.len() <= 8 {
+                true => {
+                    let mut array = [0; 8];
+                    let bytes = &bytes[..array.len()];
+                    array.copy_from_slice(bytes);
+                    Ok(u64::from_le_bytes(array))
+                },
+                false => Err(AppError::ConversionError(
+                    "✘ Too many bytes to convert to u64!".to_string()
+                ))
+            }
+        )
+}
+
+pub fn put_eth_mint_gas_limit_in_db<D>(
+    db: &D,
+    gas_limit: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH mint gas limit of {} in db...", gas_limit);
+    put_u64_in_db(db, &ETH_MINT_GAS_LIMIT_KEY.to_vec(), gas_limit)
+}
+
+pub fn get_eth_mint_gas_limit_from_db<D>(db: &D) -> Result<u64>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH mint gas limit from db...");
+    match get_u64_from_db(db, &ETH_MINT_GAS_LIMIT_KEY.to_vec()) {
+        Ok(gas_limit) => Ok(gas_limit),
+        Err(_) => {
+            trace!("✔ No ETH mint gas limit in db ∴ using default!");
+            Ok(GAS_LIMIT_FOR_MINTING_TX as u64)
+        }
+    }
+}
+
+pub fn put_eth_cancel_gas_limit_in_db<D>(
+    db: &D,
+    gas_limit: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH cancel gas limit of {} in db...", gas_limit);
+    put_u64_in_db(db, &ETH_CANCEL_GAS_LIMIT_KEY.to_vec(), gas_limit)
+}
+
+pub fn get_eth_cancel_gas_limit_from_db<D>(db: &D) -> Result<u64>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH cancel gas limit from db...");
+    match get_u64_from_db(db, &ETH_CANCEL_GAS_LIMIT_KEY.to_vec()) {
+        Ok(gas_limit) => Ok(gas_limit),
+        Err(_) => {
+            trace!("✔ No ETH cancel gas limit in db ∴ using default!");
+            Ok(GAS_LIMIT_FOR_CANCEL_TX as u64)
+        }
+    }
+}
+
+pub fn put_eth_host_token_decimals_in_db<D>(
+    db: &D,
+    decimals: &u32,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH host token decimals of {} in db...", decimals);
+    check_host_token_decimals(*decimals)
+        .and_then(|_|
+            put_u64_in_db(
+                db,
+                &ETH_HOST_TOKEN_DECIMALS_KEY.to_vec(),
+                &(*decimals as u64),
+            )
+        )
+}
+
+pub fn get_eth_host_token_decimals_from_db<D>(db: &D) -> Result<u32>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH host token decimals from db...");
+    match get_u64_from_db(db, &ETH_HOST_TOKEN_DECIMALS_KEY.to_vec()) {
+        Ok(decimals) => Ok(decimals as u32),
+        Err(_) => {
+            trace!("✔ No ETH host token decimals in db ∴ using default!");
+            Ok(PTOKEN_ERC777_NUM_DECIMALS)
+        }
+    }
+}
+
+pub fn put_eth_tail_pruning_distance_in_db<D>(
+    db: &D,
+    distance: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH tail pruning distance of {} in db...", distance);
+    // NOTE: A new distance invalidates the incremental pruning window, so the
+    // next submission falls back to one full walk behind the tail.
+    put_u64_in_db(db, &ETH_TAIL_PRUNING_DISTANCE_KEY.to_vec(), distance)
+        .and_then(|_| db.delete(ETH_TAIL_PRUNED_HEIGHT_KEY.to_vec()))
+}
+
+pub fn get_eth_tail_pruning_distance_from_db<D>(db: &D) -> Result<u64>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH tail pruning distance from db...");
+    match get_u64_from_db(db, &ETH_TAIL_PRUNING_DISTANCE_KEY.to_vec()) {
+        Ok(distance) => Ok(distance),
+        Err(_) => {
+            trace!("✔ No ETH tail pruning distance in db ∴ using default!");
+            Ok(ETH_TAIL_PRUNING_DISTANCE)
+        }
+    }
+}
+
+pub fn put_eth_tail_pruned_height_in_db<D>(
+    db: &D,
+    block_number: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH tail pruned height of {} in db...", block_number);
+    put_u64_in_db(db, &ETH_TAIL_PRUNED_HEIGHT_KEY.to_vec(), block_number)
+}
+
+pub fn get_eth_tail_pruned_height_from_db<D>(db: &D) -> Result<u64>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH tail pruned height from db...");
+    get_u64_from_db(db, &ETH_TAIL_PRUNED_HEIGHT_KEY.to_vec())
+}
+
+pub fn put_eth_pruned_block_hash_in_db<D>(
+    db: &D,
+    block_number: u64,
+    block_hash: &EthHash,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Indexing pruned ETH block number {} in db...", block_number);
+    put_eth_hash_in_db(
+        db,
+        &get_eth_pruned_block_index_db_key(block_number),
+        block_hash,
+    )
+}
+
+pub fn maybe_get_eth_pruned_block_hash_from_db<D>(
+    db: &D,
+    block_number: u64,
+) -> Option<EthHash>
+    where D: DatabaseInterface
+{
+    trace!("✔ Maybe getting pruned ETH block number {}...", block_number);
+    get_eth_hash_from_db(db, &get_eth_pruned_block_index_db_key(block_number))
+        .ok()
+}
+
+pub fn delete_eth_pruned_block_hash_from_db<D>(
+    db: &D,
+    block_number: u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Deleting pruned ETH block number {} index...", block_number);
+    db.delete(get_eth_pruned_block_index_db_key(block_number))
+}
+
+pub fn put_eth_max_fee_per_gas_in_db<D>(
+    db: &D,
+    max_fee_per_gas: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH max fee per gas of {} in db...", max_fee_per_gas);
+    put_u64_in_db(db, &ETH_MAX_FEE_PER_GAS_KEY.to_vec(), max_fee_per_gas)
+}
+
+pub fn get_eth_max_fee_per_gas_from_db<D>(db: &D) -> Result<u64>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH max fee per gas from db...");
+    get_u64_from_db(db, &ETH_MAX_FEE_PER_GAS_KEY.to_vec())
+}
+
+pub fn put_eth_max_priority_fee_per_gas_in_db<D>(
+    db: &D,
+    max_priority_fee_per_gas: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!(
+        "✔ Putting ETH max priority fee per gas of {} in db...",
+        max_priority_fee_per_gas,
+    );
+    put_u64_in_db(
+        db,
+        &ETH_MAX_PRIORITY_FEE_PER_GAS_KEY.to_vec(),
+        max_priority_fee_per_gas,
+    )
+}
+
+pub fn get_eth_max_priority_fee_per_gas_from_db<D>(db: &D) -> Result<u64>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH max priority fee per gas from db...");
+    get_u64_from_db(db, &ETH_MAX_PRIORITY_FEE_PER_GAS_KEY.to_vec())
+}
+
+pub fn put_eth_tx_type_in_db<D>(
+    db: &D,
+    tx_type: &EthTransactionType,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH tx type of '{}' in db...", tx_type);
+    db.put(
+        ETH_TX_TYPE_KEY.to_vec(),
+        vec![tx_type.to_byte()],
+        DataSensitivity::Public,
+    )
+}
+
+pub fn get_eth_tx_type_from_db<D>(db: &D) -> Result<EthTransactionType>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH tx type from db...");
+    match db.get(ETH_TX_TYPE_KEY.to_vec(), DataSensitivity::Public) {
+        Err(_) => {
+            trace!("✔ No ETH tx type in db ∴ defaulting to legacy!");
+            Ok(EthTransactionType::Legacy)
+        }
+        Ok(bytes) => match bytes.len() == 1 {
+            true => EthTransactionType::from_byte(&bytes[0]),
+            false => Err(AppError::ConversionError(
+                "✘ Wrong number of bytes to convert to ETH tx type!"
+                    .to_string()
+            ))
+        }
+    }
+}
+
+pub fn put_ptoken_contract_variant_in_db<D>(
+    db: &D,
+    variant: &PTokenContractVariant,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting pToken contract variant of '{}' in db...", variant);
+    db.put(
+        ETH_PTOKEN_CONTRACT_VARIANT_KEY.to_vec(),
+        vec![variant.to_byte()],
+        DataSensitivity::Public,
+    )
+}
+
+pub fn get_ptoken_contract_variant_from_db<D>(
+    db: &D,
+) -> Result<PTokenContractVariant>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting pToken contract variant from db...");
+    match db.get(
+        ETH_PTOKEN_CONTRACT_VARIANT_KEY.to_vec(),
+        DataSensitivity::Public,
+    ) {
+        Err(_) => {
+            trace!("✔ No pToken contract variant in db ∴ defaulting!");
+            Ok(PTokenContractVariant::Erc777)
+        }
+        Ok(bytes) => match bytes.len() == 1 {
+            true => PTokenContractVariant::from_byte(&bytes[0]),
+            false => Err(AppError::ConversionError(
+                "✘ Wrong number of bytes to convert to contract variant!"
+                    .to_string()
+            ))
+        }
+    }
+}
+
+pub fn put_ptoken_contract_bytecode_hash_in_db<D>(
+    db: &D,
+    bytecode_hash: &EthHash,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting pToken contract bytecode hash in db...");
+    put_eth_hash_in_db(
+        db,
+        &ETH_PTOKEN_CONTRACT_BYTECODE_HASH_KEY.to_vec(),
+        bytecode_hash,
+    )
+}
+
+pub fn get_ptoken_contract_bytecode_hash_from_db<D>(
+    db: &D,
+) -> Result<EthHash>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting pToken contract bytecode hash from db...");
+    get_eth_hash_from_db(db, &ETH_PTOKEN_CONTRACT_BYTECODE_HASH_KEY.to_vec())
+}
+
+pub fn get_eth_account_nonce_from_db<D>(
+    db: &D
+) -> Result<u64>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH account nonce from db...");
+    get_u64_from_db(db, &ETH_ACCOUNT_NONCE_KEY.to_vec())
+}
+
+pub fn put_eth_account_nonce_in_db<D>(
+    db: &D,
+    nonce: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH account nonce of {} in db...", nonce);
+    put_u64_in_db(db, &ETH_ACCOUNT_NONCE_KEY.to_vec(), nonce)
+}
+
+pub fn increment_eth_account_nonce_in_db<D>(
+    db: &D,
+    amount_to_increment_by: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Incrementing ETH account nonce in db...");
+    get_eth_account_nonce_from_db(db)
+        .and_then(|nonce|
+            put_eth_account_nonce_in_db(db, &(nonce + amount_to_increment_by))
+        )
+}
+
+pub fn put_eth_mint_tx_record_in_db<D>(
+    db: &D,
+    eth_mint_tx_record: &EthMintTxRecord,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!(
+        "✔ Putting ETH mint tx record w/ nonce {} in db...",
+        eth_mint_tx_record.nonce,
+    );
+    db.put(
+        get_eth_mint_tx_record_db_key(eth_mint_tx_record.nonce),
+        serde_json::to_vec(eth_mint_tx_record)?,
+        DataSensitivity::Public,
+    )
+}
+
+pub fn get_eth_mint_tx_record_from_db<D>(
+    db: &D,
+    nonce: u64,
+) -> Result<EthMintTxRecord>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH mint tx record w/ nonce {} from db...", nonce);
+    db.get(get_eth_mint_tx_record_db_key(nonce), DataSensitivity::Public)
+        .and_then(|bytes| Ok(serde_json::from_slice(&bytes)?))
+}
+
+pub fn put_eth_pending_tx_nonces_in_db<D>(
+    db: &D,
+    nonces: &[u64],
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting {} ETH pending tx nonces in db...", nonces.len());
+    db.put(
+        ETH_PENDING_TX_NONCES_KEY.to_vec(),
+        serde_json::to_vec(nonces)?,
+        DataSensitivity::Public,
+    )
+}
+
+pub fn get_eth_pending_tx_nonces_from_db<D>(db: &D) -> Result<Vec<u64>>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH pending tx nonces from db...");
+    match db.get(ETH_PENDING_TX_NONCES_KEY.to_vec(), DataSensitivity::Public) {
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+        Err(_) => {
+            trace!("✔ No ETH pending tx nonces in db ∴ queue is empty!");
+            Ok(vec![])
+        }
+    }
+}
+
+pub fn put_eth_pending_tx_in_db<D>(
+    db: &D,
+    eth_pending_tx: &EthPendingTx,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!(
+        "✔ Putting ETH pending tx w/ nonce {} in db...",
+        eth_pending_tx.nonce,
+    );
+    let key = get_eth_pending_tx_db_key(eth_pending_tx.nonce);
+    db.put(
+        key.clone(),
+        serde_json::to_vec(eth_pending_tx)?,
+        DataSensitivity::Public,
+    )
+        .and_then(|_|
+            put_namespaced_key_in_db(db, &DbKeyPrefix::EthPendingTx, &key)
+        )
+}
+
+pub fn get_eth_pending_tx_from_db<D>(
+    db: &D,
+    nonce: u64,
+) -> Result<EthPendingTx>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH pending tx w/ nonce {} from db...", nonce);
+    db.get(get_eth_pending_tx_db_key(nonce), DataSensitivity::Public)
+        .and_then(|bytes| Ok(serde_json::from_slice(&bytes)?))
+}
+
+pub fn get_eth_pending_txs_from_db<D>(db: &D) -> Result<Vec<EthPendingTx>>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting all ETH pending txs from db...");
+    get_eth_pending_tx_nonces_from_db(db)?
+        .iter()
+        .map(|nonce| get_eth_pending_tx_from_db(db, *nonce))
+        .collect()
+}
+
+pub fn add_eth_pending_tx_to_queue_in_db<D>(
+    db: &D,
+    eth_pending_tx: &EthPendingTx,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!(
+        "✔ Adding ETH tx w/ nonce {} to pending queue...",
+        eth_pending_tx.nonce,
+    );
+    let mut nonces = get_eth_pending_tx_nonces_from_db(db)?;
+    if !nonces.contains(&eth_pending_tx.nonce) {
+        nonces.push(eth_pending_tx.nonce);
+    };
+    put_eth_pending_tx_in_db(db, eth_pending_tx)
+        .and_then(|_| put_eth_pending_tx_nonces_in_db(db, &nonces))
+}
+
+pub fn remove_eth_pending_tx_from_queue_in_db<D>(
+    db: &D,
+    nonce: u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Removing ETH tx w/ nonce {} from pending queue...", nonce);
+    get_eth_pending_tx_nonces_from_db(db)
+        .map(|nonces|
+            nonces
+                .into_iter()
+                .filter(|queued_nonce| *queued_nonce != nonce)
+                .collect::<Vec<u64>>()
+        )
+        .and_then(|nonces| put_eth_pending_tx_nonces_in_db(db, &nonces))
+        .and_then(|_|
+            delete_namespaced_key_from_db(
+                db,
+                &DbKeyPrefix::EthPendingTx,
+                &get_eth_pending_tx_db_key(nonce),
+            )
+        )
+}
+
+pub fn put_eth_redeem_record_in_db<D>(
+    db: &D,
+    block_hash: &EthHash,
+    eth_redeem_record: &EthRedeemRecord,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH redeem record for block {} in db...", block_hash);
+    db.put(
+        get_eth_redeem_record_db_key(block_hash),
+        serde_json::to_vec(eth_redeem_record)?,
+        DataSensitivity::Public,
+    )
+}
+
+pub fn maybe_get_eth_redeem_record_from_db<D>(
+    db: &D,
+    block_hash: &EthHash,
+) -> Option<EthRedeemRecord>
+    where D: DatabaseInterface
+{
+    trace!("✔ Maybe getting ETH redeem record for block {}...", block_hash);
+    match db.get(
+        get_eth_redeem_record_db_key(block_hash),
+        DataSensitivity::Public,
+    ) {
+        Err(_) => None,
+        Ok(bytes) => serde_json::from_slice(&bytes).ok(),
+    }
+}
+
+pub fn delete_eth_redeem_record_from_db<D>(
+    db: &D,
+    block_hash: &EthHash,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Deleting ETH redeem record for block {}...", block_hash);
+    db.delete(get_eth_redeem_record_db_key(block_hash))
+}
+
+pub fn put_eth_relay_forwarder_address_in_db<D>(
+    db: &D,
+    forwarder_address: &EthAddress,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH relay forwarder address in db...");
+    put_eth_address_in_db(
+        db,
+        &ETH_RELAY_FORWARDER_ADDRESS_KEY.to_vec(),
+        forwarder_address,
+    )
+}
+
+pub fn get_eth_relay_forwarder_address_from_db<D>(db: &D) -> Result<EthAddress>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH relay forwarder address from db...");
+    get_eth_address_from_db(db, &ETH_RELAY_FORWARDER_ADDRESS_KEY.to_vec())
+}
+
+pub fn remove_eth_relay_forwarder_address_from_db<D>(db: &D) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Removing ETH relay forwarder address from db...");
+    db.delete(ETH_RELAY_FORWARDER_ADDRESS_KEY.to_vec())
+}
+
+pub fn eth_relay_mode_is_enabled<D>(db: &D) -> bool
+    where D: DatabaseInterface
+{
+    key_exists_in_db(
+        db,
+        &ETH_RELAY_FORWARDER_ADDRESS_KEY.to_vec(),
+        DataSensitivity::Public,
+    )
+}
+
+pub fn put_eth_relay_nonce_in_db<D>(
+    db: &D,
+    nonce: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH relay nonce of {} in db...", nonce);
+    put_u64_in_db(db, &ETH_RELAY_NONCE_KEY.to_vec(), nonce)
+}
+
+pub fn get_eth_relay_nonce_from_db<D>(db: &D) -> Result<u64>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH relay nonce from db...");
+    match get_u64_from_db(db, &ETH_RELAY_NONCE_KEY.to_vec()) {
+        Ok(nonce) => Ok(nonce),
+        Err(_) => {
+            trace!("✔ No ETH relay nonce in db ∴ defaulting to 0!");
+            Ok(0)
+        }
+    }
+}
+
+pub fn increment_eth_relay_nonce_in_db<D>(
+    db: &D,
+    amount_to_increment_by: &u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Incrementing ETH relay nonce in db...");
+    get_eth_relay_nonce_from_db(db)
+        .and_then(|nonce|
+            put_eth_relay_nonce_in_db(db, &(nonce + amount_to_increment_by))
+        )
+}
+
+pub fn put_eth_batch_mint_mode_in_db<D>(
+    db: &D,
+    enabled: bool,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH batch mint mode of {} in db...", enabled);
+    db.put(
+        ETH_BATCH_MINT_MODE_KEY.to_vec(),
+        vec![enabled as u8],
+        DataSensitivity::Public,
+    )
+}
+
+pub fn eth_batch_mint_mode_is_enabled<D>(db: &D) -> bool
+    where D: DatabaseInterface
+{
+    trace!("✔ Checking if ETH batch mint mode is enabled...");
+    match db.get(ETH_BATCH_MINT_MODE_KEY.to_vec(), DataSensitivity::Public) {
+        Ok(bytes) => bytes == vec![1u8],
+        Err(_) => false,
+    }
+}
+
+pub fn put_eth_mint_with_data_mode_in_db<D>(
+    db: &D,
+    enabled: bool,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH mint with data mode of {} in db...", enabled);
+    db.put(
+        ETH_MINT_WITH_DATA_MODE_KEY.to_vec(),
+        vec![enabled as u8],
+        DataSensitivity::Public,
+    )
+}
+
+pub fn eth_mint_with_data_mode_is_enabled<D>(db: &D) -> bool
     where D: DatabaseInterface
 {
-    trace!("✔ Checking for existence of key: {}", hex::encode(key));
-    match db.get(key.to_vec(), sensitivity) {
-        Ok(_) => true,
+    trace!("✔ Checking if ETH mint with data mode is enabled...");
+    match db.get(
+        ETH_MINT_WITH_DATA_MODE_KEY.to_vec(),
+        DataSensitivity::Public,
+    ) {
+        Ok(bytes) => bytes == vec![1u8],
         Err(_) => false,
     }
 }
 
-pub fn put_eth_gas_price_in_db<D>(
+pub fn put_eth_pre_eip155_signing_in_db<D>(
     db: &D,
-    gas_price: &u64,
+    enabled: bool,
 ) -> Result<()>
     where D: DatabaseInterface
 {
-    trace!("✔ Putting ETH gas price of {} in db...", gas_price);
+    trace!("✔ Putting ETH pre-EIP155 signing of {} in db...", enabled);
     db.put(
-        ETH_GAS_PRICE_KEY.to_vec(),
-        gas_price.to_le_bytes().to_vec(),
-        None,
+        ETH_PRE_EIP155_SIGNING_KEY.to_vec(),
+        vec![enabled as u8],
+        DataSensitivity::Public,
     )
 }
 
-pub fn get_eth_gas_price_from_db<D>(db: &D) -> Result<u64>
+pub fn eth_pre_eip155_signing_is_enabled<D>(db: &D) -> bool
     where D: DatabaseInterface
 {
-    trace!("✔ Getting ETH gas price from db...");
-    db.get(ETH_GAS_PRICE_KEY.to_vec(), None)
-        .and_then(|bytes|
-            match bytes.len() <= 8 {
-                true => {
-                    let mut array = [0; 8];
-                    let bytes = &bytes[..array.len()];
-                    array.copy_from_slice(bytes);
-                    Ok(u64::from_le_bytes(array))
-                },
-                false => Err(AppError::Custom(
-                    "✘ Too many bytes to convert to u64!".to_string()
-                ))
-            }
-        )
+    trace!("✔ Checking if ETH pre-EIP155 signing is enabled...");
+    match db.get(ETH_PRE_EIP155_SIGNING_KEY.to_vec(), DataSensitivity::Public) {
+        Ok(bytes) => bytes == vec![1u8],
+        Err(_) => false,
+    }
 }
 
-pub fn get_eth_account_nonce_from_db<D>(
-    db: &D
-) -> Result<u64>
+pub fn put_eth_pos_mode_in_db<D>(
+    db: &D,
+    enabled: bool,
+) -> Result<()>
     where D: DatabaseInterface
 {
-    trace!("✔ Getting ETH account nonce from db...");
-    get_u64_from_db(db, &ETH_ACCOUNT_NONCE_KEY.to_vec())
+    trace!("✔ Putting ETH proof-of-stake mode of {} in db...", enabled);
+    db.put(
+        ETH_POS_MODE_KEY.to_vec(),
+        vec![enabled as u8],
+        DataSensitivity::Public,
+    )
 }
 
-pub fn put_eth_account_nonce_in_db<D>(
-    db: &D,
-    nonce: &u64,
-) -> Result<()>
+pub fn eth_pos_mode_is_enabled<D>(db: &D) -> bool
     where D: DatabaseInterface
 {
-    trace!("✔ Putting ETH account nonce of {} in db...", nonce);
-    put_u64_in_db(db, &ETH_ACCOUNT_NONCE_KEY.to_vec(), nonce)
+    trace!("✔ Checking if ETH proof-of-stake mode is enabled...");
+    match db.get(ETH_POS_MODE_KEY.to_vec(), DataSensitivity::Public) {
+        Ok(bytes) => bytes == vec![1u8],
+        Err(_) => false,
+    }
 }
 
-pub fn increment_eth_account_nonce_in_db<D>(
+pub fn put_eth_ptoken_contract_topics_in_db<D>(
     db: &D,
-    amount_to_increment_by: &u64,
+    topics: &EthTopics,
 ) -> Result<()>
     where D: DatabaseInterface
 {
-    trace!("✔ Incrementing ETH account nonce in db...");
-    get_eth_account_nonce_from_db(db)
-        .and_then(|nonce|
-            put_eth_account_nonce_in_db(db, &(nonce + amount_to_increment_by))
-        )
+    trace!("✔ Putting {} pToken contract topics in db...", topics.len());
+    db.put(
+        ETH_PTOKEN_CONTRACT_TOPICS_KEY.to_vec(),
+        topics
+            .iter()
+            .map(|topic| topic.as_bytes().to_vec())
+            .flatten()
+            .collect::<Bytes>(),
+        DataSensitivity::Public,
+    )
+}
+
+pub fn get_eth_ptoken_contract_topics_from_db<D>(db: &D) -> Result<EthTopics>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting pToken contract topics from db...");
+    match db.get(
+        ETH_PTOKEN_CONTRACT_TOPICS_KEY.to_vec(),
+        DataSensitivity::Public,
+    ) {
+        Ok(bytes) => bytes
+            .chunks(32)
+            .map(|chunk| convert_bytes_to_h256(&chunk.to_vec()))
+            .collect::<Result<EthTopics>>(),
+        Err(_) => {
+            trace!("✔ No pToken contract topics in db ∴ using defaults!");
+            Ok(PTOKEN_CONTRACT_TOPICS.to_vec())
+        }
+    }
 }
 
 pub fn put_eth_chain_id_in_db<D>(
@@ -545,7 +1367,7 @@ pub fn put_eth_chain_id_in_db<D>(
     db.put(
         ETH_CHAIN_ID_KEY.to_vec(),
         chain_id.to_le_bytes().to_vec(),
-        None,
+        DataSensitivity::Public,
     )
 }
 
@@ -553,7 +1375,7 @@ pub fn get_eth_chain_id_from_db<D>(db: &D) -> Result<u8>
     where D: DatabaseInterface
 {
     trace!("✔ Getting ETH `chain_id` from db...");
-    db.get(ETH_CHAIN_ID_KEY.to_vec(), None)
+    db.get(ETH_CHAIN_ID_KEY.to_vec(), DataSensitivity::Public)
         .and_then(|bytes|
             match bytes.len() == 1 {
                 true => {
@@ -562,7 +1384,7 @@ pub fn get_eth_chain_id_from_db<D>(db: &D) -> Result<u8>
                     array.copy_from_slice(bytes);
                     Ok(u8::from_le_bytes(array))
                 },
-                false => Err(AppError::Custom(
+                false => Err(AppError::ConversionError(
                     "✘ Wrong number of bytes to convert to usize!".to_string()
                 ))
             }
@@ -583,11 +1405,16 @@ pub fn get_eth_private_key_from_db<D>(db: &D) -> Result<EthPrivateKey>
     where D: DatabaseInterface
 {
     trace!("✔ Getting ETH private key from db...");
-    db.get(ETH_PRIVATE_KEY_DB_KEY.to_vec(), Some(255))
-        .and_then(|pk_bytes| {
-            let mut array = [0; 32];
-            array.copy_from_slice(&pk_bytes[..32]);
-            EthPrivateKey::from_slice(array)
+    db.get(ETH_PRIVATE_KEY_DB_KEY.to_vec(), DataSensitivity::Private)
+        .and_then(|pk_bytes| match pk_bytes.len() {
+            32 => {
+                let mut array = [0; 32];
+                array.copy_from_slice(&pk_bytes);
+                EthPrivateKey::from_slice(array)
+            }
+            _ => Err(AppError::ConversionError(
+                "✘ ETH private key in db must be 32 bytes long!".to_string()
+            )),
         })
 }
 
@@ -595,10 +1422,8 @@ pub fn get_eth_smart_contract_address_from_db<D>(db: &D) -> Result<EthAddress>
     where D: DatabaseInterface
 {
     trace!("✔ Getting ETH smart-contract address from db...");
-    db.get(ETH_SMART_CONTRACT_ADDRESS_KEY.to_vec(), None)
-        .and_then(|address_bytes|
-            Ok(EthAddress::from_slice(&address_bytes[..]))
-        )
+    db.get(ETH_SMART_CONTRACT_ADDRESS_KEY.to_vec(), DataSensitivity::Public)
+        .and_then(|address_bytes| convert_bytes_to_eth_address(&address_bytes))
 }
 
 pub fn put_eth_smart_contract_address_in_db<D>(
@@ -615,12 +1440,95 @@ pub fn put_eth_smart_contract_address_in_db<D>(
     )
 }
 
+pub fn get_eth_smart_contract_address_history_from_db<D>(
+    db: &D,
+) -> Result<Vec<EthContractAddressUpgrade>>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH smart-contract address history from db...");
+    match db.get(
+        ETH_SMART_CONTRACT_ADDRESS_HISTORY_KEY.to_vec(),
+        DataSensitivity::Public,
+    ) {
+        Err(_) => Ok(vec![]),
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+    }
+}
+
+pub fn get_eth_queued_redeem_params_from_db<D>(
+    db: &D,
+) -> Result<Vec<RedeemParams>>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting ETH queued redeem params from db...");
+    match db.get(
+        ETH_QUEUED_REDEEM_PARAMS_KEY.to_vec(),
+        DataSensitivity::Public,
+    ) {
+        Err(_) => Ok(vec![]),
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+    }
+}
+
+pub fn put_eth_queued_redeem_params_in_db<D>(
+    db: &D,
+    redeem_params: &Vec<RedeemParams>,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting ETH queued redeem params in db...");
+    db.put(
+        ETH_QUEUED_REDEEM_PARAMS_KEY.to_vec(),
+        serde_json::to_vec(redeem_params)?,
+        DataSensitivity::Public,
+    )
+}
+
+pub fn update_eth_smart_contract_address_in_db<D>(
+    db: &D,
+    new_address: &EthAddress,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Updating ETH smart-contract address in db...");
+    let previous_address = get_eth_smart_contract_address_from_db(db)?;
+    if new_address.is_zero() {
+        return Err(AppError::InvalidAddress(
+            "✘ Cannot set ETH smart-contract address to zero address!"
+                .to_string()
+        ))
+    };
+    if *new_address == previous_address {
+        return Err(AppError::InvalidAddress(
+            "✘ ETH smart-contract address is already set to that address!"
+                .to_string()
+        ))
+    };
+    let mut history = get_eth_smart_contract_address_history_from_db(db)?;
+    history.push(
+        EthContractAddressUpgrade {
+            eth_block_number: get_latest_eth_block_number(db)? as u64,
+            new_address: format!("0x{}", hex::encode(new_address.as_bytes())),
+            previous_address: format!(
+                "0x{}",
+                hex::encode(previous_address.as_bytes())
+            ),
+        }
+    );
+    db.put(
+        ETH_SMART_CONTRACT_ADDRESS_HISTORY_KEY.to_vec(),
+        serde_json::to_vec(&history)?,
+        DataSensitivity::Public,
+    )
+        .and_then(|_| put_eth_smart_contract_address_in_db(db, new_address))
+}
+
 pub fn get_public_eth_address_from_db<D>(db: &D) -> Result<EthAddress>
     where D: DatabaseInterface
 {
     trace!("✔ Getting public ETH address from db...");
-    db.get(ETH_ADDRESS_KEY.to_vec(), None)
-        .map(|bytes| EthAddress::from_slice(&bytes))
+    db.get(ETH_ADDRESS_KEY.to_vec(), DataSensitivity::Public)
+        .and_then(|bytes| convert_bytes_to_eth_address(&bytes))
 }
 
 pub fn put_public_eth_address_in_db<D>(
@@ -630,14 +1538,18 @@ pub fn put_public_eth_address_in_db<D>(
     where D: DatabaseInterface
 {
     trace!("✔ Putting public ETH address in db...");
-    db.put(ETH_ADDRESS_KEY.to_vec(), eth_address.as_bytes().to_vec(), None)
+    db.put(
+        ETH_ADDRESS_KEY.to_vec(),
+        eth_address.as_bytes().to_vec(),
+        DataSensitivity::Public,
+    )
 }
 
 pub fn get_eth_address_from_db<D>(db: &D, key: &Bytes) -> Result<EthAddress>
     where D: DatabaseInterface
 {
-    db.get(key.to_vec(), None)
-        .map(|bytes| EthAddress::from_slice(&bytes))
+    db.get(key.to_vec(), DataSensitivity::Public)
+        .and_then(|bytes| convert_bytes_to_eth_address(&bytes))
 }
 
 pub fn put_eth_address_in_db<D>(
@@ -647,7 +1559,11 @@ pub fn put_eth_address_in_db<D>(
 ) -> Result<()>
     where D: DatabaseInterface
 {
-    db.put(key.to_vec(), eth_address.as_bytes().to_vec(), None)
+    db.put(
+        key.to_vec(),
+        eth_address.as_bytes().to_vec(),
+        DataSensitivity::Public,
+    )
 }
 
 #[cfg(test)]
@@ -655,6 +1571,7 @@ mod tests {
     use super::*;
     use crate::{
         test_utils::get_test_database,
+        eth::eth_types::EthPendingTxStatus,
         eth::eth_test_utils::{
             get_sample_eth_address,
             get_sample_eth_private_key,
@@ -670,7 +1587,7 @@ mod tests {
         let result = key_exists_in_db(
             &db,
             &ETH_ACCOUNT_NONCE_KEY.to_vec(),
-            None
+            DataSensitivity::Public
         );
         assert!(!result);
     }
@@ -680,13 +1597,13 @@ mod tests {
         let thing = vec![0xc0];
         let db = get_test_database();
         let key = ETH_ACCOUNT_NONCE_KEY;
-        if let Err(e) = db.put(key.to_vec(), thing, None) {
+        if let Err(e) = db.put(key.to_vec(), thing, DataSensitivity::Public) {
             panic!("Error putting canon to tip len in db: {}", e);
         };
         let result = key_exists_in_db(
             &db,
             &ETH_ACCOUNT_NONCE_KEY.to_vec(),
-            None,
+            DataSensitivity::Public,
         );
         assert!(result);
     }
@@ -708,6 +1625,245 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_put_and_get_eth_max_fee_per_gas_in_db() {
+        let db = get_test_database();
+        let max_fee_per_gas = 20_000_000_000;
+        if let Err(e) = put_eth_max_fee_per_gas_in_db(&db, &max_fee_per_gas) {
+            panic!("Error putting max fee per gas in db: {}", e);
+        };
+        let result = get_eth_max_fee_per_gas_from_db(&db)
+            .unwrap();
+        assert_eq!(result, max_fee_per_gas);
+    }
+
+    #[test]
+    fn should_put_and_get_eth_max_priority_fee_per_gas_in_db() {
+        let db = get_test_database();
+        let max_priority_fee_per_gas = 2_000_000_000;
+        if let Err(e) = put_eth_max_priority_fee_per_gas_in_db(
+            &db,
+            &max_priority_fee_per_gas,
+        ) {
+            panic!("Error putting max priority fee per gas in db: {}", e);
+        };
+        let result = get_eth_max_priority_fee_per_gas_from_db(&db)
+            .unwrap();
+        assert_eq!(result, max_priority_fee_per_gas);
+    }
+
+    #[test]
+    fn should_default_to_legacy_eth_tx_type_if_none_in_db() {
+        let db = get_test_database();
+        let result = get_eth_tx_type_from_db(&db)
+            .unwrap();
+        assert_eq!(result, EthTransactionType::Legacy);
+    }
+
+    #[test]
+    fn should_put_and_get_eth_tx_type_in_db() {
+        let db = get_test_database();
+        let tx_type = EthTransactionType::DynamicFee;
+        if let Err(e) = put_eth_tx_type_in_db(&db, &tx_type) {
+            panic!("Error putting ETH tx type in db: {}", e);
+        };
+        let result = get_eth_tx_type_from_db(&db)
+            .unwrap();
+        assert_eq!(result, tx_type);
+    }
+
+    #[test]
+    fn should_put_and_get_ptoken_contract_variant_in_db() {
+        let db = get_test_database();
+        assert_eq!(
+            get_ptoken_contract_variant_from_db(&db).unwrap(),
+            PTokenContractVariant::Erc777,
+        );
+        let variant = PTokenContractVariant::Erc777WithOperators;
+        put_ptoken_contract_variant_in_db(&db, &variant).unwrap();
+        let result = get_ptoken_contract_variant_from_db(&db).unwrap();
+        assert_eq!(result, variant);
+    }
+
+    #[test]
+    fn should_put_and_get_ptoken_contract_bytecode_hash_in_db() {
+        let db = get_test_database();
+        assert!(get_ptoken_contract_bytecode_hash_from_db(&db).is_err());
+        let bytecode_hash = EthHash::from_slice(
+            &ETH_PTOKEN_CONTRACT_BYTECODE_HASH_KEY
+        );
+        put_ptoken_contract_bytecode_hash_in_db(&db, &bytecode_hash).unwrap();
+        let result = get_ptoken_contract_bytecode_hash_from_db(&db).unwrap();
+        assert_eq!(result, bytecode_hash);
+    }
+
+    #[test]
+    fn should_put_and_get_eth_mint_tx_record_in_db() {
+        let db = get_test_database();
+        let record = EthMintTxRecord {
+            nonce: 5,
+            to: vec![0xc0],
+            data: vec![0xff, 0xee],
+            gas_price: 20_000_000_000,
+            gas_limit: 120_000,
+            tx_hash: "0xc0ffee".to_string(),
+            tx_type: EthTransactionType::Legacy,
+            max_priority_fee_per_gas: 0,
+            correlation_ids: vec!["0xc0ffee".to_string()],
+        };
+        put_eth_mint_tx_record_in_db(&db, &record).unwrap();
+        let result = get_eth_mint_tx_record_from_db(&db, 5).unwrap();
+        assert_eq!(result, record);
+        assert!(get_eth_mint_tx_record_from_db(&db, 6).is_err());
+    }
+
+    #[test]
+    fn should_add_and_remove_eth_pending_txs_from_queue_in_db() {
+        let db = get_test_database();
+        let pending_tx = EthPendingTx {
+            nonce: 3,
+            tx_hex: "c0ffee".to_string(),
+            tx_hash: "0xdecaf".to_string(),
+            status: EthPendingTxStatus::Signed,
+            receipt_block: None,
+            correlation_ids: vec![],
+            replaced_tx_hashes: vec![],
+        };
+        assert!(get_eth_pending_txs_from_db(&db).unwrap().is_empty());
+        add_eth_pending_tx_to_queue_in_db(&db, &pending_tx).unwrap();
+        add_eth_pending_tx_to_queue_in_db(&db, &pending_tx).unwrap();
+        assert_eq!(get_eth_pending_tx_nonces_from_db(&db).unwrap(), vec![3]);
+        assert_eq!(
+            get_eth_pending_txs_from_db(&db).unwrap(),
+            vec![pending_tx.clone()],
+        );
+        remove_eth_pending_tx_from_queue_in_db(&db, 3).unwrap();
+        assert!(get_eth_pending_txs_from_db(&db).unwrap().is_empty());
+        assert_eq!(get_eth_pending_tx_from_db(&db, 3).unwrap(), pending_tx);
+    }
+
+    #[test]
+    fn should_default_eth_mint_gas_limit_if_not_in_db() {
+        let db = get_test_database();
+        let result = get_eth_mint_gas_limit_from_db(&db).unwrap();
+        assert_eq!(result, GAS_LIMIT_FOR_MINTING_TX as u64);
+    }
+
+    #[test]
+    fn should_put_and_get_eth_mint_gas_limit_in_db() {
+        let db = get_test_database();
+        let gas_limit = 150_000;
+        put_eth_mint_gas_limit_in_db(&db, &gas_limit).unwrap();
+        let result = get_eth_mint_gas_limit_from_db(&db).unwrap();
+        assert_eq!(result, gas_limit);
+    }
+
+    #[test]
+    fn should_default_eth_cancel_gas_limit_if_not_in_db() {
+        let db = get_test_database();
+        let result = get_eth_cancel_gas_limit_from_db(&db).unwrap();
+        assert_eq!(result, GAS_LIMIT_FOR_CANCEL_TX as u64);
+    }
+
+    #[test]
+    fn should_put_and_get_eth_cancel_gas_limit_in_db() {
+        let db = get_test_database();
+        let gas_limit = 30_000;
+        put_eth_cancel_gas_limit_in_db(&db, &gas_limit).unwrap();
+        let result = get_eth_cancel_gas_limit_from_db(&db).unwrap();
+        assert_eq!(result, gas_limit);
+    }
+
+    #[test]
+    fn should_default_eth_tail_pruning_distance_if_not_in_db() {
+        let db = get_test_database();
+        let result = get_eth_tail_pruning_distance_from_db(&db).unwrap();
+        assert_eq!(result, ETH_TAIL_PRUNING_DISTANCE);
+    }
+
+    #[test]
+    fn should_put_and_get_eth_tail_pruning_distance_in_db() {
+        let db = get_test_database();
+        let distance = 50;
+        put_eth_tail_pruning_distance_in_db(&db, &distance).unwrap();
+        let result = get_eth_tail_pruning_distance_from_db(&db).unwrap();
+        assert_eq!(result, distance);
+    }
+
+    #[test]
+    fn should_enable_and_disable_eth_relay_mode() {
+        let db = get_test_database();
+        let forwarder_address = get_sample_eth_address();
+        assert!(!eth_relay_mode_is_enabled(&db));
+        put_eth_relay_forwarder_address_in_db(&db, &forwarder_address)
+            .unwrap();
+        assert!(eth_relay_mode_is_enabled(&db));
+        let result = get_eth_relay_forwarder_address_from_db(&db).unwrap();
+        assert_eq!(result, forwarder_address);
+        remove_eth_relay_forwarder_address_from_db(&db).unwrap();
+        assert!(!eth_relay_mode_is_enabled(&db));
+    }
+
+    #[test]
+    fn should_increment_eth_relay_nonce_in_db() {
+        let db = get_test_database();
+        assert_eq!(get_eth_relay_nonce_from_db(&db).unwrap(), 0);
+        increment_eth_relay_nonce_in_db(&db, &3).unwrap();
+        assert_eq!(get_eth_relay_nonce_from_db(&db).unwrap(), 3);
+    }
+
+    #[test]
+    fn should_put_eth_batch_mint_mode_in_db() {
+        let db = get_test_database();
+        assert!(!eth_batch_mint_mode_is_enabled(&db));
+        put_eth_batch_mint_mode_in_db(&db, true).unwrap();
+        assert!(eth_batch_mint_mode_is_enabled(&db));
+        put_eth_batch_mint_mode_in_db(&db, false).unwrap();
+        assert!(!eth_batch_mint_mode_is_enabled(&db));
+    }
+
+    #[test]
+    fn should_put_and_get_eth_host_token_decimals_in_db() {
+        let db = get_test_database();
+        let decimals = 6;
+        let default_decimals = get_eth_host_token_decimals_from_db(&db)
+            .unwrap();
+        assert_eq!(default_decimals, PTOKEN_ERC777_NUM_DECIMALS);
+        put_eth_host_token_decimals_in_db(&db, &decimals).unwrap();
+        let result = get_eth_host_token_decimals_from_db(&db).unwrap();
+        assert_eq!(result, decimals);
+    }
+
+    #[test]
+    fn should_put_eth_mint_with_data_mode_in_db() {
+        let db = get_test_database();
+        assert!(!eth_mint_with_data_mode_is_enabled(&db));
+        put_eth_mint_with_data_mode_in_db(&db, true).unwrap();
+        assert!(eth_mint_with_data_mode_is_enabled(&db));
+        put_eth_mint_with_data_mode_in_db(&db, false).unwrap();
+        assert!(!eth_mint_with_data_mode_is_enabled(&db));
+    }
+
+    #[test]
+    fn should_put_eth_pre_eip155_signing_in_db() {
+        let db = get_test_database();
+        assert!(!eth_pre_eip155_signing_is_enabled(&db));
+        put_eth_pre_eip155_signing_in_db(&db, true).unwrap();
+        assert!(eth_pre_eip155_signing_is_enabled(&db));
+        put_eth_pre_eip155_signing_in_db(&db, false).unwrap();
+        assert!(!eth_pre_eip155_signing_is_enabled(&db));
+    }
+
+    #[test]
+    fn should_put_eth_pos_mode_in_db() {
+        let db = get_test_database();
+        assert!(!eth_pos_mode_is_enabled(&db));
+        put_eth_pos_mode_in_db(&db, true).unwrap();
+        assert!(eth_pos_mode_is_enabled(&db));
+        put_eth_pos_mode_in_db(&db, false).unwrap();
+        assert!(!eth_pos_mode_is_enabled(&db));
+    }
+
     #[test]
     fn should_put_chain_id_in_db() {
         let db = get_test_database();
@@ -757,6 +1913,56 @@ mod tests {
         assert!(result == contract_address);
     }
 
+    #[test]
+    fn should_update_eth_smart_contract_address_and_record_history() {
+        let db = get_test_database();
+        let previous_address = get_sample_eth_address();
+        let new_address = get_sample_contract_address();
+        let latest_block = get_sample_eth_block_and_receipts_n(1).unwrap();
+        put_eth_latest_block_in_db(&db, &latest_block).unwrap();
+        put_eth_smart_contract_address_in_db(&db, &previous_address).unwrap();
+        assert!(get_eth_smart_contract_address_history_from_db(&db)
+            .unwrap()
+            .is_empty());
+        update_eth_smart_contract_address_in_db(&db, &new_address).unwrap();
+        let history = get_eth_smart_contract_address_history_from_db(&db)
+            .unwrap();
+        assert_eq!(
+            get_eth_smart_contract_address_from_db(&db).unwrap(),
+            new_address,
+        );
+        assert_eq!(history.len(), 1);
+        assert_eq!(
+            history[0].previous_address,
+            format!("0x{}", hex::encode(previous_address.as_bytes())),
+        );
+        assert_eq!(
+            history[0].new_address,
+            format!("0x{}", hex::encode(new_address.as_bytes())),
+        );
+        assert_eq!(
+            history[0].eth_block_number,
+            latest_block.block.number.as_u64(),
+        );
+    }
+
+    #[test]
+    fn should_fail_to_update_eth_smart_contract_address_to_same_address() {
+        let db = get_test_database();
+        let address = get_sample_eth_address();
+        let latest_block = get_sample_eth_block_and_receipts_n(1).unwrap();
+        put_eth_latest_block_in_db(&db, &latest_block).unwrap();
+        put_eth_smart_contract_address_in_db(&db, &address).unwrap();
+        assert!(update_eth_smart_contract_address_in_db(
+            &db,
+            &address,
+        ).is_err());
+        assert!(update_eth_smart_contract_address_in_db(
+            &db,
+            &EthAddress::zero(),
+        ).is_err());
+    }
+
     #[test]
     fn should_get_eth_pk_from_database() {
         let db = get_test_database();
@@ -1054,4 +2260,96 @@ mod tests {
             panic!("Shouldn't have ancestor #{} in db!", blocks.len());
         };
     }
+
+    #[test]
+    fn should_get_nth_ancestor_eth_block_header_if_extant() {
+        let db = get_test_database();
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        let block_hash = blocks[blocks.len() - 1].block.hash;
+        blocks
+            .iter()
+            .for_each(|block| put_eth_block_and_receipts_in_db(&db, block)
+                .unwrap()
+            );
+        let result = maybe_get_nth_ancestor_eth_block_header(
+            &db,
+            &block_hash,
+            &2,
+        ).unwrap();
+        assert_eq!(
+            result,
+            EthBlockHeaderInDbFormat::from_eth_block(
+                &blocks[blocks.len() - 3].block
+            ),
+        );
+    }
+
+    #[test]
+    fn should_get_eth_block_header_from_full_block_if_no_header_stored() {
+        let db = get_test_database();
+        let block = get_sample_eth_block_and_receipts_n(1)
+            .unwrap();
+        let block_hash = block.block.hash;
+        let expected_result =
+            EthBlockHeaderInDbFormat::from_eth_block(&block.block);
+        put_eth_block_and_receipts_in_db(&db, &block).unwrap();
+        let header_key = get_eth_block_header_db_key(&block_hash);
+        assert_eq!(
+            db.get(header_key.clone(), DataSensitivity::Public).unwrap(),
+            expected_result.to_bytes(),
+        );
+        db.delete(header_key).unwrap();
+        let result = get_eth_block_header_from_db(&db, &block_hash).unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_delete_eth_block_from_db() {
+        let db = get_test_database();
+        let block = get_sample_eth_block_and_receipts_n(1)
+            .unwrap();
+        let block_hash = block.block.hash;
+        put_eth_block_and_receipts_in_db(&db, &block).unwrap();
+        delete_eth_block_from_db(&db, &block_hash).unwrap();
+        assert!(!eth_block_exists_in_db(&db, &block_hash));
+        assert!(get_eth_block_header_from_db(&db, &block_hash).is_err());
+    }
+
+    #[test]
+    fn should_put_get_and_delete_eth_redeem_record_in_db() {
+        let db = get_test_database();
+        let block = get_sequential_eth_blocks_and_receipts()[0].block.clone();
+        let record = EthRedeemRecord {
+            block_hash: format!("0x{}", hex::encode(block.hash.as_bytes())),
+            block_number: block.number.as_u64(),
+            btc_tx_hashes: vec!["some-btc-tx-hash".to_string()],
+            redeems: vec![],
+            spent_utxos: vec![],
+            change_utxos: vec![],
+        };
+        put_eth_redeem_record_in_db(&db, &block.hash, &record).unwrap();
+        let result = maybe_get_eth_redeem_record_from_db(&db, &block.hash);
+        assert_eq!(result, Some(record));
+        delete_eth_redeem_record_from_db(&db, &block.hash).unwrap();
+        let result = maybe_get_eth_redeem_record_from_db(&db, &block.hash);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn should_default_to_ptoken_contract_topics_if_none_in_db() {
+        let db = get_test_database();
+        let result = get_eth_ptoken_contract_topics_from_db(&db)
+            .unwrap();
+        assert_eq!(result, PTOKEN_CONTRACT_TOPICS.to_vec());
+    }
+
+    #[test]
+    fn should_put_and_get_eth_ptoken_contract_topics_in_db() {
+        let db = get_test_database();
+        let topics = vec![EthHash::random(), EthHash::random()];
+        put_eth_ptoken_contract_topics_in_db(&db, &topics).unwrap();
+        let result = get_eth_ptoken_contract_topics_from_db(&db)
+            .unwrap();
+        assert_eq!(result, topics);
+    }
 }
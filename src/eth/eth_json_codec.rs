@@ -8,6 +8,11 @@ use crate::{
         Bytes,
         Result,
     },
+    db_codec::{
+        serialize_for_db,
+        deserialize_from_db,
+        DbSerializationFormat,
+    },
     eth::{
         parse_eth_block_and_receipts::parse_eth_block_and_receipts_json,
         eth_types::{
@@ -82,6 +87,7 @@ fn encode_eth_receipt_as_json(
                 "0x{}",
                 hex::encode(eth_receipt.logs_bloom.as_bytes())
             ),
+            "type": format!("0x{:x}", eth_receipt.receipt_type),
         })
     )
 }
@@ -155,6 +161,18 @@ fn encode_eth_block_as_json(
                 format!("0x{}", hex::encode(eth_block.seal_fields.0.clone())),
                 format!("0x{:x}", eth_block.seal_fields.1),
             ],
+            "baseFeePerGas": eth_block.base_fee_per_gas
+                .map(|fee| format!("0x{:x}", fee)),
+            "withdrawalsRoot": eth_block.withdrawals_root
+                .map(|root| format!("0x{}", hex::encode(root.as_bytes()))),
+            "blobGasUsed": eth_block.blob_gas_used
+                .map(|gas| format!("0x{:x}", gas)),
+            "excessBlobGas": eth_block.excess_blob_gas
+                .map(|gas| format!("0x{:x}", gas)),
+            "parentBeaconBlockRoot": eth_block.parent_beacon_block_root
+                .map(|root| format!("0x{}", hex::encode(root.as_bytes()))),
+            "requestsHash": eth_block.requests_hash
+                .map(|hash| format!("0x{}", hex::encode(hash.as_bytes()))),
         })
     )
 }
@@ -179,17 +197,17 @@ pub fn decode_eth_block_and_receipts_from_json_bytes(
     block_and_receipt_bytes: Bytes
 ) -> Result<EthBlockAndReceipts> {
     parse_eth_block_and_receipts_json(
-        serde_json::from_slice(&block_and_receipt_bytes)?
+        deserialize_from_db(&block_and_receipt_bytes)?
     )
 }
 
 pub fn encode_eth_block_and_receipts_as_json_bytes(
-    eth_block_and_receipts: &EthBlockAndReceipts
+    eth_block_and_receipts: &EthBlockAndReceipts,
+    format: DbSerializationFormat,
 ) -> Result<Bytes> {
-    Ok(
-        serde_json::to_vec(
-            &encode_eth_block_and_receipts_as_json(eth_block_and_receipts)?
-        )?
+    serialize_for_db(
+        &encode_eth_block_and_receipts_as_json(eth_block_and_receipts)?,
+        format,
     )
 }
 
@@ -240,7 +258,8 @@ mod tests {
     fn should_encode_eth_block_and_receipts_as_json_bytes() {
         let block_and_receipts = get_sample_eth_block_and_receipts();
         if let Err(e) = encode_eth_block_and_receipts_as_json_bytes(
-            &block_and_receipts
+            &block_and_receipts,
+            DbSerializationFormat::Json,
         ) {
             panic!("Error encoding eth block and receipts as json bytes: {}", e)
         }
@@ -250,7 +269,8 @@ mod tests {
     fn should_decode_block_and_recipts_json_correctly() {
         let block_and_receipts = get_sample_eth_block_and_receipts();
         let bytes = encode_eth_block_and_receipts_as_json_bytes(
-            &block_and_receipts
+            &block_and_receipts,
+            DbSerializationFormat::Json,
         ).unwrap();
         let result = decode_eth_block_and_receipts_from_json_bytes(bytes)
             .unwrap();
@@ -1,59 +1,70 @@
 use crate::{
     types::Result,
-    errors::AppError,
+    state_slot::StateSlot,
+    skipped_items::SkippedItems,
+    stage_timing::StageTimings,
     traits::{
         DatabaseInterface,
     },
     eth::eth_types::{
         EthHash,
         RedeemParams,
+        EthReorgReport,
+        EthReceiptProofs,
         EthBlockAndReceipts,
     },
     btc::btc_types::{
         BtcTransactions,
         BtcUtxosAndValues,
     },
-    utils::{
-        get_not_in_state_err,
-        get_no_overwrite_state_err,
-    },
 };
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct EthState<D: DatabaseInterface> {
     pub db: D,
-    pub misc: Option<String>,
+    pub dry_run: bool,
+    pub misc: StateSlot<String>,
+    pub stage_timings: StageTimings,
+    pub skipped_items: SkippedItems,
     pub redeem_params: Vec<RedeemParams>,
-    pub btc_transactions: Option<BtcTransactions>,
-    pub btc_utxos_and_values: Option<BtcUtxosAndValues>,
-    pub eth_block_and_receipts: Option<EthBlockAndReceipts>,
+    pub btc_transactions: StateSlot<BtcTransactions>,
+    pub eth_reorg_report: StateSlot<EthReorgReport>,
+    pub eth_receipt_proofs: StateSlot<EthReceiptProofs>,
+    pub btc_utxos_and_values: StateSlot<BtcUtxosAndValues>,
+    pub spent_btc_utxos_and_values: StateSlot<BtcUtxosAndValues>,
+    pub eth_block_and_receipts: StateSlot<EthBlockAndReceipts>,
+    pub filtered_eth_block_and_receipts: StateSlot<EthBlockAndReceipts>,
 }
 
 impl<D> EthState<D> where D: DatabaseInterface {
     pub fn init(db: D) -> EthState<D> {
         EthState {
             db,
-            misc: None,
-            btc_transactions: None,
+            dry_run: false,
+            stage_timings: Vec::new(),
+            skipped_items: Vec::new(),
             redeem_params: Vec::new(),
-            btc_utxos_and_values: None,
-            eth_block_and_receipts: None,
+            misc: StateSlot::empty("misc_string"),
+            btc_transactions: StateSlot::empty("btc_transactions"),
+            eth_reorg_report: StateSlot::empty("eth_reorg_report"),
+            eth_receipt_proofs: StateSlot::empty("eth_receipt_proofs"),
+            btc_utxos_and_values: StateSlot::empty("btc_utxos_and_values"),
+            spent_btc_utxos_and_values:
+                StateSlot::empty("spent_btc_utxos_and_values"),
+            eth_block_and_receipts: StateSlot::empty("eth_block_and_receipts"),
+            filtered_eth_block_and_receipts:
+                StateSlot::empty("filtered_eth_block_and_receipts"),
         }
     }
 
     pub fn add_eth_block_and_receipts(
         mut self,
-        eth_block_and_receipts: EthBlockAndReceipts
+        eth_block_and_receipts: EthBlockAndReceipts,
     ) -> Result<EthState<D>> {
-        match self.eth_block_and_receipts {
-            Some(_) => Err(AppError::Custom(
-                get_no_overwrite_state_err("eth_block_and_receipts"))
-            ),
-            None => {
-                self.eth_block_and_receipts = Some(eth_block_and_receipts);
-                Ok(self)
-            }
-        }
+        self.eth_block_and_receipts = self
+            .eth_block_and_receipts
+            .fill(eth_block_and_receipts)?;
+        Ok(self)
     }
 
     pub fn add_redeem_params(
@@ -65,6 +76,15 @@ impl<D> EthState<D> where D: DatabaseInterface {
         Ok(self)
     }
 
+    pub fn add_skipped_items(
+        mut self,
+        mut skipped_items: SkippedItems,
+    ) -> Result<EthState<D>> {
+        info!("✔ Adding {} skipped items to ETH state...", skipped_items.len());
+        self.skipped_items.append(&mut skipped_items);
+        Ok(self)
+    }
+
     pub fn replace_redeem_params(
         mut self,
         replacement_params: Vec<RedeemParams>,
@@ -75,70 +95,86 @@ impl<D> EthState<D> where D: DatabaseInterface {
 
     pub fn add_misc_string_to_state(
         mut self,
-        misc_string: String
+        misc_string: String,
     ) -> Result<EthState<D>> {
-        match self.misc {
-            Some(_) => Err(AppError::Custom(
-                get_no_overwrite_state_err("misc_string"))
-            ),
-            None => {
-                self.misc = Some(misc_string);
-                Ok(self)
-            }
-        }
+        self.misc = self.misc.fill(misc_string)?;
+        Ok(self)
     }
 
     pub fn add_btc_transactions(
         mut self,
-        btc_transactions: BtcTransactions
+        btc_transactions: BtcTransactions,
     ) -> Result<EthState<D>> {
-        match self.btc_transactions {
-            Some(_) => Err(AppError::Custom(
-                get_no_overwrite_state_err("btc_transaction"))
-            ),
-            None => {
-                self.btc_transactions = Some(btc_transactions);
-                Ok(self)
-            }
-        }
+        self.btc_transactions = self.btc_transactions.fill(btc_transactions)?;
+        Ok(self)
     }
 
     pub fn add_btc_utxos_and_values(
         mut self,
         btc_utxos_and_values: BtcUtxosAndValues,
     ) -> Result<EthState<D>> {
-        match self.btc_utxos_and_values {
-            Some(_) => Err(AppError::Custom(
-                get_no_overwrite_state_err("btc_utxos_and_values"))
-            ),
-            None => {
-                self.btc_utxos_and_values = Some(btc_utxos_and_values);
-                Ok(self)
-            }
-        }
+        self.btc_utxos_and_values = self
+            .btc_utxos_and_values
+            .fill(btc_utxos_and_values)?;
+        Ok(self)
     }
 
-    pub fn update_eth_block_and_receipts(
+    pub fn add_spent_btc_utxos_and_values(
         mut self,
-        new_eth_block_and_receipts: EthBlockAndReceipts
+        spent_btc_utxos_and_values: BtcUtxosAndValues,
     ) -> Result<EthState<D>> {
-        self.eth_block_and_receipts = Some(new_eth_block_and_receipts);
+        self.spent_btc_utxos_and_values = self
+            .spent_btc_utxos_and_values
+            .fill(spent_btc_utxos_and_values)?;
         Ok(self)
     }
 
+    pub fn add_eth_receipt_proofs(
+        mut self,
+        eth_receipt_proofs: EthReceiptProofs,
+    ) -> Result<EthState<D>> {
+        self.eth_receipt_proofs = self
+            .eth_receipt_proofs
+            .fill(eth_receipt_proofs)?;
+        Ok(self)
+    }
+
+    pub fn add_eth_reorg_report(
+        mut self,
+        eth_reorg_report: EthReorgReport,
+    ) -> Result<EthState<D>> {
+        self.eth_reorg_report = self.eth_reorg_report.fill(eth_reorg_report)?;
+        Ok(self)
+    }
+
+    pub fn add_filtered_eth_block_and_receipts(
+        mut self,
+        filtered_eth_block_and_receipts: EthBlockAndReceipts
+    ) -> Result<EthState<D>> {
+        self.filtered_eth_block_and_receipts = self
+            .filtered_eth_block_and_receipts
+            .fill(filtered_eth_block_and_receipts)?;
+        Ok(self)
+    }
+
+    // NOTE: Stages after the receipts are filtered see only the filtered ones,
+    // whilst the submitted block & receipts are kept as they were.
     pub fn get_eth_block_and_receipts(
         &self
     ) -> Result<&EthBlockAndReceipts> {
-        match &self.eth_block_and_receipts {
-            Some(eth_block_and_receipts) => Ok(&eth_block_and_receipts),
-            None => Err(AppError::Custom(
-                get_not_in_state_err("eth_block_and_receipts"))
-            )
+        match self.filtered_eth_block_and_receipts.as_option() {
+            Some(filtered_eth_block_and_receipts) =>
+                Ok(filtered_eth_block_and_receipts),
+            None => self.eth_block_and_receipts.get(),
         }
     }
 
+    pub fn get_eth_receipt_proofs(&self) -> Result<&EthReceiptProofs> {
+        self.eth_receipt_proofs.get()
+    }
+
     pub fn get_misc_string(&self) -> Result<String> {
-        match &self.misc {
+        match self.misc.as_option() {
             None => Ok("".to_string()),
             Some(misc) => Ok(misc.to_string()),
         }
@@ -158,23 +194,23 @@ impl<D> EthState<D> where D: DatabaseInterface {
 mod tests {
     use super::*;
     use crate::{
+        errors::AppError,
         test_utils::get_test_database,
         eth::eth_test_utils::{
             get_expected_block,
             get_expected_receipt,
             SAMPLE_RECEIPT_INDEX,
             get_sample_eth_block_and_receipts,
-            get_sample_eth_block_and_receipts_n,
             get_valid_state_with_block_and_receipts,
         },
     };
 
     #[test]
     fn should_fail_to_get_eth_block_and_receipts_in_state() {
-        let expected_error = get_not_in_state_err("eth_block_and_receipts");
         let initial_state = EthState::init(get_test_database());
         match initial_state.get_eth_block_and_receipts() {
-            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            Err(AppError::NotInState(name)) =>
+                assert_eq!(name, "eth_block_and_receipts"),
             Ok(_) => panic!("Eth block should not be in state yet!"),
             Err(_) => panic!("Wrong error received!")
         };
@@ -182,11 +218,11 @@ mod tests {
 
     #[test]
     fn should_add_eth_block_and_receipts_state() {
-        let expected_error = get_not_in_state_err("eth_block_and_receipts");
         let eth_block_and_receipts = get_sample_eth_block_and_receipts();
         let initial_state = EthState::init(get_test_database());
         match initial_state.get_eth_block_and_receipts() {
-            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            Err(AppError::NotInState(name)) =>
+                assert_eq!(name, "eth_block_and_receipts"),
             Ok(_) => panic!("Eth block should not be in state yet!"),
             Err(_) => panic!("Wrong error received!")
         };
@@ -212,8 +248,6 @@ mod tests {
 
     #[test]
     fn should_err_when_overwriting_eth_block_and_receipts_in_state() {
-        let expected_error = get_no_overwrite_state_err(
-            "eth_block_and_receipts");
         let eth_block_and_receipts = get_sample_eth_block_and_receipts();
         let initial_state = EthState::init(get_test_database());
         let updated_state = initial_state.add_eth_block_and_receipts(
@@ -224,34 +258,46 @@ mod tests {
             eth_block_and_receipts
         ) {
             Ok(_) => panic!("Overwriting state should not have succeeded!"),
-            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            Err(AppError::CannotOverwriteState(name)) =>
+                assert_eq!(name, "eth_block_and_receipts"),
             Err(_) => panic!("Wrong error recieved!")
         }
     }
 
     #[test]
-    fn should_update_eth_block_and_receipts() {
-        let eth_block_and_receipts_1 = get_sample_eth_block_and_receipts_n(0)
+    fn should_get_filtered_eth_block_and_receipts_once_added() {
+        let eth_block_and_receipts = get_sample_eth_block_and_receipts();
+        let filtered_eth_block_and_receipts = EthBlockAndReceipts {
+            block: eth_block_and_receipts.block.clone(),
+            receipts: vec![],
+        };
+        let state = EthState::init(get_test_database())
+            .add_eth_block_and_receipts(eth_block_and_receipts.clone())
             .unwrap();
-        let eth_block_and_receipts_2 = get_sample_eth_block_and_receipts_n(1)
+        assert_eq!(
+            state.get_eth_block_and_receipts().unwrap(),
+            &eth_block_and_receipts,
+        );
+        let state = state
+            .add_filtered_eth_block_and_receipts(
+                filtered_eth_block_and_receipts.clone()
+            )
             .unwrap();
-        let initial_state = EthState::init(get_test_database());
-        let updated_state = initial_state.add_eth_block_and_receipts(
-            eth_block_and_receipts_1
-        ).unwrap();
-        let initial_state_block_num = updated_state.get_eth_block_and_receipts()
-            .unwrap()
-            .block
-            .number
-            .clone();
-        let final_state = updated_state.update_eth_block_and_receipts(
-            eth_block_and_receipts_2
-        ).unwrap();
-        let final_state_block_number = final_state.get_eth_block_and_receipts()
-            .unwrap()
-            .block
-            .number;
-        assert_ne!(final_state_block_number, initial_state_block_num);
+        assert_eq!(
+            state.get_eth_block_and_receipts().unwrap(),
+            &filtered_eth_block_and_receipts,
+        );
+        assert_eq!(
+            state.eth_block_and_receipts.get().unwrap(),
+            &eth_block_and_receipts,
+        );
+        match state.add_filtered_eth_block_and_receipts(
+            filtered_eth_block_and_receipts
+        ) {
+            Err(AppError::CannotOverwriteState(name)) =>
+                assert_eq!(name, "filtered_eth_block_and_receipts"),
+            _ => panic!("Filtered block should not be added twice!"),
+        }
     }
 
     #[test]
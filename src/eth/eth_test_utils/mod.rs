@@ -81,6 +81,8 @@ pub const SAMPLE_BLOCK_AND_RECEIPT_JSON: &str =
     "src/eth/eth_test_utils/sample-eth-block-and-receipts-json";
 pub const SAMPLE_ROPSTEN_BLOCK_AND_RECEIPTS_JSON: &str =
     "src/eth/eth_test_utils/sample-ropsten-eth-block-and-receipts.json";
+pub const SAMPLE_BLOCK_AND_RECEIPT_PROOFS_JSON: &str =
+    "src/eth/eth_test_utils/sample-eth-block-and-receipt-proofs-json";
 pub const SAMPLE_INVALID_BLOCK_AND_RECEIPT_JSON: &str =
     "src/eth/eth_test_utils/sample-invalid-eth-block-and-receipts-json";
 pub const ROPSTEN_CONTRACT_TOPIC: &str =
@@ -422,6 +424,10 @@ pub fn get_sample_ropsten_eth_block_and_receipts() -> EthBlockAndReceipts {
     parse_eth_block_and_receipts(&string).unwrap()
 }
 
+pub fn get_sample_eth_block_and_receipt_proofs_string() -> Result<String> {
+    Ok(read_to_string(SAMPLE_BLOCK_AND_RECEIPT_PROOFS_JSON)?)
+}
+
 pub fn get_sample_eth_block_and_receipts() -> EthBlockAndReceipts {
     let string = get_sample_eth_block_and_receipts_string(0).unwrap();
     parse_eth_block_and_receipts(&string).unwrap()
@@ -1,4 +1,10 @@
-use std::collections::HashMap;
+use std::{
+    fmt,
+    borrow::Cow,
+    mem,
+    str::FromStr,
+    collections::HashMap,
+};
 use ethereum_types::{
     H256,
     U256,
@@ -6,10 +12,27 @@ use ethereum_types::{
     Address,
 };
 use crate::{
-    types::Bytes,
+    errors::AppError,
+    cow_json::{
+        deserialize_cow_strs,
+        deserialize_maybe_cow_str,
+        deserialize_maybe_cow_str_pair,
+        deserialize_maybe_nested_cow_strs,
+    },
+    correlation_id::get_correlation_id,
+    types::{
+        Byte,
+        Bytes,
+        Result,
+    },
+    btc::btc_types::BtcUtxosAndValues,
     eth::{
         trie_nodes::Node,
         eth_crypto::eth_transaction::EthTransaction,
+        eth_constants::{
+            ETH_WORD_SIZE_IN_BYTES,
+            ETH_BLOCK_HEADER_IN_DB_FORMAT_LENGTH,
+        },
     },
 };
 
@@ -17,21 +40,307 @@ pub type EthHash = H256;
 pub type EthAddress = Address;
 pub type EthTopics = EthHashes;
 pub type NodeStack = Vec<Node>;
+#[cfg(test)]
 pub type EthLogs = Vec<EthLog>;
 pub type EthSignature = [u8; 65];
 pub type EthHashes = Vec<EthHash>;
 pub type EthReceipts = Vec<EthReceipt>;
+pub type EthReceiptProof = Vec<Bytes>;
 pub type EthSignedTransaction = String;
 pub type ChildNodes = [Option<Bytes>; 16];
 pub type TrieHashMap = HashMap<H256, Bytes>;
 pub type EthTransactions = Vec<EthTransaction>;
+pub type EthReceiptProofs = Vec<EthReceiptProof>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EthTransactionType {
+    Legacy,
+    DynamicFee,
+}
+
+impl Default for EthTransactionType {
+    fn default() -> Self {
+        EthTransactionType::Legacy
+    }
+}
+
+impl EthTransactionType {
+    pub fn to_byte(&self) -> Byte {
+        match self {
+            EthTransactionType::Legacy => 0,
+            EthTransactionType::DynamicFee => 2, // NOTE: Per EIP2718 & EIP1559
+        }
+    }
+
+    pub fn from_byte(byte: &Byte) -> Result<Self> {
+        match byte {
+            0 => Ok(EthTransactionType::Legacy),
+            2 => Ok(EthTransactionType::DynamicFee),
+            _ => Err(AppError::InvalidInput(
+                format!("✘ Unrecognized ETH transaction type: {}!", byte)
+            ))
+        }
+    }
+}
+
+impl FromStr for EthTransactionType {
+    type Err = AppError;
+
+    fn from_str(tx_type: &str) -> Result<Self> {
+        match tx_type {
+            "legacy" => Ok(EthTransactionType::Legacy),
+            "dynamic-fee" => Ok(EthTransactionType::DynamicFee),
+            _ => Err(AppError::InvalidInput(
+                format!("✘ Unrecognized ETH transaction type: {}!", tx_type)
+            ))
+        }
+    }
+}
+
+impl fmt::Display for EthTransactionType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EthTransactionType::Legacy => write!(f, "legacy"),
+            EthTransactionType::DynamicFee => write!(f, "dynamic-fee"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PTokenContractVariant {
+    Erc777,
+    Erc777WithOperators,
+}
+
+impl PTokenContractVariant {
+    pub fn to_byte(&self) -> Byte {
+        match self {
+            PTokenContractVariant::Erc777 => 0,
+            PTokenContractVariant::Erc777WithOperators => 1,
+        }
+    }
+
+    pub fn from_byte(byte: &Byte) -> Result<Self> {
+        match byte {
+            0 => Ok(PTokenContractVariant::Erc777),
+            1 => Ok(PTokenContractVariant::Erc777WithOperators),
+            _ => Err(AppError::InvalidInput(
+                format!("✘ Unrecognized pToken contract variant: {}!", byte)
+            ))
+        }
+    }
+
+    pub fn supports_batch_minting(&self) -> bool {
+        match self {
+            PTokenContractVariant::Erc777 => true,
+            PTokenContractVariant::Erc777WithOperators => false,
+        }
+    }
+}
+
+impl FromStr for PTokenContractVariant {
+    type Err = AppError;
+
+    fn from_str(variant: &str) -> Result<Self> {
+        match variant {
+            "erc777" => Ok(PTokenContractVariant::Erc777),
+            "erc777-operator" => Ok(PTokenContractVariant::Erc777WithOperators),
+            _ => Err(AppError::InvalidInput(
+                format!("✘ Unrecognized pToken contract variant: {}!", variant)
+            ))
+        }
+    }
+}
+
+impl fmt::Display for PTokenContractVariant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PTokenContractVariant::Erc777 => write!(f, "erc777"),
+            PTokenContractVariant::Erc777WithOperators =>
+                write!(f, "erc777-operator"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EthMintTxRecord {
+    pub to: Bytes,
+    pub data: Bytes,
+    pub nonce: u64,
+    pub gas_price: u64,
+    // NOTE: Defaulted so records written before these fields existed still
+    // deserialize, a zero gas limit meaning the db's mint gas limit applies.
+    #[serde(default)]
+    pub gas_limit: u64,
+    #[serde(default)]
+    pub tx_hash: String,
+    #[serde(default)]
+    pub tx_type: EthTransactionType,
+    #[serde(default)]
+    pub max_priority_fee_per_gas: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub correlation_ids: Vec<String>,
+}
+
+impl EthMintTxRecord {
+    pub fn from_eth_tx(eth_tx: &EthTransaction) -> EthMintTxRecord {
+        EthMintTxRecord {
+            to: eth_tx.to.clone(),
+            data: eth_tx.data.clone(),
+            tx_type: eth_tx.tx_type,
+            nonce: eth_tx.nonce.as_u64(),
+            gas_limit: eth_tx.gas_limit.as_u64(),
+            tx_hash: format!("0x{}", eth_tx.get_tx_hash()),
+            max_priority_fee_per_gas: eth_tx.max_priority_fee_per_gas.as_u64(),
+            gas_price: match eth_tx.tx_type {
+                EthTransactionType::Legacy => eth_tx.gas_price.as_u64(),
+                EthTransactionType::DynamicFee =>
+                    eth_tx.max_fee_per_gas.as_u64(),
+            },
+            correlation_ids: vec![],
+        }
+    }
+
+    pub fn with_correlation_ids(self, correlation_ids: Vec<String>) -> Self {
+        EthMintTxRecord { correlation_ids, ..self }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EthPendingTxStatus {
+    Signed,
+    Reported,
+    Confirmed,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EthReceiptBlock {
+    pub number: u64,
+    pub hash: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EthPendingTx {
+    pub nonce: u64,
+    pub tx_hex: String,
+    pub tx_hash: String,
+    pub status: EthPendingTxStatus,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub replaced_tx_hashes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub receipt_block: Option<EthReceiptBlock>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub correlation_ids: Vec<String>,
+}
+
+impl EthPendingTx {
+    pub fn from_eth_tx(eth_tx: &EthTransaction) -> EthPendingTx {
+        EthPendingTx {
+            receipt_block: None,
+            correlation_ids: vec![],
+            replaced_tx_hashes: vec![],
+            nonce: eth_tx.nonce.as_u64(),
+            tx_hex: eth_tx.serialize_hex(),
+            status: EthPendingTxStatus::Signed,
+            tx_hash: format!("0x{}", eth_tx.get_tx_hash()),
+        }
+    }
+
+    pub fn with_correlation_ids(self, correlation_ids: Vec<String>) -> Self {
+        EthPendingTx { correlation_ids, ..self }
+    }
+
+    // NOTE: Any of the txs signed for this nonce may be the one mined, so those
+    // it replaced are still tracked, as are the mints they carry.
+    pub fn replacing(mut self, previous_tx: &EthPendingTx) -> EthPendingTx {
+        self.replaced_tx_hashes = previous_tx
+            .replaced_tx_hashes
+            .iter()
+            .chain(std::iter::once(&previous_tx.tx_hash))
+            .filter(|tx_hash| **tx_hash != self.tx_hash)
+            .cloned()
+            .collect();
+        self.receipt_block = previous_tx.receipt_block.clone();
+        self.correlation_ids = previous_tx.correlation_ids.clone();
+        self
+    }
+
+    pub fn has_receipt_in(&self, receipts: &[EthReceipt]) -> bool {
+        receipts
+            .iter()
+            .map(|receipt|
+                format!("0x{}", hex::encode(receipt.transaction_hash))
+            )
+            .any(|hash|
+                hash == self.tx_hash || self.replaced_tx_hashes.contains(&hash)
+            )
+    }
+}
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EthRedeemInfo {
+    pub amount: String,
+    pub from: String,
+    pub recipient: String,
+    pub originating_tx_hash: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub correlation_id: String,
+}
+
+impl EthRedeemInfo {
+    pub fn from_redeem_params(redeem_params: &RedeemParams) -> EthRedeemInfo {
+        EthRedeemInfo {
+            amount: redeem_params.amount.to_string(),
+            recipient: redeem_params.recipient.clone(),
+            from: format!("0x{}", hex::encode(redeem_params.from.as_bytes())),
+            originating_tx_hash: format!(
+                "0x{}",
+                hex::encode(redeem_params.originating_tx_hash.as_bytes())
+            ),
+            correlation_id: redeem_params.correlation_id.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EthRedeemRecord {
+    pub block_hash: String,
+    pub block_number: u64,
+    pub btc_tx_hashes: Vec<String>,
+    pub redeems: Vec<EthRedeemInfo>,
+    #[serde(default)]
+    pub spent_utxos: BtcUtxosAndValues,
+    #[serde(default)]
+    pub change_utxos: BtcUtxosAndValues,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EthReorgReport {
+    pub old_canon_block_hash: String,
+    pub new_canon_block_hash: String,
+    pub common_ancestor_hash: String,
+    pub orphaned_block_hashes: Vec<String>,
+    pub orphaned_redeems: Vec<EthRedeemRecord>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EthContractAddressUpgrade {
+    pub new_address: String,
+    pub eth_block_number: u64,
+    pub previous_address: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct RedeemParams {
     pub amount: U256,
     pub from: EthAddress,
     pub recipient: String,
     pub originating_tx_hash: EthHash,
+    pub recipient_diversion_reason: Option<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub correlation_id: String,
+    #[serde(default)]
+    pub peg_out_fee: u64,
 }
 
 impl RedeemParams {
@@ -41,7 +350,30 @@ impl RedeemParams {
         recipient: String,
         originating_tx_hash: EthHash,
     ) -> RedeemParams {
-        RedeemParams { amount, recipient, originating_tx_hash, from }
+        RedeemParams {
+            amount,
+            recipient,
+            originating_tx_hash,
+            from,
+            recipient_diversion_reason: None,
+            correlation_id: String::new(),
+            peg_out_fee: 0,
+        }
+    }
+
+    pub fn get_gross_amount(&self) -> u64 {
+        self.amount.as_u64() + self.peg_out_fee
+    }
+
+    pub fn with_correlation_id(self, log_index: usize) -> Self {
+        RedeemParams {
+            correlation_id: get_correlation_id(
+                "ETH",
+                &format!("0x{}", hex::encode(self.originating_tx_hash)),
+                log_index,
+            ),
+            ..self
+        }
     }
 }
 
@@ -51,10 +383,40 @@ pub struct EthBlockAndReceipts {
     pub receipts: Vec<EthReceipt>
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl EthBlockAndReceipts {
+    pub fn get_size_in_bytes(&self) -> usize {
+        self.block.get_size_in_bytes() + self
+            .receipts
+            .iter()
+            .map(|receipt| receipt.get_size_in_bytes())
+            .sum::<usize>()
+    }
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EthBlockAndReceiptsJson {
     pub block: EthBlockJson,
-    pub receipts: Vec<EthReceiptJson>
+    pub receipts: Vec<EthReceiptJson>,
+    #[serde(default)]
+    pub receiptProofs: Option<Vec<Vec<String>>>,
+}
+
+// NOTE: Borrows its strings from the submission rather than copying them,
+// save for any containing JSON escapes, which are unescaped into owned copies.
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Deserialize)]
+pub struct EthBlockAndReceiptsJsonRef<'a> {
+    #[serde(borrow)]
+    pub block: EthBlockJsonRef<'a>,
+    #[serde(borrow)]
+    pub receipts: Vec<EthReceiptJsonRef<'a>>,
+    #[serde(
+        default,
+        borrow,
+        deserialize_with = "deserialize_maybe_nested_cow_strs",
+    )]
+    pub receiptProofs: Option<Vec<Vec<Cow<'a, str>>>>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
@@ -71,6 +433,17 @@ pub struct EthReceipt {
     pub contract_address: Address,
     pub logs: Vec<EthLog>,
     pub logs_bloom: Bloom,
+    pub receipt_type: u8,
+}
+
+impl EthReceipt {
+    pub fn get_size_in_bytes(&self) -> usize {
+        mem::size_of::<Self>() + self
+            .logs
+            .iter()
+            .map(|log| log.get_size_in_bytes())
+            .sum::<usize>()
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
@@ -96,10 +469,72 @@ pub struct EthBlock {
     pub transactions: Vec<H256>,
     pub transactions_root: H256,
     pub uncles: Vec<H256>,
+    pub base_fee_per_gas: Option<U256>,
+    pub withdrawals_root: Option<H256>,
+    pub blob_gas_used: Option<U256>,
+    pub excess_blob_gas: Option<U256>,
+    pub parent_beacon_block_root: Option<H256>,
+    pub requests_hash: Option<H256>,
+}
+
+impl EthBlock {
+    pub fn get_size_in_bytes(&self) -> usize {
+        mem::size_of::<Self>()
+            + self.nonce.len()
+            + self.extra_data.len()
+            + self.seal_fields.0.len()
+            + self.uncles.len() * mem::size_of::<H256>()
+            + self.transactions.len() * mem::size_of::<H256>()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EthBlockHeaderInDbFormat {
+    pub hash: H256,
+    pub number: U256,
+    pub parent_hash: H256,
+}
+
+impl EthBlockHeaderInDbFormat {
+    pub fn from_eth_block(eth_block: &EthBlock) -> Self {
+        EthBlockHeaderInDbFormat {
+            hash: eth_block.hash,
+            number: eth_block.number,
+            parent_hash: eth_block.parent_hash,
+        }
+    }
+
+    pub fn to_bytes(&self) -> Bytes {
+        let mut number = [0u8; ETH_WORD_SIZE_IN_BYTES];
+        self.number.to_big_endian(&mut number);
+        [
+            self.hash.as_bytes(),
+            self.parent_hash.as_bytes(),
+            &number[..],
+        ].concat()
+    }
+
+    pub fn from_bytes(bytes: &[Byte]) -> Result<Self> {
+        match bytes.len() == ETH_BLOCK_HEADER_IN_DB_FORMAT_LENGTH {
+            false => Err(AppError::ConversionError(
+                format!(
+                    "✘ ETH block header in db format must be {} bytes long!",
+                    ETH_BLOCK_HEADER_IN_DB_FORMAT_LENGTH,
+                )
+            )),
+            true => Ok(
+                EthBlockHeaderInDbFormat {
+                    hash: H256::from_slice(&bytes[..32]),
+                    parent_hash: H256::from_slice(&bytes[32..64]),
+                    number: U256::from_big_endian(&bytes[64..]),
+                }
+            ),
+        }
+    }
 }
 
 #[allow(non_snake_case)]
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EthBlockJson {
     pub difficulty: String,
     pub extraData: String,
@@ -113,7 +548,8 @@ pub struct EthBlockJson {
     pub number: usize,
     pub parentHash: String,
     pub receiptsRoot: String,
-    pub sealFields: (String, String),
+    #[serde(default)]
+    pub sealFields: Option<(String, String)>,
     pub sha3Uncles: String,
     pub size: usize,
     pub stateRoot: String,
@@ -122,10 +558,22 @@ pub struct EthBlockJson {
     pub transactions: Vec<String>,
     pub transactionsRoot: String,
     pub uncles: Vec<String>,
+    #[serde(default)]
+    pub baseFeePerGas: Option<String>,
+    #[serde(default)]
+    pub withdrawalsRoot: Option<String>,
+    #[serde(default)]
+    pub blobGasUsed: Option<String>,
+    #[serde(default)]
+    pub excessBlobGas: Option<String>,
+    #[serde(default)]
+    pub parentBeaconBlockRoot: Option<String>,
+    #[serde(default)]
+    pub requestsHash: Option<String>,
 }
 
 #[allow(non_snake_case)]
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EthReceiptJson {
     pub from: String,
     pub status: bool,
@@ -139,19 +587,191 @@ pub struct EthReceiptJson {
     pub transactionIndex: usize,
     pub cumulativeGasUsed: usize,
     pub contractAddress: serde_json::Value,
+    #[serde(default, rename = "type")]
+    pub receiptType: Option<String>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Deserialize)]
+pub struct EthBlockJsonRef<'a> {
+    #[serde(borrow)]
+    pub difficulty: Cow<'a, str>,
+    #[serde(borrow)]
+    pub extraData: Cow<'a, str>,
+    pub gasLimit: usize,
+    pub gasUsed: usize,
+    #[serde(borrow)]
+    pub hash: Cow<'a, str>,
+    #[serde(borrow)]
+    pub logsBloom: Cow<'a, str>,
+    #[serde(borrow)]
+    pub miner: Cow<'a, str>,
+    #[serde(borrow)]
+    pub mixHash: Cow<'a, str>,
+    #[serde(borrow)]
+    pub nonce: Cow<'a, str>,
+    pub number: usize,
+    #[serde(borrow)]
+    pub parentHash: Cow<'a, str>,
+    #[serde(borrow)]
+    pub receiptsRoot: Cow<'a, str>,
+    #[serde(
+        default,
+        borrow,
+        deserialize_with = "deserialize_maybe_cow_str_pair",
+    )]
+    pub sealFields: Option<(Cow<'a, str>, Cow<'a, str>)>,
+    #[serde(borrow)]
+    pub sha3Uncles: Cow<'a, str>,
+    pub size: usize,
+    #[serde(borrow)]
+    pub stateRoot: Cow<'a, str>,
+    pub timestamp: usize,
+    #[serde(borrow)]
+    pub totalDifficulty: Cow<'a, str>,
+    #[serde(borrow, deserialize_with = "deserialize_cow_strs")]
+    pub transactions: Vec<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub transactionsRoot: Cow<'a, str>,
+    #[serde(borrow, deserialize_with = "deserialize_cow_strs")]
+    pub uncles: Vec<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "deserialize_maybe_cow_str")]
+    pub baseFeePerGas: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "deserialize_maybe_cow_str")]
+    pub withdrawalsRoot: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "deserialize_maybe_cow_str")]
+    pub blobGasUsed: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "deserialize_maybe_cow_str")]
+    pub excessBlobGas: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "deserialize_maybe_cow_str")]
+    pub parentBeaconBlockRoot: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "deserialize_maybe_cow_str")]
+    pub requestsHash: Option<Cow<'a, str>>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Deserialize)]
+pub struct EthReceiptJsonRef<'a> {
+    #[serde(borrow)]
+    pub from: Cow<'a, str>,
+    pub status: bool,
+    pub gasUsed: usize,
+    #[serde(borrow)]
+    pub blockHash: Cow<'a, str>,
+    #[serde(borrow)]
+    pub logsBloom: Cow<'a, str>,
+    #[serde(borrow)]
+    pub logs: Vec<EthLogJsonRef<'a>>,
+    pub blockNumber: usize,
+    #[serde(default, borrow, deserialize_with = "deserialize_maybe_cow_str")]
+    pub to: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub transactionHash: Cow<'a, str>,
+    pub transactionIndex: usize,
+    pub cumulativeGasUsed: usize,
+    #[serde(default, borrow, deserialize_with = "deserialize_maybe_cow_str")]
+    pub contractAddress: Option<Cow<'a, str>>,
+    #[serde(
+        default,
+        borrow,
+        rename = "type",
+        deserialize_with = "deserialize_maybe_cow_str",
+    )]
+    pub receiptType: Option<Cow<'a, str>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EthRpcSubmissionJson {
+    pub block: EthRpcBlockJson,
+    pub receipts: Vec<EthRpcReceiptJson>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Deserialize)]
+pub struct EthRpcBlockJson {
+    pub difficulty: String,
+    pub extraData: String,
+    pub gasLimit: String,
+    pub gasUsed: String,
+    pub hash: String,
+    pub logsBloom: String,
+    pub miner: String,
+    pub mixHash: String,
+    pub nonce: String,
+    pub number: String,
+    pub parentHash: String,
+    pub receiptsRoot: String,
+    pub sha3Uncles: String,
+    pub size: String,
+    pub stateRoot: String,
+    pub timestamp: String,
+    #[serde(default)]
+    pub totalDifficulty: Option<String>,
+    pub transactions: Vec<serde_json::Value>,
+    pub transactionsRoot: String,
+    pub uncles: Vec<String>,
+    #[serde(default)]
+    pub baseFeePerGas: Option<String>,
+    #[serde(default)]
+    pub withdrawalsRoot: Option<String>,
+    #[serde(default)]
+    pub blobGasUsed: Option<String>,
+    #[serde(default)]
+    pub excessBlobGas: Option<String>,
+    #[serde(default)]
+    pub parentBeaconBlockRoot: Option<String>,
+    #[serde(default)]
+    pub requestsHash: Option<String>,
 }
 
 #[allow(non_snake_case)]
 #[derive(Clone, Debug, Deserialize)]
+pub struct EthRpcReceiptJson {
+    pub from: String,
+    pub status: String,
+    pub gasUsed: String,
+    pub blockHash: String,
+    pub logsBloom: String,
+    pub logs: Vec<EthLogJson>,
+    pub blockNumber: String,
+    pub to: serde_json::Value,
+    pub transactionHash: String,
+    pub transactionIndex: String,
+    pub cumulativeGasUsed: String,
+    pub contractAddress: serde_json::Value,
+    #[serde(default, rename = "type")]
+    pub receiptType: Option<String>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EthLogJson {
     pub data: String,
     pub address: String,
     pub topics: Vec<String>,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+pub struct EthLogJsonRef<'a> {
+    #[serde(borrow)]
+    pub data: Cow<'a, str>,
+    #[serde(borrow)]
+    pub address: Cow<'a, str>,
+    #[serde(borrow, deserialize_with = "deserialize_cow_strs")]
+    pub topics: Vec<Cow<'a, str>>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
 pub struct EthLog {
     pub address: Address,
     pub topics: Vec<H256>,
     pub data: Bytes,
 }
+
+impl EthLog {
+    pub fn get_size_in_bytes(&self) -> usize {
+        mem::size_of::<Self>()
+            + self.data.len()
+            + self.topics.len() * mem::size_of::<H256>()
+    }
+}
@@ -0,0 +1,203 @@
+use ethereum_types::{
+    H256,
+    U256,
+    U512,
+};
+use tiny_keccak::{
+    keccak256,
+    keccak512,
+};
+
+type Hash512 = [u32; 16];
+
+const HASH_BYTES: u64 = 64;
+const MIX_BYTES: u64 = 128;
+const ACCESSES: u32 = 64;
+const CACHE_ROUNDS: usize = 3;
+const EPOCH_LENGTH: u64 = 30_000;
+const DATASET_PARENTS: u32 = 256;
+const FNV_PRIME: u32 = 0x0100_0193;
+const CACHE_BYTES_INIT: u64 = 1 << 24;
+const CACHE_BYTES_GROWTH: u64 = 1 << 17;
+const DATASET_BYTES_INIT: u64 = 1 << 30;
+const DATASET_BYTES_GROWTH: u64 = 1 << 23;
+
+fn fnv(a: u32, b: u32) -> u32 {
+    a.wrapping_mul(FNV_PRIME) ^ b
+}
+
+fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false
+    };
+    let mut i = 2;
+    while i * i <= n {
+        if n % i == 0 {
+            return false
+        };
+        i += 1;
+    }
+    true
+}
+
+fn convert_bytes_to_words(bytes: &[u8]) -> Hash512 {
+    let mut words = [0u32; 16];
+    words
+        .iter_mut()
+        .zip(bytes.chunks(4))
+        .for_each(|(word, chunk)|
+            *word = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]])
+        );
+    words
+}
+
+fn convert_words_to_bytes(words: &[u32]) -> Vec<u8> {
+    words
+        .iter()
+        .flat_map(|word| word.to_le_bytes().to_vec())
+        .collect()
+}
+
+fn keccak512_words(words: &Hash512) -> Hash512 {
+    convert_bytes_to_words(&keccak512(&convert_words_to_bytes(words)))
+}
+
+pub fn get_epoch(block_number: u64) -> u64 {
+    block_number / EPOCH_LENGTH
+}
+
+pub fn get_cache_size(epoch: u64) -> u64 {
+    let mut size = CACHE_BYTES_INIT + CACHE_BYTES_GROWTH * epoch - HASH_BYTES;
+    while !is_prime(size / HASH_BYTES) {
+        size -= 2 * HASH_BYTES;
+    }
+    size
+}
+
+pub fn get_full_size(epoch: u64) -> u64 {
+    let mut size =
+        DATASET_BYTES_INIT + DATASET_BYTES_GROWTH * epoch - MIX_BYTES;
+    while !is_prime(size / MIX_BYTES) {
+        size -= 2 * MIX_BYTES;
+    }
+    size
+}
+
+pub fn get_seed_hash(epoch: u64) -> [u8; 32] {
+    (0..epoch).fold([0u8; 32], |seed, _| keccak256(&seed))
+}
+
+pub fn make_cache(cache_size: u64, seed_hash: &[u8; 32]) -> Vec<Hash512> {
+    info!("✔ Generating ethash cache of {} bytes...", cache_size);
+    let n = (cache_size / HASH_BYTES) as usize;
+    let mut cache = Vec::with_capacity(n);
+    cache.push(convert_bytes_to_words(&keccak512(seed_hash)));
+    for i in 1..n {
+        let next = keccak512_words(&cache[i - 1]);
+        cache.push(next);
+    }
+    for _ in 0..CACHE_ROUNDS {
+        for i in 0..n {
+            let v = cache[i][0] as usize % n;
+            let mut xored = cache[(i + n - 1) % n];
+            xored
+                .iter_mut()
+                .zip(cache[v].iter())
+                .for_each(|(a, b)| *a ^= b);
+            cache[i] = keccak512_words(&xored);
+        }
+    }
+    cache
+}
+
+fn calculate_dataset_item(cache: &[Hash512], i: u32) -> Hash512 {
+    let n = cache.len();
+    let mut mix = cache[i as usize % n];
+    mix[0] ^= i;
+    mix = keccak512_words(&mix);
+    for j in 0..DATASET_PARENTS {
+        let parent = cache[fnv(i ^ j, mix[j as usize % 16]) as usize % n];
+        mix
+            .iter_mut()
+            .zip(parent.iter())
+            .for_each(|(a, b)| *a = fnv(*a, *b));
+    }
+    keccak512_words(&mix)
+}
+
+pub fn hashimoto_light(
+    seal_hash: &H256,
+    nonce: &[u8],
+    full_size: u64,
+    cache: &[Hash512],
+) -> (H256, H256) {
+    let num_rows = (full_size / MIX_BYTES) as u32;
+    let mut header = seal_hash.as_bytes().to_vec();
+    header.extend(nonce.iter().rev());
+    let seed = convert_bytes_to_words(&keccak512(&header));
+    let mut mix = [0u32; 32];
+    mix[..16].copy_from_slice(&seed);
+    mix[16..].copy_from_slice(&seed);
+    for i in 0..ACCESSES {
+        let p = fnv(i ^ seed[0], mix[i as usize % 32]) % num_rows * 2;
+        let new_data = [
+            calculate_dataset_item(cache, p),
+            calculate_dataset_item(cache, p + 1),
+        ];
+        mix
+            .iter_mut()
+            .enumerate()
+            .for_each(|(k, word)| *word = fnv(*word, new_data[k / 16][k % 16]));
+    }
+    let mix_digest = convert_words_to_bytes(
+        &mix
+            .chunks(4)
+            .map(|c| fnv(fnv(fnv(c[0], c[1]), c[2]), c[3]))
+            .collect::<Vec<u32>>()
+    );
+    let mut final_preimage = convert_words_to_bytes(&seed);
+    final_preimage.extend(&mix_digest);
+    (H256::from_slice(&mix_digest), H256::from(keccak256(&final_preimage)))
+}
+
+pub fn result_meets_difficulty(result: &H256, difficulty: &U256) -> bool {
+    U256::from(result.as_bytes()).full_mul(*difficulty) <= U512::one() << 256
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_ethash_sizes_for_epoch_0() {
+        assert_eq!(get_cache_size(0), 16_776_896);
+        assert_eq!(get_full_size(0), 1_073_739_904);
+    }
+
+    #[test]
+    fn should_get_ethash_sizes_for_sample_block_epoch() {
+        let epoch = get_epoch(8_503_804);
+        assert_eq!(epoch, 283);
+        assert_eq!(get_cache_size(epoch), 53_870_528);
+        assert_eq!(get_full_size(epoch), 3_447_714_176);
+    }
+
+    #[test]
+    fn should_get_seed_hashes() {
+        assert_eq!(get_seed_hash(0), [0u8; 32]);
+        assert_eq!(
+            hex::encode(get_seed_hash(1)),
+            "290decd9548b62a8d60345a988386fc84ba6bc95484008f6362f93160ef3e563",
+        );
+    }
+
+    #[test]
+    fn should_check_result_meets_difficulty() {
+        let result = H256::from_slice(&hex::decode(
+            "0000000000000f2d145af84bf5f6c22dc9b723beedbf289b5f33e4c46e17dd07"
+        ).unwrap());
+        let difficulty = U256::from(2_273_132_780_410_076u64);
+        assert!(result_meets_difficulty(&result, &difficulty));
+        assert!(!result_meets_difficulty(&result, &(U256::one() << 64)));
+    }
+}
@@ -15,7 +15,7 @@ pub fn maybe_extract_btc_utxo_from_btc_tx_in_state<D>(
     where D: DatabaseInterface
 {
     info!("✔ Maybe extracting UTXOs from BTC txs in state...");
-    match &state.btc_transactions {
+    match state.btc_transactions.as_option() {
         None => {
             info!("✔ No BTC txs in state ∴ no UTXOs to extract...");
             Ok(state)
@@ -0,0 +1,102 @@
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    processed_tx_ledger::eth_redeem_has_been_processed,
+    skipped_items::{
+        SkipReason,
+        SkippedItem,
+    },
+    eth::{
+        eth_state::EthState,
+        eth_types::RedeemParams,
+    },
+};
+
+fn filter_processed_redeems_from_redeem_params<D>(
+    db: &D,
+    redeem_params: &[RedeemParams],
+) -> Vec<RedeemParams>
+    where D: DatabaseInterface
+{
+    redeem_params
+        .iter()
+        .filter(|params| {
+            let tx_hash = &params.originating_tx_hash;
+            match eth_redeem_has_been_processed(db, tx_hash) {
+                false => true,
+                true => {
+                    info!("✘ Filtering already processed redeem: {}", tx_hash);
+                    false
+                }
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+pub fn maybe_filter_processed_redeems_in_state<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe filtering already processed redeem params...");
+    let redeem_params = filter_processed_redeems_from_redeem_params(
+        &state.db,
+        &state.redeem_params,
+    );
+    let skipped_items = state.redeem_params
+        .iter()
+        .filter(|params| !redeem_params.contains(params))
+        .map(|params|
+            SkippedItem::new(
+                format!("0x{}", hex::encode(params.originating_tx_hash)),
+                params.amount.to_string(),
+                SkipReason::AlreadyProcessed,
+            ).with_correlation_id(&params.correlation_id)
+        )
+        .collect();
+    state.replace_redeem_params(redeem_params)
+        .and_then(|state| state.add_skipped_items(skipped_items))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethereum_types::U256;
+    use crate::{
+        test_utils::get_test_database,
+        processed_tx_ledger::put_processed_eth_redeem_in_db,
+        eth::{
+            eth_types::EthHash,
+            eth_test_utils::get_sample_eth_address,
+        },
+    };
+
+    #[test]
+    fn should_filter_processed_redeems_in_state() {
+        let db = get_test_database();
+        let redeem_params = vec![
+            EthHash::from_slice(&[0x01; 32]),
+            EthHash::from_slice(&[0x02; 32]),
+        ]
+            .into_iter()
+            .map(|tx_hash|
+                RedeemParams::new(
+                    U256::from(1337),
+                    get_sample_eth_address(),
+                    "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM".to_string(),
+                    tx_hash,
+                )
+            )
+            .collect::<Vec<RedeemParams>>();
+        put_processed_eth_redeem_in_db(
+            &db,
+            &redeem_params[0].originating_tx_hash,
+        ).unwrap();
+        let state = EthState::init(db)
+            .add_redeem_params(redeem_params.clone())
+            .and_then(maybe_filter_processed_redeems_in_state)
+            .unwrap();
+        assert_eq!(state.redeem_params, vec![redeem_params[1].clone()]);
+    }
+}
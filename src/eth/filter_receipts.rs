@@ -1,22 +1,33 @@
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use ethereum_types::{
+    Bloom,
+    BloomInput,
+};
+#[cfg(test)]
+use crate::eth::eth_types::EthLogs;
 use crate::{
     types::Result,
     traits::DatabaseInterface,
     eth::{
         eth_state::EthState,
-        eth_constants::PTOKEN_CONTRACT_TOPICS,
-        eth_database_utils::get_eth_smart_contract_address_from_db,
+        eth_database_utils::{
+            get_eth_smart_contract_address_from_db,
+            get_eth_ptoken_contract_topics_from_db,
+        },
         eth_types::{
             EthLog,
             EthHash,
-            EthLogs,
             EthTopics,
             EthAddress,
+            EthReceipt,
             EthReceipts,
             EthBlockAndReceipts
         },
     },
 };
 
+#[cfg(test)]
 pub fn log_contains_topic(log: &EthLog, topic: &EthHash) -> bool {
     log
         .topics
@@ -26,6 +37,7 @@ pub fn log_contains_topic(log: &EthLog, topic: &EthHash) -> bool {
         .len() > 0
 }
 
+#[cfg(test)]
 pub fn logs_contain_topic(logs: &EthLogs, topic: &EthHash) -> bool {
     logs
         .iter()
@@ -38,6 +50,7 @@ pub fn log_contains_address(log: &EthLog, address: &EthAddress) -> bool {
     &log.address == address
 }
 
+#[cfg(test)]
 pub fn logs_contain_address(logs: &EthLogs, address: &EthAddress) -> bool {
     logs
         .iter()
@@ -46,15 +59,57 @@ pub fn logs_contain_address(logs: &EthLogs, address: &EthAddress) -> bool {
         .len() > 0
 }
 
-fn filter_receipts_for_address_and_topic(
+pub fn log_is_event_from_address(
+    log: &EthLog,
+    address: &EthAddress,
+    event_signature: &EthHash,
+) -> bool {
+    log_contains_address(log, address) &&
+    log.topics.first() == Some(event_signature)
+}
+
+pub fn receipt_contains_event_from_address(
+    receipt: &EthReceipt,
+    address: &EthAddress,
+    event_signature: &EthHash,
+) -> bool {
+    receipt
+        .logs
+        .iter()
+        .any(|log| log_is_event_from_address(log, address, event_signature))
+}
+
+fn get_bloom_for_event_from_address(
+    address: &EthAddress,
+    event_signature: &EthHash,
+) -> Bloom {
+    let mut bloom = Bloom::from(BloomInput::Raw(address.as_bytes()));
+    bloom.accrue(BloomInput::Raw(event_signature.as_bytes()));
+    bloom
+}
+
+pub fn bloom_may_contain_events_from_address(
+    bloom: &Bloom,
+    address: &EthAddress,
+    event_signatures: &EthTopics,
+) -> bool {
+    event_signatures
+        .iter()
+        .map(|topic| get_bloom_for_event_from_address(address, topic))
+        .any(|event_bloom| bloom.contains_bloom(&event_bloom))
+}
+
+#[cfg(test)]
+pub fn filter_receipts_for_address_and_topic(
     receipts: &EthReceipts,
     address: &EthAddress,
     topic: &EthHash,
 ) -> EthReceipts {
     receipts
         .iter()
-        .filter(|receipt| logs_contain_address(&receipt.logs, address))
-        .filter(|receipt| logs_contain_topic(&receipt.logs, topic))
+        .filter(|receipt|
+            receipt_contains_event_from_address(receipt, address, topic)
+        )
         .cloned()
         .collect::<EthReceipts>()
 }
@@ -64,20 +119,30 @@ fn filter_receipts_for_address_and_topics(
     address: &EthAddress,
     eth_topics: &EthTopics,
 ) -> EthReceipts {
-    eth_topics
-        .iter()
-        .map(|topic|
-             filter_receipts_for_address_and_topic(
-                &receipts,
-                &address,
-                &topic,
-             )
-         )
-        .flatten()
+    #[cfg(feature = "parallel")]
+    let receipts_iter = receipts.par_iter();
+    #[cfg(not(feature = "parallel"))]
+    let receipts_iter = receipts.iter();
+    receipts_iter
+        .filter(|receipt|
+            bloom_may_contain_events_from_address(
+                &receipt.logs_bloom,
+                address,
+                eth_topics,
+            )
+        )
+        .filter(|receipt|
+            eth_topics
+                .iter()
+                .any(|topic|
+                    receipt_contains_event_from_address(receipt, address, topic)
+                )
+        )
+        .cloned()
         .collect::<EthReceipts>()
 }
 
-fn filter_eth_block_and_receipts(
+pub fn filter_eth_block_and_receipts(
     eth_block_and_receipts: &EthBlockAndReceipts,
     address: &EthAddress,
     eth_topics: &EthTopics,
@@ -85,11 +150,21 @@ fn filter_eth_block_and_receipts(
     Ok(
         EthBlockAndReceipts {
             block: eth_block_and_receipts.block.clone(),
-            receipts: filter_receipts_for_address_and_topics(
-                &eth_block_and_receipts.receipts,
+            receipts: match bloom_may_contain_events_from_address(
+                &eth_block_and_receipts.block.logs_bloom,
                 address,
                 eth_topics,
-            )
+            ) {
+                false => {
+                    info!("✔ Block's logs bloom has no pToken events!");
+                    Vec::new()
+                }
+                true => filter_receipts_for_address_and_topics(
+                    &eth_block_and_receipts.receipts,
+                    address,
+                    eth_topics,
+                ),
+            }
         }
     )
 }
@@ -103,14 +178,16 @@ pub fn filter_irrelevant_receipts_from_state<D>(
     filter_eth_block_and_receipts(
         state.get_eth_block_and_receipts()?,
         &get_eth_smart_contract_address_from_db(&state.db)?,
-        &PTOKEN_CONTRACT_TOPICS.to_vec(),
+        &get_eth_ptoken_contract_topics_from_db(&state.db)?,
     )
         .and_then(|filtered_block_and_receipts| {
             info!(
                 "✔ Receipts filtered, amount remaining: {}",
                 filtered_block_and_receipts.receipts.len()
             );
-            state.update_eth_block_and_receipts(filtered_block_and_receipts)
+            state.add_filtered_eth_block_and_receipts(
+                filtered_block_and_receipts
+            )
         })
 }
 
@@ -278,4 +355,108 @@ mod tests {
              )
             .for_each(drop);
     }
+
+    #[test]
+    fn should_not_filter_in_receipt_if_address_and_topic_in_different_logs() {
+        let block_and_receipts = get_sample_eth_block_and_receipts_n(6)
+            .unwrap();
+        let address = EthAddress::from_slice(
+            &hex::decode("74630cfbc4066726107a4efe73956e219bbb46ab")
+                .unwrap()
+        );
+        let topic = EthHash::from_slice(
+            &hex::decode(REDEEM_EVENT_TOPIC_HEX).unwrap()
+        );
+        let mut receipt = filter_receipts_for_address_and_topic(
+            &block_and_receipts.receipts,
+            &address,
+            &topic,
+        )[0].clone();
+        let mut log_from_other_address = receipt.logs[0].clone();
+        log_from_other_address.address = EthAddress::random();
+        let mut log_with_other_topic = receipt.logs[0].clone();
+        log_with_other_topic.topics = vec![EthHash::random(), topic];
+        receipt.logs = vec![log_from_other_address, log_with_other_topic];
+        let result = filter_receipts_for_address_and_topic(
+            &vec![receipt],
+            &address,
+            &topic,
+        );
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn should_filter_receipts_for_multiple_topics_without_duplicates() {
+        let block_and_receipts = get_sample_eth_block_and_receipts_n(6)
+            .unwrap();
+        let address = EthAddress::from_slice(
+            &hex::decode("74630cfbc4066726107a4efe73956e219bbb46ab")
+                .unwrap()
+        );
+        let topics = vec![
+            EthHash::random(),
+            EthHash::from_slice(&hex::decode(REDEEM_EVENT_TOPIC_HEX).unwrap()),
+            EthHash::from_slice(&hex::decode(REDEEM_EVENT_TOPIC_HEX).unwrap()),
+        ];
+        let result = filter_receipts_for_address_and_topics(
+            &block_and_receipts.receipts,
+            &address,
+            &topics,
+        );
+        assert_eq!(result.len(), 1);
+    }
+
+    #[test]
+    fn bloom_should_contain_events_from_address() {
+        let block_and_receipts = get_sample_eth_block_and_receipts_n(6)
+            .unwrap();
+        let address = EthAddress::from_slice(
+            &hex::decode("74630cfbc4066726107a4efe73956e219bbb46ab")
+                .unwrap()
+        );
+        let topics = vec![
+            EthHash::from_slice(&hex::decode(REDEEM_EVENT_TOPIC_HEX).unwrap())
+        ];
+        let result = bloom_may_contain_events_from_address(
+            &block_and_receipts.block.logs_bloom,
+            &address,
+            &topics,
+        );
+        assert!(result);
+    }
+
+    #[test]
+    fn bloom_should_not_contain_events_from_other_address() {
+        let block_and_receipts = get_sample_eth_block_and_receipts_n(6)
+            .unwrap();
+        let topics = vec![
+            EthHash::from_slice(&hex::decode(REDEEM_EVENT_TOPIC_HEX).unwrap())
+        ];
+        let result = bloom_may_contain_events_from_address(
+            &block_and_receipts.block.logs_bloom,
+            &EthAddress::zero(),
+            &topics,
+        );
+        assert!(!result);
+    }
+
+    #[test]
+    fn should_skip_receipts_if_block_bloom_has_no_events() {
+        let mut block_and_receipts = get_sample_eth_block_and_receipts_n(6)
+            .unwrap();
+        block_and_receipts.block.logs_bloom = Bloom::default();
+        let address = EthAddress::from_slice(
+            &hex::decode("74630cfbc4066726107a4efe73956e219bbb46ab")
+                .unwrap()
+        );
+        let topics = vec![
+            EthHash::from_slice(&hex::decode(REDEEM_EVENT_TOPIC_HEX).unwrap())
+        ];
+        let result = filter_eth_block_and_receipts(
+            &block_and_receipts,
+            &address,
+            &topics,
+        ).unwrap();
+        assert!(result.receipts.is_empty());
+    }
 }
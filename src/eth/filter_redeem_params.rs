@@ -3,6 +3,11 @@ use crate::{
     types::Result,
     traits::DatabaseInterface,
     constants::MINIMUM_REQUIRED_SATOSHIS,
+    skipped_items::{
+        SkipReason,
+        SkippedItem,
+        SkippedItems,
+    },
     eth::{
         eth_state::EthState,
         eth_types::RedeemParams,
@@ -32,14 +37,44 @@ fn filter_redeem_params(
     )
 }
 
+fn get_skipped_items_from_redeem_params(
+    redeem_params: &[RedeemParams]
+) -> SkippedItems {
+    redeem_params
+        .iter()
+        .filter_map(|params| {
+            let skipped_item = SkippedItem::new(
+                format!("0x{}", hex::encode(params.originating_tx_hash)),
+                params.amount.to_string(),
+                SkipReason::DustAmount,
+            ).with_correlation_id(&params.correlation_id);
+            match params.amount >= U256::from(MINIMUM_REQUIRED_SATOSHIS) {
+                false => Some(skipped_item),
+                true => params.recipient_diversion_reason
+                    .clone()
+                    .map(|reason|
+                        SkippedItem {
+                            reason: SkipReason::InvalidRecipient,
+                            ..skipped_item
+                        }.with_detail(reason)
+                    ),
+            }
+        })
+        .collect()
+}
+
 pub fn maybe_filter_redeem_params_in_state<D>(
     state: EthState<D>
 ) -> Result<EthState<D>>
     where D: DatabaseInterface
 {
     info!("✔ Maybe filtering any redeem params below minimum # of Satoshis...");
+    let skipped_items = get_skipped_items_from_redeem_params(
+        &state.redeem_params
+    );
     filter_redeem_params(&state.redeem_params)
         .and_then(|new_params| state.replace_redeem_params(new_params))
+        .and_then(|state| state.add_skipped_items(skipped_items))
 }
 
 #[cfg(test)]
@@ -66,6 +101,9 @@ mod tests {
                     &hex::decode("17f84a414c183bfafa4cd05e9ad13185e5eb6983085c222cae5afa4bba212da5")
                     .unwrap()[..]
                 ),
+                recipient_diversion_reason: None,
+                correlation_id: String::new(),
+                peg_out_fee: 0,
             },
             RedeemParams {
                 amount: U256::from_dec_str("5000").unwrap(),
@@ -77,6 +115,9 @@ mod tests {
                     &hex::decode("17f84a414c183bfafa4cd05e9ad13185e5eb6983085c222cae5afa4bba212da5")
                     .unwrap()[..]
                 ),
+                recipient_diversion_reason: None,
+                correlation_id: String::new(),
+                peg_out_fee: 0,
             },
             RedeemParams {
                 amount: U256::from_dec_str("5001").unwrap(),
@@ -88,6 +129,9 @@ mod tests {
                     &hex::decode("17f84a414c183bfafa4cd05e9ad13185e5eb6983085c222cae5afa4bba212da5")
                     .unwrap()[..]
                 ),
+                recipient_diversion_reason: None,
+                correlation_id: String::new(),
+                peg_out_fee: 0,
             },
         ];
         let length_before = params.len();
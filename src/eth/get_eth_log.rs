@@ -7,11 +7,16 @@ use crate::{
     eth::eth_types::{
         EthLog,
         EthLogJson,
+        EthLogJsonRef,
         EthReceiptJson,
+        EthReceiptJsonRef,
     },
     utils::{
         convert_hex_to_bytes,
         convert_hex_to_address,
+        decode_prefixed_hex_str,
+        convert_hex_strs_to_h256s,
+        convert_hex_str_to_address,
         convert_hex_strings_to_h256s,
     },
 };
@@ -38,6 +43,16 @@ fn get_log_from_json(log_json: &EthLogJson) -> Result<EthLog> {
     )
 }
 
+fn get_log_from_json_ref(log_json: &EthLogJsonRef) -> Result<EthLog> {
+    Ok(
+        EthLog {
+            address: convert_hex_str_to_address(&log_json.address)?,
+            topics: convert_hex_strs_to_h256s(&log_json.topics)?,
+            data: decode_prefixed_hex_str(&log_json.data)?,
+        }
+    )
+}
+
 pub fn get_logs_bloom_from_logs(logs: &Vec<EthLog>) -> Result<Bloom> {
     Ok(
         logs
@@ -60,6 +75,17 @@ pub fn get_logs_from_receipt_json(
         .collect::<Result<Vec<EthLog>>>()
 }
 
+pub fn get_logs_from_receipt_json_ref(
+    receipt_json: &EthReceiptJsonRef
+) -> Result <Vec<EthLog>> {
+    trace!("✔ Parsing logs in borrowed receipt...");
+    receipt_json
+        .logs
+        .iter()
+        .map(get_log_from_json_ref)
+        .collect::<Result<Vec<EthLog>>>()
+}
+
 #[cfg(test)]
 mod tests {
     use hex;
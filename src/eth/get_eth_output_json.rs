@@ -1,32 +1,40 @@
 use bitcoin::blockdata::transaction::Transaction as BtcTransaction;
-use std::time::{
-    SystemTime,
-    UNIX_EPOCH
-};
 use crate::{
     types::Result,
+    host_clock::get_unix_timestamp,
     traits::DatabaseInterface,
+    stage_timing::StageTimings,
+    skipped_items::SkippedItems,
     btc::{
         btc_utils::get_hex_tx_from_signed_btc_tx,
         btc_database_utils::get_btc_account_nonce_from_db,
     },
     eth::{
         eth_state::EthState,
-        eth_types::RedeemParams,
-        eth_database_utils::get_eth_latest_block_from_db,
+        eth_types::{
+            RedeemParams,
+            EthRedeemInfo,
+            EthReorgReport,
+        },
+        eth_database_utils::get_latest_eth_block_number,
     },
 };
 
 #[derive(Debug, Serialize, Deserialize)]
-struct BtcTxInfo {
-    btc_tx_hex: String,
-    btc_tx_hash: String,
-    btc_tx_amount: u64,
-    btc_account_nonce: u64,
-    btc_tx_recipient: String,
-    signature_timestamp: u64,
-    originating_tx_hash: String,
-    originating_address: String,
+pub struct BtcTxInfo {
+    pub btc_tx_hex: String,
+    pub btc_tx_hash: String,
+    pub btc_tx_amount: u64,
+    pub btc_tx_gross_amount: u64,
+    pub btc_tx_peg_out_fee: u64,
+    pub btc_account_nonce: u64,
+    pub btc_tx_recipient: String,
+    pub signature_timestamp: u64,
+    pub correlation_id: String,
+    pub originating_tx_hash: String,
+    pub originating_address: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub btc_tx_recipient_diversion_reason: Option<String>,
 }
 
 impl BtcTxInfo {
@@ -40,6 +48,8 @@ impl BtcTxInfo {
                 btc_account_nonce,
                 btc_tx_hash: btc_tx.txid().to_string(),
                 btc_tx_amount: redeem_params.amount.as_u64(),
+                btc_tx_gross_amount: redeem_params.get_gross_amount(),
+                btc_tx_peg_out_fee: redeem_params.peg_out_fee,
                 btc_tx_hex: get_hex_tx_from_signed_btc_tx(&btc_tx),
                 btc_tx_recipient: redeem_params.recipient.clone(),
                 originating_address: format!(
@@ -50,18 +60,30 @@ impl BtcTxInfo {
                     "0x{}",
                     hex::encode(redeem_params.originating_tx_hash.as_bytes())
                 ),
-                signature_timestamp: SystemTime::now()
-                    .duration_since(UNIX_EPOCH)?
-                    .as_secs(),
+                signature_timestamp: get_unix_timestamp()?,
+                correlation_id: redeem_params.correlation_id.clone(),
+                btc_tx_recipient_diversion_reason: redeem_params
+                    .recipient_diversion_reason
+                    .clone(),
             }
         )
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
-struct EthOutput {
-    eth_latest_block_number: usize,
-    btc_signed_transactions: Vec<BtcTxInfo>,
+pub struct EthSubmissionReport {
+    pub eth_latest_block_number: usize,
+    pub btc_signed_transactions: Vec<BtcTxInfo>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub eth_reorg_report: Option<EthReorgReport>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dry_run: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub btc_unsigned_redeems: Vec<EthRedeemInfo>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stage_timings: StageTimings,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped_items: SkippedItems,
 }
 
 fn get_btc_signed_tx_info_from_btc_txs(
@@ -89,20 +111,30 @@ pub fn get_eth_output_json<D>(state: EthState<D>) -> Result<String>
 {
     info!("✔ Getting ETH output json...");
     let output = serde_json::to_string(
-        &EthOutput {
-            eth_latest_block_number:
-                get_eth_latest_block_from_db(&state.db)?
-                    .block
-                    .number
-                    .as_usize(),
-            btc_signed_transactions: match state.btc_transactions {
+        &EthSubmissionReport {
+            eth_latest_block_number: get_latest_eth_block_number(&state.db)?,
+            btc_signed_transactions: match state
+                .btc_transactions
+                .into_option()
+            {
                 Some(txs) => get_btc_signed_tx_info_from_btc_txs(
                     get_btc_account_nonce_from_db(&state.db)?,
                     txs,
                     &state.redeem_params,
                 )?,
                 None => vec![],
-            }
+            },
+            eth_reorg_report: state.eth_reorg_report.into_option(),
+            dry_run: state.dry_run,
+            btc_unsigned_redeems: match state.dry_run {
+                false => vec![],
+                true => state.redeem_params
+                    .iter()
+                    .map(EthRedeemInfo::from_redeem_params)
+                    .collect(),
+            },
+            stage_timings: state.stage_timings,
+            skipped_items: state.skipped_items,
         }
     )?;
     info!("✔ ETH Output: {}", output);
@@ -1,6 +1,7 @@
+#[cfg(test)]
+use crate::errors::AppError;
 use crate::{
     types::Result,
-    errors::AppError,
     traits::DatabaseInterface,
     eth::{
         eth_types::EthHash,
@@ -27,6 +28,7 @@ pub fn get_linker_hash_or_genesis_hash<D>(db: &D) -> Result<EthHash>
     }
 }
 
+#[cfg(test)]
 pub fn get_linker_hash_from_db<D>(
     db: &D
 ) -> Result<EthHash>
@@ -37,7 +39,7 @@ pub fn get_linker_hash_from_db<D>(
         EthHash::from(ETH_LINKER_HASH_KEY)
     )? {
         Some(hash) => Ok(hash),
-        None => Err(AppError::Custom(
+        None => Err(AppError::NotFound(
             format!("✘ The linker hash is not yet set in db!")
         )),
     }
@@ -67,7 +69,7 @@ mod tests {
         let db = get_test_database();
         let expected_error = format!("✘ The linker hash is not yet set in db!");
         match get_linker_hash_from_db(&db) {
-            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            Err(AppError::NotFound(e)) => assert!(e == expected_error),
             Ok(_) => panic!("Should not have got linker hash!"),
             Err(e) => panic!("Wrong error received: {}", e),
         }
@@ -0,0 +1,441 @@
+use crate::{
+    types::Result,
+    errors::AppError,
+    traits::DatabaseInterface,
+    stale_transactions::flag_orphaned_btc_txs_in_watchlist_in_db,
+    eth::{
+        eth_state::EthState,
+        eth_types::{
+            EthHash,
+            EthReorgReport,
+            EthRedeemRecord,
+            EthBlockHeaderInDbFormat,
+        },
+        eth_database_utils::{
+            put_eth_canon_block_hash_in_db,
+            get_eth_block_header_from_db,
+            delete_eth_redeem_record_from_db,
+            get_eth_latest_block_hash_from_db,
+            maybe_get_parent_eth_block_header,
+            get_eth_canon_to_tip_length_from_db,
+            maybe_get_eth_redeem_record_from_db,
+            get_special_eth_block_header_from_db,
+            maybe_get_nth_ancestor_eth_block_header,
+        },
+    },
+};
+
+fn get_hash_string(hash: &EthHash) -> String {
+    format!("0x{}", hex::encode(hash.as_bytes()))
+}
+
+fn get_parent_block_header<D>(
+    db: &D,
+    block_header: &EthBlockHeaderInDbFormat,
+) -> Result<EthBlockHeaderInDbFormat>
+    where D: DatabaseInterface
+{
+    match maybe_get_parent_eth_block_header(db, &block_header.hash) {
+        Some(parent) => Ok(parent),
+        None => Err(AppError::InvalidBlock(
+            "✘ Not accepting ETH block - no common ancestor for canon!"
+                .to_string()
+        )),
+    }
+}
+
+fn find_common_ancestor_and_orphaned_blocks<D>(
+    db: &D,
+    old_chain_block: EthBlockHeaderInDbFormat,
+    new_chain_block: EthBlockHeaderInDbFormat,
+    mut orphaned_block_hashes: Vec<EthHash>,
+) -> Result<(EthHash, Vec<EthHash>)>
+    where D: DatabaseInterface
+{
+    if old_chain_block.hash == new_chain_block.hash {
+        return Ok((old_chain_block.hash, orphaned_block_hashes))
+    };
+    match old_chain_block.number < new_chain_block.number {
+        true => find_common_ancestor_and_orphaned_blocks(
+            db,
+            old_chain_block,
+            get_parent_block_header(db, &new_chain_block)?,
+            orphaned_block_hashes,
+        ),
+        false => {
+            orphaned_block_hashes.push(old_chain_block.hash);
+            find_common_ancestor_and_orphaned_blocks(
+                db,
+                get_parent_block_header(db, &old_chain_block)?,
+                match old_chain_block.number == new_chain_block.number {
+                    true => get_parent_block_header(
+                        db,
+                        &new_chain_block,
+                    )?,
+                    false => new_chain_block,
+                },
+                orphaned_block_hashes,
+            )
+        }
+    }
+}
+
+// NOTE: The BTC txs signed for an orphaned block may already have been
+// broadcast, so the UTXOs they spent, the BTC nonce & the processed redeems are
+// all left as they are: rolling any back would let a redeem re-mined in the new
+// chain be paid out a second time. The payouts are instead flagged in the
+// watchlist, for the operator to reconcile via the stale transactions getter.
+fn flag_payouts_from_orphaned_blocks<D>(
+    db: &D,
+    orphaned_block_hashes: &[EthHash],
+) -> Result<Vec<EthRedeemRecord>>
+    where D: DatabaseInterface
+{
+    info!("✔ Flagging payouts from orphaned ETH blocks...");
+    orphaned_block_hashes
+        .iter()
+        .filter_map(|hash|
+            maybe_get_eth_redeem_record_from_db(db, hash)
+                .map(|record| (hash, record))
+        )
+        .map(|(hash, record)| {
+            info!(
+                "✘ {} BTC tx(s) paid out redeems from orphaned block {}!",
+                record.btc_tx_hashes.len(),
+                hash,
+            );
+            flag_orphaned_btc_txs_in_watchlist_in_db(
+                db,
+                &record.btc_tx_hashes,
+                &record.block_hash,
+            )
+                .and_then(|_| delete_eth_redeem_record_from_db(db, hash))
+                .map(|_| record)
+        })
+        .collect::<Result<Vec<EthRedeemRecord>>>()
+}
+
+// NOTE: Orphaned blocks are found by walking back from the latest block rather
+// than the calculated canon block, so a canon block still on the chain of the
+// latest one is never orphaned, even when the canon-to-tip length grows.
+fn maybe_reselect_canon_block<D>(
+    db: &D,
+    canon_to_tip_length: &u64,
+) -> Result<Option<EthReorgReport>>
+    where D: DatabaseInterface
+{
+    let latest_block_hash = get_eth_latest_block_hash_from_db(db)?;
+    let calculated_canon_block =
+        match maybe_get_nth_ancestor_eth_block_header(
+            db,
+            &latest_block_hash,
+            canon_to_tip_length,
+        ) {
+            None => return Ok(None),
+            Some(block_header) => block_header,
+        };
+    let old_canon_block = get_special_eth_block_header_from_db(db, "canon")?;
+    let old_canon_block_hash = old_canon_block.hash;
+    let (common_ancestor_hash, orphaned_block_hashes) =
+        find_common_ancestor_and_orphaned_blocks(
+            db,
+            old_canon_block,
+            get_eth_block_header_from_db(db, &latest_block_hash)?,
+            Vec::new(),
+        )?;
+    if orphaned_block_hashes.is_empty() {
+        info!("✔ Canon block not displaced ∴ no reorg to handle!");
+        return Ok(None)
+    };
+    info!("✔ Reorg displaced canon block ∴ re-selecting canon block...");
+    let common_ancestor = get_eth_block_header_from_db(
+        db,
+        &common_ancestor_hash,
+    )?;
+    let new_canon_block_hash =
+        match calculated_canon_block.number > common_ancestor.number {
+            true => calculated_canon_block.hash,
+            false => common_ancestor_hash,
+        };
+    let orphaned_redeems = flag_payouts_from_orphaned_blocks(
+        db,
+        &orphaned_block_hashes,
+    )?;
+    put_eth_canon_block_hash_in_db(db, &new_canon_block_hash)
+        .map(|_|
+            Some(EthReorgReport {
+                orphaned_redeems,
+                old_canon_block_hash: get_hash_string(&old_canon_block_hash),
+                new_canon_block_hash: get_hash_string(&new_canon_block_hash),
+                common_ancestor_hash: get_hash_string(&common_ancestor_hash),
+                orphaned_block_hashes: orphaned_block_hashes
+                    .iter()
+                    .map(get_hash_string)
+                    .collect(),
+            })
+        )
+}
+
+pub fn maybe_handle_eth_reorg<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe handling ETH chain reorg...");
+    get_eth_canon_to_tip_length_from_db(&state.db)
+        .and_then(|canon_to_tip_length|
+            maybe_reselect_canon_block(&state.db, &canon_to_tip_length)
+        )
+        .and_then(|maybe_report| match maybe_report {
+            None => Ok(state),
+            Some(report) => state.add_eth_reorg_report(report),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{
+            TestDB,
+            get_test_database,
+        },
+        btc::{
+            btc_types::BtcUtxosAndValues,
+            btc_database_utils::{
+                put_btc_account_nonce_in_db,
+                get_btc_account_nonce_from_db,
+                increment_btc_account_nonce_in_db,
+            },
+            btc_test_utils::{
+                get_sample_utxo_and_values,
+                get_sample_p2sh_utxo_and_value,
+            },
+        },
+        utxo_manager::utxo_database_utils::{
+            save_utxos_to_db,
+            get_utxo_and_value,
+            get_total_utxo_balance_from_db,
+            get_total_number_of_utxos_from_db,
+        },
+        eth::{
+            eth_types::EthBlockAndReceipts,
+            eth_test_utils::get_sequential_eth_blocks_and_receipts,
+            eth_database_utils::{
+                put_eth_canon_block_in_db,
+                put_eth_latest_block_in_db,
+                put_eth_redeem_record_in_db,
+                get_eth_canon_block_hash_from_db,
+                put_eth_block_and_receipts_in_db,
+                put_eth_canon_to_tip_length_in_db,
+            },
+        },
+    };
+
+    fn get_fork_of_block(
+        block_and_receipts: &EthBlockAndReceipts,
+        parent_hash: &EthHash,
+    ) -> EthBlockAndReceipts {
+        let mut fork = block_and_receipts.clone();
+        fork.block.hash = EthHash::random();
+        fork.block.parent_hash = *parent_hash;
+        fork
+    }
+
+    fn get_sample_redeem_record(block_hash: &EthHash) -> EthRedeemRecord {
+        EthRedeemRecord {
+            block_hash: get_hash_string(block_hash),
+            block_number: 0,
+            btc_tx_hashes: vec!["some-btc-tx-hash".to_string()],
+            redeems: vec![],
+            spent_utxos: vec![],
+            change_utxos: vec![],
+        }
+    }
+
+    fn get_db_with_forked_blocks(
+        blocks: &[EthBlockAndReceipts],
+        fork_1: &EthBlockAndReceipts,
+        fork_2: &EthBlockAndReceipts,
+    ) -> TestDB {
+        let db = get_test_database();
+        vec![&blocks[0], &blocks[1], &blocks[2], fork_1, fork_2]
+            .iter()
+            .for_each(|block| put_eth_block_and_receipts_in_db(&db, block)
+                .unwrap()
+            );
+        put_eth_canon_to_tip_length_in_db(&db, &1).unwrap();
+        put_btc_account_nonce_in_db(&db, &1).unwrap();
+        db
+    }
+
+    // NOTE: Mimics the ETH pipeline signing a BTC tx for the redeems in the
+    // given block: spending UTXOs, saving the change & bumping the nonce.
+    fn sign_btc_tx_for_block(
+        db: &TestDB,
+        block_hash: &EthHash,
+        num_utxos_to_spend: usize,
+    ) {
+        let spent_utxos = (0..num_utxos_to_spend)
+            .map(|_| get_utxo_and_value(db).unwrap())
+            .collect::<BtcUtxosAndValues>();
+        let change_utxos = vec![get_sample_p2sh_utxo_and_value().unwrap()];
+        save_utxos_to_db(db, &change_utxos).unwrap();
+        increment_btc_account_nonce_in_db(db, &1).unwrap();
+        let record = EthRedeemRecord {
+            spent_utxos,
+            change_utxos,
+            ..get_sample_redeem_record(block_hash)
+        };
+        put_eth_redeem_record_in_db(db, block_hash, &record).unwrap();
+    }
+
+    #[test]
+    fn should_find_common_ancestor_and_orphaned_blocks() {
+        let db = get_test_database();
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        let fork_1 = get_fork_of_block(&blocks[1], &blocks[0].block.hash);
+        let fork_2 = get_fork_of_block(&blocks[2], &fork_1.block.hash);
+        vec![&blocks[0], &blocks[1], &blocks[2], &fork_1, &fork_2]
+            .iter()
+            .for_each(|block| put_eth_block_and_receipts_in_db(&db, block)
+                .unwrap()
+            );
+        let (common_ancestor_hash, orphaned_block_hashes) =
+            find_common_ancestor_and_orphaned_blocks(
+                &db,
+                EthBlockHeaderInDbFormat::from_eth_block(&blocks[2].block),
+                EthBlockHeaderInDbFormat::from_eth_block(&fork_2.block),
+                Vec::new(),
+            ).unwrap();
+        assert_eq!(common_ancestor_hash, blocks[0].block.hash);
+        assert_eq!(
+            orphaned_block_hashes,
+            vec![blocks[2].block.hash, blocks[1].block.hash],
+        );
+    }
+
+    #[test]
+    fn should_not_find_orphaned_blocks_if_new_canon_is_descendant() {
+        let db = get_test_database();
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        vec![&blocks[0], &blocks[1]]
+            .iter()
+            .for_each(|block| put_eth_block_and_receipts_in_db(&db, block)
+                .unwrap()
+            );
+        let (common_ancestor_hash, orphaned_block_hashes) =
+            find_common_ancestor_and_orphaned_blocks(
+                &db,
+                EthBlockHeaderInDbFormat::from_eth_block(&blocks[0].block),
+                EthBlockHeaderInDbFormat::from_eth_block(&blocks[1].block),
+                Vec::new(),
+            ).unwrap();
+        assert_eq!(common_ancestor_hash, blocks[0].block.hash);
+        assert!(orphaned_block_hashes.is_empty());
+    }
+
+    #[test]
+    fn should_handle_reorg_displacing_canon_block() {
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        let fork_1 = get_fork_of_block(&blocks[1], &blocks[0].block.hash);
+        let fork_2 = get_fork_of_block(&blocks[2], &fork_1.block.hash);
+        let db = get_db_with_forked_blocks(&blocks, &fork_1, &fork_2);
+        let redeem_record = get_sample_redeem_record(&blocks[1].block.hash);
+        put_eth_redeem_record_in_db(&db, &blocks[1].block.hash, &redeem_record)
+            .unwrap();
+        put_eth_canon_block_in_db(&db, &blocks[1]).unwrap();
+        put_eth_latest_block_in_db(&db, &fork_2).unwrap();
+        let state = maybe_handle_eth_reorg(EthState::init(db)).unwrap();
+        let report = state.eth_reorg_report.get().unwrap().clone();
+        assert_eq!(
+            get_eth_canon_block_hash_from_db(&state.db).unwrap(),
+            fork_1.block.hash,
+        );
+        assert_eq!(report.orphaned_redeems, vec![redeem_record]);
+        assert_eq!(
+            report.orphaned_block_hashes,
+            vec![get_hash_string(&blocks[1].block.hash)],
+        );
+        assert_eq!(
+            report.common_ancestor_hash,
+            get_hash_string(&blocks[0].block.hash),
+        );
+        assert!(
+            maybe_get_eth_redeem_record_from_db(
+                &state.db,
+                &blocks[1].block.hash,
+            ).is_none()
+        );
+    }
+
+    #[test]
+    fn should_not_handle_reorg_if_canon_block_not_displaced() {
+        let db = get_test_database();
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        vec![&blocks[0], &blocks[1], &blocks[2]]
+            .iter()
+            .for_each(|block| put_eth_block_and_receipts_in_db(&db, block)
+                .unwrap()
+            );
+        put_eth_canon_to_tip_length_in_db(&db, &1).unwrap();
+        put_eth_canon_block_in_db(&db, &blocks[0]).unwrap();
+        put_eth_latest_block_in_db(&db, &blocks[2]).unwrap();
+        let state = maybe_handle_eth_reorg(EthState::init(db)).unwrap();
+        assert!(!state.eth_reorg_report.is_filled());
+        assert_eq!(
+            get_eth_canon_block_hash_from_db(&state.db).unwrap(),
+            blocks[0].block.hash,
+        );
+    }
+
+    #[test]
+    fn should_leave_utxos_and_btc_nonce_untouched_after_reorg() {
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        let fork_1 = get_fork_of_block(&blocks[1], &blocks[0].block.hash);
+        let fork_2 = get_fork_of_block(&blocks[2], &fork_1.block.hash);
+        let db = get_db_with_forked_blocks(&blocks, &fork_1, &fork_2);
+        save_utxos_to_db(&db, &get_sample_utxo_and_values()).unwrap();
+        sign_btc_tx_for_block(&db, &blocks[1].block.hash, 2);
+        sign_btc_tx_for_block(&db, &blocks[2].block.hash, 5);
+        let balance_before = get_total_utxo_balance_from_db(&db).unwrap();
+        let num_utxos_before = get_total_number_of_utxos_from_db(&db).unwrap();
+        put_eth_canon_block_in_db(&db, &blocks[2]).unwrap();
+        put_eth_latest_block_in_db(&db, &fork_2).unwrap();
+        let state = maybe_handle_eth_reorg(EthState::init(db)).unwrap();
+        let report = state.eth_reorg_report.get().unwrap().clone();
+        assert_eq!(report.orphaned_redeems.len(), 2);
+        assert_eq!(
+            get_total_utxo_balance_from_db(&state.db).unwrap(),
+            balance_before,
+        );
+        assert_eq!(
+            get_total_number_of_utxos_from_db(&state.db).unwrap(),
+            num_utxos_before,
+        );
+        assert_eq!(get_btc_account_nonce_from_db(&state.db).unwrap(), 3);
+    }
+
+    #[test]
+    fn should_report_blocks_orphaned_below_calculated_canon_block() {
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        let fork_1 = get_fork_of_block(&blocks[1], &blocks[0].block.hash);
+        let fork_2 = get_fork_of_block(&blocks[2], &fork_1.block.hash);
+        let db = get_db_with_forked_blocks(&blocks, &fork_1, &fork_2);
+        let redeem_record = get_sample_redeem_record(&blocks[2].block.hash);
+        put_eth_redeem_record_in_db(&db, &blocks[2].block.hash, &redeem_record)
+            .unwrap();
+        put_btc_account_nonce_in_db(&db, &2).unwrap();
+        put_eth_canon_block_in_db(&db, &blocks[2]).unwrap();
+        put_eth_latest_block_in_db(&db, &fork_1).unwrap();
+        let state = maybe_handle_eth_reorg(EthState::init(db)).unwrap();
+        let report = state.eth_reorg_report.get().unwrap().clone();
+        assert_eq!(
+            get_eth_canon_block_hash_from_db(&state.db).unwrap(),
+            blocks[0].block.hash,
+        );
+        assert_eq!(report.orphaned_redeems, vec![redeem_record]);
+        assert_eq!(report.orphaned_block_hashes.len(), 2);
+        assert_eq!(get_btc_account_nonce_from_db(&state.db).unwrap(), 2);
+    }
+}
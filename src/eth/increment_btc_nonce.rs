@@ -10,7 +10,7 @@ pub fn maybe_increment_btc_nonce_in_db<D>(
 ) -> Result<EthState<D>>
     where D: DatabaseInterface,
 {
-    match &state.btc_transactions {
+    match state.btc_transactions.as_option() {
         None => {
             info!("✔ Not incrementing BTC account nonce - no signatures made!");
             Ok(state)
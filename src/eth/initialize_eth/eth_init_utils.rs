@@ -1,31 +1,52 @@
 use crate::{
     errors::AppError,
     traits::DatabaseInterface,
+    crypto_utils::keccak_hash_bytes,
     types::{
         Bytes,
         Result,
     },
     eth::{
         eth_state::EthState,
-        eth_types::EthBlockAndReceipts,
+        eth_types::{
+            EthBlockAndReceipts,
+            PTokenContractVariant,
+        },
         eth_crypto::eth_transaction::get_ptoken_smart_contract_bytecode,
         eth_database_utils::{
-            put_eth_chain_id_in_db,
-            put_eth_gas_price_in_db,
-            put_eth_account_nonce_in_db,
             put_eth_tail_block_hash_in_db,
             put_eth_canon_block_hash_in_db,
             put_eth_anchor_block_hash_in_db,
             put_eth_latest_block_hash_in_db,
             put_eth_block_and_receipts_in_db,
-            put_eth_canon_to_tip_length_in_db,
+            put_ptoken_contract_bytecode_hash_in_db,
         },
     },
-
 };
 
-pub fn check_for_existence_of_eth_contract_byte_code() -> Result<Bytes> {
-    get_ptoken_smart_contract_bytecode()
+pub fn check_for_existence_of_eth_contract_byte_code(
+    contract_variant: &PTokenContractVariant,
+) -> Result<Bytes> {
+    get_ptoken_smart_contract_bytecode(contract_variant)
+}
+
+pub fn put_ptoken_contract_bytecode_hash_in_db_and_return_state<D>(
+    contract_variant: &PTokenContractVariant,
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Putting pToken contract bytecode hash in db...");
+    get_ptoken_smart_contract_bytecode(contract_variant)
+        .map(keccak_hash_bytes)
+        .and_then(|bytecode_hash| {
+            info!(
+                "✔ pToken contract bytecode hash: 0x{}",
+                hex::encode(bytecode_hash.as_bytes()),
+            );
+            put_ptoken_contract_bytecode_hash_in_db(&state.db, &bytecode_hash)
+        })
+        .map(|_| state)
 }
 
 pub fn put_eth_tail_block_hash_in_db_and_return_state<D>(
@@ -61,7 +82,9 @@ fn set_hash_from_block_in_state<D>(
             info!("✔ Initializating ETH anchor block hash...");
             put_eth_anchor_block_hash_in_db(&state.db, hash)
         }
-        _ => Err(AppError::Custom("✘ Hash type not recognized!".to_string()))
+        _ => Err(AppError::InvalidInput(
+            "✘ Hash type not recognized!".to_string()
+        ))
     }?;
     Ok(state)
 }
@@ -90,54 +113,6 @@ pub fn set_eth_canon_block_hash_and_return_state<D>(
     set_hash_from_block_in_state(state, "canon")
 }
 
-pub fn put_canon_to_tip_length_in_db_and_return_state<D>(
-    canon_to_tip_length: u64,
-    state: EthState<D>,
-) -> Result<EthState<D>>
-    where D: DatabaseInterface
-{
-    put_eth_canon_to_tip_length_in_db(&state.db, &canon_to_tip_length)
-        .map(|_| state)
-}
-
-pub fn put_eth_chain_id_in_db_and_return_state<D>(
-    chain_id: u8,
-    state: EthState<D>
-) -> Result<EthState<D>>
-    where D: DatabaseInterface
-{
-    trace!(
-        "✔ Putting ETH chain ID of {} in db...",
-        chain_id,
-    );
-    put_eth_chain_id_in_db(&state.db, &chain_id)
-        .map(|_| state)
-}
-
-pub fn put_eth_gas_price_in_db_and_return_state<D>(
-    gas_price: u64,
-    state: EthState<D>,
-) -> Result<EthState<D>>
-    where D: DatabaseInterface
-{
-    trace!(
-        "✔ Putting ETH gas price of {} in db...",
-        gas_price
-    );
-    put_eth_gas_price_in_db(&state.db, &gas_price)
-        .map(|_| state)
-}
-
-pub fn put_eth_account_nonce_in_db_and_return_state<D>(
-    state: EthState<D>,
-) -> Result<EthState<D>>
-    where D: DatabaseInterface
-{
-    trace!("✔ Putting ETH account nonce of 1 in db...");
-    put_eth_account_nonce_in_db(&state.db, &1) // NOTE: ∵ of the contract tx!
-        .map(|_| state)
-}
-
 pub fn remove_receipts_from_block_in_state<D>( // ∵ there shouldn't be relevant txs!
     state: EthState<D>
 ) -> Result<EthState<D>>
@@ -148,7 +123,7 @@ pub fn remove_receipts_from_block_in_state<D>( // ∵ there shouldn't be relevan
         block: state.get_eth_block_and_receipts()?.block.clone(),
         receipts: vec![],
     };
-    state.update_eth_block_and_receipts(block_with_no_receipts)
+    state.add_filtered_eth_block_and_receipts(block_with_no_receipts)
 }
 
 pub fn add_eth_block_to_db_and_return_state<D>(
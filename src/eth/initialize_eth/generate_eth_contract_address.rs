@@ -2,7 +2,10 @@ use rlp::RlpStream;
 use tiny_keccak::keccak256;
 use ethereum_types::Address as EthAddress;
 use crate::{
-    types::Result,
+    types::{
+        Result,
+        DataSensitivity,
+    },
     traits::DatabaseInterface,
     eth::{
         eth_state::EthState,
@@ -48,7 +51,7 @@ pub fn generate_and_store_eth_contract_address<D>(
             &state.db.put(
                 ETH_SMART_CONTRACT_ADDRESS_KEY.to_vec(),
                 smart_contract_address.as_bytes().to_vec(),
-                None,
+                DataSensitivity::Public,
             );
             state
         })
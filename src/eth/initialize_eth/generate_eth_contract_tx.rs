@@ -3,7 +3,10 @@ use crate::{
     traits::DatabaseInterface,
     eth::{
         eth_state::EthState,
-        eth_database_utils::get_eth_private_key_from_db,
+        eth_database_utils::{
+            get_eth_private_key_from_db,
+            get_ptoken_contract_variant_from_db,
+        },
         eth_crypto::eth_transaction::get_signed_ptoken_smart_contract_tx,
     },
 };
@@ -22,6 +25,7 @@ pub fn generate_eth_contract_tx_and_put_in_state<D>(
                 chain_id,
                 eth_private_key,
                 gas_price,
+                &get_ptoken_contract_variant_from_db(&state.db)?,
             )
         )
         .and_then(|signed_tx|  state.add_misc_string_to_state(signed_tx))
@@ -1,6 +1,6 @@
 use crate::{
     types::Result,
-    errors::AppError,
+    init_report::InitReport,
     traits::DatabaseInterface,
     eth::{
         eth_state::EthState,
@@ -38,15 +38,6 @@ impl EthInitializationOutput {
     }
 }
 
-fn json_stringify(
-    output: EthInitializationOutput
-) -> Result<String> {
-    match serde_json::to_string(&output) {
-        Ok(res) => Ok(res),
-        Err(err) => Err(AppError::Custom(err.to_string()))
-    }
-}
-
 pub fn get_eth_init_output_json<D>(
     state: EthState<D>
 ) -> Result<String>
@@ -68,5 +59,5 @@ pub fn get_eth_init_output_json<D>(
             )
         ),
     )
-        .and_then(json_stringify)
+        .and_then(|output| InitReport::Eth(output).to_json_string())
 }
@@ -1,11 +1,29 @@
+use std::str::FromStr;
 use crate::{
     types::Result,
+    errors::AppError,
+    init_report::InitReport,
     traits::DatabaseInterface,
+    core_version::maybe_put_core_version_info_in_db,
+    chain_state::{
+        db_step,
+        end_db_transaction,
+        start_db_transaction,
+    },
+    utils::{
+        convert_hex_to_address,
+        check_hex_is_valid_ethereum_address,
+    },
     eth::{
         eth_state::EthState,
+        eth_types::PTokenContractVariant,
         eth_database_utils::{
-            end_eth_db_transaction,
-            start_eth_db_transaction,
+            put_eth_chain_id_in_db,
+            put_eth_gas_price_in_db,
+            put_eth_account_nonce_in_db,
+            put_ptoken_contract_variant_in_db,
+            put_eth_canon_to_tip_length_in_db,
+            put_eth_smart_contract_address_in_db,
         },
         parse_eth_block_and_receipts::{
             parse_eth_block_and_receipts_and_put_in_state,
@@ -25,20 +43,47 @@ use crate::{
             eth_init_utils::{
                 remove_receipts_from_block_in_state,
                 add_eth_block_to_db_and_return_state,
-                put_eth_chain_id_in_db_and_return_state,
-                put_eth_gas_price_in_db_and_return_state,
                 set_eth_canon_block_hash_and_return_state,
                 set_eth_anchor_block_hash_and_return_state,
                 set_eth_latest_block_hash_and_return_state,
-                put_eth_account_nonce_in_db_and_return_state,
                 check_for_existence_of_eth_contract_byte_code,
-                put_canon_to_tip_length_in_db_and_return_state,
                 put_eth_tail_block_hash_in_db_and_return_state,
+                put_ptoken_contract_bytecode_hash_in_db_and_return_state,
             },
         },
     },
 };
 
+fn initialize_eth_core<D>(
+    state: EthState<D>,
+    block_json_string: String,
+    chain_id: u8,
+    gas_price: u64,
+    canon_to_tip_length: u64,
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    parse_eth_block_and_receipts_and_put_in_state(
+        block_json_string,
+        state,
+    )
+        .and_then(validate_eth_block_in_state)
+        .and_then(remove_receipts_from_block_in_state)
+        .and_then(start_db_transaction)
+        .and_then(add_eth_block_to_db_and_return_state)
+        .and_then(db_step(|db|
+            put_eth_canon_to_tip_length_in_db(db, &canon_to_tip_length)
+        ))
+        .and_then(set_eth_anchor_block_hash_and_return_state)
+        .and_then(set_eth_latest_block_hash_and_return_state)
+        .and_then(set_eth_canon_block_hash_and_return_state)
+        .and_then(generate_and_store_eth_private_key)
+        .and_then(put_eth_tail_block_hash_in_db_and_return_state)
+        .and_then(db_step(|db| put_eth_chain_id_in_db(db, &chain_id)))
+        .and_then(db_step(|db| put_eth_gas_price_in_db(db, &gas_price)))
+        .and_then(db_step(maybe_put_core_version_info_in_db))
+}
+
 pub fn maybe_initialize_eth_enclave<D>(
     db: D,
     block_json_string: String,
@@ -48,48 +93,59 @@ pub fn maybe_initialize_eth_enclave<D>(
 ) -> Result<String>
     where D: DatabaseInterface
 {
-    check_for_existence_of_eth_contract_byte_code()
+    maybe_initialize_eth_enclave_with_contract_variant(
+        db,
+        block_json_string,
+        chain_id,
+        gas_price,
+        canon_to_tip_length,
+        PTokenContractVariant::Erc777.to_string(),
+    )
+}
+
+pub fn maybe_initialize_eth_enclave_with_contract_variant<D>(
+    db: D,
+    block_json_string: String,
+    chain_id: u8,
+    gas_price: u64,
+    canon_to_tip_length: u64,
+    contract_variant: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    let contract_variant = PTokenContractVariant::from_str(&contract_variant)?;
+    check_for_existence_of_eth_contract_byte_code(&contract_variant)
         .map(|_| EthState::init(db))
         .and_then(|state|
             match is_eth_enclave_initialized(&state.db) {
                 true => {
                     info!("✔ ETH Enclave already initialized!");
-                    Ok("{eth_enclave_initialized:true}".to_string())
+                    InitReport::eth_already_initialized().to_json_string()
                 }
                 false => {
                     info!("✔ Initializing enclave for ETH...");
-                    parse_eth_block_and_receipts_and_put_in_state(
-                        block_json_string,
+                    initialize_eth_core(
                         state,
+                        block_json_string,
+                        chain_id,
+                        gas_price,
+                        canon_to_tip_length,
                     )
-                        .and_then(validate_eth_block_in_state)
-                        .and_then(remove_receipts_from_block_in_state)
-                        .and_then(start_eth_db_transaction)
-                        .and_then(add_eth_block_to_db_and_return_state)
-                        .and_then(|state|
-                            put_canon_to_tip_length_in_db_and_return_state(
-                                canon_to_tip_length,
-                                state,
+                        .and_then(db_step(|db|
+                            put_ptoken_contract_variant_in_db(
+                                db,
+                                &contract_variant,
                             )
-                        )
-                        .and_then(set_eth_anchor_block_hash_and_return_state)
-                        .and_then(set_eth_latest_block_hash_and_return_state)
-                        .and_then(set_eth_canon_block_hash_and_return_state)
-                        .and_then(generate_and_store_eth_private_key)
-                        .and_then(put_eth_tail_block_hash_in_db_and_return_state)
+                        ))
                         .and_then(|state|
-                            put_eth_chain_id_in_db_and_return_state(
-                                chain_id,
+                            put_ptoken_contract_bytecode_hash_in_db_and_return_state(
+                                &contract_variant,
                                 state,
                             )
                         )
-                        .and_then(|state|
-                            put_eth_gas_price_in_db_and_return_state(
-                                gas_price,
-                                state
-                            )
-                        )
-                        .and_then(put_eth_account_nonce_in_db_and_return_state)
+                        .and_then(db_step(|db| // NOTE: ∵ of the contract tx!
+                            put_eth_account_nonce_in_db(db, &1)
+                        ))
                         .and_then(generate_and_store_eth_address)
                         .and_then(generate_and_store_eth_contract_address)
                         .and_then(|state|
@@ -99,9 +155,116 @@ pub fn maybe_initialize_eth_enclave<D>(
                                 state,
                             )
                         )
-                        .and_then(end_eth_db_transaction)
+                        .and_then(end_db_transaction)
                         .and_then(get_eth_init_output_json)
                 }
             }
         )
 }
+
+pub fn maybe_initialize_eth_enclave_with_contract_address<D>(
+    db: D,
+    block_json_string: String,
+    chain_id: u8,
+    gas_price: u64,
+    canon_to_tip_length: u64,
+    ptoken_contract_address: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    match check_hex_is_valid_ethereum_address(&ptoken_contract_address) {
+        false => Err(AppError::InvalidAddress(
+            "✘ pToken contract address is not a valid ETH address!"
+                .to_string()
+        )),
+        true => convert_hex_to_address(ptoken_contract_address),
+    }
+        .map(|contract_address| (EthState::init(db), contract_address))
+        .and_then(|(state, contract_address)|
+            match is_eth_enclave_initialized(&state.db) {
+                true => {
+                    info!("✔ ETH Enclave already initialized!");
+                    InitReport::eth_already_initialized().to_json_string()
+                }
+                false => {
+                    info!("✔ Initializing enclave for ETH w/ existing contract...");
+                    initialize_eth_core(
+                        state,
+                        block_json_string,
+                        chain_id,
+                        gas_price,
+                        canon_to_tip_length,
+                    )
+                        .and_then(db_step(|db| // NOTE: ∵ no contract tx!
+                            put_eth_account_nonce_in_db(db, &0)
+                        ))
+                        .and_then(generate_and_store_eth_address)
+                        .and_then(db_step(|db|
+                            put_eth_smart_contract_address_in_db(
+                                db,
+                                &contract_address,
+                            )
+                        ))
+                        .and_then(end_db_transaction)
+                        .and_then(get_eth_init_output_json)
+                }
+            }
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        eth::{
+            eth_test_utils::get_sample_eth_block_and_receipts_string,
+            eth_database_utils::{
+                get_eth_account_nonce_from_db,
+                get_eth_smart_contract_address_from_db,
+            },
+        },
+    };
+
+    const SAMPLE_PTOKEN_CONTRACT_ADDRESS: &str =
+        "0x1739624f5cd969885a224da84418d12b8570d61a";
+
+    #[test]
+    fn should_init_eth_enclave_with_existing_contract_address() {
+        let db = get_test_database();
+        let expected_contract_address = convert_hex_to_address(
+            SAMPLE_PTOKEN_CONTRACT_ADDRESS.to_string()
+        ).unwrap();
+        maybe_initialize_eth_enclave_with_contract_address(
+            &db,
+            get_sample_eth_block_and_receipts_string(0).unwrap(),
+            1,
+            20_000_000_000,
+            2,
+            SAMPLE_PTOKEN_CONTRACT_ADDRESS.to_string(),
+        ).unwrap();
+        assert!(is_eth_enclave_initialized(&db));
+        assert_eq!(get_eth_account_nonce_from_db(&db).unwrap(), 0);
+        assert_eq!(
+            get_eth_smart_contract_address_from_db(&db).unwrap(),
+            expected_contract_address,
+        );
+    }
+
+    #[test]
+    fn should_not_init_eth_enclave_with_invalid_contract_address() {
+        let db = get_test_database();
+        match maybe_initialize_eth_enclave_with_contract_address(
+            &db,
+            get_sample_eth_block_and_receipts_string(0).unwrap(),
+            1,
+            20_000_000_000,
+            2,
+            "0xc0ffee".to_string(),
+        ) {
+            Err(AppError::InvalidAddress(_)) => (),
+            _ => panic!("Should not init w/ invalid contract address!"),
+        }
+        assert!(!is_eth_enclave_initialized(&db));
+    }
+}
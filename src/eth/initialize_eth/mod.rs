@@ -1,3 +1,5 @@
+#[cfg(test)]
+#[allow(dead_code)]
 pub mod test_utils;
 pub mod eth_init_utils;
 pub mod is_eth_initialized;
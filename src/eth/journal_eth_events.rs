@@ -0,0 +1,126 @@
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    correlation_id::get_checked_correlation_id,
+    eth::{
+        eth_state::EthState,
+        eth_database_utils::get_eth_canon_block_from_db,
+    },
+    event_journal::{
+        BridgeEvent,
+        BridgeEventType,
+        append_events_to_journal,
+    },
+};
+
+fn get_reorg_events_from_state<D>(state: &EthState<D>) -> Vec<BridgeEvent>
+    where D: DatabaseInterface
+{
+    match state.eth_reorg_report.as_option() {
+        None => vec![],
+        Some(reorg_report) => vec![
+            BridgeEvent::new(
+                BridgeEventType::ReorgHandled,
+                reorg_report.new_canon_block_hash.clone(),
+                None,
+            )
+        ],
+    }
+}
+
+// NOTE: A BTC tx pays out every redeem in the block, so one event's journaled
+// per redeem a signed tx pays.
+fn get_redeem_and_payout_events_from_state<D>(
+    state: &EthState<D>
+) -> Result<Vec<BridgeEvent>>
+    where D: DatabaseInterface
+{
+    let has_payouts = state.btc_transactions.is_filled();
+    if state.redeem_params.is_empty() && !has_payouts {
+        return Ok(vec![])
+    };
+    let block_hash = format!(
+        "0x{}",
+        hex::encode(get_eth_canon_block_from_db(&state.db)?.block.hash)
+    );
+    let redeem_events = state.redeem_params
+        .iter()
+        .map(|params|
+            BridgeEvent::new(
+                BridgeEventType::RedeemDetected,
+                block_hash.clone(),
+                Some(format!("0x{}", hex::encode(params.originating_tx_hash))),
+            ).with_correlation_id(&params.correlation_id)
+        );
+    let payout_events = state.btc_transactions
+        .as_option()
+        .into_iter()
+        .flatten()
+        .flat_map(|btc_tx| {
+            let tx_hash = btc_tx.txid().to_string();
+            let block_hash = block_hash.clone();
+            state.redeem_params
+                .iter()
+                .map(move |params|
+                    get_checked_correlation_id(&params.correlation_id, &tx_hash)
+                        .map(|correlation_id|
+                            BridgeEvent::new(
+                                BridgeEventType::PayoutSigned,
+                                block_hash.clone(),
+                                Some(tx_hash.clone()),
+                            ).with_correlation_id(&correlation_id)
+                        )
+                )
+        })
+        .collect::<Result<Vec<BridgeEvent>>>()?;
+    Ok(redeem_events.chain(payout_events).collect())
+}
+
+pub fn maybe_journal_eth_bridge_events<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe journaling ETH bridge events...");
+    let events = [
+        get_reorg_events_from_state(&state),
+        get_redeem_and_payout_events_from_state(&state)?,
+    ].concat();
+    match events.is_empty() {
+        true => {
+            info!("✔ No ETH bridge events to journal!");
+            Ok(state)
+        }
+        false => append_events_to_journal(&state.db, events).map(|_| state),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        eth::eth_types::EthReorgReport,
+        event_journal::get_bridge_events_from_journal,
+    };
+
+    #[test]
+    fn should_journal_handled_eth_reorg() {
+        let new_canon_block_hash = "0xc0ffee".to_string();
+        let reorg_report = EthReorgReport {
+            orphaned_redeems: vec![],
+            orphaned_block_hashes: vec![],
+            old_canon_block_hash: "0xdecaf".to_string(),
+            common_ancestor_hash: "0xbeef".to_string(),
+            new_canon_block_hash: new_canon_block_hash.clone(),
+        };
+        let state = EthState::init(get_test_database())
+            .add_eth_reorg_report(reorg_report)
+            .unwrap();
+        let state = maybe_journal_eth_bridge_events(state).unwrap();
+        let events = get_bridge_events_from_journal(&state.db, 0, 10).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, BridgeEventType::ReorgHandled);
+        assert_eq!(events[0].block_hash, new_canon_block_hash);
+    }
+}
@@ -1,4 +1,6 @@
 pub mod trie;
+#[cfg(feature = "ethash-light")]
+pub mod ethash;
 pub mod eth_state;
 pub mod rlp_codec;
 pub mod eth_types;
@@ -10,30 +12,48 @@ pub mod nibble_utils;
 pub mod eth_constants;
 pub mod eth_test_utils;
 pub mod eth_json_codec;
+pub mod eth_abi_encoder;
 pub mod initialize_eth;
 pub mod validate_block;
 pub mod filter_receipts;
 pub mod parse_eth_block;
 pub mod get_linker_hash;
 pub mod submit_eth_block;
+pub mod handle_eth_reorg;
+pub mod journal_eth_events;
 pub mod validate_receipts;
+pub mod validate_eth_block;
 pub mod get_trie_hash_map;
 pub mod parse_eth_receipt;
 pub mod eth_database_utils;
+pub mod validate_pos_header;
 pub mod parse_redeem_params;
 pub mod get_eth_output_json;
 pub mod increment_btc_nonce;
 pub mod check_parent_exists;
+pub mod reprocess_eth_block;
+pub mod queue_redeem_params;
+pub mod replace_eth_txs;
 pub mod filter_redeem_params;
+pub mod deduct_peg_out_fees;
 pub mod save_btc_utxos_to_db;
 pub mod calculate_linker_hash;
+pub mod validation_checkpoint;
 pub mod update_eth_linker_hash;
+pub mod validate_proof_of_work;
+pub mod validate_parent_linkage;
+pub mod verify_receipt_proofs;
 pub mod create_btc_transactions;
 pub mod update_latest_block_hash;
+pub mod filter_processed_redeems;
 pub mod remove_old_eth_tail_block;
 pub mod update_eth_tail_block_hash;
 pub mod extract_utxos_from_btc_txs;
 pub mod update_eth_canon_block_hash;
+pub mod save_processed_redeems_to_db;
+pub mod save_eth_redeem_record_to_db;
 pub mod parse_eth_block_and_receipts;
+pub mod parse_eth_rpc_block;
 pub mod remove_receipts_from_canon_block;
+pub mod clear_confirmed_eth_pending_txs;
 pub mod add_block_and_receipts_to_database;
@@ -360,7 +360,7 @@ pub fn get_nibble_at_index(
     nibble_index: usize
 ) -> Result<Byte> {
     match nibble_index > get_length_in_nibbles(&nibbles) {
-        true => Err(AppError::Custom(
+        true => Err(AppError::TrieError(
             format!(
                 "✘ Index {} is out-of-bounds in nibble vector!",
                 nibble_index
@@ -563,7 +563,7 @@ mod tests {
             out_of_bounds_index
         );
         match get_nibble_at_index(&nibbles, out_of_bounds_index) {
-            Err(AppError::Custom(e)) => assert!(e.contains(expected_error)),
+            Err(AppError::TrieError(e)) => assert!(e.contains(expected_error)),
             _ => panic!("Expected error not receieved!")
         }
     }
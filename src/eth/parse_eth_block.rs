@@ -1,15 +1,29 @@
 use ethereum_types::{
+    H256,
     U256,
     Bloom,
 };
 use crate::{
-    types::Result,
-    eth::eth_types::{
-        EthBlock,
-        EthBlockJson,
+    errors::AppError,
+    types::{
+        Bytes,
+        Result,
+    },
+    eth::{
+        eth_constants::LOGS_BLOOM_LENGTH,
+        eth_types::{
+            EthBlock,
+            EthBlockJson,
+            EthBlockJsonRef,
+        },
     },
     utils::{
         decode_prefixed_hex,
+        decode_prefixed_hex_str,
+        convert_hex_str_to_u256,
+        convert_hex_str_to_h256,
+        convert_hex_str_to_address,
+        convert_hex_strs_to_h256s,
         convert_hex_to_u256,
         convert_hex_to_h256,
         convert_hex_to_bytes,
@@ -19,6 +33,63 @@ use crate::{
     },
 };
 
+fn convert_maybe_hex_to_u256(
+    maybe_hex: Option<String>
+) -> Result<Option<U256>> {
+    match maybe_hex {
+        None => Ok(None),
+        Some(hex) => Ok(Some(convert_hex_to_u256(hex)?)),
+    }
+}
+
+fn convert_maybe_hex_to_h256(
+    maybe_hex: Option<String>
+) -> Result<Option<H256>> {
+    match maybe_hex {
+        None => Ok(None),
+        Some(hex) => Ok(Some(convert_hex_to_h256(hex)?)),
+    }
+}
+
+fn convert_maybe_hex_str_to_u256(
+    maybe_hex_str: Option<&str>
+) -> Result<Option<U256>> {
+    match maybe_hex_str {
+        None => Ok(None),
+        Some(hex_str) => Ok(Some(convert_hex_str_to_u256(hex_str)?)),
+    }
+}
+
+fn convert_maybe_hex_str_to_h256(
+    maybe_hex_str: Option<&str>
+) -> Result<Option<H256>> {
+    match maybe_hex_str {
+        None => Ok(None),
+        Some(hex_str) => Ok(Some(convert_hex_str_to_h256(hex_str)?)),
+    }
+}
+
+fn convert_bytes_to_bloom(bytes: Bytes) -> Result<Bloom> {
+    match bytes.len() {
+        LOGS_BLOOM_LENGTH => Ok(Bloom::from_slice(&bytes)),
+        _ => Err(AppError::ConversionError(
+            format!(
+                "✘ {} bytes required to create logs bloom, {} provided!",
+                LOGS_BLOOM_LENGTH,
+                bytes.len(),
+            )
+        )),
+    }
+}
+
+fn convert_hex_to_bloom(hex: String) -> Result<Bloom> {
+    convert_hex_to_bytes(hex).and_then(convert_bytes_to_bloom)
+}
+
+fn convert_hex_str_to_bloom(hex_str: &str) -> Result<Bloom> {
+    decode_prefixed_hex_str(hex_str).and_then(convert_bytes_to_bloom)
+}
+
 pub fn parse_eth_block_json(
     eth_block_json: EthBlockJson
 ) -> Result<EthBlock> {
@@ -39,9 +110,9 @@ pub fn parse_eth_block_json(
             hash: convert_hex_to_h256(
                 eth_block_json.hash
             )?,
-            logs_bloom: Bloom::from_slice(
-                &convert_hex_to_bytes(eth_block_json.logsBloom)?[..]
-            ),
+            logs_bloom: convert_hex_to_bloom(
+                eth_block_json.logsBloom
+            )?,
             miner: convert_hex_to_address(
                 eth_block_json.miner
             )?,
@@ -58,10 +129,13 @@ pub fn parse_eth_block_json(
             receipts_root: convert_hex_to_h256(
                 eth_block_json.receiptsRoot
             )?,
-            seal_fields: (
-                convert_hex_to_bytes(eth_block_json.sealFields.0)?,
-                convert_hex_to_u256(eth_block_json.sealFields.1)?
-            ),
+            seal_fields: match eth_block_json.sealFields {
+                None => (Vec::new(), U256::zero()),
+                Some(seal_fields) => (
+                    convert_hex_to_bytes(seal_fields.0)?,
+                    convert_hex_to_u256(seal_fields.1)?
+                ),
+            },
             sha3_uncles: convert_hex_to_h256(
                 eth_block_json.sha3Uncles
             )?,
@@ -86,6 +160,86 @@ pub fn parse_eth_block_json(
             uncles: convert_hex_strings_to_h256s(
                 eth_block_json.uncles
             )?,
+            base_fee_per_gas: convert_maybe_hex_to_u256(
+                eth_block_json.baseFeePerGas
+            )?,
+            withdrawals_root: convert_maybe_hex_to_h256(
+                eth_block_json.withdrawalsRoot
+            )?,
+            blob_gas_used: convert_maybe_hex_to_u256(
+                eth_block_json.blobGasUsed
+            )?,
+            excess_blob_gas: convert_maybe_hex_to_u256(
+                eth_block_json.excessBlobGas
+            )?,
+            parent_beacon_block_root: convert_maybe_hex_to_h256(
+                eth_block_json.parentBeaconBlockRoot
+            )?,
+            requests_hash: convert_maybe_hex_to_h256(
+                eth_block_json.requestsHash
+            )?,
+        }
+    )
+}
+
+pub fn parse_eth_block_json_ref(
+    eth_block_json: &EthBlockJsonRef
+) -> Result<EthBlock> {
+    Ok(
+        EthBlock {
+            difficulty: convert_dec_str_to_u256(&eth_block_json.difficulty)?,
+            extra_data: decode_prefixed_hex_str(&eth_block_json.extraData)?,
+            gas_limit: U256::from(eth_block_json.gasLimit),
+            gas_used: U256::from(eth_block_json.gasUsed),
+            hash: convert_hex_str_to_h256(&eth_block_json.hash)?,
+            logs_bloom: convert_hex_str_to_bloom(&eth_block_json.logsBloom)?,
+            miner: convert_hex_str_to_address(&eth_block_json.miner)?,
+            mix_hash: convert_hex_str_to_h256(&eth_block_json.mixHash)?,
+            nonce: decode_prefixed_hex_str(&eth_block_json.nonce)?,
+            number: U256::from(eth_block_json.number),
+            parent_hash: convert_hex_str_to_h256(&eth_block_json.parentHash)?,
+            receipts_root: convert_hex_str_to_h256(
+                &eth_block_json.receiptsRoot
+            )?,
+            seal_fields: match &eth_block_json.sealFields {
+                None => (Vec::new(), U256::zero()),
+                Some((seal_bytes, seal_u256)) => (
+                    decode_prefixed_hex_str(seal_bytes)?,
+                    convert_hex_str_to_u256(seal_u256)?
+                ),
+            },
+            sha3_uncles: convert_hex_str_to_h256(&eth_block_json.sha3Uncles)?,
+            size: U256::from(eth_block_json.size),
+            state_root: convert_hex_str_to_h256(&eth_block_json.stateRoot)?,
+            timestamp: U256::from(eth_block_json.timestamp),
+            total_difficulty: convert_dec_str_to_u256(
+                &eth_block_json.totalDifficulty
+            )?,
+            transactions: convert_hex_strs_to_h256s(
+                &eth_block_json.transactions
+            )?,
+            transactions_root: convert_hex_str_to_h256(
+                &eth_block_json.transactionsRoot
+            )?,
+            uncles: convert_hex_strs_to_h256s(&eth_block_json.uncles)?,
+            base_fee_per_gas: convert_maybe_hex_str_to_u256(
+                eth_block_json.baseFeePerGas.as_deref()
+            )?,
+            withdrawals_root: convert_maybe_hex_str_to_h256(
+                eth_block_json.withdrawalsRoot.as_deref()
+            )?,
+            blob_gas_used: convert_maybe_hex_str_to_u256(
+                eth_block_json.blobGasUsed.as_deref()
+            )?,
+            excess_blob_gas: convert_maybe_hex_str_to_u256(
+                eth_block_json.excessBlobGas.as_deref()
+            )?,
+            parent_beacon_block_root: convert_maybe_hex_str_to_h256(
+                eth_block_json.parentBeaconBlockRoot.as_deref()
+            )?,
+            requests_hash: convert_maybe_hex_str_to_h256(
+                eth_block_json.requestsHash.as_deref()
+            )?,
         }
     )
 }
@@ -93,9 +247,13 @@ pub fn parse_eth_block_json(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::eth::eth_test_utils::{
-        get_expected_block,
-        get_sample_eth_block_and_receipts_json,
+    use crate::eth::{
+        eth_types::EthBlockAndReceiptsJsonRef,
+        eth_test_utils::{
+            get_expected_block,
+            get_sample_eth_block_and_receipts_json,
+            get_sample_eth_block_and_receipts_string,
+        },
     };
 
     #[test]
@@ -107,4 +265,15 @@ mod tests {
             Ok(block) => assert!(block == get_expected_block()),
         }
     }
+
+    #[test]
+    fn should_parse_borrowed_eth_block_json_to_eth_block() {
+        let json_string = get_sample_eth_block_and_receipts_string(0)
+            .unwrap();
+        let json: EthBlockAndReceiptsJsonRef = serde_json::from_str(
+            &json_string
+        ).unwrap();
+        let result = parse_eth_block_json_ref(&json.block).unwrap();
+        assert_eq!(result, get_expected_block());
+    }
 }
@@ -1,24 +1,36 @@
 use crate::{
-    types::Result,
     errors::AppError,
+    utils::convert_hex_to_bytes,
+    types::{
+        Bytes,
+        Result,
+    },
     traits::DatabaseInterface,
     eth::{
         eth_state::EthState,
-        parse_eth_block::parse_eth_block_json,
-        parse_eth_receipt::parse_eth_receipt_jsons,
+        parse_eth_block::{
+            parse_eth_block_json,
+            parse_eth_block_json_ref,
+        },
+        parse_eth_receipt::{
+            parse_eth_receipt_jsons,
+            parse_eth_receipt_json_refs,
+        },
         eth_types::{
+            EthReceiptProofs,
             EthBlockAndReceipts,
             EthBlockAndReceiptsJson,
+            EthBlockAndReceiptsJsonRef,
         },
     },
 };
 
-fn parse_eth_block_and_receipts_json_string(
+pub fn parse_eth_block_and_receipts_json_string(
     eth_block_and_receipt_json_string: &String
 ) -> Result<EthBlockAndReceiptsJson> {
     match serde_json::from_str(&eth_block_and_receipt_json_string) {
         Ok(result) => Ok(result),
-        Err(e) => Err(AppError::Custom(e.to_string()))
+        Err(e) => Err(AppError::InvalidSubmission(e.to_string()))
     }
 }
 
@@ -37,6 +49,46 @@ pub fn parse_eth_block_and_receipts_json(
     )
 }
 
+pub fn parse_eth_block_and_receipts_json_str_to_ref(
+    eth_block_and_receipt_json_str: &str
+) -> Result<EthBlockAndReceiptsJsonRef> {
+    match serde_json::from_str(eth_block_and_receipt_json_str) {
+        Ok(result) => Ok(result),
+        Err(e) => Err(AppError::InvalidSubmission(e.to_string()))
+    }
+}
+
+pub fn parse_eth_block_and_receipts_json_ref(
+    eth_block_and_receipt_json: &EthBlockAndReceiptsJsonRef
+) -> Result<EthBlockAndReceipts> {
+    Ok(
+        EthBlockAndReceipts {
+            block: parse_eth_block_json_ref(
+                &eth_block_and_receipt_json.block
+            )?,
+            receipts: parse_eth_receipt_json_refs(
+                &eth_block_and_receipt_json.receipts
+            )?,
+        }
+    )
+}
+
+pub fn parse_eth_receipt_proofs_json<S: AsRef<str>>(
+    receipt_proofs_json: &[Vec<S>],
+) -> Result<EthReceiptProofs> {
+    receipt_proofs_json
+        .iter()
+        .map(|proof_json|
+            proof_json
+                .iter()
+                .map(|node_hex|
+                    convert_hex_to_bytes(node_hex.as_ref().to_string())
+                )
+                .collect::<Result<Vec<Bytes>>>()
+        )
+        .collect()
+}
+
 pub fn parse_eth_block_and_receipts(
     eth_block_and_receipts: &String
 ) -> Result<EthBlockAndReceipts> {
@@ -44,25 +96,69 @@ pub fn parse_eth_block_and_receipts(
         .and_then(parse_eth_block_and_receipts_json)
 }
 
+fn maybe_add_receipt_proofs_to_state<D>(
+    maybe_receipt_proofs: Option<EthReceiptProofs>,
+    state: EthState<D>,
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    match maybe_receipt_proofs {
+        None => Ok(state),
+        Some(proofs) => {
+            info!("✔ Submission contains ETH receipt proofs!");
+            state.add_eth_receipt_proofs(proofs)
+        }
+    }
+}
+
+pub fn put_eth_block_and_receipts_json_in_state<D>(
+    json: EthBlockAndReceiptsJson,
+    state: EthState<D>,
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    let maybe_receipt_proofs = match &json.receiptProofs {
+        None => None,
+        Some(proofs) => Some(parse_eth_receipt_proofs_json(proofs)?),
+    };
+    parse_eth_block_and_receipts_json(json)
+        .and_then(|result| state.add_eth_block_and_receipts(result))
+        .and_then(|state|
+            maybe_add_receipt_proofs_to_state(maybe_receipt_proofs, state)
+        )
+}
+
+// NOTE: Parses via the borrowed JSON types, so the submission's hex is decoded
+// in place rather than first being copied into a `String` per field.
 pub fn parse_eth_block_and_receipts_and_put_in_state<D>(
     block_json: String,
     state: EthState<D>,
 ) -> Result<EthState<D>>
     where D: DatabaseInterface
 {
-    parse_eth_block_and_receipts(&block_json)
+    let json = parse_eth_block_and_receipts_json_str_to_ref(&block_json)?;
+    let maybe_receipt_proofs = match &json.receiptProofs {
+        None => None,
+        Some(proofs) => Some(parse_eth_receipt_proofs_json(proofs)?),
+    };
+    parse_eth_block_and_receipts_json_ref(&json)
         .and_then(|result| state.add_eth_block_and_receipts(result))
+        .and_then(|state|
+            maybe_add_receipt_proofs_to_state(maybe_receipt_proofs, state)
+        )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
+        test_utils::get_test_database,
         eth::eth_test_utils::{
             get_expected_block,
             get_expected_receipt,
             SAMPLE_RECEIPT_INDEX,
             get_sample_eth_block_and_receipts_string,
+            get_sample_eth_block_and_receipt_proofs_string,
         },
     };
 
@@ -114,4 +210,75 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn should_parse_borrowed_json_as_owned_json_is_parsed() {
+        (0..7).for_each(|i| {
+            let json_string = get_sample_eth_block_and_receipts_string(i)
+                .unwrap();
+            let expected_result = parse_eth_block_and_receipts(&json_string)
+                .unwrap();
+            let result = parse_eth_block_and_receipts_json_str_to_ref(
+                &json_string
+            )
+                .and_then(|json| parse_eth_block_and_receipts_json_ref(&json))
+                .unwrap();
+            assert_eq!(result, expected_result);
+        });
+    }
+
+    #[test]
+    fn should_borrow_hex_from_submission_rather_than_copy_it() {
+        let json_string = get_sample_eth_block_and_receipts_string(0)
+            .unwrap();
+        let json = parse_eth_block_and_receipts_json_str_to_ref(&json_string)
+            .unwrap();
+        let submission_start = json_string.as_ptr() as usize;
+        let hash_start = json.block.hash.as_ptr() as usize;
+        assert!(hash_start > submission_start);
+        assert!(hash_start < submission_start + json_string.len());
+    }
+
+    #[test]
+    fn should_parse_borrowed_json_with_escaped_hex() {
+        let json_string = get_sample_eth_block_and_receipts_string(0)
+            .unwrap();
+        let escaped_json_string = json_string.replace("\"0x", "\"\\u0030x");
+        let expected_result = parse_eth_block_and_receipts_json_str_to_ref(
+            &json_string
+        )
+            .and_then(|json| parse_eth_block_and_receipts_json_ref(&json))
+            .unwrap();
+        let result = parse_eth_block_and_receipts_json_str_to_ref(
+            &escaped_json_string
+        )
+            .and_then(|json| parse_eth_block_and_receipts_json_ref(&json))
+            .unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_parse_eth_block_and_receipts_and_put_in_state_without_proofs() {
+        let json_string = get_sample_eth_block_and_receipts_string(0)
+            .unwrap();
+        let state = parse_eth_block_and_receipts_and_put_in_state(
+            json_string,
+            EthState::init(get_test_database()),
+        ).unwrap();
+        assert!(state.get_eth_receipt_proofs().is_err());
+    }
+
+    #[test]
+    fn should_parse_eth_block_and_receipt_proofs_and_put_in_state() {
+        let json_string = get_sample_eth_block_and_receipt_proofs_string()
+            .unwrap();
+        let state = parse_eth_block_and_receipts_and_put_in_state(
+            json_string,
+            EthState::init(get_test_database()),
+        ).unwrap();
+        let block_and_receipts = state.get_eth_block_and_receipts().unwrap();
+        assert_eq!(block_and_receipts.block, get_expected_block());
+        assert_eq!(block_and_receipts.receipts.len(), 2);
+        assert_eq!(state.get_eth_receipt_proofs().unwrap().len(), 2);
+    }
 }
@@ -1,3 +1,4 @@
+use std::convert::TryFrom;
 use ethereum_types::{
     U256,
     H160,
@@ -5,9 +6,15 @@ use ethereum_types::{
 };
 use crate::{
     types::Result,
+    errors::AppError,
     utils::{
+        convert_u256_to_u64,
         convert_hex_to_h256,
+        convert_hex_to_u256,
         convert_hex_to_address,
+        convert_hex_str_to_h256,
+        convert_hex_str_to_u256,
+        convert_hex_str_to_address,
         convert_json_value_to_string,
     },
     eth::{
@@ -15,14 +22,33 @@ use crate::{
             EthReceipt,
             EthReceipts,
             EthReceiptJson,
+            EthReceiptJsonRef,
         },
         get_eth_log::{
             get_logs_bloom_from_logs,
             get_logs_from_receipt_json,
+            get_logs_from_receipt_json_ref,
         },
     },
 };
 
+fn convert_hex_to_receipt_type(hex: String) -> Result<u8> {
+    convert_u256_to_receipt_type(convert_hex_to_u256(hex)?)
+}
+
+fn convert_hex_str_to_receipt_type(hex_str: &str) -> Result<u8> {
+    convert_u256_to_receipt_type(convert_hex_str_to_u256(hex_str)?)
+}
+
+fn convert_u256_to_receipt_type(receipt_type: U256) -> Result<u8> {
+    convert_u256_to_u64(&receipt_type)
+        .ok()
+        .and_then(|receipt_type| u8::try_from(receipt_type).ok())
+        .ok_or_else(|| AppError::InvalidSubmission(
+            format!("✘ Invalid ETH receipt type: {}!", receipt_type)
+        ))
+}
+
 pub fn parse_eth_receipt_json(
     eth_receipt_json: EthReceiptJson
 ) -> Result<EthReceipt> {
@@ -45,7 +71,7 @@ pub fn parse_eth_receipt_json(
                 eth_receipt_json.blockNumber
             ),
             transaction_hash: convert_hex_to_h256(
-                eth_receipt_json.transactionHash
+                &eth_receipt_json.transactionHash
             )?,
             transaction_index: U256::from(
                 eth_receipt_json.transactionIndex
@@ -54,7 +80,11 @@ pub fn parse_eth_receipt_json(
                 eth_receipt_json.cumulativeGasUsed
             ),
             status: eth_receipt_json.status,
-            to: match eth_receipt_json.to {
+            receipt_type: match &eth_receipt_json.receiptType {
+                None => 0,
+                Some(hex) => convert_hex_to_receipt_type(hex)?,
+            },
+            to: match &eth_receipt_json.to {
                 serde_json::Value::Null => H160::zero(),
                 _ => convert_hex_to_address(
                     convert_json_value_to_string(eth_receipt_json.to)?
@@ -68,7 +98,7 @@ pub fn parse_eth_receipt_json(
                 )?,
             },
             */
-            contract_address: match eth_receipt_json.contractAddress {
+            contract_address: match &eth_receipt_json.contractAddress {
                 serde_json::Value::Null => Address::zero(),
                 _ => convert_hex_to_address(
                     convert_json_value_to_string(
@@ -92,6 +122,52 @@ pub fn parse_eth_receipt_jsons(
         .collect::<Result<EthReceipts>>()
 }
 
+pub fn parse_eth_receipt_json_ref(
+    eth_receipt_json: &EthReceiptJsonRef
+) -> Result<EthReceipt> {
+    let logs = get_logs_from_receipt_json_ref(eth_receipt_json)?;
+    Ok(
+        EthReceipt {
+            from: convert_hex_str_to_address(&eth_receipt_json.from)?,
+            logs_bloom: get_logs_bloom_from_logs(&logs)?,
+            gas_used: U256::from(eth_receipt_json.gasUsed),
+            block_hash: convert_hex_str_to_h256(&eth_receipt_json.blockHash)?,
+            block_number: U256::from(eth_receipt_json.blockNumber),
+            transaction_hash: convert_hex_str_to_h256(
+                &eth_receipt_json.transactionHash
+            )?,
+            transaction_index: U256::from(eth_receipt_json.transactionIndex),
+            cumulative_gas_used: U256::from(
+                eth_receipt_json.cumulativeGasUsed
+            ),
+            status: eth_receipt_json.status,
+            receipt_type: match &eth_receipt_json.receiptType {
+                None => 0,
+                Some(hex_str) => convert_hex_str_to_receipt_type(hex_str)?,
+            },
+            to: match &eth_receipt_json.to {
+                None => H160::zero(),
+                Some(hex_str) => convert_hex_str_to_address(hex_str)?,
+            },
+            contract_address: match &eth_receipt_json.contractAddress {
+                None => Address::zero(),
+                Some(hex_str) => convert_hex_str_to_address(hex_str)?,
+            },
+            logs,
+        }
+    )
+}
+
+pub fn parse_eth_receipt_json_refs(
+    eth_receipts_jsons: &[EthReceiptJsonRef]
+) -> Result<EthReceipts> {
+    trace!("✔ Parsing borrowed ETH receipt JSON...");
+    eth_receipts_jsons
+        .iter()
+        .map(parse_eth_receipt_json_ref)
+        .collect::<Result<EthReceipts>>()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,4 +196,11 @@ mod tests {
             panic!("Should have generated receipts correctly!")
         }
     }
+
+    #[test]
+    fn should_convert_u256_to_receipt_type() {
+        assert_eq!(convert_u256_to_receipt_type(U256::from(2)).unwrap(), 2);
+        assert!(convert_u256_to_receipt_type(U256::from(256)).is_err());
+        assert!(convert_u256_to_receipt_type(U256::max_value()).is_err());
+    }
 }
@@ -0,0 +1,191 @@
+use serde_json::Value as JsonValue;
+use crate::{
+    types::Result,
+    errors::AppError,
+    traits::DatabaseInterface,
+    utils::{
+        strip_hex_prefix,
+        convert_hex_to_u256,
+    },
+    eth::{
+        eth_state::EthState,
+        parse_eth_block_and_receipts::put_eth_block_and_receipts_json_in_state,
+        eth_types::{
+            EthBlockJson,
+            EthReceiptJson,
+            EthRpcBlockJson,
+            EthRpcReceiptJson,
+            EthRpcSubmissionJson,
+            EthBlockAndReceiptsJson,
+        },
+    },
+};
+
+pub fn parse_eth_rpc_submission_string_to_json(
+    eth_rpc_submission_json_string: &str
+) -> Result<EthRpcSubmissionJson> {
+    trace!("✔ Parsing JSON string to `EthRpcSubmissionJson`...");
+    match serde_json::from_str(eth_rpc_submission_json_string) {
+        Ok(json) => Ok(json),
+        Err(e) => Err(AppError::InvalidSubmission(e.to_string()))
+    }
+}
+
+pub fn convert_hex_quantity_to_usize(hex: &str) -> Result<usize> {
+    Ok(usize::from_str_radix(&strip_hex_prefix(hex)?, 16)?)
+}
+
+fn convert_hex_quantity_to_dec_str(hex: String) -> Result<String> {
+    Ok(convert_hex_to_u256(hex)?.to_string())
+}
+
+// NOTE: Accepts the tx hashes of `eth_getBlockByNumber(<number>, false)` as
+// well as the full tx objects returned when its second param is `true`.
+fn get_tx_hash_from_rpc_tx_json(tx_json: &JsonValue) -> Result<String> {
+    match tx_json.get("hash").unwrap_or(tx_json).as_str() {
+        Some(tx_hash) => Ok(tx_hash.to_string()),
+        None => Err(AppError::InvalidSubmission(
+            format!("✘ Could not get tx hash from ETH RPC tx: {}!", tx_json)
+        )),
+    }
+}
+
+fn convert_eth_rpc_block_json_to_eth_block_json(
+    eth_rpc_block_json: EthRpcBlockJson
+) -> Result<EthBlockJson> {
+    Ok(
+        EthBlockJson {
+            difficulty: convert_hex_quantity_to_dec_str(
+                eth_rpc_block_json.difficulty
+            )?,
+            extraData: eth_rpc_block_json.extraData,
+            gasLimit: convert_hex_quantity_to_usize(
+                &eth_rpc_block_json.gasLimit
+            )?,
+            gasUsed: convert_hex_quantity_to_usize(
+                &eth_rpc_block_json.gasUsed
+            )?,
+            hash: eth_rpc_block_json.hash,
+            logsBloom: eth_rpc_block_json.logsBloom,
+            miner: eth_rpc_block_json.miner,
+            mixHash: eth_rpc_block_json.mixHash,
+            nonce: eth_rpc_block_json.nonce,
+            number: convert_hex_quantity_to_usize(
+                &eth_rpc_block_json.number
+            )?,
+            parentHash: eth_rpc_block_json.parentHash,
+            receiptsRoot: eth_rpc_block_json.receiptsRoot,
+            sealFields: None,
+            sha3Uncles: eth_rpc_block_json.sha3Uncles,
+            size: convert_hex_quantity_to_usize(&eth_rpc_block_json.size)?,
+            stateRoot: eth_rpc_block_json.stateRoot,
+            timestamp: convert_hex_quantity_to_usize(
+                &eth_rpc_block_json.timestamp
+            )?,
+            // NOTE: Post-merge nodes may omit the total difficulty.
+            totalDifficulty: match eth_rpc_block_json.totalDifficulty {
+                None => "0".to_string(),
+                Some(hex) => convert_hex_quantity_to_dec_str(hex)?,
+            },
+            transactions: eth_rpc_block_json
+                .transactions
+                .iter()
+                .map(get_tx_hash_from_rpc_tx_json)
+                .collect::<Result<Vec<String>>>()?,
+            transactionsRoot: eth_rpc_block_json.transactionsRoot,
+            uncles: eth_rpc_block_json.uncles,
+            baseFeePerGas: eth_rpc_block_json.baseFeePerGas,
+            withdrawalsRoot: eth_rpc_block_json.withdrawalsRoot,
+            blobGasUsed: eth_rpc_block_json.blobGasUsed,
+            excessBlobGas: eth_rpc_block_json.excessBlobGas,
+            parentBeaconBlockRoot: eth_rpc_block_json.parentBeaconBlockRoot,
+            requestsHash: eth_rpc_block_json.requestsHash,
+        }
+    )
+}
+
+fn convert_eth_rpc_receipt_json_to_eth_receipt_json(
+    eth_rpc_receipt_json: EthRpcReceiptJson
+) -> Result<EthReceiptJson> {
+    Ok(
+        EthReceiptJson {
+            from: eth_rpc_receipt_json.from,
+            status: convert_hex_quantity_to_usize(
+                &eth_rpc_receipt_json.status
+            )? == 1,
+            gasUsed: convert_hex_quantity_to_usize(
+                &eth_rpc_receipt_json.gasUsed
+            )?,
+            blockHash: eth_rpc_receipt_json.blockHash,
+            logsBloom: eth_rpc_receipt_json.logsBloom,
+            logs: eth_rpc_receipt_json.logs,
+            blockNumber: convert_hex_quantity_to_usize(
+                &eth_rpc_receipt_json.blockNumber
+            )?,
+            to: eth_rpc_receipt_json.to,
+            transactionHash: eth_rpc_receipt_json.transactionHash,
+            transactionIndex: convert_hex_quantity_to_usize(
+                &eth_rpc_receipt_json.transactionIndex
+            )?,
+            cumulativeGasUsed: convert_hex_quantity_to_usize(
+                &eth_rpc_receipt_json.cumulativeGasUsed
+            )?,
+            contractAddress: eth_rpc_receipt_json.contractAddress,
+            receiptType: eth_rpc_receipt_json.receiptType,
+        }
+    )
+}
+
+pub fn convert_eth_rpc_json_to_eth_block_and_receipts_json(
+    eth_rpc_submission_json: EthRpcSubmissionJson
+) -> Result<EthBlockAndReceiptsJson> {
+    trace!("✔ Converting ETH RPC JSON to `EthBlockAndReceiptsJson`...");
+    Ok(
+        EthBlockAndReceiptsJson {
+            block: convert_eth_rpc_block_json_to_eth_block_json(
+                eth_rpc_submission_json.block
+            )?,
+            receipts: eth_rpc_submission_json
+                .receipts
+                .into_iter()
+                .map(convert_eth_rpc_receipt_json_to_eth_receipt_json)
+                .collect::<Result<Vec<EthReceiptJson>>>()?,
+            receiptProofs: None,
+        }
+    )
+}
+
+pub fn parse_eth_rpc_block_and_receipts_and_put_in_state<D>(
+    block_json: String,
+    state: EthState<D>,
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Parsing ETH RPC block & receipts...");
+    parse_eth_rpc_submission_string_to_json(&block_json)
+        .and_then(convert_eth_rpc_json_to_eth_block_and_receipts_json)
+        .and_then(|json| put_eth_block_and_receipts_json_in_state(json, state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_convert_hex_quantity_to_usize() {
+        let result = convert_hex_quantity_to_usize("0x1b4").unwrap();
+        assert_eq!(result, 436);
+    }
+
+    #[test]
+    fn should_get_tx_hash_from_rpc_tx_hash_or_tx_object() {
+        let tx_hash =
+            "0x7cf8f2ea9a2f4f3b10bc0e6a0ee5b3d4c47e3d8f1a6e32c3a2b2c0f6f1a2b3c4";
+        let tx_hash_json = JsonValue::String(tx_hash.to_string());
+        let tx_object_json = serde_json::json!({ "hash": tx_hash });
+        let result_1 = get_tx_hash_from_rpc_tx_json(&tx_hash_json).unwrap();
+        let result_2 = get_tx_hash_from_rpc_tx_json(&tx_object_json).unwrap();
+        assert_eq!(result_1, tx_hash);
+        assert_eq!(result_2, tx_hash);
+    }
+}
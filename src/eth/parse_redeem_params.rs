@@ -1,68 +1,153 @@
 use std::str::FromStr;
-use bitcoin::util::address::Address as BtcAddress;
+use bitcoin::{
+    network::constants::Network as BtcNetwork,
+    util::address::Address as BtcAddress,
+};
 use ethereum_types::{
     U256,
     H256 as EthHash,
 };
 use crate::{
-    types::Result,
     errors::AppError,
+    types::{
+        Bytes,
+        Result,
+    },
     traits::DatabaseInterface,
-    constants::SAFE_BTC_ADDRESS,
-    utils::convert_ptoken_to_satoshis,
+    safe_addresses::{
+        SafeAddressTier,
+        TieredSafeAddresses,
+        get_tiered_safe_btc_addresses_from_db,
+    },
+    token_decimals::{
+        REDEEMING_ROUNDING_MODE,
+        convert_host_token_amount_to_satoshis,
+    },
+    btc::btc_database_utils::get_btc_network_from_db,
     eth::{
         eth_state::EthState,
         eth_types::RedeemParams,
-        eth_database_utils::get_eth_canon_block_from_db,
+        eth_database_utils::{
+            get_eth_canon_block_from_db,
+            get_eth_host_token_decimals_from_db,
+            get_eth_smart_contract_address_from_db,
+        },
         eth_types::{
             EthLog,
+            EthAddress,
             EthReceipt,
             EthBlockAndReceipts,
         },
         eth_constants::{
             REDEEM_EVENT_TOPIC_HEX,
             ETH_WORD_SIZE_IN_BYTES,
+            MAX_BTC_ADDRESS_LENGTH,
+            MIN_BTC_ADDRESS_LENGTH,
             LOG_DATA_BTC_ADDRESS_START_INDEX,
         }
     },
 };
 
-fn parse_btc_address_from_log(log: &EthLog) -> Result<String> {
+fn get_btc_address_bytes_from_log(log: &EthLog) -> Result<Bytes> {
+    match log.data.len() > LOG_DATA_BTC_ADDRESS_START_INDEX {
+        true => Ok(
+            log.data[LOG_DATA_BTC_ADDRESS_START_INDEX..]
+                .iter()
+                .filter(|byte| *byte != &0u8)
+                .cloned()
+                .collect::<Bytes>()
+        ),
+        false => Err(AppError::InvalidAddress(
+            "✘ No BTC address in redeem event log data!".to_string()
+        )),
+    }
+}
+
+fn networks_are_compatible(
+    address_network: &BtcNetwork,
+    core_network: &BtcNetwork,
+) -> bool {
+    match (address_network, core_network) {
+        (BtcNetwork::Testnet, BtcNetwork::Regtest) => true,
+        (BtcNetwork::Regtest, BtcNetwork::Testnet) => true,
+        _ => address_network == core_network,
+    }
+}
+
+pub fn validate_btc_address(
+    maybe_btc_address: &str,
+    network: &BtcNetwork,
+) -> Result<BtcAddress> {
+    let address_length = maybe_btc_address.len();
+    if address_length < MIN_BTC_ADDRESS_LENGTH ||
+        address_length > MAX_BTC_ADDRESS_LENGTH
+    {
+        return Err(AppError::InvalidAddress(format!(
+            "✘ BTC address length of {} is invalid!",
+            address_length,
+        )))
+    };
+    let address = match BtcAddress::from_str(maybe_btc_address) {
+        Ok(address) => address,
+        Err(e) => return Err(AppError::InvalidAddress(format!(
+            "✘ BTC address encoding or checksum is invalid: {}!",
+            e,
+        ))),
+    };
+    match networks_are_compatible(&address.network, network) {
+        true => Ok(address),
+        false => Err(AppError::InvalidAddress(format!(
+            "✘ BTC address network '{}' does not match core's '{}' network!",
+            address.network,
+            network,
+        ))),
+    }
+}
+
+fn parse_btc_address_from_log(
+    log: &EthLog,
+    network: &BtcNetwork,
+) -> Result<String> {
     info!("✔ Parsing BTC address from log...");
-    let default_address_error_string = format!(
-        "✔ Defaulting to safe BTC address: {}!",
-        SAFE_BTC_ADDRESS
-    );
-    let maybe_btc_address = log.data[LOG_DATA_BTC_ADDRESS_START_INDEX..]
+    let address_bytes = get_btc_address_bytes_from_log(log)?;
+    if !address_bytes.iter().all(|byte| byte.is_ascii_alphanumeric()) {
+        return Err(AppError::InvalidAddress(
+            "✘ BTC address contains non-alphanumeric characters!".to_string()
+        ))
+    };
+    let maybe_btc_address = address_bytes
         .iter()
-        .filter(|byte| *byte != &0u8)
         .map(|byte| *byte as char)
         .collect::<String>();
     info!("✔ Maybe BTC address parsed from log: {}", maybe_btc_address);
-    match BtcAddress::from_str(&maybe_btc_address) {
-        Ok(address) => {
+    validate_btc_address(&maybe_btc_address, network)
+        .map(|address| {
             info!("✔ Good BTC address parsed from log: {}", address);
-            Ok(address.to_string())
-        },
-        Err(_) => {
-            info!("✔ Failed to parse BTC address from log!");
-            info!("{}", default_address_error_string);
-            Ok(SAFE_BTC_ADDRESS.to_string())
-        }
+            address.to_string()
+        })
+}
+
+fn get_safe_address_tier_for_unparseable_log(log: &EthLog) -> SafeAddressTier {
+    match get_btc_address_bytes_from_log(log) {
+        Ok(bytes) if bytes.len() > MAX_BTC_ADDRESS_LENGTH =>
+            SafeAddressTier::Oversize,
+        _ => SafeAddressTier::MalformedMetadata,
     }
 }
 
-fn parse_redeem_amount_from_log(log: &EthLog) -> Result<U256> {
+fn parse_redeem_amount_from_log(
+    log: &EthLog,
+    token_decimals: u32,
+) -> Result<U256> {
     info!("✔ Parsing redeem amount from log...");
     match log.data.len() >= ETH_WORD_SIZE_IN_BYTES {
-        true => Ok(
-            U256::from(
-                convert_ptoken_to_satoshis(
-                    U256::from(&log.data[..ETH_WORD_SIZE_IN_BYTES])
-                )
-            )
-        ),
-        false => Err(AppError::Custom(format!(
+        true => convert_host_token_amount_to_satoshis(
+            U256::from(&log.data[..ETH_WORD_SIZE_IN_BYTES]),
+            token_decimals,
+            &REDEEMING_ROUNDING_MODE,
+        )
+            .map(U256::from),
+        false => Err(AppError::ConversionError(format!(
             "✘ Not enough bytes in log data to slice out redeem amount!"
         )))
     }
@@ -71,16 +156,40 @@ fn parse_redeem_amount_from_log(log: &EthLog) -> Result<U256> {
 fn parse_redeem_params_from_log_and_receipt(
     eth_log: &EthLog,
     eth_receipt: &EthReceipt,
+    network: &BtcNetwork,
+    token_decimals: u32,
+    safe_btc_addresses: &TieredSafeAddresses<String>,
 ) -> Result<RedeemParams> {
     info!("✔ Parsing redeems from logs...");
-    Ok(
-        RedeemParams::new(
-            parse_redeem_amount_from_log(eth_log)?,
-            eth_receipt.from.clone(),
-            parse_btc_address_from_log(eth_log)?,
-            eth_receipt.transaction_hash.clone(),
-        )
-    )
+    let amount = parse_redeem_amount_from_log(eth_log, token_decimals)?;
+    match parse_btc_address_from_log(eth_log, network) {
+        Ok(recipient) => Ok(
+            RedeemParams::new(
+                amount,
+                eth_receipt.from.clone(),
+                recipient,
+                eth_receipt.transaction_hash.clone(),
+            )
+        ),
+        Err(e) => {
+            info!("✔ Failed to parse BTC address from log: {}", e);
+            let safe_btc_address = safe_btc_addresses.get(
+                get_safe_address_tier_for_unparseable_log(eth_log)
+            );
+            info!("✔ Defaulting to safe BTC address: {}!", safe_btc_address);
+            Ok(
+                RedeemParams {
+                    recipient_diversion_reason: Some(e.to_string()),
+                    ..RedeemParams::new(
+                        amount,
+                        eth_receipt.from.clone(),
+                        safe_btc_address.to_string(),
+                        eth_receipt.transaction_hash.clone(),
+                    )
+                }
+            )
+        }
+    }
 }
 
 fn log_is_redeem(log: &EthLog) -> Result<bool> {
@@ -92,27 +201,52 @@ fn log_is_redeem(log: &EthLog) -> Result<bool> {
 }
 
 fn parse_amount_and_address_tuples_from_receipt(
-    receipt: &EthReceipt
+    receipt: &EthReceipt,
+    contract_address: &EthAddress,
+    network: &BtcNetwork,
+    token_decimals: u32,
+    safe_btc_addresses: &TieredSafeAddresses<String>,
 ) -> Result<Vec<RedeemParams>> {
     info!("✔ Parsing amount & address tuples from receipt...");
     receipt
         .logs
         .iter()
-        .filter(|log| match log_is_redeem(log) {
+        .enumerate()
+        .filter(|(_, log)| &log.address == contract_address)
+        .filter(|(_, log)| match log_is_redeem(log) {
             Ok(true) => true,
             _ => false,
         })
-        .map(|log| parse_redeem_params_from_log_and_receipt(log, receipt))
+        .map(|(log_index, log)|
+            parse_redeem_params_from_log_and_receipt(
+                log,
+                receipt,
+                network,
+                token_decimals,
+                safe_btc_addresses,
+            )
+                .map(|params| params.with_correlation_id(log_index))
+        )
         .collect::<Result<Vec<RedeemParams>>>()
 }
 
-fn parse_redeem_params_from_block(
-    eth_block_and_receipts: EthBlockAndReceipts
+pub fn parse_redeem_params_from_block(
+    eth_block_and_receipts: EthBlockAndReceipts,
+    contract_address: &EthAddress,
+    network: &BtcNetwork,
+    token_decimals: u32,
+    safe_btc_addresses: &TieredSafeAddresses<String>,
 ) -> Result<Vec<RedeemParams>> {
     info!("✔ Parsing redeem params from block...");
     let mut redeem_params_vec = Vec::new();
     for receipt in eth_block_and_receipts.receipts {
-        let structures = parse_amount_and_address_tuples_from_receipt(&receipt)?;
+        let structures = parse_amount_and_address_tuples_from_receipt(
+            &receipt,
+            contract_address,
+            network,
+            token_decimals,
+            safe_btc_addresses,
+        )?;
         for structure in structures {
             redeem_params_vec.push(structure);
         }
@@ -138,7 +272,13 @@ pub fn maybe_parse_redeem_params_and_add_to_state<D>(
                         "✔ Receipts in canon block #{} ∴ parsing params...",
                         block_and_receipts.block.number
                     );
-                    parse_redeem_params_from_block(block_and_receipts)
+                    parse_redeem_params_from_block(
+                        block_and_receipts,
+                        &get_eth_smart_contract_address_from_db(&state.db)?,
+                        &get_btc_network_from_db(&state.db)?,
+                        get_eth_host_token_decimals_from_db(&state.db)?,
+                        &get_tiered_safe_btc_addresses_from_db(&state.db)?,
+                    )
                         .and_then(|redeem_params|
                             state.add_redeem_params(redeem_params)
                         )
@@ -152,9 +292,15 @@ mod tests {
     use super::*;
     use std::str::FromStr;
     use ethereum_types::Address as EthAddress;
-    use crate::eth::eth_test_utils::{
-        get_sample_log_n,
-        get_sample_eth_block_and_receipts_n,
+    use crate::{
+        constants::{
+            SAFE_BTC_ADDRESS,
+            PTOKEN_ERC777_NUM_DECIMALS,
+        },
+        eth::eth_test_utils::{
+            get_sample_log_n,
+            get_sample_eth_block_and_receipts_n,
+        },
     };
 
     fn get_sample_log_with_p2sh_redeem() -> EthLog {
@@ -162,11 +308,20 @@ mod tests {
             .unwrap()
     }
 
+    fn get_sample_safe_btc_addresses() -> TieredSafeAddresses<String> {
+        TieredSafeAddresses::new(SAFE_BTC_ADDRESS.to_string())
+    }
+
     fn get_sample_block_with_redeem() -> EthBlockAndReceipts {
         get_sample_eth_block_and_receipts_n(4)
             .unwrap()
     }
 
+    fn get_sample_ptoken_contract_address() -> EthAddress {
+        EthAddress::from_str("f5a8b686325d79b9239f0a29925503ade0d0cb96")
+            .unwrap()
+    }
+
     fn get_tx_hash_of_redeem_tx() -> &'static str {
         "442612aba789ce873bb3804ff62ced770dcecb07d19ddcf9b651c357eebaed40"
     }
@@ -182,6 +337,7 @@ mod tests {
             get_tx_hash_of_redeem_tx()
         ).unwrap()[..]);
         RedeemParams::new(amount, from, recipient, originating_tx_hash)
+            .with_correlation_id(2)
     }
 
     fn get_sample_receipt_with_redeem() -> EthReceipt {
@@ -207,7 +363,8 @@ mod tests {
     fn should_parse_btc_address_from_log() {
         let expected_result = "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM";
         let log = get_sample_log_with_redeem();
-        let result = parse_btc_address_from_log(&log).unwrap();
+        let result = parse_btc_address_from_log(&log, &BtcNetwork::Testnet)
+            .unwrap();
         assert!(result == expected_result);
     }
 
@@ -216,8 +373,10 @@ mod tests {
         let expected_result = U256::from_dec_str("666")
             .unwrap();
         let log = get_sample_log_with_redeem();
-        let result = parse_redeem_amount_from_log(&log)
-            .unwrap();
+        let result = parse_redeem_amount_from_log(
+            &log,
+            PTOKEN_ERC777_NUM_DECIMALS,
+        ).unwrap();
         assert_eq!(result, expected_result);
     }
 
@@ -226,6 +385,9 @@ mod tests {
         let result = parse_redeem_params_from_log_and_receipt(
             &get_sample_log_with_redeem(),
             &get_sample_receipt_with_redeem(),
+            &BtcNetwork::Testnet,
+            PTOKEN_ERC777_NUM_DECIMALS,
+            &get_sample_safe_btc_addresses(),
         ).unwrap();
         assert_eq!(result, get_expected_redeem_params());
     }
@@ -249,16 +411,36 @@ mod tests {
     fn should_parse_amount_and_address_tuples_from_receipt() {
         let expected_num_results = 1;
         let result = parse_amount_and_address_tuples_from_receipt(
-            &get_sample_receipt_with_redeem()
+            &get_sample_receipt_with_redeem(),
+            &get_sample_ptoken_contract_address(),
+            &BtcNetwork::Testnet,
+            PTOKEN_ERC777_NUM_DECIMALS,
+            &get_sample_safe_btc_addresses(),
         ).unwrap();
         assert_eq!(result.len(), expected_num_results);
         assert_eq!(result[0], get_expected_redeem_params());
     }
 
+    #[test]
+    fn should_not_parse_redeem_params_from_other_contract_address() {
+        let result = parse_amount_and_address_tuples_from_receipt(
+            &get_sample_receipt_with_redeem(),
+            &EthAddress::zero(),
+            &BtcNetwork::Testnet,
+            PTOKEN_ERC777_NUM_DECIMALS,
+            &get_sample_safe_btc_addresses(),
+        ).unwrap();
+        assert!(result.is_empty());
+    }
+
     #[test]
     fn should_parse_redeem_params_from_block() {
         let result = parse_redeem_params_from_block(
-            get_sample_block_with_redeem()
+            get_sample_block_with_redeem(),
+            &get_sample_ptoken_contract_address(),
+            &BtcNetwork::Testnet,
+            PTOKEN_ERC777_NUM_DECIMALS,
+            &get_sample_safe_btc_addresses(),
         ).unwrap();
         let expected_result = RedeemParams {
             amount: U256::from_dec_str("666").unwrap(),
@@ -270,6 +452,9 @@ mod tests {
                 &hex::decode(get_tx_hash_of_redeem_tx())
                 .unwrap()[..]
             ),
+            recipient_diversion_reason: None,
+            correlation_id: String::new(),
+            peg_out_fee: 0,
         };
         assert_eq!(expected_result.from, result[0].from);
         assert_eq!(expected_result.amount, result[0].amount);
@@ -283,7 +468,77 @@ mod tests {
     fn should_parse_p2sh_btc_address_from_log() {
         let expected_result = "2MyT7cyDnsHFwkhGDJa3LhayYtPN3cSE7wx";
         let log = get_sample_log_with_p2sh_redeem();
-        let result = parse_btc_address_from_log(&log).unwrap();
+        let result = parse_btc_address_from_log(&log, &BtcNetwork::Testnet)
+            .unwrap();
         assert!(result == expected_result);
     }
+
+    #[test]
+    fn should_divert_redeem_to_safe_address_if_network_mismatch() {
+        let result = parse_redeem_params_from_log_and_receipt(
+            &get_sample_log_with_redeem(),
+            &get_sample_receipt_with_redeem(),
+            &BtcNetwork::Bitcoin,
+            PTOKEN_ERC777_NUM_DECIMALS,
+            &get_sample_safe_btc_addresses(),
+        ).unwrap();
+        assert_eq!(result.recipient, SAFE_BTC_ADDRESS);
+        assert!(result.recipient_diversion_reason.is_some());
+    }
+
+    #[test]
+    fn should_divert_oversize_btc_address_to_oversize_safe_address() {
+        let oversize_safe_address = "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM";
+        let safe_btc_addresses = TieredSafeAddresses {
+            oversize: oversize_safe_address.to_string(),
+            ..get_sample_safe_btc_addresses()
+        };
+        let mut log = get_sample_log_with_redeem();
+        log.data.extend_from_slice(&[b'a'; MAX_BTC_ADDRESS_LENGTH]);
+        let result = parse_redeem_params_from_log_and_receipt(
+            &log,
+            &get_sample_receipt_with_redeem(),
+            &BtcNetwork::Testnet,
+            PTOKEN_ERC777_NUM_DECIMALS,
+            &safe_btc_addresses,
+        ).unwrap();
+        assert_eq!(result.recipient, oversize_safe_address);
+        let result = parse_redeem_params_from_log_and_receipt(
+            &get_sample_log_with_redeem(),
+            &get_sample_receipt_with_redeem(),
+            &BtcNetwork::Bitcoin,
+            PTOKEN_ERC777_NUM_DECIMALS,
+            &safe_btc_addresses,
+        ).unwrap();
+        assert_eq!(result.recipient, SAFE_BTC_ADDRESS);
+    }
+
+    #[test]
+    fn should_fail_to_validate_btc_address_with_bad_checksum() {
+        let address = "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiN";
+        let result = validate_btc_address(address, &BtcNetwork::Testnet);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_fail_to_validate_btc_address_with_invalid_length() {
+        let address = "mudzxCq9aCQ";
+        let result = validate_btc_address(address, &BtcNetwork::Testnet);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_fail_to_parse_btc_address_with_non_alphanumeric_chars() {
+        let mut log = get_sample_log_with_redeem();
+        log.data[LOG_DATA_BTC_ADDRESS_START_INDEX + 32] = b'!';
+        let result = parse_btc_address_from_log(&log, &BtcNetwork::Testnet);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_validate_testnet_btc_address_on_regtest() {
+        let address = "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM";
+        let result = validate_btc_address(address, &BtcNetwork::Regtest);
+        assert!(result.is_ok());
+    }
 }
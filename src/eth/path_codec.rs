@@ -103,7 +103,7 @@ pub fn decode_path_to_nibbles_and_node_type(
             decode_odd_length_nibbles(nibbles)?,
             EXTENSION_NODE_STRING
         )),
-        _ => Err(AppError::Custom(
+        _ => Err(AppError::TrieError(
             "✘ Malformed path - cannot determine node type!".to_string()
         ))
     }
@@ -283,7 +283,7 @@ mod tests {
             .to_string();
         match decode_path_to_nibbles_and_node_type(wrong_path) {
             Ok(_) => panic!("Should not decode a bad encoding!"),
-            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            Err(AppError::TrieError(e)) => assert!(e == expected_error),
             _ => panic!("Didn't get correct decoding error!"),
         }
     }
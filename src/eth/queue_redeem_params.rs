@@ -0,0 +1,108 @@
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    database_utils::core_is_paused,
+    eth::{
+        eth_state::EthState,
+        eth_database_utils::{
+            put_eth_queued_redeem_params_in_db,
+            get_eth_queued_redeem_params_from_db,
+        },
+    },
+};
+
+pub fn maybe_queue_or_release_redeem_params<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe queueing or releasing redeem params...");
+    let queued_params = get_eth_queued_redeem_params_from_db(&state.db)?;
+    match core_is_paused(&state.db) {
+        true => match state.redeem_params.is_empty() {
+            true => {
+                info!("✔ Core is paused but no redeem params to queue!");
+                Ok(state)
+            }
+            false => {
+                info!(
+                    "✔ Core is paused ∴ queueing {} redeem params...",
+                    state.redeem_params.len(),
+                );
+                put_eth_queued_redeem_params_in_db(
+                    &state.db,
+                    &[queued_params, state.redeem_params.clone()].concat(),
+                )
+                    .and_then(|_| state.replace_redeem_params(vec![]))
+            }
+        },
+        false => match queued_params.is_empty() {
+            true => {
+                info!("✔ No queued redeem params to release!");
+                Ok(state)
+            }
+            false => {
+                info!(
+                    "✔ Core is not paused ∴ releasing {} queued redeem params...",
+                    queued_params.len(),
+                );
+                let redeem_params =
+                    [queued_params, state.redeem_params.clone()].concat();
+                put_eth_queued_redeem_params_in_db(&state.db, &vec![])
+                    .and_then(|_| state.replace_redeem_params(redeem_params))
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethereum_types::U256;
+    use crate::{
+        test_utils::get_test_database,
+        database_utils::put_core_is_paused_in_db,
+        eth::{
+            eth_types::{
+                EthHash,
+                RedeemParams,
+            },
+            eth_test_utils::get_sample_eth_address,
+        },
+    };
+
+    fn get_sample_redeem_params() -> Vec<RedeemParams> {
+        vec![
+            RedeemParams::new(
+                U256::from(1337),
+                get_sample_eth_address(),
+                "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM".to_string(),
+                EthHash::zero(),
+            )
+        ]
+    }
+
+    #[test]
+    fn should_queue_redeem_params_if_core_is_paused_and_release_after() {
+        let db = get_test_database();
+        let redeem_params = get_sample_redeem_params();
+        put_core_is_paused_in_db(&db, true).unwrap();
+        let state = EthState::init(db)
+            .add_redeem_params(redeem_params.clone())
+            .and_then(maybe_queue_or_release_redeem_params)
+            .unwrap();
+        assert!(state.redeem_params.is_empty());
+        assert_eq!(
+            get_eth_queued_redeem_params_from_db(&state.db).unwrap(),
+            redeem_params,
+        );
+        put_core_is_paused_in_db(&state.db, false).unwrap();
+        let state = maybe_queue_or_release_redeem_params(state).unwrap();
+        assert_eq!(state.redeem_params, redeem_params);
+        assert!(
+            get_eth_queued_redeem_params_from_db(&state.db)
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -3,13 +3,23 @@ use crate::{
     types::Result,
     errors::AppError,
     traits::DatabaseInterface,
+    utils::convert_u256_to_u64,
     eth::{
         eth_types::EthBlockAndReceipts,
         eth_state::EthState,
+        remove_receipts_from_canon_block::remove_receipts_from_block,
         eth_database_utils::{
             get_eth_block_from_db,
+            delete_eth_block_from_db,
             get_eth_tail_block_from_db,
+            put_eth_block_and_receipts_in_db,
+            put_eth_pruned_block_hash_in_db,
             get_eth_anchor_block_hash_from_db,
+            put_eth_tail_pruned_height_in_db,
+            get_eth_tail_pruned_height_from_db,
+            delete_eth_pruned_block_hash_from_db,
+            get_eth_tail_pruning_distance_from_db,
+            maybe_get_eth_pruned_block_hash_from_db,
         },
     },
 };
@@ -22,7 +32,7 @@ fn is_anchor_block<D>(
 {
     match get_eth_anchor_block_hash_from_db(db) {
         Ok(hash) => Ok(&hash == eth_block_hash),
-        _ => Err(AppError::Custom(
+        _ => Err(AppError::NotFound(
             format!("✘ No anchor hash found in db!")
         ))
     }
@@ -51,8 +61,15 @@ fn remove_parents_if_not_anchor<D>(
                 }
                 false => {
                     info!("✔ Block is NOT the anchor ∴ removing it...");
-                    db
-                        .delete(parent_block.block.hash.as_bytes().to_vec())
+                    let block_number =
+                        convert_u256_to_u64(&parent_block.block.number)?;
+                    delete_eth_block_from_db(db, &parent_block.block.hash)
+                        .and_then(|_|
+                            delete_eth_pruned_block_hash_from_db(
+                                db,
+                                block_number,
+                            )
+                        )
                         .and_then(|_|
                             remove_parents_if_not_anchor(db, &parent_block)
                         )
@@ -62,15 +79,162 @@ fn remove_parents_if_not_anchor<D>(
     }
 }
 
+fn drop_receipts_from_block<D>(
+    db: &D,
+    block: EthBlockAndReceipts,
+) -> Result<EthBlockAndReceipts>
+    where D: DatabaseInterface
+{
+    let pruned_block = match block.receipts.is_empty() {
+        true => block,
+        false => {
+            info!("✔ Dropping receipts from block behind ETH tail...");
+            let pruned_block = remove_receipts_from_block(block);
+            put_eth_block_and_receipts_in_db(db, &pruned_block)?;
+            pruned_block
+        }
+    };
+    put_eth_pruned_block_hash_in_db(
+        db,
+        convert_u256_to_u64(&pruned_block.block.number)?,
+        &pruned_block.block.hash,
+    )
+        .map(|_| pruned_block)
+}
+
+fn prune_parents_behind_block<D>(
+    db: &D,
+    block_whose_parents_to_be_pruned: &EthBlockAndReceipts,
+    pruning_distance: u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    if pruning_distance == 0 {
+        return remove_parents_if_not_anchor(
+            db,
+            block_whose_parents_to_be_pruned,
+        )
+    };
+    match get_eth_block_from_db(
+        db,
+        &block_whose_parents_to_be_pruned.block.parent_hash,
+    ) {
+        Err(_) => {
+            info!("✔ No block found ∵ doing nothing!");
+            Ok(())
+        }
+        Ok(parent_block) => {
+            let pruned_block = drop_receipts_from_block(db, parent_block)?;
+            prune_parents_behind_block(db, &pruned_block, pruning_distance - 1)
+        }
+    }
+}
+
+fn drop_receipts_from_new_blocks_behind_block<D>(
+    db: &D,
+    block: &EthBlockAndReceipts,
+    num_new_blocks: u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    if num_new_blocks == 0 {
+        return Ok(())
+    };
+    match get_eth_block_from_db(db, &block.block.parent_hash) {
+        Err(_) => {
+            info!("✔ No block found ∵ doing nothing!");
+            Ok(())
+        }
+        Ok(parent_block) => {
+            let pruned_block = drop_receipts_from_block(db, parent_block)?;
+            drop_receipts_from_new_blocks_behind_block(
+                db,
+                &pruned_block,
+                num_new_blocks - 1,
+            )
+        }
+    }
+}
+
+fn remove_pruned_block_if_not_anchor<D>(
+    db: &D,
+    block_number: u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    match maybe_get_eth_pruned_block_hash_from_db(db, block_number) {
+        None => Ok(()),
+        Some(block_hash) => {
+            match is_anchor_block(db, &block_hash)? {
+                true => {
+                    info!("✔ Block IS the anchor block ∴ not removing it!");
+                    Ok(())
+                }
+                false => {
+                    info!("✔ Block is NOT the anchor ∴ removing it...");
+                    delete_eth_block_from_db(db, &block_hash)
+                }
+            }
+                .and_then(|_|
+                    delete_eth_pruned_block_hash_from_db(db, block_number)
+                )
+        }
+    }
+}
+
+fn prune_blocks_behind_tail<D>(
+    db: &D,
+    tail_block: &EthBlockAndReceipts,
+    pruning_distance: u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    let tail_height = convert_u256_to_u64(&tail_block.block.number)?;
+    // NOTE: Only the blocks the tail has advanced over since the last pruning
+    // need touching: their receipts get dropped & the same number of indexed
+    // blocks fall out of the back of the window. Any other case (no pruned
+    // height yet, a new distance, a tail which jumped the whole window) falls
+    // back to one full walk, which (re)builds the index as it goes.
+    match get_eth_tail_pruned_height_from_db(db) {
+        Ok(pruned_height) if
+            pruning_distance > 0 &&
+            tail_height >= pruned_height &&
+            tail_height - pruned_height <= pruning_distance
+        => {
+            info!("✔ Incrementally pruning ETH blocks behind tail...");
+            drop_receipts_from_new_blocks_behind_block(
+                db,
+                tail_block,
+                tail_height - pruned_height,
+            )?;
+            (
+                pruned_height.saturating_sub(pruning_distance)..
+                tail_height.saturating_sub(pruning_distance)
+            )
+                .map(|block_number|
+                    remove_pruned_block_if_not_anchor(db, block_number)
+                )
+                .collect::<Result<()>>()
+        }
+        _ => prune_parents_behind_block(db, tail_block, pruning_distance),
+    }
+        .and_then(|_| put_eth_tail_pruned_height_in_db(db, &tail_height))
+}
+
 pub fn maybe_remove_old_eth_tail_block<D>(
     state: EthState<D>
 ) -> Result<EthState<D>>
     where D: DatabaseInterface
 {
     info!("✔ Maybe removing old ETH tail block...");
+    let pruning_distance = get_eth_tail_pruning_distance_from_db(&state.db)?;
     get_eth_tail_block_from_db(&state.db)
         .and_then(|tail_block|
-            remove_parents_if_not_anchor(&state.db, &tail_block)
+            prune_blocks_behind_tail(
+                &state.db,
+                &tail_block,
+                pruning_distance,
+            )
         )
         .and_then(|_| Ok(state))
 }
@@ -86,11 +250,21 @@ mod tests {
                 eth_block_exists_in_db,
                 put_eth_tail_block_in_db,
                 put_eth_anchor_block_in_db,
-                put_eth_block_and_receipts_in_db
+                put_eth_tail_pruning_distance_in_db,
             },
         },
     };
 
+    fn prune_behind_tail(
+        db: crate::test_utils::TestDB,
+        tail_block: &EthBlockAndReceipts,
+    ) -> crate::test_utils::TestDB {
+        put_eth_tail_block_in_db(&db, tail_block).unwrap();
+        maybe_remove_old_eth_tail_block(EthState::init(db))
+            .unwrap()
+            .db
+    }
+
     #[test]
     fn should_return_false_block_is_not_anchor_block() {
         let db = get_test_database();
@@ -211,4 +385,122 @@ mod tests {
         assert!(eth_block_exists_in_db(&db, &tail_block.block.hash));
         assert!(eth_block_exists_in_db(&db, &anchor_block.block.hash));
     }
+
+    #[test]
+    fn should_drop_receipts_then_bodies_of_blocks_behind_tail() {
+        let db = get_test_database();
+        let all_blocks = get_sequential_eth_blocks_and_receipts();
+        let anchor_block = all_blocks[0].clone();
+        let tail_block = all_blocks[all_blocks.len() - 1].clone();
+        let blocks = &all_blocks[1..all_blocks.len() - 1];
+        let pruning_distance = 5;
+        put_eth_anchor_block_in_db(&db, &anchor_block).unwrap();
+        put_eth_tail_block_in_db(&db, &tail_block).unwrap();
+        put_eth_tail_pruning_distance_in_db(&db, &pruning_distance).unwrap();
+        blocks
+            .iter()
+            .map(|block| put_eth_block_and_receipts_in_db(&db, block))
+            .collect::<Result<()>>()
+            .unwrap();
+        let state = maybe_remove_old_eth_tail_block(EthState::init(db))
+            .unwrap();
+        let (removed_blocks, retained_blocks) = blocks
+            .split_at(blocks.len() - pruning_distance as usize);
+        removed_blocks
+            .iter()
+            .for_each(|block|
+                assert!(!eth_block_exists_in_db(&state.db, &block.block.hash))
+            );
+        retained_blocks
+            .iter()
+            .for_each(|block| {
+                let result = get_eth_block_from_db(
+                    &state.db,
+                    &block.block.hash,
+                ).unwrap();
+                assert_eq!(result.block, block.block);
+                assert!(result.receipts.is_empty());
+            });
+        assert!(eth_block_exists_in_db(&state.db, &anchor_block.block.hash));
+    }
+
+    #[test]
+    fn should_only_prune_blocks_tail_advanced_over_since_last_pruning() {
+        let db = get_test_database();
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        let last = blocks.len() - 1;
+        put_eth_anchor_block_in_db(&db, &blocks[0]).unwrap();
+        put_eth_tail_pruning_distance_in_db(&db, &3).unwrap();
+        blocks[1..]
+            .iter()
+            .map(|block| put_eth_block_and_receipts_in_db(&db, block))
+            .collect::<Result<()>>()
+            .unwrap();
+        let db = prune_behind_tail(db, &blocks[last - 1]);
+        assert_eq!(
+            get_eth_tail_pruned_height_from_db(&db).unwrap(),
+            blocks[last - 1].block.number.as_u64(),
+        );
+        assert!(!eth_block_exists_in_db(&db, &blocks[last - 5].block.hash));
+        // NOTE: A full walk would strip these receipts again...
+        assert!(!blocks[last - 3].receipts.is_empty());
+        put_eth_block_and_receipts_in_db(&db, &blocks[last - 3]).unwrap();
+        let db = prune_behind_tail(db, &blocks[last]);
+        assert_eq!(
+            get_eth_tail_pruned_height_from_db(&db).unwrap(),
+            blocks[last].block.number.as_u64(),
+        );
+        assert!(!eth_block_exists_in_db(&db, &blocks[last - 4].block.hash));
+        assert!(
+            maybe_get_eth_pruned_block_hash_from_db(
+                &db,
+                blocks[last - 4].block.number.as_u64(),
+            ).is_none()
+        );
+        let untouched_block = get_eth_block_from_db(
+            &db,
+            &blocks[last - 3].block.hash,
+        ).unwrap();
+        assert_eq!(untouched_block, blocks[last - 3]);
+        let newly_pruned_block = get_eth_block_from_db(
+            &db,
+            &blocks[last - 1].block.hash,
+        ).unwrap();
+        assert!(newly_pruned_block.receipts.is_empty());
+        assert!(eth_block_exists_in_db(&db, &blocks[0].block.hash));
+    }
+
+    #[test]
+    fn should_fall_back_to_full_walk_after_pruning_distance_changes() {
+        let db = get_test_database();
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        let last = blocks.len() - 1;
+        put_eth_anchor_block_in_db(&db, &blocks[0]).unwrap();
+        put_eth_tail_pruning_distance_in_db(&db, &5).unwrap();
+        blocks[1..]
+            .iter()
+            .map(|block| put_eth_block_and_receipts_in_db(&db, block))
+            .collect::<Result<()>>()
+            .unwrap();
+        let db = prune_behind_tail(db, &blocks[last]);
+        put_eth_tail_pruning_distance_in_db(&db, &2).unwrap();
+        assert!(get_eth_tail_pruned_height_from_db(&db).is_err());
+        let db = prune_behind_tail(db, &blocks[last]);
+        blocks[last - 5..last - 2]
+            .iter()
+            .for_each(|block| {
+                assert!(!eth_block_exists_in_db(&db, &block.block.hash));
+                assert!(
+                    maybe_get_eth_pruned_block_hash_from_db(
+                        &db,
+                        block.block.number.as_u64(),
+                    ).is_none()
+                );
+            });
+        blocks[last - 2..last]
+            .iter()
+            .for_each(|block|
+                assert!(eth_block_exists_in_db(&db, &block.block.hash))
+            );
+    }
 }
@@ -0,0 +1,375 @@
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    stale_transactions::replace_watched_tx_in_db,
+    constants::ETH_ADDRESS_LENGTH,
+    types::Result,
+    eth::{
+        eth_constants::VALUE_FOR_MINTING_TX,
+        eth_crypto::eth_transaction::{
+            EthTransaction,
+            get_signed_cancel_tx,
+        },
+        eth_types::{
+            EthAddress,
+            EthPendingTx,
+            EthMintTxRecord,
+            EthTransactionType,
+        },
+        eth_database_utils::{
+            get_eth_chain_id_from_db,
+            get_eth_private_key_from_db,
+            get_public_eth_address_from_db,
+            put_eth_mint_tx_record_in_db,
+            get_eth_mint_gas_limit_from_db,
+            get_eth_pending_tx_from_db,
+            get_eth_cancel_gas_limit_from_db,
+            get_eth_mint_tx_record_from_db,
+            add_eth_pending_tx_to_queue_in_db,
+            eth_pre_eip155_signing_is_enabled,
+            get_eth_max_priority_fee_per_gas_from_db,
+        },
+    },
+};
+
+// NOTE: Nodes only accept a tx replacing a pending one w/ the same nonce if it
+// raises the fee by at least 10%.
+pub fn get_minimum_replacement_fee(previous_fee: u64) -> u64 {
+    let bump = previous_fee / 10 + (previous_fee % 10 != 0) as u64;
+    previous_fee.saturating_add(bump)
+}
+
+pub fn check_replacement_fee_is_sufficient(
+    fee_name: &str,
+    new_fee: u64,
+    previous_fee: u64,
+) -> Result<()> {
+    let minimum_fee = get_minimum_replacement_fee(previous_fee);
+    match new_fee >= minimum_fee {
+        true => Ok(()),
+        false => Err(AppError::InvalidAmount(format!(
+            "✘ New {} of {} must be at least {} to replace previous of {}!",
+            fee_name,
+            new_fee,
+            minimum_fee,
+            previous_fee,
+        ))),
+    }
+}
+
+fn get_recipient_from_mint_tx_record(
+    record: &EthMintTxRecord,
+) -> Result<EthAddress> {
+    match record.to.len() == ETH_ADDRESS_LENGTH {
+        true => Ok(EthAddress::from_slice(&record.to)),
+        false => Err(AppError::InvalidAddress(format!(
+            "✘ ETH mint tx record w/ nonce {} has invalid `to` address: 0x{}!",
+            record.nonce,
+            hex::encode(&record.to),
+        ))),
+    }
+}
+
+fn get_gas_limit_for_mint_tx_record<D>(
+    db: &D,
+    record: &EthMintTxRecord,
+) -> Result<usize>
+    where D: DatabaseInterface
+{
+    match record.gas_limit {
+        0 => get_eth_mint_gas_limit_from_db(db).map(|limit| limit as usize),
+        gas_limit => Ok(gas_limit as usize),
+    }
+}
+
+// NOTE: For dynamic fee txs the `gas_price` is the new max fee per gas, whilst
+// the priority fee is bumped by the minimum the replacement rules require.
+pub fn get_signed_replacement_mint_tx<D>(
+    db: &D,
+    record: &EthMintTxRecord,
+    gas_price: u64,
+) -> Result<EthTransaction>
+    where D: DatabaseInterface
+{
+    info!(
+        "✔ Signing {} replacement for ETH mint tx w/ nonce {}...",
+        record.tx_type,
+        record.nonce,
+    );
+    check_replacement_fee_is_sufficient(
+        "gas price",
+        gas_price,
+        record.gas_price,
+    )?;
+    let to = get_recipient_from_mint_tx_record(record)?;
+    let chain_id = get_eth_chain_id_from_db(db)?;
+    let gas_limit = get_gas_limit_for_mint_tx_record(db, record)?;
+    let unsigned_tx = match record.tx_type {
+        EthTransactionType::Legacy => EthTransaction::new(
+            record.data.clone(),
+            record.nonce,
+            VALUE_FOR_MINTING_TX,
+            to,
+            chain_id,
+            gas_limit,
+            gas_price,
+        )
+            .with_eip155(!eth_pre_eip155_signing_is_enabled(db)),
+        EthTransactionType::DynamicFee => {
+            let max_priority_fee_per_gas = std::cmp::max(
+                get_minimum_replacement_fee(record.max_priority_fee_per_gas),
+                get_eth_max_priority_fee_per_gas_from_db(db).unwrap_or(0),
+            );
+            if max_priority_fee_per_gas > gas_price {
+                return Err(AppError::InvalidAmount(format!(
+                    "✘ Max fee per gas of {} is below priority fee of {}!",
+                    gas_price,
+                    max_priority_fee_per_gas,
+                )))
+            };
+            EthTransaction::new_dynamic_fee(
+                record.data.clone(),
+                record.nonce,
+                VALUE_FOR_MINTING_TX,
+                to,
+                chain_id,
+                gas_limit,
+                gas_price,
+                max_priority_fee_per_gas,
+            )
+        },
+    };
+    unsigned_tx.sign(get_eth_private_key_from_db(db)?)
+}
+
+// NOTE: The pending queue is keyed by nonce ∴ a replacement supersedes the
+// entry of the tx it replaces, whose hash it carries in case that's mined. The
+// watchlist is likewise updated, so the replaced tx isn't reported as stale.
+pub fn track_replacement_eth_tx_in_db<D>(
+    db: &D,
+    signed_tx: &EthTransaction,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!(
+        "✔ Tracking replacement ETH tx w/ nonce {}...",
+        signed_tx.nonce,
+    );
+    let pending_tx = EthPendingTx::from_eth_tx(signed_tx);
+    let mut pending_tx = match get_eth_pending_tx_from_db(
+        db,
+        pending_tx.nonce,
+    ) {
+        Ok(previous_tx) => pending_tx.replacing(&previous_tx),
+        Err(_) => pending_tx,
+    };
+    let mut replaced_tx_hashes = pending_tx.replaced_tx_hashes.clone();
+    if let Ok(record) = get_eth_mint_tx_record_from_db(db, pending_tx.nonce) {
+        replaced_tx_hashes.push(record.tx_hash);
+        pending_tx.correlation_ids = record.correlation_ids;
+    };
+    replace_watched_tx_in_db(
+        db,
+        "ETH",
+        &replaced_tx_hashes,
+        &pending_tx.tx_hash,
+    )
+        .and_then(|_| add_eth_pending_tx_to_queue_in_db(db, &pending_tx))
+}
+
+pub fn replace_eth_mint_tx_in_db<D>(
+    db: &D,
+    nonce: u64,
+    gas_price: u64,
+) -> Result<EthTransaction>
+    where D: DatabaseInterface
+{
+    get_eth_mint_tx_record_from_db(db, nonce)
+        .and_then(|record| {
+            let signed_tx = get_signed_replacement_mint_tx(
+                db,
+                &record,
+                gas_price,
+            )?;
+            // NOTE: Tracked first, whilst the record still holds the hash of
+            // the tx being replaced.
+            track_replacement_eth_tx_in_db(db, &signed_tx)?;
+            put_eth_mint_tx_record_in_db(
+                db,
+                &EthMintTxRecord::from_eth_tx(&signed_tx)
+                    .with_correlation_ids(record.correlation_ids),
+            )?;
+            Ok(signed_tx)
+        })
+}
+
+// NOTE: Nonces in a gap which still have a mint record are re-signed so the
+// mint isn't lost; only those w/o one are filled by a "cancel" tx.
+pub fn sign_eth_nonce_gap_tx_in_db<D>(
+    db: &D,
+    nonce: u64,
+    gas_price: u64,
+) -> Result<(&'static str, EthTransaction)>
+    where D: DatabaseInterface
+{
+    match get_eth_mint_tx_record_from_db(db, nonce) {
+        Ok(_) => replace_eth_mint_tx_in_db(db, nonce, gas_price)
+            .map(|signed_tx| ("mint", signed_tx)),
+        Err(_) => {
+            info!("✔ No mint record for nonce {} ∴ cancelling it...", nonce);
+            let signed_tx = get_signed_cancel_tx(
+                nonce,
+                get_eth_chain_id_from_db(db)?,
+                !eth_pre_eip155_signing_is_enabled(db),
+                gas_price,
+                get_eth_cancel_gas_limit_from_db(db)? as usize,
+                get_public_eth_address_from_db(db)?,
+                get_eth_private_key_from_db(db)?,
+            )?;
+            track_replacement_eth_tx_in_db(db, &signed_tx)?;
+            Ok(("cancel", signed_tx))
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{
+            TestDB,
+            get_test_database,
+        },
+        eth::{
+            eth_types::EthPendingTxStatus,
+            eth_test_utils::{
+                get_sample_eth_address,
+                get_sample_eth_private_key,
+            },
+            eth_database_utils::{
+                put_eth_chain_id_in_db,
+                put_eth_private_key_in_db,
+                put_public_eth_address_in_db,
+            },
+        },
+    };
+
+    fn get_sample_record(tx_type: EthTransactionType) -> EthMintTxRecord {
+        EthMintTxRecord {
+            tx_type,
+            nonce: 3,
+            gas_limit: 240_000,
+            data: vec![0xc0, 0xff, 0xee],
+            gas_price: 20_000_000_000,
+            max_priority_fee_per_gas: 1_000_000_000,
+            tx_hash: "0xdecaf".to_string(),
+            to: get_sample_eth_address().as_bytes().to_vec(),
+            correlation_ids: vec!["0xc0ffee".to_string()],
+        }
+    }
+
+    fn get_db_with_record(record: &EthMintTxRecord) -> TestDB {
+        let db = get_test_database();
+        put_eth_chain_id_in_db(&db, &4).unwrap();
+        put_eth_private_key_in_db(&db, &get_sample_eth_private_key()).unwrap();
+        put_eth_mint_tx_record_in_db(&db, record).unwrap();
+        db
+    }
+
+    #[test]
+    fn should_get_minimum_replacement_fee() {
+        assert_eq!(get_minimum_replacement_fee(0), 0);
+        assert_eq!(get_minimum_replacement_fee(100), 110);
+        assert_eq!(get_minimum_replacement_fee(101), 112);
+        assert_eq!(get_minimum_replacement_fee(u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn should_reject_replacement_below_minimum_bump() {
+        let record = get_sample_record(EthTransactionType::Legacy);
+        let db = get_db_with_record(&record);
+        let result = replace_eth_mint_tx_in_db(&db, 3, 21_000_000_000);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_reject_record_w_invalid_to_address() {
+        let mut record = get_sample_record(EthTransactionType::Legacy);
+        record.to = vec![0xc0];
+        let db = get_db_with_record(&record);
+        let result = replace_eth_mint_tx_in_db(&db, 3, 22_000_000_000);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_replace_legacy_mint_tx_and_track_it() {
+        let record = get_sample_record(EthTransactionType::Legacy);
+        let db = get_db_with_record(&record);
+        let signed_tx = replace_eth_mint_tx_in_db(&db, 3, 22_000_000_000)
+            .unwrap();
+        assert_eq!(signed_tx.tx_type, EthTransactionType::Legacy);
+        assert_eq!(signed_tx.data, record.data);
+        assert_eq!(signed_tx.gas_limit.as_u64(), record.gas_limit);
+        let tx_hash = format!("0x{}", signed_tx.get_tx_hash());
+        let updated_record = get_eth_mint_tx_record_from_db(&db, 3).unwrap();
+        assert_eq!(updated_record.tx_hash, tx_hash);
+        assert_eq!(updated_record.gas_price, 22_000_000_000);
+        assert_eq!(updated_record.correlation_ids, record.correlation_ids);
+        let pending_tx = get_eth_pending_tx_from_db(&db, 3).unwrap();
+        assert_eq!(pending_tx.tx_hash, tx_hash);
+        assert_eq!(pending_tx.status, EthPendingTxStatus::Signed);
+        assert_eq!(pending_tx.correlation_ids, record.correlation_ids);
+    }
+
+    #[test]
+    fn should_track_replacement_under_original_nonce() {
+        let record = get_sample_record(EthTransactionType::Legacy);
+        let db = get_db_with_record(&record);
+        let first_tx = replace_eth_mint_tx_in_db(&db, 3, 22_000_000_000)
+            .unwrap();
+        let second_tx = replace_eth_mint_tx_in_db(&db, 3, 25_000_000_000)
+            .unwrap();
+        let pending_tx = get_eth_pending_tx_from_db(&db, 3).unwrap();
+        assert_eq!(
+            pending_tx.tx_hash,
+            format!("0x{}", second_tx.get_tx_hash()),
+        );
+        assert_eq!(
+            pending_tx.replaced_tx_hashes,
+            vec![format!("0x{}", first_tx.get_tx_hash())],
+        );
+    }
+
+    #[test]
+    fn should_replace_dynamic_fee_mint_tx_w_bumped_priority_fee() {
+        let record = get_sample_record(EthTransactionType::DynamicFee);
+        let db = get_db_with_record(&record);
+        let signed_tx = replace_eth_mint_tx_in_db(&db, 3, 22_000_000_000)
+            .unwrap();
+        assert_eq!(signed_tx.tx_type, EthTransactionType::DynamicFee);
+        assert_eq!(signed_tx.max_fee_per_gas.as_u64(), 22_000_000_000);
+        assert_eq!(
+            signed_tx.max_priority_fee_per_gas.as_u64(),
+            1_100_000_000,
+        );
+    }
+
+    #[test]
+    fn should_resign_mints_and_cancel_other_nonces_in_gap() {
+        let record = get_sample_record(EthTransactionType::Legacy);
+        let db = get_db_with_record(&record);
+        put_public_eth_address_in_db(&db, &get_sample_eth_address()).unwrap();
+        let (mint_kind, mint_tx) =
+            sign_eth_nonce_gap_tx_in_db(&db, 3, 22_000_000_000).unwrap();
+        let (cancel_kind, cancel_tx) =
+            sign_eth_nonce_gap_tx_in_db(&db, 4, 22_000_000_000).unwrap();
+        assert_eq!(mint_kind, "mint");
+        assert_eq!(mint_tx.data, record.data);
+        assert_eq!(cancel_kind, "cancel");
+        assert!(cancel_tx.data.is_empty());
+        assert_eq!(
+            get_eth_pending_tx_from_db(&db, 4).unwrap().tx_hash,
+            format!("0x{}", cancel_tx.get_tx_hash()),
+        );
+    }
+}
@@ -0,0 +1,63 @@
+use ethereum_types::H256 as EthHash;
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    utils::convert_u256_to_usize,
+    staged_database::StagedDatabase,
+    safe_addresses::get_tiered_safe_btc_addresses_from_db,
+    recipient_policy::maybe_apply_recipient_policy_to_redeem_params,
+    btc::btc_database_utils::get_btc_network_from_db,
+    eth::{
+        eth_state::EthState,
+        eth_types::RedeemParams,
+        parse_redeem_params::parse_redeem_params_from_block,
+        filter_redeem_params::maybe_filter_redeem_params_in_state,
+        eth_database_utils::{
+            get_eth_block_from_db,
+            get_eth_host_token_decimals_from_db,
+            get_eth_smart_contract_address_from_db,
+        },
+    },
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct EthBlockReprocessingReport {
+    pub eth_block_hash: String,
+    pub eth_block_number: usize,
+    pub reprocessed_redeem_params: Vec<RedeemParams>,
+}
+
+pub fn reprocess_eth_block_in_db<D>(
+    db: D,
+    eth_block_hash: &EthHash,
+) -> Result<EthBlockReprocessingReport>
+    where D: DatabaseInterface
+{
+    info!("✔ Re-processing ETH block {} from db...", eth_block_hash);
+    let block_and_receipts = get_eth_block_from_db(&db, eth_block_hash)?;
+    let eth_block_number = convert_u256_to_usize(
+        &block_and_receipts.block.number
+    )?;
+    let redeem_params = parse_redeem_params_from_block(
+        block_and_receipts,
+        &get_eth_smart_contract_address_from_db(&db)?,
+        &get_btc_network_from_db(&db)?,
+        get_eth_host_token_decimals_from_db(&db)?,
+        &get_tiered_safe_btc_addresses_from_db(&db)?,
+    )?;
+    // NOTE: Staged & never committed so nothing re-processed is persisted.
+    EthState::init(StagedDatabase::new(db))
+        .add_redeem_params(redeem_params)
+        .and_then(maybe_filter_redeem_params_in_state)
+        .and_then(maybe_apply_recipient_policy_to_redeem_params)
+        .map(|state|
+            EthBlockReprocessingReport {
+                eth_block_number,
+                eth_block_hash: format!(
+                    "0x{}",
+                    hex::encode(eth_block_hash.as_bytes()),
+                ),
+                reprocessed_redeem_params: state.redeem_params,
+            }
+        )
+}
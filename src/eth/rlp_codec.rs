@@ -48,8 +48,19 @@ impl Encodable for EthLog {
 
 impl Encodable for EthBlock {
     fn rlp_append(&self, rlp_stream: &mut RlpStream) {
-        rlp_stream
-            .begin_list(15)
+        let num_optional_fields = [
+            self.base_fee_per_gas.is_some(),
+            self.withdrawals_root.is_some(),
+            self.blob_gas_used.is_some(),
+            self.excess_blob_gas.is_some(),
+            self.parent_beacon_block_root.is_some(),
+            self.requests_hash.is_some(),
+        ]
+            .iter()
+            .filter(|is_some| **is_some)
+            .count();
+        let rlp = rlp_stream.begin_list(15 + num_optional_fields);
+        rlp
             .append(&self.parent_hash)
             .append(&self.sha3_uncles)
             .append(&self.miner)
@@ -65,6 +76,24 @@ impl Encodable for EthBlock {
             .append(&self.extra_data)
             .append(&self.mix_hash)
             .append(&self.nonce);
+        if let Some(base_fee_per_gas) = &self.base_fee_per_gas {
+            rlp.append(base_fee_per_gas);
+        };
+        if let Some(withdrawals_root) = &self.withdrawals_root {
+            rlp.append(withdrawals_root);
+        };
+        if let Some(blob_gas_used) = &self.blob_gas_used {
+            rlp.append(blob_gas_used);
+        };
+        if let Some(excess_blob_gas) = &self.excess_blob_gas {
+            rlp.append(excess_blob_gas);
+        };
+        if let Some(beacon_root) = &self.parent_beacon_block_root {
+            rlp.append(beacon_root);
+        };
+        if let Some(requests_hash) = &self.requests_hash {
+            rlp.append(requests_hash);
+        };
     }
 }
 
@@ -74,10 +103,38 @@ pub fn rlp_encode_block(block: &EthBlock) -> Result<Bytes> {
     Ok(rlp_stream.out())
 }
 
+pub fn rlp_encode_block_without_seal(block: &EthBlock) -> Result<Bytes> {
+    let mut rlp_stream = RlpStream::new();
+    rlp_stream
+        .begin_list(13)
+        .append(&block.parent_hash)
+        .append(&block.sha3_uncles)
+        .append(&block.miner)
+        .append(&block.state_root)
+        .append(&block.transactions_root)
+        .append(&block.receipts_root)
+        .append(&block.logs_bloom)
+        .append(&block.difficulty)
+        .append(&block.number)
+        .append(&block.gas_limit)
+        .append(&block.gas_used)
+        .append(&block.timestamp)
+        .append(&block.extra_data);
+    Ok(rlp_stream.out())
+}
+
 pub fn rlp_encode_receipt(receipt: &EthReceipt) -> Result<Bytes> {
     let mut rlp_stream = RlpStream::new();
     rlp_stream.append(receipt);
-    Ok(rlp_stream.out())
+    match receipt.receipt_type {
+        0 => Ok(rlp_stream.out()),
+        receipt_type => Ok(
+            vec![receipt_type]
+                .into_iter()
+                .chain(rlp_stream.out().into_iter())
+                .collect::<Bytes>()
+        ),
+    }
 }
 
 pub fn rlp_encode_transaction_index(index: &U256) -> Result<Bytes> {
@@ -114,6 +171,8 @@ pub fn get_rlp_encoded_receipts_and_nibble_tuples(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ethereum_types::H256;
+    use crate::crypto_utils::keccak_hash_bytes;
     use crate::eth::eth_test_utils::{
         get_expected_receipt,
         get_sample_eth_block_and_receipts,
@@ -152,6 +211,37 @@ mod tests {
         assert!(result == expected_result);
     }
 
+    #[test]
+    fn should_rlp_encode_typed_receipt() {
+        let mut receipt = get_expected_receipt();
+        receipt.receipt_type = 2;
+        let result = rlp_encode_receipt(&receipt)
+            .unwrap();
+        assert_eq!(result[0], 2);
+        assert_eq!(result[1..].to_vec(), get_rlp_encoded_receipt());
+    }
+
+    #[test]
+    fn should_rlp_encode_block_with_optional_header_fields() {
+        let mut block = get_sample_eth_block_and_receipts().block;
+        block.base_fee_per_gas = Some(U256::from(7));
+        block.withdrawals_root = Some(H256::random());
+        let result = rlp_encode_block(&block)
+            .unwrap();
+        assert_ne!(result, get_rlp_encoded_block());
+        assert_eq!(rlp::Rlp::new(&result).item_count().unwrap(), 17);
+    }
+
+    #[test]
+    fn should_rlp_encode_block_without_seal() {
+        let block = get_sample_eth_block_and_receipts().block;
+        let result = rlp_encode_block_without_seal(&block)
+            .unwrap();
+        let expected_seal_hash =
+            "5f8f31f7d94b81ab27edfe7c89fd2e85ee033c468de3c8e9377801a8a1d89469";
+        assert_eq!(hex::encode(keccak_hash_bytes(result)), expected_seal_hash);
+    }
+
     #[test]
     fn should_get_encoded_receipt_and_hash_tuple() {
         let result = get_rlp_encoded_receipt_and_encoded_key_tuple(
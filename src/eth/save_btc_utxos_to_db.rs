@@ -9,7 +9,7 @@ pub fn maybe_save_btc_utxos_to_db<D>(state: EthState<D>) -> Result<EthState<D>>
     where D: DatabaseInterface
 {
     info!("✔ Maybe saving BTC UTXOs...");
-    match &state.btc_utxos_and_values {
+    match state.btc_utxos_and_values.as_option() {
         None => {
             info!("✔ No BTC UTXOs in state to save!");
             Ok(state)
@@ -0,0 +1,172 @@
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    correlation_id::get_checked_correlation_id,
+    btc::{
+        btc_utils::get_pay_to_pub_key_hash_script,
+        btc_database_utils::get_btc_address_from_db,
+        extract_utxos_from_op_return_txs::extract_utxos_from_txs,
+        btc_types::{
+            BtcTransactions,
+            BtcUtxosAndValues,
+        },
+    },
+    eth::{
+        eth_state::EthState,
+        eth_types::{
+            RedeemParams,
+            EthRedeemInfo,
+            EthRedeemRecord,
+            EthBlockAndReceipts,
+        },
+        eth_database_utils::{
+            get_eth_canon_block_from_db,
+            put_eth_redeem_record_in_db,
+        },
+    },
+};
+
+fn get_eth_redeem_record(
+    canon_block_and_receipts: &EthBlockAndReceipts,
+    redeem_params: &[RedeemParams],
+    btc_txs: &BtcTransactions,
+    spent_utxos: BtcUtxosAndValues,
+    change_utxos: BtcUtxosAndValues,
+) -> Result<EthRedeemRecord> {
+    Ok(EthRedeemRecord {
+        block_hash: format!(
+            "0x{}",
+            hex::encode(canon_block_and_receipts.block.hash.as_bytes())
+        ),
+        block_number: canon_block_and_receipts.block.number.as_u64(),
+        btc_tx_hashes: btc_txs
+            .iter()
+            .map(|btc_tx| btc_tx.txid().to_string())
+            .collect(),
+        redeems: redeem_params
+            .iter()
+            .map(|params|
+                get_checked_correlation_id(
+                    &params.correlation_id,
+                    &format!("0x{}", hex::encode(params.originating_tx_hash)),
+                ).map(|_| EthRedeemInfo::from_redeem_params(params))
+            )
+            .collect::<Result<Vec<EthRedeemInfo>>>()?,
+        spent_utxos,
+        change_utxos,
+    })
+}
+
+// NOTE: The change outputs get saved to the db as UTXOs later in the pipeline,
+// so they're recorded here too, in order that a reorg may remove them again.
+fn get_change_utxos_from_btc_txs<D>(
+    db: &D,
+    btc_txs: &BtcTransactions,
+) -> Result<BtcUtxosAndValues>
+    where D: DatabaseInterface
+{
+    get_btc_address_from_db(db)
+        .and_then(|address| get_pay_to_pub_key_hash_script(&address))
+        .map(|target_script| extract_utxos_from_txs(&target_script, btc_txs))
+}
+
+pub fn maybe_save_eth_redeem_record_to_db<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe saving ETH redeem record to db...");
+    match state.btc_transactions.as_option() {
+        None => {
+            info!("✔ No BTC txs in state ∴ no redeem record to save!");
+            Ok(state)
+        }
+        Some(btc_txs) => {
+            info!("✔ BTC txs in state ∴ saving redeem record to db...");
+            get_eth_canon_block_from_db(&state.db)
+                .and_then(|canon_block_and_receipts|
+                    put_eth_redeem_record_in_db(
+                        &state.db,
+                        &canon_block_and_receipts.block.hash,
+                        &get_eth_redeem_record(
+                            &canon_block_and_receipts,
+                            &state.redeem_params,
+                            btc_txs,
+                            state
+                                .spent_btc_utxos_and_values
+                                .as_option()
+                                .cloned()
+                                .unwrap_or_default(),
+                            get_change_utxos_from_btc_txs(
+                                &state.db,
+                                btc_txs,
+                            )?,
+                        )?,
+                    )
+                )
+                .map(|_| state)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        btc::{
+            btc_database_utils::put_btc_address_in_db,
+            btc_test_utils::{
+                get_sample_btc_tx,
+                get_sample_utxo_and_values,
+                SAMPLE_TARGET_BTC_ADDRESS,
+            },
+        },
+        eth::{
+            eth_test_utils::get_sequential_eth_blocks_and_receipts,
+            eth_database_utils::{
+                put_eth_canon_block_in_db,
+                maybe_get_eth_redeem_record_from_db,
+            },
+        },
+    };
+
+    #[test]
+    fn should_save_eth_redeem_record_to_db() {
+        let db = get_test_database();
+        let canon_block = get_sequential_eth_blocks_and_receipts()[0].clone();
+        let btc_tx = get_sample_btc_tx();
+        let spent_utxos = get_sample_utxo_and_values();
+        put_eth_canon_block_in_db(&db, &canon_block).unwrap();
+        put_btc_address_in_db(&db, &SAMPLE_TARGET_BTC_ADDRESS.to_string())
+            .unwrap();
+        let state = EthState::init(db)
+            .add_btc_transactions(vec![btc_tx.clone()])
+            .and_then(|state|
+                state.add_spent_btc_utxos_and_values(spent_utxos.clone())
+            )
+            .unwrap();
+        let state = maybe_save_eth_redeem_record_to_db(state).unwrap();
+        let result = maybe_get_eth_redeem_record_from_db(
+            &state.db,
+            &canon_block.block.hash,
+        ).unwrap();
+        assert_eq!(result.btc_tx_hashes, vec![btc_tx.txid().to_string()]);
+        assert_eq!(result.block_number, canon_block.block.number.as_u64());
+        assert_eq!(result.spent_utxos, spent_utxos);
+    }
+
+    #[test]
+    fn should_not_save_eth_redeem_record_if_no_btc_txs_in_state() {
+        let db = get_test_database();
+        let canon_block = get_sequential_eth_blocks_and_receipts()[0].clone();
+        put_eth_canon_block_in_db(&db, &canon_block).unwrap();
+        let state = maybe_save_eth_redeem_record_to_db(EthState::init(db))
+            .unwrap();
+        let result = maybe_get_eth_redeem_record_from_db(
+            &state.db,
+            &canon_block.block.hash,
+        );
+        assert!(result.is_none());
+    }
+}
@@ -0,0 +1,36 @@
+use crate::{
+    types::Result,
+    eth::eth_state::EthState,
+    traits::DatabaseInterface,
+    processed_tx_ledger::put_processed_eth_redeem_in_db,
+};
+
+pub fn maybe_save_processed_redeems_to_db<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe saving processed redeems to db...");
+    match state.btc_transactions.as_option() {
+        None => {
+            info!("✔ No BTC txs in state ∴ no processed redeems to save!");
+            Ok(state)
+        }
+        Some(_) => {
+            info!(
+                "✔ Saving {} processed redeems to db...",
+                state.redeem_params.len(),
+            );
+            state.redeem_params
+                .iter()
+                .map(|params|
+                    put_processed_eth_redeem_in_db(
+                        &state.db,
+                        &params.originating_tx_hash,
+                    )
+                )
+                .collect::<Result<()>>()
+                .map(|_| state)
+        }
+    }
+}
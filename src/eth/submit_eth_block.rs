@@ -1,73 +1,223 @@
 use crate::{
     types::Result,
     traits::DatabaseInterface,
+    pipeline::{
+        Pipeline,
+        run_pipeline,
+        PipelineStage,
+    },
+    state_size::{
+        check_state_size_is_within_limit,
+        check_submission_size_is_within_limit,
+    },
+    log_config::apply_log_config_from_db,
+    error_log::{
+        ErrorContext,
+        record_error_in_db,
+    },
+    heartbeat::update_eth_heartbeat_in_db,
+    volume_limits::maybe_apply_payout_volume_limit,
+    pending_approvals::maybe_hold_redeem_params_for_approval,
+    recipient_policy::maybe_apply_recipient_policy_to_redeem_params,
+    stale_transactions::{
+        maybe_watch_signed_btc_txs,
+        mark_seen_eth_txs_in_watchlist,
+        maybe_remove_canon_eth_txs_from_watchlist,
+    },
+    balance_reconciliation::maybe_increment_total_satoshis_redeemed_in_db,
+    core_metrics::{
+        record_last_error_code,
+        increment_blocks_processed_metric,
+    },
+    staged_database::StagedDatabase,
+    chain_state::{
+        end_db_transaction,
+        start_db_transaction,
+    },
     check_enclave_is_initialized::{
         check_enclave_is_initialized_and_return_eth_state,
     },
     eth::{
         eth_state::EthState,
-        validate_block::validate_block_in_state,
+        validation_checkpoint::{
+            clear_eth_validation_checkpoint,
+            validate_eth_block_unless_checkpointed,
+        },
+        handle_eth_reorg::maybe_handle_eth_reorg,
+        journal_eth_events::maybe_journal_eth_bridge_events,
         get_eth_output_json::get_eth_output_json,
-        validate_receipts::validate_receipts_in_state,
         save_btc_utxos_to_db::maybe_save_btc_utxos_to_db,
         increment_btc_nonce::maybe_increment_btc_nonce_in_db,
         filter_receipts::filter_irrelevant_receipts_from_state,
         check_parent_exists::check_for_parent_of_block_in_state,
         update_latest_block_hash::maybe_update_latest_block_hash,
         filter_redeem_params::maybe_filter_redeem_params_in_state,
+        queue_redeem_params::maybe_queue_or_release_redeem_params,
         remove_old_eth_tail_block::maybe_remove_old_eth_tail_block,
         update_eth_tail_block_hash::maybe_update_eth_tail_block_hash,
         create_btc_transactions::maybe_create_btc_txs_and_add_to_state,
         update_eth_canon_block_hash::maybe_update_eth_canon_block_hash,
         parse_redeem_params::maybe_parse_redeem_params_and_add_to_state,
+        filter_processed_redeems::maybe_filter_processed_redeems_in_state,
+        save_processed_redeems_to_db::maybe_save_processed_redeems_to_db,
+        save_eth_redeem_record_to_db::maybe_save_eth_redeem_record_to_db,
         update_eth_linker_hash::maybe_update_eth_linker_hash_and_return_state,
         extract_utxos_from_btc_txs::maybe_extract_btc_utxo_from_btc_tx_in_state,
-        eth_database_utils::{
-            end_eth_db_transaction,
-            start_eth_db_transaction,
-        },
+        validate_parent_linkage::validate_parent_linkage_of_block_in_state,
         parse_eth_block_and_receipts::{
             parse_eth_block_and_receipts_and_put_in_state,
         },
+        parse_eth_rpc_block::{
+            parse_eth_rpc_block_and_receipts_and_put_in_state,
+        },
         add_block_and_receipts_to_database::{
             maybe_add_block_and_receipts_to_db_and_return_state,
         },
+        clear_confirmed_eth_pending_txs::{
+            maybe_record_eth_pending_tx_receipts,
+            maybe_clear_confirmed_eth_pending_txs,
+        },
         remove_receipts_from_canon_block::{
             maybe_remove_receipts_from_canon_block_and_return_state,
         },
+        deduct_peg_out_fees::{
+            maybe_accrue_peg_out_fees_in_db,
+            maybe_deduct_peg_out_fees_from_redeem_params,
+        },
     }
 };
 
+pub fn get_eth_submission_pipeline<'a, D>()
+    -> Pipeline<'a, EthState<StagedDatabase<D>>>
+    where D: DatabaseInterface + 'a
+{
+    vec![
+        PipelineStage::required(
+            check_enclave_is_initialized_and_return_eth_state
+        ),
+        PipelineStage::required(check_state_size_is_within_limit),
+        PipelineStage::required(start_db_transaction),
+        PipelineStage::required(apply_log_config_from_db),
+        PipelineStage::required(check_for_parent_of_block_in_state),
+        PipelineStage::required(validate_parent_linkage_of_block_in_state),
+        PipelineStage::required(validate_eth_block_unless_checkpointed),
+        PipelineStage::optional(
+            "eth_pending_tx_tracking",
+            maybe_record_eth_pending_tx_receipts,
+        ),
+        PipelineStage::optional(
+            "stale_tx_watchdog",
+            mark_seen_eth_txs_in_watchlist,
+        ),
+        PipelineStage::required(filter_irrelevant_receipts_from_state),
+        PipelineStage::required(
+            maybe_add_block_and_receipts_to_db_and_return_state
+        ),
+        PipelineStage::required(maybe_update_latest_block_hash),
+        PipelineStage::required(maybe_handle_eth_reorg),
+        PipelineStage::required(maybe_update_eth_canon_block_hash),
+        PipelineStage::optional(
+            "eth_pending_tx_tracking",
+            maybe_clear_confirmed_eth_pending_txs,
+        ),
+        PipelineStage::optional(
+            "stale_tx_watchdog",
+            maybe_remove_canon_eth_txs_from_watchlist,
+        ),
+        PipelineStage::required(maybe_update_eth_tail_block_hash),
+        PipelineStage::required(maybe_update_eth_linker_hash_and_return_state),
+        PipelineStage::required(maybe_parse_redeem_params_and_add_to_state),
+        PipelineStage::required(maybe_filter_redeem_params_in_state),
+        PipelineStage::required(
+            maybe_apply_recipient_policy_to_redeem_params
+        ),
+        PipelineStage::required(maybe_filter_processed_redeems_in_state),
+        PipelineStage::required(maybe_hold_redeem_params_for_approval),
+        PipelineStage::required(maybe_apply_payout_volume_limit),
+        PipelineStage::required(maybe_queue_or_release_redeem_params),
+        // NOTE: Again, for any params just released from the queue.
+        PipelineStage::required(maybe_filter_processed_redeems_in_state),
+        PipelineStage::required(maybe_deduct_peg_out_fees_from_redeem_params),
+        PipelineStage::required(maybe_create_btc_txs_and_add_to_state),
+        PipelineStage::required(maybe_accrue_peg_out_fees_in_db),
+        PipelineStage::optional("stale_tx_watchdog", maybe_watch_signed_btc_txs),
+        PipelineStage::required(maybe_save_eth_redeem_record_to_db),
+        PipelineStage::required(maybe_save_processed_redeems_to_db),
+        PipelineStage::required(maybe_increment_btc_nonce_in_db),
+        PipelineStage::required(maybe_increment_total_satoshis_redeemed_in_db),
+        PipelineStage::required(maybe_extract_btc_utxo_from_btc_tx_in_state),
+        PipelineStage::required(maybe_save_btc_utxos_to_db),
+        PipelineStage::required(maybe_remove_old_eth_tail_block),
+        PipelineStage::required(
+            maybe_remove_receipts_from_canon_block_and_return_state
+        ),
+        PipelineStage::required(maybe_journal_eth_bridge_events),
+        PipelineStage::required(increment_blocks_processed_metric),
+        PipelineStage::optional("heartbeat", update_eth_heartbeat_in_db),
+        PipelineStage::required(clear_eth_validation_checkpoint),
+        PipelineStage::required(end_db_transaction),
+    ]
+}
+
+pub fn submit_parsed_eth_block_to_enclave<D, P>(
+    db: D,
+    dry_run: bool,
+    parse_block_and_put_in_state: P,
+) -> Result<String>
+    where
+        D: DatabaseInterface,
+        P: FnOnce(EthState<StagedDatabase<&D>>)
+            -> Result<EthState<StagedDatabase<&D>>>,
+{
+    parse_block_and_put_in_state(
+        EthState { dry_run, ..EthState::init(StagedDatabase::new(&db)) }
+    )
+        .map_err(|err|
+            record_error_in_db(&db, dry_run, ErrorContext::new("ETH"), err)
+        )
+        .and_then(|state| {
+            let error_context = ErrorContext::from_state(&state);
+            run_pipeline(state, get_eth_submission_pipeline())
+                .and_then(get_eth_output_json)
+                .map_err(|err|
+                    record_error_in_db(&db, dry_run, error_context, err)
+                )
+        })
+        .map_err(record_last_error_code)
+}
+
 pub fn submit_eth_block_to_enclave<D>(
     db: D,
-    block_json_string: String
+    block_json_string: String,
+    dry_run: bool,
 ) -> Result<String>
     where D: DatabaseInterface
 {
     info!("✔ Submitting ETH block to enclave...");
-    parse_eth_block_and_receipts_and_put_in_state(
-        block_json_string,
-        EthState::init(db),
+    submit_parsed_eth_block_to_enclave(db, dry_run, |state|
+        check_submission_size_is_within_limit(state, &block_json_string)
+            .and_then(|state| parse_eth_block_and_receipts_and_put_in_state(
+                block_json_string,
+                state,
+            ))
+    )
+}
+
+pub fn submit_eth_rpc_block_to_enclave<D>(
+    db: D,
+    block_json_string: String,
+    dry_run: bool,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Submitting ETH RPC block to enclave...");
+    submit_parsed_eth_block_to_enclave(db, dry_run, |state|
+        check_submission_size_is_within_limit(state, &block_json_string)
+            .and_then(|state|
+                parse_eth_rpc_block_and_receipts_and_put_in_state(
+                    block_json_string,
+                    state,
+                )
+            )
     )
-        .and_then(check_enclave_is_initialized_and_return_eth_state)
-        .and_then(start_eth_db_transaction)
-        .and_then(validate_block_in_state)
-        .and_then(check_for_parent_of_block_in_state)
-        .and_then(validate_receipts_in_state)
-        .and_then(filter_irrelevant_receipts_from_state)
-        .and_then(maybe_add_block_and_receipts_to_db_and_return_state)
-        .and_then(maybe_update_latest_block_hash)
-        .and_then(maybe_update_eth_canon_block_hash)
-        .and_then(maybe_update_eth_tail_block_hash)
-        .and_then(maybe_update_eth_linker_hash_and_return_state)
-        .and_then(maybe_parse_redeem_params_and_add_to_state)
-        .and_then(maybe_filter_redeem_params_in_state)
-        .and_then(maybe_create_btc_txs_and_add_to_state)
-        .and_then(maybe_increment_btc_nonce_in_db)
-        .and_then(maybe_extract_btc_utxo_from_btc_tx_in_state)
-        .and_then(maybe_save_btc_utxos_to_db)
-        .and_then(maybe_remove_old_eth_tail_block)
-        .and_then(maybe_remove_receipts_from_canon_block_and_return_state)
-        .and_then(end_eth_db_transaction)
-        .and_then(get_eth_output_json)
 }
@@ -138,12 +138,12 @@ impl Trie {
                         remaining_key,
                         value,
                     ),
-                    _ => Err(AppError::Custom(
+                    _ => Err(AppError::TrieError(
                         "✘ Node type not recognized!".to_string()
                     ))
                 }
             },
-            None =>  Err(AppError::Custom(
+            None =>  Err(AppError::TrieError(
                 "✘ Cannot process node stack: It's empty!".to_string()
             )),
         }
@@ -661,7 +661,7 @@ impl Trie {
                     new_stack,
                     stack_to_delete,
                 ),
-                _ => Err(AppError::Custom(
+                _ => Err(AppError::TrieError(
                     "✘ Error updating old nodes: Wrong node type!".to_string()
                 ))
             },
@@ -839,7 +839,7 @@ impl Trie {
                     vec![node],
                     target_key
                 ),
-                None => Err(AppError::Custom(
+                None => Err(AppError::TrieError(
                     "✘ Find Error: Could not find root node in db!".to_string()
                 ))
             })
@@ -879,7 +879,7 @@ impl Trie {
                         found_stack,
                         remaining_key,
                     ),
-                    _ => Err(AppError::Custom(
+                    _ => Err(AppError::TrieError(
                         "✘ Find Error: Node type not recognized!".to_string()
                     ))
                 }
@@ -987,7 +987,7 @@ impl Trie {
                                         remaining_key
                                     )
                                 },
-                                None => Err(AppError::Custom(
+                                None => Err(AppError::TrieError(
                                     "✘ Find Error: Extension child not in db!"
                                         .to_string()
                                 ))
@@ -1065,7 +1065,7 @@ impl Trie {
                                     remaining_nibbles
                                 )
                             },
-                            None => Err(AppError::Custom(
+                            None => Err(AppError::TrieError(
                                 "✘ Find Error: Branch child not in db!"
                                     .to_string()
                             )),
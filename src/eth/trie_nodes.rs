@@ -139,7 +139,7 @@ impl Node {
                 }
             )
         } else {
-            Err(AppError::Custom(
+            Err(AppError::TrieError(
                 "✘ Cannot update branches - not a branch node!".to_string()
             ))
         }
@@ -171,7 +171,7 @@ impl Node {
             };
             Ok(rlp_stream.out())
         } else {
-            Err(AppError::Custom(NO_NODE_IN_STRUCT_ERR.to_string()))
+            Err(AppError::TrieError(NO_NODE_IN_STRUCT_ERR.to_string()))
         }
     }
 
@@ -229,7 +229,7 @@ impl Node {
 
 pub fn rlp_decode_node(rlp_data: Bytes) -> Result<Node> {
     match Rlp::new(&rlp_data).as_list() {
-        Err(e) => Err(AppError::Custom(e.to_string())),
+        Err(e) => Err(AppError::TrieError(e.to_string())),
         Ok(list) => {
             match list.len() {
                 2 => {
@@ -276,7 +276,7 @@ pub fn rlp_decode_node(rlp_data: Bytes) -> Result<Node> {
                         }
                     )
                 },
-                _ => Err(AppError::Custom(
+                _ => Err(AppError::TrieError(
                     "✘ Cannot decode node from rlp data!".to_string()
                 ))
             }
@@ -579,7 +579,7 @@ mod tests {
         let expected_error = "✘ Cannot update branches - not a branch node!";
         let non_branch_node = get_sample_leaf_node();
         match non_branch_node.update_branch_at_index(None, 4) {
-            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            Err(AppError::TrieError(e)) => assert!(e == expected_error),
             _ => panic!("Did not receive expected error!")
         }
     }
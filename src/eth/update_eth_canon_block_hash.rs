@@ -3,43 +3,43 @@ use crate::{
     traits::DatabaseInterface,
     eth::{
         eth_state::EthState,
-        eth_types::EthBlockAndReceipts,
+        eth_types::EthBlockHeaderInDbFormat,
         eth_database_utils::{
-            get_eth_canon_block_from_db,
-            get_eth_latest_block_from_db,
             put_eth_canon_block_hash_in_db,
+            get_eth_latest_block_hash_from_db,
             get_eth_canon_to_tip_length_from_db,
-            maybe_get_nth_ancestor_eth_block_and_receipts,
+            get_special_eth_block_header_from_db,
+            maybe_get_nth_ancestor_eth_block_header,
         },
     },
 };
 
 fn does_canon_block_require_updating<D>(
     db: &D,
-    calculated_canon_block_and_receipts: &EthBlockAndReceipts,
+    calculated_canon_block_header: &EthBlockHeaderInDbFormat,
 ) -> Result<bool>
     where D: DatabaseInterface
 {
-    get_eth_canon_block_from_db(db)
-        .map(|db_canon_block_and_receipts|
-            db_canon_block_and_receipts.block.number <=
-            calculated_canon_block_and_receipts.block.number - 1
+    get_special_eth_block_header_from_db(db, "canon")
+        .map(|db_canon_block_header|
+            db_canon_block_header.number <=
+            calculated_canon_block_header.number - 1
         )
 }
 
 fn maybe_get_nth_ancestor_of_latest_block<D>(
     db: &D,
     n: &u64,
-) -> Option<EthBlockAndReceipts>
+) -> Option<EthBlockHeaderInDbFormat>
     where D: DatabaseInterface
 {
     info!("✔ Maybe getting ancestor #{} of latest ETH block...", n);
-    match get_eth_latest_block_from_db(db) {
+    match get_eth_latest_block_hash_from_db(db) {
         Err(_) => None,
-        Ok(block_and_receipts) => {
-            maybe_get_nth_ancestor_eth_block_and_receipts(
+        Ok(latest_block_hash) => {
+            maybe_get_nth_ancestor_eth_block_header(
                 db,
-                &block_and_receipts.block.hash,
+                &latest_block_hash,
                 n
             )
         }
@@ -64,7 +64,7 @@ fn maybe_update_canon_block_hash<D>(
                     info!("✔ Updating canon block...");
                     put_eth_canon_block_hash_in_db(
                         db,
-                        &ancestor_block.block.hash
+                        &ancestor_block.hash
                     )
                 }
                 false => {
@@ -118,7 +118,9 @@ mod tests {
             .unwrap();
         let result = does_canon_block_require_updating(
             &db,
-            &calculated_canon_block
+            &EthBlockHeaderInDbFormat::from_eth_block(
+                &calculated_canon_block.block
+            ),
         ).unwrap();
         assert!(result);
     }
@@ -133,7 +135,9 @@ mod tests {
             .unwrap();
         let result = does_canon_block_require_updating(
             &db,
-            &calculated_canon_block
+            &EthBlockHeaderInDbFormat::from_eth_block(
+                &calculated_canon_block.block
+            ),
         ).unwrap();
         assert!(!result);
     }
@@ -149,7 +153,11 @@ mod tests {
         put_eth_latest_block_in_db(&db, &block_2)
             .unwrap();
         let result = maybe_get_nth_ancestor_of_latest_block(&db, &1);
-        assert!(result == Some(block_1));
+        assert!(
+            result == Some(EthBlockHeaderInDbFormat::from_eth_block(
+                &block_1.block
+            ))
+        );
     }
 
     #[test]
@@ -7,13 +7,13 @@ use crate::{
         get_linker_hash::get_linker_hash_or_genesis_hash,
         eth_types::{
             EthHash,
-            EthBlockAndReceipts,
+            EthBlockHeaderInDbFormat,
         },
         eth_database_utils::{
             put_eth_linker_hash_in_db,
-            get_eth_tail_block_from_db,
-            get_eth_anchor_block_from_db,
-            maybe_get_parent_eth_block_and_receipts,
+            get_eth_tail_block_hash_from_db,
+            get_eth_anchor_block_hash_from_db,
+            maybe_get_parent_eth_block_header,
         },
     },
 };
@@ -25,12 +25,12 @@ fn get_new_linker_hash<D>(
     where D: DatabaseInterface
 {
     info!("✔ Calculating new linker hash...");
-    get_eth_anchor_block_from_db(db)
-        .and_then(|anchor_block|
+    get_eth_anchor_block_hash_from_db(db)
+        .and_then(|anchor_block_hash|
             Ok(
                 calculate_linker_hash(
                     *block_hash_to_link_to,
-                    anchor_block.block.hash,
+                    anchor_block_hash,
                     get_linker_hash_or_genesis_hash(db)?,
                 )
             )
@@ -39,16 +39,13 @@ fn get_new_linker_hash<D>(
 
 fn maybe_get_parent_of_eth_tail_block<D>(
     db: &D
-) -> Result<Option<EthBlockAndReceipts>>
+) -> Result<Option<EthBlockHeaderInDbFormat>>
     where D: DatabaseInterface
 {
     info!("✔ Maybe getting parent of ETH tail block from db...");
-    get_eth_tail_block_from_db(db)
-        .map(|eth_canon_block|
-            maybe_get_parent_eth_block_and_receipts(
-                db,
-                &eth_canon_block.block.hash
-            )
+    get_eth_tail_block_hash_from_db(db)
+        .map(|eth_tail_block_hash|
+            maybe_get_parent_eth_block_header(db, &eth_tail_block_hash)
         )
 }
 
@@ -68,7 +65,7 @@ fn maybe_update_linker_hash<D>(db: &D) -> Result<()>
     match maybe_get_parent_of_eth_tail_block(db)? {
         Some(parent_of_eth_tail_block) => {
             info!("✔ Updating ETH linker hash...");
-            get_new_linker_hash(db, &parent_of_eth_tail_block.block.hash)
+            get_new_linker_hash(db, &parent_of_eth_tail_block.hash)
                 .and_then(|linker_hash|
                     put_eth_linker_hash_in_db(db, linker_hash)
                 )
@@ -113,7 +110,11 @@ mod tests {
         let result = maybe_get_parent_of_eth_tail_block(&db)
             .unwrap()
             .unwrap();
-        assert!(result == parent_of_eth_tail_block);
+        assert!(
+            result == EthBlockHeaderInDbFormat::from_eth_block(
+                &parent_of_eth_tail_block.block
+            )
+        );
     }
 
     #[test]
@@ -4,27 +4,27 @@ use crate::{
     eth::{
         eth_state::EthState,
         eth_constants::ETH_TAIL_LENGTH,
-        eth_types::EthBlockAndReceipts,
+        eth_types::EthBlockHeaderInDbFormat,
         eth_database_utils::{
-            get_eth_tail_block_from_db,
-            get_eth_latest_block_from_db,
             put_eth_tail_block_hash_in_db,
+            get_eth_latest_block_hash_from_db,
             get_eth_canon_to_tip_length_from_db,
-            maybe_get_nth_ancestor_eth_block_and_receipts,
+            get_special_eth_block_header_from_db,
+            maybe_get_nth_ancestor_eth_block_header,
         },
     },
 };
 
 fn does_tail_block_require_updating<D>(
     db: &D,
-    calculated_tail_block: &EthBlockAndReceipts,
+    calculated_tail_block: &EthBlockHeaderInDbFormat,
 ) -> Result<bool>
     where D: DatabaseInterface
 {
     trace!("✔ Checking if ETH tail block needs updating...");
-    get_eth_tail_block_from_db(db)
+    get_special_eth_block_header_from_db(db, "tail")
         .map(|db_tail_block|
-            db_tail_block.block.number <= calculated_tail_block.block.number - 1
+            db_tail_block.number <= calculated_tail_block.number - 1
         )
 }
 
@@ -35,15 +35,15 @@ pub fn maybe_update_eth_tail_block_hash<D>(
 {
     info!("✔ Maybe updating ETH tail block hash...");
     let canon_to_tip_length = get_eth_canon_to_tip_length_from_db(&state.db)?;
-    get_eth_latest_block_from_db(&state.db)
-        .map(|latest_eth_block| {
+    get_eth_latest_block_hash_from_db(&state.db)
+        .map(|latest_eth_block_hash| {
             info!(
                 "✔ Searching for tail block {} blocks back from tip...",
                 canon_to_tip_length + ETH_TAIL_LENGTH,
             );
-            maybe_get_nth_ancestor_eth_block_and_receipts(
+            maybe_get_nth_ancestor_eth_block_header(
                 &state.db,
-                &latest_eth_block.block.hash,
+                &latest_eth_block_hash,
                 &(canon_to_tip_length + ETH_TAIL_LENGTH),
             )
         })
@@ -74,7 +74,7 @@ pub fn maybe_update_eth_tail_block_hash<D>(
                             info!("✔ Updating ETH tail block...");
                             put_eth_tail_block_hash_in_db(
                                 &state.db,
-                                &ancestor_block.block.hash
+                                &ancestor_block.hash
                             )
                                 .and_then(|_| Ok(state))
                         }
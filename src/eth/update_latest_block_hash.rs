@@ -5,6 +5,7 @@ use crate::{
         eth_state::EthState,
         eth_types::EthBlock,
         eth_database_utils::{
+            eth_pos_mode_is_enabled,
             get_eth_latest_block_from_db,
             put_eth_latest_block_hash_in_db,
         },
@@ -18,34 +19,90 @@ fn is_block_subsequent(
     latest_block_from_database.number == block_in_question.number + 1
 }
 
-fn update_latest_block_hash_if_subsequent<D>(
+// NOTE: The host supplies each block's total difficulty, but it's only
+// trusted once `validate_parent_linkage` has checked it's the stored parent's
+// plus the block's own difficulty. That difficulty is in turn only checked
+// against the block's proof-of-work w/ the `ethash-light` feature, so without
+// it a host may make a chain look heavier than it is.
+fn is_block_heavier(
+    latest_block_from_database: &EthBlock,
+    block_in_question: &EthBlock,
+) -> bool {
+    block_in_question.total_difficulty >
+        latest_block_from_database.total_difficulty
+}
+
+// NOTE: Competing blocks of equal height are tie-broken by lowest hash, so the
+// latest block doesn't depend on the order they're submitted in.
+fn is_block_higher(
+    latest_block_from_database: &EthBlock,
+    block_in_question: &EthBlock,
+) -> bool {
+    match block_in_question.number == latest_block_from_database.number {
+        true => block_in_question.hash.as_bytes() <
+            latest_block_from_database.hash.as_bytes(),
+        false => block_in_question.number > latest_block_from_database.number,
+    }
+}
+
+fn is_block_heavier_for_consensus(
+    pos_mode_enabled: bool,
+    latest_block_from_database: &EthBlock,
+    block_in_question: &EthBlock,
+) -> bool {
+    match pos_mode_enabled {
+        true => is_block_higher(
+            latest_block_from_database,
+            block_in_question,
+        ),
+        false => is_block_heavier(
+            latest_block_from_database,
+            block_in_question,
+        ),
+    }
+}
+
+fn update_latest_block_hash_if_heavier<D>(
     db: &D,
-    maybe_subsequent_block: &EthBlock,
+    maybe_heavier_block: &EthBlock,
 ) -> Result<()>
     where D: DatabaseInterface
 {
-    info!("✔ Updating latest ETH block hash if subsequent...");
+    info!("✔ Updating latest ETH block hash if heavier...");
     get_eth_latest_block_from_db(db)
         .and_then(|latest_block_and_receipts|
-            match is_block_subsequent(
+            match is_block_heavier_for_consensus(
+                eth_pos_mode_is_enabled(db),
                 &latest_block_and_receipts.block,
-                &maybe_subsequent_block,
+                &maybe_heavier_block,
             ) {
                 false => {
                     info!(
-                        "✔ Block NOT subsequent {}",
+                        "✔ Block NOT heavier {}",
                         "∴ NOT updating latest block hash",
                     );
                     Ok(())
                 }
                 true => {
-                    info!(
-                        "✔ Block IS subsequent {}",
-                        "∴ updating latest block hash...",
-                    );
+                    match is_block_subsequent(
+                        &latest_block_and_receipts.block,
+                        &maybe_heavier_block,
+                    ) &&
+                        maybe_heavier_block.parent_hash ==
+                        latest_block_and_receipts.block.hash
+                    {
+                        true => info!(
+                            "✔ Block IS subsequent {}",
+                            "∴ updating latest block hash...",
+                        ),
+                        false => info!(
+                            "✔ Block IS heavier competing block {}",
+                            "∴ re-selecting latest block hash...",
+                        ),
+                    };
                     put_eth_latest_block_hash_in_db(
                         db,
-                        &maybe_subsequent_block.hash,
+                        &maybe_heavier_block.hash,
                     )
                 }
             }
@@ -57,8 +114,8 @@ pub fn maybe_update_latest_block_hash<D>(
 ) -> Result<EthState<D>>
     where D: DatabaseInterface
 {
-    info!("✔ Maybe updating latest ETH block hash if subsequent...");
-    update_latest_block_hash_if_subsequent(
+    info!("✔ Maybe updating latest ETH block hash if heavier...");
+    update_latest_block_hash_if_heavier(
         &state.db,
         &state.get_eth_block_and_receipts()?.block,
     )
@@ -68,6 +125,7 @@ pub fn maybe_update_latest_block_hash<D>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ethereum_types::U256;
     use crate::{
         test_utils::get_test_database,
         eth::{
@@ -75,6 +133,7 @@ mod tests {
             eth_constants::ETH_LATEST_BLOCK_HASH_KEY,
             eth_test_utils::get_sequential_eth_blocks_and_receipts,
             eth_database_utils::{
+                put_eth_pos_mode_in_db,
                 put_eth_latest_block_in_db,
                 get_hash_from_db_via_hash_key,
                 get_eth_latest_block_hash_from_db,
@@ -124,7 +183,7 @@ mod tests {
             .block
             .hash
             .clone();
-        if let Err(e) = update_latest_block_hash_if_subsequent(
+        if let Err(e) = update_latest_block_hash_if_heavier(
             &db,
             &subsequent_block.block,
         ) {
@@ -151,7 +210,7 @@ mod tests {
         let non_subsequent_block = get_sequential_eth_blocks_and_receipts()
             [0]
             .clone();
-        if let Err(e) = update_latest_block_hash_if_subsequent(
+        if let Err(e) = update_latest_block_hash_if_heavier(
             &db,
             &non_subsequent_block.block,
         ) {
@@ -165,4 +224,118 @@ mod tests {
             .unwrap();
         assert!(latest_block_hash_before == latest_block_hash_after);
     }
+
+    #[test]
+    fn should_return_true_if_block_is_heavier() {
+        let blocks_and_receipts = get_sequential_eth_blocks_and_receipts();
+        assert!(
+            is_block_heavier(
+                &blocks_and_receipts[0].block,
+                &blocks_and_receipts[1].block,
+            )
+        );
+        assert!(
+            !is_block_heavier(
+                &blocks_and_receipts[1].block,
+                &blocks_and_receipts[0].block,
+            )
+        );
+    }
+
+    #[test]
+    fn should_update_latest_block_hash_to_heavier_competing_block() {
+        let db = get_test_database();
+        let blocks_and_receipts = get_sequential_eth_blocks_and_receipts();
+        let latest_block_and_receipts = blocks_and_receipts[1].clone();
+        put_eth_latest_block_in_db(&db, &latest_block_and_receipts)
+            .unwrap();
+        let mut competing_block = latest_block_and_receipts.block.clone();
+        competing_block.hash = EthHash::random();
+        competing_block.total_difficulty =
+            competing_block.total_difficulty + U256::one();
+        update_latest_block_hash_if_heavier(&db, &competing_block)
+            .unwrap();
+        let result = get_eth_latest_block_hash_from_db(&db)
+            .unwrap();
+        assert_eq!(result, competing_block.hash);
+    }
+
+    #[test]
+    fn should_not_update_latest_block_hash_to_lighter_competing_block() {
+        let db = get_test_database();
+        let blocks_and_receipts = get_sequential_eth_blocks_and_receipts();
+        let latest_block_and_receipts = blocks_and_receipts[1].clone();
+        put_eth_latest_block_in_db(&db, &latest_block_and_receipts)
+            .unwrap();
+        let mut competing_block = latest_block_and_receipts.block.clone();
+        competing_block.hash = EthHash::random();
+        competing_block.total_difficulty =
+            competing_block.total_difficulty - U256::one();
+        update_latest_block_hash_if_heavier(&db, &competing_block)
+            .unwrap();
+        let result = get_eth_latest_block_hash_from_db(&db)
+            .unwrap();
+        assert_eq!(result, latest_block_and_receipts.block.hash);
+    }
+
+    #[test]
+    fn should_update_latest_block_hash_by_height_in_pos_mode() {
+        let db = get_test_database();
+        let blocks_and_receipts = get_sequential_eth_blocks_and_receipts();
+        let latest_block_and_receipts = blocks_and_receipts[1].clone();
+        put_eth_pos_mode_in_db(&db, true).unwrap();
+        put_eth_latest_block_in_db(&db, &latest_block_and_receipts)
+            .unwrap();
+        let mut higher_block = blocks_and_receipts[2].block.clone();
+        higher_block.total_difficulty =
+            latest_block_and_receipts.block.total_difficulty;
+        update_latest_block_hash_if_heavier(&db, &higher_block)
+            .unwrap();
+        let result = get_eth_latest_block_hash_from_db(&db)
+            .unwrap();
+        assert_eq!(result, higher_block.hash);
+    }
+
+    #[test]
+    fn should_not_update_latest_block_hash_to_competing_block_in_pos_mode() {
+        let db = get_test_database();
+        let blocks_and_receipts = get_sequential_eth_blocks_and_receipts();
+        let latest_block_and_receipts = blocks_and_receipts[1].clone();
+        put_eth_pos_mode_in_db(&db, true).unwrap();
+        put_eth_latest_block_in_db(&db, &latest_block_and_receipts)
+            .unwrap();
+        let mut competing_block = latest_block_and_receipts.block.clone();
+        competing_block.hash = EthHash::repeat_byte(0xff);
+        competing_block.total_difficulty =
+            competing_block.total_difficulty + U256::one();
+        update_latest_block_hash_if_heavier(&db, &competing_block)
+            .unwrap();
+        let result = get_eth_latest_block_hash_from_db(&db)
+            .unwrap();
+        assert_eq!(result, latest_block_and_receipts.block.hash);
+    }
+
+    #[test]
+    fn should_tie_break_competing_blocks_by_lowest_hash_in_pos_mode() {
+        let db = get_test_database();
+        let blocks_and_receipts = get_sequential_eth_blocks_and_receipts();
+        let latest_block_and_receipts = blocks_and_receipts[1].clone();
+        put_eth_pos_mode_in_db(&db, true).unwrap();
+        put_eth_latest_block_in_db(&db, &latest_block_and_receipts)
+            .unwrap();
+        let mut competing_block = latest_block_and_receipts.block.clone();
+        competing_block.hash = EthHash::zero();
+        update_latest_block_hash_if_heavier(&db, &competing_block)
+            .unwrap();
+        let result = get_eth_latest_block_hash_from_db(&db)
+            .unwrap();
+        assert_eq!(result, competing_block.hash);
+        update_latest_block_hash_if_heavier(
+            &db,
+            &latest_block_and_receipts.block,
+        ).unwrap();
+        let result = get_eth_latest_block_hash_from_db(&db)
+            .unwrap();
+        assert_eq!(result, competing_block.hash);
+    }
 }
@@ -33,7 +33,7 @@ pub fn validate_block_in_state<D>(state: EthState<D>) -> Result<EthState<D>>
     trace!("✔ Validating block header...");
     match validate_block_header(&state.get_eth_block_and_receipts()?.block)? {
         true => Ok(state),
-        false => Err(AppError::Custom(
+        false => Err(AppError::InvalidBlock(
             format!("✘ Not accepting ETH block - header hash not valid!")
         )),
     }
@@ -89,7 +89,7 @@ mod tests {
         let state = get_valid_state_with_invalid_block_and_receipts()
             .unwrap();
         match validate_block_in_state(state) {
-            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            Err(AppError::InvalidBlock(e)) => assert!(e == expected_error),
             _ => panic!("Should not validate invalid block in state!")
         }
     }
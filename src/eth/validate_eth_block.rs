@@ -0,0 +1,60 @@
+use crate::{
+    types::Result,
+    stage_hooks::stage,
+    traits::DatabaseInterface,
+    validation_verdict::ValidationVerdict,
+    state_size::{
+        check_state_size_is_within_limit,
+        check_submission_size_is_within_limit,
+    },
+    eth::{
+        eth_state::EthState,
+        validate_block::validate_block_in_state,
+        validate_receipts::validate_receipts_in_state,
+        validate_pos_header::validate_pos_header_of_eth_block_in_state,
+        validate_proof_of_work::{
+            validate_proof_of_work_of_eth_block_in_state,
+        },
+        parse_eth_block_and_receipts::{
+            parse_eth_block_and_receipts_and_put_in_state,
+        },
+    },
+};
+
+pub fn validate_eth_block_and_receipts_in_state<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    stage(validate_block_in_state)(state)
+        .and_then(stage(validate_pos_header_of_eth_block_in_state))
+        .and_then(stage(validate_proof_of_work_of_eth_block_in_state))
+        .and_then(stage(validate_receipts_in_state))
+}
+
+pub fn validate_eth_block<D>(
+    db: D,
+    block_json_string: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Validating ETH block without submitting it...");
+    let validation_result = check_submission_size_is_within_limit(
+        EthState::init(db),
+        &block_json_string,
+    )
+        .and_then(|state| parse_eth_block_and_receipts_and_put_in_state(
+            block_json_string,
+            state,
+        ))
+        .and_then(check_state_size_is_within_limit)
+        .and_then(validate_eth_block_and_receipts_in_state)
+        .and_then(|state|
+            Ok(format!(
+                "0x{}",
+                hex::encode(state.get_eth_block_and_receipts()?.block.hash),
+            ))
+        );
+    ValidationVerdict::from_validation_result("ETH", validation_result)
+        .to_json_string()
+}
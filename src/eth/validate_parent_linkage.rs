@@ -0,0 +1,114 @@
+use ethereum_types::U256;
+use crate::{
+    types::Result,
+    errors::AppError,
+    traits::DatabaseInterface,
+    eth::{
+        eth_types::EthBlock,
+        eth_state::EthState,
+        eth_database_utils::get_eth_block_from_db,
+    },
+};
+
+// NOTE: Block fields are host-supplied ∴ sums that overflow don't link.
+fn block_links_to_parent(block: &EthBlock, parent: &EthBlock) -> bool {
+    block.parent_hash == parent.hash &&
+    parent.number.checked_add(U256::one()) == Some(block.number) &&
+    block.timestamp > parent.timestamp &&
+    parent.total_difficulty.checked_add(block.difficulty) ==
+        Some(block.total_difficulty)
+}
+
+pub fn validate_parent_linkage_of_block_in_state<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Validating ETH block links to its parent...");
+    let parent = get_eth_block_from_db(&state.db, &state.get_parent_hash()?)?;
+    match block_links_to_parent(
+        &state.get_eth_block_and_receipts()?.block,
+        &parent.block,
+    ) {
+        true => {
+            info!("✔ ETH block links to its parent!");
+            Ok(state)
+        },
+        false => Err(AppError::InvalidBlock(
+            "✘ Not accepting ETH block - does not link to parent!".to_string()
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        eth::{
+            eth_database_utils::put_eth_block_and_receipts_in_db,
+            eth_test_utils::get_sequential_eth_blocks_and_receipts,
+        },
+    };
+
+    #[test]
+    fn should_return_true_if_block_links_to_parent() {
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        assert!(block_links_to_parent(&blocks[1].block, &blocks[0].block));
+    }
+
+    #[test]
+    fn should_return_false_if_block_number_does_not_follow_parent() {
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        let mut block = blocks[1].block.clone();
+        block.number = block.number + U256::one();
+        assert!(!block_links_to_parent(&block, &blocks[0].block));
+    }
+
+    #[test]
+    fn should_return_false_if_block_timestamp_not_after_parent() {
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        let mut block = blocks[1].block.clone();
+        block.timestamp = blocks[0].block.timestamp;
+        assert!(!block_links_to_parent(&block, &blocks[0].block));
+    }
+
+    #[test]
+    fn should_return_false_if_total_difficulty_does_not_follow_parent() {
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        let mut block = blocks[1].block.clone();
+        block.total_difficulty = block.total_difficulty + U256::one();
+        assert!(!block_links_to_parent(&block, &blocks[0].block));
+    }
+
+    #[test]
+    fn should_return_false_if_total_difficulty_overflows() {
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        let mut parent = blocks[0].block.clone();
+        let mut block = blocks[1].block.clone();
+        parent.total_difficulty = U256::max_value();
+        block.total_difficulty = U256::max_value();
+        assert!(!block_links_to_parent(&block, &parent));
+    }
+
+    #[test]
+    fn should_return_false_if_parent_number_overflows() {
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        let mut parent = blocks[0].block.clone();
+        let mut block = blocks[1].block.clone();
+        parent.number = U256::max_value();
+        block.number = U256::zero();
+        assert!(!block_links_to_parent(&block, &parent));
+    }
+
+    #[test]
+    fn should_validate_parent_linkage_of_block_in_state() {
+        let blocks = get_sequential_eth_blocks_and_receipts();
+        let db = get_test_database();
+        put_eth_block_and_receipts_in_db(&db, &blocks[0]).unwrap();
+        let state = EthState::init(db)
+            .add_eth_block_and_receipts(blocks[1].clone())
+            .unwrap();
+        assert!(validate_parent_linkage_of_block_in_state(state).is_ok());
+    }
+}
@@ -0,0 +1,120 @@
+use ethereum_types::H256;
+use crate::{
+    types::Result,
+    errors::AppError,
+    traits::DatabaseInterface,
+    eth::{
+        eth_state::EthState,
+        eth_types::EthBlock,
+        eth_database_utils::eth_pos_mode_is_enabled,
+    },
+};
+
+pub const EMPTY_UNCLES_HASH: &str =
+    "1dcc4de8dec75d7aab85b567b6ccd41ad312451b948a7413f0a142fd40d49347";
+
+fn get_empty_uncles_hash() -> Result<H256> {
+    Ok(H256::from_slice(&hex::decode(EMPTY_UNCLES_HASH)?))
+}
+
+fn validate_pos_header(block: &EthBlock) -> Result<()> {
+    if !block.difficulty.is_zero() {
+        return Err(AppError::InvalidBlock(
+            "✘ Not accepting ETH block - PoS difficulty must be zero!"
+                .to_string()
+        ))
+    };
+    if block.nonce.iter().any(|byte| *byte != 0) {
+        return Err(AppError::InvalidBlock(
+            "✘ Not accepting ETH block - PoS nonce must be zero!".to_string()
+        ))
+    };
+    if !block.uncles.is_empty() ||
+        block.sha3_uncles != get_empty_uncles_hash()?
+    {
+        return Err(AppError::InvalidBlock(
+            "✘ Not accepting ETH block - PoS block cannot have uncles!"
+                .to_string()
+        ))
+    };
+    Ok(())
+}
+
+pub fn validate_pos_header_of_eth_block_in_state<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    match eth_pos_mode_is_enabled(&state.db) {
+        false => {
+            info!("✔ Skipping ETH PoS header validation - PoS mode disabled!");
+            Ok(state)
+        }
+        true => {
+            info!("✔ Validating ETH block's proof-of-stake header...");
+            validate_pos_header(&state.get_eth_block_and_receipts()?.block)
+                .map(|_| state)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethereum_types::U256;
+    use crate::eth::{
+        eth_database_utils::put_eth_pos_mode_in_db,
+        eth_test_utils::{
+            get_sample_eth_block_and_receipts,
+            get_valid_state_with_block_and_receipts,
+        },
+    };
+
+    fn get_sample_pos_block() -> EthBlock {
+        let mut block = get_sample_eth_block_and_receipts().block;
+        block.difficulty = U256::zero();
+        block.nonce = vec![0u8; 8];
+        block.uncles = vec![];
+        block.sha3_uncles = get_empty_uncles_hash().unwrap();
+        block
+    }
+
+    #[test]
+    fn should_validate_pos_header() {
+        assert!(validate_pos_header(&get_sample_pos_block()).is_ok());
+    }
+
+    #[test]
+    fn should_fail_to_validate_pos_header_with_difficulty() {
+        let mut block = get_sample_pos_block();
+        block.difficulty = U256::one();
+        assert!(validate_pos_header(&block).is_err());
+    }
+
+    #[test]
+    fn should_fail_to_validate_pos_header_with_nonce() {
+        let mut block = get_sample_pos_block();
+        block.nonce = vec![0, 0, 0, 0, 0, 0, 0, 1];
+        assert!(validate_pos_header(&block).is_err());
+    }
+
+    #[test]
+    fn should_fail_to_validate_pos_header_with_uncles() {
+        let mut block = get_sample_pos_block();
+        block.uncles = vec![H256::random()];
+        assert!(validate_pos_header(&block).is_err());
+    }
+
+    #[test]
+    fn should_skip_pos_header_validation_if_pos_mode_disabled() {
+        let state = get_valid_state_with_block_and_receipts().unwrap();
+        assert!(validate_pos_header_of_eth_block_in_state(state).is_ok());
+    }
+
+    #[test]
+    fn should_fail_pos_header_validation_of_pow_block_in_pos_mode() {
+        let state = get_valid_state_with_block_and_receipts().unwrap();
+        put_eth_pos_mode_in_db(&state.db, true).unwrap();
+        assert!(validate_pos_header_of_eth_block_in_state(state).is_err());
+    }
+}
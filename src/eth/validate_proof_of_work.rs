@@ -0,0 +1,101 @@
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    eth::eth_state::EthState,
+};
+#[cfg(feature = "ethash-light")]
+use crate::{
+    errors::AppError,
+    utils::convert_u256_to_u64,
+    crypto_utils::keccak_hash_bytes,
+    eth::{
+        eth_types::EthBlock,
+        rlp_codec::rlp_encode_block_without_seal,
+        eth_database_utils::eth_pos_mode_is_enabled,
+        ethash::{
+            get_epoch,
+            make_cache,
+            get_seed_hash,
+            get_full_size,
+            get_cache_size,
+            hashimoto_light,
+            result_meets_difficulty,
+        },
+    },
+};
+
+#[cfg(feature = "ethash-light")]
+fn proof_of_work_is_valid(block: &EthBlock) -> Result<bool> {
+    let epoch = get_epoch(convert_u256_to_u64(&block.number)?);
+    let cache = make_cache(get_cache_size(epoch), &get_seed_hash(epoch));
+    let (mix_digest, result) = hashimoto_light(
+        &keccak_hash_bytes(rlp_encode_block_without_seal(block)?),
+        &block.nonce,
+        get_full_size(epoch),
+        &cache,
+    );
+    trace!("✔ Ethash mix digest: {}", mix_digest);
+    trace!("✔ Ethash result: {}", result);
+    Ok(
+        mix_digest == block.mix_hash &&
+        result_meets_difficulty(&result, &block.difficulty)
+    )
+}
+
+#[cfg(feature = "ethash-light")]
+pub fn validate_proof_of_work_of_eth_block_in_state<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    if eth_pos_mode_is_enabled(&state.db) {
+        info!("✔ Skipping ETH PoW validation - proof-of-stake mode enabled!");
+        return Ok(state)
+    };
+    info!("✔ Validating ETH block's proof-of-work...");
+    match proof_of_work_is_valid(&state.get_eth_block_and_receipts()?.block)? {
+        true => {
+            info!("✔ ETH block's proof-of-work is valid!");
+            Ok(state)
+        },
+        false => Err(AppError::InvalidBlock(
+            "✘ Not accepting ETH block - proof-of-work not valid!".to_string()
+        )),
+    }
+}
+
+#[cfg(not(feature = "ethash-light"))]
+pub fn validate_proof_of_work_of_eth_block_in_state<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Skipping ETH PoW validation - `ethash-light` not enabled!");
+    Ok(state)
+}
+
+#[cfg(all(test, feature = "ethash-light"))]
+mod tests {
+    use super::*;
+    use crate::eth::eth_test_utils::get_sample_eth_block_and_receipts;
+
+    #[test]
+    fn should_validate_proof_of_work_of_sample_block() {
+        let block = get_sample_eth_block_and_receipts().block;
+        assert!(proof_of_work_is_valid(&block).unwrap());
+    }
+
+    #[test]
+    fn should_not_validate_proof_of_work_with_wrong_nonce() {
+        let mut block = get_sample_eth_block_and_receipts().block;
+        block.nonce = vec![0u8; 8];
+        assert!(!proof_of_work_is_valid(&block).unwrap());
+    }
+
+    #[test]
+    fn should_error_if_block_number_too_large_for_epoch() {
+        let mut block = get_sample_eth_block_and_receipts().block;
+        block.number = ethereum_types::U256::max_value();
+        assert!(proof_of_work_is_valid(&block).is_err());
+    }
+}
@@ -14,6 +14,7 @@ use crate::{
             Trie,
             put_in_trie_recursively,
         },
+        verify_receipt_proofs::receipt_proofs_are_valid,
     },
 };
 
@@ -30,7 +31,7 @@ fn get_receipts_root_from_receipts(receipts: &EthReceipts) -> Result<H256> {
         .map(|trie| trie.root)
 }
 
-fn receipts_root_is_correct(
+pub fn receipts_root_is_correct(
     block: &EthBlock,
     receipts: &EthReceipts,
 ) -> Result<bool> {
@@ -39,23 +40,61 @@ fn receipts_root_is_correct(
         .map(|root| root == block.receipts_root)
 }
 
+// NOTE: Proven receipts are only the relevant subset of a block's ∴ only a
+// submission w/out receipt proofs must have a receipt for every tx.
+fn receipts_are_from_block(
+    block: &EthBlock,
+    receipts: &EthReceipts,
+    has_receipt_proofs: bool,
+) -> bool {
+    info!("✔ Checking receipts are from block...");
+    (has_receipt_proofs || receipts.len() == block.transactions.len()) &&
+    receipts
+        .iter()
+        .all(|receipt|
+            receipt.block_hash == block.hash &&
+            receipt.block_number == block.number &&
+            block.transactions.get(receipt.transaction_index.as_usize()) ==
+                Some(&receipt.transaction_hash)
+        )
+}
+
 pub fn validate_receipts_in_state<D>(
     state: EthState<D>
 ) -> Result<EthState<D>>
     where D: DatabaseInterface
 {
     info!("✔ Validating receipts...");
-    match receipts_root_is_correct(
-        &state.get_eth_block_and_receipts()?.block,
-        &state.get_eth_block_and_receipts()?.receipts,
-    )? {
-        true => {
-            info!("✔ Receipts are valid!");
-            Ok(state)
-        },
-        false => Err(AppError::Custom(
+    let block_and_receipts = state.get_eth_block_and_receipts()?;
+    let receipts_are_valid = match state.get_eth_receipt_proofs() {
+        Err(_) => receipts_root_is_correct(
+            &block_and_receipts.block,
+            &block_and_receipts.receipts,
+        )?,
+        Ok(receipt_proofs) => receipt_proofs_are_valid(
+            &block_and_receipts.block.receipts_root,
+            &block_and_receipts.receipts,
+            receipt_proofs,
+        )?,
+    };
+    match receipts_are_valid {
+        false => Err(AppError::InvalidBlock(
             format!("✘ Not accepting ETH block - receipts root not valid!")
-        ))
+        )),
+        true => match receipts_are_from_block(
+            &block_and_receipts.block,
+            &block_and_receipts.receipts,
+            state.eth_receipt_proofs.is_filled(),
+        ) {
+            false => Err(AppError::InvalidBlock(
+                "✘ Not accepting ETH block - receipts not from block!"
+                    .to_string()
+            )),
+            true => {
+                info!("✔ Receipts are valid!");
+                Ok(state)
+            },
+        }
     }
 }
 
@@ -100,6 +139,60 @@ mod tests {
         assert!(!result);
     }
 
+    #[test]
+    fn should_return_true_if_receipts_are_from_block() {
+        let block_and_receipts = get_sample_eth_block_and_receipts();
+        assert!(receipts_are_from_block(
+            &block_and_receipts.block,
+            &block_and_receipts.receipts,
+            false,
+        ));
+    }
+
+    #[test]
+    fn should_return_false_if_receipt_tx_hash_not_in_block() {
+        let mut block_and_receipts = get_sample_eth_block_and_receipts();
+        block_and_receipts.receipts[0].transaction_hash = H256::zero();
+        assert!(!receipts_are_from_block(
+            &block_and_receipts.block,
+            &block_and_receipts.receipts,
+            false,
+        ));
+    }
+
+    #[test]
+    fn should_return_false_if_receipt_block_hash_not_block_hash() {
+        let mut block_and_receipts = get_sample_eth_block_and_receipts();
+        block_and_receipts.receipts[0].block_hash = H256::zero();
+        assert!(!receipts_are_from_block(
+            &block_and_receipts.block,
+            &block_and_receipts.receipts,
+            false,
+        ));
+    }
+
+    #[test]
+    fn should_return_false_if_receipts_missing_without_receipt_proofs() {
+        let mut block_and_receipts = get_sample_eth_block_and_receipts();
+        block_and_receipts.receipts.pop();
+        assert!(!receipts_are_from_block(
+            &block_and_receipts.block,
+            &block_and_receipts.receipts,
+            false,
+        ));
+    }
+
+    #[test]
+    fn should_return_true_if_receipts_missing_with_receipt_proofs() {
+        let mut block_and_receipts = get_sample_eth_block_and_receipts();
+        block_and_receipts.receipts.pop();
+        assert!(receipts_are_from_block(
+            &block_and_receipts.block,
+            &block_and_receipts.receipts,
+            true,
+        ));
+    }
+
     #[test]
     fn should_validate_receipts_in_state() {
         let state = get_valid_state_with_block_and_receipts()
@@ -116,7 +209,7 @@ mod tests {
         let state = get_valid_state_with_invalid_block_and_receipts()
             .unwrap();
         match validate_receipts_in_state(state) {
-            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            Err(AppError::InvalidBlock(e)) => assert!(e == expected_error),
             Ok(_) => panic!("Receipts should not be valid!"),
             Err(_) => panic!("Wrong error message!"),
         }
@@ -0,0 +1,61 @@
+use crate::{
+    traits::DatabaseInterface,
+    crypto_utils::keccak_hash_bytes,
+    staged_database::StagedDatabase,
+    db_codec::DbSerializationFormat,
+    constants::ETH_VALIDATION_CHECKPOINT_KEY,
+    types::{
+        Bytes,
+        Result,
+    },
+    validation_checkpoint::{
+        clear_validation_checkpoint,
+        validate_unless_checkpointed,
+    },
+    eth::{
+        eth_state::EthState,
+        validate_eth_block::validate_eth_block_and_receipts_in_state,
+        eth_json_codec::encode_eth_block_and_receipts_as_json_bytes,
+    },
+};
+
+// NOTE: Always digested as JSON so a checkpoint doesn't depend on the format
+// the db happens to store blocks in.
+fn get_eth_block_digest<D>(state: &EthState<D>) -> Result<Bytes>
+    where D: DatabaseInterface
+{
+    let proofs = match state.eth_receipt_proofs.as_option() {
+        Some(proofs) => proofs.concat().concat(),
+        None => vec![],
+    };
+    encode_eth_block_and_receipts_as_json_bytes(
+        state.get_eth_block_and_receipts()?,
+        DbSerializationFormat::Json,
+    )
+        .map(|block_bytes| [block_bytes, proofs].concat())
+        .map(|bytes| keccak_hash_bytes(bytes).as_bytes().to_vec())
+}
+
+pub fn validate_eth_block_unless_checkpointed<D>(
+    state: EthState<StagedDatabase<D>>
+) -> Result<EthState<StagedDatabase<D>>>
+    where D: DatabaseInterface
+{
+    info!("✔ Validating ETH block unless already checkpointed...");
+    let block_digest = get_eth_block_digest(&state)?;
+    validate_unless_checkpointed(
+        state,
+        &ETH_VALIDATION_CHECKPOINT_KEY,
+        block_digest,
+        validate_eth_block_and_receipts_in_state,
+    )
+}
+
+pub fn clear_eth_validation_checkpoint<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    clear_validation_checkpoint(&state.db, &ETH_VALIDATION_CHECKPOINT_KEY)
+        .map(|_| state)
+}
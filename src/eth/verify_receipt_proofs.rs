@@ -0,0 +1,198 @@
+use ethereum_types::H256;
+use crate::{
+    types::{
+        Byte,
+        Bytes,
+        Result,
+    },
+    crypto_utils::keccak_hash_bytes,
+    eth::{
+        trie_nodes::rlp_decode_node,
+        eth_types::{
+            EthReceipt,
+            EthReceipts,
+            EthReceiptProof,
+            EthReceiptProofs,
+        },
+        nibble_utils::{
+            Nibbles,
+            get_nibble_at_index,
+        },
+        rlp_codec::{
+            rlp_encode_receipt,
+            rlp_encode_transaction_index,
+        },
+    },
+};
+
+fn convert_bytes_to_nibble_vec(bytes: &[Byte]) -> Vec<Byte> {
+    bytes
+        .iter()
+        .flat_map(|byte| vec![byte >> 4, byte & 0x0f])
+        .collect()
+}
+
+fn convert_nibbles_to_nibble_vec(nibbles: &Nibbles) -> Result<Vec<Byte>> {
+    (0..nibbles.len())
+        .map(|i| get_nibble_at_index(nibbles, i))
+        .collect()
+}
+
+fn get_value_from_proof(
+    root: &H256,
+    key: &[Byte],
+    proof: &[Bytes],
+) -> Result<Option<Bytes>> {
+    let mut remaining_key = key;
+    let mut expected_hash = *root;
+    for (i, encoded_node) in proof.iter().enumerate() {
+        let is_last_node = i == proof.len() - 1;
+        if keccak_hash_bytes(encoded_node.clone()) != expected_hash {
+            return Ok(None)
+        };
+        let node = rlp_decode_node(encoded_node.clone())?;
+        if let Some(leaf) = node.leaf {
+            let path = convert_nibbles_to_nibble_vec(&leaf.path_nibbles)?;
+            return match is_last_node && path == remaining_key {
+                true => Ok(Some(leaf.value)),
+                false => Ok(None),
+            }
+        } else if let Some(extension) = node.extension {
+            let path = convert_nibbles_to_nibble_vec(&extension.path_nibbles)?;
+            if
+                !remaining_key.starts_with(&path) ||
+                extension.value.len() != 32
+            {
+                return Ok(None)
+            };
+            remaining_key = &remaining_key[path.len()..];
+            expected_hash = H256::from_slice(&extension.value);
+        } else if let Some(branch) = node.branch {
+            match remaining_key.split_first() {
+                None => return match is_last_node {
+                    true => Ok(branch.value),
+                    false => Ok(None),
+                },
+                Some((nibble, rest)) => {
+                    match &branch.branches[*nibble as usize] {
+                        Some(child) if child.len() == 32 => {
+                            remaining_key = rest;
+                            expected_hash = H256::from_slice(child);
+                        },
+                        _ => return Ok(None),
+                    }
+                },
+            }
+        }
+    }
+    Ok(None)
+}
+
+pub fn verify_receipt_proof(
+    receipts_root: &H256,
+    receipt: &EthReceipt,
+    proof: &EthReceiptProof,
+) -> Result<bool> {
+    let key = convert_bytes_to_nibble_vec(
+        &rlp_encode_transaction_index(&receipt.transaction_index)?
+    );
+    get_value_from_proof(receipts_root, &key, proof)
+        .and_then(|maybe_value| match maybe_value {
+            None => Ok(false),
+            Some(value) => Ok(value == rlp_encode_receipt(receipt)?),
+        })
+}
+
+pub fn receipt_proofs_are_valid(
+    receipts_root: &H256,
+    receipts: &EthReceipts,
+    proofs: &EthReceiptProofs,
+) -> Result<bool> {
+    info!("✔ Verifying {} ETH receipt proofs...", proofs.len());
+    match receipts.len() == proofs.len() {
+        false => Ok(false),
+        true => receipts
+            .iter()
+            .zip(proofs.iter())
+            .map(|(receipt, proof)|
+                verify_receipt_proof(receipts_root, receipt, proof)
+            )
+            .collect::<Result<Vec<bool>>>()
+            .map(|results| results.iter().all(|result| *result)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{
+            TestDB,
+            get_test_database,
+        },
+        eth::{
+            eth_state::EthState,
+            eth_test_utils::get_sample_eth_block_and_receipt_proofs_string,
+            parse_eth_block_and_receipts::{
+                parse_eth_block_and_receipts_and_put_in_state,
+            },
+        },
+    };
+
+    fn get_sample_state_with_receipt_proofs() -> EthState<TestDB> {
+        parse_eth_block_and_receipts_and_put_in_state(
+            get_sample_eth_block_and_receipt_proofs_string().unwrap(),
+            EthState::init(get_test_database()),
+        ).unwrap()
+    }
+
+    #[test]
+    fn should_verify_receipt_proofs() {
+        let state = get_sample_state_with_receipt_proofs();
+        let block_and_receipts = state.get_eth_block_and_receipts().unwrap();
+        let result = receipt_proofs_are_valid(
+            &block_and_receipts.block.receipts_root,
+            &block_and_receipts.receipts,
+            state.get_eth_receipt_proofs().unwrap(),
+        ).unwrap();
+        assert!(result);
+    }
+
+    #[test]
+    fn should_not_verify_receipt_proof_against_wrong_root() {
+        let state = get_sample_state_with_receipt_proofs();
+        let block_and_receipts = state.get_eth_block_and_receipts().unwrap();
+        let result = verify_receipt_proof(
+            &H256::zero(),
+            &block_and_receipts.receipts[0],
+            &state.get_eth_receipt_proofs().unwrap()[0],
+        ).unwrap();
+        assert!(!result);
+    }
+
+    #[test]
+    fn should_not_verify_receipt_proof_for_different_receipt() {
+        let state = get_sample_state_with_receipt_proofs();
+        let block_and_receipts = state.get_eth_block_and_receipts().unwrap();
+        let result = verify_receipt_proof(
+            &block_and_receipts.block.receipts_root,
+            &block_and_receipts.receipts[1],
+            &state.get_eth_receipt_proofs().unwrap()[0],
+        ).unwrap();
+        assert!(!result);
+    }
+
+    #[test]
+    fn should_not_verify_tampered_receipt() {
+        let state = get_sample_state_with_receipt_proofs();
+        let block_and_receipts = state.get_eth_block_and_receipts().unwrap();
+        let mut receipt = block_and_receipts.receipts[1].clone();
+        receipt.status = !receipt.status;
+        let result = verify_receipt_proof(
+            &block_and_receipts.block.receipts_root,
+            &receipt,
+            &state.get_eth_receipt_proofs().unwrap()[1],
+        ).unwrap();
+        assert!(!result);
+    }
+}
@@ -0,0 +1,197 @@
+use std::cmp::min;
+use crate::{
+    traits::DatabaseInterface,
+    db_keys::get_event_journal_entry_db_key,
+    core_metrics::increment_event_metrics_in_db,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    constants::{
+        EVENT_JOURNAL_SEQUENCE_KEY,
+        MAX_EVENT_JOURNAL_PAGE_SIZE,
+    },
+    database_utils::{
+        put_u64_in_db,
+        get_u64_from_db,
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BridgeEventType {
+    MintSigned,
+    ReorgHandled,
+    PayoutSigned,
+    PegInDetected,
+    RedeemDetected,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BridgeEvent {
+    pub sequence: u64,
+    pub event_type: BridgeEventType,
+    pub block_hash: String,
+    pub tx_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+}
+
+impl BridgeEvent {
+    pub fn new(
+        event_type: BridgeEventType,
+        block_hash: String,
+        tx_hash: Option<String>,
+    ) -> Self {
+        BridgeEvent {
+            tx_hash,
+            block_hash,
+            event_type,
+            sequence: 0,
+            correlation_id: None,
+        }
+    }
+
+    pub fn with_correlation_id(self, correlation_id: &str) -> Self {
+        BridgeEvent { correlation_id: Some(correlation_id.to_string()), ..self }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BridgeEventPage {
+    pub events: Vec<BridgeEvent>,
+    pub next_sequence: u64,
+}
+
+pub fn get_next_event_sequence_from_db<D>(db: &D) -> u64
+    where D: DatabaseInterface
+{
+    get_u64_from_db(db, &EVENT_JOURNAL_SEQUENCE_KEY.to_vec()).unwrap_or(0)
+}
+
+fn put_bridge_event_in_db<D>(db: &D, event: &BridgeEvent) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting bridge event #{} in db...", event.sequence);
+    db.put(
+        get_event_journal_entry_db_key(event.sequence),
+        serde_json::to_vec(event)?,
+        DataSensitivity::Public,
+    )
+}
+
+fn get_bridge_event_from_db<D>(db: &D, sequence: u64) -> Result<BridgeEvent>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting bridge event #{} from db...", sequence);
+    db.get(get_event_journal_entry_db_key(sequence), DataSensitivity::Public)
+        .and_then(|bytes| Ok(serde_json::from_slice(&bytes)?))
+}
+
+pub fn append_events_to_journal<D>(
+    db: &D,
+    events: Vec<BridgeEvent>,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!("✔ Appending {} events to bridge event journal...", events.len());
+    let first_sequence = get_next_event_sequence_from_db(db);
+    let next_sequence = first_sequence + events.len() as u64;
+    increment_event_metrics_in_db(db, &events)?;
+    events
+        .into_iter()
+        .zip(first_sequence..next_sequence)
+        .map(|(event, sequence)|
+            put_bridge_event_in_db(db, &BridgeEvent { sequence, ..event })
+        )
+        .collect::<Result<Vec<()>>>()
+        .and_then(|_|
+            put_u64_in_db(
+                db,
+                &EVENT_JOURNAL_SEQUENCE_KEY.to_vec(),
+                &next_sequence,
+            )
+        )
+}
+
+pub fn get_bridge_events_from_journal<D>(
+    db: &D,
+    from_sequence: u64,
+    limit: u64,
+) -> Result<Vec<BridgeEvent>>
+    where D: DatabaseInterface
+{
+    let end_sequence = min(
+        get_next_event_sequence_from_db(db),
+        from_sequence.saturating_add(min(limit, MAX_EVENT_JOURNAL_PAGE_SIZE)),
+    );
+    (from_sequence..end_sequence)
+        .map(|sequence| get_bridge_event_from_db(db, sequence))
+        .collect()
+}
+
+pub fn get_bridge_events<D>(
+    db: D,
+    from_sequence: u64,
+    limit: u64,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting bridge events from sequence #{}...", from_sequence);
+    check_enclave_is_initialized(&db)
+        .and_then(|_| get_bridge_events_from_journal(&db, from_sequence, limit))
+        .and_then(|events| {
+            let next_sequence = match events.last() {
+                Some(event) => event.sequence + 1,
+                None => from_sequence,
+            };
+            Ok(serde_json::to_string(
+                &BridgeEventPage { events, next_sequence }
+            )?)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_test_database;
+
+    fn get_sample_events(num_events: usize) -> Vec<BridgeEvent> {
+        (0..num_events)
+            .map(|i|
+                BridgeEvent::new(
+                    BridgeEventType::PegInDetected,
+                    format!("block-{}", i),
+                    Some(format!("tx-{}", i)),
+                )
+            )
+            .collect()
+    }
+
+    #[test]
+    fn should_append_events_with_increasing_sequence_numbers() {
+        let db = get_test_database();
+        append_events_to_journal(&db, get_sample_events(2)).unwrap();
+        append_events_to_journal(&db, get_sample_events(3)).unwrap();
+        let result = get_bridge_events_from_journal(&db, 0, 10).unwrap();
+        let sequences = result
+            .iter()
+            .map(|event| event.sequence)
+            .collect::<Vec<u64>>();
+        assert_eq!(sequences, vec![0, 1, 2, 3, 4]);
+        assert_eq!(result[2].block_hash, "block-0");
+        assert_eq!(get_next_event_sequence_from_db(&db), 5);
+    }
+
+    #[test]
+    fn should_paginate_bridge_events() {
+        let db = get_test_database();
+        append_events_to_journal(&db, get_sample_events(5)).unwrap();
+        let page = get_bridge_events_from_journal(&db, 1, 2).unwrap();
+        assert_eq!(page.len(), 2);
+        assert_eq!(page[0].sequence, 1);
+        assert_eq!(page[1].sequence, 2);
+        assert!(get_bridge_events_from_journal(&db, 5, 2).unwrap().is_empty());
+    }
+}
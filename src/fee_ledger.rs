@@ -0,0 +1,321 @@
+use std::collections::BTreeMap;
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    database_utils::{
+        put_u64_in_db,
+        get_u64_from_db,
+    },
+    constants::{
+        BTC_FEE_LEDGER_SIZE_KEY,
+        ETH_FEE_LEDGER_SIZE_KEY,
+    },
+    db_keys::{
+        get_fee_ledger_entry_db_key,
+        BTC_FEE_LEDGER_ENTRY_KEY_PREFIX,
+        ETH_FEE_LEDGER_ENTRY_KEY_PREFIX,
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeeType {
+    PegIn,
+    PegOut,
+    GasSubsidy,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeLedgerRecord {
+    pub block_height: u64,
+    pub fee_type: FeeType,
+    pub amount: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct FeeLedgerEntry {
+    peg_in_fees: u64,
+    peg_out_fees: u64,
+    gas_subsidies: u64,
+}
+
+impl FeeLedgerEntry {
+    fn add_fee(self, fee_type: FeeType, amount: u64) -> Self {
+        match fee_type {
+            FeeType::PegIn => FeeLedgerEntry {
+                peg_in_fees: self.peg_in_fees.saturating_add(amount),
+                ..self
+            },
+            FeeType::PegOut => FeeLedgerEntry {
+                peg_out_fees: self.peg_out_fees.saturating_add(amount),
+                ..self
+            },
+            FeeType::GasSubsidy => FeeLedgerEntry {
+                gas_subsidies: self.gas_subsidies.saturating_add(amount),
+                ..self
+            },
+        }
+    }
+
+    fn get_total(&self) -> u64 {
+        self.peg_in_fees
+            .saturating_add(self.peg_out_fees)
+            .saturating_add(self.gas_subsidies)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeEpochSummary {
+    pub epoch: u64,
+    pub first_block: u64,
+    pub last_block: u64,
+    pub peg_in_fees: u64,
+    pub peg_out_fees: u64,
+    pub gas_subsidies: u64,
+    pub total_fees: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeLedgerReport {
+    pub epoch_length_in_blocks: u64,
+    pub btc_epochs: Vec<FeeEpochSummary>,
+    pub eth_epochs: Vec<FeeEpochSummary>,
+}
+
+// NOTE: Each fee is its own record, keyed by its index in its chain's ledger,
+// so recording one only writes that record & the ledger's size.
+struct FeeLedgerKeys {
+    size_key: &'static [u8],
+    entry_key_prefix: &'static str,
+}
+
+static BTC_FEE_LEDGER_KEYS: FeeLedgerKeys = FeeLedgerKeys {
+    size_key: &BTC_FEE_LEDGER_SIZE_KEY,
+    entry_key_prefix: BTC_FEE_LEDGER_ENTRY_KEY_PREFIX,
+};
+
+static ETH_FEE_LEDGER_KEYS: FeeLedgerKeys = FeeLedgerKeys {
+    size_key: &ETH_FEE_LEDGER_SIZE_KEY,
+    entry_key_prefix: ETH_FEE_LEDGER_ENTRY_KEY_PREFIX,
+};
+
+fn get_fee_ledger_size_from_db<D>(db: &D, keys: &FeeLedgerKeys) -> u64
+    where D: DatabaseInterface
+{
+    get_u64_from_db(db, &keys.size_key.to_vec()).unwrap_or(0)
+}
+
+fn get_fee_ledger_record_from_db<D>(
+    db: &D,
+    keys: &FeeLedgerKeys,
+    index: u64,
+) -> Result<FeeLedgerRecord>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting fee ledger record #{} from db...", index);
+    db.get(
+        get_fee_ledger_entry_db_key(keys.entry_key_prefix, index),
+        DataSensitivity::Public,
+    )
+        .and_then(|bytes| Ok(serde_json::from_slice(&bytes)?))
+}
+
+fn get_fee_ledger_records_from_db<D>(
+    db: &D,
+    keys: &FeeLedgerKeys,
+) -> Result<Vec<FeeLedgerRecord>>
+    where D: DatabaseInterface
+{
+    (0..get_fee_ledger_size_from_db(db, keys))
+        .map(|index| get_fee_ledger_record_from_db(db, keys, index))
+        .collect()
+}
+
+fn record_fee_in_ledger<D>(
+    db: &D,
+    keys: &FeeLedgerKeys,
+    block_height: u64,
+    fee_type: FeeType,
+    amount: u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    if amount == 0 {
+        return Ok(())
+    };
+    info!(
+        "✔ Recording {:?} fee of {} satoshis in ledger at block #{}...",
+        fee_type,
+        amount,
+        block_height,
+    );
+    let index = get_fee_ledger_size_from_db(db, keys);
+    let record = FeeLedgerRecord { block_height, fee_type, amount };
+    db.put(
+        get_fee_ledger_entry_db_key(keys.entry_key_prefix, index),
+        serde_json::to_vec(&record)?,
+        DataSensitivity::Public,
+    )
+        .and_then(|_| put_u64_in_db(db, &keys.size_key.to_vec(), &(index + 1)))
+}
+
+pub fn record_btc_fee_in_ledger<D>(
+    db: &D,
+    btc_block_height: u64,
+    fee_type: FeeType,
+    amount: u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    record_fee_in_ledger(
+        db,
+        &BTC_FEE_LEDGER_KEYS,
+        btc_block_height,
+        fee_type,
+        amount,
+    )
+}
+
+pub fn record_eth_fee_in_ledger<D>(
+    db: &D,
+    eth_block_height: u64,
+    fee_type: FeeType,
+    amount: u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    record_fee_in_ledger(
+        db,
+        &ETH_FEE_LEDGER_KEYS,
+        eth_block_height,
+        fee_type,
+        amount,
+    )
+}
+
+fn summarize_fee_ledger(
+    records: &[FeeLedgerRecord],
+    epoch_length_in_blocks: u64,
+) -> Vec<FeeEpochSummary> {
+    let mut epochs: BTreeMap<u64, FeeLedgerEntry> = BTreeMap::new();
+    records
+        .iter()
+        .for_each(|record| {
+            let epoch = record.block_height / epoch_length_in_blocks;
+            let summary = epochs.remove(&epoch).unwrap_or_default()
+                .add_fee(record.fee_type, record.amount);
+            epochs.insert(epoch, summary);
+        });
+    epochs
+        .into_iter()
+        .map(|(epoch, entry)|
+            FeeEpochSummary {
+                epoch,
+                total_fees: entry.get_total(),
+                peg_in_fees: entry.peg_in_fees,
+                peg_out_fees: entry.peg_out_fees,
+                gas_subsidies: entry.gas_subsidies,
+                first_block: epoch * epoch_length_in_blocks,
+                last_block:
+                    epoch * epoch_length_in_blocks + epoch_length_in_blocks - 1,
+            }
+        )
+        .collect()
+}
+
+pub fn get_fee_ledger_report_from_db<D>(
+    db: &D,
+    epoch_length_in_blocks: u64,
+) -> Result<FeeLedgerReport>
+    where D: DatabaseInterface
+{
+    if epoch_length_in_blocks == 0 {
+        return Err(AppError::InvalidInput(
+            "✘ Epoch length must be greater than zero!".to_string()
+        ))
+    };
+    Ok(
+        FeeLedgerReport {
+            epoch_length_in_blocks,
+            btc_epochs: summarize_fee_ledger(
+                &get_fee_ledger_records_from_db(db, &BTC_FEE_LEDGER_KEYS)?,
+                epoch_length_in_blocks,
+            ),
+            eth_epochs: summarize_fee_ledger(
+                &get_fee_ledger_records_from_db(db, &ETH_FEE_LEDGER_KEYS)?,
+                epoch_length_in_blocks,
+            ),
+        }
+    )
+}
+
+pub fn get_fee_ledger_summaries<D>(
+    db: D,
+    epoch_length_in_blocks: u64,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!(
+        "✔ Getting fee ledger summaries per {} block epoch...",
+        epoch_length_in_blocks,
+    );
+    check_enclave_is_initialized(&db)
+        .and_then(|_|
+            get_fee_ledger_report_from_db(&db, epoch_length_in_blocks)
+        )
+        .and_then(|report| Ok(serde_json::to_string(&report)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_test_database;
+
+    #[test]
+    fn should_record_fees_in_ledger_by_block_height() {
+        let db = get_test_database();
+        record_btc_fee_in_ledger(&db, 10, FeeType::PegIn, 100).unwrap();
+        record_btc_fee_in_ledger(&db, 10, FeeType::GasSubsidy, 5).unwrap();
+        record_btc_fee_in_ledger(&db, 11, FeeType::PegIn, 0).unwrap();
+        let records = get_fee_ledger_records_from_db(&db, &BTC_FEE_LEDGER_KEYS)
+            .unwrap();
+        assert_eq!(records, vec![
+            FeeLedgerRecord {
+                block_height: 10,
+                fee_type: FeeType::PegIn,
+                amount: 100,
+            },
+            FeeLedgerRecord {
+                block_height: 10,
+                fee_type: FeeType::GasSubsidy,
+                amount: 5,
+            },
+        ]);
+        let records = get_fee_ledger_records_from_db(&db, &ETH_FEE_LEDGER_KEYS)
+            .unwrap();
+        assert!(records.is_empty());
+    }
+
+    #[test]
+    fn should_summarize_fee_ledger_per_epoch() {
+        let db = get_test_database();
+        record_eth_fee_in_ledger(&db, 5, FeeType::PegOut, 10).unwrap();
+        record_eth_fee_in_ledger(&db, 99, FeeType::PegOut, 20).unwrap();
+        record_eth_fee_in_ledger(&db, 250, FeeType::PegOut, 30).unwrap();
+        let report = get_fee_ledger_report_from_db(&db, 100).unwrap();
+        assert!(report.btc_epochs.is_empty());
+        assert_eq!(report.eth_epochs.len(), 2);
+        assert_eq!(report.eth_epochs[0].epoch, 0);
+        assert_eq!(report.eth_epochs[0].total_fees, 30);
+        assert_eq!(report.eth_epochs[1].epoch, 2);
+        assert_eq!(report.eth_epochs[1].first_block, 200);
+        assert_eq!(report.eth_epochs[1].last_block, 299);
+        assert_eq!(report.eth_epochs[1].peg_out_fees, 30);
+        assert!(get_fee_ledger_report_from_db(&db, 0).is_err());
+    }
+}
@@ -0,0 +1,179 @@
+use std::{
+    ptr,
+    slice,
+    os::raw::{
+        c_int,
+        c_void,
+    },
+};
+use crate::{
+    errors::AppError,
+    utils::get_not_in_db_err,
+    traits::DatabaseInterface,
+    types::{
+        Bytes,
+        Result,
+        DataSensitivity,
+    },
+};
+
+pub type FfiStatus = c_int;
+
+pub const FFI_SUCCESS: FfiStatus = 0;
+pub const FFI_NOT_FOUND: FfiStatus = 1;
+
+// NOTE: The core only ever borrows the key & value buffers it passes to the
+// host. Values returned via `get` are allocated by the host, copied by the
+// core, then handed back to the host via `free_value`.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct FfiDatabase {
+    pub context: *mut c_void,
+    pub start_transaction: extern "C" fn(context: *mut c_void) -> FfiStatus,
+    pub end_transaction: extern "C" fn(context: *mut c_void) -> FfiStatus,
+    pub delete: extern "C" fn(
+        context: *mut c_void,
+        key: *const u8,
+        key_len: usize,
+    ) -> FfiStatus,
+    pub put: extern "C" fn(
+        context: *mut c_void,
+        key: *const u8,
+        key_len: usize,
+        value: *const u8,
+        value_len: usize,
+        is_private: bool,
+    ) -> FfiStatus,
+    pub get: extern "C" fn(
+        context: *mut c_void,
+        key: *const u8,
+        key_len: usize,
+        is_private: bool,
+        value: *mut *mut u8,
+        value_len: *mut usize,
+    ) -> FfiStatus,
+    pub free_value: extern "C" fn(
+        context: *mut c_void,
+        value: *mut u8,
+        value_len: usize,
+    ),
+}
+
+fn is_private(sensitivity: DataSensitivity) -> bool {
+    match sensitivity {
+        DataSensitivity::Public => false,
+        DataSensitivity::Private => true,
+    }
+}
+
+fn get_ffi_err(status: FfiStatus, operation: &str) -> AppError {
+    AppError::DatabaseError(
+        format!("✘ FFI database {} failed: {}!", operation, status)
+    )
+}
+
+fn check_ffi_status(status: FfiStatus, operation: &str) -> Result<()> {
+    match status {
+        FFI_SUCCESS => Ok(()),
+        _ => Err(get_ffi_err(status, operation)),
+    }
+}
+
+impl DatabaseInterface for FfiDatabase {
+    fn end_transaction(&self) -> Result<()> {
+        check_ffi_status(
+            (self.end_transaction)(self.context),
+            "end transaction",
+        )
+    }
+
+    fn start_transaction(&self) -> Result<()> {
+        check_ffi_status(
+            (self.start_transaction)(self.context),
+            "start transaction",
+        )
+    }
+
+    fn delete(&self, key: Bytes) -> Result<()> {
+        check_ffi_status(
+            (self.delete)(self.context, key.as_ptr(), key.len()),
+            "delete",
+        )
+    }
+
+    fn get(&self, key: Bytes, sensitivity: DataSensitivity) -> Result<Bytes> {
+        let mut value_ptr: *mut u8 = ptr::null_mut();
+        let mut value_len: usize = 0;
+        let status = (self.get)(
+            self.context,
+            key.as_ptr(),
+            key.len(),
+            is_private(sensitivity),
+            &mut value_ptr,
+            &mut value_len,
+        );
+        match (status, value_ptr.is_null()) {
+            (FFI_NOT_FOUND, _) | (FFI_SUCCESS, true) =>
+                Err(get_not_in_db_err()),
+            (FFI_SUCCESS, false) => {
+                let value = unsafe {
+                    slice::from_raw_parts(value_ptr, value_len).to_vec()
+                };
+                (self.free_value)(self.context, value_ptr, value_len);
+                Ok(value)
+            }
+            _ => Err(get_ffi_err(status, "get")),
+        }
+    }
+
+    fn put(
+        &self,
+        key: Bytes,
+        value: Bytes,
+        sensitivity: DataSensitivity,
+    ) -> Result<()> {
+        check_ffi_status(
+            (self.put)(
+                self.context,
+                key.as_ptr(),
+                key.len(),
+                value.as_ptr(),
+                value.len(),
+                is_private(sensitivity),
+            ),
+            "put",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ffi::ffi_test_utils::FfiTestContext;
+
+    #[test]
+    fn should_put_get_and_delete_via_ffi_callbacks() {
+        let context = FfiTestContext::default();
+        let db = context.get_ffi_database();
+        let key = vec![0xc0, 0xff, 0xee];
+        let value = vec![0xde, 0xca, 0xf0];
+        db.put(key.clone(), value.clone(), DataSensitivity::Private)
+            .unwrap();
+        assert_eq!(context.num_items(), 1);
+        let result = db.get(key.clone(), DataSensitivity::Private).unwrap();
+        assert_eq!(result, value);
+        db.delete(key.clone()).unwrap();
+        assert_eq!(context.num_items(), 0);
+        assert!(db.get(key, DataSensitivity::Public).is_err());
+    }
+
+    #[test]
+    fn should_get_empty_value_via_ffi_callbacks() {
+        let context = FfiTestContext::default();
+        let db = context.get_ffi_database();
+        let key = vec![0xc0, 0xff, 0xee];
+        db.put(key.clone(), vec![], DataSensitivity::Public).unwrap();
+        let result = db.get(key, DataSensitivity::Public).unwrap();
+        assert!(result.is_empty());
+    }
+}
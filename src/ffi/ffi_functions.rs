@@ -0,0 +1,423 @@
+use std::{
+    ptr,
+    ffi::{
+        CStr,
+        CString,
+    },
+    os::raw::c_char,
+    panic::{
+        catch_unwind,
+        AssertUnwindSafe,
+    },
+};
+use crate::{
+    types::Result,
+    errors::AppError,
+    ffi::ffi_database::FfiDatabase,
+    get_enclave_state::get_enclave_state,
+    get_latest_block_numbers::get_latest_block_numbers,
+    submit_block::{
+        submit_block,
+        SubmissionMaterial,
+    },
+    debug_functions::{
+        debug_get_all_utxos,
+        debug_get_core_state,
+        debug_get_key_from_db,
+        debug_get_safe_addresses,
+        debug_get_unexpected_db_keys,
+    },
+    eth::{
+        submit_eth_block::submit_eth_block_to_enclave,
+        initialize_eth::initialize_eth_enclave::maybe_initialize_eth_enclave,
+    },
+    btc::{
+        submit_btc_block::submit_btc_block_to_enclave,
+        initialize_btc::initialize_btc_enclave::maybe_initialize_btc_enclave,
+    },
+};
+
+// NOTE: The `output` string is owned by the caller once returned & must be
+// released via `pbtc_free_string`, never via the host's own `free`.
+#[repr(C)]
+pub struct FfiResult {
+    pub is_error: bool,
+    pub output: *mut c_char,
+}
+
+fn to_c_string(string: String) -> *mut c_char {
+    CString::new(string.replace('\0', ""))
+        .map(CString::into_raw)
+        .unwrap_or(ptr::null_mut())
+}
+
+fn to_ffi_result(result: Result<String>) -> FfiResult {
+    match result {
+        Ok(output) => FfiResult {
+            is_error: false,
+            output: to_c_string(output),
+        },
+        Err(err) => FfiResult {
+            is_error: true,
+            output: to_c_string(err.to_string()),
+        },
+    }
+}
+
+// NOTE: Unwinding across the FFI boundary is undefined behaviour ∴ any panic
+// is caught & returned as an error instead.
+fn run_ffi_function<F>(ffi_function: F) -> FfiResult
+    where F: FnOnce() -> Result<String>
+{
+    match catch_unwind(AssertUnwindSafe(ffi_function)) {
+        Ok(result) => to_ffi_result(result),
+        Err(_) => to_ffi_result(Err(AppError::HostError(
+            "✘ Core panicked during FFI call!".to_string()
+        ))),
+    }
+}
+
+unsafe fn get_string_from_c_str(c_str: *const c_char) -> Result<String> {
+    match c_str.is_null() {
+        true => Err(AppError::HostError(
+            "✘ Null string pointer passed to core!".to_string()
+        )),
+        false => Ok(CStr::from_ptr(c_str).to_str()?.to_string()),
+    }
+}
+
+unsafe fn get_ffi_database(db: *const FfiDatabase) -> Result<FfiDatabase> {
+    match db.is_null() {
+        true => Err(AppError::HostError(
+            "✘ Null database pointer passed to core!".to_string()
+        )),
+        false => Ok(*db),
+    }
+}
+
+/// # Safety
+///
+/// The `db` pointer must point to a valid `FfiDatabase` & all string pointers
+/// must point to valid, nul-terminated UTF-8 strings owned by the caller.
+#[no_mangle]
+pub unsafe extern "C" fn pbtc_maybe_initialize_btc_enclave(
+    db: *const FfiDatabase,
+    block_json: *const c_char,
+    fee: u64,
+    difficulty: u64,
+    network: *const c_char,
+    canon_to_tip_length: u64,
+) -> FfiResult {
+    run_ffi_function(|| maybe_initialize_btc_enclave(
+        get_ffi_database(db)?,
+        get_string_from_c_str(block_json)?,
+        fee,
+        difficulty,
+        get_string_from_c_str(network)?,
+        canon_to_tip_length,
+    ))
+}
+
+/// # Safety
+///
+/// The `db` pointer must point to a valid `FfiDatabase` & the `block_json`
+/// pointer to a valid, nul-terminated UTF-8 string owned by the caller.
+#[no_mangle]
+pub unsafe extern "C" fn pbtc_maybe_initialize_eth_enclave(
+    db: *const FfiDatabase,
+    block_json: *const c_char,
+    chain_id: u8,
+    gas_price: u64,
+    canon_to_tip_length: u64,
+) -> FfiResult {
+    run_ffi_function(|| maybe_initialize_eth_enclave(
+        get_ffi_database(db)?,
+        get_string_from_c_str(block_json)?,
+        chain_id,
+        gas_price,
+        canon_to_tip_length,
+    ))
+}
+
+/// # Safety
+///
+/// The `db` pointer must point to a valid `FfiDatabase` & the `block_json`
+/// pointer to a valid, nul-terminated UTF-8 string owned by the caller.
+#[no_mangle]
+pub unsafe extern "C" fn pbtc_submit_btc_block(
+    db: *const FfiDatabase,
+    block_json: *const c_char,
+    dry_run: bool,
+) -> FfiResult {
+    run_ffi_function(|| submit_btc_block_to_enclave(
+        get_ffi_database(db)?,
+        get_string_from_c_str(block_json)?,
+        dry_run,
+    ))
+}
+
+/// # Safety
+///
+/// The `db` pointer must point to a valid `FfiDatabase` & the `block_json`
+/// pointer to a valid, nul-terminated UTF-8 string owned by the caller.
+#[no_mangle]
+pub unsafe extern "C" fn pbtc_submit_eth_block(
+    db: *const FfiDatabase,
+    block_json: *const c_char,
+    dry_run: bool,
+) -> FfiResult {
+    run_ffi_function(|| submit_eth_block_to_enclave(
+        get_ffi_database(db)?,
+        get_string_from_c_str(block_json)?,
+        dry_run,
+    ))
+}
+
+/// # Safety
+///
+/// The `db` pointer must point to a valid `FfiDatabase` & the
+/// `submission_material_json` pointer to a valid, nul-terminated UTF-8
+/// string owned by the caller.
+#[no_mangle]
+pub unsafe extern "C" fn pbtc_submit_block(
+    db: *const FfiDatabase,
+    submission_material_json: *const c_char,
+) -> FfiResult {
+    run_ffi_function(|| {
+        let db = get_ffi_database(db)?;
+        let material = SubmissionMaterial::from_str_within_limit(
+            &db,
+            &get_string_from_c_str(submission_material_json)?,
+        )?;
+        submit_block(db, material)
+    })
+}
+
+/// # Safety
+///
+/// The `db` pointer must point to a valid `FfiDatabase`.
+#[no_mangle]
+pub unsafe extern "C" fn pbtc_get_enclave_state(
+    db: *const FfiDatabase,
+) -> FfiResult {
+    run_ffi_function(|| get_enclave_state(get_ffi_database(db)?))
+}
+
+/// # Safety
+///
+/// The `db` pointer must point to a valid `FfiDatabase`.
+#[no_mangle]
+pub unsafe extern "C" fn pbtc_get_latest_block_numbers(
+    db: *const FfiDatabase,
+) -> FfiResult {
+    run_ffi_function(|| get_latest_block_numbers(get_ffi_database(db)?))
+}
+
+/// # Safety
+///
+/// The `db` pointer must point to a valid `FfiDatabase`.
+#[no_mangle]
+pub unsafe extern "C" fn pbtc_debug_get_core_state(
+    db: *const FfiDatabase,
+) -> FfiResult {
+    run_ffi_function(|| debug_get_core_state(get_ffi_database(db)?))
+}
+
+/// # Safety
+///
+/// The `db` pointer must point to a valid `FfiDatabase`.
+#[no_mangle]
+pub unsafe extern "C" fn pbtc_debug_get_all_utxos(
+    db: *const FfiDatabase,
+) -> FfiResult {
+    run_ffi_function(|| debug_get_all_utxos(get_ffi_database(db)?))
+}
+
+/// # Safety
+///
+/// The `db` pointer must point to a valid `FfiDatabase`.
+#[no_mangle]
+pub unsafe extern "C" fn pbtc_debug_get_safe_addresses(
+    db: *const FfiDatabase,
+) -> FfiResult {
+    run_ffi_function(|| debug_get_safe_addresses(get_ffi_database(db)?))
+}
+
+/// # Safety
+///
+/// The `db` pointer must point to a valid `FfiDatabase`.
+#[no_mangle]
+pub unsafe extern "C" fn pbtc_debug_get_unexpected_db_keys(
+    db: *const FfiDatabase,
+) -> FfiResult {
+    run_ffi_function(|| debug_get_unexpected_db_keys(get_ffi_database(db)?))
+}
+
+/// # Safety
+///
+/// The `db` pointer must point to a valid `FfiDatabase` & the `key` pointer
+/// to a valid, nul-terminated UTF-8 string owned by the caller.
+#[no_mangle]
+pub unsafe extern "C" fn pbtc_debug_get_key_from_db(
+    db: *const FfiDatabase,
+    key: *const c_char,
+) -> FfiResult {
+    run_ffi_function(|| debug_get_key_from_db(
+        get_ffi_database(db)?,
+        get_string_from_c_str(key)?,
+    ))
+}
+
+/// # Safety
+///
+/// The `output` pointer must either be null or have been returned in an
+/// `FfiResult` by this core, & must not be used again after being freed.
+#[no_mangle]
+pub unsafe extern "C" fn pbtc_free_string(output: *mut c_char) {
+    if !output.is_null() {
+        drop(CString::from_raw(output));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::read_to_string;
+    use crate::ffi::ffi_test_utils::FfiTestContext;
+
+    const BTC_BLOCK_JSON_PATH: &str =
+        "src/btc/btc_test_utils/sequential_block_and_ids/\
+        1611090-btc-block-and-txs.json";
+    const ETH_BLOCK_JSON_PATH: &str =
+        "src/eth/eth_test_utils/sequential_block_and_receipts_jsons/\
+        eth_block_and_receipts_num_8065750.json";
+
+    fn get_c_string(path: &str) -> CString {
+        CString::new(read_to_string(path).unwrap()).unwrap()
+    }
+
+    unsafe fn get_output(result: FfiResult) -> (bool, String) {
+        let output = CStr::from_ptr(result.output)
+            .to_str()
+            .unwrap()
+            .to_string();
+        pbtc_free_string(result.output);
+        (result.is_error, output)
+    }
+
+    unsafe fn initialize_enclave_via_ffi(db: &FfiDatabase) {
+        let btc_block_json = get_c_string(BTC_BLOCK_JSON_PATH);
+        let eth_block_json = get_c_string(ETH_BLOCK_JSON_PATH);
+        let network = CString::new("Testnet").unwrap();
+        let (is_error, output) = get_output(pbtc_maybe_initialize_btc_enclave(
+            db,
+            btc_block_json.as_ptr(),
+            23,
+            1,
+            network.as_ptr(),
+            2,
+        ));
+        assert!(!is_error, "{}", output);
+        let (is_error, output) = get_output(pbtc_maybe_initialize_eth_enclave(
+            db,
+            eth_block_json.as_ptr(),
+            1,
+            20_000_000_000,
+            2,
+        ));
+        assert!(!is_error, "{}", output);
+    }
+
+    #[test]
+    fn should_initialize_enclave_and_get_its_state_via_ffi() {
+        let context = FfiTestContext::default();
+        let db = context.get_ffi_database();
+        unsafe {
+            initialize_enclave_via_ffi(&db);
+            assert!(context.num_items() > 0);
+            let (is_error, output) = get_output(pbtc_get_enclave_state(&db));
+            assert!(!is_error, "{}", output);
+            let (is_error, output) =
+                get_output(pbtc_get_latest_block_numbers(&db));
+            assert!(!is_error, "{}", output);
+            assert!(output.contains("1611090"));
+        }
+    }
+
+    #[test]
+    fn should_error_getting_enclave_state_if_not_initialized() {
+        let context = FfiTestContext::default();
+        let db = context.get_ffi_database();
+        let (is_error, _) = unsafe {
+            get_output(pbtc_get_enclave_state(&db))
+        };
+        assert!(is_error);
+    }
+
+    #[test]
+    fn should_error_on_null_database_pointer() {
+        let (is_error, output) = unsafe {
+            get_output(pbtc_get_latest_block_numbers(ptr::null()))
+        };
+        assert!(is_error);
+        assert!(output.contains("Null database pointer"));
+    }
+
+    #[test]
+    fn should_error_on_null_string_pointer() {
+        let context = FfiTestContext::default();
+        let db = context.get_ffi_database();
+        let (is_error, output) = unsafe {
+            get_output(pbtc_submit_btc_block(&db, ptr::null(), false))
+        };
+        assert!(is_error);
+        assert!(output.contains("Null string pointer"));
+        assert_eq!(context.num_items(), 0);
+    }
+
+    #[test]
+    fn should_error_on_invalid_utf8_string() {
+        let context = FfiTestContext::default();
+        let db = context.get_ffi_database();
+        let invalid_utf8 = CString::new(vec![0xc0, 0xff, 0xee]).unwrap();
+        let (is_error, _) = unsafe {
+            get_output(pbtc_submit_block(&db, invalid_utf8.as_ptr()))
+        };
+        assert!(is_error);
+        assert_eq!(context.num_items(), 0);
+    }
+
+    #[test]
+    fn should_error_on_invalid_submission_material() {
+        let context = FfiTestContext::default();
+        let db = context.get_ffi_database();
+        let material = CString::new("{\"chain\":\"EOS\",\"block\":{}}")
+            .unwrap();
+        let (is_error, output) = unsafe {
+            get_output(pbtc_submit_block(&db, material.as_ptr()))
+        };
+        assert!(is_error);
+        assert!(output.contains("Invalid submission material"));
+    }
+
+    #[test]
+    fn should_strip_nul_bytes_from_output() {
+        let result = to_ffi_result(Ok("c0\0ffee".to_string()));
+        let (is_error, output) = unsafe { get_output(result) };
+        assert!(!is_error);
+        assert_eq!(output, "c0ffee");
+    }
+
+    #[test]
+    fn should_return_panic_as_error() {
+        let result = run_ffi_function(|| panic!("Panic in FFI call!"));
+        let (is_error, output) = unsafe { get_output(result) };
+        assert!(is_error);
+        assert!(output.contains("Core panicked"));
+    }
+
+    #[test]
+    fn should_free_null_string() {
+        unsafe { pbtc_free_string(ptr::null_mut()) }
+    }
+}
@@ -0,0 +1,128 @@
+#![cfg(test)]
+use std::{
+    slice,
+    sync::Mutex,
+    os::raw::c_void,
+    collections::HashMap,
+};
+use crate::{
+    types::Bytes,
+    ffi::ffi_database::{
+        FfiStatus,
+        FfiDatabase,
+        FFI_SUCCESS,
+        FFI_NOT_FOUND,
+    },
+};
+
+// NOTE: Stands in for the host's side of the FFI, backing the callbacks with
+// a `HashMap` & allocating returned values exactly as a C host would have to.
+#[derive(Default)]
+pub struct FfiTestContext {
+    pub items: Mutex<HashMap<Bytes, Bytes>>,
+}
+
+impl FfiTestContext {
+    pub fn get_ffi_database(&self) -> FfiDatabase {
+        FfiDatabase {
+            context: self as *const FfiTestContext as *mut c_void,
+            start_transaction,
+            end_transaction,
+            delete,
+            put,
+            get,
+            free_value,
+        }
+    }
+
+    pub fn num_items(&self) -> usize {
+        self.items.lock().unwrap().len()
+    }
+}
+
+unsafe fn get_context<'a>(context: *mut c_void) -> &'a FfiTestContext {
+    &*(context as *const FfiTestContext)
+}
+
+unsafe fn get_bytes(pointer: *const u8, len: usize) -> Bytes {
+    slice::from_raw_parts(pointer, len).to_vec()
+}
+
+extern "C" fn start_transaction(_context: *mut c_void) -> FfiStatus {
+    FFI_SUCCESS
+}
+
+extern "C" fn end_transaction(_context: *mut c_void) -> FfiStatus {
+    FFI_SUCCESS
+}
+
+extern "C" fn delete(
+    context: *mut c_void,
+    key: *const u8,
+    key_len: usize,
+) -> FfiStatus {
+    unsafe {
+        get_context(context)
+            .items
+            .lock()
+            .unwrap()
+            .remove(&get_bytes(key, key_len));
+    }
+    FFI_SUCCESS
+}
+
+extern "C" fn put(
+    context: *mut c_void,
+    key: *const u8,
+    key_len: usize,
+    value: *const u8,
+    value_len: usize,
+    _is_private: bool,
+) -> FfiStatus {
+    unsafe {
+        get_context(context)
+            .items
+            .lock()
+            .unwrap()
+            .insert(get_bytes(key, key_len), get_bytes(value, value_len));
+    }
+    FFI_SUCCESS
+}
+
+extern "C" fn get(
+    context: *mut c_void,
+    key: *const u8,
+    key_len: usize,
+    _is_private: bool,
+    value: *mut *mut u8,
+    value_len: *mut usize,
+) -> FfiStatus {
+    unsafe {
+        match get_context(context)
+            .items
+            .lock()
+            .unwrap()
+            .get(&get_bytes(key, key_len))
+        {
+            None => FFI_NOT_FOUND,
+            Some(item) => {
+                let boxed_item = item.clone().into_boxed_slice();
+                *value_len = boxed_item.len();
+                *value = Box::into_raw(boxed_item) as *mut u8;
+                FFI_SUCCESS
+            }
+        }
+    }
+}
+
+extern "C" fn free_value(
+    _context: *mut c_void,
+    value: *mut u8,
+    value_len: usize,
+) {
+    unsafe {
+        drop(Box::from_raw(
+            slice::from_raw_parts_mut(value, value_len) as *mut [u8]
+        ));
+    }
+}
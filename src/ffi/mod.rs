@@ -0,0 +1,4 @@
+#![cfg(feature="ffi")]
+pub mod ffi_database;
+pub mod ffi_functions;
+pub mod ffi_test_utils;
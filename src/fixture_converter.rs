@@ -0,0 +1,366 @@
+#![cfg(any(test, feature="fixture-converter"))]
+// NOTE: Converts recorded `bitcoind` & `geth` JSON-RPC responses into the
+// sample block fixtures used throughout the tests, validating them on the way
+// so that a malformed recording fails here rather than in some later test.
+use serde_json::Value as JsonValue;
+use crate::{
+    types::Result,
+    errors::AppError,
+    db_codec::DbSerializationFormat,
+    btc::{
+        btc_utils::{
+            get_btc_block_in_db_format,
+            serialize_btc_block_in_db_format,
+        },
+        parse_btc_block::{
+            parse_btc_block_string_to_json,
+            parse_btc_block_and_tx_json_to_struct,
+        },
+        parse_bitcoind_block::{
+            convert_bitcoind_submission_json_to_btc_block_and_txs_json,
+        },
+        btc_types::{
+            BtcBlockAndId,
+            BtcBlockAndTxsJson,
+            BitcoindSubmissionJson,
+            DepositAddressJsonList,
+        },
+    },
+    eth::{
+        validate_receipts::receipts_root_is_correct,
+        parse_eth_block_and_receipts::parse_eth_block_and_receipts_json,
+        parse_eth_rpc_block::{
+            convert_eth_rpc_json_to_eth_block_and_receipts_json,
+        },
+        eth_types::{
+            EthRpcSubmissionJson,
+            EthBlockAndReceiptsJson,
+        },
+    },
+};
+
+#[derive(Clone, Debug, Deserialize)]
+struct RpcResponseJson {
+    #[serde(default)]
+    result: JsonValue,
+    #[serde(default)]
+    error: JsonValue,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BtcDbFormatFixtureJson {
+    pub id: String,
+    pub height: u64,
+    pub serialized_block_in_db_format: String,
+}
+
+// NOTE: Accepts either a full JSON-RPC envelope or a bare `result`, since
+// some recording tools strip the envelope.
+fn get_result_from_rpc_response(rpc_response: &str) -> Result<JsonValue> {
+    let json: JsonValue = serde_json::from_str(rpc_response)?;
+    if json.get("result").is_none() && json.get("error").is_none() {
+        return Ok(json)
+    };
+    let response: RpcResponseJson = serde_json::from_value(json)?;
+    match (response.error.is_null(), response.result.is_null()) {
+        (false, _) => Err(AppError::InvalidSubmission(
+            format!("✘ RPC response contains an error: {}!", response.error)
+        )),
+        (true, true) => Err(AppError::InvalidSubmission(
+            "✘ RPC response contains no result!".to_string()
+        )),
+        (true, false) => Ok(response.result),
+    }
+}
+
+fn check_btc_block_is_self_consistent(
+    btc_block_and_id: &BtcBlockAndId,
+) -> Result<()> {
+    match btc_block_and_id.block.check_merkle_root() {
+        true => Ok(()),
+        false => Err(AppError::InvalidBlock(
+            format!(
+                "✘ Merkle root of BTC block {} is invalid!",
+                btc_block_and_id.id,
+            )
+        )),
+    }
+}
+
+fn check_eth_fixture_is_self_consistent(
+    json: &EthBlockAndReceiptsJson,
+) -> Result<()> {
+    let block_and_receipts = parse_eth_block_and_receipts_json(json.clone())?;
+    match receipts_root_is_correct(
+        &block_and_receipts.block,
+        &block_and_receipts.receipts,
+    )? {
+        true => Ok(()),
+        false => Err(AppError::InvalidBlock(
+            format!(
+                "✘ Receipts root of ETH block {} is invalid!",
+                block_and_receipts.block.hash,
+            )
+        )),
+    }
+}
+
+pub fn convert_bitcoind_rpc_response_to_btc_fixture(
+    getblock_rpc_response: &str,
+    deposit_address_list: DepositAddressJsonList,
+) -> Result<String> {
+    info!("✔ Converting `bitcoind` RPC response to BTC block fixture...");
+    get_result_from_rpc_response(getblock_rpc_response)
+        .and_then(|block| {
+            let submission_json = serde_json::json!({
+                "block": block,
+                "deposit_address_list": deposit_address_list,
+            });
+            Ok(serde_json::from_value::<BitcoindSubmissionJson>(
+                submission_json
+            )?)
+        })
+        .and_then(convert_bitcoind_submission_json_to_btc_block_and_txs_json)
+        .and_then(|json: BtcBlockAndTxsJson| {
+            parse_btc_block_and_tx_json_to_struct(json.clone())
+                .and_then(|block| check_btc_block_is_self_consistent(&block))
+                .and_then(|_| Ok(serde_json::to_string(&json)?))
+        })
+}
+
+pub fn convert_btc_fixture_to_db_format_fixture(
+    btc_fixture: &str,
+) -> Result<String> {
+    info!("✔ Converting BTC block fixture to db format fixture...");
+    parse_btc_block_string_to_json(btc_fixture)
+        .and_then(parse_btc_block_and_tx_json_to_struct)
+        .and_then(|block| get_btc_block_in_db_format(block, vec![], vec![]))
+        .and_then(|block_in_db_format| {
+            let (id, bytes) = serialize_btc_block_in_db_format(
+                &block_in_db_format,
+                DbSerializationFormat::Json,
+            )?;
+            Ok(
+                serde_json::to_string(
+                    &BtcDbFormatFixtureJson {
+                        id: hex::encode(id),
+                        height: block_in_db_format.height,
+                        serialized_block_in_db_format: hex::encode(bytes),
+                    }
+                )?
+            )
+        })
+}
+
+pub fn convert_geth_rpc_responses_to_eth_fixture(
+    get_block_rpc_response: &str,
+    get_receipt_rpc_responses: &[String],
+) -> Result<String> {
+    info!("✔ Converting `geth` RPC responses to ETH block fixture...");
+    let receipts = get_receipt_rpc_responses
+        .iter()
+        .map(|response| get_result_from_rpc_response(response))
+        .collect::<Result<Vec<JsonValue>>>()?;
+    get_result_from_rpc_response(get_block_rpc_response)
+        .and_then(|block| {
+            let submission_json = serde_json::json!({
+                "block": block,
+                "receipts": receipts,
+            });
+            Ok(serde_json::from_value::<EthRpcSubmissionJson>(
+                submission_json
+            )?)
+        })
+        .and_then(convert_eth_rpc_json_to_eth_block_and_receipts_json)
+        .and_then(|json| {
+            check_eth_fixture_is_self_consistent(&json)
+                .and_then(|_| Ok(serde_json::to_string(&json)?))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::read_to_string;
+    use ethereum_types::U256;
+    use crate::{
+        btc::{
+            btc_utils::deserialize_btc_block_in_db_format,
+            btc_test_utils::{
+                get_sample_btc_block_json,
+                get_sample_btc_block_and_id,
+                get_sample_btc_block_json_string,
+            },
+        },
+        eth::parse_eth_block_and_receipts::parse_eth_block_and_receipts,
+    };
+
+    const SAMPLE_ETH_BLOCK_AND_RECEIPTS_PATH: &str =
+        "src/eth/eth_test_utils/sequential_block_and_receipts_jsons/\
+        eth_block_and_receipts_num_8065750.json";
+
+    fn wrap_in_rpc_envelope(result: JsonValue) -> String {
+        serde_json::json!({ "jsonrpc": "2.0", "id": 1, "result": result })
+            .to_string()
+    }
+
+    fn get_sample_getblock_rpc_response() -> String {
+        let json = get_sample_btc_block_json().unwrap();
+        wrap_in_rpc_envelope(serde_json::json!({
+            "hash": json.block.id,
+            "height": json.block.height,
+            "version": json.block.version,
+            "merkleroot": json.block.merkle_root,
+            "time": json.block.timestamp,
+            "nonce": json.block.nonce,
+            "bits": format!("{:08x}", json.block.bits),
+            "previousblockhash": json.block.previousblockhash,
+            "tx": json
+                .transactions
+                .iter()
+                .map(|hex| serde_json::json!({ "hex": hex }))
+                .collect::<Vec<JsonValue>>(),
+        }))
+    }
+
+    fn convert_to_hex_quantity(json: &mut JsonValue, field: &str) {
+        let quantity = match &json[field] {
+            JsonValue::Bool(boolean) => U256::from(*boolean as u64),
+            JsonValue::Number(number) => U256::from(number.as_u64().unwrap()),
+            JsonValue::String(string) => U256::from_dec_str(string).unwrap(),
+            _ => panic!("✘ Cannot convert field {} to hex quantity!", field),
+        };
+        json[field] = JsonValue::String(format!("0x{:x}", quantity));
+    }
+
+    // NOTE: Inverts the conversion of a sample fixture back into the shapes
+    // `geth` returns, where every quantity is a hex string.
+    fn get_sample_geth_rpc_responses() -> (String, Vec<String>) {
+        let mut json: JsonValue = serde_json::from_str(
+            &read_to_string(SAMPLE_ETH_BLOCK_AND_RECEIPTS_PATH).unwrap()
+        ).unwrap();
+        let mut block = json["block"].take();
+        vec![
+            "difficulty",
+            "gasLimit",
+            "gasUsed",
+            "number",
+            "size",
+            "timestamp",
+            "totalDifficulty",
+        ]
+            .iter()
+            .for_each(|field| convert_to_hex_quantity(&mut block, field));
+        let receipts = json["receipts"]
+            .as_array_mut()
+            .unwrap()
+            .iter_mut()
+            .map(|receipt| {
+                vec![
+                    "status",
+                    "gasUsed",
+                    "blockNumber",
+                    "transactionIndex",
+                    "cumulativeGasUsed",
+                ]
+                    .iter()
+                    .for_each(|field| convert_to_hex_quantity(receipt, field));
+                wrap_in_rpc_envelope(receipt.take())
+            })
+            .collect();
+        (wrap_in_rpc_envelope(block), receipts)
+    }
+
+    #[test]
+    fn should_get_result_from_rpc_response_with_or_without_envelope() {
+        let result = serde_json::json!({ "hash": "0xc0ffee" });
+        let result_1 = get_result_from_rpc_response(
+            &wrap_in_rpc_envelope(result.clone())
+        ).unwrap();
+        let result_2 = get_result_from_rpc_response(&result.to_string())
+            .unwrap();
+        assert_eq!(result_1, result);
+        assert_eq!(result_2, result);
+    }
+
+    #[test]
+    fn should_error_on_rpc_response_containing_an_error() {
+        let response = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "result": null,
+            "error": { "code": -5, "message": "Block not found" },
+        }).to_string();
+        assert!(get_result_from_rpc_response(&response).is_err());
+    }
+
+    #[test]
+    fn should_convert_bitcoind_rpc_response_to_btc_fixture() {
+        let fixture = convert_bitcoind_rpc_response_to_btc_fixture(
+            &get_sample_getblock_rpc_response(),
+            get_sample_btc_block_json().unwrap().deposit_address_list,
+        ).unwrap();
+        let result = parse_btc_block_string_to_json(&fixture)
+            .and_then(parse_btc_block_and_tx_json_to_struct)
+            .unwrap();
+        assert_eq!(result, get_sample_btc_block_and_id().unwrap());
+    }
+
+    #[test]
+    fn should_not_convert_bitcoind_rpc_response_with_missing_tx() {
+        let mut json: JsonValue = serde_json::from_str(
+            &get_sample_getblock_rpc_response()
+        ).unwrap();
+        json["result"]["tx"].as_array_mut().unwrap().pop();
+        let result = convert_bitcoind_rpc_response_to_btc_fixture(
+            &json.to_string(),
+            vec![],
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_convert_btc_fixture_to_db_format_fixture() {
+        let fixture = convert_btc_fixture_to_db_format_fixture(
+            &get_sample_btc_block_json_string()
+        ).unwrap();
+        let json: BtcDbFormatFixtureJson = serde_json::from_str(&fixture)
+            .unwrap();
+        let result = deserialize_btc_block_in_db_format(
+            &hex::decode(&json.serialized_block_in_db_format).unwrap()
+        ).unwrap();
+        let expected_block = get_sample_btc_block_and_id().unwrap();
+        assert_eq!(json.height, expected_block.height);
+        assert_eq!(result.id, expected_block.id);
+        assert_eq!(result.block, expected_block.block);
+    }
+
+    #[test]
+    fn should_convert_geth_rpc_responses_to_eth_fixture() {
+        let (block_response, receipt_responses) =
+            get_sample_geth_rpc_responses();
+        let fixture = convert_geth_rpc_responses_to_eth_fixture(
+            &block_response,
+            &receipt_responses,
+        ).unwrap();
+        let result = parse_eth_block_and_receipts(&fixture).unwrap();
+        let expected_result = parse_eth_block_and_receipts(
+            &read_to_string(SAMPLE_ETH_BLOCK_AND_RECEIPTS_PATH).unwrap()
+        ).unwrap();
+        assert_eq!(result.block.hash, expected_result.block.hash);
+        assert_eq!(result.block.number, expected_result.block.number);
+        assert_eq!(result.receipts, expected_result.receipts);
+    }
+
+    #[test]
+    fn should_not_convert_geth_rpc_responses_with_missing_receipt() {
+        let (block_response, mut receipt_responses) =
+            get_sample_geth_rpc_responses();
+        receipt_responses.pop();
+        let result = convert_geth_rpc_responses_to_eth_fixture(
+            &block_response,
+            &receipt_responses,
+        );
+        assert!(result.is_err());
+    }
+}
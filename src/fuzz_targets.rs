@@ -0,0 +1,265 @@
+#![cfg(any(test, feature="fuzzing"))]
+// NOTE: Entrypoints for `cargo fuzz`, one per external parser. Each takes raw
+// bytes & must return an error, never panic, whatever those bytes are, eg:
+// `fuzz_target!(|data: &[u8]| { let _ = fuzz_parse_btc_submission(data); });`
+use std::str::from_utf8;
+use crate::{
+    types::Result,
+    errors::AppError,
+    utxo_manager::utxo_utils::deserialize_utxo_and_value,
+    btc::{
+        btc_utils::{
+            deserialize_minting_params,
+            deserialize_btc_block_in_db_format,
+            deserialize_btc_block_header_in_db_format,
+        },
+        parse_btc_block::{
+            parse_btc_block_string_to_json,
+            parse_btc_block_and_tx_json_to_struct,
+        },
+        parse_bitcoind_block::{
+            parse_bitcoind_submission_string_to_json,
+            convert_bitcoind_submission_json_to_btc_block_and_txs_json,
+        },
+    },
+    eth::{
+        eth_types::EthBlockHeaderInDbFormat,
+        eth_json_codec::decode_eth_block_and_receipts_from_json_bytes,
+        parse_eth_rpc_block::{
+            parse_eth_rpc_submission_string_to_json,
+            convert_eth_rpc_json_to_eth_block_and_receipts_json,
+        },
+        parse_eth_block_and_receipts::{
+            parse_eth_receipt_proofs_json,
+            parse_eth_block_and_receipts_json,
+            parse_eth_block_and_receipts_json_string,
+        },
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DbBlobKind {
+    BtcBlockInDbFormat = 0,
+    BtcBlockHeaderInDbFormat = 1,
+    BtcUtxoAndValue = 2,
+    MintingParams = 3,
+    EthBlockAndReceipts = 4,
+    EthBlockHeaderInDbFormat = 5,
+}
+
+impl DbBlobKind {
+    const NUM_KINDS: u8 = 6;
+
+    pub fn from_byte(byte: u8) -> Self {
+        match byte % Self::NUM_KINDS {
+            0 => DbBlobKind::BtcBlockInDbFormat,
+            1 => DbBlobKind::BtcBlockHeaderInDbFormat,
+            2 => DbBlobKind::BtcUtxoAndValue,
+            3 => DbBlobKind::MintingParams,
+            4 => DbBlobKind::EthBlockAndReceipts,
+            _ => DbBlobKind::EthBlockHeaderInDbFormat,
+        }
+    }
+}
+
+pub fn fuzz_parse_btc_submission(bytes: &[u8]) -> Result<()> {
+    parse_btc_block_string_to_json(from_utf8(bytes)?)
+        .and_then(parse_btc_block_and_tx_json_to_struct)
+        .map(|_| ())
+}
+
+pub fn fuzz_parse_bitcoind_submission(bytes: &[u8]) -> Result<()> {
+    parse_bitcoind_submission_string_to_json(from_utf8(bytes)?)
+        .and_then(convert_bitcoind_submission_json_to_btc_block_and_txs_json)
+        .and_then(parse_btc_block_and_tx_json_to_struct)
+        .map(|_| ())
+}
+
+pub fn fuzz_parse_eth_submission(bytes: &[u8]) -> Result<()> {
+    let json = parse_eth_block_and_receipts_json_string(
+        &from_utf8(bytes)?.to_string()
+    )?;
+    if let Some(proofs) = &json.receiptProofs {
+        parse_eth_receipt_proofs_json(proofs)?;
+    };
+    parse_eth_block_and_receipts_json(json).map(|_| ())
+}
+
+pub fn fuzz_parse_eth_rpc_submission(bytes: &[u8]) -> Result<()> {
+    parse_eth_rpc_submission_string_to_json(from_utf8(bytes)?)
+        .and_then(convert_eth_rpc_json_to_eth_block_and_receipts_json)
+        .and_then(parse_eth_block_and_receipts_json)
+        .map(|_| ())
+}
+
+// NOTE: The first byte selects which deserializer the rest are fed to, so a
+// single fuzz target covers every blob format the core reads from its db.
+pub fn fuzz_deserialize_db_blob(bytes: &[u8]) -> Result<()> {
+    match bytes.split_first() {
+        None => Err(AppError::InvalidSubmission(
+            "✘ Cannot deserialize db blob from no bytes!".to_string()
+        )),
+        Some((kind_byte, blob)) => {
+            let blob = blob.to_vec();
+            match DbBlobKind::from_byte(*kind_byte) {
+                DbBlobKind::BtcBlockInDbFormat =>
+                    deserialize_btc_block_in_db_format(&blob).map(|_| ()),
+                DbBlobKind::BtcBlockHeaderInDbFormat =>
+                    deserialize_btc_block_header_in_db_format(&blob)
+                        .map(|_| ()),
+                DbBlobKind::BtcUtxoAndValue =>
+                    deserialize_utxo_and_value(&blob).map(|_| ()),
+                DbBlobKind::MintingParams =>
+                    deserialize_minting_params(blob).map(|_| ()),
+                DbBlobKind::EthBlockAndReceipts =>
+                    decode_eth_block_and_receipts_from_json_bytes(blob)
+                        .map(|_| ()),
+                DbBlobKind::EthBlockHeaderInDbFormat =>
+                    EthBlockHeaderInDbFormat::from_bytes(&blob).map(|_| ()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{
+        prelude::*,
+        collection::vec,
+    };
+    use serde_json::Value as JsonValue;
+    use crate::{
+        db_codec::DbSerializationFormat,
+        utxo_manager::utxo_utils::serialize_btc_utxo_and_value,
+        eth::{
+            eth_json_codec::encode_eth_block_and_receipts_as_json_bytes,
+            eth_test_utils::{
+                get_sample_eth_block_and_receipts_n,
+                get_sample_eth_block_and_receipts_string,
+            },
+        },
+        btc::{
+            btc_utils::serialize_btc_block_in_db_format,
+            btc_test_utils::{
+                get_sample_btc_block_json_string,
+                get_sample_btc_block_in_db_format,
+                get_sample_op_return_utxo_and_value,
+            },
+        },
+    };
+
+    const MAX_NUM_FUZZ_BYTES: usize = 1024;
+
+    fn get_sample_eth_submission_json() -> JsonValue {
+        serde_json::from_str(
+            &get_sample_eth_block_and_receipts_string(0).unwrap()
+        ).unwrap()
+    }
+
+    fn get_sample_btc_submission_json() -> JsonValue {
+        serde_json::from_str(&get_sample_btc_block_json_string()).unwrap()
+    }
+
+    fn prefix_with_kind(kind: DbBlobKind, blob: Vec<u8>) -> Vec<u8> {
+        [vec![kind as u8], blob].concat()
+    }
+
+    #[test]
+    fn should_parse_sample_submissions() {
+        let btc_json = get_sample_btc_block_json_string();
+        let eth_json = get_sample_eth_block_and_receipts_string(0).unwrap();
+        assert!(fuzz_parse_btc_submission(btc_json.as_bytes()).is_ok());
+        assert!(fuzz_parse_eth_submission(eth_json.as_bytes()).is_ok());
+    }
+
+    #[test]
+    fn should_deserialize_sample_db_blobs() {
+        let (_, btc_block_bytes) = serialize_btc_block_in_db_format(
+            &get_sample_btc_block_in_db_format().unwrap(),
+            DbSerializationFormat::Json,
+        ).unwrap();
+        let utxo_bytes = serialize_btc_utxo_and_value(
+            &get_sample_op_return_utxo_and_value(),
+            DbSerializationFormat::Json,
+        ).unwrap();
+        let eth_block_bytes = encode_eth_block_and_receipts_as_json_bytes(
+            &get_sample_eth_block_and_receipts_n(0).unwrap(),
+            DbSerializationFormat::Json,
+        ).unwrap();
+        vec![
+            prefix_with_kind(DbBlobKind::BtcBlockInDbFormat, btc_block_bytes),
+            prefix_with_kind(DbBlobKind::BtcUtxoAndValue, utxo_bytes),
+            prefix_with_kind(DbBlobKind::EthBlockAndReceipts, eth_block_bytes),
+        ]
+            .iter()
+            .for_each(|bytes| assert!(fuzz_deserialize_db_blob(bytes).is_ok()));
+    }
+
+    #[test]
+    fn should_error_on_eth_block_with_wrong_length_logs_bloom() {
+        let mut json = get_sample_eth_submission_json();
+        json["block"]["logsBloom"] = JsonValue::from("0xc0ffee");
+        let result = fuzz_parse_eth_submission(json.to_string().as_bytes());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_error_on_eth_receipt_with_wrong_length_address() {
+        let mut json = get_sample_eth_submission_json();
+        json["receipts"][0]["from"] = JsonValue::from("0xc0ffee");
+        let result = fuzz_parse_eth_submission(json.to_string().as_bytes());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_error_on_eth_receipt_with_out_of_range_type() {
+        let mut json = get_sample_eth_submission_json();
+        json["receipts"][0]["type"] = JsonValue::from("0x1ff");
+        let result = fuzz_parse_eth_submission(json.to_string().as_bytes());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_error_on_eth_seal_field_wider_than_u256() {
+        let mut json = get_sample_eth_submission_json();
+        json["block"]["sealFields"][1] =
+            JsonValue::from(format!("0x01{}", "00".repeat(32)));
+        let result = fuzz_parse_eth_submission(json.to_string().as_bytes());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_error_on_btc_deposit_info_with_wrong_length_eth_address() {
+        let mut json = get_sample_btc_submission_json();
+        json["deposit_address_list"][0]["eth_address"] =
+            JsonValue::from("0xc0ffee");
+        let result = fuzz_parse_btc_submission(json.to_string().as_bytes());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_error_on_empty_db_blob() {
+        assert!(fuzz_deserialize_db_blob(&[]).is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn parsers_should_not_panic_on_arbitrary_bytes(
+            bytes in vec(any::<u8>(), 0..MAX_NUM_FUZZ_BYTES)
+        ) {
+            let _ = fuzz_parse_btc_submission(&bytes);
+            let _ = fuzz_parse_bitcoind_submission(&bytes);
+            let _ = fuzz_parse_eth_submission(&bytes);
+            let _ = fuzz_parse_eth_rpc_submission(&bytes);
+        }
+
+        #[test]
+        fn db_deserializers_should_not_panic_on_arbitrary_bytes(
+            kind_byte in 0..DbBlobKind::NUM_KINDS,
+            blob in vec(any::<u8>(), 0..MAX_NUM_FUZZ_BYTES)
+        ) {
+            let _ = fuzz_deserialize_db_blob(&[vec![kind_byte], blob].concat());
+        }
+    }
+}
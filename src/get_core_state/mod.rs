@@ -0,0 +1,164 @@
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    utils::convert_u256_to_u64,
+    eth::eth_database_utils::{
+        get_eth_tx_type_from_db,
+        get_eth_chain_id_from_db,
+        get_eth_gas_price_from_db,
+        get_eth_relay_nonce_from_db,
+        get_eth_account_nonce_from_db,
+        get_eth_mint_gas_limit_from_db,
+        get_eth_cancel_gas_limit_from_db,
+        get_eth_max_fee_per_gas_from_db,
+        get_eth_canon_to_tip_length_from_db,
+        get_special_eth_block_header_from_db,
+        get_eth_max_priority_fee_per_gas_from_db,
+    },
+    btc::btc_database_utils::{
+        get_btc_fee_from_db,
+        get_btc_network_from_db,
+        get_btc_difficulty_from_db,
+        get_btc_account_nonce_from_db,
+        get_btc_canon_to_tip_length_from_db,
+        get_special_btc_block_header_from_db,
+        get_btc_peg_in_fee_basis_points_from_db,
+        get_btc_peg_out_fee_basis_points_from_db,
+    },
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockPointerState {
+    pub hash: String,
+    pub height: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BtcCoreState {
+    pub btc_network: String,
+    pub btc_difficulty: u64,
+    pub btc_sats_per_byte: u64,
+    pub btc_account_nonce: u64,
+    pub btc_canon_to_tip_length: u64,
+    pub btc_peg_in_fee_basis_points: u64,
+    pub btc_peg_out_fee_basis_points: u64,
+    pub btc_tail_block: BlockPointerState,
+    pub btc_canon_block: BlockPointerState,
+    pub btc_anchor_block: BlockPointerState,
+    pub btc_latest_block: BlockPointerState,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EthCoreState {
+    pub eth_chain_id: u8,
+    pub eth_tx_type: String,
+    pub eth_gas_price: u64,
+    pub eth_relay_nonce: u64,
+    pub eth_account_nonce: u64,
+    pub eth_mint_gas_limit: u64,
+    pub eth_cancel_gas_limit: u64,
+    pub eth_canon_to_tip_length: u64,
+    pub eth_max_fee_per_gas: Option<u64>,
+    pub eth_max_priority_fee_per_gas: Option<u64>,
+    pub eth_tail_block: BlockPointerState,
+    pub eth_canon_block: BlockPointerState,
+    pub eth_anchor_block: BlockPointerState,
+    pub eth_latest_block: BlockPointerState,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CoreState {
+    pub btc: BtcCoreState,
+    pub eth: EthCoreState,
+}
+
+fn get_btc_block_pointer_state<D>(
+    db: &D,
+    block_type: &str,
+) -> Result<BlockPointerState>
+    where D: DatabaseInterface
+{
+    get_special_btc_block_header_from_db(db, block_type)
+        .map(|block_header|
+            BlockPointerState {
+                hash: block_header.id.to_string(),
+                height: block_header.height,
+            }
+        )
+}
+
+fn get_eth_block_pointer_state<D>(
+    db: &D,
+    block_type: &str,
+) -> Result<BlockPointerState>
+    where D: DatabaseInterface
+{
+    get_special_eth_block_header_from_db(db, block_type)
+        .and_then(|block_header|
+            Ok(
+                BlockPointerState {
+                    hash: format!("0x{}", hex::encode(block_header.hash)),
+                    height: convert_u256_to_u64(&block_header.number)?,
+                }
+            )
+        )
+}
+
+pub fn get_btc_core_state<D>(db: &D) -> Result<BtcCoreState>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting BTC core state...");
+    Ok(
+        BtcCoreState {
+            btc_network: get_btc_network_from_db(db)?.to_string(),
+            btc_difficulty: get_btc_difficulty_from_db(db)?,
+            btc_sats_per_byte: get_btc_fee_from_db(db)?,
+            btc_account_nonce: get_btc_account_nonce_from_db(db)?,
+            btc_canon_to_tip_length: get_btc_canon_to_tip_length_from_db(db)?,
+            btc_peg_in_fee_basis_points:
+                get_btc_peg_in_fee_basis_points_from_db(db)?,
+            btc_peg_out_fee_basis_points:
+                get_btc_peg_out_fee_basis_points_from_db(db)?,
+            btc_tail_block: get_btc_block_pointer_state(db, "tail")?,
+            btc_canon_block: get_btc_block_pointer_state(db, "canon")?,
+            btc_anchor_block: get_btc_block_pointer_state(db, "anchor")?,
+            btc_latest_block: get_btc_block_pointer_state(db, "latest")?,
+        }
+    )
+}
+
+pub fn get_eth_core_state<D>(db: &D) -> Result<EthCoreState>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting ETH core state...");
+    Ok(
+        EthCoreState {
+            eth_chain_id: get_eth_chain_id_from_db(db)?,
+            eth_tx_type: get_eth_tx_type_from_db(db)?.to_string(),
+            eth_gas_price: get_eth_gas_price_from_db(db)?,
+            eth_relay_nonce: get_eth_relay_nonce_from_db(db)?,
+            eth_account_nonce: get_eth_account_nonce_from_db(db)?,
+            eth_mint_gas_limit: get_eth_mint_gas_limit_from_db(db)?,
+            eth_cancel_gas_limit: get_eth_cancel_gas_limit_from_db(db)?,
+            eth_canon_to_tip_length: get_eth_canon_to_tip_length_from_db(db)?,
+            eth_max_fee_per_gas: get_eth_max_fee_per_gas_from_db(db).ok(),
+            eth_max_priority_fee_per_gas:
+                get_eth_max_priority_fee_per_gas_from_db(db).ok(),
+            eth_tail_block: get_eth_block_pointer_state(db, "tail")?,
+            eth_canon_block: get_eth_block_pointer_state(db, "canon")?,
+            eth_anchor_block: get_eth_block_pointer_state(db, "anchor")?,
+            eth_latest_block: get_eth_block_pointer_state(db, "latest")?,
+        }
+    )
+}
+
+pub fn get_core_state<D>(db: &D) -> Result<CoreState>
+    where D: DatabaseInterface
+{
+    Ok(
+        CoreState {
+            btc: get_btc_core_state(db)?,
+            eth: get_eth_core_state(db)?,
+        }
+    )
+}
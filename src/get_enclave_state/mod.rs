@@ -1,6 +1,7 @@
 use crate::{
     types::Result,
     constants::DEBUG_MODE,
+    database_utils::core_is_paused,
     traits::DatabaseInterface,
     utxo_manager::utxo_database_utils::{
         get_utxo_nonce_from_db,
@@ -14,14 +15,14 @@ use crate::{
         },
         eth_database_utils::{
             get_eth_gas_price_from_db,
-            get_eth_tail_block_from_db,
-            get_eth_canon_block_from_db,
-            get_eth_latest_block_from_db,
-            get_eth_anchor_block_from_db,
             get_eth_account_nonce_from_db,
             get_public_eth_address_from_db,
             get_eth_canon_to_tip_length_from_db,
+            get_special_eth_block_header_from_db,
+            get_eth_tail_pruning_distance_from_db,
+            get_eth_host_token_decimals_from_db,
             get_eth_smart_contract_address_from_db,
+            get_eth_queued_redeem_params_from_db,
         },
     },
     btc::{
@@ -33,13 +34,16 @@ use crate::{
             get_btc_fee_from_db,
             get_btc_network_from_db,
             get_btc_address_from_db,
-            get_btc_tail_block_from_db,
             get_btc_difficulty_from_db,
             get_btc_private_key_from_db,
-            get_btc_canon_block_from_db,
-            get_btc_latest_block_from_db,
-            get_btc_anchor_block_from_db,
             get_btc_canon_to_tip_length_from_db,
+            get_btc_accrued_peg_in_fees_from_db,
+            get_special_btc_block_header_from_db,
+            get_btc_peg_in_fee_basis_points_from_db,
+            get_btc_queued_minting_params_from_db,
+            get_btc_accrued_peg_out_fees_from_db,
+            get_btc_peg_out_fee_basis_points_from_db,
+            get_btc_mint_gas_recapture_rate_from_db,
         },
     },
     check_enclave_is_initialized::check_enclave_is_initialized,
@@ -48,6 +52,7 @@ use crate::{
 #[derive(Serialize, Deserialize)]
 pub struct EnclaveState {
     debug_mode: bool,
+    core_is_paused: bool,
     eth_gas_price: u64,
     btc_difficulty: u64,
     btc_network: String,
@@ -79,9 +84,18 @@ pub struct EnclaveState {
     smart_contract_address: String,
     btc_latest_block_number: u64,
     eth_canon_to_tip_length: u64,
+    eth_tail_pruning_distance: u64,
+    eth_host_token_decimals: u32,
     btc_anchor_block_number: u64,
     btc_canon_to_tip_length: u64,
     eth_latest_block_number: usize,
+    btc_accrued_peg_in_fees: u64,
+    btc_peg_in_fee_basis_points: u64,
+    btc_accrued_peg_out_fees: u64,
+    btc_peg_out_fee_basis_points: u64,
+    btc_mint_gas_recapture_rate: u64,
+    eth_num_queued_redeem_params: usize,
+    btc_num_queued_minting_params: usize,
 }
 
 pub fn get_enclave_state<D>(
@@ -92,14 +106,22 @@ pub fn get_enclave_state<D>(
     info!("✔ Getting enclave state...");
     check_enclave_is_initialized(&db)
         .and_then(|_| {
-            let eth_tail_block = get_eth_tail_block_from_db(&db)?;
-            let btc_tail_block = get_btc_tail_block_from_db(&db)?;
-            let eth_canon_block = get_eth_canon_block_from_db(&db)?;
-            let btc_canon_block = get_btc_canon_block_from_db(&db)?;
-            let eth_anchor_block = get_eth_anchor_block_from_db(&db)?;
-            let btc_anchor_block = get_btc_anchor_block_from_db(&db)?;
-            let eth_latest_block = get_eth_latest_block_from_db(&db)?;
-            let btc_latest_block = get_btc_latest_block_from_db(&db)?;
+            let eth_tail_block =
+                get_special_eth_block_header_from_db(&db, "tail")?;
+            let btc_tail_block =
+                get_special_btc_block_header_from_db(&db, "tail")?;
+            let eth_canon_block =
+                get_special_eth_block_header_from_db(&db, "canon")?;
+            let btc_canon_block =
+                get_special_btc_block_header_from_db(&db, "canon")?;
+            let eth_anchor_block =
+                get_special_eth_block_header_from_db(&db, "anchor")?;
+            let btc_anchor_block =
+                get_special_btc_block_header_from_db(&db, "anchor")?;
+            let eth_latest_block =
+                get_special_eth_block_header_from_db(&db, "latest")?;
+            let btc_latest_block =
+                get_special_btc_block_header_from_db(&db, "latest")?;
             let btc_private_key = get_btc_private_key_from_db(&db)?;
             let btc_public_key_hex = hex::encode(
                 &btc_private_key
@@ -109,6 +131,7 @@ pub fn get_enclave_state<D>(
             Ok(serde_json::to_string(
                 &EnclaveState {
                     debug_mode: DEBUG_MODE,
+                    core_is_paused: core_is_paused(&db),
                     btc_tail_length:
                         BTC_TAIL_LENGTH,
                     eth_tail_length:
@@ -132,21 +155,21 @@ pub fn get_enclave_state<D>(
                     btc_canon_block_hash:
                         btc_canon_block.id.to_string(),
                     eth_tail_block_number:
-                        eth_tail_block.block.number.as_usize(),
+                        eth_tail_block.number.as_usize(),
                     eth_latest_block_number:
-                        eth_latest_block.block.number.as_usize(),
+                        eth_latest_block.number.as_usize(),
                     eth_canon_block_number:
-                        eth_canon_block.block.number.as_usize(),
+                        eth_canon_block.number.as_usize(),
                     eth_anchor_block_number:
-                        eth_anchor_block.block.number.as_usize(),
+                        eth_anchor_block.number.as_usize(),
                     eth_tail_block_hash:
-                        hex::encode(eth_tail_block.block.hash.as_bytes()),
+                        hex::encode(eth_tail_block.hash.as_bytes()),
                     eth_latest_block_hash:
-                        hex::encode(eth_latest_block.block.hash.as_bytes()),
+                        hex::encode(eth_latest_block.hash.as_bytes()),
                     eth_canon_block_hash:
-                        hex::encode(eth_canon_block.block.hash.as_bytes()),
+                        hex::encode(eth_canon_block.hash.as_bytes()),
                     eth_anchor_block_hash:
-                        hex::encode(eth_anchor_block.block.hash.as_bytes()),
+                        hex::encode(eth_anchor_block.hash.as_bytes()),
                     eth_linker_hash:
                         hex::encode(get_eth_linker_hash(&db)?.as_bytes()),
                     btc_sats_per_byte:
@@ -157,8 +180,26 @@ pub fn get_enclave_state<D>(
                         get_btc_linker_hash(&db)?.to_string(),
                     eth_canon_to_tip_length:
                         get_eth_canon_to_tip_length_from_db(&db)?,
+                    eth_tail_pruning_distance:
+                        get_eth_tail_pruning_distance_from_db(&db)?,
+                    eth_host_token_decimals:
+                        get_eth_host_token_decimals_from_db(&db)?,
                     btc_canon_to_tip_length:
                         get_btc_canon_to_tip_length_from_db(&db)?,
+                    btc_accrued_peg_in_fees:
+                        get_btc_accrued_peg_in_fees_from_db(&db)?,
+                    btc_peg_in_fee_basis_points:
+                        get_btc_peg_in_fee_basis_points_from_db(&db)?,
+                    btc_accrued_peg_out_fees:
+                        get_btc_accrued_peg_out_fees_from_db(&db)?,
+                    btc_peg_out_fee_basis_points:
+                        get_btc_peg_out_fee_basis_points_from_db(&db)?,
+                    btc_mint_gas_recapture_rate:
+                        get_btc_mint_gas_recapture_rate_from_db(&db)?,
+                    eth_num_queued_redeem_params:
+                        get_eth_queued_redeem_params_from_db(&db)?.len(),
+                    btc_num_queued_minting_params:
+                        get_btc_queued_minting_params_from_db(&db)?.len(),
                     eth_address:
                         hex::encode(
                             get_public_eth_address_from_db(&db)?.as_bytes()
@@ -0,0 +1,27 @@
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    eth::eth_database_utils::get_eth_account_nonce_from_db,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct EthAccountNonce {
+    eth_account_nonce: u64,
+}
+
+pub fn get_eth_account_nonce<D>(
+    db: D,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting ETH account nonce...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| {
+            Ok(serde_json::to_string(
+                &EthAccountNonce {
+                    eth_account_nonce: get_eth_account_nonce_from_db(&db)?,
+                }
+            )?)
+        })
+}
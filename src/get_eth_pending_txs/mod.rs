@@ -0,0 +1,32 @@
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    eth::eth_database_utils::{
+        get_eth_pending_tx_from_db,
+        get_eth_pending_txs_from_db,
+    },
+};
+
+pub fn get_eth_pending_txs<D>(
+    db: D,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting ETH pending txs...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| get_eth_pending_txs_from_db(&db))
+        .and_then(|pending_txs| Ok(serde_json::to_string(&pending_txs)?))
+}
+
+pub fn get_eth_pending_tx_by_nonce<D>(
+    db: D,
+    nonce: u64,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting ETH pending tx w/ nonce {}...", nonce);
+    check_enclave_is_initialized(&db)
+        .and_then(|_| get_eth_pending_tx_from_db(&db, nonce))
+        .and_then(|pending_tx| Ok(serde_json::to_string(&pending_tx)?))
+}
@@ -1,11 +1,21 @@
 use crate::{
     types::Result,
     traits::DatabaseInterface,
-    eth::eth_database_utils::get_latest_eth_block_number,
-    btc::btc_database_utils::get_btc_latest_block_number,
+    eth::eth_database_utils::get_latest_eth_block_number as get_eth_number,
+    btc::btc_database_utils::get_btc_latest_block_number as get_btc_number,
     check_enclave_is_initialized::check_enclave_is_initialized,
 };
 
+#[derive(Serialize, Deserialize)]
+pub struct BtcBlockNumber {
+    btc_latest_block_number: u64,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct EthBlockNumber {
+    eth_latest_block_number: usize,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct BlockNumbers {
     btc_latest_block_number: u64,
@@ -22,8 +32,40 @@ pub fn get_latest_block_numbers<D>(
         .and_then(|_| {
             Ok(serde_json::to_string(
                 &BlockNumbers {
-                    btc_latest_block_number: get_btc_latest_block_number(&db)?,
-                    eth_latest_block_number: get_latest_eth_block_number(&db)?,
+                    btc_latest_block_number: get_btc_number(&db)?,
+                    eth_latest_block_number: get_eth_number(&db)?,
+                }
+            )?)
+        })
+}
+
+pub fn get_latest_btc_block_number<D>(
+    db: D,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting latest BTC block number...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| {
+            Ok(serde_json::to_string(
+                &BtcBlockNumber {
+                    btc_latest_block_number: get_btc_number(&db)?,
+                }
+            )?)
+        })
+}
+
+pub fn get_latest_eth_block_number<D>(
+    db: D,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting latest ETH block number...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| {
+            Ok(serde_json::to_string(
+                &EthBlockNumber {
+                    eth_latest_block_number: get_eth_number(&db)?,
                 }
             )?)
         })
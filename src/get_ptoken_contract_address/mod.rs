@@ -0,0 +1,42 @@
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    eth::{
+        eth_types::EthContractAddressUpgrade,
+        eth_database_utils::{
+            get_eth_smart_contract_address_from_db,
+            get_eth_smart_contract_address_history_from_db,
+        },
+    },
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct PTokenContractAddress {
+    ptoken_contract_address: String,
+    upgrade_history: Vec<EthContractAddressUpgrade>,
+}
+
+pub fn get_ptoken_contract_address<D>(
+    db: D,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting pToken contract address...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| {
+            Ok(serde_json::to_string(
+                &PTokenContractAddress {
+                    ptoken_contract_address: format!(
+                        "0x{}",
+                        hex::encode(
+                            get_eth_smart_contract_address_from_db(&db)?
+                                .as_bytes()
+                        ),
+                    ),
+                    upgrade_history:
+                        get_eth_smart_contract_address_history_from_db(&db)?,
+                }
+            )?)
+        })
+}
@@ -0,0 +1,111 @@
+use ethereum_types::H256 as EthHash;
+use crate::{
+    types::Result,
+    traits::DatabaseInterface,
+    crypto_utils::keccak_hash_bytes,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    eth::{
+        eth_crypto::eth_transaction::get_ptoken_smart_contract_bytecode,
+        eth_database_utils::{
+            get_ptoken_contract_variant_from_db,
+            get_ptoken_contract_bytecode_hash_from_db,
+        },
+    },
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct PTokenContractBytecodeHash {
+    contract_variant: String,
+    bytecode_hash: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PTokenContractBytecodeHashVerification {
+    contract_variant: String,
+    stored_bytecode_hash: String,
+    computed_bytecode_hash: String,
+    bytecode_hash_verified: bool,
+}
+
+fn encode_eth_hash_as_hex(eth_hash: &EthHash) -> String {
+    format!("0x{}", hex::encode(eth_hash.as_bytes()))
+}
+
+fn verify_ptoken_contract_bytecode_hash_in_db<D>(
+    db: &D,
+) -> Result<PTokenContractBytecodeHashVerification>
+    where D: DatabaseInterface
+{
+    let contract_variant = get_ptoken_contract_variant_from_db(db)?;
+    let stored_hash = get_ptoken_contract_bytecode_hash_from_db(db)?;
+    let computed_hash = get_ptoken_smart_contract_bytecode(&contract_variant)
+        .map(keccak_hash_bytes)?;
+    Ok(
+        PTokenContractBytecodeHashVerification {
+            contract_variant: contract_variant.to_string(),
+            stored_bytecode_hash: encode_eth_hash_as_hex(&stored_hash),
+            computed_bytecode_hash: encode_eth_hash_as_hex(&computed_hash),
+            bytecode_hash_verified: stored_hash == computed_hash,
+        }
+    )
+}
+
+pub fn get_ptoken_contract_bytecode_hash<D>(
+    db: D,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting pToken contract bytecode hash...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| {
+            Ok(serde_json::to_string(
+                &PTokenContractBytecodeHash {
+                    contract_variant:
+                        get_ptoken_contract_variant_from_db(&db)?.to_string(),
+                    bytecode_hash: encode_eth_hash_as_hex(
+                        &get_ptoken_contract_bytecode_hash_from_db(&db)?
+                    ),
+                }
+            )?)
+        })
+}
+
+pub fn verify_ptoken_contract_bytecode_hash<D>(
+    db: D,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Verifying pToken contract bytecode hash...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| verify_ptoken_contract_bytecode_hash_in_db(&db))
+        .and_then(|verification| Ok(serde_json::to_string(&verification)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        eth::{
+            eth_types::PTokenContractVariant,
+            eth_database_utils::put_ptoken_contract_bytecode_hash_in_db,
+        },
+    };
+
+    #[test]
+    fn should_verify_ptoken_contract_bytecode_hash_in_db() {
+        let db = get_test_database();
+        let bytecode_hash = get_ptoken_smart_contract_bytecode(
+            &PTokenContractVariant::Erc777
+        )
+            .map(keccak_hash_bytes)
+            .unwrap();
+        put_ptoken_contract_bytecode_hash_in_db(&db, &bytecode_hash).unwrap();
+        let result = verify_ptoken_contract_bytecode_hash_in_db(&db).unwrap();
+        assert!(result.bytecode_hash_verified);
+        put_ptoken_contract_bytecode_hash_in_db(&db, &EthHash::zero())
+            .unwrap();
+        let result = verify_ptoken_contract_bytecode_hash_in_db(&db).unwrap();
+        assert!(!result.bytecode_hash_verified);
+    }
+}
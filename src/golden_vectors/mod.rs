@@ -0,0 +1,369 @@
+#![cfg(test)]
+// NOTE: Golden vectors pin the exact bytes the core signs & writes, so any
+// change to them across versions fails here rather than on chain or in a db
+// migration. Vectors are never regenerated to match new output: a failure
+// means either the change is a bug, or it is a deliberate format change which
+// requires a new vector alongside the old.
+use ethereum_types::{
+    U256,
+    Address as EthAddress,
+};
+use crate::{
+    types::Result,
+    errors::AppError,
+    eth::{
+        eth_types::PTokenContractVariant,
+        eth_constants::GAS_LIMIT_FOR_CANCEL_TX,
+        eth_crypto::{
+            eth_private_key::EthPrivateKey,
+            eth_transaction::{
+                EthTransaction,
+                get_signed_cancel_tx,
+                get_signed_minting_tx,
+                get_signed_dynamic_fee_minting_tx,
+            },
+        },
+    },
+    btc::{
+        btc_crypto::btc_private_key::BtcPrivateKey,
+        btc_utils::deserialize_btc_utxo,
+        btc_types::{
+            BtcUtxoAndValue,
+            BtcRecipientAndAmount,
+            DepositAddressInfoJson,
+        },
+    },
+};
+
+const ETH_TX_SIGNING_VECTORS: &str = include_str!("eth_tx_signing.json");
+const BTC_P2SH_SPENDING_VECTORS: &str = include_str!("btc_p2sh_spending.json");
+const DB_SERIALIZATION_VECTORS: &str = include_str!("db_serialization.json");
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EthTxVectorType {
+    Legacy,
+    Cancel,
+    DynamicFee,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EthTxSigningVector {
+    pub description: String,
+    pub tx_type: EthTxVectorType,
+    pub private_key: String,
+    pub chain_id: u8,
+    pub nonce: u64,
+    pub to: String,
+    #[serde(default)]
+    pub gas_price: u64,
+    #[serde(default)]
+    pub gas_limit: usize,
+    #[serde(default)]
+    pub max_fee_per_gas: u64,
+    #[serde(default)]
+    pub max_priority_fee_per_gas: u64,
+    #[serde(default)]
+    pub recipient: Option<String>,
+    #[serde(default)]
+    pub amount: Option<String>,
+    pub expected_tx_hex: String,
+    pub expected_tx_hash: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct BtcRecipientVector {
+    pub address: String,
+    pub amount: u64,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct BtcUtxoVector {
+    pub value: u64,
+    pub serialized_utxo: String,
+    #[serde(default)]
+    pub deposit_info: Option<DepositAddressInfoJson>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct BtcP2shSpendingVector {
+    pub description: String,
+    pub private_key_wif: String,
+    pub sats_per_byte: u64,
+    pub remainder_btc_address: String,
+    pub recipients: Vec<BtcRecipientVector>,
+    pub utxos: Vec<BtcUtxoVector>,
+    pub expected_tx_hex: String,
+    pub expected_tx_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct StaticDbKeyVector {
+    pub name: String,
+    pub expected_hex: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UtxoDbKeyVector {
+    pub utxo_number: u64,
+    pub expected_hex: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UtxoAndValueVector {
+    pub description: String,
+    #[serde(flatten)]
+    pub utxo: BtcUtxoVector,
+    pub expected_hex: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DbSerializationVectors {
+    pub static_db_keys: Vec<StaticDbKeyVector>,
+    pub utxo_db_keys: Vec<UtxoDbKeyVector>,
+    pub utxos_and_values: Vec<UtxoAndValueVector>,
+}
+
+pub fn get_eth_tx_signing_vectors() -> Result<Vec<EthTxSigningVector>> {
+    Ok(serde_json::from_str(ETH_TX_SIGNING_VECTORS)?)
+}
+
+pub fn get_btc_p2sh_spending_vectors() -> Result<Vec<BtcP2shSpendingVector>> {
+    Ok(serde_json::from_str(BTC_P2SH_SPENDING_VECTORS)?)
+}
+
+pub fn get_db_serialization_vectors() -> Result<DbSerializationVectors> {
+    Ok(serde_json::from_str(DB_SERIALIZATION_VECTORS)?)
+}
+
+fn get_eth_address_from_hex(hex_string: &str) -> Result<EthAddress> {
+    Ok(EthAddress::from_slice(&hex::decode(hex_string)?))
+}
+
+impl EthTxSigningVector {
+    fn get_private_key(&self) -> Result<EthPrivateKey> {
+        let mut private_key_bytes = [0u8; 32];
+        private_key_bytes.copy_from_slice(&hex::decode(&self.private_key)?);
+        EthPrivateKey::from_slice(private_key_bytes)
+    }
+
+    fn get_recipient(&self) -> Result<EthAddress> {
+        get_eth_address_from_hex(self.recipient.as_ref().expect(
+            "✘ Minting vectors must have a recipient!"
+        ))
+    }
+
+    fn get_amount(&self) -> Result<U256> {
+        U256::from_dec_str(self.amount.as_ref().expect(
+            "✘ Minting vectors must have an amount!"
+        ))
+            .map_err(|_| AppError::InvalidAmount(
+                format!("✘ Invalid amount in vector: {}!", self.description)
+            ))
+    }
+
+    pub fn sign(&self) -> Result<EthTransaction> {
+        let to = get_eth_address_from_hex(&self.to)?;
+        match self.tx_type {
+            EthTxVectorType::Legacy => get_signed_minting_tx(
+                self.get_amount()?,
+                self.nonce,
+                self.chain_id,
+                to,
+                self.gas_price,
+                self.gas_limit,
+                self.get_recipient()?,
+                self.get_private_key()?,
+                &PTokenContractVariant::Erc777,
+            ),
+            EthTxVectorType::DynamicFee => get_signed_dynamic_fee_minting_tx(
+                self.get_amount()?,
+                self.nonce,
+                self.chain_id,
+                to,
+                self.max_fee_per_gas,
+                self.max_priority_fee_per_gas,
+                self.gas_limit,
+                self.get_recipient()?,
+                self.get_private_key()?,
+                &PTokenContractVariant::Erc777,
+            ),
+            EthTxVectorType::Cancel => get_signed_cancel_tx(
+                self.nonce,
+                self.chain_id,
+                true,
+                self.gas_price,
+                GAS_LIMIT_FOR_CANCEL_TX,
+                to,
+                self.get_private_key()?,
+            ),
+        }
+    }
+}
+
+impl BtcUtxoVector {
+    pub fn to_utxo_and_value(&self) -> Result<BtcUtxoAndValue> {
+        Ok(
+            BtcUtxoAndValue::new(
+                self.value,
+                &deserialize_btc_utxo(&hex::decode(&self.serialized_utxo)?)?,
+                self.deposit_info.clone(),
+                None,
+            )
+        )
+    }
+}
+
+impl BtcP2shSpendingVector {
+    pub fn get_recipients_and_amounts(
+        &self,
+    ) -> Result<Vec<BtcRecipientAndAmount>> {
+        self.recipients
+            .iter()
+            .map(|recipient|
+                BtcRecipientAndAmount::new(&recipient.address, recipient.amount)
+            )
+            .collect()
+    }
+
+    pub fn get_utxos_and_values(&self) -> Result<Vec<BtcUtxoAndValue>> {
+        self.utxos
+            .iter()
+            .map(|utxo| utxo.to_utxo_and_value())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        db_codec::DbSerializationFormat,
+        db_keys::{
+            get_static_db_keys,
+            get_utxo_and_value_db_key,
+        },
+        utxo_manager::utxo_utils::{
+            deserialize_utxo_and_value,
+            serialize_btc_utxo_and_value,
+        },
+        btc::{
+            btc_transaction::create_signed_raw_btc_tx_for_n_input_n_outputs,
+            btc_utils::{
+                get_tx_id_from_signed_btc_tx,
+                get_hex_tx_from_signed_btc_tx,
+            },
+        },
+    };
+
+    #[test]
+    fn should_match_eth_tx_signing_vectors() {
+        let vectors = get_eth_tx_signing_vectors().unwrap();
+        assert!(!vectors.is_empty());
+        vectors
+            .iter()
+            .for_each(|vector| {
+                let tx = vector.sign().unwrap();
+                assert_eq!(
+                    tx.serialize_hex(),
+                    vector.expected_tx_hex,
+                    "{}",
+                    vector.description,
+                );
+                assert_eq!(
+                    tx.get_tx_hash(),
+                    vector.expected_tx_hash,
+                    "{}",
+                    vector.description,
+                );
+            });
+    }
+
+    #[test]
+    fn should_match_btc_p2sh_spending_vectors() {
+        let vectors = get_btc_p2sh_spending_vectors().unwrap();
+        assert!(!vectors.is_empty());
+        vectors
+            .iter()
+            .for_each(|vector| {
+                let tx = create_signed_raw_btc_tx_for_n_input_n_outputs(
+                    vector.sats_per_byte,
+                    vector.get_recipients_and_amounts().unwrap(),
+                    &vector.remainder_btc_address,
+                    BtcPrivateKey::from_wif(&vector.private_key_wif).unwrap(),
+                    vector.get_utxos_and_values().unwrap(),
+                ).unwrap();
+                assert_eq!(
+                    get_hex_tx_from_signed_btc_tx(&tx),
+                    vector.expected_tx_hex,
+                    "{}",
+                    vector.description,
+                );
+                assert_eq!(
+                    get_tx_id_from_signed_btc_tx(&tx),
+                    vector.expected_tx_id,
+                    "{}",
+                    vector.description,
+                );
+            });
+    }
+
+    #[test]
+    fn should_match_db_key_vectors() {
+        let vectors = get_db_serialization_vectors().unwrap();
+        let static_db_keys = get_static_db_keys();
+        vectors.static_db_keys
+            .iter()
+            .for_each(|vector| {
+                let (_, key) = static_db_keys
+                    .iter()
+                    .find(|(name, _)| *name == vector.name)
+                    .unwrap_or_else(||
+                        panic!("✘ No db key named {}!", vector.name)
+                    );
+                assert_eq!(
+                    hex::encode(key),
+                    vector.expected_hex,
+                    "{}",
+                    vector.name,
+                );
+            });
+        vectors.utxo_db_keys
+            .iter()
+            .for_each(|vector|
+                assert_eq!(
+                    hex::encode(get_utxo_and_value_db_key(vector.utxo_number)),
+                    vector.expected_hex,
+                    "UTXO #{}",
+                    vector.utxo_number,
+                )
+            );
+    }
+
+    #[test]
+    fn should_match_utxo_and_value_serialization_vectors() {
+        let vectors = get_db_serialization_vectors().unwrap();
+        assert!(!vectors.utxos_and_values.is_empty());
+        vectors.utxos_and_values
+            .iter()
+            .for_each(|vector| {
+                let utxo_and_value = vector.utxo.to_utxo_and_value().unwrap();
+                let expected_bytes = hex::decode(&vector.expected_hex).unwrap();
+                assert_eq!(
+                    serialize_btc_utxo_and_value(
+                        &utxo_and_value,
+                        DbSerializationFormat::Json,
+                    ).unwrap(),
+                    expected_bytes,
+                    "{}",
+                    vector.description,
+                );
+                assert_eq!(
+                    deserialize_utxo_and_value(&expected_bytes).unwrap(),
+                    utxo_and_value,
+                    "{}",
+                    vector.description,
+                );
+            });
+    }
+}
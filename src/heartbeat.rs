@@ -0,0 +1,169 @@
+use crate::{
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    eth::eth_state::EthState,
+    btc::btc_state::BtcState,
+    traits::DatabaseInterface,
+    utils::convert_u256_to_u64,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    constants::{
+        BTC_HEARTBEAT_KEY,
+        ETH_HEARTBEAT_KEY,
+    },
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Heartbeat {
+    pub block_height: u64,
+    pub block_hash: String,
+    pub timestamp: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Heartbeats {
+    pub btc: Option<Heartbeat>,
+    pub eth: Option<Heartbeat>,
+}
+
+impl Heartbeat {
+    pub fn new(block_height: u64, block_hash: String, timestamp: u64) -> Self {
+        Heartbeat { block_height, block_hash, timestamp }
+    }
+}
+
+fn put_heartbeat_in_db<D>(
+    db: &D,
+    key: &[u8],
+    heartbeat: &Heartbeat,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting heartbeat for block #{} in db...", heartbeat.block_height);
+    db.put(
+        key.to_vec(),
+        serde_json::to_vec(heartbeat)?,
+        DataSensitivity::Public,
+    )
+}
+
+fn get_heartbeat_from_db<D>(db: &D, key: &[u8]) -> Result<Option<Heartbeat>>
+    where D: DatabaseInterface
+{
+    match db.get(key.to_vec(), DataSensitivity::Public) {
+        Err(_) => Ok(None),
+        Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+    }
+}
+
+// NOTE: Stamped w/ the submission's `host_timestamp` if it has one, else the
+// block's own timestamp, so that replaying a submission yields the same state.
+pub fn update_btc_heartbeat_in_db<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Updating BTC heartbeat in db...");
+    state
+        .get_btc_block_and_id()
+        .map(|block_and_id|
+            Heartbeat::new(
+                block_and_id.height,
+                block_and_id.id.to_string(),
+                state
+                    .host_timestamp
+                    .as_option()
+                    .cloned()
+                    .unwrap_or_else(|| block_and_id.block.header.time.into()),
+            )
+        )
+        .and_then(|heartbeat|
+            put_heartbeat_in_db(&state.db, &BTC_HEARTBEAT_KEY, &heartbeat)
+        )
+        .map(|_| state)
+}
+
+// NOTE: ETH submissions carry no `host_timestamp` ∴ the block's own is used.
+pub fn update_eth_heartbeat_in_db<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Updating ETH heartbeat in db...");
+    state
+        .get_eth_block_and_receipts()
+        .and_then(|block_and_receipts|
+            Ok(
+                Heartbeat::new(
+                    convert_u256_to_u64(&block_and_receipts.block.number)?,
+                    format!("0x{}", hex::encode(block_and_receipts.block.hash)),
+                    convert_u256_to_u64(&block_and_receipts.block.timestamp)?,
+                )
+            )
+        )
+        .and_then(|heartbeat|
+            put_heartbeat_in_db(&state.db, &ETH_HEARTBEAT_KEY, &heartbeat)
+        )
+        .map(|_| state)
+}
+
+pub fn get_heartbeats_from_db<D>(db: &D) -> Result<Heartbeats>
+    where D: DatabaseInterface
+{
+    Ok(
+        Heartbeats {
+            btc: get_heartbeat_from_db(db, &BTC_HEARTBEAT_KEY)?,
+            eth: get_heartbeat_from_db(db, &ETH_HEARTBEAT_KEY)?,
+        }
+    )
+}
+
+pub fn get_heartbeat<D>(db: D) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting heartbeat...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| get_heartbeats_from_db(&db))
+        .and_then(|heartbeats| Ok(serde_json::to_string(&heartbeats)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        btc::btc_test_utils::get_sample_btc_block_and_id,
+    };
+
+    #[test]
+    fn should_update_btc_heartbeat_in_db() {
+        let db = get_test_database();
+        let block_and_id = get_sample_btc_block_and_id().unwrap();
+        let state = BtcState::init(db)
+            .add_btc_block_and_id(block_and_id.clone())
+            .and_then(update_btc_heartbeat_in_db)
+            .unwrap();
+        let result = get_heartbeats_from_db(&state.db).unwrap();
+        let heartbeat = result.btc.unwrap();
+        assert_eq!(heartbeat.block_height, block_and_id.height);
+        assert_eq!(heartbeat.block_hash, block_and_id.id.to_string());
+        assert_eq!(
+            heartbeat.timestamp,
+            u64::from(block_and_id.block.header.time),
+        );
+        assert!(result.eth.is_none());
+    }
+
+    #[test]
+    fn should_stamp_btc_heartbeat_with_host_timestamp() {
+        let host_timestamp = 1584696813;
+        let state = BtcState::init(get_test_database())
+            .add_btc_block_and_id(get_sample_btc_block_and_id().unwrap())
+            .and_then(|state| state.add_host_timestamp(host_timestamp))
+            .and_then(update_btc_heartbeat_in_db)
+            .unwrap();
+        let result = get_heartbeats_from_db(&state.db).unwrap();
+        assert_eq!(result.btc.unwrap().timestamp, host_timestamp);
+    }
+}
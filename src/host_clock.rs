@@ -0,0 +1,133 @@
+use std::sync::{
+    Arc,
+    Mutex,
+};
+#[cfg(any(test, feature = "test-doubles"))]
+use std::sync::atomic::{
+    Ordering,
+    AtomicU64,
+};
+#[cfg(not(any(feature = "sgx", feature = "wasm")))]
+use std::time::{
+    SystemTime,
+    UNIX_EPOCH,
+};
+use crate::{
+    types::Result,
+    errors::AppError,
+};
+
+pub trait Clock: Send {
+    fn get_unix_timestamp(&self) -> Result<u64>;
+}
+
+impl Clock for fn() -> u64 {
+    fn get_unix_timestamp(&self) -> Result<u64> {
+        Ok(self())
+    }
+}
+
+// NOTE: Lets a host keep a handle on a clock it has registered.
+impl<C: Clock + Sync> Clock for Arc<C> {
+    fn get_unix_timestamp(&self) -> Result<u64> {
+        self.as_ref().get_unix_timestamp()
+    }
+}
+
+lazy_static! {
+    static ref HOST_CLOCK: Mutex<Option<Box<dyn Clock>>> = Mutex::new(None);
+}
+
+pub fn set_clock(clock: Box<dyn Clock>) -> Result<()> {
+    info!("✔ Setting host supplied clock for timestamps...");
+    HOST_CLOCK
+        .lock()
+        .map(|mut host_clock| *host_clock = Some(clock))
+        .map_err(|_|
+            AppError::HostError("✘ Could not set host clock!".to_string())
+        )
+}
+
+pub fn set_host_clock(clock: fn() -> u64) -> Result<()> {
+    set_clock(Box::new(clock))
+}
+
+// NOTE: A test double whose time only moves when told to.
+#[cfg(any(test, feature = "test-doubles"))]
+#[derive(Debug, Default)]
+pub struct MockClock(AtomicU64);
+
+#[cfg(any(test, feature = "test-doubles"))]
+impl MockClock {
+    pub fn new(timestamp: u64) -> Self {
+        MockClock(AtomicU64::new(timestamp))
+    }
+
+    pub fn set(&self, timestamp: u64) {
+        self.0.store(timestamp, Ordering::SeqCst)
+    }
+
+    pub fn advance(&self, num_seconds: u64) {
+        self.0.fetch_add(num_seconds, Ordering::SeqCst);
+    }
+}
+
+#[cfg(any(test, feature = "test-doubles"))]
+impl Clock for MockClock {
+    fn get_unix_timestamp(&self) -> Result<u64> {
+        Ok(self.0.load(Ordering::SeqCst))
+    }
+}
+
+#[cfg(not(any(feature = "sgx", feature = "wasm")))]
+fn get_system_unix_timestamp() -> Result<u64> {
+    Ok(SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs())
+}
+
+// NOTE: `SystemTime` panics in `wasm32-unknown-unknown` ∴ ask the JS host.
+#[cfg(all(feature = "wasm", not(feature = "sgx")))]
+fn get_system_unix_timestamp() -> Result<u64> {
+    Ok((js_sys::Date::now() / 1000.0) as u64)
+}
+
+#[cfg(feature = "sgx")]
+fn get_system_unix_timestamp() -> Result<u64> {
+    Err(AppError::HostError(
+        "✘ No system clock in `sgx` builds ∴ use `set_host_clock`!"
+            .to_string()
+    ))
+}
+
+pub fn get_unix_timestamp() -> Result<u64> {
+    match HOST_CLOCK.lock() {
+        Ok(host_clock) => match host_clock.as_ref() {
+            Some(clock) => clock.get_unix_timestamp(),
+            None => get_system_unix_timestamp(),
+        },
+        Err(_) => get_system_unix_timestamp(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_get_unix_timestamp() {
+        let result = get_unix_timestamp();
+        match cfg!(feature = "sgx") {
+            false => assert!(result.unwrap() > 0),
+            true => assert!(result.is_err()),
+        }
+    }
+
+    #[test]
+    fn mock_clock_should_only_move_when_told_to() {
+        let clock = MockClock::new(1337);
+        assert_eq!(clock.get_unix_timestamp().unwrap(), 1337);
+        clock.advance(10);
+        assert_eq!(clock.get_unix_timestamp().unwrap(), 1347);
+        clock.set(42);
+        assert_eq!(clock.get_unix_timestamp().unwrap(), 42);
+    }
+}
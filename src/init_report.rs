@@ -0,0 +1,54 @@
+use crate::{
+    types::Result,
+    eth::initialize_eth::get_eth_init_output_json::EthInitializationOutput,
+    btc::initialize_btc::get_btc_init_output_json::BtcInitializationOutput,
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum InitReport {
+    Btc(BtcInitializationOutput),
+    Eth(EthInitializationOutput),
+    BtcAlreadyInitialized { btc_enclave_initialized: bool },
+    EthAlreadyInitialized { eth_enclave_initialized: bool },
+}
+
+impl InitReport {
+    pub fn btc_already_initialized() -> Self {
+        InitReport::BtcAlreadyInitialized { btc_enclave_initialized: true }
+    }
+
+    pub fn eth_already_initialized() -> Self {
+        InitReport::EthAlreadyInitialized { eth_enclave_initialized: true }
+    }
+
+    pub fn to_json_string(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_serialize_already_initialized_report_to_valid_json() {
+        let expected_result = "{\"eth_enclave_initialized\":true}";
+        let result = InitReport::eth_already_initialized()
+            .to_json_string()
+            .unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_deserialize_btc_init_report_to_correct_variant() {
+        let json = "{\"btc_address\":\"mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM\",\
+            \"btc_latest_block_num\":1611090}";
+        let result = serde_json::from_str::<InitReport>(json).unwrap();
+        match result {
+            InitReport::Btc(output) =>
+                assert_eq!(output.btc_latest_block_num, 1611090),
+            _ => panic!("Should deserialize to BTC init report!"),
+        }
+    }
+}
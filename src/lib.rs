@@ -2,48 +2,376 @@
 #![recursion_limit="128"] // NOTE: For the format! macro in block parsing.
 #![cfg(feature="btc-on-eth")]
 
-pub mod btc;
-pub mod eth;
-pub mod utils;
-pub mod types;
-pub mod base58;
-pub mod errors;
-pub mod traits;
-pub mod constants;
-pub mod test_utils;
-pub mod utxo_manager;
-pub mod crypto_utils;
-pub mod database_utils;
-pub mod debug_functions;
-pub mod check_debug_mode;
-pub mod get_enclave_state;
-pub mod get_latest_block_numbers;
-pub mod check_enclave_is_initialized;
+#[cfg(all(feature="sgx", any(feature="rocksdb", feature="parallel")))]
+compile_error!(
+    "✘ The `rocksdb` & `parallel` features are not `sgx` compatible!"
+);
+
+#[cfg(all(feature="wasm", any(feature="rocksdb", feature="parallel")))]
+compile_error!(
+    "✘ The `rocksdb` & `parallel` features are not `wasm` compatible!"
+);
+
+#[cfg(all(
+    feature="script-verification",
+    any(feature="sgx", feature="wasm")
+))]
+compile_error!(
+    "✘ The `script-verification` feature is not `sgx` or `wasm` compatible!"
+);
+
+#[cfg(all(feature="benchmarks", any(feature="sgx", feature="wasm")))]
+compile_error!(
+    "✘ The `benchmarks` feature is not `sgx` or `wasm` compatible!"
+);
+
+pub mod prelude;
+
+// NOTE: Declared first so its macros are in scope for the other modules.
+#[macro_use] pub(crate) mod log_config;
+
+pub(crate) mod btc;
+pub(crate) mod eth;
+pub(crate) mod ffi;
+pub(crate) mod utils;
+pub(crate) mod wasm;
+pub(crate) mod python;
+pub(crate) mod types;
+pub(crate) mod base58;
+pub(crate) mod errors;
+pub(crate) mod traits;
+pub(crate) mod db_keys;
+pub(crate) mod db_codec;
+pub(crate) mod cow_json;
+pub(crate) mod heartbeat;
+pub(crate) mod host_clock;
+pub(crate) mod entropy;
+pub(crate) mod init_report;
+pub(crate) mod chain_state;
+pub(crate) mod core_version;
+pub(crate) mod core_metrics;
+pub(crate) mod correlation_id;
+pub(crate) mod error_log;
+pub(crate) mod constants;
+pub(crate) mod test_utils;
+pub(crate) mod submit_block;
+pub(crate) mod submit_block_async;
+pub(crate) mod utxo_manager;
+pub(crate) mod crypto_utils;
+pub(crate) mod test_database;
+pub(crate) mod test_generators;
+pub(crate) mod simulation;
+pub(crate) mod reorg_kit;
+pub(crate) mod golden_vectors;
+pub(crate) mod fixture_converter;
+pub(crate) mod fuzz_targets;
+pub(crate) mod bench_targets;
+pub(crate) mod token_decimals;
+pub(crate) mod safe_addresses;
+pub(crate) mod get_core_state;
+pub(crate) mod database_utils;
+pub(crate) mod debug_functions;
+pub(crate) mod debug_signature;
+pub(crate) mod pipeline;
+pub(crate) mod protobuf;
+pub(crate) mod async_database;
+pub(crate) mod stage_hooks;
+pub(crate) mod stage_timing;
+pub(crate) mod state_size;
+pub(crate) mod state_slot;
+pub(crate) mod skipped_items;
+pub(crate) mod state_attestation;
+pub(crate) mod stale_transactions;
+pub(crate) mod operator_fees;
+pub(crate) mod volume_limits;
+pub(crate) mod recipient_policy;
+pub(crate) mod fee_ledger;
+pub(crate) mod refund_ledger;
+pub(crate) mod pending_approvals;
+pub(crate) mod event_journal;
+pub(crate) mod staged_database;
+pub(crate) mod database_snapshot;
+pub(crate) mod db_state_snapshot;
+pub(crate) mod validation_verdict;
+pub(crate) mod validation_checkpoint;
+pub(crate) mod rocksdb_database;
+pub(crate) mod asset_dictionary;
+pub(crate) mod balance_reconciliation;
+pub(crate) mod check_debug_mode;
+pub(crate) mod get_enclave_state;
+pub(crate) mod get_eth_account_nonce;
+pub(crate) mod get_eth_pending_txs;
+pub(crate) mod processed_tx_ledger;
+pub(crate) mod check_core_integrity;
+pub(crate) mod get_latest_block_numbers;
+pub(crate) mod get_ptoken_contract_address;
+pub(crate) mod check_enclave_is_initialized;
+pub(crate) mod get_ptoken_contract_bytecode_hash;
 
 #[macro_use] extern crate log;
 #[macro_use] extern crate lazy_static;
 #[macro_use] extern crate serde_derive;
 
+#[cfg(feature="test-database")]
+pub use test_database::TestDatabase;
+
+#[cfg(feature="test-database")]
+pub use db_state_snapshot::{
+    DbStateDiff,
+    DbStateSnapshot,
+    check_db_matches_snapshot,
+    assert_db_matches_snapshot,
+};
+
+#[cfg(feature="rocksdb")]
+pub use rocksdb_database::RocksDbDatabase;
+
+#[cfg(feature="simulation")]
+pub use simulation::{
+    Simulation,
+    SimulationStep,
+    SimulationSnapshot,
+};
+
+#[cfg(feature="reorg-kit")]
+pub use reorg_kit::{
+    get_eth_fork,
+    get_btc_fork,
+    mine_btc_block,
+    REORG_KIT_BTC_BITS,
+    get_btc_fork_with_txs,
+    get_btc_submission_json,
+    get_op_return_deposit_tx,
+    get_btc_reorg_genesis_block,
+};
+
+#[cfg(feature="fixture-converter")]
+pub use fixture_converter::{
+    BtcDbFormatFixtureJson,
+    convert_btc_fixture_to_db_format_fixture,
+    convert_geth_rpc_responses_to_eth_fixture,
+    convert_bitcoind_rpc_response_to_btc_fixture,
+};
+
+#[cfg(feature="test-doubles")]
+pub use host_clock::MockClock;
+
+#[cfg(feature="test-doubles")]
+pub use entropy::DeterministicEntropySource;
+
+#[cfg(feature="benchmarks")]
+pub use bench_targets::{
+    BenchFixtures,
+    BENCH_BTC_BLOCK_JSON_PATH,
+    BENCH_ETH_BLOCK_AND_RECEIPTS_JSON_PATH,
+};
+
+#[cfg(feature="fuzzing")]
+pub use fuzz_targets::{
+    DbBlobKind,
+    fuzz_deserialize_db_blob,
+    fuzz_parse_eth_submission,
+    fuzz_parse_btc_submission,
+    fuzz_parse_eth_rpc_submission,
+    fuzz_parse_bitcoind_submission,
+};
+
+#[cfg(feature="test-generators")]
+pub use test_generators::{
+    arb_eth_address,
+    arb_sha256d_hash,
+    arb_deposit_amount,
+    arb_minting_params,
+    arb_btc_block_and_id,
+    arb_utxos_and_values,
+    arb_op_return_deposit_tx,
+};
+
+#[cfg(feature="protobuf")]
+pub use protobuf::submit_protobuf_material::submit_protobuf_material;
+
+#[cfg(feature="async")]
 pub use {
-    traits::DatabaseInterface,
+    async_database::{
+        AsyncDbFuture,
+        AsyncDatabaseInterface,
+    },
+    submit_block_async::{
+        submit_block_async,
+        submit_btc_block_to_enclave_async,
+        submit_eth_block_to_enclave_async,
+    },
+};
+
+pub use {
+    traits::{
+        DatabaseWrite,
+        DatabaseInterface,
+    },
+    init_report::InitReport,
     errors::AppError as PbtcCoreError,
+    eth::get_eth_output_json::EthSubmissionReport,
+    btc::get_btc_output_json::BtcSubmissionReport,
+    validation_verdict::ValidationVerdict,
+    skipped_items::{
+        SkipReason,
+        SkippedItem,
+    },
+    submit_block::{
+        BlockFormat,
+        submit_block,
+        BlockSubmission,
+        SubmissionMaterial,
+    },
     get_enclave_state::get_enclave_state,
-    get_latest_block_numbers::get_latest_block_numbers,
+    core_version::get_core_version_info,
+    core_metrics::get_core_metrics,
+    error_log::get_error_log,
+    event_journal::{
+        BridgeEvent,
+        BridgeEventType,
+        get_bridge_events,
+    },
+    heartbeat::get_heartbeat,
+    state_attestation::get_signed_state_attestation,
+    stale_transactions::get_stale_transactions,
+    volume_limits::get_volume_limits,
+    fee_ledger::get_fee_ledger_summaries,
+    refund_ledger::get_refund_ledger,
+    pending_approvals::get_pending_approvals,
+    host_clock::{
+        Clock,
+        set_clock,
+        set_host_clock,
+    },
+    entropy::{
+        EntropySource,
+        set_entropy_source,
+        ThreadRngEntropySource,
+    },
+    db_codec::DbSerializationFormat,
+    stage_timing::set_host_stage_clock,
+    stage_hooks::{
+        StageHook,
+        StageView,
+        clear_stage_hooks,
+        register_pre_stage_hook,
+        register_post_stage_hook,
+    },
+    get_eth_account_nonce::get_eth_account_nonce,
+    get_eth_pending_txs::{
+        get_eth_pending_txs,
+        get_eth_pending_tx_by_nonce,
+    },
+    get_latest_block_numbers::{
+        get_latest_block_numbers,
+        get_latest_btc_block_number,
+        get_latest_eth_block_number,
+    },
+    get_ptoken_contract_address::get_ptoken_contract_address,
+    check_core_integrity::check_core_integrity,
+    balance_reconciliation::get_balance_reconciliation_report,
+    debug_signature::{
+        maybe_set_debug_admin_public_key,
+        debug_set_debug_admin_public_key,
+    },
+    processed_tx_ledger::{
+        has_redeem_been_processed,
+        has_deposit_been_processed,
+    },
+    get_ptoken_contract_bytecode_hash::{
+        get_ptoken_contract_bytecode_hash,
+        verify_ptoken_contract_bytecode_hash,
+    },
     types::{
         Bytes,
+        DataSensitivity,
         Result as PbtcResult,
     },
     debug_functions::{
         debug_get_all_utxos,
+        debug_get_core_state,
         debug_get_key_from_db,
+        debug_set_eth_tx_type,
+        debug_set_eth_pos_mode,
+        debug_set_max_state_size,
+        debug_set_log_config,
+        debug_set_stale_tx_threshold,
+        debug_remove_watched_tx,
+        debug_resign_eth_mint_tx,
+        debug_set_btc_peg_in_fee,
+        debug_set_btc_peg_out_fee,
+        debug_set_btc_mint_gas_recapture_rate,
+        debug_set_operator_fee_btc_address,
+        debug_withdraw_accrued_operator_fees,
+        debug_set_mint_volume_limit,
+        debug_set_payout_volume_limit,
+        debug_remove_mint_volume_limit,
+        debug_remove_payout_volume_limit,
+        debug_release_volume_limited_params,
+        debug_add_recipient_to_policy,
+        debug_set_recipient_policy_mode,
+        debug_remove_recipient_from_policy,
+        debug_approve_refund,
+        debug_build_refund_txs,
+        debug_set_safe_address_for_tier,
+        debug_set_mint_approval_threshold,
+        debug_set_payout_approval_threshold,
+        debug_remove_mint_approval_threshold,
+        debug_remove_payout_approval_threshold,
+        debug_release_pending_approval,
+        debug_reject_pending_approval,
+        debug_set_core_is_paused,
+        debug_get_eth_core_state,
+        debug_get_btc_core_state,
+        debug_get_safe_addresses,
+        debug_reprocess_eth_block,
+        debug_reprocess_btc_block,
+        debug_unset_core_is_paused,
+        debug_set_safe_btc_address,
+        debug_set_safe_eth_address,
+        debug_enable_eth_relay_mode,
+        debug_set_eth_account_nonce,
+        debug_disable_eth_relay_mode,
+        debug_set_eth_mint_gas_limit,
+        debug_set_eth_cancel_gas_limit,
         debug_set_key_in_db_to_value,
+        debug_get_unexpected_db_keys,
+        debug_set_eth_batch_mint_mode,
+        debug_get_signature_challenge,
+        debug_set_pipeline_stage_enabled,
+        debug_set_eth_pre_eip155_signing,
+        debug_set_eth_dynamic_fee_params,
+        debug_set_db_serialization_format,
+        debug_add_asset_dictionary_entry,
+        debug_reconcile_eth_account_nonce,
+        debug_set_eth_canon_to_tip_length,
+        debug_set_eth_mint_with_data_mode,
+        debug_set_ptoken_contract_address,
+        debug_set_eth_host_token_decimals,
+        debug_set_eth_tail_pruning_distance,
+        debug_remove_asset_dictionary_entry,
+        debug_set_eth_ptoken_contract_topics,
     },
     eth::{
-        submit_eth_block::submit_eth_block_to_enclave,
-        initialize_eth::initialize_eth_enclave::maybe_initialize_eth_enclave,
+        validate_eth_block::validate_eth_block,
+        submit_eth_block::{
+            submit_eth_block_to_enclave,
+            submit_eth_rpc_block_to_enclave,
+        },
+        initialize_eth::initialize_eth_enclave::{
+            maybe_initialize_eth_enclave,
+            maybe_initialize_eth_enclave_with_contract_address,
+            maybe_initialize_eth_enclave_with_contract_variant,
+        },
     },
     btc::{
-        submit_btc_block::submit_btc_block_to_enclave,
+        validate_btc_block::validate_btc_block,
+        preview_minting_txs::preview_eth_minting_txs_for_btc_block,
         initialize_btc::initialize_btc_enclave::maybe_initialize_btc_enclave,
+        submit_btc_block::{
+            submit_btc_block_to_enclave,
+            submit_bitcoind_block_to_enclave,
+        },
     },
 };
@@ -0,0 +1,129 @@
+use std::{
+    str::FromStr,
+    sync::atomic::{
+        Ordering,
+        AtomicBool,
+    },
+};
+use log::LevelFilter;
+use crate::{
+    errors::AppError,
+    chain_state::ChainState,
+    constants::{
+        DEBUG_MODE,
+        LOG_CONFIG_KEY,
+    },
+    traits::DatabaseInterface,
+    types::{
+        Result,
+        DataSensitivity,
+    },
+};
+
+static LOG_REDACTION_ENABLED: AtomicBool = AtomicBool::new(!DEBUG_MODE);
+
+// NOTE: For dumps of key-adjacent data such as addresses, scripts & deposit
+// info, which are suppressed whilst log redaction is enabled.
+macro_rules! sensitive_debug {
+    ($($arg:tt)+) => {
+        if !$crate::log_config::is_log_redaction_enabled() {
+            debug!($($arg)+);
+        }
+    };
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogConfig {
+    pub max_level: Option<String>,
+    pub redact_sensitive_data: bool,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        LogConfig { max_level: None, redact_sensitive_data: !DEBUG_MODE }
+    }
+}
+
+impl LogConfig {
+    pub fn new(max_level: &str, redact_sensitive_data: bool) -> Result<Self> {
+        parse_log_level(max_level)
+            .map(|level| LogConfig {
+                redact_sensitive_data,
+                max_level: Some(level.to_string().to_lowercase()),
+            })
+    }
+}
+
+fn parse_log_level(level: &str) -> Result<LevelFilter> {
+    LevelFilter::from_str(level)
+        .map_err(|_|
+            AppError::InvalidInput(
+                format!("✘ Unrecognised log level: '{}'!", level)
+            )
+        )
+}
+
+// NOTE: Redaction cannot be disabled in production builds.
+pub fn is_log_redaction_enabled() -> bool {
+    !DEBUG_MODE || LOG_REDACTION_ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn get_log_config_from_db<D>(db: &D) -> Result<LogConfig>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting log config from db...");
+    match db.get(LOG_CONFIG_KEY.to_vec(), DataSensitivity::Public) {
+        Err(_) => Ok(LogConfig::default()),
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+    }
+}
+
+pub fn put_log_config_in_db<D>(db: &D, log_config: &LogConfig) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting log config in db...");
+    db.put(
+        LOG_CONFIG_KEY.to_vec(),
+        serde_json::to_vec(log_config)?,
+        DataSensitivity::Public,
+    )
+}
+
+pub fn apply_log_config(log_config: &LogConfig) -> Result<()> {
+    if let Some(max_level) = &log_config.max_level {
+        log::set_max_level(parse_log_level(max_level)?);
+    };
+    LOG_REDACTION_ENABLED
+        .store(log_config.redact_sensitive_data, Ordering::Relaxed);
+    Ok(())
+}
+
+pub fn apply_log_config_from_db<S>(state: S) -> Result<S>
+    where S: ChainState
+{
+    get_log_config_from_db(state.db())
+        .and_then(|log_config| apply_log_config(&log_config))
+        .map(|_| state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_test_database;
+
+    #[test]
+    fn should_put_and_get_log_config_in_db() {
+        let db = get_test_database();
+        assert_eq!(get_log_config_from_db(&db).unwrap(), LogConfig::default());
+        let log_config = LogConfig::new("WARN", true).unwrap();
+        put_log_config_in_db(&db, &log_config).unwrap();
+        let result = get_log_config_from_db(&db).unwrap();
+        assert_eq!(result.max_level, Some("warn".to_string()));
+        assert!(result.redact_sensitive_data);
+    }
+
+    #[test]
+    fn should_fail_to_create_log_config_with_invalid_level() {
+        assert!(LogConfig::new("verbose", false).is_err());
+    }
+}
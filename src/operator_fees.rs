@@ -0,0 +1,223 @@
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    database_utils::put_u64_in_db,
+    eth::{
+        parse_redeem_params::validate_btc_address,
+        create_btc_transactions::get_enough_utxos_to_cover_total,
+    },
+    constants::{
+        MINIMUM_REQUIRED_SATOSHIS,
+        OPERATOR_FEE_BTC_ADDRESS_KEY,
+    },
+    utxo_manager::utxo_database_utils::save_utxos_to_db,
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    btc::{
+        btc_types::BtcRecipientAndAmount,
+        extract_utxos_from_op_return_txs::extract_utxos_from_txs,
+        btc_transaction::create_signed_raw_btc_tx_for_n_input_n_outputs,
+        btc_constants::{
+            BTC_ACCRUED_PEG_IN_FEES_KEY,
+            BTC_ACCRUED_PEG_OUT_FEES_KEY,
+        },
+        btc_utils::{
+            get_hex_tx_from_signed_btc_tx,
+            get_pay_to_pub_key_hash_script,
+        },
+        btc_database_utils::{
+            get_btc_fee_from_db,
+            get_btc_address_from_db,
+            get_btc_network_from_db,
+            get_btc_private_key_from_db,
+            get_btc_account_nonce_from_db,
+            increment_btc_account_nonce_in_db,
+            get_btc_accrued_peg_in_fees_from_db,
+            get_btc_accrued_peg_out_fees_from_db,
+        },
+    },
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperatorFeeWithdrawal {
+    pub btc_tx_hex: String,
+    pub btc_tx_hash: String,
+    pub btc_tx_amount: u64,
+    pub btc_account_nonce: u64,
+    pub btc_tx_recipient: String,
+}
+
+pub fn put_operator_fee_btc_address_in_db<D>(
+    db: &D,
+    address: &str,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!("✔ Putting operator fee BTC address in db...");
+    validate_btc_address(address, &get_btc_network_from_db(db)?)
+        .and_then(|_|
+            db.put(
+                OPERATOR_FEE_BTC_ADDRESS_KEY.to_vec(),
+                address.as_bytes().to_vec(),
+                DataSensitivity::Public,
+            )
+        )
+}
+
+pub fn get_operator_fee_btc_address_from_db<D>(db: &D) -> Result<String>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting operator fee BTC address from db...");
+    db.get(OPERATOR_FEE_BTC_ADDRESS_KEY.to_vec(), DataSensitivity::Public)
+        .map_err(|_|
+            AppError::NotFound(
+                "✘ No operator fee BTC address set in db!".to_string()
+            )
+        )
+        .and_then(|bytes|
+            String::from_utf8(bytes).map_err(|e|
+                AppError::InvalidAddress(
+                    format!("✘ Invalid operator BTC address: {}!", e)
+                )
+            )
+        )
+}
+
+pub fn get_total_accrued_operator_fees_from_db<D>(db: &D) -> Result<u64>
+    where D: DatabaseInterface
+{
+    Ok(
+        get_btc_accrued_peg_in_fees_from_db(db)? +
+        get_btc_accrued_peg_out_fees_from_db(db)?
+    )
+}
+
+fn reset_accrued_operator_fees_in_db<D>(db: &D) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!("✔ Resetting accrued operator fees in db...");
+    put_u64_in_db(db, &BTC_ACCRUED_PEG_IN_FEES_KEY.to_vec(), &0)
+        .and_then(|_|
+            put_u64_in_db(db, &BTC_ACCRUED_PEG_OUT_FEES_KEY.to_vec(), &0)
+        )
+}
+
+// NOTE: The caller must wrap this in a db transaction so that the UTXOs spent,
+// the change saved & the reset counters are all committed together.
+pub fn withdraw_accrued_operator_fees_in_db<D>(
+    db: &D,
+) -> Result<OperatorFeeWithdrawal>
+    where D: DatabaseInterface
+{
+    info!("✔ Withdrawing accrued operator fees...");
+    let amount = get_total_accrued_operator_fees_from_db(db)?;
+    if amount == 0 {
+        return Err(AppError::InvalidAmount(
+            "✘ No accrued operator fees to withdraw!".to_string()
+        ))
+    };
+    if amount < MINIMUM_REQUIRED_SATOSHIS {
+        return Err(AppError::InvalidAmount(format!(
+            "✘ Accrued operator fees of {} are below the minimum of {}!",
+            amount,
+            MINIMUM_REQUIRED_SATOSHIS,
+        )))
+    };
+    let recipient = get_operator_fee_btc_address_from_db(db)?;
+    let sats_per_byte = get_btc_fee_from_db(db)?;
+    let btc_address = get_btc_address_from_db(db)?;
+    let btc_account_nonce = get_btc_account_nonce_from_db(db)?;
+    let signed_tx = get_enough_utxos_to_cover_total(
+        db,
+        amount,
+        1,
+        sats_per_byte,
+        Vec::new(),
+    )
+        .and_then(|utxos_and_values|
+            create_signed_raw_btc_tx_for_n_input_n_outputs(
+                sats_per_byte,
+                vec![BtcRecipientAndAmount::new(&recipient, amount)?],
+                &btc_address,
+                get_btc_private_key_from_db(db)?,
+                utxos_and_values,
+            )
+        )?;
+    let change_utxos = extract_utxos_from_txs(
+        &get_pay_to_pub_key_hash_script(&btc_address)?,
+        &vec![signed_tx.clone()],
+    );
+    save_utxos_to_db(db, &change_utxos)?;
+    increment_btc_account_nonce_in_db(db, &1)?;
+    reset_accrued_operator_fees_in_db(db)?;
+    Ok(
+        OperatorFeeWithdrawal {
+            btc_account_nonce,
+            btc_tx_amount: amount,
+            btc_tx_recipient: recipient,
+            btc_tx_hash: signed_tx.txid().to_string(),
+            btc_tx_hex: get_hex_tx_from_signed_btc_tx(&signed_tx),
+        }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::network::constants::Network as BtcNetwork;
+    use crate::{
+        test_utils::get_test_database,
+        btc::btc_database_utils::{
+            put_btc_network_in_db,
+            increment_btc_accrued_peg_in_fees_in_db,
+            increment_btc_accrued_peg_out_fees_in_db,
+        },
+    };
+
+    #[test]
+    fn should_sum_and_reset_accrued_operator_fees() {
+        let db = get_test_database();
+        increment_btc_accrued_peg_in_fees_in_db(&db, &1337).unwrap();
+        increment_btc_accrued_peg_out_fees_in_db(&db, &1).unwrap();
+        let result = get_total_accrued_operator_fees_from_db(&db).unwrap();
+        assert_eq!(result, 1338);
+        reset_accrued_operator_fees_in_db(&db).unwrap();
+        assert_eq!(get_total_accrued_operator_fees_from_db(&db).unwrap(), 0);
+    }
+
+    #[test]
+    fn should_not_withdraw_if_no_accrued_operator_fees() {
+        let db = get_test_database();
+        assert!(withdraw_accrued_operator_fees_in_db(&db).is_err());
+    }
+    #[test]
+    fn should_not_withdraw_accrued_operator_fees_below_minimum() {
+        let db = get_test_database();
+        increment_btc_accrued_peg_in_fees_in_db(
+            &db,
+            &(MINIMUM_REQUIRED_SATOSHIS - 1),
+        ).unwrap();
+        assert!(withdraw_accrued_operator_fees_in_db(&db).is_err());
+        assert_eq!(
+            get_total_accrued_operator_fees_from_db(&db).unwrap(),
+            MINIMUM_REQUIRED_SATOSHIS - 1,
+        );
+    }
+
+    #[test]
+    fn should_only_put_operator_fee_btc_address_for_core_network() {
+        let db = get_test_database();
+        let testnet_address = "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM";
+        let mainnet_address = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
+        put_btc_network_in_db(&db, &BtcNetwork::Testnet).unwrap();
+        assert!(put_operator_fee_btc_address_in_db(&db, mainnet_address)
+            .is_err());
+        put_operator_fee_btc_address_in_db(&db, testnet_address).unwrap();
+        assert_eq!(
+            get_operator_fee_btc_address_from_db(&db).unwrap(),
+            testnet_address,
+        );
+    }
+}
@@ -0,0 +1,694 @@
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    db_codec::get_db_serialization_format_from_db,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    database_utils::{
+        put_u64_in_db,
+        get_u64_from_db,
+    },
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    token_decimals::{
+        MINTING_ROUNDING_MODE,
+        convert_host_token_amount_to_satoshis,
+    },
+    safe_addresses::{
+        SafeAddressTier,
+        get_safe_eth_address_for_tier_from_db,
+        get_safe_btc_address_for_tier_from_db,
+    },
+    constants::{
+        MINT_APPROVAL_THRESHOLD_KEY,
+        PAYOUT_APPROVAL_THRESHOLD_KEY,
+        PENDING_APPROVAL_REDEEM_PARAMS_KEY,
+        PENDING_APPROVAL_MINTING_PARAMS_KEY,
+    },
+    eth::{
+        eth_state::EthState,
+        eth_types::RedeemParams,
+        eth_database_utils::{
+            get_eth_host_token_decimals_from_db,
+            put_eth_queued_redeem_params_in_db,
+            get_eth_queued_redeem_params_from_db,
+        },
+    },
+    btc::{
+        btc_state::BtcState,
+        btc_types::{
+            MintingParams,
+            MintingParamStruct,
+            BtcBlockInDbFormat,
+        },
+        btc_utils::{
+            serialize_minting_params,
+            deserialize_minting_params,
+        },
+        btc_database_utils::{
+            put_btc_canon_block_in_db,
+            get_btc_canon_block_from_db,
+            put_btc_queued_minting_params_in_db,
+            get_btc_queued_minting_params_from_db,
+        },
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalDecision {
+    Released,
+    Rejected,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingApprovalItem {
+    pub chain: String,
+    pub correlation_id: String,
+    pub originating_tx_hash: String,
+    pub amount_in_satoshis: u64,
+    pub recipient: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingApprovalsReport {
+    pub mint_approval_threshold: Option<u64>,
+    pub payout_approval_threshold: Option<u64>,
+    pub pending_approvals: Vec<PendingApprovalItem>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingApprovalOutcome {
+    pub decision: ApprovalDecision,
+    pub item: PendingApprovalItem,
+}
+
+pub fn get_approval_threshold_from_db<D>(
+    db: &D,
+    key: &[u8],
+) -> Result<Option<u64>>
+    where D: DatabaseInterface
+{
+    match get_u64_from_db(db, &key.to_vec()) {
+        Ok(threshold) => Ok(Some(threshold)),
+        Err(_) => Ok(None),
+    }
+}
+
+// NOTE: A threshold of zero is a real one, holding every transfer for
+// approval. Only removing the threshold stops params from being held.
+pub fn put_approval_threshold_in_db<D>(
+    db: &D,
+    key: &[u8],
+    threshold_in_satoshis: u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    put_u64_in_db(db, &key.to_vec(), &threshold_in_satoshis)
+}
+
+pub fn remove_approval_threshold_from_db<D>(db: &D, key: &[u8]) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!("✔ Removing approval threshold from db...");
+    db.delete(key.to_vec())
+}
+
+pub fn get_pending_approval_minting_params_from_db<D>(
+    db: &D,
+) -> Result<MintingParams>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting pending approval minting params from db...");
+    match db.get(
+        PENDING_APPROVAL_MINTING_PARAMS_KEY.to_vec(),
+        DataSensitivity::Public,
+    ) {
+        Err(_) => Ok(vec![]),
+        Ok(bytes) => deserialize_minting_params(bytes),
+    }
+}
+
+fn put_pending_approval_minting_params_in_db<D>(
+    db: &D,
+    minting_params: &MintingParams,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting pending approval minting params in db...");
+    db.put(
+        PENDING_APPROVAL_MINTING_PARAMS_KEY.to_vec(),
+        serialize_minting_params(
+            minting_params,
+            get_db_serialization_format_from_db(db)?,
+        )?,
+        DataSensitivity::Public,
+    )
+}
+
+pub fn get_pending_approval_redeem_params_from_db<D>(
+    db: &D,
+) -> Result<Vec<RedeemParams>>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting pending approval redeem params from db...");
+    match db.get(
+        PENDING_APPROVAL_REDEEM_PARAMS_KEY.to_vec(),
+        DataSensitivity::Public,
+    ) {
+        Err(_) => Ok(vec![]),
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+    }
+}
+
+fn put_pending_approval_redeem_params_in_db<D>(
+    db: &D,
+    redeem_params: &[RedeemParams],
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting pending approval redeem params in db...");
+    db.put(
+        PENDING_APPROVAL_REDEEM_PARAMS_KEY.to_vec(),
+        serde_json::to_vec(redeem_params)?,
+        DataSensitivity::Public,
+    )
+}
+
+fn get_minting_params_amount_in_satoshis(
+    params: &MintingParamStruct,
+    token_decimals: u32,
+) -> Result<u64> {
+    convert_host_token_amount_to_satoshis(
+        params.amount,
+        token_decimals,
+        &MINTING_ROUNDING_MODE,
+    )
+}
+
+fn get_pending_approval_item_from_minting_params(
+    params: &MintingParamStruct,
+    token_decimals: u32,
+) -> Result<PendingApprovalItem> {
+    Ok(
+        PendingApprovalItem {
+            chain: "BTC".to_string(),
+            correlation_id: params.correlation_id.clone(),
+            originating_tx_hash: params.originating_tx_hash.to_string(),
+            amount_in_satoshis:
+                get_minting_params_amount_in_satoshis(params, token_decimals)?,
+            recipient: format!("0x{}", hex::encode(params.eth_address)),
+        }
+    )
+}
+
+fn get_pending_approval_item_from_redeem_params(
+    params: &RedeemParams,
+) -> PendingApprovalItem {
+    PendingApprovalItem {
+        chain: "ETH".to_string(),
+        recipient: params.recipient.clone(),
+        correlation_id: params.correlation_id.clone(),
+        amount_in_satoshis: params.amount.as_u64(),
+        originating_tx_hash:
+            format!("0x{}", hex::encode(params.originating_tx_hash)),
+    }
+}
+
+// NOTE: Returns the items above the threshold, then those at or below it.
+fn partition_by_threshold<T: Clone>(
+    items: &[T],
+    amounts: &[u64],
+    threshold: u64,
+) -> (Vec<T>, Vec<T>) {
+    let mut held_items = vec![];
+    let mut admitted_items = vec![];
+    items
+        .iter()
+        .zip(amounts.iter())
+        .for_each(|(item, amount)|
+            match *amount > threshold {
+                true => held_items.push(item.clone()),
+                false => admitted_items.push(item.clone()),
+            }
+        );
+    (held_items, admitted_items)
+}
+
+pub fn maybe_hold_minting_params_for_approval<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe holding large canon block minting params for approval...");
+    let threshold = match get_approval_threshold_from_db(
+        &state.db,
+        &MINT_APPROVAL_THRESHOLD_KEY,
+    )? {
+        Some(threshold) => threshold,
+        None => {
+            info!("✔ No mint approval threshold ∴ not holding any params!");
+            return Ok(state)
+        }
+    };
+    let canon_block = get_btc_canon_block_from_db(&state.db)?;
+    let token_decimals = get_eth_host_token_decimals_from_db(&state.db)?;
+    let amounts = canon_block.minting_params
+        .iter()
+        .map(|params|
+            get_minting_params_amount_in_satoshis(params, token_decimals)
+        )
+        .collect::<Result<Vec<u64>>>()?;
+    let (held_params, admitted_params) = partition_by_threshold(
+        &canon_block.minting_params,
+        &amounts,
+        threshold,
+    );
+    if held_params.is_empty() {
+        return Ok(state)
+    };
+    info!(
+        "✔ Holding {} minting params above {} satoshis for approval...",
+        held_params.len(),
+        threshold,
+    );
+    put_pending_approval_minting_params_in_db(
+        &state.db,
+        &[
+            get_pending_approval_minting_params_from_db(&state.db)?,
+            held_params,
+        ].concat(),
+    )?;
+    BtcBlockInDbFormat::new(
+        canon_block.height,
+        canon_block.id,
+        admitted_params,
+        canon_block.block,
+        canon_block.extra_data,
+    )
+        .and_then(|block| put_btc_canon_block_in_db(&state.db, &block))
+        .map(|_| state)
+}
+
+pub fn maybe_hold_redeem_params_for_approval<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe holding large redeem params for approval...");
+    let threshold = match get_approval_threshold_from_db(
+        &state.db,
+        &PAYOUT_APPROVAL_THRESHOLD_KEY,
+    )? {
+        Some(threshold) => threshold,
+        None => {
+            info!("✔ No payout approval threshold ∴ not holding any params!");
+            return Ok(state)
+        }
+    };
+    let amounts = state.redeem_params
+        .iter()
+        .map(|params| params.amount.as_u64())
+        .collect::<Vec<u64>>();
+    let (held_params, admitted_params) = partition_by_threshold(
+        &state.redeem_params,
+        &amounts,
+        threshold,
+    );
+    if held_params.is_empty() {
+        return Ok(state)
+    };
+    info!(
+        "✔ Holding {} redeem params above {} satoshis for approval...",
+        held_params.len(),
+        threshold,
+    );
+    put_pending_approval_redeem_params_in_db(
+        &state.db,
+        &[
+            get_pending_approval_redeem_params_from_db(&state.db)?,
+            held_params,
+        ].concat(),
+    )
+        .and_then(|_| state.replace_redeem_params(admitted_params))
+}
+
+fn get_no_pending_approval_error(correlation_id: &str) -> AppError {
+    AppError::NotFound(
+        format!("✘ No transfer pending approval with ID '{}'!", correlation_id)
+    )
+}
+
+// NOTE: Released items join the pause queues & so are signed on the next
+// submission of the relevant chain's block. Rejected ones are likewise
+// queued, but with their recipient diverted to the `policy_blocked` tier
+// safe address.
+pub fn decide_pending_approval_in_db<D>(
+    db: &D,
+    correlation_id: &str,
+    decision: ApprovalDecision,
+) -> Result<PendingApprovalOutcome>
+    where D: DatabaseInterface
+{
+    info!("✔ Marking {} pending approval {:?}...", correlation_id, decision);
+    let mut minting_params = get_pending_approval_minting_params_from_db(db)?;
+    let mut redeem_params = get_pending_approval_redeem_params_from_db(db)?;
+    let item = match minting_params
+        .iter()
+        .position(|params| params.correlation_id == correlation_id)
+    {
+        Some(index) => {
+            let params = minting_params.remove(index);
+            let item = get_pending_approval_item_from_minting_params(
+                &params,
+                get_eth_host_token_decimals_from_db(db)?,
+            )?;
+            let params = match decision {
+                ApprovalDecision::Released => params,
+                ApprovalDecision::Rejected => MintingParamStruct {
+                    eth_address: get_safe_eth_address_for_tier_from_db(
+                        db,
+                        SafeAddressTier::PolicyBlocked,
+                    )?,
+                    ..params
+                },
+            };
+            put_btc_queued_minting_params_in_db(
+                db,
+                &[get_btc_queued_minting_params_from_db(db)?, vec![params]]
+                    .concat(),
+            )?;
+            put_pending_approval_minting_params_in_db(db, &minting_params)?;
+            item
+        }
+        None => {
+            let index = redeem_params
+                .iter()
+                .position(|params| params.correlation_id == correlation_id)
+                .ok_or_else(|| get_no_pending_approval_error(correlation_id))?;
+            let params = redeem_params.remove(index);
+            let item = get_pending_approval_item_from_redeem_params(&params);
+            let params = match decision {
+                ApprovalDecision::Released => params,
+                ApprovalDecision::Rejected => RedeemParams {
+                    recipient: get_safe_btc_address_for_tier_from_db(
+                        db,
+                        SafeAddressTier::PolicyBlocked,
+                    )?,
+                    recipient_diversion_reason: Some(
+                        "Rejected whilst pending approval".to_string()
+                    ),
+                    ..params
+                },
+            };
+            put_eth_queued_redeem_params_in_db(
+                db,
+                &[get_eth_queued_redeem_params_from_db(db)?, vec![params]]
+                    .concat(),
+            )?;
+            put_pending_approval_redeem_params_in_db(db, &redeem_params)?;
+            item
+        }
+    };
+    Ok(PendingApprovalOutcome { decision, item })
+}
+
+pub fn get_pending_approvals_report_from_db<D>(
+    db: &D,
+) -> Result<PendingApprovalsReport>
+    where D: DatabaseInterface
+{
+    let token_decimals = get_eth_host_token_decimals_from_db(db)?;
+    let minting_items = get_pending_approval_minting_params_from_db(db)?
+        .iter()
+        .map(|params|
+            get_pending_approval_item_from_minting_params(
+                params,
+                token_decimals,
+            )
+        )
+        .collect::<Result<Vec<PendingApprovalItem>>>()?;
+    let redeem_items = get_pending_approval_redeem_params_from_db(db)?
+        .iter()
+        .map(get_pending_approval_item_from_redeem_params)
+        .collect::<Vec<PendingApprovalItem>>();
+    Ok(
+        PendingApprovalsReport {
+            mint_approval_threshold: get_approval_threshold_from_db(
+                db,
+                &MINT_APPROVAL_THRESHOLD_KEY,
+            )?,
+            payout_approval_threshold: get_approval_threshold_from_db(
+                db,
+                &PAYOUT_APPROVAL_THRESHOLD_KEY,
+            )?,
+            pending_approvals: [minting_items, redeem_items].concat(),
+        }
+    )
+}
+
+pub fn get_pending_approvals<D>(db: D) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting transfers pending approval...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| get_pending_approvals_report_from_db(&db))
+        .and_then(|report| Ok(serde_json::to_string(&report)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethereum_types::{
+        U256,
+        H256 as EthHash,
+    };
+    use crate::{
+        test_utils::get_test_database,
+        constants::MINIMUM_REQUIRED_SATOSHIS,
+        btc::btc_test_utils::{
+            get_sample_minting_params,
+            get_sample_btc_block_in_db_format,
+        },
+        eth::eth_test_utils::get_sample_eth_address,
+    };
+
+    fn get_sample_redeem_params() -> Vec<RedeemParams> {
+        vec![1_000, 5_000, 10_000]
+            .into_iter()
+            .enumerate()
+            .map(|(i, amount)|
+                RedeemParams::new(
+                    U256::from(amount),
+                    get_sample_eth_address(),
+                    "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM".to_string(),
+                    EthHash::zero(),
+                ).with_correlation_id(i)
+            )
+            .collect()
+    }
+
+    fn put_canon_block_w_sample_minting_params_in_db<D>(db: &D)
+        where D: DatabaseInterface
+    {
+        let block = get_sample_btc_block_in_db_format().unwrap();
+        let canon_block = BtcBlockInDbFormat::new(
+            block.height,
+            block.id,
+            get_sample_minting_params(),
+            block.block,
+            block.extra_data,
+        ).unwrap();
+        put_btc_canon_block_in_db(db, &canon_block).unwrap();
+    }
+
+    #[test]
+    fn should_partition_items_by_threshold() {
+        let items = vec!["a", "b", "c", "d"];
+        let amounts = vec![10, 1_000, 100, 1_001];
+        let (held, admitted) = partition_by_threshold(&items, &amounts, 100);
+        assert_eq!(held, vec!["b", "d"]);
+        assert_eq!(admitted, vec!["a", "c"]);
+    }
+
+    #[test]
+    fn should_keep_zero_approval_threshold_until_removed() {
+        let db = get_test_database();
+        let key = MINT_APPROVAL_THRESHOLD_KEY;
+        put_approval_threshold_in_db(&db, &key, 1337).unwrap();
+        let result = get_approval_threshold_from_db(&db, &key).unwrap();
+        assert_eq!(result, Some(1337));
+        put_approval_threshold_in_db(&db, &key, 0).unwrap();
+        assert_eq!(get_approval_threshold_from_db(&db, &key).unwrap(), Some(0));
+        remove_approval_threshold_from_db(&db, &key).unwrap();
+        assert_eq!(get_approval_threshold_from_db(&db, &key).unwrap(), None);
+    }
+
+    #[test]
+    fn should_not_hold_minting_params_w_no_threshold() {
+        let db = get_test_database();
+        put_canon_block_w_sample_minting_params_in_db(&db);
+        let state = maybe_hold_minting_params_for_approval(BtcState::init(db))
+            .unwrap();
+        assert_eq!(
+            get_btc_canon_block_from_db(&state.db).unwrap().minting_params,
+            get_sample_minting_params(),
+        );
+        assert!(
+            get_pending_approval_minting_params_from_db(&state.db)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn should_hold_minting_params_above_threshold_for_approval() {
+        let db = get_test_database();
+        let minting_params = get_sample_minting_params();
+        put_canon_block_w_sample_minting_params_in_db(&db);
+        put_approval_threshold_in_db(
+            &db,
+            &MINT_APPROVAL_THRESHOLD_KEY,
+            MINIMUM_REQUIRED_SATOSHIS,
+        ).unwrap();
+        let state = maybe_hold_minting_params_for_approval(BtcState::init(db))
+            .unwrap();
+        assert_eq!(
+            get_btc_canon_block_from_db(&state.db).unwrap().minting_params,
+            vec![minting_params[0].clone(), minting_params[2].clone()],
+        );
+        assert_eq!(
+            get_pending_approval_minting_params_from_db(&state.db).unwrap(),
+            vec![minting_params[1].clone()],
+        );
+    }
+
+    #[test]
+    fn should_hold_all_minting_params_w_zero_threshold() {
+        let db = get_test_database();
+        put_canon_block_w_sample_minting_params_in_db(&db);
+        put_approval_threshold_in_db(&db, &MINT_APPROVAL_THRESHOLD_KEY, 0)
+            .unwrap();
+        let state = maybe_hold_minting_params_for_approval(BtcState::init(db))
+            .unwrap();
+        assert!(
+            get_btc_canon_block_from_db(&state.db)
+                .unwrap()
+                .minting_params
+                .is_empty()
+        );
+        assert_eq!(
+            get_pending_approval_minting_params_from_db(&state.db).unwrap(),
+            get_sample_minting_params(),
+        );
+    }
+
+    #[test]
+    fn should_hold_redeem_params_above_threshold_for_approval() {
+        let db = get_test_database();
+        let redeem_params = get_sample_redeem_params();
+        put_approval_threshold_in_db(&db, &PAYOUT_APPROVAL_THRESHOLD_KEY, 5_000)
+            .unwrap();
+        let state = EthState::init(db)
+            .add_redeem_params(redeem_params.clone())
+            .and_then(maybe_hold_redeem_params_for_approval)
+            .unwrap();
+        assert_eq!(state.redeem_params, redeem_params[..2].to_vec());
+        assert_eq!(
+            get_pending_approval_redeem_params_from_db(&state.db).unwrap(),
+            vec![redeem_params[2].clone()],
+        );
+    }
+
+    #[test]
+    fn should_release_and_reject_pending_redeem_params() {
+        let db = get_test_database();
+        let redeem_params = get_sample_redeem_params();
+        put_pending_approval_redeem_params_in_db(&db, &redeem_params).unwrap();
+        let released_id = &redeem_params[0].correlation_id;
+        let rejected_id = &redeem_params[1].correlation_id;
+        let result = decide_pending_approval_in_db(
+            &db,
+            released_id,
+            ApprovalDecision::Released,
+        ).unwrap();
+        assert_eq!(result.item.chain, "ETH");
+        assert_eq!(result.item.amount_in_satoshis, 1_000);
+        let result = decide_pending_approval_in_db(
+            &db,
+            rejected_id,
+            ApprovalDecision::Rejected,
+        ).unwrap();
+        assert_eq!(result.decision, ApprovalDecision::Rejected);
+        let queued_params = get_eth_queued_redeem_params_from_db(&db).unwrap();
+        assert_eq!(queued_params[0], redeem_params[0]);
+        assert_eq!(
+            queued_params[1].recipient,
+            get_safe_btc_address_for_tier_from_db(
+                &db,
+                SafeAddressTier::PolicyBlocked,
+            ).unwrap(),
+        );
+        assert!(queued_params[1].recipient_diversion_reason.is_some());
+        assert_eq!(
+            get_pending_approval_redeem_params_from_db(&db).unwrap(),
+            vec![redeem_params[2].clone()],
+        );
+        assert!(
+            decide_pending_approval_in_db(
+                &db,
+                rejected_id,
+                ApprovalDecision::Rejected,
+            ).is_err()
+        );
+    }
+
+    #[test]
+    fn should_release_and_reject_pending_minting_params() {
+        let db = get_test_database();
+        let minting_params = get_sample_minting_params()
+            .into_iter()
+            .enumerate()
+            .map(|(i, params)| params.with_correlation_id(i))
+            .collect::<MintingParams>();
+        put_pending_approval_minting_params_in_db(&db, &minting_params)
+            .unwrap();
+        let report = get_pending_approvals_report_from_db(&db).unwrap();
+        assert_eq!(report.pending_approvals.len(), minting_params.len());
+        let released_id = &minting_params[0].correlation_id;
+        let rejected_id = &minting_params[1].correlation_id;
+        let result = decide_pending_approval_in_db(
+            &db,
+            released_id,
+            ApprovalDecision::Released,
+        ).unwrap();
+        assert_eq!(&result.item.correlation_id, released_id);
+        decide_pending_approval_in_db(
+            &db,
+            rejected_id,
+            ApprovalDecision::Rejected,
+        ).unwrap();
+        let queued_params = get_btc_queued_minting_params_from_db(&db)
+            .unwrap();
+        assert_eq!(queued_params[0], minting_params[0]);
+        assert_eq!(
+            queued_params[1].eth_address,
+            get_safe_eth_address_for_tier_from_db(
+                &db,
+                SafeAddressTier::PolicyBlocked,
+            ).unwrap(),
+        );
+        assert_eq!(
+            get_pending_approval_minting_params_from_db(&db).unwrap().len(),
+            minting_params.len() - 2,
+        );
+        assert!(
+            decide_pending_approval_in_db(
+                &db,
+                released_id,
+                ApprovalDecision::Released,
+            ).is_err()
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    chain_state::ChainState,
+    traits::DatabaseInterface,
+    stage_hooks::stage_as,
+    stage_timing::get_stage_name,
+    constants::DISABLED_PIPELINE_STAGES_KEY,
+    types::{
+        Result,
+        DataSensitivity,
+    },
+};
+
+pub struct PipelineStage<'a, S> {
+    pub name: String,
+    pub flag: Option<&'static str>,
+    stage_fn: Box<dyn FnOnce(S) -> Result<S> + 'a>,
+}
+
+pub type Pipeline<'a, S> = Vec<PipelineStage<'a, S>>;
+
+impl<'a, S> PipelineStage<'a, S> where S: ChainState {
+    fn new<F>(flag: Option<&'static str>, stage_fn: F) -> Self
+        where F: FnOnce(S) -> Result<S> + 'a
+    {
+        PipelineStage {
+            flag,
+            name: get_stage_name::<F>(),
+            stage_fn: Box::new(stage_fn),
+        }
+    }
+
+    pub fn required<F>(stage_fn: F) -> Self
+        where F: FnOnce(S) -> Result<S> + 'a
+    {
+        Self::new(None, stage_fn)
+    }
+
+    pub fn optional<F>(flag: &'static str, stage_fn: F) -> Self
+        where F: FnOnce(S) -> Result<S> + 'a
+    {
+        Self::new(Some(flag), stage_fn)
+    }
+
+    fn is_disabled(&self, disabled_flags: &[String]) -> bool {
+        match self.flag {
+            None => false,
+            Some(flag) => disabled_flags
+                .iter()
+                .any(|disabled_flag| disabled_flag == flag),
+        }
+    }
+}
+
+pub fn get_pipeline_flags<S>(pipeline: &Pipeline<S>) -> Vec<&'static str> {
+    let mut flags = pipeline
+        .iter()
+        .filter_map(|pipeline_stage| pipeline_stage.flag)
+        .collect::<Vec<&'static str>>();
+    flags.sort();
+    flags.dedup();
+    flags
+}
+
+pub fn get_disabled_pipeline_stages_from_db<D>(db: &D) -> Result<Vec<String>>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting disabled pipeline stages from db...");
+    let key = DISABLED_PIPELINE_STAGES_KEY.to_vec();
+    match db.get(key, DataSensitivity::Public) {
+        Err(_) => Ok(vec![]),
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+    }
+}
+
+pub fn set_pipeline_stage_enabled_in_db<D>(
+    db: &D,
+    flag: &str,
+    enabled: bool,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Setting pipeline stage '{}' enabled to {}...", flag, enabled);
+    let mut disabled_flags = get_disabled_pipeline_stages_from_db(db)?
+        .into_iter()
+        .filter(|disabled| disabled != flag)
+        .collect::<Vec<String>>();
+    if !enabled {
+        disabled_flags.push(flag.to_string());
+    };
+    db.put(
+        DISABLED_PIPELINE_STAGES_KEY.to_vec(),
+        serde_json::to_vec(&disabled_flags)?,
+        DataSensitivity::Public,
+    )
+}
+
+pub fn run_pipeline<S>(state: S, pipeline: Pipeline<S>) -> Result<S>
+    where S: ChainState
+{
+    let disabled_flags = get_disabled_pipeline_stages_from_db(state.db())?;
+    pipeline
+        .into_iter()
+        .try_fold(state, |state, pipeline_stage|
+            match pipeline_stage.is_disabled(&disabled_flags) {
+                false => stage_as(
+                    pipeline_stage.name,
+                    pipeline_stage.stage_fn,
+                )(state),
+                true => {
+                    info!(
+                        "✔ Skipping disabled {} stage '{}'!",
+                        S::chain_name(),
+                        pipeline_stage.name,
+                    );
+                    Ok(state)
+                }
+            }
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::AppError,
+        btc::btc_state::BtcState,
+        test_utils::get_test_database,
+    };
+
+    fn failing_stage<D>(_state: BtcState<D>) -> Result<BtcState<D>>
+        where D: DatabaseInterface
+    {
+        Err(AppError::Custom("✘ Stage should have been skipped!".to_string()))
+    }
+
+    #[test]
+    fn should_skip_disabled_optional_stages() {
+        let db = get_test_database();
+        set_pipeline_stage_enabled_in_db(&db, "sample_flag", false).unwrap();
+        let pipeline = vec![
+            PipelineStage::optional("sample_flag", failing_stage),
+        ];
+        assert!(run_pipeline(BtcState::init(db), pipeline).is_ok());
+    }
+
+    #[test]
+    fn should_run_stages_once_re_enabled() {
+        let db = get_test_database();
+        set_pipeline_stage_enabled_in_db(&db, "sample_flag", false).unwrap();
+        set_pipeline_stage_enabled_in_db(&db, "sample_flag", true).unwrap();
+        let disabled_flags = get_disabled_pipeline_stages_from_db(&db)
+            .unwrap();
+        assert!(disabled_flags.is_empty());
+        let pipeline = vec![
+            PipelineStage::optional("sample_flag", failing_stage),
+        ];
+        assert!(run_pipeline(BtcState::init(db), pipeline).is_err());
+    }
+}
@@ -0,0 +1,221 @@
+// NOTE: The stable public surface of the core. Downstream users should
+// `use pbtc_core::prelude::*;` rather than reaching into internal modules.
+#[cfg(feature="test-database")]
+pub use crate::{
+    DbStateDiff,
+    TestDatabase,
+    DbStateSnapshot,
+    check_db_matches_snapshot,
+    assert_db_matches_snapshot,
+};
+
+#[cfg(feature="rocksdb")]
+pub use crate::RocksDbDatabase;
+
+#[cfg(feature="simulation")]
+pub use crate::{
+    Simulation,
+    SimulationStep,
+    SimulationSnapshot,
+};
+
+#[cfg(feature="reorg-kit")]
+pub use crate::{
+    get_eth_fork,
+    get_btc_fork,
+    mine_btc_block,
+    REORG_KIT_BTC_BITS,
+    get_btc_fork_with_txs,
+    get_btc_submission_json,
+    get_op_return_deposit_tx,
+    get_btc_reorg_genesis_block,
+};
+
+#[cfg(feature="fixture-converter")]
+pub use crate::{
+    BtcDbFormatFixtureJson,
+    convert_btc_fixture_to_db_format_fixture,
+    convert_geth_rpc_responses_to_eth_fixture,
+    convert_bitcoind_rpc_response_to_btc_fixture,
+};
+
+#[cfg(feature="test-doubles")]
+pub use crate::{
+    MockClock,
+    DeterministicEntropySource,
+};
+
+#[cfg(feature="benchmarks")]
+pub use crate::{
+    BenchFixtures,
+    BENCH_BTC_BLOCK_JSON_PATH,
+    BENCH_ETH_BLOCK_AND_RECEIPTS_JSON_PATH,
+};
+
+#[cfg(feature="fuzzing")]
+pub use crate::{
+    DbBlobKind,
+    fuzz_deserialize_db_blob,
+    fuzz_parse_eth_submission,
+    fuzz_parse_btc_submission,
+    fuzz_parse_eth_rpc_submission,
+    fuzz_parse_bitcoind_submission,
+};
+
+#[cfg(feature="test-generators")]
+pub use crate::{
+    arb_eth_address,
+    arb_sha256d_hash,
+    arb_deposit_amount,
+    arb_minting_params,
+    arb_btc_block_and_id,
+    arb_utxos_and_values,
+    arb_op_return_deposit_tx,
+};
+
+#[cfg(feature="protobuf")]
+pub use crate::submit_protobuf_material;
+
+#[cfg(feature="async")]
+pub use crate::{
+    AsyncDbFuture,
+    submit_block_async,
+    AsyncDatabaseInterface,
+    submit_btc_block_to_enclave_async,
+    submit_eth_block_to_enclave_async,
+};
+
+pub use crate::{
+    Bytes,
+    PbtcResult,
+    Clock,
+    StageHook,
+    StageView,
+    SkipReason,
+    SkippedItem,
+    BlockFormat,
+    BridgeEvent,
+    InitReport,
+    DatabaseWrite,
+    PbtcCoreError,
+    DataSensitivity,
+    EntropySource,
+    BlockSubmission,
+    BridgeEventType,
+    DatabaseInterface,
+    ValidationVerdict,
+    SubmissionMaterial,
+    EthSubmissionReport,
+    BtcSubmissionReport,
+    DbSerializationFormat,
+    ThreadRngEntropySource,
+    set_clock,
+    submit_block,
+    set_host_clock,
+    set_entropy_source,
+    get_enclave_state,
+    get_bridge_events,
+    clear_stage_hooks,
+    validate_eth_block,
+    validate_btc_block,
+    set_host_stage_clock,
+    get_eth_account_nonce,
+    get_eth_pending_txs,
+    check_core_integrity,
+    get_balance_reconciliation_report,
+    get_core_version_info,
+    get_core_metrics,
+    get_error_log,
+    get_heartbeat,
+    get_signed_state_attestation,
+    get_stale_transactions,
+    get_volume_limits,
+    get_fee_ledger_summaries,
+    get_refund_ledger,
+    get_pending_approvals,
+    register_pre_stage_hook,
+    register_post_stage_hook,
+    get_latest_block_numbers,
+    has_redeem_been_processed,
+    has_deposit_been_processed,
+    get_eth_pending_tx_by_nonce,
+    get_ptoken_contract_address,
+    get_latest_btc_block_number,
+    get_latest_eth_block_number,
+    submit_eth_block_to_enclave,
+    submit_btc_block_to_enclave,
+    maybe_initialize_eth_enclave,
+    maybe_initialize_btc_enclave,
+    submit_eth_rpc_block_to_enclave,
+    submit_bitcoind_block_to_enclave,
+    maybe_set_debug_admin_public_key,
+    debug_set_debug_admin_public_key,
+    get_ptoken_contract_bytecode_hash,
+    verify_ptoken_contract_bytecode_hash,
+    preview_eth_minting_txs_for_btc_block,
+    maybe_initialize_eth_enclave_with_contract_address,
+    maybe_initialize_eth_enclave_with_contract_variant,
+    debug_get_all_utxos,
+    debug_get_core_state,
+    debug_get_key_from_db,
+    debug_set_eth_tx_type,
+    debug_set_eth_pos_mode,
+    debug_set_max_state_size,
+    debug_set_log_config,
+    debug_set_stale_tx_threshold,
+    debug_remove_watched_tx,
+    debug_resign_eth_mint_tx,
+    debug_set_btc_peg_in_fee,
+    debug_set_btc_peg_out_fee,
+    debug_set_btc_mint_gas_recapture_rate,
+    debug_set_operator_fee_btc_address,
+    debug_withdraw_accrued_operator_fees,
+    debug_set_mint_volume_limit,
+    debug_set_payout_volume_limit,
+    debug_remove_mint_volume_limit,
+    debug_remove_payout_volume_limit,
+    debug_release_volume_limited_params,
+    debug_add_recipient_to_policy,
+    debug_set_recipient_policy_mode,
+    debug_remove_recipient_from_policy,
+    debug_approve_refund,
+    debug_build_refund_txs,
+    debug_set_safe_address_for_tier,
+    debug_set_mint_approval_threshold,
+    debug_set_payout_approval_threshold,
+    debug_remove_mint_approval_threshold,
+    debug_remove_payout_approval_threshold,
+    debug_release_pending_approval,
+    debug_reject_pending_approval,
+    debug_set_core_is_paused,
+    debug_get_eth_core_state,
+    debug_get_btc_core_state,
+    debug_get_safe_addresses,
+    debug_reprocess_eth_block,
+    debug_reprocess_btc_block,
+    debug_unset_core_is_paused,
+    debug_set_safe_btc_address,
+    debug_set_safe_eth_address,
+    debug_enable_eth_relay_mode,
+    debug_set_eth_account_nonce,
+    debug_disable_eth_relay_mode,
+    debug_set_eth_mint_gas_limit,
+    debug_set_eth_cancel_gas_limit,
+    debug_set_key_in_db_to_value,
+    debug_get_unexpected_db_keys,
+    debug_set_eth_batch_mint_mode,
+    debug_get_signature_challenge,
+    debug_set_pipeline_stage_enabled,
+    debug_set_eth_pre_eip155_signing,
+    debug_set_eth_dynamic_fee_params,
+    debug_set_db_serialization_format,
+    debug_add_asset_dictionary_entry,
+    debug_reconcile_eth_account_nonce,
+    debug_set_eth_canon_to_tip_length,
+    debug_set_eth_mint_with_data_mode,
+    debug_set_ptoken_contract_address,
+    debug_set_eth_host_token_decimals,
+    debug_set_eth_tail_pruning_distance,
+    debug_remove_asset_dictionary_entry,
+    debug_set_eth_ptoken_contract_topics,
+};
@@ -0,0 +1,151 @@
+use std::str::FromStr;
+use bitcoin_hashes::sha256d;
+use crate::{
+    db_keys::DbKeyPrefix,
+    eth::eth_types::EthHash,
+    traits::DatabaseInterface,
+    utils::convert_hex_to_h256,
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    database_utils::put_namespaced_key_in_db,
+};
+
+#[derive(Serialize, Deserialize)]
+pub struct ProcessedDepositStatus {
+    txid: String,
+    deposit_processed: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProcessedRedeemStatus {
+    tx_hash: String,
+    redeem_processed: bool,
+}
+
+fn namespaced_key_exists_in_db<D>(
+    db: &D,
+    prefix: &DbKeyPrefix,
+    key: &[u8],
+) -> bool
+    where D: DatabaseInterface
+{
+    db.get(prefix.namespace_key(key), DataSensitivity::Public).is_ok()
+}
+
+pub fn put_processed_btc_deposit_in_db<D>(
+    db: &D,
+    txid: &sha256d::Hash,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Marking BTC deposit {} as processed in db...", txid);
+    put_namespaced_key_in_db(
+        db,
+        &DbKeyPrefix::ProcessedBtcDeposit,
+        &txid.to_vec(),
+    )
+}
+
+pub fn btc_deposit_has_been_processed<D>(
+    db: &D,
+    txid: &sha256d::Hash,
+) -> bool
+    where D: DatabaseInterface
+{
+    namespaced_key_exists_in_db(
+        db,
+        &DbKeyPrefix::ProcessedBtcDeposit,
+        &txid.to_vec(),
+    )
+}
+
+pub fn put_processed_eth_redeem_in_db<D>(
+    db: &D,
+    tx_hash: &EthHash,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Marking ETH redeem {} as processed in db...", tx_hash);
+    put_namespaced_key_in_db(
+        db,
+        &DbKeyPrefix::ProcessedEthRedeem,
+        tx_hash.as_bytes(),
+    )
+}
+
+pub fn eth_redeem_has_been_processed<D>(db: &D, tx_hash: &EthHash) -> bool
+    where D: DatabaseInterface
+{
+    namespaced_key_exists_in_db(
+        db,
+        &DbKeyPrefix::ProcessedEthRedeem,
+        tx_hash.as_bytes(),
+    )
+}
+
+pub fn has_deposit_been_processed<D>(
+    db: D,
+    txid: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Checking if BTC deposit {} has been processed...", txid);
+    check_enclave_is_initialized(&db)
+        .and_then(|_| Ok(sha256d::Hash::from_str(&txid)?))
+        .and_then(|hash| {
+            Ok(serde_json::to_string(
+                &ProcessedDepositStatus {
+                    txid: hash.to_string(),
+                    deposit_processed:
+                        btc_deposit_has_been_processed(&db, &hash),
+                }
+            )?)
+        })
+}
+
+pub fn has_redeem_been_processed<D>(
+    db: D,
+    tx_hash: String,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Checking if ETH redeem {} has been processed...", tx_hash);
+    check_enclave_is_initialized(&db)
+        .and_then(|_| convert_hex_to_h256(tx_hash))
+        .and_then(|hash| {
+            Ok(serde_json::to_string(
+                &ProcessedRedeemStatus {
+                    tx_hash: format!("0x{}", hex::encode(hash.as_bytes())),
+                    redeem_processed: eth_redeem_has_been_processed(&db, &hash),
+                }
+            )?)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin_hashes::Hash;
+    use crate::test_utils::get_test_database;
+
+    #[test]
+    fn should_mark_btc_deposit_as_processed() {
+        let db = get_test_database();
+        let txid = sha256d::Hash::hash(b"deposit");
+        assert!(!btc_deposit_has_been_processed(&db, &txid));
+        put_processed_btc_deposit_in_db(&db, &txid).unwrap();
+        assert!(btc_deposit_has_been_processed(&db, &txid));
+    }
+
+    #[test]
+    fn should_mark_eth_redeem_as_processed() {
+        let db = get_test_database();
+        let tx_hash = EthHash::from_slice(&[0xab; 32]);
+        assert!(!eth_redeem_has_been_processed(&db, &tx_hash));
+        put_processed_eth_redeem_in_db(&db, &tx_hash).unwrap();
+        assert!(eth_redeem_has_been_processed(&db, &tx_hash));
+    }
+}
@@ -0,0 +1,4 @@
+#![cfg(feature="protobuf")]
+pub mod protobuf_types;
+pub mod parse_protobuf_material;
+pub mod submit_protobuf_material;
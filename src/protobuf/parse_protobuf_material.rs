@@ -0,0 +1,370 @@
+use std::str::FromStr;
+use bitcoin_hashes::{
+    Hash,
+    sha256d,
+};
+use ethereum_types::{
+    H160,
+    H256,
+    U256,
+    Bloom,
+};
+use bitcoin::{
+    util::hash::BitcoinHash,
+    util::address::Address as BtcAddress,
+    consensus::encode::deserialize,
+    blockdata::block::Block as BtcBlock,
+};
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    utils::convert_bytes_to_h256,
+    types::{
+        Bytes,
+        Result,
+    },
+    protobuf::protobuf_types::{
+        EthLog as EthLogProtobuf,
+        EthBlock as EthBlockProtobuf,
+        EthReceipt as EthReceiptProtobuf,
+        BtcSubmissionMaterial,
+        EthSubmissionMaterial,
+        DepositAddressInfo as DepositAddressInfoProtobuf,
+    },
+    btc::{
+        btc_state::BtcState,
+        btc_types::{
+            BtcBlockAndId,
+            DepositInfoList,
+            DepositAddressInfo,
+        },
+    },
+    eth::{
+        eth_state::EthState,
+        get_eth_log::get_logs_bloom_from_logs,
+        eth_types::{
+            EthLog,
+            EthBlock,
+            EthReceipt,
+            EthAddress,
+            EthReceiptProofs,
+            EthBlockAndReceipts,
+        },
+    },
+};
+
+const ETH_ADDRESS_LENGTH: usize = 20;
+const ETH_BLOOM_LENGTH: usize = 256;
+const U256_LENGTH: usize = 32;
+
+fn get_wrong_length_err(expected: usize, name: &str, bytes: &[u8]) -> AppError {
+    AppError::InvalidSubmission(format!(
+        "✘ {} bytes required to create {}, {} provided!",
+        expected,
+        name,
+        bytes.len(),
+    ))
+}
+
+fn convert_bytes_to_address(bytes: &[u8]) -> Result<EthAddress> {
+    match bytes.len() {
+        ETH_ADDRESS_LENGTH => Ok(EthAddress::from_slice(bytes)),
+        _ => Err(get_wrong_length_err(ETH_ADDRESS_LENGTH, "address", bytes)),
+    }
+}
+
+fn convert_maybe_empty_bytes_to_address(bytes: &[u8]) -> Result<EthAddress> {
+    match bytes.is_empty() {
+        true => Ok(H160::zero()),
+        false => convert_bytes_to_address(bytes),
+    }
+}
+
+fn convert_bytes_to_bloom(bytes: &[u8]) -> Result<Bloom> {
+    match bytes.len() {
+        ETH_BLOOM_LENGTH => Ok(Bloom::from_slice(bytes)),
+        _ => Err(get_wrong_length_err(ETH_BLOOM_LENGTH, "bloom", bytes)),
+    }
+}
+
+fn convert_bytes_to_u256(bytes: &[u8]) -> Result<U256> {
+    match bytes.len() <= U256_LENGTH {
+        true => Ok(U256::from_big_endian(bytes)),
+        false => Err(get_wrong_length_err(U256_LENGTH, "u256", bytes)),
+    }
+}
+
+fn convert_maybe_bytes_to_u256(bytes: &[u8]) -> Result<Option<U256>> {
+    match bytes.is_empty() {
+        true => Ok(None),
+        false => Ok(Some(convert_bytes_to_u256(bytes)?)),
+    }
+}
+
+fn convert_maybe_bytes_to_h256(bytes: &Bytes) -> Result<Option<H256>> {
+    match bytes.is_empty() {
+        true => Ok(None),
+        false => Ok(Some(convert_bytes_to_h256(bytes)?)),
+    }
+}
+
+fn convert_bytes_to_h256s(hashes: &[Bytes]) -> Result<Vec<H256>> {
+    hashes
+        .iter()
+        .map(convert_bytes_to_h256)
+        .collect::<Result<Vec<H256>>>()
+}
+
+fn parse_seal_fields(seal_fields: &[Bytes]) -> Result<(Bytes, U256)> {
+    match seal_fields {
+        [] => Ok((vec![], U256::zero())),
+        [seal, value] => Ok((seal.clone(), convert_bytes_to_u256(value)?)),
+        _ => Err(AppError::InvalidSubmission(format!(
+            "✘ Expected 0 or 2 seal fields, not {}!",
+            seal_fields.len(),
+        ))),
+    }
+}
+
+fn parse_deposit_address_info_protobuf(
+    deposit_address_info: &DepositAddressInfoProtobuf,
+) -> Result<DepositAddressInfo> {
+    Ok(
+        DepositAddressInfo {
+            nonce: deposit_address_info.nonce,
+            eth_address: convert_bytes_to_address(
+                &deposit_address_info.eth_address
+            )?,
+            btc_deposit_address: BtcAddress::from_str(
+                &deposit_address_info.btc_deposit_address
+            )?,
+            eth_address_and_nonce_hash: sha256d::Hash::from_slice(
+                &deposit_address_info.eth_address_and_nonce_hash
+            )?,
+        }
+    )
+}
+
+// NOTE: The block id is derived from the serialized header rather than
+// submitted alongside it as in the JSON material.
+pub fn parse_btc_block_and_id_from_protobuf(
+    material: &BtcSubmissionMaterial,
+) -> Result<BtcBlockAndId> {
+    trace!("✔ Parsing `BtcSubmissionMaterial` to `BtcBlockAndId`...");
+    let block = deserialize::<BtcBlock>(&material.block)?;
+    Ok(
+        BtcBlockAndId {
+            id: block.bitcoin_hash(),
+            height: material.height,
+            deposit_address_list: material
+                .deposit_address_list
+                .iter()
+                .map(parse_deposit_address_info_protobuf)
+                .collect::<Result<DepositInfoList>>()?,
+            block,
+        }
+    )
+}
+
+pub fn parse_eth_block_protobuf(block: &EthBlockProtobuf) -> Result<EthBlock> {
+    Ok(
+        EthBlock {
+            difficulty: convert_bytes_to_u256(&block.difficulty)?,
+            extra_data: block.extra_data.clone(),
+            gas_limit: U256::from(block.gas_limit),
+            gas_used: U256::from(block.gas_used),
+            hash: convert_bytes_to_h256(&block.hash)?,
+            logs_bloom: convert_bytes_to_bloom(&block.logs_bloom)?,
+            miner: convert_bytes_to_address(&block.miner)?,
+            mix_hash: convert_bytes_to_h256(&block.mix_hash)?,
+            nonce: block.nonce.clone(),
+            number: U256::from(block.number),
+            parent_hash: convert_bytes_to_h256(&block.parent_hash)?,
+            receipts_root: convert_bytes_to_h256(&block.receipts_root)?,
+            seal_fields: parse_seal_fields(&block.seal_fields)?,
+            sha3_uncles: convert_bytes_to_h256(&block.sha3_uncles)?,
+            size: U256::from(block.size),
+            state_root: convert_bytes_to_h256(&block.state_root)?,
+            timestamp: U256::from(block.timestamp),
+            total_difficulty: convert_bytes_to_u256(&block.total_difficulty)?,
+            transactions: convert_bytes_to_h256s(&block.transactions)?,
+            transactions_root: convert_bytes_to_h256(
+                &block.transactions_root
+            )?,
+            uncles: convert_bytes_to_h256s(&block.uncles)?,
+            base_fee_per_gas: convert_maybe_bytes_to_u256(
+                &block.base_fee_per_gas
+            )?,
+            withdrawals_root: convert_maybe_bytes_to_h256(
+                &block.withdrawals_root
+            )?,
+            blob_gas_used: convert_maybe_bytes_to_u256(
+                &block.blob_gas_used
+            )?,
+            excess_blob_gas: convert_maybe_bytes_to_u256(
+                &block.excess_blob_gas
+            )?,
+            parent_beacon_block_root: convert_maybe_bytes_to_h256(
+                &block.parent_beacon_block_root
+            )?,
+            requests_hash: convert_maybe_bytes_to_h256(
+                &block.requests_hash
+            )?,
+        }
+    )
+}
+
+fn parse_eth_log_protobuf(log: &EthLogProtobuf) -> Result<EthLog> {
+    Ok(
+        EthLog {
+            address: convert_bytes_to_address(&log.address)?,
+            topics: convert_bytes_to_h256s(&log.topics)?,
+            data: log.data.clone(),
+        }
+    )
+}
+
+pub fn parse_eth_receipt_protobuf(
+    receipt: &EthReceiptProtobuf,
+) -> Result<EthReceipt> {
+    let logs = receipt
+        .logs
+        .iter()
+        .map(parse_eth_log_protobuf)
+        .collect::<Result<Vec<EthLog>>>()?;
+    Ok(
+        EthReceipt {
+            to: convert_maybe_empty_bytes_to_address(&receipt.to)?,
+            from: convert_bytes_to_address(&receipt.from)?,
+            status: receipt.status,
+            gas_used: U256::from(receipt.gas_used),
+            block_hash: convert_bytes_to_h256(&receipt.block_hash)?,
+            transaction_hash: convert_bytes_to_h256(
+                &receipt.transaction_hash
+            )?,
+            cumulative_gas_used: U256::from(receipt.cumulative_gas_used),
+            block_number: U256::from(receipt.block_number),
+            transaction_index: U256::from(receipt.transaction_index),
+            contract_address: convert_maybe_empty_bytes_to_address(
+                &receipt.contract_address
+            )?,
+            logs_bloom: get_logs_bloom_from_logs(&logs)?,
+            receipt_type: receipt.receipt_type as u8,
+            logs,
+        }
+    )
+}
+
+pub fn parse_eth_block_and_receipts_from_protobuf(
+    material: &EthSubmissionMaterial,
+) -> Result<EthBlockAndReceipts> {
+    trace!("✔ Parsing `EthSubmissionMaterial` to `EthBlockAndReceipts`...");
+    match &material.block {
+        None => Err(AppError::InvalidSubmission(
+            "✘ No ETH block in protobuf submission material!".to_string()
+        )),
+        Some(block) => Ok(
+            EthBlockAndReceipts {
+                block: parse_eth_block_protobuf(block)?,
+                receipts: material
+                    .receipts
+                    .iter()
+                    .map(parse_eth_receipt_protobuf)
+                    .collect::<Result<Vec<EthReceipt>>>()?,
+            }
+        ),
+    }
+}
+
+pub fn parse_btc_protobuf_material_and_put_in_state<D>(
+    material: BtcSubmissionMaterial,
+    state: BtcState<D>,
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Parsing BTC protobuf submission material...");
+    parse_btc_block_and_id_from_protobuf(&material)
+        .and_then(|block_and_id| state.add_btc_block_and_id(block_and_id))
+        .and_then(|state| match material.host_timestamp {
+            0 => Ok(state),
+            timestamp => state.add_host_timestamp(timestamp),
+        })
+        .and_then(|state| match material.host_eth_gas_price {
+            0 => Ok(state),
+            gas_price => state.add_host_eth_gas_price(gas_price),
+        })
+}
+
+pub fn parse_eth_protobuf_material_and_put_in_state<D>(
+    material: EthSubmissionMaterial,
+    state: EthState<D>,
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Parsing ETH protobuf submission material...");
+    parse_eth_block_and_receipts_from_protobuf(&material)
+        .and_then(|block_and_receipts|
+            state.add_eth_block_and_receipts(block_and_receipts)
+        )
+        .and_then(|state| match material.receipt_proofs.is_empty() {
+            true => Ok(state),
+            false => {
+                info!("✔ Submission contains ETH receipt proofs!");
+                state.add_eth_receipt_proofs(
+                    material
+                        .receipt_proofs
+                        .into_iter()
+                        .map(|proof| proof.nodes)
+                        .collect::<EthReceiptProofs>()
+                )
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prost::Message;
+    use bitcoin::consensus::encode::serialize as btc_serialize;
+    use crate::btc::btc_test_utils::get_sample_btc_block_and_id;
+
+    fn get_sample_btc_protobuf_material() -> BtcSubmissionMaterial {
+        let block_and_id = get_sample_btc_block_and_id().unwrap();
+        BtcSubmissionMaterial {
+            block: btc_serialize(&block_and_id.block),
+            height: block_and_id.height,
+            host_timestamp: 0,
+            host_eth_gas_price: 0,
+            deposit_address_list: block_and_id
+                .deposit_address_list
+                .iter()
+                .map(|info| DepositAddressInfoProtobuf {
+                    nonce: info.nonce,
+                    eth_address: info.eth_address.as_bytes().to_vec(),
+                    btc_deposit_address: info.btc_deposit_address.to_string(),
+                    eth_address_and_nonce_hash:
+                        info.eth_address_and_nonce_hash[..].to_vec(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn should_parse_btc_block_and_id_from_protobuf() {
+        let expected_result = get_sample_btc_block_and_id().unwrap();
+        let mut bytes = vec![];
+        get_sample_btc_protobuf_material().encode(&mut bytes).unwrap();
+        let material = BtcSubmissionMaterial::decode(&bytes[..]).unwrap();
+        let result = parse_btc_block_and_id_from_protobuf(&material).unwrap();
+        assert_eq!(result, expected_result);
+    }
+
+    #[test]
+    fn should_fail_to_parse_eth_block_protobuf_with_wrong_length_hash() {
+        let block = EthBlockProtobuf {
+            hash: vec![0xc0, 0xff, 0xee],
+            ..EthBlockProtobuf::default()
+        };
+        assert!(parse_eth_block_protobuf(&block).is_err());
+    }
+}
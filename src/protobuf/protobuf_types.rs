@@ -0,0 +1,157 @@
+// NOTE: Mirrors `proto/submission_material.proto`. Written out by hand rather
+// than via `prost-build` so as not to require `protoc` in enclave builds.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SubmissionMaterial {
+    #[prost(oneof = "submission_material::Chain", tags = "1, 2")]
+    pub chain: Option<submission_material::Chain>,
+    #[prost(bool, tag = "3")]
+    pub dry_run: bool,
+}
+
+pub mod submission_material {
+    #[derive(Clone, PartialEq, prost::Oneof)]
+    pub enum Chain {
+        #[prost(message, tag = "1")]
+        Btc(super::BtcSubmissionMaterial),
+        #[prost(message, tag = "2")]
+        Eth(super::EthSubmissionMaterial),
+    }
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct BtcSubmissionMaterial {
+    #[prost(bytes, tag = "1")]
+    pub block: Vec<u8>,
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    #[prost(message, repeated, tag = "3")]
+    pub deposit_address_list: Vec<DepositAddressInfo>,
+    #[prost(uint64, tag = "4")]
+    pub host_timestamp: u64,
+    #[prost(uint64, tag = "5")]
+    pub host_eth_gas_price: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DepositAddressInfo {
+    #[prost(uint64, tag = "1")]
+    pub nonce: u64,
+    #[prost(bytes, tag = "2")]
+    pub eth_address: Vec<u8>,
+    #[prost(string, tag = "3")]
+    pub btc_deposit_address: String,
+    #[prost(bytes, tag = "4")]
+    pub eth_address_and_nonce_hash: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct EthSubmissionMaterial {
+    #[prost(message, optional, tag = "1")]
+    pub block: Option<EthBlock>,
+    #[prost(message, repeated, tag = "2")]
+    pub receipts: Vec<EthReceipt>,
+    #[prost(message, repeated, tag = "3")]
+    pub receipt_proofs: Vec<EthReceiptProof>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct EthBlock {
+    #[prost(bytes, tag = "1")]
+    pub difficulty: Vec<u8>,
+    #[prost(bytes, tag = "2")]
+    pub extra_data: Vec<u8>,
+    #[prost(uint64, tag = "3")]
+    pub gas_limit: u64,
+    #[prost(uint64, tag = "4")]
+    pub gas_used: u64,
+    #[prost(bytes, tag = "5")]
+    pub hash: Vec<u8>,
+    #[prost(bytes, tag = "6")]
+    pub logs_bloom: Vec<u8>,
+    #[prost(bytes, tag = "7")]
+    pub miner: Vec<u8>,
+    #[prost(bytes, tag = "8")]
+    pub mix_hash: Vec<u8>,
+    #[prost(bytes, tag = "9")]
+    pub nonce: Vec<u8>,
+    #[prost(uint64, tag = "10")]
+    pub number: u64,
+    #[prost(bytes, tag = "11")]
+    pub parent_hash: Vec<u8>,
+    #[prost(bytes, tag = "12")]
+    pub receipts_root: Vec<u8>,
+    #[prost(bytes, repeated, tag = "13")]
+    pub seal_fields: Vec<Vec<u8>>,
+    #[prost(bytes, tag = "14")]
+    pub sha3_uncles: Vec<u8>,
+    #[prost(uint64, tag = "15")]
+    pub size: u64,
+    #[prost(bytes, tag = "16")]
+    pub state_root: Vec<u8>,
+    #[prost(uint64, tag = "17")]
+    pub timestamp: u64,
+    #[prost(bytes, tag = "18")]
+    pub total_difficulty: Vec<u8>,
+    #[prost(bytes, repeated, tag = "19")]
+    pub transactions: Vec<Vec<u8>>,
+    #[prost(bytes, tag = "20")]
+    pub transactions_root: Vec<u8>,
+    #[prost(bytes, repeated, tag = "21")]
+    pub uncles: Vec<Vec<u8>>,
+    #[prost(bytes, tag = "22")]
+    pub base_fee_per_gas: Vec<u8>,
+    #[prost(bytes, tag = "23")]
+    pub withdrawals_root: Vec<u8>,
+    #[prost(bytes, tag = "24")]
+    pub blob_gas_used: Vec<u8>,
+    #[prost(bytes, tag = "25")]
+    pub excess_blob_gas: Vec<u8>,
+    #[prost(bytes, tag = "26")]
+    pub parent_beacon_block_root: Vec<u8>,
+    #[prost(bytes, tag = "27")]
+    pub requests_hash: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct EthReceipt {
+    #[prost(bytes, tag = "1")]
+    pub to: Vec<u8>,
+    #[prost(bytes, tag = "2")]
+    pub from: Vec<u8>,
+    #[prost(bool, tag = "3")]
+    pub status: bool,
+    #[prost(uint64, tag = "4")]
+    pub gas_used: u64,
+    #[prost(bytes, tag = "5")]
+    pub block_hash: Vec<u8>,
+    #[prost(bytes, tag = "6")]
+    pub transaction_hash: Vec<u8>,
+    #[prost(uint64, tag = "7")]
+    pub cumulative_gas_used: u64,
+    #[prost(uint64, tag = "8")]
+    pub block_number: u64,
+    #[prost(uint64, tag = "9")]
+    pub transaction_index: u64,
+    #[prost(bytes, tag = "10")]
+    pub contract_address: Vec<u8>,
+    #[prost(message, repeated, tag = "11")]
+    pub logs: Vec<EthLog>,
+    #[prost(uint32, tag = "12")]
+    pub receipt_type: u32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct EthLog {
+    #[prost(bytes, tag = "1")]
+    pub address: Vec<u8>,
+    #[prost(bytes, repeated, tag = "2")]
+    pub topics: Vec<Vec<u8>>,
+    #[prost(bytes, tag = "3")]
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct EthReceiptProof {
+    #[prost(bytes, repeated, tag = "1")]
+    pub nodes: Vec<Vec<u8>>,
+}
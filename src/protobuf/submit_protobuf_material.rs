@@ -0,0 +1,55 @@
+use prost::Message;
+use crate::{
+    types::Result,
+    errors::AppError,
+    traits::DatabaseInterface,
+    state_size::check_size_is_within_limit,
+    eth::submit_eth_block::submit_parsed_eth_block_to_enclave,
+    btc::submit_btc_block::submit_parsed_btc_block_to_enclave,
+    protobuf::{
+        protobuf_types::{
+            SubmissionMaterial,
+            submission_material::Chain,
+        },
+        parse_protobuf_material::{
+            parse_btc_protobuf_material_and_put_in_state,
+            parse_eth_protobuf_material_and_put_in_state,
+        },
+    },
+};
+
+pub fn submit_protobuf_material<D>(
+    db: D,
+    material_bytes: &[u8],
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    check_size_is_within_limit(&db, "UNKNOWN", material_bytes.len() as u64)?;
+    let material = SubmissionMaterial::decode(material_bytes)
+        .map_err(|e| AppError::InvalidSubmission(
+            format!("✘ Invalid protobuf submission material: {}!", e)
+        ))?;
+    match material.chain {
+        None => Err(AppError::InvalidSubmission(
+            "✘ No chain in protobuf submission material!".to_string()
+        )),
+        Some(Chain::Btc(btc_material)) => {
+            info!("✔ Submitting BTC protobuf submission material...");
+            submit_parsed_btc_block_to_enclave(db, material.dry_run, |state|
+                parse_btc_protobuf_material_and_put_in_state(
+                    btc_material,
+                    state,
+                )
+            )
+        }
+        Some(Chain::Eth(eth_material)) => {
+            info!("✔ Submitting ETH protobuf submission material...");
+            submit_parsed_eth_block_to_enclave(db, material.dry_run, |state|
+                parse_eth_protobuf_material_and_put_in_state(
+                    eth_material,
+                    state,
+                )
+            )
+        }
+    }
+}
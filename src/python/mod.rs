@@ -0,0 +1,3 @@
+#![cfg(feature="python")]
+pub mod py_database;
+pub mod py_bindings;
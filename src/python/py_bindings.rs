@@ -0,0 +1,229 @@
+use pyo3::{
+    prelude::*,
+    wrap_pyfunction,
+    exceptions::RuntimeError,
+};
+use crate::{
+    types::Result,
+    python::py_database::PyDatabase,
+    get_enclave_state::get_enclave_state,
+    get_latest_block_numbers::get_latest_block_numbers,
+    submit_block::{
+        submit_block,
+        SubmissionMaterial,
+    },
+    debug_functions::{
+        debug_get_all_utxos,
+        debug_get_core_state,
+        debug_get_key_from_db,
+        debug_get_safe_addresses,
+        debug_reprocess_btc_block,
+        debug_reprocess_eth_block,
+        debug_get_unexpected_db_keys,
+        debug_get_signature_challenge,
+    },
+    eth::{
+        submit_eth_block::submit_eth_block_to_enclave,
+        initialize_eth::initialize_eth_enclave::maybe_initialize_eth_enclave,
+    },
+    btc::{
+        submit_btc_block::submit_btc_block_to_enclave,
+        initialize_btc::initialize_btc_enclave::maybe_initialize_btc_enclave,
+    },
+};
+
+fn to_py_result(result: Result<String>) -> PyResult<String> {
+    result.map_err(|err| PyErr::new::<RuntimeError, _>(err.to_string()))
+}
+
+#[pyfunction]
+#[name = "maybe_initialize_btc_enclave"]
+fn py_maybe_initialize_btc_enclave(
+    db: PyObject,
+    block_json_string: String,
+    fee: u64,
+    difficulty: u64,
+    network: String,
+    canon_to_tip_length: u64,
+) -> PyResult<String> {
+    to_py_result(
+        maybe_initialize_btc_enclave(
+            PyDatabase::new(db),
+            block_json_string,
+            fee,
+            difficulty,
+            network,
+            canon_to_tip_length,
+        )
+    )
+}
+
+#[pyfunction]
+#[name = "maybe_initialize_eth_enclave"]
+fn py_maybe_initialize_eth_enclave(
+    db: PyObject,
+    block_json_string: String,
+    chain_id: u8,
+    gas_price: u64,
+    canon_to_tip_length: u64,
+) -> PyResult<String> {
+    to_py_result(
+        maybe_initialize_eth_enclave(
+            PyDatabase::new(db),
+            block_json_string,
+            chain_id,
+            gas_price,
+            canon_to_tip_length,
+        )
+    )
+}
+
+#[pyfunction]
+#[name = "submit_btc_block"]
+fn py_submit_btc_block(
+    db: PyObject,
+    block_json_string: String,
+    dry_run: bool,
+) -> PyResult<String> {
+    to_py_result(
+        submit_btc_block_to_enclave(
+            PyDatabase::new(db),
+            block_json_string,
+            dry_run,
+        )
+    )
+}
+
+#[pyfunction]
+#[name = "submit_eth_block"]
+fn py_submit_eth_block(
+    db: PyObject,
+    block_json_string: String,
+    dry_run: bool,
+) -> PyResult<String> {
+    to_py_result(
+        submit_eth_block_to_enclave(
+            PyDatabase::new(db),
+            block_json_string,
+            dry_run,
+        )
+    )
+}
+
+#[pyfunction]
+#[name = "submit_block"]
+fn py_submit_block(
+    db: PyObject,
+    submission_material_json_string: String,
+) -> PyResult<String> {
+    let db = PyDatabase::new(db);
+    to_py_result(
+        SubmissionMaterial::from_str_within_limit(
+            &db,
+            &submission_material_json_string,
+        )
+            .and_then(|material| submit_block(db, material))
+    )
+}
+
+#[pyfunction]
+#[name = "get_enclave_state"]
+fn py_get_enclave_state(db: PyObject) -> PyResult<String> {
+    to_py_result(get_enclave_state(PyDatabase::new(db)))
+}
+
+#[pyfunction]
+#[name = "get_latest_block_numbers"]
+fn py_get_latest_block_numbers(db: PyObject) -> PyResult<String> {
+    to_py_result(get_latest_block_numbers(PyDatabase::new(db)))
+}
+
+#[pyfunction]
+#[name = "debug_get_core_state"]
+fn py_debug_get_core_state(db: PyObject) -> PyResult<String> {
+    to_py_result(debug_get_core_state(PyDatabase::new(db)))
+}
+
+#[pyfunction]
+#[name = "debug_get_all_utxos"]
+fn py_debug_get_all_utxos(db: PyObject) -> PyResult<String> {
+    to_py_result(debug_get_all_utxos(PyDatabase::new(db)))
+}
+
+#[pyfunction]
+#[name = "debug_get_safe_addresses"]
+fn py_debug_get_safe_addresses(db: PyObject) -> PyResult<String> {
+    to_py_result(debug_get_safe_addresses(PyDatabase::new(db)))
+}
+
+#[pyfunction]
+#[name = "debug_get_unexpected_db_keys"]
+fn py_debug_get_unexpected_db_keys(db: PyObject) -> PyResult<String> {
+    to_py_result(debug_get_unexpected_db_keys(PyDatabase::new(db)))
+}
+
+#[pyfunction]
+#[name = "debug_get_key_from_db"]
+fn py_debug_get_key_from_db(db: PyObject, key: String) -> PyResult<String> {
+    to_py_result(debug_get_key_from_db(PyDatabase::new(db), key))
+}
+
+#[pyfunction]
+#[name = "debug_get_signature_challenge"]
+fn py_debug_get_signature_challenge(db: PyObject) -> PyResult<String> {
+    to_py_result(debug_get_signature_challenge(PyDatabase::new(db)))
+}
+
+#[pyfunction]
+#[name = "debug_reprocess_btc_block"]
+fn py_debug_reprocess_btc_block(
+    db: PyObject,
+    btc_block_hash: String,
+    deposit_address_list_json: String,
+    signature: String,
+) -> PyResult<String> {
+    to_py_result(
+        debug_reprocess_btc_block(
+            PyDatabase::new(db),
+            btc_block_hash,
+            deposit_address_list_json,
+            signature,
+        )
+    )
+}
+
+#[pyfunction]
+#[name = "debug_reprocess_eth_block"]
+fn py_debug_reprocess_eth_block(
+    db: PyObject,
+    eth_block_hash: String,
+    signature: String,
+) -> PyResult<String> {
+    to_py_result(
+        debug_reprocess_eth_block(
+            PyDatabase::new(db),
+            eth_block_hash,
+            signature,
+        )
+    )
+}
+
+#[pymodule]
+fn pbtc_core(_py: Python, module: &PyModule) -> PyResult<()> {
+    module.add_wrapped(wrap_pyfunction!(py_maybe_initialize_btc_enclave))?;
+    module.add_wrapped(wrap_pyfunction!(py_maybe_initialize_eth_enclave))?;
+    module.add_wrapped(wrap_pyfunction!(py_submit_btc_block))?;
+    module.add_wrapped(wrap_pyfunction!(py_submit_eth_block))?;
+    module.add_wrapped(wrap_pyfunction!(py_submit_block))?;
+    module.add_wrapped(wrap_pyfunction!(py_get_enclave_state))?;
+    module.add_wrapped(wrap_pyfunction!(py_get_latest_block_numbers))?;
+    module.add_wrapped(wrap_pyfunction!(py_debug_get_core_state))?;
+    module.add_wrapped(wrap_pyfunction!(py_debug_get_all_utxos))?;
+    module.add_wrapped(wrap_pyfunction!(py_debug_get_safe_addresses))?;
+    module.add_wrapped(wrap_pyfunction!(py_debug_get_unexpected_db_keys))?;
+    module.add_wrapped(wrap_pyfunction!(py_debug_get_key_from_db))?;
+    module.add_wrapped(wrap_pyfunction!(py_debug_get_signature_challenge))?;
+    module.add_wrapped(wrap_pyfunction!(py_debug_reprocess_btc_block))?;
+    module.add_wrapped(wrap_pyfunction!(py_debug_reprocess_eth_block))?;
+    Ok(())
+}
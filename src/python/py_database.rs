@@ -0,0 +1,86 @@
+use pyo3::{
+    prelude::*,
+    types::{
+        PyBytes,
+        PyTuple,
+    },
+};
+use crate::{
+    errors::AppError,
+    utils::get_not_in_db_err,
+    traits::DatabaseInterface,
+    types::{
+        Bytes,
+        Result,
+        DataSensitivity,
+    },
+};
+
+fn convert_py_err_to_app_err(py_err: PyErr) -> AppError {
+    AppError::DatabaseError(format!("✘ Python database error: {:?}!", py_err))
+}
+
+// NOTE: Wraps any Python object exposing `get(key, is_private)`,
+// `put(key, value, is_private)`, `delete(key)`, `start_transaction()` &
+// `end_transaction()`, with keys & values passed as `bytes`.
+pub struct PyDatabase(PyObject);
+
+impl PyDatabase {
+    pub fn new(py_object: PyObject) -> Self {
+        PyDatabase(py_object)
+    }
+
+    fn call_method<A>(&self, name: &str, args: A) -> Result<PyObject>
+        where A: IntoPy<Py<PyTuple>>
+    {
+        let gil = Python::acquire_gil();
+        self.0
+            .call_method1(gil.python(), name, args)
+            .map_err(convert_py_err_to_app_err)
+    }
+}
+
+impl DatabaseInterface for PyDatabase {
+    fn end_transaction(&self) -> Result<()> {
+        self.call_method("end_transaction", ()).map(|_| ())
+    }
+
+    fn start_transaction(&self) -> Result<()> {
+        self.call_method("start_transaction", ()).map(|_| ())
+    }
+
+    fn delete(&self, key: Bytes) -> Result<()> {
+        let gil = Python::acquire_gil();
+        let py_key = PyBytes::new(gil.python(), &key);
+        self.call_method("delete", (py_key,)).map(|_| ())
+    }
+
+    fn get(&self, key: Bytes, sensitivity: DataSensitivity) -> Result<Bytes> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let is_private = sensitivity == DataSensitivity::Private;
+        self.call_method("get", (PyBytes::new(py, &key), is_private))
+            .and_then(|py_value| match py_value.is_none() {
+                true => Err(get_not_in_db_err()),
+                false => py_value
+                    .extract::<&PyBytes>(py)
+                    .map(|py_bytes| py_bytes.as_bytes().to_vec())
+                    .map_err(convert_py_err_to_app_err),
+            })
+    }
+
+    fn put(
+        &self,
+        key: Bytes,
+        value: Bytes,
+        sensitivity: DataSensitivity,
+    ) -> Result<()> {
+        let gil = Python::acquire_gil();
+        let py = gil.python();
+        let is_private = sensitivity == DataSensitivity::Private;
+        self.call_method(
+            "put",
+            (PyBytes::new(py, &key), PyBytes::new(py, &value), is_private),
+        ).map(|_| ())
+    }
+}
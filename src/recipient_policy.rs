@@ -0,0 +1,401 @@
+use std::{
+    str::FromStr,
+    collections::BTreeSet,
+};
+use bitcoin::util::address::Address as BtcAddress;
+use ethereum_types::Address as EthAddress;
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    constants::RECIPIENT_POLICY_KEY,
+    utils::check_hex_is_valid_ethereum_address,
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    skipped_items::{
+        SkipReason,
+        SkippedItem,
+        SkippedItems,
+    },
+    safe_addresses::{
+        SafeAddressTier,
+        get_safe_eth_address_for_tier_from_db,
+        get_safe_btc_address_for_tier_from_db,
+    },
+    eth::{
+        eth_state::EthState,
+        eth_types::RedeemParams,
+    },
+    btc::{
+        btc_state::BtcState,
+        btc_types::{
+            MintingParams,
+            MintingParamStruct,
+        },
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecipientPolicyMode {
+    Blacklist,
+    Whitelist,
+}
+
+impl FromStr for RecipientPolicyMode {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "blacklist" => Ok(RecipientPolicyMode::Blacklist),
+            "whitelist" => Ok(RecipientPolicyMode::Whitelist),
+            _ => Err(AppError::InvalidInput(
+                format!("✘ Unrecognised recipient policy mode: '{}'!", s)
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecipientPolicy {
+    pub mode: RecipientPolicyMode,
+    pub eth_addresses: BTreeSet<String>,
+    pub btc_addresses: BTreeSet<String>,
+}
+
+impl Default for RecipientPolicy {
+    fn default() -> Self {
+        RecipientPolicy {
+            mode: RecipientPolicyMode::Blacklist,
+            eth_addresses: BTreeSet::new(),
+            btc_addresses: BTreeSet::new(),
+        }
+    }
+}
+
+fn normalize_eth_address(eth_address: &EthAddress) -> String {
+    format!("0x{}", hex::encode(eth_address.as_bytes()))
+}
+
+fn normalize_address(chain: &str, address: &str) -> Result<String> {
+    match chain.to_uppercase().as_str() {
+        "ETH" => match check_hex_is_valid_ethereum_address(
+            &address.to_string()
+        ) {
+            true => Ok(address.to_lowercase()),
+            false => Err(AppError::InvalidAddress(
+                format!("✘ Invalid ETH address: '{}'!", address)
+            )),
+        },
+        "BTC" => BtcAddress::from_str(address)
+            .map(|address| address.to_string())
+            .map_err(|e|
+                AppError::InvalidAddress(
+                    format!("✘ Invalid BTC address: {}!", e)
+                )
+            ),
+        _ => Err(AppError::InvalidInput(
+            format!("✘ Unrecognised chain: '{}'!", chain)
+        )),
+    }
+}
+
+impl RecipientPolicy {
+    fn get_addresses_mut(&mut self, chain: &str) -> &mut BTreeSet<String> {
+        match chain.to_uppercase().as_str() {
+            "ETH" => &mut self.eth_addresses,
+            _ => &mut self.btc_addresses,
+        }
+    }
+
+    pub fn add_address(mut self, chain: &str, address: &str) -> Result<Self> {
+        let address = normalize_address(chain, address)?;
+        self.get_addresses_mut(chain).insert(address);
+        Ok(self)
+    }
+
+    pub fn remove_address(
+        mut self,
+        chain: &str,
+        address: &str,
+    ) -> Result<Self> {
+        let address = normalize_address(chain, address)?;
+        match self.get_addresses_mut(chain).remove(&address) {
+            true => Ok(self),
+            false => Err(AppError::InvalidAddress(
+                format!("✘ Address '{}' is not in recipient policy!", address)
+            )),
+        }
+    }
+
+    pub fn with_mode(self, mode: RecipientPolicyMode) -> Self {
+        RecipientPolicy { mode, ..self }
+    }
+
+    fn is_allowed(&self, is_listed: bool) -> bool {
+        match self.mode {
+            RecipientPolicyMode::Blacklist => !is_listed,
+            RecipientPolicyMode::Whitelist => is_listed,
+        }
+    }
+
+    pub fn is_eth_recipient_allowed(&self, eth_address: &EthAddress) -> bool {
+        let address = normalize_eth_address(eth_address);
+        self.is_allowed(self.eth_addresses.contains(&address))
+    }
+
+    // NOTE: Addresses are compared parsed, since the same one has more than
+    // one valid string form, eg. upper & lower case bech32.
+    pub fn is_btc_recipient_allowed(&self, btc_address: &str) -> bool {
+        let is_listed = match BtcAddress::from_str(btc_address) {
+            Err(_) => self.btc_addresses.contains(btc_address),
+            Ok(address) => self.btc_addresses
+                .iter()
+                .filter_map(|listed| BtcAddress::from_str(listed).ok())
+                .any(|listed| listed == address),
+        };
+        self.is_allowed(is_listed)
+    }
+}
+
+pub fn get_recipient_policy_from_db<D>(
+    db: &D,
+) -> Result<Option<RecipientPolicy>>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting recipient policy from db...");
+    match db.get(RECIPIENT_POLICY_KEY.to_vec(), DataSensitivity::Public) {
+        Err(_) => Ok(None),
+        Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+    }
+}
+
+pub fn put_recipient_policy_in_db<D>(
+    db: &D,
+    policy: &RecipientPolicy,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting recipient policy in db...");
+    db.put(
+        RECIPIENT_POLICY_KEY.to_vec(),
+        serde_json::to_vec(policy)?,
+        DataSensitivity::Public,
+    )
+}
+
+fn get_policy_diversion_detail(recipient: &str, safe_address: &str) -> String {
+    format!(
+        "Recipient {} disallowed by policy ∴ diverted to safe address: {}",
+        recipient,
+        safe_address,
+    )
+}
+
+fn apply_recipient_policy_to_minting_params(
+    policy: &RecipientPolicy,
+    minting_params: &[MintingParamStruct],
+    safe_eth_address: &EthAddress,
+) -> (MintingParams, SkippedItems) {
+    let mut skipped_items = vec![];
+    let minting_params = minting_params
+        .iter()
+        .map(|params| {
+            if policy.is_eth_recipient_allowed(&params.eth_address) {
+                return params.clone()
+            };
+            info!("✘ Diverting minting params ∵ recipient disallowed!");
+            let detail = get_policy_diversion_detail(
+                &normalize_eth_address(&params.eth_address),
+                &normalize_eth_address(safe_eth_address),
+            );
+            skipped_items.push(
+                SkippedItem::new(
+                    params.originating_tx_hash.to_string(),
+                    params.amount.to_string(),
+                    SkipReason::DisallowedRecipient,
+                )
+                    .with_correlation_id(&params.correlation_id)
+                    .with_detail(detail)
+            );
+            MintingParamStruct {
+                eth_address: *safe_eth_address,
+                ..params.clone()
+            }
+        })
+        .collect();
+    (minting_params, skipped_items)
+}
+
+fn apply_recipient_policy_to_redeem_params(
+    policy: &RecipientPolicy,
+    redeem_params: &[RedeemParams],
+    safe_btc_address: &str,
+) -> (Vec<RedeemParams>, SkippedItems) {
+    let mut skipped_items = vec![];
+    let redeem_params = redeem_params
+        .iter()
+        .map(|params| {
+            if policy.is_btc_recipient_allowed(&params.recipient) {
+                return params.clone()
+            };
+            info!("✘ Diverting redeem params ∵ recipient disallowed!");
+            let detail = get_policy_diversion_detail(
+                &params.recipient,
+                safe_btc_address,
+            );
+            skipped_items.push(
+                SkippedItem::new(
+                    format!("0x{}", hex::encode(params.originating_tx_hash)),
+                    params.amount.to_string(),
+                    SkipReason::DisallowedRecipient,
+                )
+                    .with_correlation_id(&params.correlation_id)
+                    .with_detail(detail.clone())
+            );
+            RedeemParams {
+                recipient: safe_btc_address.to_string(),
+                recipient_diversion_reason: Some(detail),
+                ..params.clone()
+            }
+        })
+        .collect();
+    (redeem_params, skipped_items)
+}
+
+pub fn maybe_apply_recipient_policy_to_minting_params<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    match get_recipient_policy_from_db(&state.db)? {
+        None => {
+            info!("✔ No recipient policy ∴ not applying to minting params!");
+            Ok(state)
+        }
+        Some(policy) => {
+            info!("✔ Applying recipient policy to minting params...");
+            let (minting_params, skipped_items) =
+                apply_recipient_policy_to_minting_params(
+                    &policy,
+                    &state.minting_params,
+                    &get_safe_eth_address_for_tier_from_db(
+                        &state.db,
+                        SafeAddressTier::PolicyBlocked,
+                    )?,
+                );
+            state
+                .replace_minting_params(minting_params)
+                .and_then(|state| state.add_skipped_items(skipped_items))
+        }
+    }
+}
+
+pub fn maybe_apply_recipient_policy_to_redeem_params<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    match get_recipient_policy_from_db(&state.db)? {
+        None => {
+            info!("✔ No recipient policy ∴ not applying to redeem params!");
+            Ok(state)
+        }
+        Some(policy) => {
+            info!("✔ Applying recipient policy to redeem params...");
+            let (redeem_params, skipped_items) =
+                apply_recipient_policy_to_redeem_params(
+                    &policy,
+                    &state.redeem_params,
+                    &get_safe_btc_address_for_tier_from_db(
+                        &state.db,
+                        SafeAddressTier::PolicyBlocked,
+                    )?,
+                );
+            state
+                .replace_redeem_params(redeem_params)
+                .and_then(|state| state.add_skipped_items(skipped_items))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        btc::btc_test_utils::get_sample_minting_params,
+    };
+
+    const SAMPLE_BTC_ADDRESS: &str = "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM";
+
+    #[test]
+    fn should_add_and_remove_addresses_from_recipient_policy() {
+        let eth_address = "0xEDB86CD455EF3CA43F0E227E00469C3BDFA40628";
+        let policy = RecipientPolicy::default()
+            .add_address("ETH", eth_address)
+            .and_then(|policy| policy.add_address("btc", SAMPLE_BTC_ADDRESS))
+            .unwrap();
+        assert!(policy.eth_addresses.contains(&eth_address.to_lowercase()));
+        assert!(!policy.is_btc_recipient_allowed(SAMPLE_BTC_ADDRESS));
+        let policy = policy.remove_address("BTC", SAMPLE_BTC_ADDRESS).unwrap();
+        assert!(policy.is_btc_recipient_allowed(SAMPLE_BTC_ADDRESS));
+        assert!(policy.remove_address("BTC", SAMPLE_BTC_ADDRESS).is_err());
+    }
+
+    #[test]
+    fn should_disallow_listed_btc_recipient_in_any_case() {
+        let lower_case_address = "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4";
+        let upper_case_address = "BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4";
+        let policy = RecipientPolicy::default()
+            .add_address("BTC", lower_case_address)
+            .unwrap();
+        assert!(!policy.is_btc_recipient_allowed(upper_case_address));
+        let policy = RecipientPolicy::default()
+            .add_address("BTC", upper_case_address)
+            .unwrap();
+        assert!(policy.btc_addresses.contains(lower_case_address));
+        assert!(!policy.is_btc_recipient_allowed(lower_case_address));
+        assert!(policy.remove_address("BTC", lower_case_address).is_ok());
+    }
+
+    #[test]
+    fn should_only_allow_listed_recipients_in_whitelist_mode() {
+        let policy = RecipientPolicy::default()
+            .with_mode(RecipientPolicyMode::Whitelist)
+            .add_address("BTC", SAMPLE_BTC_ADDRESS)
+            .unwrap();
+        assert!(policy.is_btc_recipient_allowed(SAMPLE_BTC_ADDRESS));
+        assert!(!policy.is_eth_recipient_allowed(&EthAddress::zero()));
+    }
+
+    #[test]
+    fn should_divert_minting_params_to_disallowed_recipients() {
+        let db = get_test_database();
+        let minting_params = get_sample_minting_params();
+        let disallowed_address =
+            normalize_eth_address(&minting_params[0].eth_address);
+        let policy = RecipientPolicy::default()
+            .add_address("ETH", &disallowed_address)
+            .unwrap();
+        put_recipient_policy_in_db(&db, &policy).unwrap();
+        let state = BtcState::init(db)
+            .add_minting_params(minting_params.clone())
+            .and_then(maybe_apply_recipient_policy_to_minting_params)
+            .unwrap();
+        let safe_eth_address = get_safe_eth_address_for_tier_from_db(
+            &state.db,
+            SafeAddressTier::PolicyBlocked,
+        ).unwrap();
+        assert_eq!(state.minting_params[0].eth_address, safe_eth_address);
+        assert_eq!(
+            state.minting_params[1].eth_address,
+            minting_params[1].eth_address,
+        );
+        assert_eq!(
+            state.skipped_items[0].reason,
+            SkipReason::DisallowedRecipient,
+        );
+    }
+}
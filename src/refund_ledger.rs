@@ -0,0 +1,667 @@
+use std::str::FromStr;
+use ethereum_types::Address as EthAddress;
+use bitcoin::util::address::Address as BtcAddress;
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    constants::{
+        REFUND_LEDGER_KEY,
+        MINIMUM_REQUIRED_SATOSHIS,
+        PROVISIONAL_REFUND_LEDGER_KEY,
+    },
+    skipped_items::SkipReason,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    safe_addresses::{
+        SafeAddressTier,
+        get_safe_eth_address_for_tier_from_db,
+    },
+    utxo_manager::utxo_database_utils::{
+        remove_utxos_from_db,
+        get_total_number_of_utxos_from_db,
+    },
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    btc::{
+        btc_state::BtcState,
+        btc_constants::BTC_DUST_LIMIT_IN_SATOSHIS,
+        btc_transaction::create_signed_raw_btc_tx_for_n_input_n_outputs,
+        btc_types::{
+            MintingParams,
+            BtcUtxoAndValue,
+            BtcUtxosAndValues,
+            BtcRecipientAndAmount,
+        },
+        btc_utils::{
+            calculate_btc_tx_fee,
+            get_hex_tx_from_signed_btc_tx,
+        },
+        btc_database_utils::{
+            get_btc_fee_from_db,
+            get_btc_address_from_db,
+            get_btc_private_key_from_db,
+            get_btc_canon_block_from_db,
+            get_btc_account_nonce_from_db,
+            increment_btc_account_nonce_in_db,
+        },
+    },
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundStatus {
+    Pending,
+    Approved,
+    Refunded,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RefundLedgerEntry {
+    pub utxo_reference: String,
+    pub amount_in_satoshis: u64,
+    pub reason: SkipReason,
+    pub btc_block_height: u64,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub btc_block_hash: String,
+    pub status: RefundStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refund_btc_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refund_btc_tx_hash: Option<String>,
+    pub utxo_and_value: BtcUtxoAndValue,
+}
+
+impl RefundLedgerEntry {
+    pub fn new(
+        utxo_and_value: &BtcUtxoAndValue,
+        reason: SkipReason,
+        btc_block_height: u64,
+        btc_block_hash: String,
+    ) -> Result<Self> {
+        Ok(
+            RefundLedgerEntry {
+                reason,
+                btc_block_hash,
+                btc_block_height,
+                refund_btc_address: None,
+                refund_btc_tx_hash: None,
+                status: RefundStatus::Pending,
+                utxo_and_value: utxo_and_value.clone(),
+                amount_in_satoshis: utxo_and_value.value,
+                utxo_reference:
+                    utxo_and_value.get_utxo()?.previous_output.to_string(),
+            }
+        )
+    }
+
+    // NOTE: Deposits w/ bad metadata are minted to a safe address ∴ their UTXOs
+    // are saved to the UTXO set, unlike those of dust deposits.
+    fn is_in_utxo_set(&self) -> bool {
+        self.reason != SkipReason::DustAmount
+    }
+}
+
+pub type RefundLedger = Vec<RefundLedgerEntry>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BtcRefundTx {
+    pub btc_tx_hex: String,
+    pub btc_tx_hash: String,
+    pub btc_tx_amount: u64,
+    pub btc_account_nonce: u64,
+    pub btc_tx_recipient: String,
+    pub utxo_reference: String,
+}
+
+fn get_refund_entries_from_db<D>(db: &D, key: &[u8]) -> Result<RefundLedger>
+    where D: DatabaseInterface
+{
+    match db.get(key.to_vec(), DataSensitivity::Public) {
+        Err(_) => Ok(vec![]),
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+    }
+}
+
+fn put_refund_entries_in_db<D>(
+    db: &D,
+    key: &[u8],
+    entries: &[RefundLedgerEntry],
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    db.put(key.to_vec(), serde_json::to_vec(entries)?, DataSensitivity::Public)
+}
+
+pub fn get_refund_ledger_from_db<D>(db: &D) -> Result<RefundLedger>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting refund ledger from db...");
+    get_refund_entries_from_db(db, &REFUND_LEDGER_KEY)
+}
+
+fn put_refund_ledger_in_db<D>(
+    db: &D,
+    ledger: &[RefundLedgerEntry],
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting refund ledger in db...");
+    put_refund_entries_in_db(db, &REFUND_LEDGER_KEY, ledger)
+}
+
+fn get_provisional_refund_entries_from_db<D>(db: &D) -> Result<RefundLedger>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting provisional refund entries from db...");
+    get_refund_entries_from_db(db, &PROVISIONAL_REFUND_LEDGER_KEY)
+}
+
+fn put_provisional_refund_entries_in_db<D>(
+    db: &D,
+    entries: &[RefundLedgerEntry],
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting provisional refund entries in db...");
+    put_refund_entries_in_db(db, &PROVISIONAL_REFUND_LEDGER_KEY, entries)
+}
+
+fn add_entries_to_refund_ledger_in_db<D>(
+    db: &D,
+    entries: RefundLedger,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!("✔ Adding {} entries to refund ledger...", entries.len());
+    let mut ledger = get_refund_ledger_from_db(db)?;
+    entries
+        .into_iter()
+        .for_each(|entry|
+            match ledger
+                .iter()
+                .any(|existing| existing.utxo_reference == entry.utxo_reference)
+            {
+                true => info!(
+                    "✘ Not re-adding {} to refund ledger!",
+                    entry.utxo_reference,
+                ),
+                false => ledger.push(entry),
+            }
+        );
+    put_refund_ledger_in_db(db, &ledger)
+}
+
+fn get_bad_metadata_utxos(
+    utxos_and_values: &[BtcUtxoAndValue],
+    minting_params: &MintingParams,
+    safe_eth_address: &EthAddress,
+) -> Result<BtcUtxosAndValues> {
+    let bad_metadata_tx_hashes = minting_params
+        .iter()
+        .filter(|params| &params.eth_address == safe_eth_address)
+        .map(|params| params.originating_tx_hash.to_string())
+        .collect::<Vec<String>>();
+    utxos_and_values
+        .iter()
+        .filter(|utxo| utxo.value >= MINIMUM_REQUIRED_SATOSHIS)
+        .map(|utxo| Ok((utxo, utxo.get_utxo()?.previous_output.txid)))
+        .collect::<Result<Vec<_>>>()
+        .map(|utxos_and_txids|
+            utxos_and_txids
+                .into_iter()
+                .filter(|(_, txid)|
+                    bad_metadata_tx_hashes.contains(&txid.to_string())
+                )
+                .map(|(utxo, _)| utxo.clone())
+                .collect()
+        )
+}
+
+// NOTE: Must run before the dust UTXOs are filtered out, since they are
+// otherwise discarded & never saved to the UTXO set. Deposits below the
+// minimum are caught here via their dust UTXOs. The entries are provisional
+// until their block becomes canon, in case it's orphaned by a reorg.
+pub fn maybe_record_refundable_utxos_in_db<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe recording refundable UTXOs in db...");
+    let block_and_id = state.get_btc_block_and_id()?;
+    let get_entry = |utxo: &BtcUtxoAndValue, reason: SkipReason|
+        RefundLedgerEntry::new(
+            utxo,
+            reason,
+            block_and_id.height,
+            block_and_id.id.to_string(),
+        );
+    let dust_entries = state.utxos_and_values
+        .iter()
+        .filter(|utxo| utxo.value < MINIMUM_REQUIRED_SATOSHIS)
+        .map(|utxo| get_entry(utxo, SkipReason::DustAmount))
+        .collect::<Result<RefundLedger>>()?;
+    let bad_metadata_entries = get_bad_metadata_utxos(
+        &state.utxos_and_values,
+        &state.minting_params,
+        &get_safe_eth_address_for_tier_from_db(
+            &state.db,
+            SafeAddressTier::MalformedMetadata,
+        )?,
+    )?
+        .iter()
+        .map(|utxo| get_entry(utxo, SkipReason::InvalidRecipient))
+        .collect::<Result<RefundLedger>>()?;
+    let entries = [dust_entries, bad_metadata_entries].concat();
+    if entries.is_empty() {
+        return Ok(state)
+    };
+    info!("✔ Recording {} provisional refund entries...", entries.len());
+    let mut provisional_entries = get_provisional_refund_entries_from_db(
+        &state.db
+    )?;
+    entries
+        .into_iter()
+        .for_each(|entry|
+            if !provisional_entries.contains(&entry) {
+                provisional_entries.push(entry)
+            }
+        );
+    put_provisional_refund_entries_in_db(&state.db, &provisional_entries)
+        .map(|_| state)
+}
+
+// NOTE: Provisional entries from blocks at or below the canon block which
+// aren't in it were orphaned by a reorg & so are dropped.
+pub fn maybe_add_canon_block_refunds_to_refund_ledger<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe adding canon block refunds to refund ledger...");
+    let provisional_entries = get_provisional_refund_entries_from_db(
+        &state.db
+    )?;
+    if provisional_entries.is_empty() {
+        return Ok(state)
+    };
+    let canon_block = get_btc_canon_block_from_db(&state.db)?;
+    let canon_block_hash = canon_block.id.to_string();
+    let (canon_entries, other_entries): (RefundLedger, RefundLedger) =
+        provisional_entries
+            .into_iter()
+            .partition(|entry| entry.btc_block_hash == canon_block_hash);
+    let remaining_entries = other_entries
+        .into_iter()
+        .filter(|entry| match entry.btc_block_height > canon_block.height {
+            true => true,
+            false => {
+                info!("✘ Dropping orphaned refund {}!", entry.utxo_reference);
+                false
+            }
+        })
+        .collect::<RefundLedger>();
+    put_provisional_refund_entries_in_db(&state.db, &remaining_entries)
+        .and_then(|_| match canon_entries.is_empty() {
+            true => Ok(()),
+            false => add_entries_to_refund_ledger_in_db(
+                &state.db,
+                canon_entries,
+            ),
+        })
+        .map(|_| state)
+}
+
+pub fn approve_refund_in_db<D>(
+    db: &D,
+    utxo_reference: &str,
+    refund_btc_address: &str,
+) -> Result<RefundLedgerEntry>
+    where D: DatabaseInterface
+{
+    info!(
+        "✔ Approving refund of {} to BTC address {}...",
+        utxo_reference,
+        refund_btc_address,
+    );
+    BtcAddress::from_str(refund_btc_address).map_err(|e|
+        AppError::InvalidAddress(
+            format!("✘ Invalid refund BTC address: {}!", e)
+        )
+    )?;
+    let mut ledger = get_refund_ledger_from_db(db)?;
+    let entry = ledger
+        .iter_mut()
+        .find(|entry| entry.utxo_reference == utxo_reference)
+        .ok_or_else(|| AppError::NotFound(
+            format!("✘ No refund ledger entry for {}!", utxo_reference)
+        ))?;
+    if entry.status == RefundStatus::Refunded {
+        return Err(AppError::InvalidInput(
+            format!("✘ {} has already been refunded!", utxo_reference)
+        ))
+    };
+    entry.status = RefundStatus::Approved;
+    entry.refund_btc_address = Some(refund_btc_address.to_string());
+    let approved_entry = entry.clone();
+    put_refund_ledger_in_db(db, &ledger).map(|_| approved_entry)
+}
+
+fn remove_utxo_from_utxo_set<D>(
+    db: &D,
+    utxo_and_value: &BtcUtxoAndValue,
+) -> Result<bool>
+    where D: DatabaseInterface
+{
+    let num_utxos_before = get_total_number_of_utxos_from_db(db)?;
+    remove_utxos_from_db(db, &vec![utxo_and_value.clone()])
+        .and_then(|_| get_total_number_of_utxos_from_db(db))
+        .map(|num_utxos_after| num_utxos_after < num_utxos_before)
+}
+
+// NOTE: The caller must wrap this in a db transaction so that the nonces used
+// & the ledger entries marked as refunded are committed together.
+pub fn build_refund_txs_in_db<D>(db: &D) -> Result<Vec<BtcRefundTx>>
+    where D: DatabaseInterface
+{
+    info!("✔ Building BTC refund txs for approved refund ledger entries...");
+    let sats_per_byte = get_btc_fee_from_db(db)?;
+    let btc_address = get_btc_address_from_db(db)?;
+    let fee = calculate_btc_tx_fee(1, 1, sats_per_byte);
+    let mut ledger = get_refund_ledger_from_db(db)?;
+    let mut refund_txs = vec![];
+    for entry in ledger.iter_mut() {
+        if entry.status != RefundStatus::Approved {
+            continue
+        };
+        let btc_tx_amount = entry.amount_in_satoshis.saturating_sub(fee);
+        if btc_tx_amount < BTC_DUST_LIMIT_IN_SATOSHIS {
+            info!(
+                "✘ Not refunding {} ∵ amount after tx fee of {} is dust!",
+                entry.utxo_reference,
+                fee,
+            );
+            continue
+        };
+        let recipient = entry.refund_btc_address.clone().ok_or_else(||
+            AppError::NotFound("✘ Approved refund has no address!".to_string())
+        )?;
+        if entry.is_in_utxo_set() &&
+            !remove_utxo_from_utxo_set(db, &entry.utxo_and_value)?
+        {
+            info!(
+                "✘ Not refunding {} ∵ UTXO is no longer in the UTXO set!",
+                entry.utxo_reference,
+            );
+            continue
+        };
+        let signed_tx = create_signed_raw_btc_tx_for_n_input_n_outputs(
+            sats_per_byte,
+            vec![BtcRecipientAndAmount::new(&recipient, btc_tx_amount)?],
+            &btc_address,
+            get_btc_private_key_from_db(db)?,
+            vec![entry.utxo_and_value.clone()],
+        )?;
+        let btc_tx_hash = signed_tx.txid().to_string();
+        refund_txs.push(
+            BtcRefundTx {
+                btc_tx_amount,
+                btc_tx_hash: btc_tx_hash.clone(),
+                btc_tx_recipient: recipient,
+                utxo_reference: entry.utxo_reference.clone(),
+                btc_account_nonce: get_btc_account_nonce_from_db(db)?,
+                btc_tx_hex: get_hex_tx_from_signed_btc_tx(&signed_tx),
+            }
+        );
+        increment_btc_account_nonce_in_db(db, &1)?;
+        entry.status = RefundStatus::Refunded;
+        entry.refund_btc_tx_hash = Some(btc_tx_hash);
+    }
+    put_refund_ledger_in_db(db, &ledger).map(|_| refund_txs)
+}
+
+pub fn get_refund_ledger<D>(db: D) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting refund ledger...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| get_refund_ledger_from_db(&db))
+        .and_then(|ledger| Ok(serde_json::to_string(&ledger)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::{
+            TestDB,
+            get_test_database,
+        },
+        utxo_manager::utxo_database_utils::{
+            save_utxos_to_db,
+            get_utxo_and_value,
+        },
+        btc::{
+            btc_types::BtcBlockInDbFormat,
+            btc_database_utils::{
+                put_btc_fee_in_db,
+                put_btc_address_in_db,
+                put_btc_private_key_in_db,
+                put_btc_canon_block_in_db,
+                put_btc_account_nonce_in_db,
+            },
+            btc_test_utils::{
+                get_sample_btc_block_n,
+                get_sample_btc_private_key,
+                get_sample_utxo_and_values,
+                SAMPLE_TARGET_BTC_ADDRESS,
+            },
+        },
+    };
+
+    const SAMPLE_REFUND_ADDRESS: &str = "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM";
+
+    fn get_sample_refund_ledger_entries() -> RefundLedger {
+        get_sample_utxo_and_values()[..3]
+            .iter()
+            .map(|utxo|
+                RefundLedgerEntry::new(
+                    utxo,
+                    SkipReason::DustAmount,
+                    1,
+                    "decaf".to_string(),
+                )
+            )
+            .collect::<Result<RefundLedger>>()
+            .unwrap()
+    }
+
+    fn get_db_for_building_refund_txs(fee: u64) -> TestDB {
+        let db = get_test_database();
+        put_btc_fee_in_db(&db, &fee).unwrap();
+        put_btc_account_nonce_in_db(&db, &0).unwrap();
+        put_btc_private_key_in_db(&db, &get_sample_btc_private_key()).unwrap();
+        put_btc_address_in_db(&db, &SAMPLE_TARGET_BTC_ADDRESS.to_string())
+            .unwrap();
+        db
+    }
+
+    fn approve_all_refunds_in_db(db: &TestDB, entries: RefundLedger) {
+        add_entries_to_refund_ledger_in_db(db, entries.clone()).unwrap();
+        entries
+            .iter()
+            .for_each(|entry| {
+                approve_refund_in_db(
+                    db,
+                    &entry.utxo_reference,
+                    SAMPLE_REFUND_ADDRESS,
+                ).unwrap();
+            });
+    }
+
+    #[test]
+    fn should_not_add_duplicate_entries_to_refund_ledger() {
+        let db = get_test_database();
+        let entries = get_sample_refund_ledger_entries();
+        let num_entries = entries.len();
+        add_entries_to_refund_ledger_in_db(&db, entries.clone()).unwrap();
+        add_entries_to_refund_ledger_in_db(&db, entries).unwrap();
+        let result = get_refund_ledger_from_db(&db).unwrap();
+        assert_eq!(result.len(), num_entries);
+        assert!(
+            result.iter().all(|entry| entry.status == RefundStatus::Pending)
+        );
+    }
+
+    #[test]
+    fn should_approve_refund_in_db() {
+        let db = get_test_database();
+        let entries = get_sample_refund_ledger_entries();
+        let utxo_reference = entries[0].utxo_reference.clone();
+        add_entries_to_refund_ledger_in_db(&db, entries).unwrap();
+        let result = approve_refund_in_db(
+            &db,
+            &utxo_reference,
+            SAMPLE_REFUND_ADDRESS,
+        ).unwrap();
+        assert_eq!(result.status, RefundStatus::Approved);
+        let ledger = get_refund_ledger_from_db(&db).unwrap();
+        assert_eq!(ledger[0], result);
+        assert_eq!(ledger[1].status, RefundStatus::Pending);
+        assert!(approve_refund_in_db(&db, "nope", SAMPLE_REFUND_ADDRESS)
+            .is_err());
+        assert!(approve_refund_in_db(&db, &utxo_reference, "bad").is_err());
+    }
+
+    #[test]
+    fn should_build_refund_txs_in_db() {
+        let db = get_db_for_building_refund_txs(23);
+        let entry = get_sample_refund_ledger_entries()[0].clone();
+        approve_all_refunds_in_db(&db, vec![entry.clone()]);
+        let fee = calculate_btc_tx_fee(1, 1, 23);
+        let result = build_refund_txs_in_db(&db).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].btc_account_nonce, 0);
+        assert_eq!(result[0].btc_tx_amount, entry.amount_in_satoshis - fee);
+        assert_eq!(result[0].btc_tx_recipient, SAMPLE_REFUND_ADDRESS);
+        assert_eq!(get_btc_account_nonce_from_db(&db).unwrap(), 1);
+        let ledger = get_refund_ledger_from_db(&db).unwrap();
+        assert_eq!(ledger[0].status, RefundStatus::Refunded);
+        assert_eq!(
+            ledger[0].refund_btc_tx_hash,
+            Some(result[0].btc_tx_hash.clone()),
+        );
+    }
+
+    #[test]
+    fn should_not_build_refund_tx_if_amount_after_fee_is_dust() {
+        let db = get_db_for_building_refund_txs(23);
+        let fee = calculate_btc_tx_fee(1, 1, 23);
+        let mut entry = get_sample_refund_ledger_entries()[0].clone();
+        entry.amount_in_satoshis = fee + 100;
+        approve_all_refunds_in_db(&db, vec![entry]);
+        let result = build_refund_txs_in_db(&db).unwrap();
+        assert!(result.is_empty());
+        assert_eq!(get_btc_account_nonce_from_db(&db).unwrap(), 0);
+        let ledger = get_refund_ledger_from_db(&db).unwrap();
+        assert_eq!(ledger[0].status, RefundStatus::Approved);
+        assert!(ledger[0].refund_btc_tx_hash.is_none());
+    }
+
+    #[test]
+    fn should_remove_bad_metadata_utxo_from_utxo_set_when_refunding() {
+        let db = get_db_for_building_refund_txs(23);
+        let utxos = get_sample_utxo_and_values();
+        let mut entry = get_sample_refund_ledger_entries()[0].clone();
+        entry.reason = SkipReason::InvalidRecipient;
+        save_utxos_to_db(&db, &utxos[..2].to_vec()).unwrap();
+        approve_all_refunds_in_db(&db, vec![entry]);
+        let result = build_refund_txs_in_db(&db).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(get_total_number_of_utxos_from_db(&db).unwrap(), 1);
+        assert_eq!(
+            get_utxo_and_value(&db).unwrap().serialized_utxo,
+            utxos[1].serialized_utxo,
+        );
+    }
+
+    #[test]
+    fn should_not_refund_bad_metadata_utxo_no_longer_in_utxo_set() {
+        let db = get_db_for_building_refund_txs(23);
+        let mut entry = get_sample_refund_ledger_entries()[0].clone();
+        entry.reason = SkipReason::InvalidRecipient;
+        approve_all_refunds_in_db(&db, vec![entry]);
+        let result = build_refund_txs_in_db(&db).unwrap();
+        assert!(result.is_empty());
+        assert_eq!(get_btc_account_nonce_from_db(&db).unwrap(), 0);
+        let ledger = get_refund_ledger_from_db(&db).unwrap();
+        assert_eq!(ledger[0].status, RefundStatus::Approved);
+    }
+
+    #[test]
+    fn should_record_dust_utxos_once_their_block_is_canon() {
+        let db = get_test_database();
+        let block_and_id = get_sample_btc_block_n(5).unwrap();
+        let dust_utxo = get_sample_utxo_and_values()[4].clone();
+        assert!(dust_utxo.value < MINIMUM_REQUIRED_SATOSHIS);
+        let state = BtcState::init(db)
+            .add_btc_block_and_id(block_and_id.clone())
+            .and_then(|state| state.add_utxos_and_values(vec![dust_utxo]))
+            .and_then(maybe_record_refundable_utxos_in_db)
+            .unwrap();
+        assert!(get_refund_ledger_from_db(&state.db).unwrap().is_empty());
+        let provisional_entries = get_provisional_refund_entries_from_db(
+            &state.db
+        ).unwrap();
+        assert_eq!(provisional_entries.len(), 1);
+        assert_eq!(provisional_entries[0].reason, SkipReason::DustAmount);
+        put_btc_canon_block_in_db(
+            &state.db,
+            &BtcBlockInDbFormat::new(
+                block_and_id.height,
+                block_and_id.id,
+                vec![],
+                block_and_id.block,
+                vec![],
+            ).unwrap(),
+        ).unwrap();
+        let state = maybe_add_canon_block_refunds_to_refund_ledger(state)
+            .unwrap();
+        let ledger = get_refund_ledger_from_db(&state.db).unwrap();
+        assert_eq!(ledger.len(), 1);
+        assert_eq!(ledger[0], provisional_entries[0]);
+        assert!(
+            get_provisional_refund_entries_from_db(&state.db)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn should_drop_provisional_refunds_from_orphaned_blocks() {
+        let db = get_test_database();
+        let block_and_id = get_sample_btc_block_n(5).unwrap();
+        let mut entries = get_sample_refund_ledger_entries();
+        entries[0].btc_block_height = block_and_id.height;
+        entries[1].btc_block_height = block_and_id.height + 1;
+        put_provisional_refund_entries_in_db(&db, &entries[..2]).unwrap();
+        put_btc_canon_block_in_db(
+            &db,
+            &BtcBlockInDbFormat::new(
+                block_and_id.height,
+                block_and_id.id,
+                vec![],
+                block_and_id.block,
+                vec![],
+            ).unwrap(),
+        ).unwrap();
+        let state = maybe_add_canon_block_refunds_to_refund_ledger(
+            BtcState::init(db)
+        ).unwrap();
+        assert!(get_refund_ledger_from_db(&state.db).unwrap().is_empty());
+        assert_eq!(
+            get_provisional_refund_entries_from_db(&state.db).unwrap(),
+            vec![entries[1].clone()],
+        );
+    }
+}
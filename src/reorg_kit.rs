@@ -0,0 +1,742 @@
+#![cfg(any(test, feature="reorg-kit"))]
+// NOTE: Builds competing BTC & ETH chains forked from a common ancestor, so
+// that the core's fork choice & reorg handling can be exercised with chains of
+// a chosen length & work rather than whatever the static sample blocks allow.
+use ethereum_types::{
+    U256,
+    Address as EthAddress,
+};
+use bitcoin::{
+    util::hash::BitcoinHash,
+    consensus::encode::serialize as btc_serialize,
+    hashes::{
+        Hash,
+        sha256d,
+    },
+    blockdata::{
+        opcodes,
+        block::{
+            Block as BtcBlock,
+            BlockHeader as BtcBlockHeader,
+        },
+        script::{
+            Script as BtcScript,
+            Builder as BtcScriptBuilder,
+        },
+        transaction::{
+            TxIn as BtcTxIn,
+            TxOut as BtcTxOut,
+            OutPoint as BtcOutPoint,
+            Transaction as BtcTransaction,
+        },
+    },
+};
+use crate::{
+    types::Result,
+    crypto_utils::keccak_hash_bytes,
+    btc::{
+        btc_constants::DEFAULT_BTC_SEQUENCE,
+        btc_utils::get_pay_to_pub_key_hash_script,
+        btc_types::{
+            BtcBlockJson,
+            BtcBlockAndId,
+            BtcBlockAndTxsJson,
+        },
+    },
+    eth::{
+        rlp_codec::rlp_encode_block,
+        eth_types::{
+            EthBlock,
+            EthBlockAndReceipts,
+        },
+    },
+};
+
+// NOTE: The easiest target `nBits` can encode, so blocks are mined in a few
+// hashes. Only accepted off mainnet, where the difficulty check is skipped.
+pub const REORG_KIT_BTC_BITS: u32 = 0x207f_ffff;
+const BTC_BLOCK_INTERVAL_IN_SECONDS: u32 = 600;
+const ETH_BLOCK_INTERVAL_IN_SECONDS: u64 = 15;
+const COINBASE_VALUE_IN_SATOSHIS: u64 = 50 * 100_000_000;
+
+// NOTE: The branch ID is committed to in the coinbase so that sibling blocks
+// at the same height on different branches never share a block ID.
+fn get_coinbase_tx(height: u64, branch_id: u8) -> BtcTransaction {
+    BtcTransaction {
+        version: 1,
+        lock_time: 0,
+        input: vec![
+            BtcTxIn {
+                witness: vec![],
+                sequence: DEFAULT_BTC_SEQUENCE,
+                previous_output: BtcOutPoint::null(),
+                script_sig: BtcScriptBuilder::new()
+                    .push_int(height as i64)
+                    .push_slice(&[branch_id])
+                    .into_script(),
+            }
+        ],
+        output: vec![
+            BtcTxOut {
+                value: COINBASE_VALUE_IN_SATOSHIS,
+                script_pubkey: BtcScript::new(),
+            }
+        ],
+    }
+}
+
+fn mine_btc_block_header(mut header: BtcBlockHeader) -> BtcBlockHeader {
+    while header.validate_pow(&header.target()).is_err() {
+        header.nonce += 1;
+    };
+    header
+}
+
+fn mine_btc_block_at_height(
+    height: u64,
+    time: u32,
+    prev_blockhash: sha256d::Hash,
+    branch_id: u8,
+    txs: Vec<BtcTransaction>,
+) -> BtcBlockAndId {
+    let mut block = BtcBlock {
+        txdata: [vec![get_coinbase_tx(height, branch_id)], txs].concat(),
+        header: BtcBlockHeader {
+            time,
+            nonce: 0,
+            version: 1,
+            prev_blockhash,
+            bits: REORG_KIT_BTC_BITS,
+            merkle_root: sha256d::Hash::hash(&[]),
+        },
+    };
+    block.header.merkle_root = block.merkle_root();
+    block.header = mine_btc_block_header(block.header);
+    BtcBlockAndId::new(
+        height,
+        block.clone(),
+        block.header.bitcoin_hash(),
+        vec![],
+    )
+}
+
+pub fn get_btc_reorg_genesis_block(height: u64) -> BtcBlockAndId {
+    mine_btc_block_at_height(
+        height,
+        1_231_006_505,
+        sha256d::Hash::from_slice(&[0u8; 32])
+            .expect("✘ 32 bytes should make a valid hash!"),
+        0,
+        vec![],
+    )
+}
+
+pub fn mine_btc_block(
+    parent: &BtcBlockAndId,
+    branch_id: u8,
+    txs: Vec<BtcTransaction>,
+) -> BtcBlockAndId {
+    mine_btc_block_at_height(
+        parent.height + 1,
+        parent.block.header.time + BTC_BLOCK_INTERVAL_IN_SECONDS,
+        parent.id,
+        branch_id,
+        txs,
+    )
+}
+
+// NOTE: The core follows the highest BTC tip rather than the one with the most
+// work, so a BTC branch is weighted by its length alone.
+pub fn get_btc_fork_with_txs(
+    ancestor: &BtcBlockAndId,
+    branch_id: u8,
+    txs_per_block: Vec<Vec<BtcTransaction>>,
+) -> Vec<BtcBlockAndId> {
+    txs_per_block
+        .into_iter()
+        .fold(Vec::<BtcBlockAndId>::new(), |mut fork, txs| {
+            let block = mine_btc_block(
+                fork.last().unwrap_or(ancestor),
+                branch_id,
+                txs,
+            );
+            fork.push(block);
+            fork
+        })
+}
+
+pub fn get_btc_fork(
+    ancestor: &BtcBlockAndId,
+    branch_id: u8,
+    length: usize,
+) -> Vec<BtcBlockAndId> {
+    get_btc_fork_with_txs(ancestor, branch_id, vec![vec![]; length])
+}
+
+// NOTE: The input carries a witness so the spender is not parsed from it, &
+// the salt seeds the spent outpoint so that otherwise equal deposits differ.
+pub fn get_op_return_deposit_tx(
+    core_btc_address: &str,
+    recipient: &EthAddress,
+    amount_in_satoshis: u64,
+    salt: u8,
+) -> Result<BtcTransaction> {
+    Ok(
+        BtcTransaction {
+            version: 1,
+            lock_time: 0,
+            input: vec![
+                BtcTxIn {
+                    script_sig: BtcScript::new(),
+                    sequence: DEFAULT_BTC_SEQUENCE,
+                    witness: vec![vec![0u8; 72]],
+                    previous_output: BtcOutPoint {
+                        vout: 0,
+                        txid: sha256d::Hash::hash(&[salt]),
+                    },
+                }
+            ],
+            output: vec![
+                BtcTxOut {
+                    value: amount_in_satoshis,
+                    script_pubkey: get_pay_to_pub_key_hash_script(
+                        core_btc_address
+                    )?,
+                },
+                BtcTxOut {
+                    value: 0,
+                    script_pubkey: BtcScriptBuilder::new()
+                        .push_opcode(opcodes::all::OP_RETURN)
+                        .push_slice(recipient.as_bytes())
+                        .into_script(),
+                },
+            ],
+        }
+    )
+}
+
+pub fn get_btc_submission_json(block_and_id: &BtcBlockAndId) -> Result<String> {
+    let header = &block_and_id.block.header;
+    Ok(
+        serde_json::to_string(
+            &BtcBlockAndTxsJson {
+                host_timestamp: None,
+                host_eth_gas_price: None,
+                deposit_address_list: vec![],
+                block: BtcBlockJson {
+                    bits: header.bits,
+                    nonce: header.nonce,
+                    version: header.version,
+                    timestamp: header.time,
+                    height: block_and_id.height,
+                    id: block_and_id.id.to_string(),
+                    merkle_root: header.merkle_root.to_string(),
+                    previousblockhash: header.prev_blockhash.to_string(),
+                },
+                transactions: block_and_id.block.txdata
+                    .iter()
+                    .map(|tx| hex::encode(btc_serialize(tx)))
+                    .collect(),
+            }
+        )?
+    )
+}
+
+// NOTE: ETH fork blocks carry valid header hashes & accumulate difficulty, but
+// no receipts & no proof-of-work, so are for exercising fork choice & reorg
+// handling beneath block validation.
+fn get_eth_child_block(
+    parent: &EthBlock,
+    branch_id: u8,
+    difficulty: U256,
+) -> Result<EthBlock> {
+    let block = EthBlock {
+        difficulty,
+        parent_hash: parent.hash,
+        extra_data: vec![branch_id],
+        number: parent.number + 1,
+        total_difficulty: parent.total_difficulty + difficulty,
+        timestamp: parent.timestamp + ETH_BLOCK_INTERVAL_IN_SECONDS,
+        ..parent.clone()
+    };
+    rlp_encode_block(&block)
+        .map(keccak_hash_bytes)
+        .map(|hash| EthBlock { hash, ..block })
+}
+
+pub fn get_eth_fork(
+    ancestor: &EthBlockAndReceipts,
+    branch_id: u8,
+    length: usize,
+    difficulty_per_block: U256,
+) -> Result<Vec<EthBlockAndReceipts>> {
+    let mut fork: Vec<EthBlockAndReceipts> = Vec::with_capacity(length);
+    for _ in 0..length {
+        let block = get_eth_child_block(
+            fork
+                .last()
+                .map(|block_and_receipts| &block_and_receipts.block)
+                .unwrap_or(&ancestor.block),
+            branch_id,
+            difficulty_per_block,
+        )?;
+        fork.push(EthBlockAndReceipts { block, receipts: vec![] });
+    };
+    Ok(fork)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::read_to_string;
+    use crate::{
+        simulation::Simulation,
+        traits::DatabaseInterface,
+        test_utils::{
+            TestDB,
+            get_test_database,
+        },
+        processed_tx_ledger::{
+            eth_redeem_has_been_processed,
+            btc_deposit_has_been_processed,
+            put_processed_eth_redeem_in_db,
+        },
+        utxo_manager::utxo_database_utils::{
+            get_total_utxo_balance_from_db,
+            get_total_number_of_utxos_from_db,
+        },
+        btc::btc_database_utils::{
+            put_btc_account_nonce_in_db,
+            get_btc_account_nonce_from_db,
+            get_btc_private_key_from_db,
+            get_btc_canon_block_hash_from_db,
+            get_btc_latest_block_hash_from_db,
+        },
+        eth::{
+            eth_state::EthState,
+            validate_block::validate_block_header,
+            filter_processed_redeems::maybe_filter_processed_redeems_in_state,
+            handle_eth_reorg::maybe_handle_eth_reorg,
+            update_latest_block_hash::maybe_update_latest_block_hash,
+            update_eth_canon_block_hash::maybe_update_eth_canon_block_hash,
+            eth_test_utils::get_sequential_eth_blocks_and_receipts,
+            eth_types::{
+                EthHash,
+                RedeemParams,
+                EthRedeemInfo,
+                EthRedeemRecord,
+            },
+            add_block_and_receipts_to_database::{
+                maybe_add_block_and_receipts_to_db_and_return_state,
+            },
+            eth_database_utils::{
+                put_eth_tail_block_in_db,
+                put_eth_canon_block_in_db,
+                put_eth_anchor_block_in_db,
+                put_eth_latest_block_in_db,
+                put_eth_redeem_record_in_db,
+                get_eth_canon_block_hash_from_db,
+                put_eth_block_and_receipts_in_db,
+                get_eth_latest_block_hash_from_db,
+                put_eth_canon_to_tip_length_in_db,
+                maybe_get_eth_redeem_record_from_db,
+            },
+        },
+    };
+
+    const BTC_GENESIS_HEIGHT: u64 = 1_000;
+    const DEPOSIT_AMOUNT_IN_SATOSHIS: u64 = 1_337_000;
+    const ETH_BLOCKS_PATH_PREFIX: &str =
+        "src/eth/eth_test_utils/sequential_block_and_receipts_jsons/";
+    const ETH_INIT_BLOCK_NUMBER: u64 = 8065750;
+
+    fn get_eth_init_block_json() -> String {
+        read_to_string(format!(
+            "{}eth_block_and_receipts_num_{}.json",
+            ETH_BLOCKS_PATH_PREFIX,
+            ETH_INIT_BLOCK_NUMBER,
+        )).unwrap()
+    }
+
+    fn get_initialized_simulation(genesis: &BtcBlockAndId) -> Simulation {
+        let mut simulation = Simulation::new();
+        simulation
+            .init_btc(
+                &get_btc_submission_json(genesis).unwrap(),
+                23,
+                1,
+                "Testnet",
+                1,
+            )
+            .and_then(|_|
+                simulation.init_eth(
+                    &get_eth_init_block_json(),
+                    1,
+                    20_000_000_000,
+                    2,
+                )
+            )
+            .unwrap();
+        simulation
+    }
+
+    fn submit_btc_blocks(
+        simulation: &mut Simulation,
+        blocks: &[BtcBlockAndId],
+    ) {
+        blocks
+            .iter()
+            .for_each(|block|
+                get_btc_submission_json(block)
+                    .and_then(|json| simulation.submit_btc_block(&json))
+                    .map(|_| ())
+                    .unwrap()
+            )
+    }
+
+    // NOTE: The losing branch confirms the deposit first, then the longer
+    // winning branch re-mines it one block later & overtakes its canon block.
+    fn run_btc_reorg_with_remined_deposit()
+        -> (Simulation, BtcTransaction, Vec<BtcBlockAndId>)
+    {
+        let genesis = get_btc_reorg_genesis_block(BTC_GENESIS_HEIGHT);
+        let mut simulation = get_initialized_simulation(&genesis);
+        let deposit = get_op_return_deposit_tx(
+            &get_btc_private_key_from_db(simulation.get_db())
+                .unwrap()
+                .to_p2pkh_btc_address(),
+            &EthAddress::from_slice(&[0xde; 20]),
+            DEPOSIT_AMOUNT_IN_SATOSHIS,
+            1,
+        ).unwrap();
+        let losing_branch = get_btc_fork_with_txs(
+            &genesis,
+            1,
+            vec![vec![deposit.clone()], vec![]],
+        );
+        let winning_branch = get_btc_fork_with_txs(
+            &genesis,
+            2,
+            vec![vec![], vec![deposit.clone()], vec![]],
+        );
+        submit_btc_blocks(&mut simulation, &losing_branch);
+        submit_btc_blocks(&mut simulation, &winning_branch);
+        (simulation, deposit, winning_branch)
+    }
+
+    fn submit_eth_block_to_fork_choice_stages<D>(
+        db: D,
+        block_and_receipts: &EthBlockAndReceipts,
+    ) -> EthState<D>
+        where D: DatabaseInterface
+    {
+        EthState::init(db)
+            .add_eth_block_and_receipts(block_and_receipts.clone())
+            .and_then(maybe_add_block_and_receipts_to_db_and_return_state)
+            .and_then(maybe_update_latest_block_hash)
+            .and_then(maybe_handle_eth_reorg)
+            .and_then(maybe_update_eth_canon_block_hash)
+            .unwrap()
+    }
+
+    fn get_redeem_record(
+        block_and_receipts: &EthBlockAndReceipts,
+        redeem_tx_hash: &EthHash,
+    ) -> EthRedeemRecord {
+        EthRedeemRecord {
+            block_hash: format!(
+                "0x{}",
+                hex::encode(block_and_receipts.block.hash.as_bytes()),
+            ),
+            block_number: block_and_receipts.block.number.as_u64(),
+            btc_tx_hashes: vec!["some-btc-tx-hash".to_string()],
+            redeems: vec![
+                EthRedeemInfo {
+                    amount: "1337".to_string(),
+                    from: "some-eth-address".to_string(),
+                    recipient: "some-btc-address".to_string(),
+                    originating_tx_hash: format!(
+                        "0x{}",
+                        hex::encode(redeem_tx_hash.as_bytes()),
+                    ),
+                    correlation_id: String::new(),
+                },
+            ],
+            spent_utxos: vec![],
+            change_utxos: vec![],
+        }
+    }
+
+    fn put_processed_redeem_record_in_db<D>(
+        db: &D,
+        block_and_receipts: &EthBlockAndReceipts,
+        redeem_tx_hash: &EthHash,
+    )
+        where D: DatabaseInterface
+    {
+        put_eth_redeem_record_in_db(
+            db,
+            &block_and_receipts.block.hash,
+            &get_redeem_record(block_and_receipts, redeem_tx_hash),
+        ).unwrap();
+        put_processed_eth_redeem_in_db(db, redeem_tx_hash).unwrap();
+    }
+
+    fn get_eth_db_with_ancestor(ancestor: &EthBlockAndReceipts) -> TestDB {
+        let db = get_test_database();
+        put_eth_block_and_receipts_in_db(&db, ancestor).unwrap();
+        put_eth_canon_to_tip_length_in_db(&db, &1).unwrap();
+        put_eth_tail_block_in_db(&db, ancestor).unwrap();
+        put_eth_canon_block_in_db(&db, ancestor).unwrap();
+        put_eth_anchor_block_in_db(&db, ancestor).unwrap();
+        put_eth_latest_block_in_db(&db, ancestor).unwrap();
+        put_btc_account_nonce_in_db(&db, &1).unwrap();
+        db
+    }
+
+    #[test]
+    fn should_mine_linked_btc_forks_from_common_ancestor() {
+        let genesis = get_btc_reorg_genesis_block(BTC_GENESIS_HEIGHT);
+        let fork_1 = get_btc_fork(&genesis, 1, 3);
+        let fork_2 = get_btc_fork(&genesis, 2, 3);
+        vec![&fork_1, &fork_2]
+            .iter()
+            .for_each(|fork| {
+                assert_eq!(fork.len(), 3);
+                assert_eq!(fork[0].block.header.prev_blockhash, genesis.id);
+                fork
+                    .iter()
+                    .enumerate()
+                    .for_each(|(i, block_and_id)| {
+                        let header = &block_and_id.block.header;
+                        assert!(header.validate_pow(&header.target()).is_ok());
+                        assert!(block_and_id.block.check_merkle_root());
+                        assert_eq!(header.bitcoin_hash(), block_and_id.id);
+                        assert_eq!(
+                            block_and_id.height,
+                            BTC_GENESIS_HEIGHT + i as u64 + 1,
+                        );
+                    });
+                fork
+                    .windows(2)
+                    .for_each(|pair|
+                        assert_eq!(
+                            pair[1].block.header.prev_blockhash,
+                            pair[0].id,
+                        )
+                    );
+            });
+        fork_1
+            .iter()
+            .zip(fork_2.iter())
+            .for_each(|(block_1, block_2)| assert_ne!(block_1.id, block_2.id));
+    }
+
+    #[test]
+    fn should_build_eth_fork_with_valid_hashes_and_accumulated_difficulty() {
+        let ancestor = get_sequential_eth_blocks_and_receipts()[0].clone();
+        let difficulty = U256::from(1337);
+        let fork = get_eth_fork(&ancestor, 1, 3, difficulty).unwrap();
+        let sibling = get_eth_fork(&ancestor, 2, 3, difficulty).unwrap();
+        assert_eq!(fork[0].block.parent_hash, ancestor.block.hash);
+        assert_ne!(fork[0].block.hash, sibling[0].block.hash);
+        fork
+            .iter()
+            .enumerate()
+            .for_each(|(i, block_and_receipts)| {
+                let block = &block_and_receipts.block;
+                assert!(validate_block_header(block).unwrap());
+                assert_eq!(
+                    block.number,
+                    ancestor.block.number + U256::from(i + 1),
+                );
+                assert_eq!(
+                    block.total_difficulty,
+                    ancestor.block.total_difficulty +
+                        difficulty * U256::from(i + 1),
+                );
+            });
+    }
+
+    #[test]
+    fn should_follow_longer_btc_branch_after_reorg() {
+        let (simulation, _, winning_branch) =
+            run_btc_reorg_with_remined_deposit();
+        assert_eq!(
+            get_btc_latest_block_hash_from_db(simulation.get_db()).unwrap(),
+            winning_branch[2].id,
+        );
+        assert_eq!(
+            get_btc_canon_block_hash_from_db(simulation.get_db()).unwrap(),
+            winning_branch[1].id,
+        );
+    }
+
+    #[test]
+    fn should_not_double_mint_deposit_remined_after_btc_reorg() {
+        let (simulation, deposit, _) = run_btc_reorg_with_remined_deposit();
+        let snapshots = simulation.get_snapshots();
+        assert!(
+            btc_deposit_has_been_processed(simulation.get_db(), &deposit.txid())
+        );
+        assert_eq!(
+            snapshots[snapshots.len() - 1].eth_account_nonce,
+            snapshots[0].eth_account_nonce + 1,
+        );
+    }
+
+    #[test]
+    fn should_not_duplicate_utxo_of_deposit_remined_after_btc_reorg() {
+        let (simulation, _, _) = run_btc_reorg_with_remined_deposit();
+        assert_eq!(
+            get_total_number_of_utxos_from_db(simulation.get_db()).unwrap(),
+            1,
+        );
+        assert_eq!(
+            get_total_utxo_balance_from_db(simulation.get_db()).unwrap(),
+            DEPOSIT_AMOUNT_IN_SATOSHIS,
+        );
+    }
+
+    #[test]
+    fn heavier_shorter_eth_fork_should_report_only_orphaned_redeems() {
+        let ancestor = get_sequential_eth_blocks_and_receipts()[0].clone();
+        let light_fork = get_eth_fork(&ancestor, 1, 3, U256::from(1)).unwrap();
+        let heavy_fork = get_eth_fork(&ancestor, 2, 2, U256::from(10)).unwrap();
+        let ancestor_redeem_tx_hash = EthHash::random();
+        let orphaned_redeem_tx_hash = EthHash::random();
+        let db = get_eth_db_with_ancestor(&ancestor);
+        put_processed_redeem_record_in_db(
+            &db,
+            &ancestor,
+            &ancestor_redeem_tx_hash,
+        );
+        let db = light_fork
+            .iter()
+            .fold(db, |db, block|
+                submit_eth_block_to_fork_choice_stages(db, block).db
+            );
+        put_processed_redeem_record_in_db(
+            &db,
+            &light_fork[0],
+            &orphaned_redeem_tx_hash,
+        );
+        let state = submit_eth_block_to_fork_choice_stages(db, &heavy_fork[0]);
+        let report = state.eth_reorg_report.get().unwrap().clone();
+        assert_eq!(
+            get_eth_canon_block_hash_from_db(&state.db).unwrap(),
+            ancestor.block.hash,
+        );
+        let state = submit_eth_block_to_fork_choice_stages(
+            state.db,
+            &heavy_fork[1],
+        );
+        assert!(!state.eth_reorg_report.is_filled());
+        assert_eq!(
+            get_eth_latest_block_hash_from_db(&state.db).unwrap(),
+            heavy_fork[1].block.hash,
+        );
+        assert_eq!(
+            get_eth_canon_block_hash_from_db(&state.db).unwrap(),
+            heavy_fork[0].block.hash,
+        );
+        assert_eq!(report.orphaned_block_hashes.len(), 2);
+        assert_eq!(
+            report.orphaned_redeems,
+            vec![get_redeem_record(&light_fork[0], &orphaned_redeem_tx_hash)],
+        );
+        assert!(eth_redeem_has_been_processed(
+            &state.db,
+            &orphaned_redeem_tx_hash,
+        ));
+        assert!(eth_redeem_has_been_processed(
+            &state.db,
+            &ancestor_redeem_tx_hash,
+        ));
+        assert!(
+            maybe_get_eth_redeem_record_from_db(
+                &state.db,
+                &ancestor.block.hash,
+            ).is_some()
+        );
+    }
+
+    // NOTE: The redeem paid out from the light fork is re-mined in the heavy
+    // one, whose redeem params then pass through the processed redeem filter
+    // just as they would in the ETH submission pipeline.
+    #[test]
+    fn should_not_pay_out_redeem_remined_after_eth_reorg_twice() {
+        let ancestor = get_sequential_eth_blocks_and_receipts()[0].clone();
+        let light_fork = get_eth_fork(&ancestor, 1, 2, U256::from(1)).unwrap();
+        let heavy_fork = get_eth_fork(&ancestor, 2, 2, U256::from(10)).unwrap();
+        let redeem_tx_hash = EthHash::random();
+        let db = light_fork
+            .iter()
+            .fold(get_eth_db_with_ancestor(&ancestor), |db, block|
+                submit_eth_block_to_fork_choice_stages(db, block).db
+            );
+        put_processed_redeem_record_in_db(&db, &light_fork[0], &redeem_tx_hash);
+        let nonce_before = get_btc_account_nonce_from_db(&db).unwrap();
+        let state = heavy_fork
+            .iter()
+            .fold(EthState::init(db), |state, block|
+                submit_eth_block_to_fork_choice_stages(state.db, block)
+            );
+        assert_eq!(
+            get_eth_canon_block_hash_from_db(&state.db).unwrap(),
+            heavy_fork[0].block.hash,
+        );
+        let state = state
+            .add_redeem_params(vec![
+                RedeemParams::new(
+                    U256::from(1337),
+                    EthAddress::random(),
+                    "some-btc-address".to_string(),
+                    redeem_tx_hash,
+                ),
+            ])
+            .and_then(maybe_filter_processed_redeems_in_state)
+            .unwrap();
+        assert!(state.redeem_params.is_empty());
+        assert!(eth_redeem_has_been_processed(&state.db, &redeem_tx_hash));
+        assert_eq!(
+            get_btc_account_nonce_from_db(&state.db).unwrap(),
+            nonce_before,
+        );
+    }
+
+    #[test]
+    fn lighter_longer_eth_fork_should_not_displace_canon_block() {
+        let ancestor = get_sequential_eth_blocks_and_receipts()[0].clone();
+        let heavy_fork = get_eth_fork(&ancestor, 1, 2, U256::from(10)).unwrap();
+        let light_fork = get_eth_fork(&ancestor, 2, 3, U256::from(1)).unwrap();
+        let redeem_tx_hash = EthHash::random();
+        let db = heavy_fork
+            .iter()
+            .fold(get_eth_db_with_ancestor(&ancestor), |db, block|
+                submit_eth_block_to_fork_choice_stages(db, block).db
+            );
+        put_processed_redeem_record_in_db(
+            &db,
+            &heavy_fork[0],
+            &redeem_tx_hash,
+        );
+        let (db, reorg_flags) = light_fork
+            .iter()
+            .fold((db, Vec::new()), |(db, mut reorg_flags), block| {
+                let state = submit_eth_block_to_fork_choice_stages(db, block);
+                reorg_flags.push(state.eth_reorg_report.is_filled());
+                (state.db, reorg_flags)
+            });
+        assert!(reorg_flags.iter().all(|is_reorg| !is_reorg));
+        assert_eq!(
+            get_eth_latest_block_hash_from_db(&db).unwrap(),
+            heavy_fork[1].block.hash,
+        );
+        assert_eq!(
+            get_eth_canon_block_hash_from_db(&db).unwrap(),
+            heavy_fork[0].block.hash,
+        );
+        assert!(eth_redeem_has_been_processed(&db, &redeem_tx_hash));
+    }
+}
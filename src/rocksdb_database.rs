@@ -0,0 +1,128 @@
+#![cfg(feature="rocksdb")]
+use rocksdb::{
+    DB,
+    Direction,
+    WriteBatch,
+    IteratorMode,
+};
+use crate::{
+    errors::AppError,
+    utils::get_not_in_db_err,
+    types::{
+        Bytes,
+        Result,
+        DataSensitivity,
+    },
+    traits::{
+        DatabaseWrite,
+        DatabaseInterface,
+    },
+};
+
+fn convert_rocksdb_error(err: rocksdb::Error) -> AppError {
+    AppError::DatabaseError(format!("✘ RocksDB Error!\n✘ {}", err))
+}
+
+pub struct RocksDbDatabase(DB);
+
+impl RocksDbDatabase {
+    pub fn open(path: &str) -> Result<Self> {
+        info!("✔ Opening RocksDB database at path: {}", path);
+        DB::open_default(path)
+            .map(RocksDbDatabase)
+            .map_err(convert_rocksdb_error)
+    }
+}
+
+impl DatabaseInterface for RocksDbDatabase {
+    // NOTE: Writes are atomic via `write_batch`, which the pipelines use.
+    fn end_transaction(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn start_transaction(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn put(
+        &self,
+        key: Bytes,
+        value: Bytes,
+        _sensitivity: DataSensitivity,
+    ) -> Result<()> {
+        self.0.put(key, value).map_err(convert_rocksdb_error)
+    }
+
+    fn delete(&self, key: Bytes) -> Result<()> {
+        self.0.delete(key).map_err(convert_rocksdb_error)
+    }
+
+    fn get(&self, key: Bytes, _sensitivity: DataSensitivity) -> Result<Bytes> {
+        match self.0.get(key).map_err(convert_rocksdb_error)? {
+            Some(value) => Ok(value.to_vec()),
+            None => Err(get_not_in_db_err())
+        }
+    }
+
+    fn write_batch(&self, batch: Vec<DatabaseWrite>) -> Result<()> {
+        trace!("✔ Writing batch of {} writes to RocksDB...", batch.len());
+        let mut write_batch = WriteBatch::default();
+        batch
+            .into_iter()
+            .map(|write| match write {
+                DatabaseWrite::Delete(key) => write_batch.delete(key),
+                DatabaseWrite::Put(key, value, _) =>
+                    write_batch.put(key, value),
+            })
+            .collect::<std::result::Result<Vec<()>, rocksdb::Error>>()
+            .and_then(|_| self.0.write(write_batch))
+            .map_err(convert_rocksdb_error)
+    }
+
+    fn get_keys_with_prefix(&self, prefix: Bytes) -> Result<Vec<Bytes>> {
+        Ok(
+            self.0
+                .iterator(IteratorMode::From(&prefix, Direction::Forward))
+                .map(|(key, _)| key.to_vec())
+                .take_while(|key| key.starts_with(&prefix))
+                .collect()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_test_rocksdb_database(name: &str) -> RocksDbDatabase {
+        let path = std::env::temp_dir().join(format!("pbtc-core-{}", name));
+        let _ = DB::destroy(&rocksdb::Options::default(), &path);
+        RocksDbDatabase::open(path.to_str().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn should_put_get_and_delete_in_rocksdb_database() {
+        let sensitivity = DataSensitivity::Public;
+        let db = get_test_rocksdb_database("put-get-delete");
+        let key = vec![0xc0, 0xff, 0xee];
+        let value = vec![0xde, 0xca, 0xf0];
+        db.put(key.clone(), value.clone(), sensitivity).unwrap();
+        assert_eq!(db.get(key.clone(), sensitivity).unwrap(), value);
+        db.delete(key.clone()).unwrap();
+        assert!(db.get(key, sensitivity).is_err());
+    }
+
+    #[test]
+    fn should_write_batch_and_get_keys_with_prefix_in_rocksdb_database() {
+        let sensitivity = DataSensitivity::Public;
+        let db = get_test_rocksdb_database("batch-prefix");
+        db.put(vec![0xc0, 0x01], vec![], sensitivity).unwrap();
+        db.write_batch(vec![
+            DatabaseWrite::Put(vec![0xc0, 0x02], vec![], sensitivity),
+            DatabaseWrite::Put(vec![0xde, 0x01], vec![], sensitivity),
+            DatabaseWrite::Delete(vec![0xc0, 0x01]),
+        ]).unwrap();
+        let result = db.get_keys_with_prefix(vec![0xc0]).unwrap();
+        assert_eq!(result, vec![vec![0xc0, 0x02]]);
+    }
+}
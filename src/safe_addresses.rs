@@ -0,0 +1,490 @@
+use std::{
+    str::FromStr,
+    collections::BTreeMap,
+};
+use ethereum_types::Address as EthAddress;
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    host_clock::get_unix_timestamp,
+    utils::check_hex_is_valid_ethereum_address,
+    eth::parse_redeem_params::validate_btc_address,
+    btc::btc_database_utils::get_btc_network_from_db,
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    constants::{
+        SAFE_ETH_ADDRESS,
+        ETH_ADDRESS_LENGTH,
+        SAFE_BTC_ADDRESS,
+        SAFE_ETH_ADDRESS_KEY,
+        SAFE_BTC_ADDRESS_KEY,
+        SAFE_ADDRESS_TIERS_KEY,
+        SAFE_ADDRESS_HISTORY_KEY,
+    },
+};
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize
+)]
+#[serde(rename_all = "snake_case")]
+pub enum SafeAddressTier {
+    MalformedMetadata,
+    PolicyBlocked,
+    Oversize,
+}
+
+impl FromStr for SafeAddressTier {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "malformed_metadata" => Ok(SafeAddressTier::MalformedMetadata),
+            "policy_blocked" => Ok(SafeAddressTier::PolicyBlocked),
+            "oversize" => Ok(SafeAddressTier::Oversize),
+            _ => Err(AppError::InvalidInput(
+                format!("✘ Unrecognised safe address tier: '{}'!", s)
+            )),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SafeAddressTiers {
+    pub eth: BTreeMap<SafeAddressTier, String>,
+    pub btc: BTreeMap<SafeAddressTier, String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TieredSafeAddresses<T> {
+    pub malformed_metadata: T,
+    pub policy_blocked: T,
+    pub oversize: T,
+}
+
+impl<T: Clone> TieredSafeAddresses<T> {
+    pub fn new(safe_address: T) -> Self {
+        TieredSafeAddresses {
+            oversize: safe_address.clone(),
+            policy_blocked: safe_address.clone(),
+            malformed_metadata: safe_address,
+        }
+    }
+
+    pub fn get(&self, tier: SafeAddressTier) -> &T {
+        match tier {
+            SafeAddressTier::MalformedMetadata => &self.malformed_metadata,
+            SafeAddressTier::PolicyBlocked => &self.policy_blocked,
+            SafeAddressTier::Oversize => &self.oversize,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SafeAddressUpdate {
+    pub chain: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tier: Option<SafeAddressTier>,
+    pub timestamp: u64,
+    pub new_address: String,
+    pub previous_address: String,
+}
+
+pub fn get_safe_eth_address_from_db<D>(db: &D) -> Result<EthAddress>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting safe ETH address from db...");
+    match db.get(SAFE_ETH_ADDRESS_KEY.to_vec(), DataSensitivity::Public) {
+        Ok(bytes) => match bytes.len() == ETH_ADDRESS_LENGTH {
+            true => Ok(EthAddress::from_slice(&bytes)),
+            false => Err(AppError::InvalidAddress(format!(
+                "✘ Safe ETH address in db has invalid length of {}!",
+                bytes.len(),
+            ))),
+        },
+        Err(_) => {
+            trace!("✔ No safe ETH address in db ∴ using default!");
+            Ok(EthAddress::from_slice(&SAFE_ETH_ADDRESS))
+        }
+    }
+}
+
+pub fn get_safe_btc_address_from_db<D>(db: &D) -> Result<String>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting safe BTC address from db...");
+    match db.get(SAFE_BTC_ADDRESS_KEY.to_vec(), DataSensitivity::Public) {
+        Ok(bytes) => Ok(String::from_utf8(bytes).map_err(|e|
+            AppError::InvalidAddress(
+                format!("✘ Invalid safe BTC address: {}!", e)
+            )
+        )?),
+        Err(_) => {
+            trace!("✔ No safe BTC address in db ∴ using default!");
+            Ok(SAFE_BTC_ADDRESS.to_string())
+        }
+    }
+}
+
+pub fn get_safe_address_tiers_from_db<D>(db: &D) -> Result<SafeAddressTiers>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting safe address tiers from db...");
+    match db.get(SAFE_ADDRESS_TIERS_KEY.to_vec(), DataSensitivity::Public) {
+        Err(_) => Ok(SafeAddressTiers::default()),
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+    }
+}
+
+// NOTE: A tier with no address of its own falls back to the default safe
+// address, so an unconfigured core routes every failure class as before.
+pub fn get_safe_eth_address_for_tier_from_db<D>(
+    db: &D,
+    tier: SafeAddressTier,
+) -> Result<EthAddress>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting {:?} tier safe ETH address from db...", tier);
+    match get_safe_address_tiers_from_db(db)?.eth.get(&tier) {
+        Some(address) => Ok(EthAddress::from_slice(
+            &hex::decode(address.trim_start_matches("0x"))?
+        )),
+        None => get_safe_eth_address_from_db(db),
+    }
+}
+
+pub fn get_safe_btc_address_for_tier_from_db<D>(
+    db: &D,
+    tier: SafeAddressTier,
+) -> Result<String>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting {:?} tier safe BTC address from db...", tier);
+    match get_safe_address_tiers_from_db(db)?.btc.get(&tier) {
+        Some(address) => Ok(address.clone()),
+        None => get_safe_btc_address_from_db(db),
+    }
+}
+
+pub fn get_tiered_safe_btc_addresses_from_db<D>(
+    db: &D,
+) -> Result<TieredSafeAddresses<String>>
+    where D: DatabaseInterface
+{
+    Ok(
+        TieredSafeAddresses {
+            malformed_metadata: get_safe_btc_address_for_tier_from_db(
+                db,
+                SafeAddressTier::MalformedMetadata,
+            )?,
+            policy_blocked: get_safe_btc_address_for_tier_from_db(
+                db,
+                SafeAddressTier::PolicyBlocked,
+            )?,
+            oversize: get_safe_btc_address_for_tier_from_db(
+                db,
+                SafeAddressTier::Oversize,
+            )?,
+        }
+    )
+}
+
+pub fn get_safe_address_history_from_db<D>(
+    db: &D,
+) -> Result<Vec<SafeAddressUpdate>>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting safe address history from db...");
+    match db.get(SAFE_ADDRESS_HISTORY_KEY.to_vec(), DataSensitivity::Public) {
+        Err(_) => Ok(vec![]),
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+    }
+}
+
+fn add_safe_address_update_to_history<D>(
+    db: &D,
+    chain: &str,
+    tier: Option<SafeAddressTier>,
+    previous_address: String,
+    new_address: String,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    if previous_address == new_address {
+        return Err(AppError::InvalidAddress(
+            format!("✘ New safe {} address is the current one!", chain)
+        ))
+    };
+    let mut history = get_safe_address_history_from_db(db)?;
+    history.push(
+        SafeAddressUpdate {
+            tier,
+            new_address,
+            previous_address,
+            chain: chain.to_string(),
+            timestamp: get_unix_timestamp()?,
+        }
+    );
+    db.put(
+        SAFE_ADDRESS_HISTORY_KEY.to_vec(),
+        serde_json::to_vec(&history)?,
+        DataSensitivity::Public,
+    )
+}
+
+pub fn update_safe_eth_address_in_db<D>(
+    db: &D,
+    new_address: &EthAddress,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!("✔ Updating safe ETH address in db...");
+    if new_address.is_zero() {
+        return Err(AppError::InvalidAddress(
+            "✘ Safe ETH address cannot be the zero address!".to_string()
+        ))
+    };
+    add_safe_address_update_to_history(
+        db,
+        "ETH",
+        None,
+        format!(
+            "0x{}",
+            hex::encode(get_safe_eth_address_from_db(db)?.as_bytes())
+        ),
+        format!("0x{}", hex::encode(new_address.as_bytes())),
+    )
+        .and_then(|_|
+            db.put(
+                SAFE_ETH_ADDRESS_KEY.to_vec(),
+                new_address.as_bytes().to_vec(),
+                DataSensitivity::Public,
+            )
+        )
+}
+
+pub fn update_safe_btc_address_in_db<D>(
+    db: &D,
+    new_address: &str,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!("✔ Updating safe BTC address in db...");
+    validate_btc_address(new_address, &get_btc_network_from_db(db)?)
+        .and_then(|_|
+            add_safe_address_update_to_history(
+                db,
+                "BTC",
+                None,
+                get_safe_btc_address_from_db(db)?,
+                new_address.to_string(),
+            )
+        )
+        .and_then(|_|
+            db.put(
+                SAFE_BTC_ADDRESS_KEY.to_vec(),
+                new_address.as_bytes().to_vec(),
+                DataSensitivity::Public,
+            )
+        )
+}
+
+pub fn update_safe_address_for_tier_in_db<D>(
+    db: &D,
+    chain: &str,
+    tier: SafeAddressTier,
+    new_address: &str,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!("✔ Updating {:?} tier safe {} address in db...", tier, chain);
+    let mut tiers = get_safe_address_tiers_from_db(db)?;
+    match chain.to_uppercase().as_str() {
+        "ETH" => {
+            if !check_hex_is_valid_ethereum_address(&new_address.to_string()) {
+                return Err(AppError::InvalidAddress(
+                    format!("✘ Invalid safe ETH address: '{}'!", new_address)
+                ))
+            };
+            let new_address = format!(
+                "0x{}",
+                new_address.trim_start_matches("0x").to_lowercase()
+            );
+            if hex::decode(&new_address[2..])?
+                .iter()
+                .all(|byte| *byte == 0)
+            {
+                return Err(AppError::InvalidAddress(
+                    "✘ Safe ETH address cannot be the zero address!"
+                        .to_string()
+                ))
+            };
+            add_safe_address_update_to_history(
+                db,
+                "ETH",
+                Some(tier),
+                format!(
+                    "0x{}",
+                    hex::encode(
+                        get_safe_eth_address_for_tier_from_db(db, tier)?
+                            .as_bytes()
+                    )
+                ),
+                new_address.clone(),
+            )?;
+            tiers.eth.insert(tier, new_address);
+        }
+        "BTC" => {
+            validate_btc_address(new_address, &get_btc_network_from_db(db)?)?;
+            add_safe_address_update_to_history(
+                db,
+                "BTC",
+                Some(tier),
+                get_safe_btc_address_for_tier_from_db(db, tier)?,
+                new_address.to_string(),
+            )?;
+            tiers.btc.insert(tier, new_address.to_string());
+        }
+        _ => return Err(AppError::InvalidInput(
+            format!("✘ Unrecognised chain: '{}'!", chain)
+        )),
+    };
+    db.put(
+        SAFE_ADDRESS_TIERS_KEY.to_vec(),
+        serde_json::to_vec(&tiers)?,
+        DataSensitivity::Public,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::network::constants::Network as BtcNetwork;
+    use crate::{
+        test_utils::get_test_database,
+        btc::btc_database_utils::put_btc_network_in_db,
+    };
+
+    #[test]
+    fn should_default_to_constant_safe_addresses() {
+        let db = get_test_database();
+        assert_eq!(
+            get_safe_eth_address_from_db(&db).unwrap(),
+            EthAddress::from_slice(&SAFE_ETH_ADDRESS),
+        );
+        assert_eq!(get_safe_btc_address_from_db(&db).unwrap(), SAFE_BTC_ADDRESS);
+        assert!(get_safe_address_history_from_db(&db).unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_update_safe_addresses_and_record_history() {
+        let db = get_test_database();
+        let new_eth_address = EthAddress::from_slice(&[0xc0; 20]);
+        let new_btc_address = "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM";
+        put_btc_network_in_db(&db, &BtcNetwork::Testnet).unwrap();
+        update_safe_eth_address_in_db(&db, &new_eth_address).unwrap();
+        update_safe_btc_address_in_db(&db, new_btc_address).unwrap();
+        assert_eq!(get_safe_eth_address_from_db(&db).unwrap(), new_eth_address);
+        assert_eq!(get_safe_btc_address_from_db(&db).unwrap(), new_btc_address);
+        let history = get_safe_address_history_from_db(&db).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].chain, "ETH");
+        assert_eq!(history[1].previous_address, SAFE_BTC_ADDRESS);
+        assert_eq!(history[1].new_address, new_btc_address);
+    }
+
+    #[test]
+    fn should_fail_to_update_safe_btc_address_to_current_one() {
+        let db = get_test_database();
+        put_btc_network_in_db(&db, &BtcNetwork::Bitcoin).unwrap();
+        assert!(update_safe_btc_address_in_db(&db, SAFE_BTC_ADDRESS).is_err());
+    }
+
+    #[test]
+    fn should_fail_to_update_safe_btc_address_for_other_network() {
+        let db = get_test_database();
+        let mainnet_address = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
+        put_btc_network_in_db(&db, &BtcNetwork::Testnet).unwrap();
+        assert!(update_safe_btc_address_in_db(&db, mainnet_address).is_err());
+        assert!(get_safe_address_history_from_db(&db).unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_fail_to_get_safe_eth_address_of_invalid_length_from_db() {
+        let db = get_test_database();
+        db.put(
+            SAFE_ETH_ADDRESS_KEY.to_vec(),
+            vec![0xc0; 19],
+            DataSensitivity::Public,
+        ).unwrap();
+        assert!(get_safe_eth_address_from_db(&db).is_err());
+    }
+
+    #[test]
+    fn should_fall_back_to_default_safe_address_for_unset_tiers() {
+        let db = get_test_database();
+        let result = get_tiered_safe_btc_addresses_from_db(&db).unwrap();
+        assert_eq!(
+            result,
+            TieredSafeAddresses::new(SAFE_BTC_ADDRESS.to_string()),
+        );
+        assert_eq!(
+            get_safe_eth_address_for_tier_from_db(
+                &db,
+                SafeAddressTier::PolicyBlocked,
+            ).unwrap(),
+            EthAddress::from_slice(&SAFE_ETH_ADDRESS),
+        );
+    }
+
+    #[test]
+    fn should_update_safe_address_for_tier() {
+        let db = get_test_database();
+        let new_eth_address = format!("0x{}", "c0".repeat(20));
+        let new_btc_address = "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM";
+        let tier = SafeAddressTier::from_str("policy_blocked").unwrap();
+        put_btc_network_in_db(&db, &BtcNetwork::Testnet).unwrap();
+        update_safe_address_for_tier_in_db(&db, "ETH", tier, &new_eth_address)
+            .unwrap();
+        update_safe_address_for_tier_in_db(&db, "btc", tier, new_btc_address)
+            .unwrap();
+        assert_eq!(
+            get_safe_eth_address_for_tier_from_db(&db, tier).unwrap(),
+            EthAddress::from_slice(&[0xc0; 20]),
+        );
+        let result = get_tiered_safe_btc_addresses_from_db(&db).unwrap();
+        assert_eq!(result.policy_blocked, new_btc_address);
+        assert_eq!(result.malformed_metadata, SAFE_BTC_ADDRESS);
+        assert_eq!(
+            get_safe_btc_address_from_db(&db).unwrap(),
+            SAFE_BTC_ADDRESS,
+        );
+        let history = get_safe_address_history_from_db(&db).unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].tier, Some(tier));
+        assert!(
+            update_safe_address_for_tier_in_db(&db, "BTC", tier, "bad")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn should_fail_to_set_tier_safe_btc_address_for_other_network() {
+        let db = get_test_database();
+        let mainnet_address = "1BvBMSEYstWetqTFn5Au4m4GFg7xJaNVN2";
+        let tier = SafeAddressTier::Oversize;
+        put_btc_network_in_db(&db, &BtcNetwork::Testnet).unwrap();
+        assert!(
+            update_safe_address_for_tier_in_db(
+                &db,
+                "BTC",
+                tier,
+                mainnet_address,
+            ).is_err()
+        );
+        put_btc_network_in_db(&db, &BtcNetwork::Bitcoin).unwrap();
+        update_safe_address_for_tier_in_db(&db, "BTC", tier, mainnet_address)
+            .unwrap();
+    }
+}
@@ -0,0 +1,381 @@
+#![cfg(any(test, feature="simulation"))]
+// NOTE: A deterministic end-to-end harness: an in-memory db is initialized,
+// fed a scripted sequence of blocks & the core's invariants are asserted after
+// every submission, so integration tests & regression repros share a scaffold.
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    test_database::TestDatabase,
+    check_core_integrity::get_core_integrity_report,
+    utxo_manager::utxo_database_utils::get_utxo_nonce_from_db,
+    balance_reconciliation::get_balance_reconciliation_report_from_db,
+    types::{
+        Bytes,
+        Result,
+        DataSensitivity,
+    },
+    btc::{
+        submit_btc_block::submit_btc_block_to_enclave,
+        btc_database_utils::get_btc_account_nonce_from_db,
+        initialize_btc::initialize_btc_enclave::maybe_initialize_btc_enclave,
+    },
+    eth::{
+        submit_eth_block::submit_eth_block_to_enclave,
+        eth_database_utils::get_eth_account_nonce_from_db,
+        initialize_eth::initialize_eth_enclave::maybe_initialize_eth_enclave,
+    },
+};
+
+// NOTE: The pipelines take their db by value, so the simulation lends them
+// this view of its own in order for state to persist between submissions.
+struct SimulationDatabase<'a>(&'a TestDatabase);
+
+impl<'a> DatabaseInterface for SimulationDatabase<'a> {
+    fn end_transaction(&self) -> Result<()> {
+        self.0.end_transaction()
+    }
+
+    fn start_transaction(&self) -> Result<()> {
+        self.0.start_transaction()
+    }
+
+    fn delete(&self, key: Bytes) -> Result<()> {
+        self.0.delete(key)
+    }
+
+    fn get(&self, key: Bytes, sensitivity: DataSensitivity) -> Result<Bytes> {
+        self.0.get(key, sensitivity)
+    }
+
+    fn put(
+        &self,
+        key: Bytes,
+        value: Bytes,
+        sensitivity: DataSensitivity,
+    ) -> Result<()> {
+        self.0.put(key, value, sensitivity)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SimulationStep {
+    BtcBlock(String),
+    EthBlock(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SimulationSnapshot {
+    pub utxo_nonce: u64,
+    pub btc_account_nonce: u64,
+    pub eth_account_nonce: u64,
+    pub utxo_balance_in_satoshis: u64,
+    pub expected_ptoken_supply_in_satoshis: i64,
+}
+
+#[derive(Default)]
+pub struct Simulation {
+    db: TestDatabase,
+    snapshots: Vec<SimulationSnapshot>,
+}
+
+fn get_invariant_err(invariant: &str, detail: String) -> AppError {
+    AppError::SimulationInvariant(
+        format!(
+            "✘ Simulation invariant '{}' violated: {}!",
+            invariant,
+            detail,
+        )
+    )
+}
+
+fn check_nonce_is_monotonic(
+    nonce_name: &str,
+    previous_nonce: u64,
+    nonce: u64,
+) -> Result<()> {
+    match nonce >= previous_nonce {
+        true => Ok(()),
+        false => Err(get_invariant_err(
+            "nonce monotonicity",
+            format!(
+                "{} went from {} to {}",
+                nonce_name,
+                previous_nonce,
+                nonce,
+            ),
+        )),
+    }
+}
+
+impl Simulation {
+    pub fn new() -> Self {
+        Simulation::default()
+    }
+
+    fn get_simulation_db(&self) -> SimulationDatabase {
+        SimulationDatabase(&self.db)
+    }
+
+    pub fn get_db(&self) -> &TestDatabase {
+        &self.db
+    }
+
+    pub fn get_snapshots(&self) -> &[SimulationSnapshot] {
+        &self.snapshots
+    }
+
+    // NOTE: The invariants span both chains, so are first checked once the
+    // ETH side is initialized too.
+    pub fn init_btc(
+        &self,
+        block_json_string: &str,
+        fee: u64,
+        difficulty: u64,
+        network: &str,
+        canon_to_tip_length: u64,
+    ) -> Result<String> {
+        info!("✔ Initializing BTC side of simulation...");
+        maybe_initialize_btc_enclave(
+            self.get_simulation_db(),
+            block_json_string.to_string(),
+            fee,
+            difficulty,
+            network.to_string(),
+            canon_to_tip_length,
+        )
+    }
+
+    pub fn init_eth(
+        &mut self,
+        block_json_string: &str,
+        chain_id: u8,
+        gas_price: u64,
+        canon_to_tip_length: u64,
+    ) -> Result<String> {
+        info!("✔ Initializing ETH side of simulation...");
+        maybe_initialize_eth_enclave(
+            self.get_simulation_db(),
+            block_json_string.to_string(),
+            chain_id,
+            gas_price,
+            canon_to_tip_length,
+        )
+        .and_then(|output| self.check_invariants().map(|_| output))
+    }
+
+    pub fn submit_btc_block(
+        &mut self,
+        block_json_string: &str,
+    ) -> Result<String> {
+        submit_btc_block_to_enclave(
+            self.get_simulation_db(),
+            block_json_string.to_string(),
+            false,
+        )
+        .and_then(|output| self.check_invariants().map(|_| output))
+    }
+
+    pub fn submit_eth_block(
+        &mut self,
+        block_json_string: &str,
+    ) -> Result<String> {
+        submit_eth_block_to_enclave(
+            self.get_simulation_db(),
+            block_json_string.to_string(),
+            false,
+        )
+        .and_then(|output| self.check_invariants().map(|_| output))
+    }
+
+    pub fn run(&mut self, steps: &[SimulationStep]) -> Result<Vec<String>> {
+        info!("✔ Running {} simulation steps...", steps.len());
+        steps
+            .iter()
+            .enumerate()
+            .map(|(i, step)| {
+                let result = match step {
+                    SimulationStep::BtcBlock(json) =>
+                        self.submit_btc_block(json),
+                    SimulationStep::EthBlock(json) =>
+                        self.submit_eth_block(json),
+                };
+                result.map_err(|err| AppError::SimulationStepFailed {
+                    step: i,
+                    error: err.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    fn get_snapshot(&self) -> Result<SimulationSnapshot> {
+        let report = get_balance_reconciliation_report_from_db(&self.db)?;
+        Ok(
+            SimulationSnapshot {
+                utxo_nonce: get_utxo_nonce_from_db(&self.db)?,
+                btc_account_nonce: get_btc_account_nonce_from_db(&self.db)?,
+                eth_account_nonce: get_eth_account_nonce_from_db(&self.db)?,
+                utxo_balance_in_satoshis: report.utxo_balance_in_satoshis,
+                expected_ptoken_supply_in_satoshis:
+                    report.expected_ptoken_supply_in_satoshis,
+            }
+        )
+    }
+
+    // NOTE: Run after every submission, but public so tests which tamper with
+    // the db between steps may assert the invariants themselves.
+    pub fn check_invariants(&mut self) -> Result<SimulationSnapshot> {
+        trace!("✔ Checking simulation invariants...");
+        let utxo_report = get_core_integrity_report(&self.db)?.btc_utxos;
+        if !utxo_report.is_healthy {
+            return Err(get_invariant_err(
+                "UTXO consistency",
+                utxo_report.errors.join(", "),
+            ))
+        };
+        let snapshot = self.get_snapshot()?;
+        if snapshot.expected_ptoken_supply_in_satoshis >
+            snapshot.utxo_balance_in_satoshis as i64
+        {
+            return Err(get_invariant_err(
+                "supply conservation",
+                format!(
+                    "pToken supply of {} exceeds UTXO balance of {}",
+                    snapshot.expected_ptoken_supply_in_satoshis,
+                    snapshot.utxo_balance_in_satoshis,
+                ),
+            ))
+        };
+        if let Some(previous) = self.snapshots.last() {
+            check_nonce_is_monotonic(
+                "UTXO nonce",
+                previous.utxo_nonce,
+                snapshot.utxo_nonce,
+            )?;
+            check_nonce_is_monotonic(
+                "BTC account nonce",
+                previous.btc_account_nonce,
+                snapshot.btc_account_nonce,
+            )?;
+            check_nonce_is_monotonic(
+                "ETH account nonce",
+                previous.eth_account_nonce,
+                snapshot.eth_account_nonce,
+            )?;
+        };
+        self.snapshots.push(snapshot.clone());
+        Ok(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::read_to_string;
+    use crate::{
+        constants::TOTAL_SATOSHIS_MINTED_KEY,
+        database_utils::put_u64_in_db,
+        eth::eth_database_utils::put_eth_account_nonce_in_db,
+    };
+
+    const BTC_BLOCKS_PATH_PREFIX: &str =
+        "src/btc/btc_test_utils/sequential_block_and_ids/";
+    const ETH_BLOCKS_PATH_PREFIX: &str =
+        "src/eth/eth_test_utils/sequential_block_and_receipts_jsons/";
+    const FIRST_BTC_BLOCK_NUMBER: u64 = 1611090;
+    const FIRST_ETH_BLOCK_NUMBER: u64 = 8065750;
+    const NUM_BLOCKS_PER_CHAIN: u64 = 5;
+
+    fn get_btc_block_json(block_number: u64) -> String {
+        read_to_string(format!(
+            "{}{}-btc-block-and-txs.json",
+            BTC_BLOCKS_PATH_PREFIX,
+            block_number,
+        )).unwrap()
+    }
+
+    fn get_eth_block_json(block_number: u64) -> String {
+        read_to_string(format!(
+            "{}eth_block_and_receipts_num_{}.json",
+            ETH_BLOCKS_PATH_PREFIX,
+            block_number,
+        )).unwrap()
+    }
+
+    fn get_initialized_simulation() -> Simulation {
+        let mut simulation = Simulation::new();
+        simulation
+            .init_btc(
+                &get_btc_block_json(FIRST_BTC_BLOCK_NUMBER),
+                23,
+                1,
+                "Testnet",
+                2,
+            )
+            .and_then(|_|
+                simulation.init_eth(
+                    &get_eth_block_json(FIRST_ETH_BLOCK_NUMBER),
+                    1,
+                    20_000_000_000,
+                    2,
+                )
+            )
+            .unwrap();
+        simulation
+    }
+
+    fn get_interleaved_steps() -> Vec<SimulationStep> {
+        (1..NUM_BLOCKS_PER_CHAIN)
+            .flat_map(|i|
+                vec![
+                    SimulationStep::BtcBlock(
+                        get_btc_block_json(FIRST_BTC_BLOCK_NUMBER + i)
+                    ),
+                    SimulationStep::EthBlock(
+                        get_eth_block_json(FIRST_ETH_BLOCK_NUMBER + i)
+                    ),
+                ]
+            )
+            .collect()
+    }
+
+    #[test]
+    fn should_run_interleaved_blocks_without_violating_invariants() {
+        let mut simulation = get_initialized_simulation();
+        let steps = get_interleaved_steps();
+        let outputs = simulation.run(&steps).unwrap();
+        assert_eq!(outputs.len(), steps.len());
+        assert_eq!(simulation.get_snapshots().len(), steps.len() + 1);
+    }
+
+    #[test]
+    fn should_fail_step_which_resubmits_a_block() {
+        let mut simulation = get_initialized_simulation();
+        let steps = vec![
+            SimulationStep::EthBlock(
+                get_eth_block_json(FIRST_ETH_BLOCK_NUMBER)
+            ),
+        ];
+        let result = simulation.run(&steps);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_detect_nonce_regression() {
+        let mut simulation = get_initialized_simulation();
+        put_eth_account_nonce_in_db(simulation.get_db(), &5).unwrap();
+        simulation.check_invariants().unwrap();
+        put_eth_account_nonce_in_db(simulation.get_db(), &4).unwrap();
+        assert!(simulation.check_invariants().is_err());
+    }
+
+    #[test]
+    fn should_detect_supply_exceeding_utxo_balance() {
+        let mut simulation = get_initialized_simulation();
+        put_u64_in_db(
+            simulation.get_db(),
+            &TOTAL_SATOSHIS_MINTED_KEY.to_vec(),
+            &1337,
+        ).unwrap();
+        assert!(simulation.check_invariants().is_err());
+    }
+}
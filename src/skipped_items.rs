@@ -0,0 +1,44 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    DustAmount,
+    AlreadyProcessed,
+    InvalidRecipient,
+    DisallowedRecipient,
+    VolumeLimited,
+}
+
+// NOTE: Invalid recipients are diverted to the safe address rather than
+// dropped, but are still reported since the funds never reach the user.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkippedItem {
+    pub tx_hash: String,
+    pub amount: String,
+    pub reason: SkipReason,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub correlation_id: String,
+}
+
+impl SkippedItem {
+    pub fn new(tx_hash: String, amount: String, reason: SkipReason) -> Self {
+        SkippedItem {
+            tx_hash,
+            amount,
+            reason,
+            detail: None,
+            correlation_id: String::new(),
+        }
+    }
+
+    pub fn with_correlation_id(self, correlation_id: &str) -> Self {
+        SkippedItem { correlation_id: correlation_id.to_string(), ..self }
+    }
+
+    pub fn with_detail(self, detail: String) -> Self {
+        SkippedItem { detail: Some(detail), ..self }
+    }
+}
+
+pub type SkippedItems = Vec<SkippedItem>;
@@ -0,0 +1,149 @@
+use std::sync::Mutex;
+use crate::{
+    types::Result,
+    errors::AppError,
+    chain_state::ChainState,
+    stage_timing::{
+        timed_as,
+        get_stage_name,
+    },
+};
+
+pub type StageHook = fn(&StageView) -> Result<()>;
+
+lazy_static! {
+    static ref PRE_STAGE_HOOKS: Mutex<Vec<StageHook>> = Mutex::new(vec![]);
+    static ref POST_STAGE_HOOKS: Mutex<Vec<StageHook>> = Mutex::new(vec![]);
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StageView {
+    pub chain: String,
+    pub stage: String,
+    pub dry_run: bool,
+    pub senders: Vec<String>,
+    pub recipients: Vec<String>,
+    pub block_hash: Option<String>,
+}
+
+fn get_stage_hooks_lock_err() -> AppError {
+    AppError::HostError("✘ Cannot get lock on stage hooks!".to_string())
+}
+
+fn register_stage_hook(
+    hooks: &Mutex<Vec<StageHook>>,
+    hook: StageHook,
+) -> Result<()> {
+    hooks
+        .lock()
+        .map(|mut hooks| hooks.push(hook))
+        .map_err(|_| get_stage_hooks_lock_err())
+}
+
+pub fn register_pre_stage_hook(hook: StageHook) -> Result<()> {
+    info!("✔ Registering pre stage hook...");
+    register_stage_hook(&PRE_STAGE_HOOKS, hook)
+}
+
+pub fn register_post_stage_hook(hook: StageHook) -> Result<()> {
+    info!("✔ Registering post stage hook...");
+    register_stage_hook(&POST_STAGE_HOOKS, hook)
+}
+
+pub fn clear_stage_hooks() -> Result<()> {
+    info!("✔ Clearing all stage hooks...");
+    vec![&*PRE_STAGE_HOOKS, &*POST_STAGE_HOOKS]
+        .into_iter()
+        .map(|hooks|
+            hooks
+                .lock()
+                .map(|mut hooks| hooks.clear())
+                .map_err(|_| get_stage_hooks_lock_err())
+        )
+        .collect::<Result<()>>()
+}
+
+fn run_stage_hooks<S>(
+    hooks: &Mutex<Vec<StageHook>>,
+    stage: &str,
+    state: &S,
+) -> Result<()>
+    where S: ChainState
+{
+    let hooks = hooks
+        .lock()
+        .map_err(|_| get_stage_hooks_lock_err())?
+        .clone();
+    match hooks.is_empty() {
+        true => Ok(()),
+        false => {
+            trace!("✔ Running {} hooks for stage '{}'...", hooks.len(), stage);
+            let stage_view = state.get_stage_view(stage);
+            hooks.iter().map(|hook| hook(&stage_view)).collect()
+        }
+    }
+}
+
+pub fn stage_as<S, F>(
+    stage_name: String,
+    stage_fn: F,
+) -> impl FnOnce(S) -> Result<S>
+    where S: ChainState, F: FnOnce(S) -> Result<S>
+{
+    move |state| {
+        run_stage_hooks(&PRE_STAGE_HOOKS, &stage_name, &state)
+            .and_then(|_| timed_as(stage_name.clone(), stage_fn)(state))
+            .and_then(|state|
+                run_stage_hooks(&POST_STAGE_HOOKS, &stage_name, &state)
+                    .map(|_| state)
+            )
+    }
+}
+
+pub fn stage<S, F>(stage_fn: F) -> impl FnOnce(S) -> Result<S>
+    where S: ChainState, F: FnOnce(S) -> Result<S>
+{
+    stage_as(get_stage_name::<F>(), stage_fn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        btc::btc_state::BtcState,
+        traits::DatabaseInterface,
+        test_utils::get_test_database,
+    };
+
+    fn stage_with_hooks<D>(state: BtcState<D>) -> Result<BtcState<D>>
+        where D: DatabaseInterface
+    {
+        Ok(state)
+    }
+
+    fn stage_without_hooks<D>(state: BtcState<D>) -> Result<BtcState<D>>
+        where D: DatabaseInterface
+    {
+        Ok(state)
+    }
+
+    fn rejecting_hook(stage_view: &StageView) -> Result<()> {
+        match stage_view.stage == "stage_with_hooks" {
+            false => Ok(()),
+            true => Err(AppError::Custom("✘ Rejected by hook!".to_string())),
+        }
+    }
+
+    #[test]
+    fn post_stage_hook_should_be_able_to_reject_stage() {
+        register_post_stage_hook(rejecting_hook).unwrap();
+        let result = Ok(BtcState::init(get_test_database()))
+            .and_then(stage(stage_without_hooks))
+            .and_then(stage(stage_with_hooks));
+        match result {
+            Err(AppError::Custom(err)) =>
+                assert_eq!(err, "✘ Rejected by hook!"),
+            _ => panic!("Stage should have been rejected by hook!"),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    sync::Mutex,
+    any::type_name,
+};
+#[cfg(not(any(feature = "sgx", target_arch = "wasm32")))]
+use std::time::Instant;
+use crate::{
+    types::Result,
+    errors::AppError,
+    chain_state::ChainState,
+};
+
+lazy_static! {
+    static ref HOST_STAGE_CLOCK: Mutex<Option<fn() -> u64>> = Mutex::new(None);
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageTiming {
+    pub stage: String,
+    pub unit: String,
+    pub elapsed: u64,
+}
+
+pub type StageTimings = Vec<StageTiming>;
+
+enum StageClock {
+    #[cfg(not(any(feature = "sgx", target_arch = "wasm32")))]
+    Wall(Instant),
+    Host(fn() -> u64, u64),
+}
+
+impl StageClock {
+    #[cfg(not(any(feature = "sgx", target_arch = "wasm32")))]
+    fn start_wall_clock() -> Option<Self> {
+        Some(StageClock::Wall(Instant::now()))
+    }
+
+    #[cfg(any(feature = "sgx", target_arch = "wasm32"))]
+    fn start_wall_clock() -> Option<Self> {
+        trace!("✔ No wall clock available ∴ not timing stage!");
+        None
+    }
+
+    fn start() -> Option<Self> {
+        match get_host_stage_clock() {
+            Some(clock) => Some(StageClock::Host(clock, clock())),
+            None => Self::start_wall_clock(),
+        }
+    }
+
+    fn stop(self, stage: String) -> StageTiming {
+        let (unit, elapsed) = match self {
+            #[cfg(not(any(feature = "sgx", target_arch = "wasm32")))]
+            StageClock::Wall(start) =>
+                ("microseconds", start.elapsed().as_micros() as u64),
+            StageClock::Host(clock, start) =>
+                ("host-cycles", clock().saturating_sub(start)),
+        };
+        trace!("✔ Stage '{}' took {} {}", stage, elapsed, unit);
+        StageTiming { stage, elapsed, unit: unit.to_string() }
+    }
+}
+
+fn get_host_stage_clock() -> Option<fn() -> u64> {
+    HOST_STAGE_CLOCK.lock().ok().and_then(|clock| *clock)
+}
+
+pub fn set_host_stage_clock(clock: fn() -> u64) -> Result<()> {
+    info!("✔ Setting host supplied clock for stage timings...");
+    HOST_STAGE_CLOCK
+        .lock()
+        .map(|mut host_clock| *host_clock = Some(clock))
+        .map_err(|_|
+            AppError::HostError("✘ Could not set host stage clock!".to_string())
+        )
+}
+
+pub fn get_stage_name<F>() -> String {
+    let full_name = type_name::<F>();
+    full_name
+        .split('<')
+        .next()
+        .and_then(|path| path.rsplit("::").next())
+        .unwrap_or(full_name)
+        .to_string()
+}
+
+pub fn timed_as<S, F>(
+    stage_name: String,
+    stage: F,
+) -> impl FnOnce(S) -> Result<S>
+    where S: ChainState, F: FnOnce(S) -> Result<S>
+{
+    move |state| match cfg!(feature = "stage-timing") {
+        false => stage(state),
+        true => match StageClock::start() {
+            None => stage(state),
+            Some(clock) => stage(state).map(|state|
+                state.add_stage_timing(clock.stop(stage_name))
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+pub fn timed<S, F>(stage: F) -> impl FnOnce(S) -> Result<S>
+    where S: ChainState, F: FnOnce(S) -> Result<S>
+{
+    timed_as(get_stage_name::<F>(), stage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        btc::btc_state::BtcState,
+        traits::DatabaseInterface,
+        test_database::TestDatabase,
+        test_utils::get_test_database,
+    };
+
+    fn sample_stage<D>(state: BtcState<D>) -> Result<BtcState<D>>
+        where D: DatabaseInterface
+    {
+        Ok(state)
+    }
+
+    #[test]
+    fn should_get_stage_name_from_fn_type() {
+        fn get_name_of<F>(_: &F) -> String { get_stage_name::<F>() }
+        let stage = sample_stage::<TestDatabase>;
+        assert_eq!(get_name_of(&stage), "sample_stage");
+    }
+
+    #[test]
+    fn should_only_record_stage_timing_if_feature_enabled() {
+        let state = Ok(BtcState::init(get_test_database()))
+            .and_then(timed(sample_stage))
+            .unwrap();
+        match cfg!(all(feature = "stage-timing", not(feature = "sgx"))) {
+            false => assert!(state.stage_timings.is_empty()),
+            true => assert_eq!(state.stage_timings[0].stage, "sample_stage"),
+        }
+    }
+}
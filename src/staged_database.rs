@@ -0,0 +1,274 @@
+use std::{
+    sync::Mutex,
+    collections::{
+        HashMap,
+        HashSet,
+    },
+};
+use crate::{
+    errors::AppError,
+    utils::get_not_in_db_err,
+    database_snapshot::DatabaseSnapshot,
+    types::{
+        Bytes,
+        Result,
+        DataSensitivity,
+    },
+    traits::{
+        DatabaseWrite,
+        DatabaseInterface,
+    },
+};
+
+type StagedWrites = HashMap<Bytes, DatabaseWrite>;
+
+pub static STAGED_DB_LOCK_ERROR: &'static str =
+    "✘ Cannot get lock on staged DB writes!";
+
+pub struct StagedDatabase<D: DatabaseInterface> {
+    db: D,
+    staged_writes: Mutex<StagedWrites>,
+}
+
+impl<D: DatabaseInterface> StagedDatabase<D> {
+    pub fn new(db: D) -> Self {
+        StagedDatabase { db, staged_writes: Mutex::new(HashMap::new()) }
+    }
+
+    fn stage_write(&self, key: Bytes, write: DatabaseWrite) -> Result<()> {
+        self
+            .staged_writes
+            .lock()
+            .expect(STAGED_DB_LOCK_ERROR)
+            .insert(key, write);
+        Ok(())
+    }
+
+    pub fn put_unstaged(
+        &self,
+        key: Bytes,
+        value: Bytes,
+        sensitivity: DataSensitivity,
+    ) -> Result<()> {
+        trace!("✔ Putting unstaged write in db...");
+        self.db.start_transaction()
+            .and_then(|_| self.db.put(key, value, sensitivity))
+            .and_then(|_| self.db.end_transaction())
+    }
+
+    fn take_staged_writes(&self) -> Vec<DatabaseWrite> {
+        self
+            .staged_writes
+            .lock()
+            .expect(STAGED_DB_LOCK_ERROR)
+            .drain()
+            .map(|(_, write)| write)
+            .collect()
+    }
+}
+
+impl<D: DatabaseInterface> DatabaseInterface for StagedDatabase<D> {
+    fn start_transaction(&self) -> Result<()> {
+        trace!("✔ Starting staged db transaction...");
+        Ok(())
+    }
+
+    fn end_transaction(&self) -> Result<()> {
+        let staged_writes = self.take_staged_writes();
+        let snapshot = DatabaseSnapshot::take(&self.db, &staged_writes)?;
+        info!("✔ Committing {} staged db writes...", staged_writes.len());
+        self.db.start_transaction()?;
+        let commit_result = self.db
+            .write_batch(staged_writes)
+            .or_else(|err| {
+                info!("✘ Error committing staged db writes: {}", err);
+                snapshot.restore(&self.db).and(Err(err))
+            });
+        // NOTE: The host's transaction is closed even if the writes were rolled
+        // back, else it'd be left open for the host's next call.
+        let end_result = self.db.end_transaction();
+        commit_result.and(end_result)
+    }
+
+    fn delete(&self, key: Bytes) -> Result<()> {
+        self.stage_write(key.clone(), DatabaseWrite::Delete(key))
+    }
+
+    fn put(
+        &self,
+        key: Bytes,
+        value: Bytes,
+        sensitivity: DataSensitivity,
+    ) -> Result<()> {
+        self.stage_write(
+            key.clone(),
+            DatabaseWrite::Put(key, value, sensitivity),
+        )
+    }
+
+    fn get(&self, key: Bytes, sensitivity: DataSensitivity) -> Result<Bytes> {
+        let staged_write = self
+            .staged_writes
+            .lock()
+            .expect(STAGED_DB_LOCK_ERROR)
+            .get(&key)
+            .cloned();
+        match staged_write {
+            None => self.db.get(key, sensitivity),
+            Some(DatabaseWrite::Put(_, value, _)) => Ok(value),
+            Some(DatabaseWrite::Delete(_)) => Err(get_not_in_db_err()),
+        }
+    }
+
+    fn get_keys_with_prefix(&self, prefix: Bytes) -> Result<Vec<Bytes>> {
+        let mut keys = self.db.get_keys_with_prefix(prefix.clone())?
+            .into_iter()
+            .collect::<HashSet<Bytes>>();
+        self
+            .staged_writes
+            .lock()
+            .expect(STAGED_DB_LOCK_ERROR)
+            .iter()
+            .filter(|(key, _)| key.starts_with(&prefix))
+            .for_each(|(key, write)| match write {
+                DatabaseWrite::Delete(_) => { keys.remove(key); },
+                DatabaseWrite::Put(..) => { keys.insert(key.clone()); },
+            });
+        Ok(keys.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{
+        Ordering,
+        AtomicBool,
+    };
+    use crate::test_utils::{
+        TestDB,
+        get_test_database,
+    };
+
+    struct FailingDatabase {
+        db: TestDB,
+        failing_key: Bytes,
+        transaction_is_open: AtomicBool,
+    }
+
+    impl DatabaseInterface for FailingDatabase {
+        fn end_transaction(&self) -> Result<()> {
+            self.transaction_is_open.store(false, Ordering::SeqCst);
+            self.db.end_transaction()
+        }
+
+        fn start_transaction(&self) -> Result<()> {
+            self.transaction_is_open.store(true, Ordering::SeqCst);
+            self.db.start_transaction()
+        }
+
+        fn delete(&self, key: Bytes) -> Result<()> {
+            self.db.delete(key)
+        }
+
+        fn get(
+            &self,
+            key: Bytes,
+            sensitivity: DataSensitivity,
+        ) -> Result<Bytes> {
+            self.db.get(key, sensitivity)
+        }
+
+        fn put(
+            &self,
+            key: Bytes,
+            value: Bytes,
+            sensitivity: DataSensitivity,
+        ) -> Result<()> {
+            match key == self.failing_key {
+                false => self.db.put(key, value, sensitivity),
+                true => Err(AppError::Custom(
+                    "✘ Failing to put key in db!".to_string()
+                )),
+            }
+        }
+    }
+
+    #[test]
+    fn should_only_write_staged_writes_to_db_on_end_transaction() {
+        let sensitivity = DataSensitivity::Public;
+        let key = vec![0xc0, 0xff, 0xee];
+        let value = vec![0xde, 0xca, 0xf0];
+        let staged_db = StagedDatabase::new(get_test_database());
+        staged_db.start_transaction().unwrap();
+        staged_db.put(key.clone(), value.clone(), sensitivity).unwrap();
+        assert_eq!(staged_db.get(key.clone(), sensitivity).unwrap(), value);
+        assert!(staged_db.db.get(key.clone(), sensitivity).is_err());
+        staged_db.end_transaction().unwrap();
+        assert_eq!(staged_db.db.get(key, sensitivity).unwrap(), value);
+    }
+
+    #[test]
+    fn should_stage_deletions_until_end_transaction() {
+        let sensitivity = DataSensitivity::Public;
+        let key = vec![0xc0, 0xff, 0xee];
+        let value = vec![0xde, 0xca, 0xf0];
+        let db = get_test_database();
+        db.put(key.clone(), value.clone(), sensitivity).unwrap();
+        let staged_db = StagedDatabase::new(db);
+        staged_db.delete(key.clone()).unwrap();
+        assert!(staged_db.get(key.clone(), sensitivity).is_err());
+        assert_eq!(staged_db.db.get(key.clone(), sensitivity).unwrap(), value);
+        staged_db.end_transaction().unwrap();
+        assert!(staged_db.db.get(key, sensitivity).is_err());
+    }
+
+    #[test]
+    fn should_get_staged_and_stored_keys_with_prefix() {
+        let sensitivity = DataSensitivity::Public;
+        let db = get_test_database();
+        db.put(vec![0xc0, 0x01], vec![], sensitivity).unwrap();
+        db.put(vec![0xc0, 0x02], vec![], sensitivity).unwrap();
+        let staged_db = StagedDatabase::new(db);
+        staged_db.put(vec![0xc0, 0x03], vec![], sensitivity).unwrap();
+        staged_db.put(vec![0xde, 0x01], vec![], sensitivity).unwrap();
+        staged_db.delete(vec![0xc0, 0x01]).unwrap();
+        let mut result = staged_db.get_keys_with_prefix(vec![0xc0]).unwrap();
+        result.sort();
+        assert_eq!(result, vec![vec![0xc0, 0x02], vec![0xc0, 0x03]]);
+    }
+
+    #[test]
+    fn should_not_write_to_db_if_transaction_never_ends() {
+        let sensitivity = DataSensitivity::Public;
+        let key = vec![0xc0, 0xff, 0xee];
+        let staged_db = StagedDatabase::new(get_test_database());
+        staged_db.start_transaction().unwrap();
+        staged_db
+            .put(key.clone(), vec![0xde, 0xca, 0xf0], sensitivity)
+            .unwrap();
+        let db = staged_db.db;
+        assert!(db.get(key, sensitivity).is_err());
+    }
+
+    #[test]
+    fn should_roll_back_partially_committed_writes_on_error() {
+        let sensitivity = DataSensitivity::Public;
+        let key = vec![0xc0, 0xff, 0xee];
+        let failing_key = vec![0xde, 0xca, 0xf0];
+        let value = vec![0x01];
+        let db = get_test_database();
+        db.put(key.clone(), value.clone(), sensitivity).unwrap();
+        let failing_db = FailingDatabase {
+            db,
+            failing_key,
+            transaction_is_open: AtomicBool::new(false),
+        };
+        let staged_db = StagedDatabase::new(failing_db);
+        staged_db.delete(key.clone()).unwrap();
+        staged_db.put(vec![0xde, 0xca, 0xf0], vec![0x02], sensitivity).unwrap();
+        assert!(staged_db.end_transaction().is_err());
+        assert_eq!(staged_db.db.get(key, sensitivity).unwrap(), value);
+        assert!(!staged_db.db.transaction_is_open.load(Ordering::SeqCst));
+    }
+}
@@ -0,0 +1,678 @@
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    utils::convert_u256_to_u64,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    database_utils::{
+        put_u64_in_db,
+        get_u64_from_db,
+    },
+    constants::{
+        STALE_TX_THRESHOLD_KEY,
+        WATCHED_SIGNED_TXS_KEY,
+        MAX_NUM_WATCHED_TXS,
+        DEFAULT_STALE_TX_THRESHOLD_IN_BLOCKS,
+    },
+    eth::{
+        eth_state::EthState,
+        eth_database_utils::{
+            get_latest_eth_block_number,
+            get_special_eth_block_header_from_db,
+            maybe_get_nth_ancestor_eth_block_header,
+        },
+    },
+    btc::{
+        btc_state::BtcState,
+        btc_database_utils::{
+            get_btc_latest_block_number,
+            get_special_btc_block_header_from_db,
+            maybe_get_nth_ancestor_btc_block_header,
+        },
+    },
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeenInBlock {
+    pub number: u64,
+    pub hash: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WatchedTx {
+    pub chain: String,
+    pub tx_hash: String,
+    pub signed_at_block_number: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub replaced_tx_hashes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seen_in_block: Option<SeenInBlock>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orphaned_eth_block_hash: Option<String>,
+}
+
+impl WatchedTx {
+    fn has_hash_in(&self, chain: &str, tx_hashes: &[String]) -> bool {
+        self.chain == chain && (
+            tx_hashes.contains(&self.tx_hash) ||
+            self.replaced_tx_hashes
+                .iter()
+                .any(|tx_hash| tx_hashes.contains(tx_hash))
+        )
+    }
+}
+
+pub type WatchedTxs = Vec<WatchedTx>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaleTx {
+    pub chain: String,
+    pub tx_hash: String,
+    pub blocks_elapsed: u64,
+    pub signed_at_block_number: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orphaned_eth_block_hash: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StaleTxsReport {
+    pub threshold_in_blocks: u64,
+    pub stale_transactions: Vec<StaleTx>,
+}
+
+pub fn get_stale_tx_threshold_from_db<D>(db: &D) -> u64
+    where D: DatabaseInterface
+{
+    get_u64_from_db(db, &STALE_TX_THRESHOLD_KEY.to_vec())
+        .unwrap_or(DEFAULT_STALE_TX_THRESHOLD_IN_BLOCKS)
+}
+
+pub fn put_stale_tx_threshold_in_db<D>(db: &D, threshold: u64) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting stale tx threshold of {} blocks in db...", threshold);
+    put_u64_in_db(db, &STALE_TX_THRESHOLD_KEY.to_vec(), &threshold)
+}
+
+pub fn get_watched_txs_from_db<D>(db: &D) -> Result<WatchedTxs>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting watched signed txs from db...");
+    match db.get(WATCHED_SIGNED_TXS_KEY.to_vec(), DataSensitivity::Public) {
+        Err(_) => Ok(vec![]),
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+    }
+}
+
+fn put_watched_txs_in_db<D>(db: &D, watched_txs: &[WatchedTx]) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting {} watched signed txs in db...", watched_txs.len());
+    db.put(
+        WATCHED_SIGNED_TXS_KEY.to_vec(),
+        serde_json::to_vec(watched_txs)?,
+        DataSensitivity::Public,
+    )
+}
+
+// NOTE: The oldest txs are dropped once the list is full, so that a host which
+// never broadcasts can't grow it without bound.
+fn bound_watched_txs(watched_txs: WatchedTxs) -> WatchedTxs {
+    match watched_txs.len() > MAX_NUM_WATCHED_TXS {
+        false => watched_txs,
+        true => {
+            let num_to_drop = watched_txs.len() - MAX_NUM_WATCHED_TXS;
+            info!("✘ Watchlist full ∴ dropping {} oldest txs!", num_to_drop);
+            watched_txs.into_iter().skip(num_to_drop).collect()
+        }
+    }
+}
+
+fn add_txs_to_watchlist_in_db<D>(
+    db: &D,
+    chain: &str,
+    tx_hashes: Vec<String>,
+    signed_at_block_number: u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!("✔ Watching {} signed {} txs...", tx_hashes.len(), chain);
+    let mut watched_txs = get_watched_txs_from_db(db)?;
+    watched_txs.extend(
+        tx_hashes
+            .into_iter()
+            .map(|tx_hash| WatchedTx {
+                tx_hash,
+                signed_at_block_number,
+                seen_in_block: None,
+                chain: chain.to_string(),
+                replaced_tx_hashes: vec![],
+                orphaned_eth_block_hash: None,
+            })
+    );
+    put_watched_txs_in_db(db, &bound_watched_txs(watched_txs))
+}
+
+pub fn remove_tx_from_watchlist_in_db<D>(
+    db: &D,
+    chain: &str,
+    tx_hash: &str,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!("✔ Removing {} tx {} from watchlist...", chain, tx_hash);
+    let watched_txs = get_watched_txs_from_db(db)?;
+    let remaining_txs = watched_txs
+        .iter()
+        .filter(|watched_tx|
+            !watched_tx.has_hash_in(chain, &[tx_hash.to_string()])
+        )
+        .cloned()
+        .collect::<WatchedTxs>();
+    match remaining_txs.len() == watched_txs.len() {
+        true => Err(AppError::NotFound(
+            format!("✘ No {} tx {} in watchlist!", chain, tx_hash)
+        )),
+        false => put_watched_txs_in_db(db, &remaining_txs),
+    }
+}
+
+// NOTE: A replacement is watched in place of the tx it replaces, whose hash it
+// carries in case that's the one mined after all.
+pub fn replace_watched_tx_in_db<D>(
+    db: &D,
+    chain: &str,
+    replaced_tx_hashes: &[String],
+    tx_hash: &str,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    let is_replaced = |watched_tx: &WatchedTx|
+        watched_tx.tx_hash != tx_hash &&
+        watched_tx.has_hash_in(chain, replaced_tx_hashes);
+    let mut watched_txs = get_watched_txs_from_db(db)?;
+    if !watched_txs.iter().any(is_replaced) {
+        return Ok(())
+    };
+    let signed_at_block_number = get_latest_block_number_of_chain(db, chain)?;
+    watched_txs
+        .iter_mut()
+        .filter(|watched_tx| is_replaced(watched_tx))
+        .for_each(|watched_tx| {
+            info!(
+                "✔ Watching {} tx {} in place of {}...",
+                chain,
+                tx_hash,
+                watched_tx.tx_hash,
+            );
+            watched_tx.replaced_tx_hashes.push(watched_tx.tx_hash.clone());
+            watched_tx.tx_hash = tx_hash.to_string();
+            watched_tx.signed_at_block_number = signed_at_block_number;
+        });
+    put_watched_txs_in_db(db, &watched_txs)
+}
+
+// NOTE: The submitted block may yet be orphaned ∴ txs seen in it are only
+// marked as such, & removed once that block is in the canon chain.
+fn mark_seen_txs_in_watchlist_in_db<D>(
+    db: &D,
+    chain: &str,
+    seen_tx_hashes: &[String],
+    seen_in_block: SeenInBlock,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    let mut watched_txs = get_watched_txs_from_db(db)?;
+    let mut num_seen = 0;
+    watched_txs
+        .iter_mut()
+        .filter(|watched_tx|
+            watched_tx.seen_in_block.is_none() &&
+            watched_tx.has_hash_in(chain, seen_tx_hashes)
+        )
+        .for_each(|watched_tx| {
+            watched_tx.seen_in_block = Some(seen_in_block.clone());
+            num_seen += 1;
+        });
+    match num_seen {
+        0 => Ok(()),
+        _ => {
+            info!("✔ {} watched {} txs seen in block!", num_seen, chain);
+            put_watched_txs_in_db(db, &watched_txs)
+        }
+    }
+}
+
+// NOTE: A BTC tx paying out redeems from an orphaned ETH block is never rolled
+// back, since it may already have been broadcast, so it's flagged instead, &
+// reported as stale regardless of the threshold until it's in the canon chain.
+pub fn flag_orphaned_btc_txs_in_watchlist_in_db<D>(
+    db: &D,
+    tx_hashes: &[String],
+    orphaned_eth_block_hash: &str,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    let mut watched_txs = get_watched_txs_from_db(db)?;
+    let mut num_flagged = 0;
+    watched_txs
+        .iter_mut()
+        .filter(|watched_tx| watched_tx.has_hash_in("BTC", tx_hashes))
+        .for_each(|watched_tx| {
+            watched_tx.orphaned_eth_block_hash =
+                Some(orphaned_eth_block_hash.to_string());
+            num_flagged += 1;
+        });
+    match num_flagged {
+        0 => Ok(()),
+        _ => {
+            info!("✘ Flagging {} watched BTC txs as orphaned!", num_flagged);
+            put_watched_txs_in_db(db, &watched_txs)
+        }
+    }
+}
+
+fn remove_canon_txs_from_watchlist_in_db<D, F>(
+    db: &D,
+    chain: &str,
+    canon_block_number: u64,
+    is_in_canon_chain: F,
+) -> Result<()>
+    where D: DatabaseInterface, F: Fn(&SeenInBlock) -> bool
+{
+    let watched_txs = get_watched_txs_from_db(db)?;
+    let num_watched_txs = watched_txs.len();
+    let mut is_changed = false;
+    let remaining_txs = watched_txs
+        .into_iter()
+        .filter_map(|mut watched_tx| {
+            let maybe_seen_in_block = watched_tx
+                .seen_in_block
+                .clone()
+                .filter(|seen_in_block|
+                    watched_tx.chain == chain &&
+                    seen_in_block.number <= canon_block_number
+                );
+            match maybe_seen_in_block {
+                None => Some(watched_tx),
+                Some(ref seen_in_block)
+                    if is_in_canon_chain(seen_in_block) => None,
+                Some(_) => {
+                    info!(
+                        "✘ Block w/ {} tx {} was orphaned ∴ re-watching it!",
+                        chain,
+                        watched_tx.tx_hash,
+                    );
+                    is_changed = true;
+                    watched_tx.seen_in_block = None;
+                    Some(watched_tx)
+                }
+            }
+        })
+        .collect::<WatchedTxs>();
+    let num_removed = num_watched_txs - remaining_txs.len();
+    if num_removed > 0 {
+        info!(
+            "✔ Removing {} canon {} txs from watchlist...",
+            num_removed,
+            chain,
+        );
+    };
+    match is_changed || num_removed > 0 {
+        true => put_watched_txs_in_db(db, &remaining_txs),
+        false => Ok(()),
+    }
+}
+
+pub fn maybe_watch_signed_eth_txs<D>(state: BtcState<D>) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    match state.eth_signed_txs.as_option() {
+        None => {
+            info!("✔ No ETH signed txs ∴ none to watch!");
+            Ok(state)
+        }
+        Some(eth_txs) => add_txs_to_watchlist_in_db(
+            &state.db,
+            "ETH",
+            eth_txs
+                .iter()
+                .map(|eth_tx| format!("0x{}", eth_tx.get_tx_hash()))
+                .collect(),
+            get_latest_eth_block_number(&state.db)? as u64,
+        )
+            .map(|_| state),
+    }
+}
+
+pub fn maybe_watch_signed_btc_txs<D>(state: EthState<D>) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    match state.btc_transactions.as_option() {
+        None => {
+            info!("✔ No BTC signed txs ∴ none to watch!");
+            Ok(state)
+        }
+        Some(btc_txs) => add_txs_to_watchlist_in_db(
+            &state.db,
+            "BTC",
+            btc_txs.iter().map(|btc_tx| btc_tx.txid().to_string()).collect(),
+            get_btc_latest_block_number(&state.db)?,
+        )
+            .map(|_| state),
+    }
+}
+
+pub fn mark_seen_btc_txs_in_watchlist<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Marking BTC txs in submitted block as seen in watchlist...");
+    let block_and_id = state.get_btc_block_and_id()?;
+    let seen_tx_hashes = block_and_id
+        .block
+        .txdata
+        .iter()
+        .map(|tx| tx.txid().to_string())
+        .collect::<Vec<String>>();
+    mark_seen_txs_in_watchlist_in_db(
+        &state.db,
+        "BTC",
+        &seen_tx_hashes,
+        SeenInBlock {
+            number: block_and_id.height,
+            hash: block_and_id.id.to_string(),
+        },
+    )
+        .map(|_| state)
+}
+
+pub fn mark_seen_eth_txs_in_watchlist<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Marking ETH txs in submitted block as seen in watchlist...");
+    let block_and_receipts = state.get_eth_block_and_receipts()?;
+    let seen_tx_hashes = block_and_receipts
+        .receipts
+        .iter()
+        .map(|receipt| format!("0x{}", hex::encode(receipt.transaction_hash)))
+        .collect::<Vec<String>>();
+    mark_seen_txs_in_watchlist_in_db(
+        &state.db,
+        "ETH",
+        &seen_tx_hashes,
+        SeenInBlock {
+            number: convert_u256_to_u64(&block_and_receipts.block.number)?,
+            hash: format!("0x{}", hex::encode(block_and_receipts.block.hash)),
+        },
+    )
+        .map(|_| state)
+}
+
+pub fn maybe_remove_canon_btc_txs_from_watchlist<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Removing BTC txs seen in canon chain from watchlist...");
+    let canon_block = get_special_btc_block_header_from_db(&state.db, "canon")?;
+    remove_canon_txs_from_watchlist_in_db(
+        &state.db,
+        "BTC",
+        canon_block.height,
+        |seen_in_block|
+            maybe_get_nth_ancestor_btc_block_header(
+                &state.db,
+                &canon_block.id,
+                &(canon_block.height - seen_in_block.number),
+            )
+                .map_or(false, |header|
+                    header.id.to_string() == seen_in_block.hash
+                )
+    )
+        .map(|_| state)
+}
+
+pub fn maybe_remove_canon_eth_txs_from_watchlist<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Removing ETH txs seen in canon chain from watchlist...");
+    let canon_block = get_special_eth_block_header_from_db(&state.db, "canon")?;
+    let canon_block_number = convert_u256_to_u64(&canon_block.number)?;
+    remove_canon_txs_from_watchlist_in_db(
+        &state.db,
+        "ETH",
+        canon_block_number,
+        |seen_in_block|
+            maybe_get_nth_ancestor_eth_block_header(
+                &state.db,
+                &canon_block.hash,
+                &(canon_block_number - seen_in_block.number),
+            )
+                .map_or(false, |header|
+                    format!("0x{}", hex::encode(header.hash)) ==
+                        seen_in_block.hash
+                )
+    )
+        .map(|_| state)
+}
+
+fn get_latest_block_number_of_chain<D>(db: &D, chain: &str) -> Result<u64>
+    where D: DatabaseInterface
+{
+    match chain {
+        "BTC" => get_btc_latest_block_number(db),
+        _ => get_latest_eth_block_number(db).map(|number| number as u64),
+    }
+}
+
+pub fn get_stale_txs_report_from_db<D>(db: &D) -> Result<StaleTxsReport>
+    where D: DatabaseInterface
+{
+    let threshold_in_blocks = get_stale_tx_threshold_from_db(db);
+    let btc_latest_block_number = get_latest_block_number_of_chain(db, "BTC")?;
+    let eth_latest_block_number = get_latest_block_number_of_chain(db, "ETH")?;
+    let stale_transactions = get_watched_txs_from_db(db)?
+        .into_iter()
+        .filter(|watched_tx|
+            watched_tx.seen_in_block.is_none() ||
+            watched_tx.orphaned_eth_block_hash.is_some()
+        )
+        .map(|watched_tx| {
+            let latest_block_number = match watched_tx.chain.as_str() {
+                "BTC" => btc_latest_block_number,
+                _ => eth_latest_block_number,
+            };
+            StaleTx {
+                blocks_elapsed: latest_block_number
+                    .saturating_sub(watched_tx.signed_at_block_number),
+                chain: watched_tx.chain,
+                tx_hash: watched_tx.tx_hash,
+                signed_at_block_number: watched_tx.signed_at_block_number,
+                orphaned_eth_block_hash: watched_tx.orphaned_eth_block_hash,
+            }
+        })
+        .filter(|stale_tx|
+            stale_tx.orphaned_eth_block_hash.is_some() ||
+            stale_tx.blocks_elapsed > threshold_in_blocks
+        )
+        .collect();
+    Ok(StaleTxsReport { threshold_in_blocks, stale_transactions })
+}
+
+pub fn get_stale_transactions<D>(db: D) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting stale transactions...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| get_stale_txs_report_from_db(&db))
+        .and_then(|report| Ok(serde_json::to_string(&report)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        eth::{
+            eth_test_utils::get_sample_eth_block_and_receipts,
+            eth_database_utils::put_eth_latest_block_in_db,
+        },
+    };
+
+    fn get_sample_seen_in_block() -> SeenInBlock {
+        SeenInBlock { number: 1, hash: "0xc0ffee".to_string() }
+    }
+
+    #[test]
+    fn should_mark_seen_txs_in_watchlist() {
+        let db = get_test_database();
+        let tx_hashes = vec!["0xc0ffee".to_string(), "0xdecaf".to_string()];
+        add_txs_to_watchlist_in_db(&db, "ETH", tx_hashes.clone(), 1).unwrap();
+        add_txs_to_watchlist_in_db(&db, "BTC", tx_hashes.clone(), 2).unwrap();
+        mark_seen_txs_in_watchlist_in_db(
+            &db,
+            "ETH",
+            &tx_hashes[..1],
+            get_sample_seen_in_block(),
+        ).unwrap();
+        let result = get_watched_txs_from_db(&db).unwrap();
+        assert_eq!(result.len(), 4);
+        assert_eq!(
+            result
+                .iter()
+                .filter(|watched_tx| watched_tx.seen_in_block.is_some())
+                .map(|watched_tx| watched_tx.chain.clone())
+                .collect::<Vec<String>>(),
+            vec!["ETH".to_string()],
+        );
+    }
+
+    #[test]
+    fn should_remove_canon_txs_from_watchlist() {
+        let db = get_test_database();
+        let tx_hashes = vec!["0xc0ffee".to_string()];
+        add_txs_to_watchlist_in_db(&db, "ETH", tx_hashes.clone(), 1).unwrap();
+        mark_seen_txs_in_watchlist_in_db(
+            &db,
+            "ETH",
+            &tx_hashes,
+            get_sample_seen_in_block(),
+        ).unwrap();
+        remove_canon_txs_from_watchlist_in_db(&db, "ETH", 0, |_| true)
+            .unwrap();
+        assert_eq!(get_watched_txs_from_db(&db).unwrap().len(), 1);
+        remove_canon_txs_from_watchlist_in_db(&db, "ETH", 1, |_| true)
+            .unwrap();
+        assert!(get_watched_txs_from_db(&db).unwrap().is_empty());
+    }
+
+    #[test]
+    fn should_re_watch_txs_seen_in_orphaned_block() {
+        let db = get_test_database();
+        let tx_hashes = vec!["0xc0ffee".to_string()];
+        add_txs_to_watchlist_in_db(&db, "ETH", tx_hashes.clone(), 1).unwrap();
+        mark_seen_txs_in_watchlist_in_db(
+            &db,
+            "ETH",
+            &tx_hashes,
+            get_sample_seen_in_block(),
+        ).unwrap();
+        remove_canon_txs_from_watchlist_in_db(&db, "ETH", 1, |_| false)
+            .unwrap();
+        let result = get_watched_txs_from_db(&db).unwrap();
+        assert_eq!(result.len(), 1);
+        assert!(result[0].seen_in_block.is_none());
+    }
+
+    #[test]
+    fn should_drop_oldest_txs_once_watchlist_is_full() {
+        let db = get_test_database();
+        let tx_hashes = (0..MAX_NUM_WATCHED_TXS + 2)
+            .map(|i| format!("0x{:x}", i))
+            .collect::<Vec<String>>();
+        add_txs_to_watchlist_in_db(&db, "ETH", tx_hashes.clone(), 1).unwrap();
+        let result = get_watched_txs_from_db(&db).unwrap();
+        assert_eq!(result.len(), MAX_NUM_WATCHED_TXS);
+        assert_eq!(result[0].tx_hash, tx_hashes[2]);
+    }
+
+    #[test]
+    fn should_remove_tx_from_watchlist() {
+        let db = get_test_database();
+        let tx_hashes = vec!["0xc0ffee".to_string(), "0xdecaf".to_string()];
+        add_txs_to_watchlist_in_db(&db, "ETH", tx_hashes.clone(), 1).unwrap();
+        remove_tx_from_watchlist_in_db(&db, "ETH", "0xc0ffee").unwrap();
+        let result = get_watched_txs_from_db(&db).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].tx_hash, "0xdecaf");
+        assert!(remove_tx_from_watchlist_in_db(&db, "BTC", "0xdecaf").is_err());
+    }
+
+    #[test]
+    fn should_watch_replacement_in_place_of_replaced_tx() {
+        let db = get_test_database();
+        let block_and_receipts = get_sample_eth_block_and_receipts();
+        put_eth_latest_block_in_db(&db, &block_and_receipts).unwrap();
+        let tx_hashes = vec!["0xc0ffee".to_string()];
+        add_txs_to_watchlist_in_db(&db, "ETH", tx_hashes.clone(), 1).unwrap();
+        replace_watched_tx_in_db(&db, "ETH", &tx_hashes, "0xdecaf").unwrap();
+        let result = get_watched_txs_from_db(&db).unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].tx_hash, "0xdecaf");
+        assert_eq!(result[0].replaced_tx_hashes, tx_hashes);
+        assert_eq!(
+            result[0].signed_at_block_number,
+            block_and_receipts.block.number.as_u64(),
+        );
+        mark_seen_txs_in_watchlist_in_db(
+            &db,
+            "ETH",
+            &tx_hashes,
+            get_sample_seen_in_block(),
+        ).unwrap();
+        assert!(
+            get_watched_txs_from_db(&db).unwrap()[0].seen_in_block.is_some()
+        );
+    }
+
+    #[test]
+    fn should_flag_only_orphaned_btc_txs_in_watchlist() {
+        let db = get_test_database();
+        let tx_hashes = vec!["0xc0ffee".to_string(), "0xdecaf".to_string()];
+        add_txs_to_watchlist_in_db(&db, "ETH", tx_hashes.clone(), 1).unwrap();
+        add_txs_to_watchlist_in_db(&db, "BTC", tx_hashes.clone(), 2).unwrap();
+        flag_orphaned_btc_txs_in_watchlist_in_db(&db, &tx_hashes[..1], "0xb0b")
+            .unwrap();
+        let result = get_watched_txs_from_db(&db).unwrap();
+        assert_eq!(
+            result
+                .iter()
+                .filter(|watched_tx|
+                    watched_tx.orphaned_eth_block_hash.is_some()
+                )
+                .map(|watched_tx|
+                    (watched_tx.chain.clone(), watched_tx.tx_hash.clone())
+                )
+                .collect::<Vec<(String, String)>>(),
+            vec![("BTC".to_string(), tx_hashes[0].clone())],
+        );
+    }
+
+    #[test]
+    fn should_get_default_stale_tx_threshold() {
+        let db = get_test_database();
+        let result = get_stale_tx_threshold_from_db(&db);
+        assert_eq!(result, DEFAULT_STALE_TX_THRESHOLD_IN_BLOCKS);
+        put_stale_tx_threshold_in_db(&db, 3).unwrap();
+        assert_eq!(get_stale_tx_threshold_from_db(&db), 3);
+    }
+}
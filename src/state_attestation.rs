@@ -0,0 +1,253 @@
+use ethereum_types::{
+    H256,
+    U256,
+};
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    crypto_utils::keccak_hash_bytes,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    types::{
+        Bytes,
+        Result,
+    },
+    utxo_manager::utxo_database_utils::{
+        get_utxo_nonce_from_db,
+        get_total_utxo_balance_from_db,
+    },
+    eth::{
+        eth_crypto::eth_private_key::EthPrivateKey,
+        eth_abi_encoder::{
+            EthAbiToken,
+            encode_abi_tokens,
+        },
+        eth_database_utils::{
+            get_eth_private_key_from_db,
+            get_eth_account_nonce_from_db,
+            get_eth_canon_block_hash_from_db,
+            get_eth_latest_block_hash_from_db,
+        },
+    },
+    btc::btc_database_utils::{
+        get_btc_canon_block_hash_from_db,
+        get_btc_latest_block_hash_from_db,
+    },
+};
+
+// NOTE: Signed per EIP-712 under a fixed domain, so an attestation can never be
+// passed off as a tx or relay request signed by the same ETH key.
+const STATE_ATTESTATION_DOMAIN_TYPE_STRING: &str =
+    "EIP712Domain(string name,string version)";
+const STATE_ATTESTATION_DOMAIN_NAME: &str = "pTokensCoreStateAttestation";
+const STATE_ATTESTATION_DOMAIN_VERSION: &str = "1";
+const STATE_DIGEST_TYPE_STRING: &str = concat!(
+    "StateDigest(",
+    "bytes32 btcLatestBlockHash,",
+    "bytes32 btcCanonBlockHash,",
+    "bytes32 ethLatestBlockHash,",
+    "bytes32 ethCanonBlockHash,",
+    "uint256 btcUtxoNonce,",
+    "uint256 ethAccountNonce,",
+    "uint256 btcUtxoTotalValue",
+    ")",
+);
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateDigest {
+    pub btc_latest_block_hash: String,
+    pub btc_canon_block_hash: String,
+    pub eth_latest_block_hash: String,
+    pub eth_canon_block_hash: String,
+    pub btc_utxo_nonce: u64,
+    pub eth_account_nonce: u64,
+    pub btc_utxo_total_value: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedStateAttestation {
+    pub state: StateDigest,
+    pub digest: String,
+    pub signature: String,
+    pub signer: String,
+}
+
+fn decode_hash_hex(hash_hex: &str) -> Result<H256> {
+    let bytes = hex::decode(hash_hex.trim_start_matches("0x"))?;
+    match bytes.len() {
+        32 => Ok(H256::from_slice(&bytes)),
+        _ => Err(AppError::InvalidInput(
+            format!("✘ Cannot decode '{}' as a 32 byte hash!", hash_hex)
+        )),
+    }
+}
+
+fn hash_str(string: &str) -> Bytes {
+    keccak_hash_bytes(string.as_bytes().to_vec()).as_bytes().to_vec()
+}
+
+impl StateDigest {
+    // NOTE: Per `abi.encode(bytes32,bytes32,bytes32,bytes32,uint,uint,uint)`.
+    pub fn abi_encode(&self) -> Result<Bytes> {
+        vec![
+            &self.btc_latest_block_hash,
+            &self.btc_canon_block_hash,
+            &self.eth_latest_block_hash,
+            &self.eth_canon_block_hash,
+        ]
+            .iter()
+            .map(|hash_hex|
+                decode_hash_hex(hash_hex).map(EthAbiToken::FixedBytes)
+            )
+            .chain(
+                vec![
+                    self.btc_utxo_nonce,
+                    self.eth_account_nonce,
+                    self.btc_utxo_total_value,
+                ]
+                    .into_iter()
+                    .map(|num| Ok(EthAbiToken::Uint(U256::from(num))))
+            )
+            .collect::<Result<Vec<EthAbiToken>>>()
+            .and_then(|tokens| encode_abi_tokens(&tokens))
+    }
+
+    fn get_domain_separator() -> H256 {
+        keccak_hash_bytes(
+            [
+                STATE_ATTESTATION_DOMAIN_TYPE_STRING,
+                STATE_ATTESTATION_DOMAIN_NAME,
+                STATE_ATTESTATION_DOMAIN_VERSION,
+            ]
+                .iter()
+                .flat_map(|string| hash_str(*string))
+                .collect()
+        )
+    }
+
+    pub fn get_struct_hash(&self) -> Result<H256> {
+        self.abi_encode()
+            .map(|encoded_state|
+                [hash_str(STATE_DIGEST_TYPE_STRING), encoded_state].concat()
+            )
+            .map(keccak_hash_bytes)
+    }
+
+    fn get_signing_payload(&self) -> Result<Bytes> {
+        self.get_struct_hash()
+            .map(|struct_hash|
+                [
+                    vec![0x19, 0x01], // NOTE: Per EIP712
+                    Self::get_domain_separator().as_bytes().to_vec(),
+                    struct_hash.as_bytes().to_vec(),
+                ].concat()
+            )
+    }
+
+    pub fn get_digest(&self) -> Result<H256> {
+        self.get_signing_payload().map(keccak_hash_bytes)
+    }
+
+    pub fn sign(
+        self,
+        eth_private_key: &EthPrivateKey,
+    ) -> Result<SignedStateAttestation> {
+        let digest = self.get_digest()?;
+        let mut signature = eth_private_key.sign_hash(digest)?;
+        signature[64] += 27;
+        Ok(
+            SignedStateAttestation {
+                state: self,
+                signature: format!("0x{}", hex::encode(&signature[..])),
+                digest: format!("0x{}", hex::encode(digest)),
+                signer: format!(
+                    "0x{}",
+                    hex::encode(eth_private_key.to_public_key().to_address())
+                ),
+            }
+        )
+    }
+}
+
+pub fn get_state_digest_from_db<D>(db: &D) -> Result<StateDigest>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting state digest from db...");
+    Ok(
+        StateDigest {
+            btc_utxo_nonce: get_utxo_nonce_from_db(db)?,
+            eth_account_nonce: get_eth_account_nonce_from_db(db)?,
+            btc_utxo_total_value: get_total_utxo_balance_from_db(db)?,
+            btc_latest_block_hash:
+                get_btc_latest_block_hash_from_db(db)?.to_string(),
+            btc_canon_block_hash:
+                get_btc_canon_block_hash_from_db(db)?.to_string(),
+            eth_latest_block_hash: format!(
+                "0x{}",
+                hex::encode(get_eth_latest_block_hash_from_db(db)?)
+            ),
+            eth_canon_block_hash: format!(
+                "0x{}",
+                hex::encode(get_eth_canon_block_hash_from_db(db)?)
+            ),
+        }
+    )
+}
+
+pub fn get_signed_state_attestation<D>(db: D) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting signed state attestation...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| get_state_digest_from_db(&db))
+        .and_then(|state| state.sign(&get_eth_private_key_from_db(&db)?))
+        .and_then(|attestation| Ok(serde_json::to_string(&attestation)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        debug_signature::recover_public_key_from_signature,
+        eth::eth_test_utils::get_sample_eth_private_key,
+    };
+
+    fn get_sample_state_digest() -> StateDigest {
+        StateDigest {
+            btc_utxo_nonce: 1,
+            eth_account_nonce: 2,
+            btc_utxo_total_value: 1337,
+            btc_latest_block_hash: hex::encode([0xaa; 32]),
+            btc_canon_block_hash: hex::encode([0xbb; 32]),
+            eth_latest_block_hash: format!("0x{}", hex::encode([0xcc; 32])),
+            eth_canon_block_hash: format!("0x{}", hex::encode([0xdd; 32])),
+        }
+    }
+
+    #[test]
+    fn should_abi_encode_state_digest() {
+        let result = get_sample_state_digest().abi_encode().unwrap();
+        assert_eq!(result.len(), 7 * 32);
+        assert_eq!(result[..32], [0xaa; 32]);
+        assert_eq!(result[6 * 32..][30..], [0x05, 0x39]);
+    }
+
+    #[test]
+    fn should_sign_state_digest_recoverably() {
+        let eth_private_key = get_sample_eth_private_key();
+        let state = get_sample_state_digest();
+        let result = state.clone().sign(&eth_private_key).unwrap();
+        let signature = hex::decode(&result.signature[2..]).unwrap();
+        let public_key = recover_public_key_from_signature(
+            state.get_signing_payload().unwrap(),
+            &signature,
+        ).unwrap();
+        assert_eq!(public_key, eth_private_key.to_public_key().public_key);
+        assert!(signature[64] == 27 || signature[64] == 28);
+        assert_ne!(
+            result.digest,
+            format!("0x{}", hex::encode(keccak_hash_bytes(
+                state.abi_encode().unwrap()
+            ))),
+        );
+    }
+}
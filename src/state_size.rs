@@ -0,0 +1,136 @@
+use crate::{
+    types::Result,
+    errors::AppError,
+    chain_state::ChainState,
+    traits::DatabaseInterface,
+    database_utils::{
+        put_u64_in_db,
+        get_u64_from_db,
+    },
+    constants::{
+        MAX_STATE_SIZE_KEY,
+        DEFAULT_MAX_STATE_SIZE_IN_BYTES,
+    },
+};
+
+pub fn put_max_state_size_in_db<D>(db: &D, max_size: &u64) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting max state size of {} bytes in db...", max_size);
+    put_u64_in_db(db, &MAX_STATE_SIZE_KEY.to_vec(), max_size)
+}
+
+pub fn get_max_state_size_from_db<D>(db: &D) -> Result<u64>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting max state size from db...");
+    match get_u64_from_db(db, &MAX_STATE_SIZE_KEY.to_vec()) {
+        Ok(max_size) => Ok(max_size),
+        Err(_) => {
+            trace!("✔ No max state size in db ∴ using default!");
+            Ok(DEFAULT_MAX_STATE_SIZE_IN_BYTES)
+        }
+    }
+}
+
+pub fn check_size_is_within_limit<D>(
+    db: &D,
+    chain: &str,
+    size: u64,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    let limit = get_max_state_size_from_db(db)?;
+    match size <= limit {
+        true => Ok(()),
+        false => Err(AppError::StateSizeLimitExceeded {
+            chain: chain.to_string(),
+            size,
+            limit,
+        }),
+    }
+}
+
+pub fn check_state_size_is_within_limit<S>(state: S) -> Result<S>
+    where S: ChainState
+{
+    let size = state.get_size_in_bytes() as u64;
+    info!("✔ Checking {} state size of {} bytes...", S::chain_name(), size);
+    check_size_is_within_limit(state.db(), S::chain_name(), size)
+        .map(|_| state)
+}
+
+// NOTE: Checked against the raw submission before it's parsed, so a
+// pathological input is rejected before it's ever deserialized in memory.
+pub fn check_submission_size_is_within_limit<S>(
+    state: S,
+    submission: &str,
+) -> Result<S>
+    where S: ChainState
+{
+    let size = submission.len() as u64;
+    info!(
+        "✔ Checking {} submission size of {} bytes...",
+        S::chain_name(),
+        size,
+    );
+    check_size_is_within_limit(state.db(), S::chain_name(), size)
+        .map(|_| state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        btc::btc_state::BtcState,
+        test_utils::get_test_database,
+        btc::btc_test_utils::get_sample_btc_block_and_id,
+    };
+
+    #[test]
+    fn should_get_default_max_state_size_if_none_in_db() {
+        let db = get_test_database();
+        let result = get_max_state_size_from_db(&db).unwrap();
+        assert_eq!(result, DEFAULT_MAX_STATE_SIZE_IN_BYTES);
+    }
+
+    #[test]
+    fn should_reject_state_exceeding_max_state_size() {
+        let db = get_test_database();
+        let block_and_id = get_sample_btc_block_and_id().unwrap();
+        let limit = block_and_id.get_size_in_bytes() as u64 - 1;
+        put_max_state_size_in_db(&db, &limit).unwrap();
+        let state = BtcState::init(db)
+            .add_btc_block_and_id(block_and_id)
+            .unwrap();
+        match check_state_size_is_within_limit(state) {
+            Err(AppError::StateSizeLimitExceeded { size, .. }) =>
+                assert_eq!(size, limit + 1),
+            _ => panic!("State should exceed max state size!"),
+        }
+    }
+
+    #[test]
+    fn should_reject_submission_exceeding_max_state_size() {
+        let db = get_test_database();
+        let submission = "{\"block\":\"c0ffee\"}";
+        let limit = submission.len() as u64 - 1;
+        put_max_state_size_in_db(&db, &limit).unwrap();
+        let state = BtcState::init(db);
+        match check_submission_size_is_within_limit(state, submission) {
+            Err(AppError::StateSizeLimitExceeded { size, .. }) =>
+                assert_eq!(size, limit + 1),
+            _ => panic!("Submission should exceed max state size!"),
+        }
+    }
+
+    #[test]
+    fn should_accept_submission_within_max_state_size() {
+        let db = get_test_database();
+        let submission = "{\"block\":\"c0ffee\"}";
+        put_max_state_size_in_db(&db, &(submission.len() as u64)).unwrap();
+        let state = BtcState::init(db);
+        let result = check_submission_size_is_within_limit(state, submission);
+        assert!(result.is_ok());
+    }
+}
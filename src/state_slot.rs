@@ -0,0 +1,74 @@
+use crate::{
+    types::Result,
+    errors::AppError,
+};
+
+// NOTE: The value is private & `fill` is the only way to set it ∴ no code can
+// replace a slot's value once set, so a stage deriving a new value from a
+// filled slot must fill a slot of its own. Whether a slot is filled yet is
+// only known at runtime: every stage of a pipeline maps the same state type
+// to itself, & optional stages may be skipped via their flags. A premature
+// `get` or a second `fill` ∴ errs, but always w/ the name of the slot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StateSlot<T> {
+    name: &'static str,
+    value: Option<T>,
+}
+
+impl<T> StateSlot<T> {
+    pub fn empty(name: &'static str) -> Self {
+        StateSlot { name, value: None }
+    }
+
+    pub fn is_filled(&self) -> bool {
+        self.value.is_some()
+    }
+
+    pub fn fill(self, value: T) -> Result<Self> {
+        match self.value {
+            Some(_) => Err(AppError::CannotOverwriteState(
+                self.name.to_string()
+            )),
+            None => Ok(StateSlot { value: Some(value), ..self }),
+        }
+    }
+
+    pub fn get(&self) -> Result<&T> {
+        match &self.value {
+            Some(value) => Ok(value),
+            None => Err(AppError::NotInState(self.name.to_string())),
+        }
+    }
+
+    pub fn as_option(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    pub fn into_option(self) -> Option<T> {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_err_when_getting_from_empty_slot() {
+        match StateSlot::<u64>::empty("thing").get() {
+            Err(AppError::NotInState(name)) => assert_eq!(name, "thing"),
+            _ => panic!("Empty slot should not return a value!"),
+        }
+    }
+
+    #[test]
+    fn should_only_fill_slot_once() {
+        let slot = StateSlot::empty("thing").fill(1u64).unwrap();
+        assert_eq!(slot.get().unwrap(), &1);
+        match slot.fill(2) {
+            Err(AppError::CannotOverwriteState(name)) =>
+                assert_eq!(name, "thing"),
+            _ => panic!("Filled slot should not be filled again!"),
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::str::FromStr;
+use serde_json::Value as JsonValue;
+use crate::{
+    types::Result,
+    errors::AppError,
+    traits::DatabaseInterface,
+    state_size::check_size_is_within_limit,
+    eth::submit_eth_block::{
+        submit_eth_block_to_enclave,
+        submit_eth_rpc_block_to_enclave,
+    },
+    btc::submit_btc_block::{
+        submit_btc_block_to_enclave,
+        submit_bitcoind_block_to_enclave,
+    },
+};
+
+// NOTE: `Rpc` blocks are the raw output of the node's RPC, ie `bitcoind`'s
+// `getblock` at verbosity 2, or geth's `eth_getBlockByNumber` & receipts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BlockFormat {
+    Core,
+    Rpc,
+}
+
+impl Default for BlockFormat {
+    fn default() -> Self {
+        BlockFormat::Core
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BlockSubmission {
+    pub block: JsonValue,
+    #[serde(default)]
+    pub dry_run: bool,
+    #[serde(default)]
+    pub format: BlockFormat,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "chain")]
+pub enum SubmissionMaterial {
+    #[serde(rename = "BTC")]
+    Btc(BlockSubmission),
+    #[serde(rename = "ETH")]
+    Eth(BlockSubmission),
+}
+
+impl FromStr for SubmissionMaterial {
+    type Err = AppError;
+
+    fn from_str(json_string: &str) -> Result<Self> {
+        serde_json::from_str(json_string)
+            .map_err(|e| AppError::InvalidSubmission(
+                format!("✘ Invalid submission material: {}!", e)
+            ))
+    }
+}
+
+impl SubmissionMaterial {
+    // NOTE: The chain is unknown until the material is parsed, but its size
+    // is checked first so an oversized submission is never deserialized.
+    pub fn from_str_within_limit<D>(db: &D, json_string: &str) -> Result<Self>
+        where D: DatabaseInterface
+    {
+        check_size_is_within_limit(db, "UNKNOWN", json_string.len() as u64)
+            .and_then(|_| Self::from_str(json_string))
+    }
+}
+
+pub fn submit_block<D>(db: D, material: SubmissionMaterial) -> Result<String>
+    where D: DatabaseInterface
+{
+    match material {
+        SubmissionMaterial::Btc(submission) => {
+            info!("✔ Submitting BTC submission material...");
+            let submit_fn = match submission.format {
+                BlockFormat::Core => submit_btc_block_to_enclave,
+                BlockFormat::Rpc => submit_bitcoind_block_to_enclave,
+            };
+            submit_fn(db, submission.block.to_string(), submission.dry_run)
+        }
+        SubmissionMaterial::Eth(submission) => {
+            info!("✔ Submitting ETH submission material...");
+            let submit_fn = match submission.format {
+                BlockFormat::Core => submit_eth_block_to_enclave,
+                BlockFormat::Rpc => submit_eth_rpc_block_to_enclave,
+            };
+            submit_fn(db, submission.block.to_string(), submission.dry_run)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        test_utils::get_test_database,
+        state_size::put_max_state_size_in_db,
+    };
+
+    #[test]
+    fn should_parse_tagged_json_to_submission_material() {
+        let json = "{\"chain\":\"ETH\",\"block\":{\"Block\":{}},\
+            \"dry_run\":true}";
+        let result = SubmissionMaterial::from_str(json).unwrap();
+        match result {
+            SubmissionMaterial::Eth(submission) => {
+                assert!(submission.dry_run);
+                assert_eq!(submission.format, BlockFormat::Core);
+                assert!(submission.block["Block"].is_object());
+            }
+            _ => panic!("Should parse to ETH submission material!"),
+        }
+    }
+
+    #[test]
+    fn should_fail_to_parse_unknown_chain() {
+        let json = "{\"chain\":\"EOS\",\"block\":{}}";
+        assert!(SubmissionMaterial::from_str(json).is_err());
+    }
+
+    #[test]
+    fn should_parse_rpc_formatted_submission_material() {
+        let json = "{\"chain\":\"BTC\",\"block\":{},\"format\":\"rpc\"}";
+        match SubmissionMaterial::from_str(json).unwrap() {
+            SubmissionMaterial::Btc(submission) =>
+                assert_eq!(submission.format, BlockFormat::Rpc),
+            _ => panic!("Should parse to BTC submission material!"),
+        }
+    }
+
+    #[test]
+    fn should_reject_oversized_submission_material_before_parsing() {
+        let db = get_test_database();
+        let json = "{\"chain\":\"BTC\",\"block\":{}}";
+        put_max_state_size_in_db(&db, &(json.len() as u64 - 1)).unwrap();
+        match SubmissionMaterial::from_str_within_limit(&db, json) {
+            Err(AppError::StateSizeLimitExceeded { size, .. }) =>
+                assert_eq!(size, json.len() as u64),
+            _ => panic!("Submission material should exceed max state size!"),
+        }
+        put_max_state_size_in_db(&db, &(json.len() as u64)).unwrap();
+        assert!(SubmissionMaterial::from_str_within_limit(&db, json).is_ok());
+    }
+}
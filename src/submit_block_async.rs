@@ -0,0 +1,51 @@
+#![cfg(feature="async")]
+use crate::{
+    types::Result,
+    submit_block::{
+        submit_block,
+        SubmissionMaterial,
+    },
+    async_database::{
+        AsyncDatabaseInterface,
+        run_with_async_database,
+    },
+    eth::submit_eth_block::submit_eth_block_to_enclave,
+    btc::submit_btc_block::submit_btc_block_to_enclave,
+};
+
+pub async fn submit_btc_block_to_enclave_async<D>(
+    db: &D,
+    block_json_string: String,
+    dry_run: bool,
+) -> Result<String>
+    where D: AsyncDatabaseInterface
+{
+    info!("✔ Submitting BTC block to enclave via async database...");
+    run_with_async_database(db, move |fetching_db|
+        submit_btc_block_to_enclave(fetching_db, block_json_string, dry_run)
+    ).await
+}
+
+pub async fn submit_eth_block_to_enclave_async<D>(
+    db: &D,
+    block_json_string: String,
+    dry_run: bool,
+) -> Result<String>
+    where D: AsyncDatabaseInterface
+{
+    info!("✔ Submitting ETH block to enclave via async database...");
+    run_with_async_database(db, move |fetching_db|
+        submit_eth_block_to_enclave(fetching_db, block_json_string, dry_run)
+    ).await
+}
+
+pub async fn submit_block_async<D>(
+    db: &D,
+    material: SubmissionMaterial,
+) -> Result<String>
+    where D: AsyncDatabaseInterface
+{
+    run_with_async_database(db, move |fetching_db|
+        submit_block(fetching_db, material)
+    ).await
+}
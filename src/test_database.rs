@@ -0,0 +1,82 @@
+#![cfg(any(test, feature="test-database"))]
+use std::{
+    sync::Mutex,
+    collections::HashMap,
+};
+use crate::{
+    utils::get_not_in_db_err,
+    traits::DatabaseInterface,
+    types::{
+        Bytes,
+        Result,
+        DataSensitivity,
+    },
+};
+
+pub static DB_LOCK_ERROR: &'static str = "✘ Cannot get lock on DB!";
+
+#[derive(Default)]
+pub struct TestDatabase(pub Mutex<HashMap<Bytes, Bytes>>);
+
+impl TestDatabase {
+    pub fn new() -> Self {
+        Self(Mutex::new(HashMap::new()))
+    }
+}
+
+impl DatabaseInterface for TestDatabase {
+    fn end_transaction(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn start_transaction(&self) -> Result<()> {
+        Ok(())
+    }
+
+    fn put(
+        &self,
+        key: Bytes,
+        value: Bytes,
+        _sensitivity: DataSensitivity,
+    ) -> Result<()> {
+        self
+            .0
+            .lock()
+            .expect(DB_LOCK_ERROR)
+            .insert(key, value);
+        Ok(())
+    }
+
+    fn delete(&self, key: Bytes) -> Result<()> {
+        self
+            .0
+            .lock()
+            .expect(DB_LOCK_ERROR)
+            .remove(&key);
+        Ok(())
+    }
+
+    fn get(&self, key: Bytes, _sensitivity: DataSensitivity) -> Result<Bytes> {
+        match self
+            .0
+            .lock()
+            .expect(DB_LOCK_ERROR)
+            .get(&key) {
+                Some(value) => Ok(value.to_vec()),
+                None => Err(get_not_in_db_err())
+            }
+    }
+
+    fn get_keys_with_prefix(&self, prefix: Bytes) -> Result<Vec<Bytes>> {
+        Ok(
+            self
+                .0
+                .lock()
+                .expect(DB_LOCK_ERROR)
+                .keys()
+                .filter(|key| key.starts_with(&prefix))
+                .cloned()
+                .collect()
+        )
+    }
+}
@@ -0,0 +1,337 @@
+#![cfg(any(test, feature="test-generators"))]
+// NOTE: `proptest` strategies for arbitrary BTC submission material, for use
+// in property tests & fuzzing beyond the crate's static sample blocks.
+use std::str::FromStr;
+use ethereum_types::Address as EthAddress;
+use proptest::{
+    prelude::*,
+    collection::vec,
+};
+use bitcoin::{
+    util::{
+        hash::BitcoinHash,
+        address::Address as BtcAddress,
+    },
+    hashes::{
+        Hash,
+        sha256d,
+    },
+    blockdata::{
+        opcodes,
+        block::{
+            Block as BtcBlock,
+            BlockHeader as BtcBlockHeader,
+        },
+        script::{
+            Script as BtcScript,
+            Builder as BtcScriptBuilder,
+        },
+        transaction::{
+            TxIn as BtcTxIn,
+            TxOut as BtcTxOut,
+            OutPoint as BtcOutPoint,
+            Transaction as BtcTransaction,
+        },
+    },
+};
+use crate::{
+    utils::convert_satoshis_to_ptoken,
+    constants::MINIMUM_REQUIRED_SATOSHIS,
+    btc::{
+        btc_utils::create_op_return_btc_utxo_and_value_from_tx_output,
+        btc_constants::{
+            DEFAULT_BTC_ADDRESS,
+            DEFAULT_BTC_SEQUENCE,
+        },
+        btc_types::{
+            MintingParams,
+            BtcBlockAndId,
+            BtcUtxosAndValues,
+            MintingParamStruct,
+        },
+    },
+};
+
+pub const MAX_DEPOSIT_AMOUNT_IN_SATOSHIS: u64 = 21_000_000 * 100_000_000;
+const DIFFICULTY_ONE_BITS: u32 = 0x1d00ffff;
+
+pub fn arb_eth_address() -> impl Strategy<Value = EthAddress> {
+    any::<[u8; 20]>().prop_map(|bytes| EthAddress::from_slice(&bytes))
+}
+
+pub fn arb_sha256d_hash() -> impl Strategy<Value = sha256d::Hash> {
+    any::<[u8; 32]>().prop_map(|bytes| sha256d::Hash::hash(&bytes))
+}
+
+// NOTE: Straddles the minimum so that both branches of the filters are run.
+pub fn arb_deposit_amount() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        0..MINIMUM_REQUIRED_SATOSHIS,
+        MINIMUM_REQUIRED_SATOSHIS..MAX_DEPOSIT_AMOUNT_IN_SATOSHIS,
+    ]
+}
+
+pub fn arb_minting_params(
+    max_num_params: usize,
+) -> impl Strategy<Value = MintingParams> {
+    vec(
+        (arb_deposit_amount(), arb_eth_address(), arb_sha256d_hash()),
+        0..max_num_params,
+    )
+        .prop_map(|tuples|
+            tuples
+                .into_iter()
+                .enumerate()
+                .map(|(i, (amount, eth_address, originating_tx_hash))|
+                    MintingParamStruct::new(
+                        convert_satoshis_to_ptoken(amount),
+                        eth_address,
+                        originating_tx_hash,
+                        BtcAddress::from_str(DEFAULT_BTC_ADDRESS)
+                            .expect("✘ Default BTC address should be valid!"),
+                    ).with_correlation_id(i)
+                )
+                .collect()
+        )
+}
+
+fn get_op_return_script(eth_address: &EthAddress) -> BtcScript {
+    BtcScriptBuilder::new()
+        .push_opcode(opcodes::all::OP_RETURN)
+        .push_slice(eth_address.as_bytes())
+        .into_script()
+}
+
+// NOTE: The input carries a witness so the spender is not parsed from it.
+pub fn arb_op_return_deposit_tx(
+    target_deposit_script: BtcScript,
+) -> impl Strategy<Value = BtcTransaction> {
+    (
+        arb_deposit_amount(),
+        arb_eth_address(),
+        arb_sha256d_hash(),
+        any::<u32>(),
+    )
+        .prop_map(move |(amount, eth_address, previous_txid, vout)|
+            BtcTransaction {
+                version: 1,
+                lock_time: 0,
+                input: vec![
+                    BtcTxIn {
+                        script_sig: BtcScript::new(),
+                        sequence: DEFAULT_BTC_SEQUENCE,
+                        witness: vec![vec![0u8; 72]],
+                        previous_output: BtcOutPoint {
+                            vout,
+                            txid: previous_txid,
+                        },
+                    }
+                ],
+                output: vec![
+                    BtcTxOut {
+                        value: amount,
+                        script_pubkey: target_deposit_script.clone(),
+                    },
+                    BtcTxOut {
+                        value: 0,
+                        script_pubkey: get_op_return_script(&eth_address),
+                    },
+                ],
+            }
+        )
+}
+
+pub fn arb_utxos_and_values(
+    target_deposit_script: BtcScript,
+    max_num_utxos: usize,
+) -> impl Strategy<Value = BtcUtxosAndValues> {
+    vec(arb_op_return_deposit_tx(target_deposit_script), 0..max_num_utxos)
+        .prop_map(|txs|
+            txs
+                .iter()
+                .map(|tx|
+                    create_op_return_btc_utxo_and_value_from_tx_output(tx, 0)
+                )
+                .collect()
+        )
+}
+
+// NOTE: Blocks are self consistent, with correct merkle roots & IDs, but they
+// carry no valid proof-of-work & chain to arbitrary parents.
+pub fn arb_btc_block_and_id(
+    target_deposit_script: BtcScript,
+    max_num_deposits: usize,
+) -> impl Strategy<Value = BtcBlockAndId> {
+    (
+        any::<u32>(),
+        arb_sha256d_hash(),
+        any::<u32>(),
+        any::<u32>(),
+        vec(
+            arb_op_return_deposit_tx(target_deposit_script),
+            1..max_num_deposits,
+        ),
+    )
+        .prop_map(|(height, prev_blockhash, time, nonce, txdata)| {
+            let mut block = BtcBlock {
+                txdata,
+                header: BtcBlockHeader {
+                    time,
+                    nonce,
+                    version: 1,
+                    prev_blockhash,
+                    bits: DIFFICULTY_ONE_BITS,
+                    merkle_root: sha256d::Hash::hash(&[]),
+                },
+            };
+            block.header.merkle_root = block.merkle_root();
+            BtcBlockAndId::new(
+                height as u64,
+                block.clone(),
+                block.header.bitcoin_hash(),
+                vec![],
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bitcoin::consensus::encode::{
+        serialize as btc_serialize,
+        deserialize as btc_deserialize,
+    };
+    use crate::{
+        test_utils::get_test_database,
+        skipped_items::SkipReason,
+        db_codec::DbSerializationFormat,
+        btc::{
+            btc_state::BtcState,
+            filter_utxos::maybe_filter_utxos_in_state,
+            filter_minting_params::maybe_filter_minting_params_in_state,
+            extract_utxos_from_op_return_txs::extract_utxos_from_txs,
+            filter_op_return_deposit_txs::filter_txs_for_op_return_deposits,
+            btc_test_utils::get_sample_btc_private_key,
+            btc_utils::{
+                serialize_minting_params,
+                deserialize_minting_params,
+                get_pay_to_pub_key_hash_script,
+            },
+        },
+    };
+
+    const MAX_NUM_ITEMS: usize = 20;
+
+    fn get_target_deposit_script() -> BtcScript {
+        get_pay_to_pub_key_hash_script(
+            &get_sample_btc_private_key().to_p2pkh_btc_address()
+        ).unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn minting_params_should_round_trip_through_db_serialization(
+            minting_params in arb_minting_params(MAX_NUM_ITEMS)
+        ) {
+            let bytes = serialize_minting_params(
+                &minting_params,
+                DbSerializationFormat::Json,
+            ).unwrap();
+            let result = deserialize_minting_params(bytes).unwrap();
+            prop_assert_eq!(result, minting_params);
+        }
+
+        #[test]
+        fn utxos_should_round_trip_through_json(
+            utxos in arb_utxos_and_values(
+                get_target_deposit_script(),
+                MAX_NUM_ITEMS,
+            )
+        ) {
+            let json = serde_json::to_string(&utxos).unwrap();
+            let result: BtcUtxosAndValues = serde_json::from_str(&json)
+                .unwrap();
+            prop_assert_eq!(result, utxos);
+        }
+
+        #[test]
+        fn blocks_should_be_self_consistent_and_round_trip(
+            block_and_id in arb_btc_block_and_id(
+                get_target_deposit_script(),
+                MAX_NUM_ITEMS,
+            )
+        ) {
+            prop_assert!(block_and_id.block.check_merkle_root());
+            let bytes = btc_serialize(&block_and_id.block);
+            let result: BtcBlock = btc_deserialize(&bytes).unwrap();
+            prop_assert_eq!(result.bitcoin_hash(), block_and_id.id);
+        }
+
+        #[test]
+        fn should_extract_one_utxo_per_deposit_in_block(
+            block_and_id in arb_btc_block_and_id(
+                get_target_deposit_script(),
+                MAX_NUM_ITEMS,
+            )
+        ) {
+            let txs = filter_txs_for_op_return_deposits(
+                &get_sample_btc_private_key(),
+                &block_and_id.block.txdata,
+            ).unwrap();
+            prop_assert_eq!(txs.len(), block_and_id.block.txdata.len());
+            let utxos = extract_utxos_from_txs(
+                &get_target_deposit_script(),
+                &txs,
+            );
+            prop_assert_eq!(
+                utxos.iter().map(|utxo| utxo.value).sum::<u64>(),
+                txs.iter().map(|tx| tx.output[0].value).sum::<u64>(),
+            );
+        }
+
+        #[test]
+        fn utxo_filter_should_only_remove_dust(
+            utxos in arb_utxos_and_values(
+                get_target_deposit_script(),
+                MAX_NUM_ITEMS,
+            )
+        ) {
+            let state = BtcState::init(get_test_database())
+                .add_utxos_and_values(utxos.clone())
+                .and_then(maybe_filter_utxos_in_state)
+                .unwrap();
+            prop_assert_eq!(
+                state.utxos_and_values.len(),
+                utxos
+                    .iter()
+                    .filter(|utxo| utxo.value >= MINIMUM_REQUIRED_SATOSHIS)
+                    .count(),
+            );
+        }
+
+        #[test]
+        fn deposit_filter_should_only_remove_and_report_dust(
+            minting_params in arb_minting_params(MAX_NUM_ITEMS)
+        ) {
+            let state = BtcState::init(get_test_database())
+                .add_minting_params(minting_params.clone())
+                .and_then(maybe_filter_minting_params_in_state)
+                .unwrap();
+            let threshold = convert_satoshis_to_ptoken(
+                MINIMUM_REQUIRED_SATOSHIS
+            );
+            prop_assert!(
+                state.minting_params
+                    .iter()
+                    .all(|params| params.amount >= threshold)
+            );
+            prop_assert_eq!(
+                state.skipped_items
+                    .iter()
+                    .filter(|item| item.reason == SkipReason::DustAmount)
+                    .count(),
+                minting_params.len() - state.minting_params.len(),
+            );
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use ethereum_types::U256;
+use crate::{
+    types::Result,
+    errors::AppError,
+    constants::{
+        BTC_NUM_DECIMALS,
+        MAX_HOST_TOKEN_DECIMALS,
+    },
+};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundingMode {
+    Up,
+    Down,
+}
+
+// NOTE: Rounding down in both directions means the core never mints more
+// tokens than BTC received, nor releases more BTC than tokens redeemed.
+pub const MINTING_ROUNDING_MODE: RoundingMode = RoundingMode::Down;
+pub const REDEEMING_ROUNDING_MODE: RoundingMode = RoundingMode::Down;
+
+pub fn check_host_token_decimals(decimals: u32) -> Result<u32> {
+    match decimals <= MAX_HOST_TOKEN_DECIMALS {
+        true => Ok(decimals),
+        false => Err(AppError::InvalidAmount(
+            format!(
+                "✘ Host token decimals of {} exceeds the maximum of {}!",
+                decimals,
+                MAX_HOST_TOKEN_DECIMALS,
+            )
+        )),
+    }
+}
+
+fn get_overflow_error(amount: U256, decimals: u32) -> AppError {
+    AppError::ConversionError(
+        format!(
+            "✘ Overflow converting {} for token with {} decimals!",
+            amount,
+            decimals,
+        )
+    )
+}
+
+fn get_scaling_factor(decimals: u32) -> U256 {
+    match decimals >= BTC_NUM_DECIMALS {
+        true => U256::exp10((decimals - BTC_NUM_DECIMALS) as usize),
+        false => U256::exp10((BTC_NUM_DECIMALS - decimals) as usize),
+    }
+}
+
+fn divide_with_rounding(
+    dividend: U256,
+    divisor: U256,
+    rounding_mode: &RoundingMode,
+) -> U256 {
+    let quotient = dividend / divisor;
+    match rounding_mode {
+        RoundingMode::Up if !(dividend % divisor).is_zero() =>
+            quotient + U256::one(),
+        _ => quotient,
+    }
+}
+
+pub fn convert_satoshis_to_host_token_amount(
+    satoshis: u64,
+    decimals: u32,
+    rounding_mode: &RoundingMode,
+) -> Result<U256> {
+    let decimals = check_host_token_decimals(decimals)?;
+    let satoshis = U256::from(satoshis);
+    let scaling_factor = get_scaling_factor(decimals);
+    match decimals >= BTC_NUM_DECIMALS {
+        false => Ok(
+            divide_with_rounding(satoshis, scaling_factor, rounding_mode)
+        ),
+        true => match satoshis.checked_mul(scaling_factor) {
+            Some(amount) => Ok(amount),
+            None => Err(get_overflow_error(satoshis, decimals)),
+        },
+    }
+}
+
+pub fn convert_host_token_amount_to_satoshis(
+    amount: U256,
+    decimals: u32,
+    rounding_mode: &RoundingMode,
+) -> Result<u64> {
+    let decimals = check_host_token_decimals(decimals)?;
+    let scaling_factor = get_scaling_factor(decimals);
+    let satoshis = match decimals >= BTC_NUM_DECIMALS {
+        true => divide_with_rounding(amount, scaling_factor, rounding_mode),
+        false => match amount.checked_mul(scaling_factor) {
+            Some(satoshis) => satoshis,
+            None => return Err(get_overflow_error(amount, decimals)),
+        },
+    };
+    match satoshis > U256::from(u64::max_value()) {
+        true => Err(get_overflow_error(amount, decimals)),
+        false => Ok(satoshis.as_u64()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        constants::PTOKEN_ERC777_NUM_DECIMALS,
+        utils::{
+            convert_satoshis_to_ptoken,
+            convert_ptoken_to_satoshis,
+        },
+    };
+
+    #[test]
+    fn should_convert_satoshis_to_18_decimal_token_amount_like_ptoken() {
+        let satoshis = 1337;
+        let result = convert_satoshis_to_host_token_amount(
+            satoshis,
+            PTOKEN_ERC777_NUM_DECIMALS,
+            &MINTING_ROUNDING_MODE,
+        ).unwrap();
+        assert_eq!(result, convert_satoshis_to_ptoken(satoshis));
+        let satoshis_result = convert_host_token_amount_to_satoshis(
+            result,
+            PTOKEN_ERC777_NUM_DECIMALS,
+            &REDEEMING_ROUNDING_MODE,
+        ).unwrap();
+        assert_eq!(satoshis_result, convert_ptoken_to_satoshis(result));
+    }
+
+    #[test]
+    fn should_convert_satoshis_to_8_decimal_token_amount_unchanged() {
+        let satoshis = 1337;
+        let result = convert_satoshis_to_host_token_amount(
+            satoshis,
+            8,
+            &RoundingMode::Down,
+        ).unwrap();
+        assert_eq!(result, U256::from(satoshis));
+    }
+
+    #[test]
+    fn should_convert_satoshis_to_6_decimal_token_amount_with_rounding() {
+        let satoshis = 1_337;
+        let rounded_down = convert_satoshis_to_host_token_amount(
+            satoshis,
+            6,
+            &RoundingMode::Down,
+        ).unwrap();
+        let rounded_up = convert_satoshis_to_host_token_amount(
+            satoshis,
+            6,
+            &RoundingMode::Up,
+        ).unwrap();
+        assert_eq!(rounded_down, U256::from(13));
+        assert_eq!(rounded_up, U256::from(14));
+    }
+
+    #[test]
+    fn should_convert_6_decimal_token_amount_to_satoshis() {
+        let result = convert_host_token_amount_to_satoshis(
+            U256::from(13),
+            6,
+            &RoundingMode::Down,
+        ).unwrap();
+        assert_eq!(result, 1_300);
+    }
+
+    #[test]
+    fn should_error_if_token_amount_overflows_satoshis() {
+        let amount = U256::from(u64::max_value());
+        let result = convert_host_token_amount_to_satoshis(
+            amount,
+            6,
+            &RoundingMode::Down,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_error_if_host_token_decimals_too_large() {
+        let decimals = MAX_HOST_TOKEN_DECIMALS + 1;
+        let result = convert_satoshis_to_host_token_amount(
+            1,
+            decimals,
+            &RoundingMode::Up,
+        );
+        assert!(result.is_err());
+    }
+}
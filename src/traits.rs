@@ -1,10 +1,18 @@
 use crate::{
+    errors::AppError,
     types::{
         Bytes,
         Result,
+        DataSensitivity,
     },
 };
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DatabaseWrite {
+    Delete(Bytes),
+    Put(Bytes, Bytes, DataSensitivity),
+}
+
 pub trait DatabaseInterface {
     fn end_transaction(&self) -> Result<()>;
 
@@ -15,13 +23,74 @@ pub trait DatabaseInterface {
     fn get(
         &self,
         key: Bytes,
-        data_sensitivity: Option<u8>
+        data_sensitivity: DataSensitivity
     ) -> Result<Bytes>;
 
     fn put(
         &self,
         key: Bytes,
         value: Bytes,
-        data_sensitivity: Option<u8>
+        data_sensitivity: DataSensitivity
     ) -> Result<()>;
+
+    // NOTE: Hosts able to write atomically should override this.
+    fn write_batch(&self, batch: Vec<DatabaseWrite>) -> Result<()> {
+        batch
+            .into_iter()
+            .map(|write| match write {
+                DatabaseWrite::Delete(key) => self.delete(key),
+                DatabaseWrite::Put(key, value, data_sensitivity) =>
+                    self.put(key, value, data_sensitivity),
+            })
+            .collect::<Result<Vec<()>>>()
+            .map(|_| ())
+    }
+
+    fn get_keys_with_prefix(&self, _prefix: Bytes) -> Result<Vec<Bytes>> {
+        Err(AppError::DatabaseError(
+            "✘ Prefix iteration is not supported by this database!"
+                .to_string()
+        ))
+    }
+}
+
+// NOTE: So a submission may still write to the host's db via a reference once
+// the staged db wrapping it has been consumed by a failed pipeline.
+impl<D: DatabaseInterface + ?Sized> DatabaseInterface for &D {
+    fn end_transaction(&self) -> Result<()> {
+        (**self).end_transaction()
+    }
+
+    fn start_transaction(&self) -> Result<()> {
+        (**self).start_transaction()
+    }
+
+    fn delete(&self, key: Bytes) -> Result<()> {
+        (**self).delete(key)
+    }
+
+    fn get(
+        &self,
+        key: Bytes,
+        data_sensitivity: DataSensitivity
+    ) -> Result<Bytes> {
+        (**self).get(key, data_sensitivity)
+    }
+
+    fn put(
+        &self,
+        key: Bytes,
+        value: Bytes,
+        data_sensitivity: DataSensitivity
+    ) -> Result<()> {
+        (**self).put(key, value, data_sensitivity)
+    }
+
+    fn write_batch(&self, batch: Vec<DatabaseWrite>) -> Result<()> {
+        (**self).write_batch(batch)
+    }
+
+    fn get_keys_with_prefix(&self, prefix: Bytes) -> Result<Vec<Bytes>> {
+        (**self).get_keys_with_prefix(prefix)
+    }
 }
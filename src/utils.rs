@@ -1,28 +1,32 @@
+use std::convert::TryFrom;
 use serde_json::Value;
 use crate::errors::AppError;
-use crate::{
-    types::{
-        Bytes,
-        Result,
-    },
-    constants::{
-        BTC_NUM_DECIMALS,
-        PTOKEN_ERC777_NUM_DECIMALS,
-    },
+use crate::types::{
+    Bytes,
+    Result,
+};
+#[cfg(any(test, feature="test-generators"))]
+use crate::constants::{
+    BTC_NUM_DECIMALS,
+    PTOKEN_ERC777_NUM_DECIMALS,
 };
 use ethereum_types::{
     U256,
     H256,
     Address as EthAddress
 };
-use crate::constants::{
-    HASH_LENGTH,
-    U64_NUM_BYTES,
+use crate::{
+    eth::eth_constants::ETH_WORD_SIZE_IN_BYTES,
+    constants::{
+        HASH_LENGTH,
+        U64_NUM_BYTES,
+        ETH_ADDRESS_LENGTH,
+    },
 };
 
 pub fn convert_bytes_to_u64(bytes: &Bytes) -> Result<u64> {
     match bytes.len() {
-        0..=7 => Err(AppError::Custom(
+        0..=7 => Err(AppError::ConversionError(
             "✘ Not enough bytes to convert to u64!"
                 .to_string()
         )),
@@ -32,7 +36,7 @@ pub fn convert_bytes_to_u64(bytes: &Bytes) -> Result<u64> {
             arr.copy_from_slice(bytes);
             Ok(u64::from_le_bytes(arr))
         }
-        _ => Err(AppError::Custom(
+        _ => Err(AppError::ConversionError(
             "✘ Too many bytes to convert to u64 without overflowing!"
                 .to_string()
         )),
@@ -43,12 +47,35 @@ pub fn convert_u64_to_bytes(u_64: &u64) -> Bytes {
     u_64.to_le_bytes().to_vec()
 }
 
+pub fn convert_u256_to_u64(u_256: &U256) -> Result<u64> {
+    match *u_256 > U256::from(u64::MAX) {
+        false => Ok(u_256.low_u64()),
+        true => Err(AppError::ConversionError(format!(
+            "✘ Cannot convert {} to u64 without overflowing!",
+            u_256,
+        ))),
+    }
+}
+
+pub fn convert_u256_to_usize(u_256: &U256) -> Result<usize> {
+    convert_u256_to_u64(u_256)
+        .and_then(|u_64| match usize::try_from(u_64) {
+            Ok(u_size) => Ok(u_size),
+            Err(_) => Err(AppError::ConversionError(format!(
+                "✘ Cannot convert {} to usize without overflowing!",
+                u_256,
+            ))),
+        })
+}
+
+#[cfg(test)]
 pub fn convert_u256_to_32_byte_wide_zero_padded_hex(
     u256: U256
 ) -> String {
     format!("{:0>64}", format!("{:x}", u256))
 }
 
+#[cfg(test)]
 pub fn convert_eth_address_to_32_byte_wide_zero_padded_hex(
     eth_address: EthAddress
 ) -> String {
@@ -62,12 +89,13 @@ pub fn strip_new_line_chars(string: String) -> String {
 pub fn convert_dec_str_to_u256(dec_str: &str) -> Result<U256> {
     match U256::from_dec_str(dec_str) {
         Ok(u256) => Ok(u256),
-        Err(e) => Err(AppError::Custom(
+        Err(e) => Err(AppError::ConversionError(
             format!("✘ Error converting decimal string to u256:\n{:?}", e)
         ))
     }
 }
 
+#[cfg(test)]
 pub fn convert_h256_to_prefixed_hex(hash: H256) -> Result <String> {
     Ok(format!("0x{}", hex::encode(hash)))
 }
@@ -79,7 +107,7 @@ pub fn convert_h256_to_bytes(hash: H256) -> Bytes {
 pub fn convert_bytes_to_h256(bytes: &Bytes) -> Result<H256> {
     match bytes.len() {
         32 => Ok(H256::from_slice(&bytes[..])),
-        _ => Err(AppError::Custom(
+        _ => Err(AppError::ConversionError(
             "✘ Not enough bytes to convert to h256!".to_string()
         ))
     }
@@ -118,8 +146,27 @@ pub fn get_no_overwrite_state_err(substring: &str) -> String {
     format!("✘ Cannot overwrite {} in state!" , substring)
 }
 
+pub fn get_not_in_db_err() -> AppError {
+    AppError::NotInDb
+}
+
+// NOTE: Databases signal a missing key via this error alone, so it can be
+// told apart from a failed read.
+pub fn is_not_in_db_err(err: &AppError) -> bool {
+    matches!(err, AppError::NotInDb)
+}
+
 pub fn convert_hex_to_u256(hex: String) -> Result<U256> {
-    Ok(U256::from(&decode_prefixed_hex(hex)?[..]))
+    decode_prefixed_hex(hex)
+        .and_then(|bytes| match bytes.len() > ETH_WORD_SIZE_IN_BYTES {
+            false => Ok(U256::from(&bytes[..])),
+            true => Err(AppError::ConversionError(
+                format!(
+                    "✘ {} bytes is too many to convert to u256!",
+                    bytes.len(),
+                )
+            )),
+        })
 }
 
 pub fn convert_hex_to_bytes(hex: String) -> Result<Bytes> {
@@ -136,8 +183,22 @@ pub fn check_hex_is_valid_ethereum_address(hex: &String) -> bool {
     }
 }
 
+pub fn convert_bytes_to_eth_address(bytes: &[u8]) -> Result<EthAddress> {
+    match bytes.len() {
+        ETH_ADDRESS_LENGTH => Ok(EthAddress::from_slice(bytes)),
+        _ => Err(AppError::ConversionError(
+            format!(
+                "✘ {} bytes required to create ETH address, {} provided!",
+                ETH_ADDRESS_LENGTH,
+                bytes.len(),
+            )
+        )),
+    }
+}
+
 pub fn convert_hex_to_address(hex: String) -> Result<EthAddress> {
-    Ok(EthAddress::from_slice(&decode_prefixed_hex(hex)?))
+    decode_prefixed_hex(hex)
+        .and_then(|bytes| convert_bytes_to_eth_address(&bytes))
 }
 
 pub fn convert_hex_to_h256(hex: String) -> Result<H256> {
@@ -145,7 +206,7 @@ pub fn convert_hex_to_h256(hex: String) -> Result<H256> {
         .and_then(|bytes| match bytes.len() {
             HASH_LENGTH => Ok(H256::from_slice(&bytes)),
             _ => Err(
-                AppError::Custom(
+                AppError::ConversionError(
                     format!(
                         "✘ {} bytes required to create h256 type, {} provided!",
                         HASH_LENGTH,
@@ -164,12 +225,70 @@ pub fn convert_hex_strings_to_h256s(hex_strings: Vec<String>) -> Result<Vec<H256
     Ok(hashes?)
 }
 
+// NOTE: Borrowing counterparts of the above, for parsing hex straight out of
+// the submission material rather than first copying it into `String`s.
+pub fn decode_prefixed_hex_str(hex_str: &str) -> Result<Bytes> {
+    let stripped_hex = match hex_str.starts_with("0x") {
+        true => &hex_str[2..],
+        false => hex_str,
+    };
+    match stripped_hex.len() % 2 {
+        0 => Ok(hex::decode(stripped_hex)?),
+        _ => Ok(hex::decode(left_pad_with_zero(stripped_hex)?)?),
+    }
+}
+
+pub fn convert_hex_str_to_u256(hex_str: &str) -> Result<U256> {
+    decode_prefixed_hex_str(hex_str)
+        .and_then(|bytes| match bytes.len() > ETH_WORD_SIZE_IN_BYTES {
+            false => Ok(U256::from(&bytes[..])),
+            true => Err(AppError::ConversionError(
+                format!(
+                    "✘ {} bytes is too many to convert to u256!",
+                    bytes.len(),
+                )
+            )),
+        })
+}
+
+pub fn convert_hex_str_to_address(hex_str: &str) -> Result<EthAddress> {
+    decode_prefixed_hex_str(hex_str)
+        .and_then(|bytes| convert_bytes_to_eth_address(&bytes))
+}
+
+pub fn convert_hex_str_to_h256(hex_str: &str) -> Result<H256> {
+    decode_prefixed_hex_str(hex_str)
+        .and_then(|bytes| match bytes.len() {
+            HASH_LENGTH => Ok(H256::from_slice(&bytes)),
+            _ => Err(
+                AppError::ConversionError(
+                    format!(
+                        "✘ {} bytes required to create h256 type, {} provided!",
+                        HASH_LENGTH,
+                        bytes.len(),
+                    )
+                )
+            )
+        })
+}
+
+pub fn convert_hex_strs_to_h256s<S>(hex_strs: &[S]) -> Result<Vec<H256>>
+    where S: AsRef<str>
+{
+    hex_strs
+        .iter()
+        .map(|hex_str| convert_hex_str_to_h256(hex_str.as_ref()))
+        .collect()
+}
+
+#[cfg(any(test, feature="test-generators"))]
 pub fn convert_satoshis_to_ptoken(satoshis: u64) -> U256 {
     U256::from(satoshis) * U256::from(
         10u64.pow(PTOKEN_ERC777_NUM_DECIMALS - BTC_NUM_DECIMALS)
     )
 }
 
+#[cfg(test)]
 pub fn convert_ptoken_to_satoshis(ptoken: U256) -> u64 {
     match ptoken.checked_div(
         U256::from(
@@ -201,6 +320,20 @@ mod tests {
         assert_eq!(result, expected_result);
     }
 
+    #[test]
+    fn should_convert_u256_to_u64() {
+        let result = convert_u256_to_u64(&U256::from(u64::MAX)).unwrap();
+        assert_eq!(result, u64::MAX);
+        assert_eq!(convert_u256_to_usize(&U256::from(1337)).unwrap(), 1337);
+    }
+
+    #[test]
+    fn should_fail_to_convert_u256_too_large_for_u64() {
+        let u_256 = U256::from(u64::MAX) + U256::one();
+        assert!(convert_u256_to_u64(&u_256).is_err());
+        assert!(convert_u256_to_usize(&u_256).is_err());
+    }
+
     #[test]
     fn should_convert_ptoken_to_satoshis() {
         let ptoken = U256::from_dec_str("13370000000000")
@@ -267,6 +400,14 @@ mod tests {
         assert!(result.as_bytes() == expected_result_bytes);
     }
 
+    #[test]
+    fn should_fail_to_convert_hex_of_wrong_length_to_address() {
+        let short_hex = "0xb2930b35844a230f00e51431acae96fe543a03";
+        let long_hex = "0xb2930b35844a230f00e51431acae96fe543a034700";
+        assert!(convert_hex_to_address(short_hex.to_string()).is_err());
+        assert!(convert_hex_to_address(long_hex.to_string()).is_err());
+    }
+
     #[test]
     fn should_convert_unprefixed_hex_to_bytes_correctly() {
         let hex = "c0ffee".to_string();
@@ -308,6 +449,12 @@ mod tests {
         assert!(result.as_u128() == expected_result)
     }
 
+    #[test]
+    fn should_fail_to_convert_hex_of_over_32_bytes_to_u256() {
+        let hex = format!("0x01{}", "00".repeat(32));
+        assert!(convert_hex_to_u256(hex).is_err());
+    }
+
     #[test]
     fn should_decode_prefixed_hex_correctly() {
         let prefixed_hex = "0xc0ffee";
@@ -341,7 +488,7 @@ mod tests {
         );
         assert!(short_hash.len() < HASH_HEX_CHARS + HEX_PREFIX_LENGTH);
         match convert_hex_to_h256(short_hash.to_string()) {
-            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            Err(AppError::ConversionError(e)) => assert!(e == expected_error),
             _ => panic!("Should have errored ∵ of short hash!")
         }
     }
@@ -356,7 +503,7 @@ mod tests {
         );
         assert!(long_hash.len() > HASH_HEX_CHARS + HEX_PREFIX_LENGTH);
         match convert_hex_to_h256(long_hash.to_string()) {
-            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            Err(AppError::ConversionError(e)) => assert!(e == expected_error),
             _ => panic!("Should have errored ∵ of short hash!")
         }
     }
@@ -370,11 +517,35 @@ mod tests {
             Err(AppError::HexError(e)) => assert!(
                 e.to_string().contains("Invalid")
             ),
-            Err(AppError::Custom(_)) => panic!("Should be hex error!"),
+            Err(AppError::ConversionError(_)) => panic!("Should be hex error!"),
             _ => panic!("Should have errored ∵ of invalid hash!")
         }
     }
 
+    #[test]
+    fn should_decode_prefixed_and_odd_length_hex_strs() {
+        let expected = [192, 255, 238];
+        assert_eq!(decode_prefixed_hex_str("0xc0ffee").unwrap(), expected);
+        assert_eq!(decode_prefixed_hex_str("c0ffee").unwrap(), expected);
+        assert_eq!(decode_prefixed_hex_str("0x1").unwrap(), [1]);
+        assert!(decode_prefixed_hex_str("0xzz").is_err());
+    }
+
+    #[test]
+    fn should_convert_hex_strs_as_their_string_counterparts_do() {
+        let hash_hex = get_sample_block_hash();
+        assert_eq!(
+            convert_hex_str_to_h256(hash_hex).unwrap(),
+            convert_hex_to_h256(hash_hex.to_string()).unwrap(),
+        );
+        assert_eq!(
+            convert_hex_str_to_u256("0x1337").unwrap(),
+            convert_hex_to_u256("0x1337".to_string()).unwrap(),
+        );
+        assert!(convert_hex_str_to_h256("0xc0ffee").is_err());
+        assert!(convert_hex_str_to_address("0xc0ffee").is_err());
+    }
+
     #[test]
     fn should_convert_hex_strings_to_h256s() {
         let str1 = "0xebfa2e7610ea186fa3fa97bbaa5db80cce033dfff7e546c6ee05493dbcbfda7a".to_string();
@@ -428,7 +599,8 @@ mod tests {
         let expected_error = "✘ Error converting decimal string";
         let dec_str = "abcd";
         match convert_dec_str_to_u256(dec_str) {
-            Err(AppError::Custom(e)) => assert!(e.contains(expected_error)),
+            Err(AppError::ConversionError(e)) =>
+                assert!(e.contains(expected_error)),
             _ => panic!("Should not have converted non decimal string!")
         }
     }
@@ -519,7 +691,7 @@ mod tests {
         let bytes = vec![255,255,255,255,255,255,255];
         assert!(bytes.len() < U64_NUM_BYTES);
         match  convert_bytes_to_u64(&bytes) {
-            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            Err(AppError::ConversionError(e)) => assert!(e == expected_error),
             Ok(_) => panic!("Shouldn't work!"),
             Err(_) => panic!("Wrong error!"),
         }
@@ -533,9 +705,15 @@ mod tests {
         let bytes = vec![255,255,255,255,255,255,255,255,255];
         assert!(bytes.len() > U64_NUM_BYTES);
         match  convert_bytes_to_u64(&bytes) {
-            Err(AppError::Custom(e)) => assert!(e == expected_error),
+            Err(AppError::ConversionError(e)) => assert!(e == expected_error),
             Ok(_) => panic!("Shouldn't work!"),
             Err(_) => panic!("Wrong error!"),
         }
     }
+
+    #[test]
+    fn should_only_tell_not_in_db_err_is_not_in_db_err() {
+        assert!(is_not_in_db_err(&get_not_in_db_err()));
+        assert!(!is_not_in_db_err(&AppError::Custom("✘ Nope!".to_string())));
+    }
 }
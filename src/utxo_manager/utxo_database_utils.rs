@@ -5,9 +5,20 @@ use bitcoin_hashes::{
 use crate::{
     errors::AppError,
     traits::DatabaseInterface,
+    db_codec::get_db_serialization_format_from_db,
+    db_keys::{
+        DbKeyPrefix,
+        get_utxo_and_value_db_key,
+    },
+    database_utils::{
+        put_namespaced_key_in_db,
+        get_namespaced_keys_from_db,
+        delete_namespaced_key_from_db,
+    },
     types::{
         Bytes,
         Result,
+        DataSensitivity,
     },
     btc::btc_types::{
         BtcUtxoAndValue,
@@ -26,7 +37,6 @@ use crate::{
             TOTAL_NUM_UTXOS,
         },
         utxo_utils::{
-            get_utxo_and_value_db_key,
             deserialize_utxo_and_value,
             serialize_btc_utxo_and_value,
         },
@@ -46,6 +56,57 @@ pub fn save_utxos_to_db<D>(
         .collect::<Result<()>>()
 }
 
+// NOTE: UTXOs popped from the db still carry their old pointer, which must be
+// cleared before they're linked back in, lest the list follow a deleted key.
+pub fn restore_utxos_in_db<D>(
+    db: &D,
+    utxos_and_values: &BtcUtxosAndValues
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!("✔ Restoring {} UTXO(s) in db...", utxos_and_values.len());
+    save_utxos_to_db(
+        db,
+        &utxos_and_values
+            .iter()
+            .map(|utxo_and_value|
+                BtcUtxoAndValue {
+                    maybe_pointer: None,
+                    ..utxo_and_value.clone()
+                }
+            )
+            .collect(),
+    )
+}
+
+// NOTE: The UTXOs form a linked list ∴ removing arbitrary ones means draining
+// the list & re-linking those which are to be kept.
+pub fn remove_utxos_from_db<D>(
+    db: &D,
+    utxos_to_remove: &BtcUtxosAndValues
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!("✔ Removing {} UTXO(s) from db...", utxos_to_remove.len());
+    (0..get_total_number_of_utxos_from_db(db)?)
+        .map(|_| get_utxo_and_value(db))
+        .collect::<Result<BtcUtxosAndValues>>()
+        .map(|utxos_and_values|
+            utxos_and_values
+                .into_iter()
+                .filter(|utxo_and_value|
+                    !utxos_to_remove
+                        .iter()
+                        .any(|utxo_to_remove|
+                            utxo_to_remove.serialized_utxo ==
+                                utxo_and_value.serialized_utxo
+                        )
+                )
+                .collect::<BtcUtxosAndValues>()
+        )
+        .and_then(|utxos_to_keep| restore_utxos_in_db(db, &utxos_to_keep))
+}
+
 pub fn get_all_utxo_db_keys<D>(db: &D) -> Vec<Bytes>
     where D: DatabaseInterface
 {
@@ -171,6 +232,13 @@ pub fn delete_first_utxo<D>(db: &D) -> Result<()>
         .and_then(|pointer| {
             trace!("✔ Deleting UTXO under key: {}", hex::encode(&pointer));
             db.delete(pointer.to_vec())
+                .and_then(|_|
+                    delete_namespaced_key_from_db(
+                        db,
+                        &DbKeyPrefix::Utxo,
+                        &pointer,
+                    )
+                )
         })
 }
 
@@ -216,7 +284,7 @@ pub fn decrement_total_utxo_balance_in_db<D>(
                         &(balance - amount_to_decrement_by)
                     )
                 }
-                false => Err(AppError::Custom(
+                false => Err(AppError::InvalidAmount(
                     "✘ Not decrementing UTXO total value ∵ it'll underflow!"
                         .to_string()
                 ))
@@ -234,7 +302,7 @@ pub fn put_total_utxo_balance_in_db<D>(
     db.put(
         UTXO_BALANCE.to_vec(),
         convert_u64_to_bytes(balance),
-        None,
+        DataSensitivity::Public,
     )
 }
 
@@ -242,7 +310,7 @@ pub fn get_total_utxo_balance_from_db<D>(db: &D) -> Result<u64>
     where D: DatabaseInterface
 {
     trace!("✔ Getting total UTXO balance from db...");
-    match db.get(UTXO_BALANCE.to_vec(), None) {
+    match db.get(UTXO_BALANCE.to_vec(), DataSensitivity::Public) {
         Err(_) => Ok(0),
         Ok(bytes) => convert_bytes_to_u64(&bytes),
     }
@@ -285,7 +353,7 @@ pub fn maybe_get_utxo_from_db<D>(
     where D: DatabaseInterface
 {
     trace!("✔ Maybe getting UTXO in db under key: {}", hex::encode(db_key));
-    match db.get(db_key.to_vec(), None) {
+    match db.get(db_key.to_vec(), DataSensitivity::Private) {
         Err(_) => {
             trace!("✘ No UTXO exists in the database @ that key!");
             None
@@ -307,7 +375,7 @@ pub fn get_utxo_from_db<D>(db: &D, db_key: &Bytes) -> Result<BtcUtxoAndValue>
         "✔ Getting UTXO in db under key: {}",
         hex::encode(db_key),
     );
-    db.get(db_key.to_vec(), None)
+    db.get(db_key.to_vec(), DataSensitivity::Private)
         .and_then(|bytes| deserialize_utxo_and_value(&bytes))
 }
 
@@ -324,9 +392,25 @@ pub fn put_utxo_in_db<D>(
     );
     db.put(
         key.to_vec(),
-        serialize_btc_utxo_and_value(utxo)?,
-        None,
+        serialize_btc_utxo_and_value(
+            utxo,
+            get_db_serialization_format_from_db(db)?,
+        )?,
+        DataSensitivity::Private,
     )
+        .and_then(|_| put_namespaced_key_in_db(db, &DbKeyPrefix::Utxo, key))
+}
+
+pub fn get_all_utxos_from_db_via_key_prefix<D>(
+    db: &D,
+) -> Result<BtcUtxosAndValues>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting all UTXOs from db via key prefix...");
+    get_namespaced_keys_from_db(db, &DbKeyPrefix::Utxo)?
+        .iter()
+        .map(|key| get_utxo_from_db(db, key))
+        .collect()
 }
 
 pub fn set_last_utxo_pointer<D>(
@@ -336,14 +420,14 @@ pub fn set_last_utxo_pointer<D>(
     where D: DatabaseInterface
 {
     trace!("✔ Setting `UTXO_LAST` pointer to: {}", hash);
-    db.put(UTXO_LAST.to_vec(), hash.to_vec(), None)
+    db.put(UTXO_LAST.to_vec(), hash.to_vec(), DataSensitivity::Public)
 }
 
 pub fn get_last_utxo_pointer<D>(db: &D) -> Result<Bytes>
     where D: DatabaseInterface
 {
     trace!("✔ Getting `UTXO_LAST` pointer...");
-    db.get(UTXO_LAST.to_vec(), None)
+    db.get(UTXO_LAST.to_vec(), DataSensitivity::Public)
 }
 
 pub fn set_first_utxo_pointer<D>(
@@ -353,21 +437,21 @@ pub fn set_first_utxo_pointer<D>(
     where D: DatabaseInterface
 {
     trace!("✔ Setting `UTXO_FIRST` pointer to: {}", hash);
-    db.put(UTXO_FIRST.to_vec(), hash.to_vec(), None)
+    db.put(UTXO_FIRST.to_vec(), hash.to_vec(), DataSensitivity::Public)
 }
 
 pub fn get_first_utxo_pointer<D>(db: &D) -> Result<Bytes>
     where D: DatabaseInterface
 {
     trace!("✔ Getting `UTXO_FIRST` pointer...");
-    db.get(UTXO_FIRST.to_vec(), None)
+    db.get(UTXO_FIRST.to_vec(), DataSensitivity::Public)
 }
 
 pub fn get_utxo_nonce_from_db<D>(db: &D) -> Result<u64>
     where D: DatabaseInterface
 {
     trace!("✔ Getting UTXO nonce from db...");
-    match db.get(UTXO_NONCE.to_vec(), None) {
+    match db.get(UTXO_NONCE.to_vec(), DataSensitivity::Public) {
         Err(_) => {
             trace!("✘ Error getting UTXO nonce!");
             Ok(0)
@@ -383,7 +467,7 @@ pub fn get_total_number_of_utxos_from_db<D>(db: &D) -> Result<u64>
     where D: DatabaseInterface
 {
     trace!("✔ Getting total number of UTXOs from db...");
-    match db.get(TOTAL_NUM_UTXOS.to_vec(), None) {
+    match db.get(TOTAL_NUM_UTXOS.to_vec(), DataSensitivity::Public) {
         Err(_) => {
             trace!("✘ Error getting total number of UTXOs!");
             Ok(0)
@@ -405,7 +489,7 @@ pub fn put_total_number_of_utxos_in_db<D>(
     db.put(
         TOTAL_NUM_UTXOS.to_vec(),
         convert_u64_to_bytes(total_num_utxos),
-        None,
+        DataSensitivity::Public,
     )
 }
 
@@ -439,7 +523,7 @@ pub fn put_utxo_nonce_in_db<D>(
     db.put(
         UTXO_NONCE.to_vec(),
         convert_u64_to_bytes(utxo_nonce),
-        None,
+        DataSensitivity::Public,
     )
 }
 
@@ -681,7 +765,7 @@ mod tests {
             Ok(_) => {
                 panic!("Decrementing balance of utxos should error!");
             }
-            Err(AppError::Custom(e)) => {
+            Err(AppError::InvalidAmount(e)) => {
                 assert!(e == expected_error);
             }
             Err(e) => {
@@ -700,7 +784,11 @@ mod tests {
         if let Err(e) = delete_utxo_balance_key(&db) {
             panic!("Error deleting `UTXO_BALANCE` key: {}", e);
         };
-        assert!(!key_exists_in_db(&db, &UTXO_BALANCE.to_vec(), None));
+        assert!(!key_exists_in_db(
+            &db,
+            &UTXO_BALANCE.to_vec(),
+            DataSensitivity::Public,
+        ));
     }
 
     #[test]
@@ -713,7 +801,11 @@ mod tests {
         if let Err(e) = delete_first_utxo_key(&db) {
             panic!("Error deleting `UTXO_FIRST` key: {}", e);
         };
-        if key_exists_in_db(&db, &UTXO_FIRST.to_vec(), None) {
+        if key_exists_in_db(
+            &db,
+            &UTXO_FIRST.to_vec(),
+            DataSensitivity::Public,
+        ) {
             panic!("`UTXO_FIRST` key should not exist!");
         }
     }
@@ -728,7 +820,7 @@ mod tests {
         if let Err(e) = delete_last_utxo_key(&db) {
             panic!("Error deleting `UTXO_LAST` key: {}", e);
         };
-        if key_exists_in_db(&db, &UTXO_LAST.to_vec(), None) {
+        if key_exists_in_db(&db, &UTXO_LAST.to_vec(), DataSensitivity::Public) {
             panic!("`UTXO_LAST` key should not exist!");
         }
     }
@@ -889,11 +981,34 @@ mod tests {
         if let Err(e) = save_utxos_to_db(&db, &utxos) {
             panic!("Error saving utxos to db: {}", e);
         }
-        assert!(key_exists_in_db(&db, &first_utxo_db_key, None));
+        assert!(key_exists_in_db(
+            &db,
+            &first_utxo_db_key,
+            DataSensitivity::Public,
+        ));
         if let Err(e) = delete_first_utxo(&db) {
             panic!("Error deleting first UTXO from db: {}", e);
         }
-        assert!(!key_exists_in_db(&db, &first_utxo_db_key, None));
+        assert!(!key_exists_in_db(
+            &db,
+            &first_utxo_db_key,
+            DataSensitivity::Public,
+        ));
+    }
+
+    #[test]
+    fn should_get_all_utxos_from_db_via_key_prefix() {
+        let db = get_test_database();
+        let utxos = get_sample_utxo_and_values();
+        save_utxos_to_db(&db, &utxos).unwrap();
+        delete_first_utxo(&db).unwrap();
+        let result = get_all_utxos_from_db_via_key_prefix(&db).unwrap();
+        assert_eq!(result.len(), utxos.len() - 1);
+        assert!(
+            result
+                .iter()
+                .all(|utxo| utxos[1..].iter().any(|value| value == utxo))
+        );
     }
 
     #[test]
@@ -911,7 +1026,7 @@ mod tests {
                      key_exists_in_db(
                          &db,
                          &get_utxo_and_value_db_key((i + 1) as u64),
-                         None,
+                         DataSensitivity::Public,
                      )
                  )
              )
@@ -935,8 +1050,46 @@ mod tests {
             !key_exists_in_db(
                  &db,
                 &get_utxo_and_value_db_key(1),
-                None,
+                DataSensitivity::Public,
             )
         );
     }
+
+    #[test]
+    fn should_remove_utxos_from_db_and_keep_the_rest() {
+        let db = get_test_database();
+        let utxos = get_sample_utxo_and_values();
+        save_utxos_to_db(&db, &utxos).unwrap();
+        let balance_before = get_total_utxo_balance_from_db(&db).unwrap();
+        remove_utxos_from_db(&db, &vec![utxos[1].clone()]).unwrap();
+        assert_eq!(
+            get_total_number_of_utxos_from_db(&db).unwrap(),
+            utxos.len() as u64 - 1,
+        );
+        assert_eq!(
+            get_total_utxo_balance_from_db(&db).unwrap(),
+            balance_before - utxos[1].value,
+        );
+        let remaining = (1..utxos.len())
+            .map(|_| get_utxo_and_value(&db).unwrap().serialized_utxo)
+            .collect::<Vec<Bytes>>();
+        assert!(!remaining.contains(&utxos[1].serialized_utxo));
+        assert_eq!(get_total_number_of_utxos_from_db(&db).unwrap(), 0);
+    }
+
+    #[test]
+    fn should_restore_popped_utxos_in_db() {
+        let db = get_test_database();
+        let utxos = get_sample_utxo_and_values();
+        save_utxos_to_db(&db, &utxos).unwrap();
+        let popped = vec![get_utxo_and_value(&db).unwrap()];
+        assert!(popped[0].maybe_pointer.is_some());
+        restore_utxos_in_db(&db, &popped).unwrap();
+        assert_eq!(
+            get_total_number_of_utxos_from_db(&db).unwrap(),
+            utxos.len() as u64,
+        );
+        (0..utxos.len()).for_each(|_| { get_utxo_and_value(&db).unwrap(); });
+        assert_eq!(get_total_utxo_balance_from_db(&db).unwrap(), 0);
+    }
 }
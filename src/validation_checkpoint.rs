@@ -0,0 +1,148 @@
+use crate::{
+    chain_state::ChainState,
+    traits::DatabaseInterface,
+    staged_database::StagedDatabase,
+    types::{
+        Bytes,
+        Result,
+        DataSensitivity,
+    },
+};
+
+fn validation_checkpoint_matches<D>(
+    db: &D,
+    checkpoint_key: &[u8],
+    block_digest: &[u8],
+) -> bool
+    where D: DatabaseInterface
+{
+    match db.get(checkpoint_key.to_vec(), DataSensitivity::Public) {
+        Ok(checkpointed_digest) => checkpointed_digest == block_digest,
+        Err(_) => false,
+    }
+}
+
+pub fn validate_unless_checkpointed<D, S, F>(
+    state: S,
+    checkpoint_key: &[u8],
+    block_digest: Bytes,
+    validate: F,
+) -> Result<S>
+    where D: DatabaseInterface,
+          S: ChainState<Db = StagedDatabase<D>>,
+          F: FnOnce(S) -> Result<S>
+{
+    let chain = S::chain_name();
+    let is_checkpointed = validation_checkpoint_matches(
+        state.db(),
+        checkpoint_key,
+        &block_digest,
+    );
+    match is_checkpointed {
+        true => {
+            info!("✔ {} block validated ∴ resuming from checkpoint!", chain);
+            Ok(state)
+        }
+        false => validate(state).and_then(|state| match state.is_dry_run() {
+            true => Ok(state),
+            false => {
+                info!("✔ Saving {} block validation checkpoint...", chain);
+                state
+                    .db()
+                    .put_unstaged(
+                        checkpoint_key.to_vec(),
+                        block_digest,
+                        DataSensitivity::Public,
+                    )
+                    .map(|_| state)
+            }
+        }),
+    }
+}
+
+pub fn clear_validation_checkpoint<D>(
+    db: &D,
+    checkpoint_key: &[u8],
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Clearing block validation checkpoint...");
+    db.delete(checkpoint_key.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::AppError,
+        btc::btc_state::BtcState,
+        test_database::TestDatabase,
+        test_utils::get_test_database,
+    };
+
+    type TestState = BtcState<StagedDatabase<TestDatabase>>;
+
+    const CHECKPOINT_KEY: [u8; 1] = [0xc0];
+
+    fn failing_validation(_state: TestState) -> Result<TestState> {
+        Err(AppError::Custom("✘ Validation should be skipped!".to_string()))
+    }
+
+    #[test]
+    fn should_skip_validation_if_block_is_checkpointed() {
+        let digest = vec![0xde, 0xad];
+        let state = BtcState::init(StagedDatabase::new(get_test_database()));
+        let state = validate_unless_checkpointed(
+            state,
+            &CHECKPOINT_KEY,
+            digest.clone(),
+            Ok,
+        ).unwrap();
+        let result = validate_unless_checkpointed(
+            state,
+            &CHECKPOINT_KEY,
+            digest,
+            failing_validation,
+        );
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn should_validate_if_checkpoint_is_for_other_block() {
+        let state = BtcState::init(StagedDatabase::new(get_test_database()));
+        let state = validate_unless_checkpointed(
+            state,
+            &CHECKPOINT_KEY,
+            vec![0xde, 0xad],
+            Ok,
+        ).unwrap();
+        let result = validate_unless_checkpointed(
+            state,
+            &CHECKPOINT_KEY,
+            vec![0xbe, 0xef],
+            failing_validation,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_not_save_checkpoint_in_dry_run() {
+        let state = BtcState {
+            dry_run: true,
+            ..BtcState::init(StagedDatabase::new(get_test_database()))
+        };
+        let state = validate_unless_checkpointed(
+            state,
+            &CHECKPOINT_KEY,
+            vec![0xde, 0xad],
+            Ok,
+        ).unwrap();
+        assert!(
+            !validation_checkpoint_matches(
+                &state.db,
+                &CHECKPOINT_KEY,
+                &[0xde, 0xad],
+            )
+        );
+    }
+}
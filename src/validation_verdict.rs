@@ -0,0 +1,62 @@
+use crate::types::Result;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationVerdict {
+    pub chain: String,
+    pub is_valid: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub block_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl ValidationVerdict {
+    pub fn from_validation_result(
+        chain: &str,
+        validation_result: Result<String>,
+    ) -> Self {
+        match validation_result {
+            Ok(block_hash) => {
+                info!("✔ {} block is valid!", chain);
+                ValidationVerdict {
+                    error: None,
+                    is_valid: true,
+                    chain: chain.to_string(),
+                    block_hash: Some(block_hash),
+                }
+            }
+            Err(err) => {
+                info!("✘ {} block is invalid: {}", chain, err);
+                ValidationVerdict {
+                    is_valid: false,
+                    block_hash: None,
+                    chain: chain.to_string(),
+                    error: Some(err.to_string()),
+                }
+            }
+        }
+    }
+
+    pub fn to_json_string(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::AppError;
+
+    #[test]
+    fn should_serialize_invalid_verdict_without_block_hash() {
+        let expected_result = "{\"chain\":\"BTC\",\"is_valid\":false,\
+            \"error\":\"[E1000] ✘ Nope!\"}";
+        let result = ValidationVerdict::from_validation_result(
+            "BTC",
+            Err(AppError::Custom("✘ Nope!".to_string())),
+        )
+            .to_json_string()
+            .unwrap();
+        assert_eq!(result, expected_result);
+    }
+}
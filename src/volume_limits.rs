@@ -0,0 +1,539 @@
+use crate::{
+    errors::AppError,
+    traits::DatabaseInterface,
+    db_codec::get_db_serialization_format_from_db,
+    check_enclave_is_initialized::check_enclave_is_initialized,
+    skipped_items::{
+        SkipReason,
+        SkippedItem,
+    },
+    types::{
+        Result,
+        DataSensitivity,
+    },
+    token_decimals::{
+        MINTING_ROUNDING_MODE,
+        convert_host_token_amount_to_satoshis,
+    },
+    constants::{
+        MINT_VOLUME_LIMIT_KEY,
+        MINT_VOLUME_USAGE_KEY,
+        PAYOUT_VOLUME_LIMIT_KEY,
+        PAYOUT_VOLUME_USAGE_KEY,
+        VOLUME_LIMITED_REDEEM_PARAMS_KEY,
+        VOLUME_LIMITED_MINTING_PARAMS_KEY,
+    },
+    eth::{
+        eth_state::EthState,
+        eth_types::RedeemParams,
+        eth_database_utils::{
+            get_eth_canon_block_from_db,
+            get_eth_host_token_decimals_from_db,
+            put_eth_queued_redeem_params_in_db,
+            get_eth_queued_redeem_params_from_db,
+        },
+    },
+    btc::{
+        btc_state::BtcState,
+        btc_types::{
+            MintingParams,
+            BtcBlockInDbFormat,
+        },
+        btc_utils::{
+            serialize_minting_params,
+            deserialize_minting_params,
+        },
+        btc_database_utils::{
+            put_btc_canon_block_in_db,
+            get_btc_canon_block_from_db,
+            put_btc_queued_minting_params_in_db,
+            get_btc_queued_minting_params_from_db,
+        },
+    },
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VolumeLimit {
+    pub window_in_blocks: u64,
+    pub max_amount_in_satoshis: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VolumeUsageEntry {
+    pub block_number: u64,
+    pub amount_in_satoshis: u64,
+}
+
+pub type VolumeUsage = Vec<VolumeUsageEntry>;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VolumeLimitsReport {
+    pub mint_volume_limit: Option<VolumeLimit>,
+    pub payout_volume_limit: Option<VolumeLimit>,
+    pub num_volume_limited_minting_params: usize,
+    pub num_volume_limited_redeem_params: usize,
+}
+
+fn get_volume_limit_from_db<D>(
+    db: &D,
+    key: &[u8],
+) -> Result<Option<VolumeLimit>>
+    where D: DatabaseInterface
+{
+    match db.get(key.to_vec(), DataSensitivity::Public) {
+        Err(_) => Ok(None),
+        Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+    }
+}
+
+pub fn put_volume_limit_in_db<D>(
+    db: &D,
+    key: &[u8],
+    limit: &VolumeLimit,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    match limit.window_in_blocks {
+        0 => Err(AppError::InvalidInput(
+            "✘ Volume limit window must be at least one block!".to_string()
+        )),
+        _ => db.put(
+            key.to_vec(),
+            serde_json::to_vec(limit)?,
+            DataSensitivity::Public,
+        ),
+    }
+}
+
+pub fn remove_volume_limit_from_db<D>(db: &D, key: &[u8]) -> Result<()>
+    where D: DatabaseInterface
+{
+    info!("✔ Removing volume limit from db...");
+    db.delete(key.to_vec())
+}
+
+fn get_volume_usage_from_db<D>(db: &D, key: &[u8]) -> Result<VolumeUsage>
+    where D: DatabaseInterface
+{
+    match db.get(key.to_vec(), DataSensitivity::Public) {
+        Err(_) => Ok(vec![]),
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+    }
+}
+
+fn get_num_amounts_within_remaining_volume(
+    amounts: &[u64],
+    remaining_volume: u64,
+) -> usize {
+    let mut total = 0u64;
+    amounts
+        .iter()
+        .take_while(|amount| {
+            total = total.saturating_add(**amount);
+            total <= remaining_volume
+        })
+        .count()
+}
+
+fn apply_volume_limit_in_db<D>(
+    db: &D,
+    limit_key: &[u8],
+    usage_key: &[u8],
+    block_number: u64,
+    amounts: &[u64],
+) -> Result<usize>
+    where D: DatabaseInterface
+{
+    let limit = match get_volume_limit_from_db(db, limit_key)? {
+        Some(limit) => limit,
+        None => {
+            info!("✔ No volume limit set ∴ admitting all amounts!");
+            return Ok(amounts.len())
+        }
+    };
+    let mut usage = get_volume_usage_from_db(db, usage_key)?
+        .into_iter()
+        .filter(|entry|
+            entry.block_number.saturating_add(limit.window_in_blocks) >
+                block_number
+        )
+        .collect::<VolumeUsage>();
+    let volume_used = usage
+        .iter()
+        .map(|entry| entry.amount_in_satoshis)
+        .sum::<u64>();
+    let num_admitted = get_num_amounts_within_remaining_volume(
+        amounts,
+        limit.max_amount_in_satoshis.saturating_sub(volume_used),
+    );
+    let amount_admitted = amounts[..num_admitted].iter().sum::<u64>();
+    info!(
+        "✔ Admitting {} of {} amounts, totalling {} satoshis, under limit...",
+        num_admitted,
+        amounts.len(),
+        amount_admitted,
+    );
+    if amount_admitted > 0 {
+        usage.push(VolumeUsageEntry {
+            block_number,
+            amount_in_satoshis: amount_admitted,
+        });
+    };
+    db.put(
+        usage_key.to_vec(),
+        serde_json::to_vec(&usage)?,
+        DataSensitivity::Public,
+    )
+        .map(|_| num_admitted)
+}
+
+pub fn get_volume_limited_minting_params_from_db<D>(
+    db: &D,
+) -> Result<MintingParams>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting volume limited minting params from db...");
+    match db.get(
+        VOLUME_LIMITED_MINTING_PARAMS_KEY.to_vec(),
+        DataSensitivity::Public,
+    ) {
+        Err(_) => Ok(vec![]),
+        Ok(bytes) => deserialize_minting_params(bytes),
+    }
+}
+
+fn put_volume_limited_minting_params_in_db<D>(
+    db: &D,
+    minting_params: &MintingParams,
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting volume limited minting params in db...");
+    db.put(
+        VOLUME_LIMITED_MINTING_PARAMS_KEY.to_vec(),
+        serialize_minting_params(
+            minting_params,
+            get_db_serialization_format_from_db(db)?,
+        )?,
+        DataSensitivity::Public,
+    )
+}
+
+pub fn get_volume_limited_redeem_params_from_db<D>(
+    db: &D,
+) -> Result<Vec<RedeemParams>>
+    where D: DatabaseInterface
+{
+    trace!("✔ Getting volume limited redeem params from db...");
+    match db.get(
+        VOLUME_LIMITED_REDEEM_PARAMS_KEY.to_vec(),
+        DataSensitivity::Public,
+    ) {
+        Err(_) => Ok(vec![]),
+        Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+    }
+}
+
+fn put_volume_limited_redeem_params_in_db<D>(
+    db: &D,
+    redeem_params: &[RedeemParams],
+) -> Result<()>
+    where D: DatabaseInterface
+{
+    trace!("✔ Putting volume limited redeem params in db...");
+    db.put(
+        VOLUME_LIMITED_REDEEM_PARAMS_KEY.to_vec(),
+        serde_json::to_vec(redeem_params)?,
+        DataSensitivity::Public,
+    )
+}
+
+pub fn maybe_apply_mint_volume_limit<D>(
+    state: BtcState<D>
+) -> Result<BtcState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe applying mint volume limit to canon block...");
+    let canon_block = get_btc_canon_block_from_db(&state.db)?;
+    if canon_block.minting_params.is_empty() {
+        info!("✔ No minting params in canon block ∴ no volume to limit!");
+        return Ok(state)
+    };
+    let token_decimals = get_eth_host_token_decimals_from_db(&state.db)?;
+    let amounts = canon_block.minting_params
+        .iter()
+        .map(|params|
+            convert_host_token_amount_to_satoshis(
+                params.amount,
+                token_decimals,
+                &MINTING_ROUNDING_MODE,
+            )
+        )
+        .collect::<Result<Vec<u64>>>()?;
+    let num_admitted = apply_volume_limit_in_db(
+        &state.db,
+        &MINT_VOLUME_LIMIT_KEY,
+        &MINT_VOLUME_USAGE_KEY,
+        canon_block.height,
+        &amounts,
+    )?;
+    if num_admitted == amounts.len() {
+        return Ok(state)
+    };
+    let admitted_params = canon_block.minting_params[..num_admitted].to_vec();
+    let excess_params = canon_block.minting_params[num_admitted..].to_vec();
+    info!(
+        "✔ Mint volume limit reached ∴ holding {} minting params...",
+        excess_params.len(),
+    );
+    let skipped_items = excess_params
+        .iter()
+        .map(|params|
+            SkippedItem::new(
+                params.originating_tx_hash.to_string(),
+                params.amount.to_string(),
+                SkipReason::VolumeLimited,
+            ).with_correlation_id(&params.correlation_id)
+        )
+        .collect();
+    put_volume_limited_minting_params_in_db(
+        &state.db,
+        &[
+            get_volume_limited_minting_params_from_db(&state.db)?,
+            excess_params,
+        ].concat(),
+    )?;
+    BtcBlockInDbFormat::new(
+        canon_block.height,
+        canon_block.id,
+        admitted_params,
+        canon_block.block,
+        canon_block.extra_data,
+    )
+        .and_then(|block| put_btc_canon_block_in_db(&state.db, &block))
+        .and_then(|_| state.add_skipped_items(skipped_items))
+}
+
+pub fn maybe_apply_payout_volume_limit<D>(
+    state: EthState<D>
+) -> Result<EthState<D>>
+    where D: DatabaseInterface
+{
+    info!("✔ Maybe applying payout volume limit to redeem params...");
+    if state.redeem_params.is_empty() {
+        info!("✔ No redeem params in state ∴ no volume to limit!");
+        return Ok(state)
+    };
+    let amounts = state.redeem_params
+        .iter()
+        .map(|params| params.amount.as_u64())
+        .collect::<Vec<u64>>();
+    let num_admitted = apply_volume_limit_in_db(
+        &state.db,
+        &PAYOUT_VOLUME_LIMIT_KEY,
+        &PAYOUT_VOLUME_USAGE_KEY,
+        get_eth_canon_block_from_db(&state.db)?.block.number.as_u64(),
+        &amounts,
+    )?;
+    if num_admitted == amounts.len() {
+        return Ok(state)
+    };
+    let admitted_params = state.redeem_params[..num_admitted].to_vec();
+    let excess_params = state.redeem_params[num_admitted..].to_vec();
+    info!(
+        "✔ Payout volume limit reached ∴ holding {} redeem params...",
+        excess_params.len(),
+    );
+    let skipped_items = excess_params
+        .iter()
+        .map(|params|
+            SkippedItem::new(
+                format!("0x{}", hex::encode(params.originating_tx_hash)),
+                params.amount.to_string(),
+                SkipReason::VolumeLimited,
+            ).with_correlation_id(&params.correlation_id)
+        )
+        .collect();
+    put_volume_limited_redeem_params_in_db(
+        &state.db,
+        &[
+            get_volume_limited_redeem_params_from_db(&state.db)?,
+            excess_params,
+        ].concat(),
+    )
+        .and_then(|_| state.replace_redeem_params(admitted_params))
+        .and_then(|state| state.add_skipped_items(skipped_items))
+}
+
+// NOTE: Released params join the pause queues, which bypass the volume limits.
+pub fn release_volume_limited_params_in_db<D>(db: &D) -> Result<(usize, usize)>
+    where D: DatabaseInterface
+{
+    let minting_params = get_volume_limited_minting_params_from_db(db)?;
+    let redeem_params = get_volume_limited_redeem_params_from_db(db)?;
+    info!(
+        "✔ Releasing {} minting params & {} redeem params held by limits...",
+        minting_params.len(),
+        redeem_params.len(),
+    );
+    put_btc_queued_minting_params_in_db(
+        db,
+        &[get_btc_queued_minting_params_from_db(db)?, minting_params.clone()]
+            .concat(),
+    )?;
+    put_eth_queued_redeem_params_in_db(
+        db,
+        &[get_eth_queued_redeem_params_from_db(db)?, redeem_params.clone()]
+            .concat(),
+    )?;
+    put_volume_limited_minting_params_in_db(db, &vec![])?;
+    put_volume_limited_redeem_params_in_db(db, &[])?;
+    Ok((minting_params.len(), redeem_params.len()))
+}
+
+pub fn get_volume_limits_report_from_db<D>(
+    db: &D,
+) -> Result<VolumeLimitsReport>
+    where D: DatabaseInterface
+{
+    Ok(
+        VolumeLimitsReport {
+            mint_volume_limit:
+                get_volume_limit_from_db(db, &MINT_VOLUME_LIMIT_KEY)?,
+            payout_volume_limit:
+                get_volume_limit_from_db(db, &PAYOUT_VOLUME_LIMIT_KEY)?,
+            num_volume_limited_minting_params:
+                get_volume_limited_minting_params_from_db(db)?.len(),
+            num_volume_limited_redeem_params:
+                get_volume_limited_redeem_params_from_db(db)?.len(),
+        }
+    )
+}
+
+pub fn get_volume_limits<D>(db: D) -> Result<String>
+    where D: DatabaseInterface
+{
+    info!("✔ Getting volume limits...");
+    check_enclave_is_initialized(&db)
+        .and_then(|_| get_volume_limits_report_from_db(&db))
+        .and_then(|report| Ok(serde_json::to_string(&report)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ethereum_types::U256;
+    use crate::{
+        test_utils::get_test_database,
+        eth::{
+            eth_types::EthHash,
+            eth_database_utils::put_eth_canon_block_in_db,
+            eth_test_utils::{
+                get_sample_eth_address,
+                get_sample_eth_block_and_receipts_n,
+            },
+        },
+    };
+
+    fn get_sample_volume_limit() -> VolumeLimit {
+        VolumeLimit { window_in_blocks: 10, max_amount_in_satoshis: 1_000 }
+    }
+
+    #[test]
+    fn should_get_num_amounts_within_remaining_volume() {
+        let amounts = vec![400, 500, 200, 1];
+        assert_eq!(get_num_amounts_within_remaining_volume(&amounts, 0), 0);
+        assert_eq!(get_num_amounts_within_remaining_volume(&amounts, 1000), 2);
+        assert_eq!(get_num_amounts_within_remaining_volume(&amounts, 1101), 4);
+    }
+
+    #[test]
+    fn should_apply_volume_limit_over_rolling_window() {
+        let db = get_test_database();
+        let limit = get_sample_volume_limit();
+        put_volume_limit_in_db(&db, &MINT_VOLUME_LIMIT_KEY, &limit).unwrap();
+        let apply = |block_number, amounts: &[u64]|
+            apply_volume_limit_in_db(
+                &db,
+                &MINT_VOLUME_LIMIT_KEY,
+                &MINT_VOLUME_USAGE_KEY,
+                block_number,
+                amounts,
+            ).unwrap();
+        assert_eq!(apply(1, &[600, 300]), 2);
+        assert_eq!(apply(5, &[200]), 0);
+        assert_eq!(apply(11, &[200, 900]), 1);
+    }
+
+    #[test]
+    fn should_admit_all_amounts_if_no_volume_limit_set() {
+        let db = get_test_database();
+        let result = apply_volume_limit_in_db(
+            &db,
+            &PAYOUT_VOLUME_LIMIT_KEY,
+            &PAYOUT_VOLUME_USAGE_KEY,
+            1,
+            &[u64::max_value(), 1],
+        ).unwrap();
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn should_not_put_volume_limit_w_zero_window_in_db() {
+        let db = get_test_database();
+        let limit = VolumeLimit {
+            window_in_blocks: 0,
+            ..get_sample_volume_limit()
+        };
+        assert!(
+            put_volume_limit_in_db(&db, &MINT_VOLUME_LIMIT_KEY, &limit)
+                .is_err()
+        );
+        put_volume_limit_in_db(
+            &db,
+            &MINT_VOLUME_LIMIT_KEY,
+            &get_sample_volume_limit(),
+        ).unwrap();
+        remove_volume_limit_from_db(&db, &MINT_VOLUME_LIMIT_KEY).unwrap();
+        assert_eq!(
+            get_volume_limit_from_db(&db, &MINT_VOLUME_LIMIT_KEY).unwrap(),
+            None,
+        );
+    }
+
+    #[test]
+    fn should_report_volume_limited_redeem_params_as_skipped() {
+        let db = get_test_database();
+        put_eth_canon_block_in_db(
+            &db,
+            &get_sample_eth_block_and_receipts_n(1).unwrap(),
+        ).unwrap();
+        put_volume_limit_in_db(
+            &db,
+            &PAYOUT_VOLUME_LIMIT_KEY,
+            &get_sample_volume_limit(),
+        ).unwrap();
+        let redeem_params = vec![600, 600]
+            .into_iter()
+            .map(|amount|
+                RedeemParams::new(
+                    U256::from(amount),
+                    get_sample_eth_address(),
+                    "mudzxCq9aCQ4Una9MmayvJVCF1Tj9fypiM".to_string(),
+                    EthHash::zero(),
+                )
+            )
+            .collect::<Vec<RedeemParams>>();
+        let state = EthState::init(db)
+            .add_redeem_params(redeem_params.clone())
+            .and_then(maybe_apply_payout_volume_limit)
+            .unwrap();
+        assert_eq!(state.redeem_params, redeem_params[..1].to_vec());
+        assert_eq!(state.skipped_items.len(), 1);
+        assert_eq!(state.skipped_items[0].reason, SkipReason::VolumeLimited);
+        assert_eq!(
+            get_volume_limited_redeem_params_from_db(&state.db).unwrap(),
+            redeem_params[1..].to_vec(),
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use wasm_bindgen::prelude::*;
+use crate::{
+    errors::AppError,
+    utils::get_not_in_db_err,
+    traits::DatabaseInterface,
+    types::{
+        Bytes,
+        Result,
+        DataSensitivity,
+    },
+};
+
+#[wasm_bindgen]
+extern "C" {
+    pub type JsDatabaseHandle;
+
+    #[wasm_bindgen(method, catch)]
+    fn get(this: &JsDatabaseHandle, key: &str) -> JsResult<JsValue>;
+
+    #[wasm_bindgen(method, catch)]
+    fn put(this: &JsDatabaseHandle, key: &str, value: &str) -> JsResult<()>;
+
+    #[wasm_bindgen(method, catch)]
+    fn delete(this: &JsDatabaseHandle, key: &str) -> JsResult<()>;
+
+    #[wasm_bindgen(method, catch, js_name = startTransaction)]
+    fn start_transaction(this: &JsDatabaseHandle) -> JsResult<()>;
+
+    #[wasm_bindgen(method, catch, js_name = endTransaction)]
+    fn end_transaction(this: &JsDatabaseHandle) -> JsResult<()>;
+
+    #[wasm_bindgen(method, catch, js_name = keysWithPrefix)]
+    fn keys_with_prefix(
+        this: &JsDatabaseHandle,
+        prefix: &str,
+    ) -> JsResult<js_sys::Array>;
+}
+
+type JsResult<T> = std::result::Result<T, JsValue>;
+
+fn convert_js_err_to_app_err(js_err: JsValue) -> AppError {
+    AppError::DatabaseError(format!("✘ JS database error: {:?}!", js_err))
+}
+
+// NOTE: Keys & values cross the JS boundary as hex strings so hosts are free
+// to store them in any key-value store, eg IndexedDB or a plain `Map`.
+pub struct JsDatabase(JsDatabaseHandle);
+
+impl JsDatabase {
+    pub fn new(handle: JsDatabaseHandle) -> Self {
+        JsDatabase(handle)
+    }
+}
+
+impl DatabaseInterface for JsDatabase {
+    fn end_transaction(&self) -> Result<()> {
+        self.0.end_transaction().map_err(convert_js_err_to_app_err)
+    }
+
+    fn start_transaction(&self) -> Result<()> {
+        self.0.start_transaction().map_err(convert_js_err_to_app_err)
+    }
+
+    fn delete(&self, key: Bytes) -> Result<()> {
+        self.0.delete(&hex::encode(key)).map_err(convert_js_err_to_app_err)
+    }
+
+    fn get(&self, key: Bytes, _sensitivity: DataSensitivity) -> Result<Bytes> {
+        self.0
+            .get(&hex::encode(key))
+            .map_err(convert_js_err_to_app_err)
+            .and_then(|js_value| match js_value.as_string() {
+                Some(hex_value) => Ok(hex::decode(hex_value)?),
+                None => Err(get_not_in_db_err()),
+            })
+    }
+
+    fn put(
+        &self,
+        key: Bytes,
+        value: Bytes,
+        _sensitivity: DataSensitivity,
+    ) -> Result<()> {
+        self.0
+            .put(&hex::encode(key), &hex::encode(value))
+            .map_err(convert_js_err_to_app_err)
+    }
+
+    fn get_keys_with_prefix(&self, prefix: Bytes) -> Result<Vec<Bytes>> {
+        self.0
+            .keys_with_prefix(&hex::encode(prefix))
+            .map_err(convert_js_err_to_app_err)?
+            .iter()
+            .map(|js_value| match js_value.as_string() {
+                Some(hex_key) => Ok(hex::decode(hex_key)?),
+                None => Err(AppError::DatabaseError(
+                    "✘ JS database returned a non-string key!".to_string()
+                )),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,3 @@
+#![cfg(feature="wasm")]
+pub mod js_database;
+pub mod wasm_bindings;
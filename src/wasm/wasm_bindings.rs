@@ -0,0 +1,237 @@
+use wasm_bindgen::prelude::*;
+use crate::{
+    types::Result,
+    get_enclave_state::get_enclave_state,
+    get_latest_block_numbers::get_latest_block_numbers,
+    submit_block::{
+        submit_block,
+        SubmissionMaterial,
+    },
+    wasm::js_database::{
+        JsDatabase,
+        JsDatabaseHandle,
+    },
+    debug_functions::{
+        debug_get_all_utxos,
+        debug_get_core_state,
+        debug_get_key_from_db,
+        debug_get_safe_addresses,
+        debug_get_unexpected_db_keys,
+    },
+    eth::{
+        submit_eth_block::submit_eth_block_to_enclave,
+        initialize_eth::initialize_eth_enclave::maybe_initialize_eth_enclave,
+    },
+    btc::{
+        submit_btc_block::submit_btc_block_to_enclave,
+        initialize_btc::initialize_btc_enclave::maybe_initialize_btc_enclave,
+    },
+};
+
+type JsResult = std::result::Result<String, JsValue>;
+
+fn to_js_result(result: Result<String>) -> JsResult {
+    result.map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+#[wasm_bindgen(js_name = maybeInitializeBtcEnclave)]
+pub fn wasm_maybe_initialize_btc_enclave(
+    db: JsDatabaseHandle,
+    block_json_string: String,
+    fee: u64,
+    difficulty: u64,
+    network: String,
+    canon_to_tip_length: u64,
+) -> JsResult {
+    to_js_result(
+        maybe_initialize_btc_enclave(
+            JsDatabase::new(db),
+            block_json_string,
+            fee,
+            difficulty,
+            network,
+            canon_to_tip_length,
+        )
+    )
+}
+
+#[wasm_bindgen(js_name = maybeInitializeEthEnclave)]
+pub fn wasm_maybe_initialize_eth_enclave(
+    db: JsDatabaseHandle,
+    block_json_string: String,
+    chain_id: u8,
+    gas_price: u64,
+    canon_to_tip_length: u64,
+) -> JsResult {
+    to_js_result(
+        maybe_initialize_eth_enclave(
+            JsDatabase::new(db),
+            block_json_string,
+            chain_id,
+            gas_price,
+            canon_to_tip_length,
+        )
+    )
+}
+
+#[wasm_bindgen(js_name = submitBtcBlock)]
+pub fn wasm_submit_btc_block(
+    db: JsDatabaseHandle,
+    block_json_string: String,
+    dry_run: bool,
+) -> JsResult {
+    to_js_result(
+        submit_btc_block_to_enclave(
+            JsDatabase::new(db),
+            block_json_string,
+            dry_run,
+        )
+    )
+}
+
+#[wasm_bindgen(js_name = submitEthBlock)]
+pub fn wasm_submit_eth_block(
+    db: JsDatabaseHandle,
+    block_json_string: String,
+    dry_run: bool,
+) -> JsResult {
+    to_js_result(
+        submit_eth_block_to_enclave(
+            JsDatabase::new(db),
+            block_json_string,
+            dry_run,
+        )
+    )
+}
+
+#[wasm_bindgen(js_name = submitBlock)]
+pub fn wasm_submit_block(
+    db: JsDatabaseHandle,
+    submission_material_json_string: String,
+) -> JsResult {
+    let db = JsDatabase::new(db);
+    to_js_result(
+        SubmissionMaterial::from_str_within_limit(
+            &db,
+            &submission_material_json_string,
+        )
+            .and_then(|material| submit_block(db, material))
+    )
+}
+
+#[wasm_bindgen(js_name = getEnclaveState)]
+pub fn wasm_get_enclave_state(db: JsDatabaseHandle) -> JsResult {
+    to_js_result(get_enclave_state(JsDatabase::new(db)))
+}
+
+#[wasm_bindgen(js_name = getLatestBlockNumbers)]
+pub fn wasm_get_latest_block_numbers(db: JsDatabaseHandle) -> JsResult {
+    to_js_result(get_latest_block_numbers(JsDatabase::new(db)))
+}
+
+#[wasm_bindgen(js_name = debugGetCoreState)]
+pub fn wasm_debug_get_core_state(db: JsDatabaseHandle) -> JsResult {
+    to_js_result(debug_get_core_state(JsDatabase::new(db)))
+}
+
+#[wasm_bindgen(js_name = debugGetAllUtxos)]
+pub fn wasm_debug_get_all_utxos(db: JsDatabaseHandle) -> JsResult {
+    to_js_result(debug_get_all_utxos(JsDatabase::new(db)))
+}
+
+#[wasm_bindgen(js_name = debugGetSafeAddresses)]
+pub fn wasm_debug_get_safe_addresses(db: JsDatabaseHandle) -> JsResult {
+    to_js_result(debug_get_safe_addresses(JsDatabase::new(db)))
+}
+
+#[wasm_bindgen(js_name = debugGetUnexpectedDbKeys)]
+pub fn wasm_debug_get_unexpected_db_keys(db: JsDatabaseHandle) -> JsResult {
+    to_js_result(debug_get_unexpected_db_keys(JsDatabase::new(db)))
+}
+
+#[wasm_bindgen(js_name = debugGetKeyFromDb)]
+pub fn wasm_debug_get_key_from_db(
+    db: JsDatabaseHandle,
+    key: String,
+) -> JsResult {
+    to_js_result(debug_get_key_from_db(JsDatabase::new(db), key))
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use wasm_bindgen_test::*;
+    use crate::{
+        init_report::InitReport,
+        traits::DatabaseInterface,
+        types::DataSensitivity,
+    };
+
+    const BTC_BLOCK_JSON: &str = include_str!(
+        "../btc/btc_test_utils/sequential_block_and_ids/\
+        1611090-btc-block-and-txs.json"
+    );
+
+    #[wasm_bindgen(inline_js = "
+        export function getTestJsDatabase() {
+            const store = new Map();
+            return {
+                get: key => store.get(key),
+                put: (key, value) => { store.set(key, value); },
+                delete: key => { store.delete(key); },
+                startTransaction: () => {},
+                endTransaction: () => {},
+                keysWithPrefix: prefix =>
+                    [...store.keys()].filter(key => key.startsWith(prefix)),
+                size: () => store.size,
+            };
+        }
+    ")]
+    extern "C" {
+        #[wasm_bindgen(js_name = getTestJsDatabase)]
+        fn get_test_js_database() -> JsDatabaseHandle;
+
+        #[wasm_bindgen(method)]
+        fn size(this: &JsDatabaseHandle) -> u32;
+    }
+
+    fn init_btc_enclave(db: &JsDatabaseHandle) -> JsResult {
+        wasm_maybe_initialize_btc_enclave(
+            db.clone(),
+            BTC_BLOCK_JSON.to_string(),
+            23,
+            1,
+            "Testnet".to_string(),
+            2,
+        )
+    }
+
+    #[wasm_bindgen_test]
+    fn should_init_btc_enclave_via_js_database() {
+        let db = get_test_js_database();
+        assert!(init_btc_enclave(&db).is_ok());
+        assert!(db.size() > 0);
+        assert_eq!(
+            init_btc_enclave(&db).unwrap(),
+            InitReport::btc_already_initialized().to_json_string().unwrap(),
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn should_get_keys_with_prefix_from_js_database() {
+        let sensitivity = DataSensitivity::Public;
+        let db = JsDatabase::new(get_test_js_database());
+        db.put(vec![0xc0, 0x01], vec![], sensitivity).unwrap();
+        db.put(vec![0xc0, 0x02], vec![], sensitivity).unwrap();
+        db.put(vec![0xde, 0x01], vec![], sensitivity).unwrap();
+        let mut result = db.get_keys_with_prefix(vec![0xc0]).unwrap();
+        result.sort();
+        assert_eq!(result, vec![vec![0xc0, 0x01], vec![0xc0, 0x02]]);
+    }
+
+    #[wasm_bindgen_test]
+    fn should_throw_core_error_from_uninitialized_js_database() {
+        let db = get_test_js_database();
+        assert!(wasm_get_enclave_state(db).is_err());
+    }
+}